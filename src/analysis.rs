@@ -1,42 +1,312 @@
-use crate::task::Task;
-use std::collections::HashMap;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+use crate::checklist;
+use crate::config::{LintConfig, LintRule, NamingConfig};
+use crate::task::{Task, area_matches, estimate_to_minutes, format_minutes};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::LazyLock;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskAnalysis {
     pub task_id: String,
     pub complexity_score: f32,
     pub quality_score: f32,
     pub issues: Vec<LintIssue>,
     pub suggestions: Vec<String>,
+    /// Issues silenced by `<!-- taskguard-lint: ignore ... -->` in the task
+    pub ignored: Vec<LintIssue>,
+}
+
+impl TaskAnalysis {
+    /// `complexity_score` as a frontmatter `complexity` value (1-10)
+    pub fn suggested_complexity(&self) -> u8 {
+        (self.complexity_score.round() as u8).clamp(1, 10)
+    }
+
+    /// Move issues in categories the task's lint-ignore directives name into `ignored`
+    ///
+    /// Unknown category names are reported as an info issue.
+    pub fn apply_ignores(&mut self, content: &str) {
+        let ignores = lint_ignores(content);
+        if ignores.is_empty() {
+            return;
+        }
+
+        let known: Vec<&str> = IssueCategory::ALL.iter().map(IssueCategory::name).collect();
+        let (ignored, kept) = std::mem::take(&mut self.issues)
+            .into_iter()
+            .partition::<Vec<_>, _>(|issue| {
+                ignores
+                    .iter()
+                    .any(|name| name == "all" || name == issue.category.name())
+            });
+        self.issues = kept;
+        self.ignored.extend(ignored);
+
+        // Safe to call again after adding issues; typos are only reported once
+        for name in ignores
+            .iter()
+            .filter(|name| *name != "all" && !known.contains(&name.as_str()))
+        {
+            let message = format!("Unknown lint-ignore category '{}'", name);
+            if !self.issues.iter().any(|issue| issue.message == message) {
+                self.issues.push(LintIssue {
+                    severity: Severity::Info,
+                    category: IssueCategory::Quality,
+                    rule: "unknown-ignore".to_string(),
+                    message,
+                    suggestion: Some(format!("Use one of {}, or all", known.join(", "))),
+                    line: None,
+                });
+            }
+        }
+    }
+}
+
+static LINT_IGNORE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)<!--\s*taskguard-lint:\s*ignore\s+(.*?)\s*-->")
+        .expect("valid lint-ignore regex")
+});
+
+/// Category names from `<!-- taskguard-lint: ignore complexity, structure -->` comments, lowercased
+pub fn lint_ignores(content: &str) -> Vec<String> {
+    let mut names: Vec<String> = LINT_IGNORE
+        .captures_iter(content)
+        .flat_map(|caps| {
+            caps[1]
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|name| !name.is_empty())
+                .map(str::to_lowercase)
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    names.sort();
+    names.dedup();
+    names
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LintIssue {
     pub severity: Severity,
     pub category: IssueCategory,
+    /// Rule id from `RULES`, or the name of a custom rule
+    #[serde(default)]
+    pub rule: String,
     pub message: String,
     pub suggestion: Option<String>,
+    /// 1-based line in the task body the issue points at, when there is one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
 }
 
-#[derive(Debug, Clone)]
+/// Built-in rule ids, for `[lint.severity]` overrides
+pub const RULES: [&str; 24] = [
+    "high-complexity",
+    "long-description",
+    "many-subtasks",
+    "missing-context",
+    "missing-section",
+    "missing-objectives",
+    "missing-estimate",
+    "missing-tags",
+    "brief-description",
+    "estimate-overrun",
+    "many-assignees",
+    "empty-assignee",
+    "overdue-items",
+    "item-due-after-task",
+    "uncovered-criteria",
+    "many-dependencies",
+    "same-area-dependencies",
+    "vague-title",
+    "short-title",
+    "long-title",
+    "imperative-title",
+    "broken-link",
+    "typo",
+    "unknown-ignore",
+];
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Severity {
     Info,
+    #[default]
     Warning,
     Error,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum IssueCategory {
     Complexity,
     Structure,
     Dependencies,
     Completeness,
     Quality,
+    /// Project rules from `[[lint.rules]]`
+    Custom,
+    /// Title conventions from `[lint.naming]`
+    Naming,
+    /// Misspellings found by the opt-in spell check
+    Spelling,
+}
+
+impl IssueCategory {
+    pub const ALL: [IssueCategory; 8] = [
+        IssueCategory::Complexity,
+        IssueCategory::Structure,
+        IssueCategory::Dependencies,
+        IssueCategory::Completeness,
+        IssueCategory::Quality,
+        IssueCategory::Custom,
+        IssueCategory::Naming,
+        IssueCategory::Spelling,
+    ];
+
+    /// Lowercase name used in lint-ignore directives
+    pub fn name(&self) -> &'static str {
+        match self {
+            IssueCategory::Complexity => "complexity",
+            IssueCategory::Structure => "structure",
+            IssueCategory::Dependencies => "dependencies",
+            IssueCategory::Completeness => "completeness",
+            IssueCategory::Quality => "quality",
+            IssueCategory::Custom => "custom",
+            IssueCategory::Naming => "naming",
+            IssueCategory::Spelling => "spelling",
+        }
+    }
 }
 
 #[derive(Debug, Default)]
 pub struct TaskAnalyzer {
     pub complexity_thresholds: ComplexityThresholds,
+    /// Headings every task must contain; empty means the built-in objectives check
+    pub required_sections: Vec<String>,
+    custom_rules: Vec<CustomRule>,
+    /// Custom rules skipped because a pattern doesn't compile
+    pub rule_errors: Vec<String>,
+    pub naming: NamingRules,
+    /// `[lint.severity]`: rule id or category name → severity to report
+    severity_overrides: BTreeMap<String, Severity>,
+}
+
+/// Title conventions checked on every task
+#[derive(Debug)]
+pub struct NamingRules {
+    pub min_title_length: usize,
+    pub max_title_length: usize,
+    /// Verbs a title must start with; `None` skips the imperative check
+    pub verbs: Option<Vec<String>>,
+    /// Lowercase titles too vague to be useful
+    pub banned_titles: Vec<String>,
+}
+
+const DEFAULT_VERBS: [&str; 30] = [
+    "add",
+    "build",
+    "clean up",
+    "configure",
+    "create",
+    "deploy",
+    "design",
+    "document",
+    "enable",
+    "extract",
+    "fix",
+    "implement",
+    "improve",
+    "investigate",
+    "migrate",
+    "move",
+    "optimize",
+    "refactor",
+    "remove",
+    "rename",
+    "replace",
+    "research",
+    "review",
+    "set up",
+    "split",
+    "support",
+    "test",
+    "update",
+    "upgrade",
+    "write",
+];
+
+const DEFAULT_BANNED_TITLES: [&str; 13] = [
+    "fix stuff",
+    "stuff",
+    "misc",
+    "miscellaneous",
+    "todo",
+    "tbd",
+    "wip",
+    "fix",
+    "fix bugs",
+    "various fixes",
+    "changes",
+    "cleanup",
+    "update",
+];
+
+impl Default for NamingRules {
+    fn default() -> Self {
+        Self {
+            min_title_length: 5,
+            max_title_length: 80,
+            verbs: None,
+            banned_titles: DEFAULT_BANNED_TITLES.map(str::to_string).to_vec(),
+        }
+    }
+}
+
+impl NamingRules {
+    fn from_config(naming: &NamingConfig) -> Self {
+        let defaults = Self::default();
+        let lowercase = |words: &[String]| words.iter().map(|w| w.to_lowercase()).collect();
+        Self {
+            min_title_length: naming.min_title_length.unwrap_or(defaults.min_title_length),
+            max_title_length: naming.max_title_length.unwrap_or(defaults.max_title_length),
+            verbs: naming.imperative.then(|| {
+                naming
+                    .verbs
+                    .as_deref()
+                    .map_or_else(|| DEFAULT_VERBS.map(str::to_string).to_vec(), lowercase)
+            }),
+            banned_titles: naming
+                .banned_titles
+                .as_deref()
+                .map_or(defaults.banned_titles, lowercase),
+        }
+    }
+}
+
+/// A `[[lint.rules]]` entry with its patterns compiled
+#[derive(Debug)]
+struct CustomRule {
+    rule: LintRule,
+    forbid: Option<Regex>,
+    require: Option<Regex>,
+}
+
+impl CustomRule {
+    fn compile(rule: &LintRule) -> Result<Self, String> {
+        let compile = |pattern: &Option<String>| {
+            pattern
+                .as_deref()
+                .map(Regex::new)
+                .transpose()
+                .map_err(|e| format!("Custom rule '{}' skipped: {}", rule.name, e))
+        };
+        Ok(Self {
+            forbid: compile(&rule.forbid)?,
+            require: compile(&rule.require)?,
+            rule: rule.clone(),
+        })
+    }
 }
 
 #[derive(Debug)]
@@ -44,6 +314,9 @@ pub struct ComplexityThresholds {
     pub max_content_length: usize,
     pub max_task_items: usize,
     pub max_dependencies: usize,
+    pub max_assignees: usize,
+    /// Logged time beyond `estimate` × this ratio is flagged as an overrun
+    pub max_estimate_overrun: f32,
     pub high_complexity_score: f32,
     pub medium_complexity_score: f32,
 }
@@ -54,6 +327,8 @@ impl Default for ComplexityThresholds {
             max_content_length: 2000,
             max_task_items: 20,
             max_dependencies: 5,
+            max_assignees: 3,
+            max_estimate_overrun: 1.5,
             high_complexity_score: 7.0,
             medium_complexity_score: 4.0,
         }
@@ -68,7 +343,64 @@ impl TaskAnalyzer {
     pub fn with_thresholds(thresholds: ComplexityThresholds) -> Self {
         Self {
             complexity_thresholds: thresholds,
+            ..Self::default()
+        }
+    }
+
+    /// Analyzer using the project's `[lint]` settings over the defaults
+    pub fn from_config(lint: Option<&LintConfig>) -> Self {
+        let Some(lint) = lint else {
+            return Self::new();
+        };
+        let defaults = ComplexityThresholds::default();
+        let mut analyzer = Self {
+            complexity_thresholds: ComplexityThresholds {
+                max_content_length: lint
+                    .max_content_length
+                    .unwrap_or(defaults.max_content_length),
+                max_task_items: lint.max_task_items.unwrap_or(defaults.max_task_items),
+                max_dependencies: lint.max_dependencies.unwrap_or(defaults.max_dependencies),
+                max_assignees: lint.max_assignees.unwrap_or(defaults.max_assignees),
+                max_estimate_overrun: lint
+                    .max_estimate_overrun
+                    .unwrap_or(defaults.max_estimate_overrun),
+                high_complexity_score: lint
+                    .high_complexity_score
+                    .unwrap_or(defaults.high_complexity_score),
+                ..defaults
+            },
+            required_sections: lint.required_sections.clone(),
+            custom_rules: Vec::new(),
+            rule_errors: Vec::new(),
+            naming: lint
+                .naming
+                .as_ref()
+                .map(NamingRules::from_config)
+                .unwrap_or_default(),
+            severity_overrides: BTreeMap::new(),
+        };
+        for rule in &lint.rules {
+            match CustomRule::compile(rule) {
+                Ok(rule) => analyzer.custom_rules.push(rule),
+                Err(e) => analyzer.rule_errors.push(e),
+            }
         }
+        for (key, severity) in &lint.severity {
+            let known = RULES.contains(&key.as_str())
+                || IssueCategory::ALL.iter().any(|c| c.name() == key)
+                || lint.rules.iter().any(|r| &r.name == key);
+            if known {
+                analyzer
+                    .severity_overrides
+                    .insert(key.clone(), severity.clone());
+            } else {
+                analyzer.rule_errors.push(format!(
+                    "Unknown rule '{}' in [lint.severity]; use a rule id or category",
+                    key
+                ));
+            }
+        }
+        analyzer
     }
 
     pub fn analyze_task(&self, task: &Task) -> TaskAnalysis {
@@ -86,13 +418,32 @@ impl TaskAnalyzer {
         self.check_structure_issues(task, &mut issues, &mut suggestions);
         self.check_completeness_issues(task, &mut issues, &mut suggestions);
         self.check_dependency_issues(task, &mut issues, &mut suggestions);
+        self.check_custom_rules(task, &mut issues);
+        self.check_naming_issues(task, &mut issues);
+        self.apply_severity(&mut issues);
 
-        TaskAnalysis {
+        let mut analysis = TaskAnalysis {
             task_id: task.id.clone(),
             complexity_score,
             quality_score,
             issues,
             suggestions,
+            ignored: Vec::new(),
+        };
+        analysis.apply_ignores(&task.content);
+        analysis
+    }
+
+    /// Apply `[lint.severity]` overrides; a rule id wins over its category
+    pub fn apply_severity(&self, issues: &mut [LintIssue]) {
+        for issue in issues {
+            let severity = self
+                .severity_overrides
+                .get(&issue.rule)
+                .or_else(|| self.severity_overrides.get(issue.category.name()));
+            if let Some(severity) = severity {
+                issue.severity = severity.clone();
+            }
         }
     }
 
@@ -168,55 +519,11 @@ impl TaskAnalyzer {
     }
 
     pub fn estimate_to_complexity_points(&self, estimate: &str) -> f32 {
-        // Parse common estimate formats and convert to complexity points
-        // Conversion: 1 hour = 1 point, 1 day = 8 points, 1 week = 40 points, 1 month = 160 points
-        let estimate_lower = estimate.to_lowercase();
-
-        // Check for weeks
-        if (estimate_lower.contains("week") || estimate_lower.ends_with("w"))
-            && let Some(weeks) = self.extract_number(&estimate_lower)
-        {
-            return (weeks as f32 * 40.0).min(200.0);
-        }
-
-        // Check for months
-        if estimate_lower.contains("month")
-            && let Some(months) = self.extract_number(&estimate_lower)
-        {
-            return (months as f32 * 160.0).min(200.0);
-        }
-
-        // Check for days
-        if (estimate_lower.contains("day")
-            || (estimate_lower.ends_with("d") && !estimate_lower.contains("h")))
-            && let Some(days) = self.extract_number(&estimate_lower)
-        {
-            return (days as f32 * 8.0).min(200.0);
-        }
-
-        // Check for hours
-        if (estimate_lower.contains("hour") || estimate_lower.contains("h"))
-            && let Some(hours) = self.extract_number(&estimate_lower)
-        {
-            return (hours as f32).min(200.0);
-        }
-
-        // Check for minutes
-        if (estimate_lower.contains("minute") || estimate_lower.contains("m"))
-            && let Some(minutes) = self.extract_number(&estimate_lower)
-        {
-            return (minutes as f32 / 60.0).min(200.0);
-        }
-
-        1.0 // Default complexity for unknown estimates
-    }
-
-    fn extract_number(&self, text: &str) -> Option<u32> {
-        text.chars()
-            .filter(|c| c.is_ascii_digit())
-            .collect::<String>()
-            .parse()
-            .ok()
+        // 1 hour = 1 point, so 1 day = 8 points, 1 week = 40 and 1 month = 160
+        estimate_to_minutes(estimate).map_or(
+            1.0, // Default complexity for unknown estimates
+            |minutes| (minutes as f32 / 60.0).min(200.0),
+        )
     }
 
     fn check_complexity_issues(
@@ -230,10 +537,12 @@ impl TaskAnalyzer {
             issues.push(LintIssue {
                 severity: Severity::Warning,
                 category: IssueCategory::Complexity,
+                rule: "high-complexity".to_string(),
                 message: format!("Task has high complexity score: {:.1}/10", complexity_score),
                 suggestion: Some(
                     "Consider breaking this task into smaller, more focused subtasks".to_string(),
                 ),
+                line: None,
             });
             suggestions.push("Break down into smaller tasks based on natural boundaries (setup, implementation, testing)".to_string());
         }
@@ -242,6 +551,7 @@ impl TaskAnalyzer {
             issues.push(LintIssue {
                 severity: Severity::Info,
                 category: IssueCategory::Complexity,
+                rule: "long-description".to_string(),
                 message: format!(
                     "Task description is very long ({} characters)",
                     task.content.len()
@@ -249,6 +559,7 @@ impl TaskAnalyzer {
                 suggestion: Some(
                     "Consider moving detailed specifications to separate documentation".to_string(),
                 ),
+                line: None,
             });
         }
 
@@ -257,8 +568,10 @@ impl TaskAnalyzer {
             issues.push(LintIssue {
                 severity: Severity::Warning,
                 category: IssueCategory::Complexity,
+                rule: "many-subtasks".to_string(),
                 message: format!("Task has many subtasks ({} items)", task_items),
                 suggestion: Some("Group related subtasks into separate parent tasks".to_string()),
+                line: None,
             });
         }
     }
@@ -280,17 +593,37 @@ impl TaskAnalyzer {
             issues.push(LintIssue {
                 severity: Severity::Info,
                 category: IssueCategory::Structure,
+                rule: "missing-context".to_string(),
                 message: "Consider adding a ## Context section to explain background".to_string(),
                 suggestion: None,
+                line: None,
             });
         }
 
-        if !has_objectives && !has_acceptance {
+        if !self.required_sections.is_empty() {
+            let missing: Vec<&String> = self
+                .required_sections
+                .iter()
+                .filter(|section| !has_section(content, section))
+                .collect();
+            for section in &missing {
+                issues.push(LintIssue {
+                    severity: Severity::Warning,
+                    category: IssueCategory::Structure,
+                    rule: "missing-section".to_string(),
+                    message: format!("Missing required section: {}", section),
+                    suggestion: Some(format!("Add a ## {} section", section)),
+                    line: None,
+                });
+            }
+        } else if !has_objectives && !has_acceptance {
             issues.push(LintIssue {
                 severity: Severity::Warning,
                 category: IssueCategory::Structure,
+                rule: "missing-objectives".to_string(),
                 message: "Task lacks clear objectives or acceptance criteria".to_string(),
                 suggestion: Some("Add ## Objectives or ## Acceptance Criteria section".to_string()),
+                line: None,
             });
             suggestions.push("Define clear success criteria for this task".to_string());
         }
@@ -306,8 +639,10 @@ impl TaskAnalyzer {
             issues.push(LintIssue {
                 severity: Severity::Info,
                 category: IssueCategory::Completeness,
+                rule: "missing-estimate".to_string(),
                 message: "Task has no time estimate".to_string(),
                 suggestion: Some("Add an estimate field to help with planning".to_string()),
+                line: None,
             });
         }
 
@@ -315,10 +650,12 @@ impl TaskAnalyzer {
             issues.push(LintIssue {
                 severity: Severity::Info,
                 category: IssueCategory::Completeness,
+                rule: "missing-tags".to_string(),
                 message: "Task has no tags for categorization".to_string(),
                 suggestion: Some(
                     "Add relevant tags (e.g., backend, frontend, bug, feature)".to_string(),
                 ),
+                line: None,
             });
         }
 
@@ -326,8 +663,133 @@ impl TaskAnalyzer {
             issues.push(LintIssue {
                 severity: Severity::Warning,
                 category: IssueCategory::Completeness,
+                rule: "brief-description".to_string(),
                 message: "Task description is very brief".to_string(),
                 suggestion: Some("Add more detail about requirements and context".to_string()),
+                line: None,
+            });
+        }
+
+        if let Some(estimate) = task.estimate_minutes().filter(|e| *e > 0) {
+            let logged = task.logged_minutes();
+            if logged as f32 > estimate as f32 * self.complexity_thresholds.max_estimate_overrun {
+                issues.push(LintIssue {
+                    severity: Severity::Warning,
+                    category: IssueCategory::Completeness,
+                    rule: "estimate-overrun".to_string(),
+                    message: format!(
+                        "Logged time ({}) exceeds the estimate ({})",
+                        format_minutes(logged),
+                        format_minutes(estimate)
+                    ),
+                    suggestion: Some(
+                        "Revise the estimate or split the remaining work into a new task"
+                            .to_string(),
+                    ),
+                    line: None,
+                });
+            }
+        }
+
+        if task.assignees.len() > self.complexity_thresholds.max_assignees {
+            issues.push(LintIssue {
+                severity: Severity::Info,
+                category: IssueCategory::Completeness,
+                rule: "many-assignees".to_string(),
+                message: format!("Task has many assignees ({})", task.assignees.len()),
+                suggestion: Some(
+                    "Consider naming a single owner or splitting the work".to_string(),
+                ),
+                line: None,
+            });
+        }
+
+        if task.assignees.iter().any(|a| a.trim().is_empty()) {
+            issues.push(LintIssue {
+                severity: Severity::Warning,
+                category: IssueCategory::Completeness,
+                rule: "empty-assignee".to_string(),
+                message: "Task has an empty assignee entry".to_string(),
+                suggestion: Some("Remove blank names from the assignee list".to_string()),
+                line: None,
+            });
+        }
+
+        let items = checklist::parse_items(&task.content);
+        let today = chrono::Local::now().date_naive();
+        let overdue: Vec<&checklist::ChecklistItem> =
+            items.iter().filter(|item| item.is_overdue(today)).collect();
+        if !overdue.is_empty() {
+            issues.push(LintIssue {
+                severity: Severity::Warning,
+                category: IssueCategory::Completeness,
+                rule: "overdue-items".to_string(),
+                message: format!(
+                    "{} checklist item(s) past their due date: {}",
+                    overdue.len(),
+                    overdue
+                        .iter()
+                        .map(|item| item.text.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                suggestion: Some(
+                    "Complete the items or move their due: dates with 'taskguard task update'"
+                        .to_string(),
+                ),
+                line: overdue.first().map(|item| item.line_number),
+            });
+        }
+
+        if let Some(task_due) = task.due
+            && items
+                .iter()
+                .any(|item| item.due.is_some_and(|d| d > task_due))
+        {
+            issues.push(LintIssue {
+                severity: Severity::Info,
+                category: IssueCategory::Completeness,
+                rule: "item-due-after-task".to_string(),
+                message: format!("Checklist item due after the task itself ({})", task_due),
+                suggestion: Some("Align item due dates with the task due date".to_string()),
+                line: None,
+            });
+        }
+
+        let criteria = acceptance_criteria(&task.content);
+        let planned: Vec<HashSet<String>> = items
+            .iter()
+            .filter(|item| {
+                !criteria
+                    .section
+                    .as_ref()
+                    .is_some_and(|section| section.contains(&item.line_number))
+            })
+            .map(|item| keywords(&item.text))
+            .collect();
+        let uncovered: Vec<&(usize, String)> = criteria
+            .items
+            .iter()
+            .filter(|(_, text)| {
+                let words = keywords(text);
+                !words.is_empty() && !planned.iter().any(|p| !p.is_disjoint(&words))
+            })
+            .collect();
+        if !uncovered.is_empty() {
+            issues.push(LintIssue {
+                severity: Severity::Info,
+                category: IssueCategory::Completeness,
+                rule: "uncovered-criteria".to_string(),
+                message: format!(
+                    "Acceptance criteria without a matching checklist item: {}",
+                    uncovered
+                        .iter()
+                        .map(|(_, text)| text.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                suggestion: Some("Add checklist items that deliver each criterion".to_string()),
+                line: uncovered.first().map(|(line, _)| *line),
             });
         }
     }
@@ -342,11 +804,13 @@ impl TaskAnalyzer {
             issues.push(LintIssue {
                 severity: Severity::Warning,
                 category: IssueCategory::Dependencies,
+                rule: "many-dependencies".to_string(),
                 message: format!("Task has many dependencies ({})", task.dependencies.len()),
                 suggestion: Some(
                     "Consider if all dependencies are necessary or if some can be moved"
                         .to_string(),
                 ),
+                line: None,
             });
             suggestions.push(
                 "Review dependency list and consider creating intermediate tasks".to_string(),
@@ -362,8 +826,126 @@ impl TaskAnalyzer {
             issues.push(LintIssue {
                 severity: Severity::Info,
                 category: IssueCategory::Dependencies,
+                rule: "same-area-dependencies".to_string(),
                 message: "Task depends on other tasks in the same area".to_string(),
                 suggestion: Some("Verify dependency order within the area is logical".to_string()),
+                line: None,
+            });
+        }
+    }
+
+    fn check_custom_rules(&self, task: &Task, issues: &mut Vec<LintIssue>) {
+        for CustomRule {
+            rule,
+            forbid,
+            require,
+        } in &self.custom_rules
+        {
+            if !rule.areas.is_empty() && !rule.areas.iter().any(|a| area_matches(&task.area, a)) {
+                continue;
+            }
+
+            let mut problems = Vec::new();
+            if let Some(re) = forbid
+                && re.is_match(&task.content)
+            {
+                problems.push(format!("matches forbidden pattern `{}`", re.as_str()));
+            }
+            if let Some(re) = require
+                && !re.is_match(&task.content)
+            {
+                problems.push(format!("doesn't match required pattern `{}`", re.as_str()));
+            }
+            for field in &rule.required_fields {
+                if task.field_display(field).is_none() {
+                    problems.push(format!("missing required field '{}'", field));
+                }
+            }
+
+            for problem in problems {
+                issues.push(LintIssue {
+                    severity: rule.severity.clone(),
+                    category: IssueCategory::Custom,
+                    rule: rule.name.clone(),
+                    message: format!(
+                        "[{}] {}",
+                        rule.name,
+                        rule.message.as_deref().unwrap_or(&problem)
+                    ),
+                    suggestion: rule.suggestion.clone(),
+                    line: None,
+                });
+            }
+        }
+    }
+
+    fn check_naming_issues(&self, task: &Task, issues: &mut Vec<LintIssue>) {
+        let rules = &self.naming;
+        let title = task.title.trim();
+        let normalized = title
+            .trim_end_matches(|c: char| c.is_ascii_punctuation())
+            .to_lowercase();
+
+        if rules.banned_titles.contains(&normalized) {
+            issues.push(LintIssue {
+                severity: Severity::Warning,
+                category: IssueCategory::Naming,
+                rule: "vague-title".to_string(),
+                message: format!("Title '{}' is too vague", title),
+                suggestion: Some(
+                    "Say what changes and where, e.g. 'Fix token refresh in auth middleware'"
+                        .to_string(),
+                ),
+                line: None,
+            });
+        }
+
+        let length = title.chars().count();
+        if length < rules.min_title_length {
+            issues.push(LintIssue {
+                severity: Severity::Warning,
+                category: IssueCategory::Naming,
+                rule: "short-title".to_string(),
+                message: format!(
+                    "Title is too short ({} characters, minimum {})",
+                    length, rules.min_title_length
+                ),
+                suggestion: Some("Describe the outcome, not just the topic".to_string()),
+                line: None,
+            });
+        } else if length > rules.max_title_length {
+            issues.push(LintIssue {
+                severity: Severity::Info,
+                category: IssueCategory::Naming,
+                rule: "long-title".to_string(),
+                message: format!(
+                    "Title is too long ({} characters, maximum {})",
+                    length, rules.max_title_length
+                ),
+                suggestion: Some("Move details into the task body".to_string()),
+                line: None,
+            });
+        }
+
+        if let Some(verbs) = &rules.verbs
+            && !verbs.iter().any(|verb| {
+                normalized == *verb
+                    || normalized
+                        .strip_prefix(verb.as_str())
+                        .is_some_and(|rest| rest.starts_with(' '))
+            })
+        {
+            let examples: Vec<&str> = verbs.iter().take(5).map(String::as_str).collect();
+            issues.push(LintIssue {
+                severity: Severity::Info,
+                category: IssueCategory::Naming,
+                rule: "imperative-title".to_string(),
+                message: "Title doesn't start with an imperative verb".to_string(),
+                suggestion: Some(format!(
+                    "Start with what to do, e.g. {}",
+                    examples.join(", ")
+                )),
+                line: None,
             });
         }
     }
@@ -409,6 +991,144 @@ impl TaskAnalyzer {
     }
 }
 
+/// List items under an "Acceptance Criteria" heading
+struct AcceptanceCriteria {
+    /// Body lines (1-based) covered by the section, heading included; `None`
+    /// without one
+    section: Option<std::ops::RangeInclusive<usize>>,
+    /// (line, text) of each criterion
+    items: Vec<(usize, String)>,
+}
+
+fn acceptance_criteria(content: &str) -> AcceptanceCriteria {
+    let is_heading = |line: &str| line.trim_start().starts_with('#');
+    let lines: Vec<&str> = content.lines().collect();
+    let Some(start) = lines.iter().position(|line| {
+        is_heading(line)
+            && line
+                .trim_start_matches('#')
+                .trim()
+                .eq_ignore_ascii_case("acceptance criteria")
+    }) else {
+        return AcceptanceCriteria {
+            section: None,
+            items: Vec::new(),
+        };
+    };
+    let end = lines[start + 1..]
+        .iter()
+        .position(|line| is_heading(line))
+        .map_or(lines.len(), |offset| start + 1 + offset);
+
+    let items = (start + 1..end)
+        .filter_map(|i| {
+            let line = lines[i].trim_start();
+            let text = ["- [ ] ", "- [x] ", "- [X] ", "- ", "* ", "+ "]
+                .iter()
+                .find_map(|marker| line.strip_prefix(marker))
+                .or_else(|| {
+                    let (number, rest) = line.split_once(". ")?;
+                    number.parse::<u32>().ok().map(|_| rest)
+                })?
+                .trim();
+            // Template placeholders like "[Testable criterion 1]" aren't criteria yet
+            (!text.is_empty() && !text.starts_with('[')).then(|| (i + 1, text.to_string()))
+        })
+        .collect();
+    AcceptanceCriteria {
+        section: Some(start + 1..=end),
+        items,
+    }
+}
+
+const STOP_WORDS: [&str; 24] = [
+    "about", "after", "also", "been", "before", "both", "does", "done", "each", "from", "have",
+    "into", "longer", "more", "must", "only", "should", "than", "that", "them", "then", "this",
+    "when", "with",
+];
+
+/// Rough word stems for matching criteria to checklist items, so "Tests pass"
+/// matches "Set up testing" and "Reproduction" matches "Reproduce"
+fn keywords(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .map(str::to_lowercase)
+        .filter(|word| word.len() >= 4 && !STOP_WORDS.contains(&word.as_str()))
+        .map(|word| {
+            let stem = ["ing", "ed", "es", "s"]
+                .iter()
+                .find_map(|suffix| word.strip_suffix(suffix).filter(|s| s.len() >= 3))
+                .unwrap_or(&word);
+            stem.chars().take(5).collect()
+        })
+        .collect()
+}
+
+/// Whether `content` has a markdown heading named `section` (any level, case-insensitive)
+fn has_section(content: &str, section: &str) -> bool {
+    content.lines().any(|line| {
+        let line = line.trim();
+        line.starts_with('#')
+            && line
+                .trim_start_matches('#')
+                .trim()
+                .eq_ignore_ascii_case(section.trim())
+    })
+}
+
+/// Estimated vs. logged time across tasks that have both
+#[derive(Debug, Default)]
+pub struct EstimateAccuracy {
+    pub samples: usize,
+    pub estimated_minutes: u32,
+    pub actual_minutes: u32,
+    /// Tasks whose logged time exceeds their estimate
+    pub overruns: usize,
+}
+
+impl EstimateAccuracy {
+    pub fn from_tasks(tasks: &[Task]) -> Self {
+        let mut accuracy = Self::default();
+        for task in tasks {
+            let logged = task.logged_minutes();
+            let Some(estimate) = task.estimate_minutes().filter(|e| *e > 0 && logged > 0) else {
+                continue;
+            };
+            accuracy.add(estimate, logged);
+        }
+        accuracy
+    }
+
+    /// Record one task's estimated and actual minutes
+    pub fn add(&mut self, estimated: u32, actual: u32) {
+        self.samples += 1;
+        self.estimated_minutes += estimated;
+        self.actual_minutes += actual;
+        if actual > estimated {
+            self.overruns += 1;
+        }
+    }
+
+    /// Logged time as a multiple of estimated time
+    pub fn ratio(&self) -> Option<f32> {
+        (self.estimated_minutes > 0)
+            .then(|| self.actual_minutes as f32 / self.estimated_minutes as f32)
+    }
+
+    /// Most tracked tasks overran and the total is well over the estimates
+    pub fn is_chronic_underestimation(&self) -> bool {
+        self.samples >= 3
+            && self.overruns * 2 > self.samples
+            && self.ratio().is_some_and(|r| r > 1.25)
+    }
+
+    /// Most tracked tasks came in under and the total is well below the estimates
+    pub fn is_chronic_overestimation(&self) -> bool {
+        self.samples >= 3
+            && (self.samples - self.overruns) * 2 > self.samples
+            && self.ratio().is_some_and(|r| r < 0.75)
+    }
+}
+
 #[derive(Debug)]
 pub struct AnalysisSummary {
     pub total_tasks: usize,
@@ -0,0 +1,113 @@
+//! On-disk cache of task analyses for `lint`
+//!
+//! Entries are keyed by the task file's path and a hash of its content, so only
+//! changed files are analyzed again. The whole cache is dropped when the lint
+//! settings, the taskguard version or the date changes (overdue checklist
+//! items depend on it).
+
+use anyhow::{Context, Result};
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::analysis::{TaskAnalysis, TaskAnalyzer};
+use crate::config::LintConfig;
+use crate::task::Task;
+
+/// Cache file, relative to the project root
+pub const CACHE_FILE: &str = ".taskguard/cache/lint.json";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct AnalysisCache {
+    fingerprint: String,
+    /// Task file path relative to the project root → cached analysis
+    entries: HashMap<String, CacheEntry>,
+    /// Analyses served from the cache in this run
+    #[serde(skip)]
+    pub hits: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    hash: String,
+    analysis: TaskAnalysis,
+}
+
+impl AnalysisCache {
+    /// Load the cache, starting empty when it's missing, unreadable or stale
+    pub fn load(root: &Path, lint: Option<&LintConfig>) -> Self {
+        let fingerprint = fingerprint(lint);
+        fs::read_to_string(root.join(CACHE_FILE))
+            .ok()
+            .and_then(|content| serde_json::from_str::<Self>(&content).ok())
+            .filter(|cache| cache.fingerprint == fingerprint)
+            .unwrap_or_else(|| Self {
+                fingerprint,
+                ..Self::default()
+            })
+    }
+
+    /// Cached analysis when the file is unchanged, otherwise analyze and store it
+    pub fn analyze(&mut self, analyzer: &TaskAnalyzer, task: &Task, root: &Path) -> TaskAnalysis {
+        let Ok(content) = fs::read(&task.file_path) else {
+            return analyzer.analyze_task(task);
+        };
+        let key = task
+            .file_path
+            .strip_prefix(root)
+            .unwrap_or(&task.file_path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        let hash = content_hash(&content);
+
+        if let Some(entry) = self.entries.get(&key).filter(|e| e.hash == hash) {
+            self.hits += 1;
+            return entry.analysis.clone();
+        }
+        let analysis = analyzer.analyze_task(task);
+        self.entries.insert(
+            key,
+            CacheEntry {
+                hash,
+                analysis: analysis.clone(),
+            },
+        );
+        analysis
+    }
+
+    /// Write the cache, dropping entries for files that no longer exist
+    pub fn save(&mut self, root: &Path) -> Result<()> {
+        self.entries.retain(|key, _| root.join(key).exists());
+        let path = root.join(CACHE_FILE);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+        fs::write(&path, serde_json::to_string(self)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+/// Everything besides the file itself that an analysis depends on
+fn fingerprint(lint: Option<&LintConfig>) -> String {
+    let settings = serde_json::to_string(&lint).unwrap_or_default();
+    content_hash(
+        format!(
+            "{}|{}|{}",
+            env!("CARGO_PKG_VERSION"),
+            Local::now().date_naive(),
+            settings
+        )
+        .as_bytes(),
+    )
+}
+
+/// 64-bit FNV-1a, stable across Rust releases unlike `DefaultHasher`
+fn content_hash(bytes: &[u8]) -> String {
+    let hash = bytes.iter().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{:016x}", hash)
+}
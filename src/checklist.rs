@@ -0,0 +1,212 @@
+//! Checklist items in task bodies
+//!
+//! Items are markdown task-list lines (`- [ ] text` / `- [x] text`) and may end
+//! with metadata suffixes: `@alice` for an assignee and `due:2024-07-01` for a
+//! due date. Indented items nest under the item above them; a parent item is
+//! complete when all of its children are.
+
+use anyhow::Result;
+use chrono::NaiveDate;
+use regex::Regex;
+use std::sync::LazyLock;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChecklistItem {
+    /// Item text without metadata suffixes
+    pub text: String,
+    /// Checkbox state; for parent items this is derived from the children
+    pub completed: bool,
+    pub assignee: Option<String>,
+    pub due: Option<NaiveDate>,
+    /// 1-based line number within the task body
+    pub line_number: usize,
+    /// Position in the hierarchy, e.g. `[2, 3]` for item 2.3
+    pub path: Vec<usize>,
+    pub child_count: usize,
+    indent: String,
+    /// Checkbox as written in the file
+    checked: bool,
+}
+
+impl ChecklistItem {
+    /// Hierarchical index as shown to users, e.g. "2.3"
+    pub fn label(&self) -> String {
+        self.path
+            .iter()
+            .map(usize::to_string)
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    /// Nesting level, 0 for top-level items
+    pub fn depth(&self) -> usize {
+        self.path.len() - 1
+    }
+
+    pub fn has_children(&self) -> bool {
+        self.child_count > 0
+    }
+
+    /// Open item whose due date has passed
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        !self.completed && self.due.is_some_and(|due| due < today)
+    }
+
+    /// Metadata rendered for display, e.g. "@alice, due 2024-07-01"
+    pub fn metadata_display(&self) -> Option<String> {
+        let mut parts = Vec::new();
+        if let Some(assignee) = &self.assignee {
+            parts.push(format!("@{}", assignee));
+        }
+        if let Some(due) = self.due {
+            parts.push(format!("due {}", due));
+        }
+        (!parts.is_empty()).then(|| parts.join(", "))
+    }
+
+    fn to_line(&self) -> String {
+        let checkbox = if self.completed { "[x]" } else { "[ ]" };
+        let assignee = self
+            .assignee
+            .as_ref()
+            .map(|a| format!(" @{}", a))
+            .unwrap_or_default();
+        let due = self.due.map(|d| format!(" due:{}", d)).unwrap_or_default();
+        format!(
+            "{}- {} {}{}{}",
+            self.indent, checkbox, self.text, assignee, due
+        )
+    }
+}
+
+static CHECKBOX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(\s*)-\s*\[([xX\s])\]\s*(.+)$").expect("valid checkbox regex"));
+
+fn indent_width(indent: &str) -> usize {
+    indent.chars().map(|c| if c == '\t' { 4 } else { 1 }).sum()
+}
+
+/// Parse all checklist items from a task body, in document order
+pub fn parse_items(content: &str) -> Vec<ChecklistItem> {
+    let mut items: Vec<ChecklistItem> = Vec::new();
+    // (indent width, item position) of the items the next line may nest under
+    let mut parents: Vec<(usize, usize)> = Vec::new();
+    let mut top_level = 0;
+
+    for (line_index, line) in content.lines().enumerate() {
+        let Some(captures) = CHECKBOX.captures(line) else {
+            continue;
+        };
+        let width = indent_width(&captures[1]);
+        while parents.last().is_some_and(|(w, _)| *w >= width) {
+            parents.pop();
+        }
+
+        let path = match parents.last() {
+            Some(&(_, parent)) => {
+                items[parent].child_count += 1;
+                let mut path = items[parent].path.clone();
+                path.push(items[parent].child_count);
+                path
+            }
+            None => {
+                top_level += 1;
+                vec![top_level]
+            }
+        };
+
+        let checked = captures[2].eq_ignore_ascii_case("x");
+        let (text, assignee, due) = split_metadata(captures[3].trim());
+        parents.push((width, items.len()));
+        items.push(ChecklistItem {
+            text,
+            completed: checked,
+            assignee,
+            due,
+            line_number: line_index + 1,
+            path,
+            child_count: 0,
+            indent: captures[1].to_string(),
+            checked,
+        });
+    }
+
+    // Children always follow their parent, so a reverse pass settles nested parents first
+    for i in (0..items.len()).rev() {
+        if items[i].has_children() {
+            let depth = items[i].path.len();
+            items[i].completed = items[i + 1..]
+                .iter()
+                .take_while(|item| item.path.len() > depth)
+                .filter(|item| item.path.len() == depth + 1)
+                .all(|item| item.completed);
+        }
+    }
+
+    items
+}
+
+/// Position of the item with the given label ("3" or "2.3")
+pub fn find_item(items: &[ChecklistItem], label: &str) -> Option<usize> {
+    items.iter().position(|item| item.label() == label)
+}
+
+/// Items without children; these are the units of work counted for progress
+pub fn leaf_items(items: &[ChecklistItem]) -> impl Iterator<Item = &ChecklistItem> {
+    items.iter().filter(|item| !item.has_children())
+}
+
+/// Separate trailing `@assignee` and `due:YYYY-MM-DD` tokens from the item text
+fn split_metadata(text: &str) -> (String, Option<String>, Option<NaiveDate>) {
+    let mut words: Vec<&str> = text.split_whitespace().collect();
+    let mut assignee = None;
+    let mut due = None;
+
+    while words.len() > 1 {
+        let last = words[words.len() - 1];
+        if let Some(name) = last.strip_prefix('@').filter(|n| !n.is_empty()) {
+            assignee.get_or_insert_with(|| name.to_string());
+        } else if let Some(date) = last
+            .strip_prefix("due:")
+            .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+        {
+            due.get_or_insert(date);
+        } else {
+            break;
+        }
+        words.pop();
+    }
+
+    (words.join(" "), assignee, due)
+}
+
+/// Rewrite one item (0-based position) in place, then bring every parent
+/// checkbox in the body in line with its children, not only the edited item's
+/// ancestors. Parents already in line and non-parent lines are left untouched.
+pub fn update_item(
+    content: &str,
+    item_index: usize,
+    update: impl FnOnce(&mut ChecklistItem),
+) -> Result<String> {
+    let mut item = parse_items(content)
+        .into_iter()
+        .nth(item_index)
+        .ok_or_else(|| anyhow::anyhow!("Checklist item {} not found", item_index + 1))?;
+    update(&mut item);
+
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    lines[item.line_number - 1] = item.to_line();
+
+    let updated = lines.join("\n");
+    for item in parse_items(&updated) {
+        if item.completed != item.checked {
+            lines[item.line_number - 1] = item.to_line();
+        }
+    }
+
+    let mut updated = lines.join("\n");
+    if content.ends_with('\n') {
+        updated.push('\n');
+    }
+    Ok(updated)
+}
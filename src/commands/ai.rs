@@ -1,5 +1,5 @@
 use crate::analysis::TaskAnalyzer;
-use crate::config::load_all_tasks;
+use crate::config::{Config, get_config_path, load_all_tasks};
 use crate::git::GitAnalyzer;
 use crate::task::{Priority, Task, TaskStatus};
 use anyhow::Result;
@@ -22,7 +22,11 @@ impl AIAgent {
     pub fn new() -> Result<Self> {
         let git_analyzer = GitAnalyzer::new(Path::new(".")).ok();
 
-        let task_analyzer = TaskAnalyzer::new();
+        let config = get_config_path()
+            .ok()
+            .and_then(|path| Config::load_or_default(path).ok());
+        let task_analyzer =
+            TaskAnalyzer::from_config(config.as_ref().and_then(|c| c.lint.as_ref()));
 
         Ok(Self {
             git_analyzer,
@@ -1,14 +1,16 @@
 use anyhow::{Context, Result};
+use chrono::{Duration, Utc};
 use git2::Repository;
 use std::fs;
 use std::path::Path;
 use walkdir::WalkDir;
 
 use crate::config::{find_taskguard_root, get_tasks_dir, load_tasks_from_dir};
+use crate::git::task_trailers;
 use crate::github::{GitHubClient, GitHubMutations, TaskIssueMapper, is_github_sync_enabled};
 use crate::task::{Task, TaskStatus};
 
-pub fn run(dry_run: bool, _days: Option<u32>) -> Result<()> {
+pub fn run(dry_run: bool, days: Option<u32>) -> Result<()> {
     let tasks_dir = get_tasks_dir()?;
     let root = find_taskguard_root().context("Not in a TaskGuard project")?;
     let archive_dir = root.join(".taskguard").join("archive");
@@ -20,6 +22,9 @@ pub fn run(dry_run: bool, _days: Option<u32>) -> Result<()> {
 
     println!("📦 TaskGuard Archive - Efficiency Optimization");
     println!("   Action: Archive completed tasks (with dependency protection)");
+    if let Some(days) = days {
+        println!("   Age: completed at least {} days ago", days);
+    }
     if dry_run {
         println!("   Mode: DRY RUN (no files will be moved)");
     }
@@ -36,7 +41,9 @@ pub fn run(dry_run: bool, _days: Option<u32>) -> Result<()> {
     // Load ALL tasks to check dependencies
     let all_tasks = load_tasks_from_dir(&tasks_dir)?;
 
-    // Find ALL completed tasks (no age filtering)
+    // Find completed tasks, keeping ones finished within the retention window
+    let cutoff = days.map(|days| Utc::now() - Duration::days(i64::from(days)));
+    let mut recently_completed = 0;
     let mut files_to_archive = Vec::new();
     let mut blocked_from_archive = Vec::new();
     let mut github_issues_to_close = Vec::new();
@@ -53,6 +60,13 @@ pub fn run(dry_run: bool, _days: Option<u32>) -> Result<()> {
         match Task::from_file(path) {
             Ok(task) => {
                 if task.status == TaskStatus::Done {
+                    if cutoff
+                        .is_some_and(|cutoff| task.completed_at().is_some_and(|at| at > cutoff))
+                    {
+                        recently_completed += 1;
+                        continue;
+                    }
+
                     // Check if any active task depends on this
                     let dependents = find_dependents(&task.id, &all_tasks);
                     if !dependents.is_empty() {
@@ -88,6 +102,15 @@ pub fn run(dry_run: bool, _days: Option<u32>) -> Result<()> {
         }
     }
 
+    if recently_completed > 0 {
+        println!(
+            "⏳ Keeping {} task(s) completed in the last {} days",
+            recently_completed,
+            days.unwrap_or_default()
+        );
+        println!();
+    }
+
     // Show blocked tasks first
     if !blocked_from_archive.is_empty() {
         println!("🚫 BLOCKED FROM ARCHIVE (causality protection):");
@@ -155,20 +178,24 @@ pub fn run(dry_run: bool, _days: Option<u32>) -> Result<()> {
     let mut archived_task_ids = Vec::new();
 
     for (path, area, id, _) in files_to_archive {
-        let area_archive_dir = archive_dir.join(&area);
-        fs::create_dir_all(&area_archive_dir)?;
-
-        let archive_path = area_archive_dir.join(path.file_name().unwrap());
+        // Mirror the file's place under tasks/ so nested areas keep their directories
+        let relative = path.strip_prefix(&tasks_dir).map_or_else(
+            |_| Path::new(&area).join(path.file_name().unwrap()),
+            Path::to_path_buf,
+        );
+        let archive_path = archive_dir.join(&relative);
+        if let Some(parent) = archive_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
 
         match fs::rename(&path, &archive_path) {
             Ok(_) => {
                 archived_count += 1;
                 archived_task_ids.push(id.clone());
                 println!(
-                    "   ✅ Archived: {} → archive/{}/{}",
+                    "   ✅ Archived: {} → archive/{}",
                     id,
-                    area,
-                    path.file_name().unwrap().to_string_lossy()
+                    relative.to_string_lossy().replace('\\', "/")
                 );
             }
             Err(e) => {
@@ -309,7 +336,11 @@ fn create_archive_commit(repo_path: &Path, task_ids: &[String]) -> Result<()> {
 
     // Create commit message with task IDs
     let task_list = task_ids.join(", ");
-    let commit_message = format!("Archive completed tasks: {}", task_list);
+    let commit_message = format!(
+        "taskguard: archive {}\n\n{}",
+        task_list,
+        task_trailers(task_ids)
+    );
 
     // Create the commit
     repo.commit(
@@ -323,7 +354,10 @@ fn create_archive_commit(repo_path: &Path, task_ids: &[String]) -> Result<()> {
     .context("Failed to create commit")?;
 
     println!("\n📝 Git commit created:");
-    println!("   Message: {}", commit_message);
+    println!(
+        "   Message: {}",
+        commit_message.lines().next().unwrap_or_default()
+    );
 
     Ok(())
 }
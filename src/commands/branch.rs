@@ -0,0 +1,121 @@
+use anyhow::{Context, Result};
+use git2::build::CheckoutBuilder;
+use git2::{Branch, BranchType, Repository};
+
+use crate::config::{Config, find_taskguard_root, get_config_path, load_all_tasks};
+use crate::task::Task;
+
+/// Longest slug taken from a title, in characters
+const MAX_SLUG_LEN: usize = 40;
+
+/// Lowercase words of `title` joined by `-`, cut at a word boundary
+pub fn slug(title: &str) -> String {
+    let mut slug = String::new();
+    for word in title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+    {
+        let word = word.to_lowercase();
+        if !slug.is_empty() && slug.len() + 1 + word.len() > MAX_SLUG_LEN {
+            break;
+        }
+        if !slug.is_empty() {
+            slug.push('-');
+        }
+        slug.push_str(&word);
+    }
+    slug
+}
+
+/// Branch name for a task from a `[git] branch_pattern`
+pub fn branch_name(pattern: &str, task: &Task) -> String {
+    pattern
+        .replace("{area}", &task.area)
+        .replace("{id}", &task.id)
+        .replace("{slug}", &slug(&task.title))
+        .trim_end_matches(['-', '/'])
+        .to_string()
+}
+
+/// The task's recorded branch, or a new name from `[git] branch_pattern`
+pub fn task_branch(task: &Task, config: &Config) -> Result<String> {
+    let name = task
+        .branch
+        .clone()
+        .unwrap_or_else(|| branch_name(&config.git.branch_pattern, task));
+    if !Branch::name_is_valid(&name)? {
+        return Err(anyhow::anyhow!(
+            "'{}' is not a valid branch name; check [git] branch_pattern",
+            name
+        ));
+    }
+    Ok(name)
+}
+
+/// Save `name` as the task's `branch:` unless it's already recorded
+pub fn record_branch(task: &Task, name: &str) -> Result<()> {
+    if task.branch.as_deref() == Some(name) {
+        return Ok(());
+    }
+    let mut task = task.clone();
+    task.branch = Some(name.to_string());
+    task.save_to_file(&task.file_path)
+        .with_context(|| format!("Failed to update {}", task.file_path.display()))?;
+    println!(
+        "   Recorded 'branch: {}' in {}",
+        name,
+        task.file_path.display()
+    );
+    println!("   Commit the task file so 'taskguard sync --github' links the issue to this branch");
+    Ok(())
+}
+
+/// Create and check out the task's branch, or switch to it if it exists, and
+/// record its name in the task's frontmatter
+pub fn run(task_id: &str) -> Result<()> {
+    let tasks = load_all_tasks().context("Failed to load tasks")?;
+    let task = tasks
+        .iter()
+        .find(|t| t.id == task_id)
+        .ok_or_else(|| anyhow::anyhow!("Task '{}' not found", task_id))?;
+
+    let root =
+        find_taskguard_root().context("Not in a TaskGuard project. Run 'taskguard init' first.")?;
+    let repo =
+        Repository::discover(&root).context("Not in a Git repository. Run 'git init' first.")?;
+    let config = Config::load_or_default(get_config_path()?)?;
+
+    let name = task_branch(task, &config)?;
+
+    let current = repo
+        .head()
+        .ok()
+        .and_then(|head| head.shorthand().map(str::to_string));
+    if current.as_deref() == Some(name.as_str()) {
+        println!("✅ Already on {}", name);
+    } else if let Ok(branch) = repo.find_branch(&name, BranchType::Local) {
+        let reference = branch.get();
+        let commit = reference.peel_to_commit()?;
+        repo.checkout_tree(commit.as_object(), Some(CheckoutBuilder::new().safe()))
+            .with_context(|| {
+                format!(
+                    "Failed to check out {}; commit or stash your changes first",
+                    name
+                )
+            })?;
+        repo.set_head(reference.name().unwrap_or_default())?;
+        println!("🔀 Switched to existing branch {}", name);
+    } else {
+        let head = repo
+            .head()
+            .and_then(|head| head.peel_to_commit())
+            .context("The repository has no commits yet; commit once before creating branches")?;
+        let branch = repo
+            .branch(&name, &head, false)
+            .with_context(|| format!("Failed to create branch {}", name))?;
+        repo.set_head(branch.get().name().unwrap_or_default())?;
+        println!("🌿 Created and switched to {}", name);
+    }
+
+    record_branch(task, &name)
+}
@@ -0,0 +1,72 @@
+use anyhow::Result;
+use chrono::Utc;
+
+use crate::commands::comment::current_author;
+use crate::commands::update::find_task_file;
+use crate::config::get_tasks_dir;
+use crate::task::{Task, TaskStatus};
+
+/// Who holds a claim on the task, if anyone
+pub fn claim_holder(task: &Task) -> Option<String> {
+    task.claimed
+        .map(|_| task.assignees.join(", "))
+        .filter(|holder| !holder.is_empty())
+}
+
+/// Assign a task to the current git user and record when it was claimed
+///
+/// Claims are advisory: `taskguard validate` warns when someone else changes a
+/// claimed task, nothing is locked.
+pub fn run(task_id: &str, release: bool, force: bool) -> Result<()> {
+    let tasks_dir = get_tasks_dir()?;
+    let task_file_path = find_task_file(&tasks_dir, task_id)?;
+    let mut task = Task::from_file(&task_file_path)?;
+    let user = current_author();
+    let held_by_other = claim_holder(&task).filter(|_| !task.assignees.contains(&user));
+
+    if release {
+        if task.claimed.is_none() {
+            return Err(anyhow::anyhow!("Task {} is not claimed", task.id));
+        }
+        if let Some(holder) = held_by_other.filter(|_| !force) {
+            return Err(anyhow::anyhow!(
+                "Task {} is claimed by {}. Use --force to release it anyway",
+                task.id,
+                holder
+            ));
+        }
+
+        task.claimed = None;
+        task.assignees.retain(|a| *a != user);
+        task.save_to_file(&task_file_path)?;
+        println!("🔓 Released claim on {}", task.id);
+        return Ok(());
+    }
+
+    if task.status == TaskStatus::Done {
+        return Err(anyhow::anyhow!("Task {} is already done", task.id));
+    }
+    if let Some(holder) = &held_by_other {
+        if !force {
+            let since = task
+                .claimed
+                .map(|at| at.format("%Y-%m-%d %H:%M UTC").to_string())
+                .unwrap_or_default();
+            return Err(anyhow::anyhow!(
+                "Task {} is claimed by {} since {}. Coordinate with them, or use --force to take it over",
+                task.id,
+                holder,
+                since
+            ));
+        }
+        println!("⚠️  Taking over {} from {}", task.id, holder);
+    }
+
+    task.assignees = vec![user.clone()];
+    task.claimed = Some(Utc::now());
+    task.save_to_file(&task_file_path)?;
+
+    println!("🔒 Claimed {} for {}", task.id, user);
+    println!("   Commit the task file so teammates see the claim");
+    Ok(())
+}
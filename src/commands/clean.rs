@@ -1,4 +1,5 @@
 use anyhow::Result;
+use chrono::{Duration, Utc};
 use std::fs;
 use walkdir::WalkDir;
 
@@ -6,7 +7,7 @@ use crate::config::{get_tasks_dir, load_tasks_from_dir};
 use crate::github::{TaskIssueMapper, is_github_sync_enabled};
 use crate::task::{Task, TaskStatus};
 
-pub fn run(dry_run: bool, _days: Option<u32>) -> Result<()> {
+pub fn run(dry_run: bool, days: Option<u32>) -> Result<()> {
     let tasks_dir = get_tasks_dir()?;
 
     if !tasks_dir.exists() {
@@ -15,7 +16,13 @@ pub fn run(dry_run: bool, _days: Option<u32>) -> Result<()> {
     }
 
     println!("🧹 TaskGuard Clean - Efficiency Optimization");
-    println!("   Action: Delete ALL completed tasks");
+    match days {
+        Some(days) => println!(
+            "   Action: Delete tasks completed at least {} days ago",
+            days
+        ),
+        None => println!("   Action: Delete ALL completed tasks"),
+    }
     if dry_run {
         println!("   Mode: DRY RUN (no files will be deleted)");
     }
@@ -25,6 +32,8 @@ pub fn run(dry_run: bool, _days: Option<u32>) -> Result<()> {
     let all_tasks = load_tasks_from_dir(&tasks_dir)?;
 
     // Find completed tasks that are SAFE to delete
+    let cutoff = days.map(|days| Utc::now() - Duration::days(i64::from(days)));
+    let mut recently_completed = 0;
     let mut files_to_delete = Vec::new();
     let mut protected_tasks = Vec::new();
     let mut total_size: u64 = 0;
@@ -39,8 +48,15 @@ pub fn run(dry_run: bool, _days: Option<u32>) -> Result<()> {
 
         match Task::from_file(path) {
             Ok(task) => {
-                // Check if task is completed (no age check)
+                // Check if task was completed before the retention window
                 if task.status == TaskStatus::Done {
+                    if cutoff
+                        .is_some_and(|cutoff| task.completed_at().is_some_and(|at| at > cutoff))
+                    {
+                        recently_completed += 1;
+                        continue;
+                    }
+
                     // CRITICAL: Check if any active task depends on this
                     if is_task_referenced(&task.id, &all_tasks) {
                         protected_tasks.push((task.id.clone(), task.title.clone()));
@@ -127,6 +143,15 @@ pub fn run(dry_run: bool, _days: Option<u32>) -> Result<()> {
         }
     }
 
+    if recently_completed > 0 {
+        println!(
+            "⏳ Keeping {} task(s) completed in the last {} days",
+            recently_completed,
+            days.unwrap_or_default()
+        );
+        println!();
+    }
+
     // Show protected tasks
     if !protected_tasks.is_empty() {
         println!("🔒 PROTECTED TASKS (cannot delete - still referenced):");
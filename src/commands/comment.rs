@@ -0,0 +1,41 @@
+use anyhow::Result;
+
+use crate::commands::update::find_task_file;
+use crate::config::{find_taskguard_root, get_tasks_dir};
+use crate::git::config_user_name;
+use crate::task::Task;
+use crate::worklog::{self, LogEntry};
+
+/// Author for new log entries: git `user.name`, then the login name
+pub(crate) fn current_author() -> String {
+    let root = find_taskguard_root().unwrap_or_else(|| ".".into());
+    config_user_name(root)
+        .or_else(|| std::env::var("USER").ok())
+        .or_else(|| std::env::var("USERNAME").ok())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Append a timestamped note to the task's `## Log` section
+pub fn run(task_id: &str, message: &str) -> Result<()> {
+    if message.trim().is_empty() {
+        return Err(anyhow::anyhow!("Comment text cannot be empty"));
+    }
+
+    let tasks_dir = get_tasks_dir()?;
+    let task_file_path = find_task_file(&tasks_dir, task_id)?;
+    let mut task = Task::from_file(&task_file_path)?;
+
+    let entry = LogEntry::new(&current_author(), message);
+    task.content = worklog::append_entry(&task.content, &entry);
+    task.save_to_file(&task_file_path)?;
+
+    println!(
+        "💬 Logged on {} by {}: {}",
+        task.id, entry.author, entry.text
+    );
+    println!(
+        "   {} log entries; 'taskguard sync --github' posts new ones as issue comments",
+        worklog::parse_entries(&task.content).len()
+    );
+    Ok(())
+}
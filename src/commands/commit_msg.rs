@@ -0,0 +1,106 @@
+use anyhow::{Context, Result};
+use git2::Repository;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use crate::checklist;
+use crate::config::{find_taskguard_root, load_all_tasks};
+use crate::git::{mentions_task_id, task_trailers};
+use crate::task::{Task, TaskStatus};
+
+/// Commit message template for a task: an empty subject line, the `Task-Id`
+/// trailer, and the task's title and open checklist items as comments
+pub fn template(task: &Task) -> String {
+    let mut message = format!("\n\n{}\n", task_trailers(std::slice::from_ref(&task.id)));
+    let _ = writeln!(message, "# {}: {} ({})", task.id, task.title, task.status);
+
+    let items = checklist::parse_items(&task.content);
+    let remaining: Vec<_> = checklist::leaf_items(&items)
+        .filter(|item| !item.completed)
+        .collect();
+    if !remaining.is_empty() {
+        message.push_str("# Remaining checklist items:\n");
+        for item in remaining {
+            let _ = writeln!(message, "#   [ ] {}. {}", item.label(), item.text);
+        }
+    }
+    message.push_str("# The Task-Id trailer links this commit to the task for 'taskguard sync'\n");
+    message
+}
+
+/// Print the template for `task_id`, or the task of the current branch or the
+/// only task in progress. With `file`, prepend it to that commit message file
+/// instead (for a prepare-commit-msg hook), unless the message already names the task.
+pub fn run(task_id: Option<&str>, file: Option<&Path>) -> Result<()> {
+    let tasks = load_all_tasks().context("Failed to load tasks")?;
+    let task = match task_id {
+        Some(id) => tasks
+            .iter()
+            .find(|t| t.id == id)
+            .ok_or_else(|| anyhow::anyhow!("Task '{}' not found", id))?,
+        None => active_task(&tasks)?,
+    };
+
+    let Some(file) = file else {
+        print!("{}", template(task));
+        return Ok(());
+    };
+
+    let existing = fs::read_to_string(file)
+        .with_context(|| format!("Failed to read commit message: {}", file.display()))?;
+    if mentions_task_id(&existing, &task.id) {
+        return Ok(());
+    }
+    fs::write(file, format!("{}{}", template(task), existing))
+        .with_context(|| format!("Failed to write commit message: {}", file.display()))
+}
+
+/// Task recorded for or named by the current branch, else the only task in `doing`
+fn active_task(tasks: &[Task]) -> Result<&Task> {
+    let branch = find_taskguard_root()
+        .and_then(|root| Repository::discover(root).ok())
+        .and_then(|repo| {
+            repo.head()
+                .ok()
+                .and_then(|head| head.shorthand().map(str::to_string))
+        });
+    if let Some(branch) = branch
+        && let Some(task) = tasks
+            .iter()
+            .filter(|t| t.branch.as_deref() == Some(branch.as_str()) || names_task(&branch, &t.id))
+            .max_by_key(|t| t.id.len())
+    {
+        return Ok(task);
+    }
+
+    let doing: Vec<&Task> = tasks
+        .iter()
+        .filter(|t| t.status == TaskStatus::Doing)
+        .collect();
+    match doing.as_slice() {
+        [task] => Ok(task),
+        [] => Err(anyhow::anyhow!(
+            "No active task: pass a task ID, work on a branch named after one, or set one to doing"
+        )),
+        _ => Err(anyhow::anyhow!(
+            "{} tasks are in progress ({}); pass a task ID",
+            doing.len(),
+            doing
+                .iter()
+                .map(|t| t.id.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+/// Whether a branch name contains `task_id` between non-alphanumeric characters,
+/// as in `task/backend-001-login`
+fn names_task(branch: &str, task_id: &str) -> bool {
+    branch.match_indices(task_id).any(|(start, _)| {
+        let before = branch[..start].chars().next_back();
+        let after = branch[start + task_id.len()..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
@@ -3,7 +3,7 @@ use std::fs;
 use walkdir::WalkDir;
 
 use crate::config::get_tasks_dir;
-use crate::task::Task;
+use crate::task::{FrontmatterFormat, Task};
 
 pub fn run(dry_run: bool) -> Result<()> {
     let tasks_dir = get_tasks_dir()?;
@@ -111,21 +111,25 @@ pub fn run(dry_run: bool) -> Result<()> {
 }
 
 fn compact_content(content: &str) -> Result<String> {
-    // Split into YAML and markdown
-    let parts: Vec<&str> = content.splitn(3, "---").collect();
+    // Split into front-matter (YAML or TOML) and markdown
+    let delimiter = FrontmatterFormat::detect(content).delimiter();
+    let parts: Vec<&str> = content.splitn(3, delimiter).collect();
 
     if parts.len() < 3 {
         // No YAML front-matter, just compact markdown
         return Ok(compact_markdown(content));
     }
 
-    let yaml = parts[1].trim();
+    let frontmatter = parts[1].trim();
     let markdown = parts[2];
 
-    // Compact markdown (keep YAML as-is for structure)
+    // Compact markdown (keep front-matter as-is for structure)
     let compacted_markdown = compact_markdown(markdown);
 
-    Ok(format!("---\n{}\n---\n\n{}", yaml, compacted_markdown))
+    Ok(format!(
+        "{}\n{}\n{}\n\n{}",
+        delimiter, frontmatter, delimiter, compacted_markdown
+    ))
 }
 
 fn compact_markdown(text: &str) -> String {
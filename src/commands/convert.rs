@@ -0,0 +1,112 @@
+use anyhow::{Context, Result};
+use std::fs;
+use walkdir::WalkDir;
+
+use crate::config::{Config, get_archive_dir, get_config_path, get_tasks_dir};
+use crate::task::{FrontmatterFormat, Task};
+
+/// Rewrite task front-matter between YAML (`---`) and TOML (`+++`)
+///
+/// Converts active and archived tasks, then makes the format the project
+/// default for new task files.
+pub fn run(format: &str, dry_run: bool) -> Result<()> {
+    let format = match format.trim().to_lowercase().as_str() {
+        "yaml" | "yml" => FrontmatterFormat::Yaml,
+        "toml" => FrontmatterFormat::Toml,
+        other => {
+            return Err(anyhow::anyhow!(
+                "Unknown format '{}'. Valid formats: yaml, toml",
+                other
+            ));
+        }
+    };
+
+    let tasks_dir = get_tasks_dir()?;
+    let archive_dir = get_archive_dir()?;
+
+    println!("🔁 Converting task front-matter to {}", format);
+    if dry_run {
+        println!("   Mode: DRY RUN (no files will be modified)");
+    }
+    println!();
+
+    let mut converted = 0;
+    let mut unchanged = 0;
+    let mut failures = Vec::new();
+
+    for entry in [&tasks_dir, &archive_dir]
+        .into_iter()
+        .filter(|dir| dir.exists())
+        .flat_map(WalkDir::new)
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_file())
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "md"))
+    {
+        let path = entry.path();
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read task file: {}", path.display()))?;
+
+        if FrontmatterFormat::detect(&content) == format {
+            unchanged += 1;
+            continue;
+        }
+
+        let task = match Task::parse_content(&content) {
+            Ok(task) => task,
+            Err(e) => {
+                failures.push(format!("{}: {}", path.display(), e));
+                continue;
+            }
+        };
+
+        let converted_content = match task.to_file_content_as(format) {
+            Ok(content) => content,
+            Err(e) => {
+                failures.push(format!("{}: {:#}", path.display(), e));
+                continue;
+            }
+        };
+        if !dry_run && let Err(e) = fs::write(path, converted_content) {
+            failures.push(format!(
+                "{}: Failed to write task file: {}",
+                path.display(),
+                e
+            ));
+            continue;
+        }
+        println!("   ✅ {}", task.id);
+        converted += 1;
+    }
+
+    if !dry_run {
+        let config_path = get_config_path()?;
+        let mut config = Config::load_or_default(&config_path)?;
+        if config.settings.frontmatter != format {
+            config.settings.frontmatter = format;
+            config.save(&config_path)?;
+            println!("   ⚙️  New tasks will use {} front-matter", format);
+        }
+    }
+
+    if !failures.is_empty() {
+        println!();
+        println!("❌ Could not convert (fix with 'taskguard validate'):");
+        for failure in &failures {
+            println!("   {}", failure);
+        }
+    }
+
+    println!();
+    println!(
+        "📊 {} converted, {} already {}, {} failed",
+        converted,
+        unchanged,
+        format,
+        failures.len()
+    );
+    if dry_run && converted > 0 {
+        println!("   Run without --dry-run to convert");
+    }
+
+    Ok(())
+}
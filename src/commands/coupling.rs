@@ -0,0 +1,215 @@
+use anyhow::{Context, Result};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use crate::config::load_all_tasks;
+use crate::task::Task;
+
+/// Dependencies from one area onto another
+#[derive(Debug, Clone, PartialEq)]
+pub struct AreaCoupling {
+    pub from: String,
+    pub to: String,
+    /// Tasks in `from` that depend on at least one task in `to`
+    pub tasks: usize,
+    /// Dependency edges from `from` onto `to`
+    pub edges: usize,
+    /// `tasks` as a share of all tasks in `from`
+    pub share: f32,
+}
+
+#[derive(Debug, Default)]
+pub struct CouplingReport {
+    /// Task count per area
+    pub areas: BTreeMap<String, usize>,
+    /// Cross-area pairs with at least one edge, most coupled first
+    pub pairs: Vec<AreaCoupling>,
+    /// Edges between tasks of the same area
+    pub internal_edges: usize,
+}
+
+impl CouplingReport {
+    pub fn pair(&self, from: &str, to: &str) -> Option<&AreaCoupling> {
+        self.pairs.iter().find(|p| p.from == from && p.to == to)
+    }
+
+    pub fn cross_edges(&self) -> usize {
+        self.pairs.iter().map(|p| p.edges).sum()
+    }
+
+    /// Graphviz digraph of the areas, edges labeled with task counts and shares
+    pub fn to_dot(&self, threshold: f32) -> String {
+        let mut dot = String::from("digraph coupling {\n    rankdir=LR;\n    node [shape=box];\n");
+        for (area, count) in &self.areas {
+            let _ = writeln!(
+                dot,
+                "    \"{}\" [label=\"{}\\n{} tasks\"];",
+                area, area, count
+            );
+        }
+        for pair in &self.pairs {
+            let _ = writeln!(
+                dot,
+                "    \"{}\" -> \"{}\" [label=\"{} ({:.0}%)\", penwidth={:.1}{}];",
+                pair.from,
+                pair.to,
+                pair.tasks,
+                pair.share * 100.0,
+                1.0 + pair.share * 4.0,
+                if pair.share >= threshold {
+                    ", color=red"
+                } else {
+                    ""
+                }
+            );
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Dependency edges crossing area boundaries; dependencies on missing tasks are skipped
+pub fn coupling(tasks: &[Task]) -> CouplingReport {
+    let area_of: HashMap<&str, &str> = tasks
+        .iter()
+        .map(|t| (t.id.as_str(), t.area.as_str()))
+        .collect();
+
+    let mut report = CouplingReport::default();
+    for task in tasks {
+        *report.areas.entry(task.area.clone()).or_default() += 1;
+    }
+
+    // (from, to) → (tasks, edges)
+    let mut counts: BTreeMap<(&str, &str), (usize, usize)> = BTreeMap::new();
+    for task in tasks {
+        let mut targets = BTreeSet::new();
+        for dep in &task.dependencies {
+            let Some(&area) = area_of.get(dep.as_str()) else {
+                continue;
+            };
+            if area == task.area {
+                report.internal_edges += 1;
+                continue;
+            }
+            counts.entry((task.area.as_str(), area)).or_default().1 += 1;
+            targets.insert(area);
+        }
+        for area in targets {
+            counts.entry((task.area.as_str(), area)).or_default().0 += 1;
+        }
+    }
+
+    report.pairs = counts
+        .into_iter()
+        .map(|((from, to), (tasks, edges))| AreaCoupling {
+            from: from.to_string(),
+            to: to.to_string(),
+            tasks,
+            edges,
+            share: tasks as f32 / report.areas[from] as f32,
+        })
+        .collect();
+    report
+        .pairs
+        .sort_by(|a, b| b.share.total_cmp(&a.share).then(b.edges.cmp(&a.edges)));
+    report
+}
+
+/// `threshold` is the share (0-100) of an area's tasks above which a pair counts as tight
+pub fn run(threshold: u8, dot: Option<&Path>) -> Result<()> {
+    let tasks = load_all_tasks().context("Failed to load tasks")?;
+    if tasks.is_empty() {
+        println!("📋 No tasks found. Create your first task with 'taskguard create'.");
+        return Ok(());
+    }
+
+    let threshold = f32::from(threshold.min(100)) / 100.0;
+    let report = coupling(&tasks);
+
+    println!("🔗 CROSS-AREA COUPLING");
+    println!(
+        "   {} cross-area and {} same-area dependency edges across {} areas",
+        report.cross_edges(),
+        report.internal_edges,
+        report.areas.len()
+    );
+    println!();
+    println!("   Share of each row area's tasks that depend on the column area:");
+    print_matrix(&report);
+
+    let tight: Vec<&AreaCoupling> = report
+        .pairs
+        .iter()
+        .filter(|p| p.share >= threshold)
+        .collect();
+    println!();
+    if tight.is_empty() {
+        println!(
+            "✅ No tightly coupled areas ({:.0}%+ of tasks depending on another area)",
+            threshold * 100.0
+        );
+    } else {
+        println!("⚠️  TIGHTLY COUPLED ({:.0}%+)", threshold * 100.0);
+        for pair in &tight {
+            println!(
+                "   {} → {}: {} of {} tasks ({:.0}%), {} edges",
+                pair.from,
+                pair.to,
+                pair.tasks,
+                report.areas[&pair.from],
+                pair.share * 100.0,
+                pair.edges
+            );
+            if report.pair(&pair.to, &pair.from).is_some() {
+                println!("      └── depends both ways; consider merging the two areas");
+            }
+        }
+        println!();
+        println!(
+            "💡 Consider moving shared work into its own area or defining interfaces early so areas can proceed in parallel"
+        );
+    }
+
+    if let Some(path) = dot {
+        fs::write(path, report.to_dot(threshold))
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        println!();
+        println!("📈 Graphviz diagram written to {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn print_matrix(report: &CouplingReport) {
+    let width = report
+        .areas
+        .keys()
+        .map(|a| a.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(5);
+
+    let mut header = format!("   {:width$}", "", width = width);
+    for area in report.areas.keys() {
+        let _ = write!(header, "  {:>width$}", area, width = width);
+    }
+    println!("{}", header);
+
+    for from in report.areas.keys() {
+        let mut row = format!("   {:width$}", from, width = width);
+        for to in report.areas.keys() {
+            let cell = if from == to {
+                "-".to_string()
+            } else {
+                report
+                    .pair(from, to)
+                    .map_or_else(|| "·".to_string(), |p| format!("{:.0}%", p.share * 100.0))
+            };
+            let _ = write!(row, "  {:>width$}", cell, width = width);
+        }
+        println!("{}", row);
+    }
+}
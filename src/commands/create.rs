@@ -1,11 +1,83 @@
 use anyhow::{Context, Result};
 use chrono::Utc;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
-
-use crate::config::{Config, find_taskguard_root, get_config_path, get_tasks_dir};
-use crate::task::{Priority, Task, TaskStatus};
+use std::io::{self, IsTerminal, Write};
+use walkdir::WalkDir;
+
+use crate::config::{Config, find_taskguard_root, get_archive_dir, get_config_path, get_tasks_dir};
+use crate::task::{
+    FORMAT_VERSION, Priority, Relations, Task, TaskStatus, area_id_prefix, normalize_area,
+    normalize_estimate, parse_assignees, parse_due_date,
+};
 use crate::templates::TemplateManager;
 
+/// Parse `--var key=value` flags into `custom.key` template variables
+fn parse_template_vars(vars: Vec<String>) -> Result<HashMap<String, String>> {
+    vars.into_iter()
+        .map(|var| {
+            let (key, value) = var
+                .split_once('=')
+                .ok_or_else(|| anyhow::anyhow!("Invalid --var '{}'. Expected key=value", var))?;
+            let key = key.trim();
+            let key = key.strip_prefix("custom.").unwrap_or(key);
+            Ok((format!("custom.{}", key), value.trim().to_string()))
+        })
+        .collect()
+}
+
+/// Ask for `{{custom.*}}` values not given on the command line
+///
+/// Only prompts on an interactive terminal; otherwise the placeholders are
+/// left in the task body and listed as a warning.
+fn prompt_missing_vars(template: &str, vars: &mut HashMap<String, String>) -> Result<()> {
+    let missing: Vec<String> = TemplateManager::placeholders(template)
+        .into_iter()
+        .filter(|name| name.starts_with("custom.") && !vars.contains_key(name))
+        .collect();
+
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    if !io::stdin().is_terminal() {
+        println!(
+            "⚠️  Template placeholders left unfilled: {} (use --var key=value)",
+            missing.join(", ")
+        );
+        return Ok(());
+    }
+
+    println!("📝 Template fields (leave empty to skip):");
+    for name in missing {
+        print!("   {}: ", name.trim_start_matches("custom."));
+        io::stdout().flush()?;
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input)?;
+        let value = input.trim();
+        if !value.is_empty() {
+            vars.insert(name, value.to_string());
+        }
+    }
+
+    Ok(())
+}
+
+/// Area for tasks created without one: `setup` if configured, else the first area
+pub(crate) fn default_area(config: &Config) -> String {
+    if config.project.areas.contains(&"setup".to_string()) {
+        "setup".to_string()
+    } else {
+        config
+            .project
+            .areas
+            .first()
+            .cloned()
+            .unwrap_or_else(|| "general".to_string())
+    }
+}
+
 /// Add a new area to config if it doesn't exist
 fn add_area_to_config(
     config: &mut Config,
@@ -36,25 +108,35 @@ pub fn run(
     dependencies: Option<String>,
     assignee: Option<String>,
     estimate: Option<String>,
+    due: Option<String>,
+    epic: Option<String>,
+    template: Option<String>,
+    vars: Vec<String>,
     allow_orphan_task: bool,
 ) -> Result<()> {
+    let custom_vars = parse_template_vars(vars)?;
     let tasks_dir = get_tasks_dir()?;
+    let due = due.map(|d| parse_due_date(&d)).transpose()?;
+    let estimate = estimate.map(|e| normalize_estimate(&e)).transpose()?;
     let config_path = get_config_path()?;
     let mut config = Config::load_or_default(&config_path)?;
 
-    // Determine area
-    let area = area.unwrap_or_else(|| {
-        if config.project.areas.contains(&"setup".to_string()) {
-            "setup".to_string()
-        } else {
-            config
-                .project
-                .areas
-                .first()
-                .cloned()
-                .unwrap_or_else(|| "general".to_string())
-        }
-    });
+    // Determine area (nested areas like "backend/auth" map to nested directories)
+    let area = area.map(|a| normalize_area(&a)).transpose()?;
+    let area = area.unwrap_or_else(|| default_area(&config));
+
+    // Resolve the template before anything is written: --template > [templates] > area default
+    let taskguard_root = find_taskguard_root();
+    let template = match template.or_else(|| config.templates.get(&area).cloned()) {
+        Some(name) => TemplateManager::get_named_template(&name, taskguard_root.as_deref())
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Template '{}' not found. Run 'taskguard template list' to see available templates",
+                    name
+                )
+            })?,
+        None => TemplateManager::get_template(&area, taskguard_root.as_deref()),
+    };
 
     // Auto-add new area to config
     if add_area_to_config(&mut config, &config_path, &area)? {
@@ -135,18 +217,36 @@ pub fn run(
     // Note if creating orphan task with explicit flag
     let is_orphan = dependency_list.is_empty() && allow_orphan_task;
 
-    // Determine assignee (default: "developer")
-    let assignee = assignee.or_else(|| Some("developer".to_string()));
+    // Determine assignees (default: "developer")
+    let mut assignees = assignee.map(|a| parse_assignees(&a)).unwrap_or_default();
+    if assignees.is_empty() {
+        assignees.push("developer".to_string());
+    }
 
     // Generate task ID
     let area_dir = tasks_dir.join(&area);
     let task_id = generate_task_id(&area, &area_dir)?;
 
-    // Get domain-specific template
-    let taskguard_root = find_taskguard_root();
-    let template = TemplateManager::get_template(&area, taskguard_root.as_deref());
-    let date = Utc::now().format("%Y-%m-%d").to_string();
-    let content = TemplateManager::render(&template, &title, &date);
+    let mut template_vars: HashMap<String, String> = HashMap::from([
+        ("title".to_string(), title.clone()),
+        ("area".to_string(), area.clone()),
+        ("id".to_string(), task_id.clone()),
+        (
+            "date".to_string(),
+            Utc::now().format("%Y-%m-%d").to_string(),
+        ),
+        ("priority".to_string(), priority.to_string()),
+        ("assignee".to_string(), assignees.join(", ")),
+    ]);
+    if let Some(due) = due {
+        template_vars.insert("due".to_string(), due.to_string());
+    }
+    if let Some(epic) = &epic {
+        template_vars.insert("epic".to_string(), epic.clone());
+    }
+    template_vars.extend(custom_vars);
+    prompt_missing_vars(&template, &mut template_vars)?;
+    let content = TemplateManager::render_vars(&template, &template_vars);
 
     // Create task
     let task = Task {
@@ -156,11 +256,22 @@ pub fn run(
         priority,
         tags: tag_list,
         dependencies: dependency_list,
-        assignee,
+        relations: Relations::default(),
+        assignees,
         created: Utc::now(),
         estimate,
         complexity,
+        due,
+        epic,
+        time_log: vec![],
+        links: vec![],
+        branch: None,
+        release: None,
+        completed: None,
+        claimed: None,
+        format_version: FORMAT_VERSION,
         area: area.clone(),
+        extra: BTreeMap::new(),
         content,
         file_path: std::path::PathBuf::new(), // Will be set when saved
     };
@@ -180,7 +291,7 @@ pub fn run(
         ));
     }
 
-    let content = task.to_file_content()?;
+    let content = task.to_file_content_as(config.settings.frontmatter)?;
 
     fs::write(&file_path, content)
         .with_context(|| format!("Failed to write task file: {}", file_path.display()))?;
@@ -196,6 +307,12 @@ pub fn run(
     println!("   Title: {}", task.title);
     println!("   Area: {}", task.area);
     println!("   Priority: {}", task.priority);
+    if let Some(due) = task.due {
+        println!("   Due: {}", due);
+    }
+    if let Some(epic) = &task.epic {
+        println!("   Epic: {}", epic);
+    }
 
     // Show orphan warning if created with --allow-orphan-task
     if is_orphan {
@@ -216,19 +333,29 @@ pub fn run(
     Ok(())
 }
 
-fn generate_task_id(area: &str, area_dir: &std::path::Path) -> Result<String> {
-    // Find existing tasks in both active and archive directories
-    // to prevent ID reuse when tasks are archived
-    let active_max = scan_dir_for_max_id(area, area_dir)?;
-    let archive_max = get_archive_max_id(area)?;
+pub(crate) fn generate_task_id(area: &str, area_dir: &std::path::Path) -> Result<String> {
+    // Nested areas flatten into the prefix, so `backend/auth` and a flat
+    // `backend-auth` share one. Scan every area directory, active and
+    // archived, so neither reuses a number the other has taken.
+    let prefix = area_id_prefix(area);
+    let mut max_num = scan_dir_for_max_id(&prefix, area_dir)?;
+    if find_taskguard_root().is_some() {
+        for dir in [get_tasks_dir()?, get_archive_dir()?] {
+            for entry in WalkDir::new(dir)
+                .into_iter()
+                .filter_map(Result::ok)
+                .filter(|e| e.file_type().is_dir())
+            {
+                max_num = max_num.max(scan_dir_for_max_id(&prefix, entry.path())?);
+            }
+        }
+    }
 
-    let max_num = active_max.max(archive_max);
-    let next_num = max_num + 1;
-    Ok(format!("{}-{:03}", area, next_num))
+    Ok(format!("{}-{:03}", prefix, max_num + 1))
 }
 
-/// Scan a directory for the highest task ID number
-fn scan_dir_for_max_id(area: &str, dir: &std::path::Path) -> Result<u32> {
+/// Scan a directory for the highest task ID number with the given ID prefix
+fn scan_dir_for_max_id(prefix: &str, dir: &std::path::Path) -> Result<u32> {
     let mut max_num = 0;
 
     if dir.exists() {
@@ -243,7 +370,7 @@ fn scan_dir_for_max_id(area: &str, dir: &std::path::Path) -> Result<u32> {
                     let area_part = &stem[..dash_pos];
                     let num_part = &stem[dash_pos + 1..];
 
-                    if area_part == area
+                    if area_part == prefix
                         && let Ok(num) = num_part.parse::<u32>()
                     {
                         max_num = max_num.max(num);
@@ -255,16 +382,3 @@ fn scan_dir_for_max_id(area: &str, dir: &std::path::Path) -> Result<u32> {
 
     Ok(max_num)
 }
-
-/// Get the max task ID from the archive directory for an area
-fn get_archive_max_id(area: &str) -> Result<u32> {
-    use crate::config::find_taskguard_root;
-
-    let root = match find_taskguard_root() {
-        Some(r) => r,
-        None => return Ok(0),
-    };
-
-    let archive_dir = root.join(".taskguard").join("archive").join(area);
-    scan_dir_for_max_id(area, &archive_dir)
-}
@@ -0,0 +1,156 @@
+use anyhow::{Context, Result};
+use std::collections::{HashMap, HashSet};
+
+use crate::commands::list::status_icon;
+use crate::config::load_all_tasks;
+use crate::task::{Task, TaskStatus, area_matches, format_estimate};
+
+/// Longest chain of open tasks through their dependencies, weighted by estimate
+///
+/// Returns the chain in execution order with its total estimated minutes.
+/// Tasks without an estimate count as zero; between equally long chains the
+/// one with more tasks wins. Done and missing dependencies are already
+/// satisfied and don't extend a chain.
+pub fn critical_path(tasks: &[&Task]) -> Result<(Vec<String>, u32)> {
+    let open: HashMap<&str, &Task> = tasks
+        .iter()
+        .filter(|t| t.status != TaskStatus::Done)
+        .map(|t| (t.id.as_str(), *t))
+        .collect();
+
+    let mut ids: Vec<&str> = open.keys().copied().collect();
+    ids.sort_unstable();
+
+    let mut longest = HashMap::new();
+    let mut best: Option<(&str, (u32, usize))> = None;
+    for id in ids {
+        let weight = chain_to(id, &open, &mut longest, &mut HashSet::new())?;
+        if best.is_none_or(|(_, current)| weight > current) {
+            best = Some((id, weight));
+        }
+    }
+
+    let Some((end, (minutes, _))) = best else {
+        return Ok((Vec::new(), 0));
+    };
+
+    // Follow the heaviest dependency back from the last task
+    let mut path = vec![end.to_string()];
+    let mut current = end;
+    while let Some((_, Some(prev))) = longest.get(current) {
+        path.push(prev.to_string());
+        current = prev;
+    }
+    path.reverse();
+
+    Ok((path, minutes))
+}
+
+/// (minutes, tasks) of the heaviest chain ending at `id`, memoized in `longest`
+/// together with the dependency the chain comes through
+fn chain_to<'a>(
+    id: &'a str,
+    open: &HashMap<&'a str, &'a Task>,
+    longest: &mut HashMap<&'a str, ((u32, usize), Option<&'a str>)>,
+    in_progress: &mut HashSet<&'a str>,
+) -> Result<(u32, usize)> {
+    if let Some((weight, _)) = longest.get(id) {
+        return Ok(*weight);
+    }
+    if !in_progress.insert(id) {
+        return Err(anyhow::anyhow!(
+            "Dependency cycle through {}. Run 'taskguard validate' to see the full chain",
+            id
+        ));
+    }
+
+    let task = open[id];
+    let mut heaviest: Option<(&str, (u32, usize))> = None;
+    for dep in &task.dependencies {
+        if let Some((dep_id, _)) = open.get_key_value(dep.as_str()) {
+            let weight = chain_to(dep_id, open, longest, in_progress)?;
+            if heaviest.is_none_or(|(_, current)| weight > current) {
+                heaviest = Some((dep_id, weight));
+            }
+        }
+    }
+
+    let (minutes, count) = heaviest.map_or((0, 0), |(_, weight)| weight);
+    let weight = (minutes + task.estimate_minutes().unwrap_or(0), count + 1);
+    in_progress.remove(id);
+    longest.insert(id, (weight, heaviest.map(|(dep_id, _)| dep_id)));
+    Ok(weight)
+}
+
+pub fn run(area: Option<&str>) -> Result<()> {
+    let tasks = load_all_tasks().context("Failed to load tasks")?;
+    let scoped: Vec<&Task> = tasks
+        .iter()
+        .filter(|t| area.is_none_or(|a| area_matches(&t.area, a)))
+        .collect();
+
+    let (path, minutes) = critical_path(&scoped)?;
+    if path.is_empty() {
+        println!("✅ No open tasks - nothing left on the critical path");
+        return Ok(());
+    }
+
+    let by_id: HashMap<&str, &Task> = scoped.iter().map(|t| (t.id.as_str(), *t)).collect();
+    let done: Vec<String> = tasks
+        .iter()
+        .filter(|t| t.status == TaskStatus::Done)
+        .map(|t| t.id.clone())
+        .collect();
+
+    println!(
+        "🎯 CRITICAL PATH{} ({}, {} tasks)",
+        area.map(|a| format!(" - {}", a)).unwrap_or_default(),
+        format_estimate(minutes),
+        path.len()
+    );
+
+    let mut elapsed = 0;
+    let mut unestimated = 0;
+    for (i, id) in path.iter().enumerate() {
+        let task = by_id[id.as_str()];
+        let estimate = match task.estimate_minutes() {
+            Some(m) => {
+                elapsed += m;
+                format!(
+                    "{}, done after {}",
+                    format_estimate(m),
+                    format_estimate(elapsed)
+                )
+            }
+            None => {
+                unestimated += 1;
+                "no estimate".to_string()
+            }
+        };
+        println!(
+            "   {:>2}. {} {} - {} ({})",
+            i + 1,
+            status_icon(&task.status),
+            task.id,
+            task.title,
+            estimate
+        );
+    }
+
+    println!();
+    if unestimated > 0 {
+        println!(
+            "   ⚠️  {} task(s) on the path have no estimate and count as 0 (set one with 'taskguard update estimate')",
+            unestimated
+        );
+    }
+    let first = by_id[path[0].as_str()];
+    if first.is_available(&done) {
+        println!(
+            "   ▶️  Start with {}: any delay on it delays the whole project",
+            first.id
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+
+use crate::config::load_all_tasks;
+use crate::task::{Task, TaskStatus};
+
+/// Group tasks by their `epic` field, skipping tasks without one
+pub fn group_by_epic(tasks: &[Task]) -> BTreeMap<&str, Vec<&Task>> {
+    let mut epics: BTreeMap<&str, Vec<&Task>> = BTreeMap::new();
+    for task in tasks {
+        if let Some(epic) = task.epic.as_deref() {
+            epics.entry(epic).or_default().push(task);
+        }
+    }
+    epics
+}
+
+fn progress(tasks: &[&Task]) -> (usize, usize, f32) {
+    let done = tasks
+        .iter()
+        .filter(|t| t.status == TaskStatus::Done)
+        .count();
+    let percent = if tasks.is_empty() {
+        0.0
+    } else {
+        done as f32 / tasks.len() as f32 * 100.0
+    };
+    (done, tasks.len(), percent)
+}
+
+pub fn run_list() -> Result<()> {
+    let tasks = load_all_tasks().context("Failed to load tasks")?;
+    let epics = group_by_epic(&tasks);
+
+    if epics.is_empty() {
+        println!(
+            "🎯 No epics found. Set 'epic:' in task frontmatter or use 'taskguard create --epic'."
+        );
+        return Ok(());
+    }
+
+    println!("🎯 EPICS");
+    for (epic, epic_tasks) in &epics {
+        let (done, total, percent) = progress(epic_tasks);
+        let mut areas: Vec<&str> = epic_tasks.iter().map(|t| t.area.as_str()).collect();
+        areas.sort_unstable();
+        areas.dedup();
+        println!(
+            "   {} - {}/{} done ({:.0}%) │ areas: {}",
+            epic,
+            done,
+            total,
+            percent,
+            areas.join(", ")
+        );
+    }
+
+    let unassigned = tasks.iter().filter(|t| t.epic.is_none()).count();
+    if unassigned > 0 {
+        println!();
+        println!("   {} tasks are not part of an epic", unassigned);
+    }
+
+    Ok(())
+}
+
+pub fn run_show(name: &str) -> Result<()> {
+    let tasks = load_all_tasks().context("Failed to load tasks")?;
+    let epics = group_by_epic(&tasks);
+    let epic_tasks = epics
+        .get(name)
+        .ok_or_else(|| anyhow::anyhow!("Epic '{}' not found", name))?;
+
+    let (done, total, percent) = progress(epic_tasks);
+    println!("🎯 EPIC: {}", name);
+    println!("   Progress: {}/{} done ({:.0}%)", done, total, percent);
+
+    let mut by_area: BTreeMap<&str, Vec<&Task>> = BTreeMap::new();
+    for task in epic_tasks {
+        by_area.entry(task.area.as_str()).or_default().push(task);
+    }
+
+    for (area, mut area_tasks) in by_area {
+        area_tasks.sort_by_key(|t| &t.id);
+        let (done, total, _) = progress(&area_tasks);
+        println!();
+        println!("📁 {} ({}/{} done)", area, done, total);
+        for task in area_tasks {
+            let marker = if task.status == TaskStatus::Done {
+                "✅"
+            } else {
+                "⭕"
+            };
+            println!(
+                "   {} {} - {} [{}]",
+                marker, task.id, task.title, task.status
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,132 @@
+use anyhow::{Context, Result};
+use std::env;
+
+use crate::commands::update::find_task_file;
+use crate::config::get_tasks_dir;
+use crate::git::GitAnalyzer;
+use crate::task::Task;
+
+fn assignees_display(task: &Task) -> String {
+    if task.assignees.is_empty() {
+        "none".to_string()
+    } else {
+        task.assignees.join(", ")
+    }
+}
+
+fn due_display(task: &Task) -> String {
+    task.due
+        .map_or_else(|| "none".to_string(), |d| d.to_string())
+}
+
+/// Human-readable changes to tracked fields between two versions of a task
+///
+/// The first version of a task (`previous` is `None`) is described as its creation.
+pub fn describe_changes(previous: Option<&Task>, current: &Task) -> Vec<String> {
+    let Some(previous) = previous else {
+        return vec![format!(
+            "created ({}, {} priority, assignee {})",
+            current.status,
+            current.priority,
+            assignees_display(current)
+        )];
+    };
+
+    let mut changes = Vec::new();
+    if previous.status != current.status {
+        changes.push(format!("status {} → {}", previous.status, current.status));
+    }
+    if previous.assignees != current.assignees {
+        changes.push(format!(
+            "assignee {} → {}",
+            assignees_display(previous),
+            assignees_display(current)
+        ));
+    }
+    if previous.priority != current.priority {
+        changes.push(format!(
+            "priority {} → {}",
+            previous.priority, current.priority
+        ));
+    }
+    if previous.due != current.due {
+        changes.push(format!(
+            "due {} → {}",
+            due_display(previous),
+            due_display(current)
+        ));
+    }
+    if previous.area != current.area {
+        changes.push(format!("area {} → {}", previous.area, current.area));
+    }
+    changes
+}
+
+/// Show who changed a task's status, assignee, priority and due date, and when
+pub fn run(task_id: &str) -> Result<()> {
+    let current_dir = env::current_dir().context("Failed to get current directory")?;
+    let git_analyzer = GitAnalyzer::new(&current_dir)
+        .context("Failed to initialize Git analyzer. Make sure you're in a Git repository.")?;
+    let revisions = git_analyzer.task_history(task_id)?;
+
+    // The working copy may hold changes that are not committed yet
+    let working_copy = get_tasks_dir()
+        .and_then(|dir| find_task_file(&dir, task_id))
+        .and_then(Task::from_file)
+        .ok();
+
+    if revisions.is_empty() && working_copy.is_none() {
+        return Err(anyhow::anyhow!("Task '{}' not found", task_id));
+    }
+
+    let title = working_copy
+        .as_ref()
+        .or(revisions.last().map(|r| &r.task))
+        .map(|t| t.title.clone())
+        .unwrap_or_default();
+    println!("📜 HISTORY: {} - {}", task_id, title);
+    println!();
+
+    if revisions.is_empty() {
+        println!("   No committed history yet");
+    }
+
+    let mut previous: Option<&Task> = None;
+    let mut edits_without_changes = 0;
+    for revision in &revisions {
+        let changes = describe_changes(previous, &revision.task);
+        previous = Some(&revision.task);
+        if changes.is_empty() {
+            edits_without_changes += 1;
+            continue;
+        }
+
+        println!(
+            "   {}  {}  {}  {}",
+            revision.timestamp.format("%Y-%m-%d %H:%M"),
+            &revision.oid[..7],
+            revision.author,
+            changes.join("; ")
+        );
+        if !revision.message.is_empty() {
+            println!("      {}", revision.message);
+        }
+    }
+
+    if let Some(task) = &working_copy {
+        let changes = describe_changes(previous, task);
+        if !changes.is_empty() {
+            println!("   (uncommitted)  {}", changes.join("; "));
+        }
+    }
+
+    if edits_without_changes > 0 {
+        println!();
+        println!(
+            "   {} other commit(s) edited the task without changing these fields",
+            edits_without_changes
+        );
+    }
+
+    Ok(())
+}
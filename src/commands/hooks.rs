@@ -0,0 +1,241 @@
+use anyhow::{Context, Result};
+use git2::Repository;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::{CommitMsgCheck, Config, find_taskguard_root, get_config_path, load_all_tasks};
+use crate::git::{mentions_task_id, merged_branch, trailer_task_ids};
+
+/// First comment line of every hook `install` writes; only hooks carrying it
+/// are replaced or removed
+const MARKER: &str =
+    "# Installed by `taskguard hooks install`; remove with `taskguard hooks uninstall`";
+
+/// Suffix for a hook that was in place before ours; it keeps running first
+const PREVIOUS_SUFFIX: &str = ".pre-taskguard";
+
+fn hook_script(hook: &str, command: &str) -> String {
+    format!(
+        r#"#!/bin/sh
+{marker}
+# Run the hook this one replaced first, if any
+previous="$0{suffix}"
+if [ -x "$previous" ]; then
+    "$previous" "$@" || exit $?
+fi
+if ! command -v taskguard >/dev/null 2>&1; then
+    echo "taskguard not found on PATH; skipping TaskGuard {hook} hook" >&2
+    exit 0
+fi
+exec {command}
+"#,
+        marker = MARKER,
+        suffix = PREVIOUS_SUFFIX,
+        hook = hook,
+        command = command
+    )
+}
+
+/// `core.hooksPath` when set, `.git/hooks` otherwise
+fn hooks_dir() -> Result<PathBuf> {
+    let root =
+        find_taskguard_root().context("Not in a TaskGuard project. Run 'taskguard init' first.")?;
+    let repo =
+        Repository::discover(&root).context("Not in a Git repository. Run 'git init' first.")?;
+
+    if let Ok(path) = repo.config().and_then(|c| c.get_path("core.hooksPath")) {
+        let base = repo.workdir().unwrap_or_else(|| repo.path());
+        return Ok(base.join(path));
+    }
+    Ok(repo.path().join("hooks"))
+}
+
+fn previous_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(PREVIOUS_SUFFIX);
+    path.with_file_name(name)
+}
+
+fn is_taskguard_hook(path: &Path) -> bool {
+    fs::read_to_string(path).is_ok_and(|content| content.contains(MARKER))
+}
+
+/// Hooks `install` writes, in the order git runs them
+const HOOKS: [&str; 3] = ["pre-commit", "commit-msg", "pre-push"];
+
+/// Install the pre-commit, commit-msg and pre-push hooks, keeping existing hooks in the chain
+pub fn run_install(strict: bool) -> Result<()> {
+    let dir = hooks_dir()?;
+    fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create hooks directory: {}", dir.display()))?;
+
+    let validate = if strict {
+        "taskguard validate --strict"
+    } else {
+        "taskguard validate"
+    };
+    let hooks = [
+        (
+            "pre-commit",
+            hook_script("pre-commit", "taskguard validate --staged"),
+        ),
+        (
+            "commit-msg",
+            hook_script("commit-msg", "taskguard hooks check-commit-msg \"$1\""),
+        ),
+        ("pre-push", hook_script("pre-push", validate)),
+    ];
+
+    println!("🪝 INSTALLING GIT HOOKS");
+    for (hook, script) in &hooks {
+        let path = dir.join(hook);
+        let previous = previous_path(&path);
+
+        if path.exists() && !is_taskguard_hook(&path) {
+            if previous.exists() {
+                return Err(anyhow::anyhow!(
+                    "{} exists and a previous hook is already saved as {}; move one of them first",
+                    path.display(),
+                    previous.display()
+                ));
+            }
+            fs::rename(&path, &previous)
+                .with_context(|| format!("Failed to move {}", path.display()))?;
+            println!(
+                "   📦 Kept existing {} hook as {} (runs first)",
+                hook,
+                previous.display()
+            );
+        }
+
+        fs::write(&path, script)
+            .with_context(|| format!("Failed to write hook: {}", path.display()))?;
+        make_executable(&path)?;
+        println!("   ✅ {}", path.display());
+    }
+
+    println!();
+    println!("   pre-commit: runs 'taskguard validate --staged' on staged task files");
+    println!("   commit-msg: checks that messages reference a known task ID");
+    println!(
+        "               ([git] commit_msg_check = \"off\" | \"warn\" | \"error\", currently \"{}\")",
+        commit_msg_check_name(
+            Config::load_or_default(get_config_path()?)?
+                .git
+                .commit_msg_check
+        )
+    );
+    println!("   pre-push:   runs '{}'", validate);
+    Ok(())
+}
+
+/// Remove the installed hooks and put back the ones they replaced
+pub fn run_uninstall() -> Result<()> {
+    let dir = hooks_dir()?;
+
+    println!("🪝 REMOVING GIT HOOKS");
+    for hook in HOOKS {
+        let path = dir.join(hook);
+        let previous = previous_path(&path);
+
+        if !path.exists() {
+            println!("   ⏭️  {}: not installed", hook);
+            continue;
+        }
+        if !is_taskguard_hook(&path) {
+            println!("   ⏭️  {}: left alone, not installed by TaskGuard", hook);
+            continue;
+        }
+
+        fs::remove_file(&path)
+            .with_context(|| format!("Failed to remove hook: {}", path.display()))?;
+        if previous.exists() {
+            fs::rename(&previous, &path)
+                .with_context(|| format!("Failed to restore {}", previous.display()))?;
+            println!("   ♻️  {}: removed, previous hook restored", hook);
+        } else {
+            println!("   🗑️  {}: removed", hook);
+        }
+    }
+    Ok(())
+}
+
+/// Check a commit message file, as the commit-msg hook does
+pub fn run_check_commit_msg(file: &Path) -> Result<()> {
+    let config = Config::load_or_default(get_config_path()?)?;
+    let check = config.git.commit_msg_check;
+    if check == CommitMsgCheck::Off {
+        return Ok(());
+    }
+
+    let raw = fs::read_to_string(file)
+        .with_context(|| format!("Failed to read commit message: {}", file.display()))?;
+    // Drop comments and anything below the scissors line of `commit --verbose`
+    let message = raw
+        .lines()
+        .take_while(|line| !line.starts_with("# ------------------------ >8"))
+        .filter(|line| !line.starts_with('#'))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let message = message.trim();
+
+    // Empty messages abort the commit anyway; merges and fixups belong to other commits
+    if message.is_empty()
+        || merged_branch(message).is_some()
+        || message.starts_with("fixup!")
+        || message.starts_with("squash!")
+    {
+        return Ok(());
+    }
+
+    let tasks = load_all_tasks().context("Failed to load tasks")?;
+    if tasks.iter().any(|t| mentions_task_id(message, &t.id)) {
+        return Ok(());
+    }
+
+    let unknown: Vec<String> = trailer_task_ids(message)
+        .into_iter()
+        .filter(|id| !tasks.iter().any(|t| &t.id == id))
+        .collect();
+    let icon = if check == CommitMsgCheck::Error {
+        "❌"
+    } else {
+        "⚠️ "
+    };
+    eprintln!("{} Commit message doesn't reference a known task ID", icon);
+    if !unknown.is_empty() {
+        eprintln!("   No task named {}", unknown.join(", "));
+    }
+    eprintln!(
+        "   Mention one (e.g., 'Fix login in backend-001') or add a 'Task-Id: backend-001' trailer"
+    );
+
+    if check == CommitMsgCheck::Error {
+        eprintln!("   Set [git] commit_msg_check = \"warn\" to allow such commits");
+        return Err(anyhow::anyhow!(
+            "Commit rejected by TaskGuard commit-msg hook"
+        ));
+    }
+    Ok(())
+}
+
+fn commit_msg_check_name(check: CommitMsgCheck) -> &'static str {
+    match check {
+        CommitMsgCheck::Off => "off",
+        CommitMsgCheck::Warn => "warn",
+        CommitMsgCheck::Error => "error",
+    }
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    fs::set_permissions(path, fs::Permissions::from_mode(0o755))
+        .with_context(|| format!("Failed to make {} executable", path.display()))
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,130 @@
+use anyhow::{Context, Result};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::commands::list::status_icon;
+use crate::commands::plan::priority_order;
+use crate::config::load_all_tasks;
+use crate::task::{Task, TaskStatus, format_estimate};
+
+/// Open tasks that depend on a task, directly or through other tasks
+#[derive(Debug, Default)]
+pub struct Impact<'a> {
+    /// Waiting only on the task: available as soon as it's done
+    pub unblocked: Vec<&'a Task>,
+    /// Further downstream, with the other unfinished tasks each still waits on
+    pub downstream: Vec<(&'a Task, Vec<String>)>,
+}
+
+/// Everything open that finishing `task_id` moves closer to available,
+/// highest priority first
+pub fn impact<'a>(task_id: &str, tasks: &'a [Task]) -> Impact<'a> {
+    let done: HashSet<&str> = tasks
+        .iter()
+        .filter(|t| t.status == TaskStatus::Done)
+        .map(|t| t.id.as_str())
+        .collect();
+    let mut dependents: HashMap<&str, Vec<&Task>> = HashMap::new();
+    for task in tasks.iter().filter(|t| t.status != TaskStatus::Done) {
+        for dep in &task.dependencies {
+            dependents.entry(dep.as_str()).or_default().push(task);
+        }
+    }
+
+    // Breadth-first over dependents; the seen set also stops cycles
+    let mut seen: HashSet<&str> = HashSet::from([task_id]);
+    let mut queue = VecDeque::from([task_id]);
+    let mut impact = Impact::default();
+    while let Some(id) = queue.pop_front() {
+        for task in dependents.get(id).into_iter().flatten() {
+            if !seen.insert(task.id.as_str()) {
+                continue;
+            }
+            queue.push_back(task.id.as_str());
+
+            let waiting: Vec<String> = task
+                .dependencies
+                .iter()
+                .filter(|d| d.as_str() != task_id && !done.contains(d.as_str()))
+                .cloned()
+                .collect();
+            if waiting.is_empty() {
+                impact.unblocked.push(task);
+            } else {
+                impact.downstream.push((task, waiting));
+            }
+        }
+    }
+
+    impact.unblocked.sort_by(|a, b| by_priority(a, b));
+    impact
+        .downstream
+        .sort_by(|(a, _), (b, _)| by_priority(a, b));
+    impact
+}
+
+fn by_priority(a: &Task, b: &Task) -> std::cmp::Ordering {
+    priority_order(&b.priority)
+        .cmp(&priority_order(&a.priority))
+        .then_with(|| a.id.cmp(&b.id))
+}
+
+fn describe(task: &Task) -> String {
+    let estimate = task
+        .estimate_minutes()
+        .map(|m| format!(", {}", format_estimate(m)))
+        .unwrap_or_default();
+    format!(
+        "{} {} - {} [{}{}]",
+        status_icon(&task.status),
+        task.id,
+        task.title,
+        task.priority,
+        estimate
+    )
+}
+
+pub fn run(task_id: &str) -> Result<()> {
+    let tasks = load_all_tasks().context("Failed to load tasks")?;
+    let task = tasks
+        .iter()
+        .find(|t| t.id == task_id)
+        .ok_or_else(|| anyhow::anyhow!("Task '{}' not found", task_id))?;
+
+    let impact = impact(task_id, &tasks);
+    println!("💥 IMPACT OF FINISHING {} - {}", task.id, task.title);
+    if task.status == TaskStatus::Done {
+        println!("   ℹ️  {} is already done", task.id);
+    }
+    if impact.unblocked.is_empty() && impact.downstream.is_empty() {
+        println!("   No open tasks depend on {}", task.id);
+        return Ok(());
+    }
+
+    if !impact.unblocked.is_empty() {
+        println!();
+        println!(
+            "✅ BECOMES AVAILABLE ({} tasks, by priority)",
+            impact.unblocked.len()
+        );
+        for task in &impact.unblocked {
+            println!("   {}", describe(task));
+        }
+    }
+
+    if !impact.downstream.is_empty() {
+        println!();
+        println!(
+            "⏳ CLOSER TO AVAILABLE ({} tasks, still waiting on others)",
+            impact.downstream.len()
+        );
+        for (task, waiting) in &impact.downstream {
+            println!(
+                "   {} (waiting for: {})",
+                describe(task),
+                waiting.join(", ")
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -1,12 +1,13 @@
 use anyhow::{Context, Result};
 use chrono::Utc;
 use regex::Regex;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::{Path, PathBuf};
 
 use crate::config::{Config, get_config_path, get_tasks_dir, load_tasks_from_dir};
-use crate::task::{Priority, Task, TaskStatus};
+use crate::git::auto_commit;
+use crate::task::{FORMAT_VERSION, Priority, Relations, Task, TaskStatus, normalize_area};
 use std::collections::HashSet;
 
 #[derive(Debug, Clone)]
@@ -102,6 +103,7 @@ pub fn run(file_path: PathBuf, options: ImportOptions) -> Result<()> {
 
     let mut created_count = 0;
     let mut created_tasks: Vec<Task> = Vec::new();
+    let mut created_paths = Vec::new();
 
     for task in tasks {
         let file_path = area_dir.join(task.file_name());
@@ -111,7 +113,7 @@ pub fn run(file_path: PathBuf, options: ImportOptions) -> Result<()> {
             continue;
         }
 
-        let content = task.to_file_content()?;
+        let content = task.to_file_content_as(config.settings.frontmatter)?;
         fs::write(&file_path, content)
             .with_context(|| format!("Failed to write task file: {}", file_path.display()))?;
 
@@ -126,6 +128,7 @@ pub fn run(file_path: PathBuf, options: ImportOptions) -> Result<()> {
             );
         }
         created_tasks.push(task);
+        created_paths.push(file_path);
     }
 
     // Detect orphan tasks among imported ones
@@ -161,6 +164,17 @@ pub fn run(file_path: PathBuf, options: ImportOptions) -> Result<()> {
     println!("   Area: {}", area);
     println!("   Directory: {}", area_dir.display());
 
+    let created_ids: Vec<String> = created_tasks.iter().map(|t| t.id.clone()).collect();
+    auto_commit(
+        &created_paths,
+        &format!(
+            "import {} tasks from {}",
+            created_ids.len(),
+            file_path.file_name().unwrap_or_default().to_string_lossy()
+        ),
+        &created_ids,
+    );
+
     Ok(())
 }
 
@@ -328,7 +342,7 @@ fn extract_dependencies(content: &str) -> Vec<String> {
 
 fn determine_area(options: &ImportOptions, config: &Config, file_path: &Path) -> Result<String> {
     if let Some(ref area) = options.area {
-        return Ok(area.clone());
+        return normalize_area(area);
     }
 
     // Try to infer from filename
@@ -465,11 +479,22 @@ fn sections_to_tasks(
             priority,
             tags,
             dependencies,
-            assignee: Some("developer".to_string()),
+            relations: Relations::default(),
+            assignees: vec!["developer".to_string()],
             created: Utc::now(),
             estimate: section.effort,
             complexity: estimate_complexity(&section.content),
+            due: None,
+            epic: None,
+            time_log: vec![],
+            links: vec![],
+            branch: None,
+            release: None,
+            completed: None,
+            claimed: None,
+            format_version: FORMAT_VERSION,
             area: area.to_string(),
+            extra: BTreeMap::new(),
             content,
             file_path: std::path::PathBuf::new(), // Will be set when saved
         };
@@ -1,11 +1,22 @@
 use anyhow::{Context, Result};
+use git2::{Branch, Repository};
 use std::fs;
 use std::path::Path;
 
 use crate::config::Config;
+use crate::tasks_branch;
 
-pub fn run() -> Result<()> {
+/// Set up `.taskguard/` and `tasks/`; with `tasks_branch`, task files are kept
+/// on that orphan branch and `tasks/` is ignored on code branches
+pub fn run(tasks_branch: Option<&str>) -> Result<()> {
     let current_dir = std::env::current_dir().context("Failed to get current directory")?;
+    if let Some(branch) = tasks_branch {
+        Repository::discover(&current_dir)
+            .context("--tasks-branch needs a Git repository. Run 'git init' first.")?;
+        if !Branch::name_is_valid(branch)? {
+            return Err(anyhow::anyhow!("'{}' is not a valid branch name", branch));
+        }
+    }
 
     // Check if already initialized
     let taskguard_dir = current_dir.join(".taskguard");
@@ -36,7 +47,8 @@ pub fn run() -> Result<()> {
     }
 
     // Create default config
-    let config = Config::default();
+    let mut config = Config::default();
+    config.git.tasks_branch = tasks_branch.map(str::to_string);
     let config_path = taskguard_dir.join("config.toml");
     config
         .save(&config_path)
@@ -49,18 +61,42 @@ pub fn run() -> Result<()> {
             fs::read_to_string(&gitignore_path).context("Failed to read existing .gitignore")?;
 
         if !existing.contains(".taskguard/state/") {
-            format!("{}\n\n# TaskGuard\n.taskguard/state/\n", existing.trim())
+            format!(
+                "{}\n\n# TaskGuard\n.taskguard/state/\n.taskguard/cache/\n",
+                existing.trim()
+            )
+        } else if !existing.contains(".taskguard/cache/") {
+            format!("{}\n.taskguard/cache/\n", existing.trim_end())
         } else {
             existing
         }
     } else {
-        "# TaskGuard\n.taskguard/state/\n".to_string()
+        "# TaskGuard\n.taskguard/state/\n.taskguard/cache/\n".to_string()
     };
 
+    let mut gitignore_content = gitignore_content;
+    if !gitignore_content.contains(".taskguard/sync-state.json") {
+        gitignore_content = format!(
+            "{}\n.taskguard/sync-state.json\n",
+            gitignore_content.trim_end()
+        );
+    }
+    if tasks_branch.is_some()
+        && !gitignore_content
+            .lines()
+            .any(|line| line.trim() == "tasks/")
+    {
+        if !gitignore_content.ends_with('\n') {
+            gitignore_content.push('\n');
+        }
+        gitignore_content.push_str("tasks/\n");
+    }
+
     fs::write(&gitignore_path, gitignore_content).context("Failed to update .gitignore")?;
 
     // Create example task
     create_example_task(&tasks_dir)?;
+    tasks_branch::push()?;
 
     // Copy AI agent integration guide to project root
     copy_ai_guide(&current_dir)?;
@@ -73,6 +109,12 @@ pub fn run() -> Result<()> {
     for area in &default_areas {
         println!("   tasks/{}/", area);
     }
+    if let Some(branch) = tasks_branch {
+        println!();
+        println!("🌿 Task files are stored on the '{}' orphan branch", branch);
+        println!("   tasks/ is ignored here; TaskGuard commands commit changes to the branch");
+        println!("   Share them with 'git push origin {}'", branch);
+    }
     println!();
     println!("🤖 AI Agent Integration:");
     println!("   ✅ AGENTIC_AI_TASKGUARD_GUIDE.md copied to project root");
@@ -116,6 +158,7 @@ created: 2025-01-15T10:00:00Z
 estimate: 2h
 complexity: 3
 area: setup
+format_version: 1
 ---
 
 # Project Setup and Dependencies
@@ -135,7 +178,7 @@ Initial project setup to establish the foundation for development.
 - [ ] Configure development tools
 - [ ] Create basic project structure
 - [ ] Set up testing framework
-- [ ] Create initial documentation
+- [ ] Write README.md with setup instructions
 
 ## Acceptance Criteria
 ✅ **Environment Ready:**
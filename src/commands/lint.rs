@@ -1,12 +1,132 @@
 use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fs;
 use std::path::Path;
 use walkdir::WalkDir;
 
-use crate::analysis::{Severity, TaskAnalyzer};
-use crate::config::find_taskguard_root;
-use crate::task::Task;
+use crate::analysis::{
+    EstimateAccuracy, IssueCategory, LintIssue, Severity, TaskAnalysis, TaskAnalyzer,
+};
+use crate::analysis_cache::AnalysisCache;
+use crate::config::{Config, find_taskguard_root, get_config_path, load_all_tasks};
+use crate::risk::{self, TaskRisk};
+use crate::spelling::{DICTIONARY_FILE, SpellChecker};
+use crate::task::{Task, area_matches, body_start_line};
+
+/// Tasks listed by `lint --risk` in text output
+const RISK_LIMIT: usize = 10;
+
+/// Output format for `lint --format`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl OutputFormat {
+    pub fn parse(value: &str) -> Result<Self> {
+        match value {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(anyhow::anyhow!(
+                "Invalid format '{}'. Valid values: text, json",
+                value
+            )),
+        }
+    }
+}
+
+/// `lint --format json`; `version` changes only when fields are removed or renamed
+#[derive(Debug, Serialize)]
+struct LintReport<'a> {
+    version: u32,
+    summary: ReportSummary,
+    rule_errors: &'a [String],
+    tasks: Vec<TaskReport<'a>>,
+    /// Present with `--risk`, riskiest first
+    #[serde(skip_serializing_if = "Option::is_none")]
+    risk: Option<Vec<TaskRisk>>,
+}
+
+#[derive(Debug, Serialize)]
+struct ReportSummary {
+    tasks: usize,
+    errors: usize,
+    warnings: usize,
+    info: usize,
+    ignored: usize,
+    parse_errors: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct TaskReport<'a> {
+    id: &'a str,
+    /// Relative to the project root, with forward slashes
+    file: String,
+    complexity_score: f32,
+    quality_score: f32,
+    issues: Vec<IssueReport<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct IssueReport<'a> {
+    severity: &'a Severity,
+    category: &'static str,
+    rule: &'a str,
+    message: &'a str,
+    suggestion: Option<&'a str>,
+    /// 1-based line in the task file, when the issue points somewhere specific
+    line: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+#[allow(clippy::struct_excessive_bools)] // one per `taskguard lint` flag
+pub struct LintOptions {
+    pub verbose: bool,
+    pub area: Option<String>,
+    pub check_links: bool,
+    pub spellcheck: bool,
+    pub apply_complexity: bool,
+    pub no_cache: bool,
+    pub risk: bool,
+    /// `text` or `json`
+    pub format: String,
+    pub max_errors: Option<usize>,
+    pub max_warnings: Option<usize>,
+}
+
+impl Default for LintOptions {
+    fn default() -> Self {
+        Self {
+            verbose: false,
+            area: None,
+            check_links: false,
+            spellcheck: false,
+            apply_complexity: false,
+            no_cache: false,
+            risk: false,
+            format: "text".to_string(),
+            max_errors: None,
+            max_warnings: None,
+        }
+    }
+}
 
-pub fn run(verbose: bool, area: Option<String>) -> Result<()> {
+pub fn run(options: LintOptions) -> Result<()> {
+    let LintOptions {
+        verbose,
+        area,
+        check_links,
+        spellcheck,
+        apply_complexity,
+        no_cache,
+        risk,
+        format,
+        max_errors,
+        max_warnings,
+    } = options;
+    let json = OutputFormat::parse(&format)? == OutputFormat::Json;
     let taskguard_root =
         find_taskguard_root().context("Not in a TaskGuard project. Run 'taskguard init' first.")?;
 
@@ -25,14 +145,13 @@ pub fn run(verbose: bool, area: Option<String>) -> Result<()> {
         if entry.file_type().is_file()
             && entry.path().extension().and_then(|s| s.to_str()) == Some("md")
         {
-            // Filter by area if specified
+            // Filter by area if specified; nested area directories match their parents
             if let Some(ref filter_area) = area
                 && let Some(area_dir) = entry
                     .path()
                     .parent()
-                    .and_then(|p| p.file_name())
-                    .and_then(|n| n.to_str())
-                && area_dir != filter_area
+                    .and_then(|p| p.strip_prefix(&tasks_dir).ok())
+                && !area_matches(&area_dir.to_string_lossy().replace('\\', "/"), filter_area)
             {
                 continue;
             }
@@ -41,7 +160,7 @@ pub fn run(verbose: bool, area: Option<String>) -> Result<()> {
                 Ok(task) => tasks.push(task),
                 Err(_) => {
                     parse_errors += 1;
-                    if verbose {
+                    if verbose && !json {
                         println!("⚠️  Skipping {}: Failed to parse", entry.path().display());
                     }
                 }
@@ -49,19 +168,122 @@ pub fn run(verbose: bool, area: Option<String>) -> Result<()> {
         }
     }
 
-    if tasks.is_empty() && parse_errors == 0 {
+    if tasks.is_empty() && parse_errors == 0 && !json {
         println!("📋 No task files found in {}", tasks_dir.display());
         return Ok(());
     }
 
-    // Analyze all tasks
-    let analyzer = TaskAnalyzer::new();
-    let analyses = analyzer.analyze_all_tasks(&tasks);
+    // Analyze all tasks with the project's [lint] thresholds
+    let config = Config::load_or_default(get_config_path()?)?;
+    let analyzer = TaskAnalyzer::from_config(config.lint.as_ref());
+    let mut cache = (!no_cache).then(|| AnalysisCache::load(&taskguard_root, config.lint.as_ref()));
+    let mut analyses = match cache.as_mut() {
+        Some(cache) => tasks
+            .iter()
+            .map(|task| cache.analyze(&analyzer, task, &taskguard_root))
+            .collect(),
+        None => analyzer.analyze_all_tasks(&tasks),
+    };
+    if let Some(cache) = cache.as_mut() {
+        cache.save(&taskguard_root)?;
+    }
+    if check_links {
+        for (task, analysis) in tasks.iter().zip(&mut analyses) {
+            for issue in task.link_issues(&taskguard_root) {
+                analysis.issues.push(LintIssue {
+                    severity: Severity::Warning,
+                    category: IssueCategory::Completeness,
+                    rule: "broken-link".to_string(),
+                    message: format!("Broken link: {}", issue),
+                    suggestion: Some("Fix or remove the entry in links:".to_string()),
+                    line: None,
+                });
+            }
+            analysis.apply_ignores(&task.content);
+        }
+    }
+    if spellcheck || config.lint.as_ref().and_then(|l| l.spellcheck) == Some(true) {
+        let checker = SpellChecker::load(&taskguard_root)?;
+        for (task, analysis) in tasks.iter().zip(&mut analyses) {
+            let title = checker.check(&task.title).into_iter().map(|m| (m, "title"));
+            let body = checker
+                .check(&task.content)
+                .into_iter()
+                .map(|m| (m, "body"));
+            for (misspelling, place) in title.chain(body) {
+                let location = if place == "title" {
+                    "title".to_string()
+                } else {
+                    format!("body line {}", misspelling.line)
+                };
+                analysis.issues.push(LintIssue {
+                    severity: Severity::Info,
+                    category: IssueCategory::Spelling,
+                    rule: "typo".to_string(),
+                    message: format!("Possible typo '{}' in {}", misspelling.word, location),
+                    suggestion: Some(format!(
+                        "Did you mean '{}'? If it's intended, add it to {}",
+                        misspelling.suggestion, DICTIONARY_FILE
+                    )),
+                    line: (place == "body").then_some(misspelling.line),
+                });
+            }
+            analysis.apply_ignores(&task.content);
+        }
+    }
+    // Link and spelling issues are added after analysis, so map them too
+    for analysis in &mut analyses {
+        analyzer.apply_severity(&mut analysis.issues);
+    }
+
+    // Write scores into tasks that don't have a complexity yet; manual values stay
+    let mut applied = Vec::new();
+    if apply_complexity {
+        for (task, analysis) in tasks.iter_mut().zip(&analyses) {
+            if task.complexity.is_some() {
+                continue;
+            }
+            task.complexity = Some(analysis.suggested_complexity());
+            task.save_to_file(&task.file_path)
+                .with_context(|| format!("Failed to update {}", task.file_path.display()))?;
+            applied.push(format!("{} → {}", task.id, analysis.suggested_complexity()));
+        }
+    }
+
+    // Risk needs the whole project (dependents, workload), but only shows the linted tasks
+    let risks = if risk {
+        let linted: HashSet<&str> = tasks.iter().map(|t| t.id.as_str()).collect();
+        let today = chrono::Local::now().date_naive();
+        Some(
+            risk::assess(&load_all_tasks()?, &analyzer, today)
+                .into_iter()
+                .filter(|r| linted.contains(r.task_id.as_str()))
+                .collect::<Vec<_>>(),
+        )
+    } else {
+        None
+    };
+
+    if json {
+        let mut report = json_report(&taskguard_root, &tasks, &analyses, &analyzer, parse_errors);
+        report.risk = risks;
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return check_thresholds(
+            report.summary.errors,
+            report.summary.warnings,
+            max_errors,
+            max_warnings,
+        );
+    }
+
     let summary = analyzer.generate_summary(&analyses);
 
     // Print header
     println!("🔍 TASK ANALYSIS REPORT");
     println!("   ═══════════════════════");
+    for error in &analyzer.rule_errors {
+        println!("⚠️  {}", error);
+    }
 
     // Print summary
     println!();
@@ -81,12 +303,34 @@ pub fn run(verbose: bool, area: Option<String>) -> Result<()> {
     if parse_errors > 0 {
         println!("   Parse errors: {}", parse_errors);
     }
+    if verbose && let Some(cache) = &cache {
+        println!(
+            "   Cached: {} of {} tasks unchanged since the last run",
+            cache.hits,
+            tasks.len()
+        );
+    }
+
+    let accuracy = EstimateAccuracy::from_tasks(&tasks);
+    if accuracy.is_chronic_underestimation() {
+        println!();
+        println!("⏱️  ESTIMATES");
+        println!(
+            "   ⚠️  {} of {} tracked tasks ran over; logged time is {:.1}x the estimates",
+            accuracy.overruns,
+            accuracy.samples,
+            accuracy.ratio().unwrap_or_default()
+        );
+        println!("      💡 Estimates are consistently low - consider padding new ones");
+    }
 
     // Print issues by category
     if !summary.issues_by_category.is_empty() {
         println!();
         println!("📋 ISSUES BY CATEGORY");
-        for (category, count) in &summary.issues_by_category {
+        let mut categories: Vec<_> = summary.issues_by_category.iter().collect();
+        categories.sort();
+        for (category, count) in categories {
             println!("   {}: {}", category, count);
         }
     }
@@ -107,6 +351,19 @@ pub fn run(verbose: bool, area: Option<String>) -> Result<()> {
             analysis.task_id, analysis.complexity_score, analysis.quality_score
         );
 
+        // Silenced issues stay visible with --verbose
+        if verbose && !analysis.ignored.is_empty() {
+            println!("   🙈 Ignored by lint-ignore directive:");
+            for issue in &analysis.ignored {
+                println!(
+                    "      [{}] [{}] {}",
+                    issue.severity,
+                    issue.category.name(),
+                    issue.message
+                );
+            }
+        }
+
         if analysis.issues.is_empty() {
             if verbose {
                 println!("   ✅ No issues found");
@@ -147,6 +404,8 @@ pub fn run(verbose: bool, area: Option<String>) -> Result<()> {
         }
     }
 
+    let ignored_count: usize = analyses.iter().map(|a| a.ignored.len()).sum();
+
     // Print final summary
     println!();
     println!("🏁 LINT SUMMARY");
@@ -163,6 +422,13 @@ pub fn run(verbose: bool, area: Option<String>) -> Result<()> {
             println!("   ℹ️  Info: {}", info_count);
         }
     }
+    if ignored_count > 0 {
+        println!(
+            "   🙈 Ignored: {} (by lint-ignore directives{})",
+            ignored_count,
+            if verbose { "" } else { ", see --verbose" }
+        );
+    }
 
     // Recommendations
     if summary.high_complexity_count > 0 {
@@ -177,18 +443,170 @@ pub fn run(verbose: bool, area: Option<String>) -> Result<()> {
         }
     }
 
+    if let Some(risks) = &risks {
+        println!();
+        println!("🎲 RISKIEST TASKS");
+        if risks.is_empty() {
+            println!("   No open tasks");
+        }
+        for risk in risks.iter().take(RISK_LIMIT) {
+            let factors = risk.factors();
+            println!(
+                "   {:>4.1}  {}{}",
+                risk.score,
+                risk.task_id,
+                if factors.is_empty() {
+                    String::new()
+                } else {
+                    format!(" - {}", factors.join(", "))
+                }
+            );
+        }
+        if risks.len() > RISK_LIMIT {
+            println!(
+                "   ... and {} more (see --format json)",
+                risks.len() - RISK_LIMIT
+            );
+        }
+    }
+
+    if apply_complexity {
+        println!();
+        println!("✍️  COMPLEXITY APPLIED");
+        if applied.is_empty() {
+            println!("   All tasks already have a complexity");
+        } else {
+            println!(
+                "   Set complexity on {} task(s): {}",
+                applied.len(),
+                applied.join(", ")
+            );
+        }
+    }
+
+    check_thresholds(error_count, warning_count, max_errors, max_warnings)
+}
+
+/// CI gate: fail like clippy when counts go over the allowed maximum
+fn check_thresholds(
+    error_count: usize,
+    warning_count: usize,
+    max_errors: Option<usize>,
+    max_warnings: Option<usize>,
+) -> Result<()> {
+    let exceeded: Vec<String> = [
+        ("error", error_count, max_errors),
+        ("warning", warning_count, max_warnings),
+    ]
+    .into_iter()
+    .filter_map(|(kind, count, max)| {
+        max.filter(|max| count > *max)
+            .map(|max| format!("{} {}(s) (max {})", count, kind, max))
+    })
+    .collect();
+    if !exceeded.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Lint thresholds exceeded: {}",
+            exceeded.join(", ")
+        ));
+    }
+
     Ok(())
 }
 
+fn json_report<'a>(
+    root: &Path,
+    tasks: &'a [Task],
+    analyses: &'a [TaskAnalysis],
+    analyzer: &'a TaskAnalyzer,
+    parse_errors: usize,
+) -> LintReport<'a> {
+    let mut summary = ReportSummary {
+        tasks: tasks.len(),
+        errors: 0,
+        warnings: 0,
+        info: 0,
+        ignored: 0,
+        parse_errors,
+    };
+    let reports = tasks
+        .iter()
+        .zip(analyses)
+        .map(|(task, analysis)| {
+            let text = fs::read_to_string(&task.file_path).unwrap_or_default();
+            let body_start = body_start_line(&text).unwrap_or(1);
+            let title_line = text
+                .lines()
+                .position(|l| l.starts_with("title:") || l.starts_with("title ="))
+                .map(|i| i + 1);
+
+            summary.ignored += analysis.ignored.len();
+            let issues = analysis
+                .issues
+                .iter()
+                .map(|issue| {
+                    match issue.severity {
+                        Severity::Error => summary.errors += 1,
+                        Severity::Warning => summary.warnings += 1,
+                        Severity::Info => summary.info += 1,
+                    }
+                    // Naming issues and title typos (the only spelling issues without a
+                    // body line) point at the title
+                    let line = match (issue.line, &issue.category) {
+                        (Some(line), _) => Some(body_start + line - 1),
+                        (None, IssueCategory::Naming | IssueCategory::Spelling) => title_line,
+                        (None, _) => None,
+                    };
+                    IssueReport {
+                        severity: &issue.severity,
+                        category: issue.category.name(),
+                        rule: &issue.rule,
+                        message: &issue.message,
+                        suggestion: issue.suggestion.as_deref(),
+                        line,
+                    }
+                })
+                .collect();
+
+            TaskReport {
+                id: &task.id,
+                file: task
+                    .file_path
+                    .strip_prefix(root)
+                    .unwrap_or(&task.file_path)
+                    .to_string_lossy()
+                    .replace('\\', "/"),
+                complexity_score: analysis.complexity_score,
+                quality_score: analysis.quality_score,
+                issues,
+            }
+        })
+        .collect();
+
+    LintReport {
+        version: 1,
+        summary,
+        rule_errors: &analyzer.rule_errors,
+        tasks: reports,
+        risk: None,
+    }
+}
+
 pub fn run_single_task<P: AsRef<Path>>(task_path: P, _verbose: bool) -> Result<()> {
     let task = Task::from_file(&task_path)
         .with_context(|| format!("Failed to load task from {}", task_path.as_ref().display()))?;
 
-    let analyzer = TaskAnalyzer::new();
+    let config = get_config_path()
+        .ok()
+        .and_then(|path| Config::load_or_default(path).ok());
+    let analyzer = TaskAnalyzer::from_config(config.as_ref().and_then(|c| c.lint.as_ref()));
     let analysis = analyzer.analyze_task(&task);
 
     println!("🔍 TASK ANALYSIS: {}", task.id);
     println!("   ═══════════════════════");
+    for error in &analyzer.rule_errors {
+        println!("⚠️  {}", error);
+    }
     println!();
     println!("📊 SCORES");
     println!("   Complexity: {:.1}/10", analysis.complexity_score);
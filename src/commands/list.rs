@@ -1,18 +1,35 @@
 use anyhow::Result;
-use std::collections::HashMap;
-use std::path::Path;
+use std::collections::{HashMap, HashSet};
 use walkdir::WalkDir;
 
-use crate::config::{find_taskguard_root, get_tasks_dir, load_tasks_from_dir};
-use crate::task::{Task, TaskStatus};
-use regex::Regex;
+use crate::checklist;
+use crate::commands::update::find_task_file;
+use crate::config::{
+    AgingConfig, Config, find_taskguard_root, get_config_path, get_tasks_dir, load_tasks_from_dir,
+};
+use crate::task::{Task, TaskStatus, area_matches, parse_due_date};
 
 pub fn run(
     status_filter: Option<String>,
     area_filter: Option<String>,
+    assignee_filter: Option<String>,
     include_archive: bool,
+    due_before: Option<String>,
+    overdue_only: bool,
+    columns: Option<String>,
 ) -> Result<()> {
     let tasks_dir = get_tasks_dir()?;
+    let due_before = due_before.map(|d| parse_due_date(&d)).transpose()?;
+    let today = chrono::Local::now().date_naive();
+    let aging = Config::load_or_default(get_config_path()?)?.aging;
+    let columns: Vec<String> = columns
+        .map(|c| {
+            c.split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
 
     if !tasks_dir.exists() {
         println!("📁 No tasks directory found. Run 'taskguard init' first.");
@@ -58,13 +75,29 @@ pub fn run(
         tasks.extend(archived_tasks);
     }
 
+    // Dependency state is needed for risk markers even when filters hide the dependency
+    let incomplete = incomplete_ids(&tasks);
+
     // Apply filters
     if let Some(status) = &status_filter {
         tasks.retain(|task| task.status.to_string() == status.to_lowercase());
     }
 
+    // Parent areas include their nested areas (`backend` matches `backend/auth`)
     if let Some(area) = &area_filter {
-        tasks.retain(|task| task.area == *area);
+        tasks.retain(|task| area_matches(&task.area, area));
+    }
+
+    if let Some(assignee) = assignee_filter {
+        tasks.retain(|task| task.assignees.contains(&assignee));
+    }
+
+    if let Some(cutoff) = due_before {
+        tasks.retain(|task| task.due.is_some_and(|due| due < cutoff));
+    }
+
+    if overdue_only {
+        tasks.retain(|task| task.is_overdue(today));
     }
 
     // Show warnings
@@ -94,15 +127,13 @@ pub fn run(
         println!("   {}", "─".repeat(area.len() + 4));
 
         for task in area_tasks {
-            let status_icon = match task.status {
-                TaskStatus::Todo => "⭕",
-                TaskStatus::Doing => "🔄",
-                TaskStatus::Review => "👀",
-                TaskStatus::Done => "✅",
-                TaskStatus::Blocked => "🚫",
-            };
+            let status_icon = status_icon(&task.status);
 
-            let priority_icon = match task.priority {
+            let priority = aging.as_ref().map_or_else(
+                || task.priority.clone(),
+                |a| a.effective_priority(task, today),
+            );
+            let priority_icon = match priority {
                 crate::task::Priority::Critical => "🔴",
                 crate::task::Priority::High => "🟠",
                 crate::task::Priority::Medium => "🟡",
@@ -116,11 +147,42 @@ pub fn run(
                 ""
             };
 
+            let progress = task
+                .checklist_progress()
+                .filter(|_| task.status != TaskStatus::Done)
+                .map(|(done, total)| {
+                    format!(" [{}/{} items, {}%]", done, total, done * 100 / total)
+                })
+                .unwrap_or_default();
+
             println!(
-                "   {}{} {} {} {}",
-                archive_indicator, status_icon, priority_icon, task.id, task.title
+                "   {}{} {} {} {}{}{}{}{}",
+                archive_indicator,
+                status_icon,
+                priority_icon,
+                task.id,
+                task.title,
+                progress,
+                due_indicator(task, today),
+                risk_indicator(&task.deadline_risks(&incomplete, today)),
+                aging_indicator(task, aging.as_ref(), today)
             );
 
+            // Show requested columns (built-in or custom fields)
+            if !columns.is_empty() {
+                let values: Vec<String> = columns
+                    .iter()
+                    .map(|col| {
+                        format!(
+                            "{}: {}",
+                            col,
+                            task.field_display(col).unwrap_or_else(|| "-".to_string())
+                        )
+                    })
+                    .collect();
+                println!("      │ {}", values.join(" │ "));
+            }
+
             // Show dependencies if any
             if !task.dependencies.is_empty() {
                 println!("      └── Depends on: {}", task.dependencies.join(", "));
@@ -157,17 +219,160 @@ pub fn run(
     Ok(())
 }
 
+/// List tasks with subtasks nested under their parent
+pub fn run_tree(status_filter: Option<&str>, area_filter: Option<&str>) -> Result<()> {
+    let tasks_dir = get_tasks_dir()?;
+
+    if !tasks_dir.exists() {
+        println!("📁 No tasks directory found. Run 'taskguard init' first.");
+        return Ok(());
+    }
+
+    let all_tasks = load_tasks_from_dir(&tasks_dir)?;
+    let visible: Vec<&Task> = all_tasks
+        .iter()
+        .filter(|t| status_filter.is_none_or(|s| t.status.to_string() == s.to_lowercase()))
+        .filter(|t| area_filter.is_none_or(|a| area_matches(&t.area, a)))
+        .collect();
+
+    if visible.is_empty() {
+        println!("📋 No tasks match the specified filters.");
+        return Ok(());
+    }
+
+    let visible_ids: HashSet<&str> = visible.iter().map(|t| t.id.as_str()).collect();
+    let mut children: HashMap<&str, Vec<&Task>> = HashMap::new();
+    let mut roots = Vec::new();
+    for task in &visible {
+        match task.parent() {
+            Some(parent) if visible_ids.contains(parent) => {
+                children.entry(parent).or_default().push(task);
+            }
+            _ => roots.push(*task),
+        }
+    }
+    roots.sort_by_key(|t| &t.id);
+    for siblings in children.values_mut() {
+        siblings.sort_by_key(|t| &t.id);
+    }
+
+    println!("🌳 TASK TREE");
+    let mut printed = HashSet::new();
+    for root in roots {
+        print_tree_node(root, 0, &children, &all_tasks, &mut printed);
+    }
+
+    // Tasks only reachable through a parent cycle never appear under a root
+    for task in &visible {
+        if !printed.contains(task.id.as_str()) {
+            print_tree_node(task, 0, &children, &all_tasks, &mut printed);
+        }
+    }
+
+    Ok(())
+}
+
+fn print_tree_node<'a>(
+    task: &'a Task,
+    depth: usize,
+    children: &HashMap<&str, Vec<&'a Task>>,
+    all_tasks: &[Task],
+    printed: &mut HashSet<&'a str>,
+) {
+    if !printed.insert(task.id.as_str()) {
+        return;
+    }
+
+    let progress = task
+        .subtask_progress(all_tasks)
+        .map(|(done, total)| format!(" [{}/{} subtasks done]", done, total))
+        .unwrap_or_default();
+    println!(
+        "   {}{} {} {}{}",
+        "    ".repeat(depth),
+        status_icon(&task.status),
+        task.id,
+        task.title,
+        progress
+    );
+
+    for child in children.get(task.id.as_str()).into_iter().flatten() {
+        print_tree_node(child, depth + 1, children, all_tasks, printed);
+    }
+}
+
+pub(crate) fn status_icon(status: &TaskStatus) -> &'static str {
+    match status {
+        TaskStatus::Todo => "⭕",
+        TaskStatus::Doing => "🔄",
+        TaskStatus::Review => "👀",
+        TaskStatus::Done => "✅",
+        TaskStatus::Blocked => "🚫",
+    }
+}
+
+/// Suffix flagging overdue or soon-due tasks in listings
+pub fn due_indicator(task: &Task, today: chrono::NaiveDate) -> String {
+    match (task.due, task.days_until_due(today)) {
+        (Some(due), Some(_)) if task.is_overdue(today) => format!(" 🔥 OVERDUE (due {})", due),
+        (Some(due), Some(days)) if task.is_due_soon(today) => {
+            let when = match days {
+                0 => "today".to_string(),
+                1 => "tomorrow".to_string(),
+                n => format!("in {} days", n),
+            };
+            format!(" ⏰ due {} ({})", when, due)
+        }
+        _ => String::new(),
+    }
+}
+
+/// IDs of tasks that are not done yet, for deadline risk checks
+pub fn incomplete_ids(tasks: &[Task]) -> HashSet<String> {
+    tasks
+        .iter()
+        .filter(|t| t.status != TaskStatus::Done)
+        .map(|t| t.id.clone())
+        .collect()
+}
+
+/// Suffix flagging tasks likely to miss their due date (see `Task::deadline_risks`)
+pub fn risk_indicator(risks: &[String]) -> &'static str {
+    if risks.is_empty() {
+        ""
+    } else {
+        " ⚠️  at risk"
+    }
+}
+
+/// Suffix flagging tasks that have waited in `todo` past the `[aging]` threshold
+pub fn aging_indicator(
+    task: &Task,
+    aging: Option<&AgingConfig>,
+    today: chrono::NaiveDate,
+) -> String {
+    let Some((aging, age)) = aging.and_then(|a| a.age(task, today).map(|age| (a, age))) else {
+        return String::new();
+    };
+    let escalated = aging.effective_priority(task, today);
+    if escalated == task.priority {
+        format!(" ⏳ aging {}d", age)
+    } else {
+        format!(" ⏳ aging {}d ({} → {})", age, task.priority, escalated)
+    }
+}
+
 pub fn run_items(task_id: String) -> Result<()> {
     let tasks_dir = get_tasks_dir()?;
 
-    // Find the task file using the same logic as update command
+    // Find the task file (nested areas included)
     let task_file_path = find_task_file(&tasks_dir, &task_id)?;
 
     // Load the task
     let task = Task::from_file(&task_file_path)?;
 
     // Parse checklist items from the task content
-    let items = parse_checklist_items(&task.content)?;
+    let items = checklist::parse_items(&task.content);
 
     if items.is_empty() {
         println!("📋 No checklist items found in task {}", task_id);
@@ -178,82 +383,44 @@ pub fn run_items(task_id: String) -> Result<()> {
     println!("   {}", task.title);
     println!();
 
-    for (index, item) in items.iter().enumerate() {
+    let today = chrono::Local::now().date_naive();
+    for item in &items {
         let status_icon = if item.completed { "✅" } else { "⭕" };
         let status_text = if item.completed { "done" } else { "todo" };
+        let metadata = item
+            .metadata_display()
+            .map(|m| format!(" ({})", m))
+            .unwrap_or_default();
+        let overdue = if item.is_overdue(today) {
+            " 🔥 OVERDUE"
+        } else {
+            ""
+        };
 
         println!(
-            "   {}. {} [{}] {}",
-            index + 1,
+            "   {}{}. {} [{}] {}{}{}",
+            "   ".repeat(item.depth()),
+            item.label(),
             status_icon,
             status_text,
-            item.text
+            item.text,
+            metadata,
+            overdue
         );
     }
 
     println!();
     println!("📊 SUMMARY");
-    println!("   Total items: {}", items.len());
-    let completed = items.iter().filter(|i| i.completed).count();
+    // Parent items follow their sub-items, so only leaves are counted
+    let leaves: Vec<_> = checklist::leaf_items(&items).collect();
+    println!("   Total items: {}", leaves.len());
+    let completed = leaves.iter().filter(|i| i.completed).count();
     println!(
         "   Completed: {} ({:.1}%)",
         completed,
-        completed as f32 / items.len() as f32 * 100.0
+        completed as f32 / leaves.len() as f32 * 100.0
     );
-    println!("   Remaining: {}", items.len() - completed);
+    println!("   Remaining: {}", leaves.len() - completed);
 
     Ok(())
 }
-
-#[derive(Debug, Clone)]
-pub struct ChecklistItem {
-    pub text: String,
-    pub completed: bool,
-    #[allow(dead_code)] // Reserved for future line-specific editing features
-    pub line_number: usize,
-}
-
-fn parse_checklist_items(content: &str) -> Result<Vec<ChecklistItem>> {
-    let mut items = Vec::new();
-
-    // Regex to match checklist items like "- [ ] text" or "- [x] text"
-    let checkbox_regex = Regex::new(r"^(\s*)-\s*\[([x\s])\]\s*(.+)$")?;
-
-    for (line_number, line) in content.lines().enumerate() {
-        if let Some(captures) = checkbox_regex.captures(line) {
-            let checkbox_state = captures.get(2).unwrap().as_str().trim();
-            let text = captures.get(3).unwrap().as_str().trim();
-
-            let completed = matches!(checkbox_state, "x" | "X");
-
-            items.push(ChecklistItem {
-                text: text.to_string(),
-                completed,
-                line_number: line_number + 1, // 1-based for user display
-            });
-        }
-    }
-
-    Ok(items)
-}
-
-// Helper function to find task file (similar to update.rs)
-fn find_task_file(tasks_dir: &Path, task_id: &str) -> Result<std::path::PathBuf> {
-    // Extract area from task ID (e.g., "backend-001" -> "backend")
-    let area = task_id
-        .split('-')
-        .next()
-        .ok_or_else(|| anyhow::anyhow!("Invalid task ID format. Expected format: area-number"))?;
-
-    let area_dir = tasks_dir.join(area);
-    let task_file = area_dir.join(format!("{}.md", task_id));
-
-    if !task_file.exists() {
-        return Err(anyhow::anyhow!(
-            "Task file not found: {}. Available tasks can be seen with 'taskguard list'",
-            task_file.display()
-        ));
-    }
-
-    Ok(task_file)
-}
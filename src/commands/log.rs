@@ -0,0 +1,45 @@
+use anyhow::{Context, Result};
+use std::env;
+
+use crate::git::GitAnalyzer;
+
+/// Print the commits among the last `limit` that reference the task, found the
+/// way `sync` finds them (trailers, mentions, conventional commit scopes)
+pub fn run(task_id: &str, limit: usize, patch: bool, files: bool) -> Result<()> {
+    let current_dir = env::current_dir().context("Failed to get current directory")?;
+    let git_analyzer = GitAnalyzer::new(&current_dir)
+        .context("Failed to initialize Git analyzer. Make sure you're in a Git repository.")?;
+    let commits = git_analyzer.task_commits(task_id, Some(limit))?;
+
+    if commits.is_empty() {
+        println!(
+            "📜 No commits among the last {} reference {}",
+            limit, task_id
+        );
+        return Ok(());
+    }
+
+    println!("📜 {} commit(s) referencing {}", commits.len(), task_id);
+    for commit in &commits {
+        println!();
+        println!("commit {}", commit.oid);
+        println!("Author: {}", commit.author);
+        println!("Date:   {}", commit.timestamp.format("%Y-%m-%d %H:%M UTC"));
+        println!();
+        for line in commit.message.trim_end().lines() {
+            println!("    {}", line);
+        }
+
+        if files {
+            println!();
+            for path in git_analyzer.commit_files(&commit.oid)? {
+                println!("    {}", path);
+            }
+        }
+        if patch {
+            println!();
+            print!("{}", git_analyzer.commit_patch(&commit.oid)?);
+        }
+    }
+    Ok(())
+}
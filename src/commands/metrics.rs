@@ -0,0 +1,497 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+
+use crate::analysis::EstimateAccuracy;
+use crate::config::{find_taskguard_root, load_all_tasks};
+use crate::git::{GitAnalyzer, TaskActivity, TaskCommit, TaskRevision};
+use crate::task::{
+    Task, TaskStatus, area_matches, format_estimate, parse_due_date, working_minutes_until,
+};
+
+/// Time a task spent in `doing` according to its committed versions
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DoingTime {
+    /// When the task first moved to `doing`
+    pub started: Option<DateTime<Utc>>,
+    /// Total time in `doing`, counting only spans that have ended
+    pub total: Duration,
+}
+
+/// Replay a task's revisions, oldest first, and measure its `doing` spans
+///
+/// A span still open in the last revision ends at `completed`, for tasks
+/// finished without committing the status change.
+pub fn doing_time(revisions: &[TaskRevision], completed: Option<DateTime<Utc>>) -> DoingTime {
+    let mut time = DoingTime::default();
+    let mut since: Option<DateTime<Utc>> = None;
+    for revision in revisions {
+        match (revision.task.status == TaskStatus::Doing, since) {
+            (true, None) => {
+                since = Some(revision.timestamp);
+                time.started.get_or_insert(revision.timestamp);
+            }
+            (false, Some(start)) => {
+                time.total += revision.timestamp - start;
+                since = None;
+            }
+            _ => {}
+        }
+    }
+    if let (Some(start), Some(end)) = (since, completed) {
+        time.total += end - start;
+    }
+    time
+}
+
+/// Running totals for one area
+#[derive(Debug, Default)]
+struct AreaMetrics {
+    done: usize,
+    lead: Average,
+    cycle: Average,
+    doing: Average,
+}
+
+#[derive(Debug, Default)]
+struct Average {
+    total: Duration,
+    count: i32,
+}
+
+impl Average {
+    fn add(&mut self, duration: Duration) {
+        self.total += duration;
+        self.count += 1;
+    }
+
+    fn display(&self) -> String {
+        if self.count == 0 {
+            return "-".to_string();
+        }
+        format_days(self.total / self.count)
+    }
+}
+
+/// Calendar time in days with one decimal, or hours below a day
+fn format_days(duration: Duration) -> String {
+    let hours = duration.num_minutes() as f64 / 60.0;
+    if hours < 24.0 {
+        format!("{:.1}h", hours)
+    } else {
+        format!("{:.1}d", hours / 24.0)
+    }
+}
+
+/// Monday of the ISO week containing `date`
+fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(i64::from(date.weekday().num_days_from_monday()))
+}
+
+pub fn run(area: Option<&str>, weeks: u32) -> Result<()> {
+    let all_tasks = load_all_tasks().context("Failed to load tasks")?;
+    let tasks: Vec<&Task> = all_tasks
+        .iter()
+        .filter(|t| area.is_none_or(|a| area_matches(&t.area, a)))
+        .collect();
+
+    // Time in `doing` needs status history; without git only timestamps are used
+    let histories = find_taskguard_root()
+        .and_then(|root| GitAnalyzer::new(root).ok())
+        .map(|git| git.all_task_histories())
+        .transpose()?;
+
+    let mut by_area: BTreeMap<&str, AreaMetrics> = BTreeMap::new();
+    let mut overall = AreaMetrics::default();
+    let mut per_week: HashMap<NaiveDate, usize> = HashMap::new();
+
+    for task in &tasks {
+        let completed = task.completed_at();
+        let doing = histories
+            .as_ref()
+            .and_then(|h| h.get(&task.id))
+            .map(|revisions| doing_time(revisions, completed))
+            .unwrap_or_default();
+
+        for metrics in [by_area.entry(task.area.as_str()).or_default(), &mut overall] {
+            if doing.total > Duration::zero() {
+                metrics.doing.add(doing.total);
+            }
+            if let Some(completed) = completed {
+                metrics.done += 1;
+                metrics.lead.add(completed - task.created);
+                if let Some(started) = doing.started.filter(|s| *s <= completed) {
+                    metrics.cycle.add(completed - started);
+                }
+            }
+        }
+        if let Some(completed) = completed {
+            *per_week
+                .entry(week_start(completed.date_naive()))
+                .or_default() += 1;
+        }
+    }
+
+    println!(
+        "📈 DELIVERY METRICS{}",
+        area.map(|a| format!(" - {}", a)).unwrap_or_default()
+    );
+
+    println!();
+    println!("🚚 THROUGHPUT (tasks completed per week)");
+    let this_week = week_start(Utc::now().date_naive());
+    let mut completed_in_range = 0;
+    for i in (0..weeks).rev() {
+        let start = this_week - Duration::weeks(i64::from(i));
+        let count = per_week.get(&start).copied().unwrap_or(0);
+        completed_in_range += count;
+        println!(
+            "   {}-W{:02}  {:>3} {}",
+            start.iso_week().year(),
+            start.iso_week().week(),
+            count,
+            "█".repeat(count)
+        );
+    }
+    println!(
+        "   Average: {:.1} per week over {} weeks",
+        completed_in_range as f64 / f64::from(weeks.max(1)),
+        weeks
+    );
+
+    println!();
+    println!("⏱️  FLOW TIMES (averages)");
+    println!(
+        "   {:<20} {:>5} {:>10} {:>10} {:>10}",
+        "Area", "Done", "Lead", "Cycle", "In doing"
+    );
+    for (name, metrics) in by_area.iter().chain([(&"All areas", &overall)]) {
+        println!(
+            "   {:<20} {:>5} {:>10} {:>10} {:>10}",
+            name,
+            metrics.done,
+            metrics.lead.display(),
+            metrics.cycle.display(),
+            metrics.doing.display()
+        );
+    }
+
+    println!();
+    println!("   Lead time: created → done. Cycle time: first moved to doing → done.");
+    if histories.is_none() {
+        println!("   ⚠️  Not a git repository: cycle time and time in doing are unavailable");
+    }
+
+    Ok(())
+}
+
+/// Output format for `metrics --burndown`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    pub fn parse(value: &str) -> Result<Self> {
+        match value {
+            "csv" => Ok(ExportFormat::Csv),
+            "json" => Ok(ExportFormat::Json),
+            _ => Err(anyhow::anyhow!(
+                "Invalid format '{}'. Valid values: csv, json",
+                value
+            )),
+        }
+    }
+}
+
+/// Task counts by status at the end of one day
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct DailyCounts {
+    pub date: NaiveDate,
+    pub todo: usize,
+    pub doing: usize,
+    pub review: usize,
+    pub blocked: usize,
+    pub done: usize,
+    /// Everything not done: the burndown line
+    pub open: usize,
+    pub total: usize,
+}
+
+impl DailyCounts {
+    fn add(&mut self, status: &TaskStatus) {
+        match status {
+            TaskStatus::Todo => self.todo += 1,
+            TaskStatus::Doing => self.doing += 1,
+            TaskStatus::Review => self.review += 1,
+            TaskStatus::Blocked => self.blocked += 1,
+            TaskStatus::Done => self.done += 1,
+        }
+        self.total += 1;
+        self.open = self.total - self.done;
+    }
+}
+
+/// Daily status counts from `since` through `until`, replaying each task's
+/// committed versions (oldest first)
+///
+/// A task counts from its first commit; each day it has the status of its
+/// last version committed by the end of that day. `area` is matched against
+/// that version, so tasks moved between areas follow the move.
+pub fn daily_counts(
+    histories: &[Vec<TaskRevision>],
+    area: Option<&str>,
+    since: NaiveDate,
+    until: NaiveDate,
+) -> Vec<DailyCounts> {
+    since
+        .iter_days()
+        .take_while(|day| *day <= until)
+        .map(|date| {
+            let end_of_day = (date + Duration::days(1))
+                .and_hms_opt(0, 0, 0)
+                .map_or_else(Utc::now, |dt| dt.and_utc());
+            let mut counts = DailyCounts {
+                date,
+                ..DailyCounts::default()
+            };
+            for revisions in histories {
+                let committed = revisions.partition_point(|r| r.timestamp < end_of_day);
+                if let Some(revision) = committed.checked_sub(1).map(|i| &revisions[i])
+                    && area.is_none_or(|a| area_matches(&revision.task.area, a))
+                {
+                    counts.add(&revision.task.status);
+                }
+            }
+            counts
+        })
+        .collect()
+}
+
+fn render_csv(days: &[DailyCounts]) -> String {
+    let mut lines = vec!["date,todo,doing,review,blocked,done,open,total".to_string()];
+    lines.extend(days.iter().map(|day| {
+        format!(
+            "{},{},{},{},{},{},{},{}",
+            day.date, day.todo, day.doing, day.review, day.blocked, day.done, day.open, day.total
+        )
+    }));
+    lines.join("\n") + "\n"
+}
+
+/// Export daily open/closed counts for burndown and cumulative flow charts
+///
+/// History comes from committed task files; today's row reflects the working
+/// tree so uncommitted status changes show up too.
+pub fn run_burndown(
+    area: Option<&str>,
+    since: Option<&str>,
+    format: &str,
+    output: Option<&Path>,
+) -> Result<()> {
+    let format = ExportFormat::parse(format)?;
+    let today = Utc::now().date_naive();
+    let since = match since {
+        Some(since) => parse_due_date(since)?,
+        None => today - Duration::days(14),
+    };
+    if since > today {
+        return Err(anyhow::anyhow!("--since {} is in the future", since));
+    }
+
+    let root =
+        find_taskguard_root().context("Not in a TaskGuard project. Run 'taskguard init' first.")?;
+    let histories = GitAnalyzer::new(&root)
+        .context("Burndown needs git history. Make sure you're in a Git repository.")?
+        .all_task_histories()?
+        .into_values()
+        .collect::<Vec<_>>();
+
+    let mut days = daily_counts(&histories, area, since, today);
+    if let Some(last) = days.last_mut() {
+        *last = DailyCounts {
+            date: today,
+            ..DailyCounts::default()
+        };
+        for task in load_all_tasks()?
+            .iter()
+            .filter(|t| area.is_none_or(|a| area_matches(&t.area, a)))
+        {
+            last.add(&task.status);
+        }
+    }
+
+    let data = match format {
+        ExportFormat::Csv => render_csv(&days),
+        ExportFormat::Json => serde_json::to_string_pretty(&days)? + "\n",
+    };
+    match output {
+        Some(path) => {
+            fs::write(path, &data)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            println!(
+                "📉 Burndown data for {} days written to {}",
+                days.len(),
+                path.display()
+            );
+        }
+        None => print!("{}", data),
+    }
+
+    Ok(())
+}
+
+/// Working time between the first and last commit mentioning a task
+///
+/// Within one day that's the elapsed time (at most 8h); across days it's 8h
+/// per weekday spanned, matching how `estimate` counts days. `None` with fewer
+/// than two commits.
+pub fn activity_span(commits: &[TaskCommit]) -> Option<u32> {
+    if commits.len() < 2 {
+        return None;
+    }
+    let first = commits.iter().map(|c| c.timestamp).min()?;
+    let last = commits.iter().map(|c| c.timestamp).max()?;
+    if first.date_naive() == last.date_naive() {
+        let minutes = u32::try_from((last - first).num_minutes()).unwrap_or(0);
+        Some(minutes.min(8 * 60))
+    } else {
+        Some(working_minutes_until(first.date_naive(), last.date_naive()))
+    }
+}
+
+/// Estimates of done tasks against their git activity span
+#[derive(Debug, Default)]
+pub struct EstimateReport {
+    pub by_area: BTreeMap<String, EstimateAccuracy>,
+    /// Shared tasks count for each assignee; unassigned ones under "unassigned"
+    pub by_assignee: BTreeMap<String, EstimateAccuracy>,
+    pub overall: EstimateAccuracy,
+}
+
+/// Compare done tasks that have an estimate and at least two commits
+pub fn estimate_report(tasks: &[&Task], activities: &[TaskActivity]) -> EstimateReport {
+    let spans: HashMap<&str, u32> = activities
+        .iter()
+        .filter_map(|a| activity_span(&a.commits).map(|span| (a.task_id.as_str(), span)))
+        .collect();
+
+    let mut report = EstimateReport::default();
+    for task in tasks.iter().filter(|t| t.status == TaskStatus::Done) {
+        let (Some(estimate), Some(span)) = (
+            task.estimate_minutes().filter(|e| *e > 0),
+            spans.get(task.id.as_str()),
+        ) else {
+            continue;
+        };
+        report
+            .by_area
+            .entry(task.area.clone())
+            .or_default()
+            .add(estimate, *span);
+        if task.assignees.is_empty() {
+            report
+                .by_assignee
+                .entry("unassigned".to_string())
+                .or_default()
+                .add(estimate, *span);
+        }
+        for assignee in &task.assignees {
+            report
+                .by_assignee
+                .entry(assignee.clone())
+                .or_default()
+                .add(estimate, *span);
+        }
+        report.overall.add(estimate, *span);
+    }
+    report
+}
+
+fn verdict(accuracy: &EstimateAccuracy) -> &'static str {
+    if accuracy.is_chronic_underestimation() {
+        "⚠️  underestimated"
+    } else if accuracy.is_chronic_overestimation() {
+        "⚠️  overestimated"
+    } else if accuracy.samples < 3 {
+        "too few tasks"
+    } else {
+        "✅ on target"
+    }
+}
+
+fn print_accuracy_rows<'a>(
+    heading: &str,
+    rows: impl Iterator<Item = (&'a str, &'a EstimateAccuracy)>,
+) {
+    println!(
+        "   {:<20} {:>5} {:>10} {:>10} {:>6}  Verdict",
+        heading, "Tasks", "Estimated", "Actual", "Ratio"
+    );
+    for (name, accuracy) in rows {
+        println!(
+            "   {:<20} {:>5} {:>10} {:>10} {:>5.1}x  {}",
+            name,
+            accuracy.samples,
+            format_estimate(accuracy.estimated_minutes),
+            format_estimate(accuracy.actual_minutes),
+            accuracy.ratio().unwrap_or_default(),
+            verdict(accuracy)
+        );
+    }
+}
+
+/// Report systematic over- and underestimation per area and assignee
+pub fn run_estimates(area: Option<&str>) -> Result<()> {
+    let root =
+        find_taskguard_root().context("Not in a TaskGuard project. Run 'taskguard init' first.")?;
+    let activities = GitAnalyzer::new(&root)
+        .context("Estimate accuracy needs git history. Make sure you're in a Git repository.")?
+        .analyze_task_activity(Some(1000))?;
+
+    let all_tasks = load_all_tasks().context("Failed to load tasks")?;
+    let tasks: Vec<&Task> = all_tasks
+        .iter()
+        .filter(|t| area.is_none_or(|a| area_matches(&t.area, a)))
+        .collect();
+
+    let report = estimate_report(&tasks, &activities);
+    if report.overall.samples == 0 {
+        println!("📏 No done tasks with an estimate and at least two commits mentioning them");
+        return Ok(());
+    }
+
+    println!(
+        "📏 ESTIMATE ACCURACY{} ({} done tasks: estimate vs git activity span)",
+        area.map(|a| format!(" - {}", a)).unwrap_or_default(),
+        report.overall.samples
+    );
+    println!();
+    print_accuracy_rows(
+        "Area",
+        report
+            .by_area
+            .iter()
+            .map(|(name, accuracy)| (name.as_str(), accuracy))
+            .chain([("All areas", &report.overall)]),
+    );
+    println!();
+    print_accuracy_rows(
+        "Assignee",
+        report
+            .by_assignee
+            .iter()
+            .map(|(name, accuracy)| (name.as_str(), accuracy)),
+    );
+
+    println!();
+    println!(
+        "   Actual: first to last commit mentioning the task, 8h per weekday. Ratio above 1 means it took longer than estimated."
+    );
+
+    Ok(())
+}
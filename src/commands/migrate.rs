@@ -0,0 +1,219 @@
+use anyhow::{Context, Result};
+use chrono::{SecondsFormat, Utc};
+use serde_yaml::{Mapping, Value};
+use std::fs;
+use std::path::Path;
+use walkdir::WalkDir;
+
+use crate::config::{Config, get_archive_dir, get_config_path, get_tasks_dir};
+use crate::task::{FORMAT_VERSION, FrontmatterFormat, LEGACY_STATUSES, Task, split_frontmatter};
+
+/// Where a task file lives, for filling in fields older versions didn't require
+struct FileContext<'a> {
+    id: &'a str,
+    area: &'a str,
+    body: &'a str,
+}
+
+type Step = fn(&mut Mapping, &FileContext) -> Vec<String>;
+
+/// Upgrade steps; step `i` takes a file from format version `i` to `i + 1`
+const STEPS: [Step; FORMAT_VERSION as usize] = [upgrade_v0];
+
+/// Version 1: rename legacy statuses and write out required fields explicitly
+fn upgrade_v0(fields: &mut Mapping, file: &FileContext) -> Vec<String> {
+    let mut changes = Vec::new();
+
+    if let Some(old) = fields.get("status").and_then(Value::as_str)
+        && let Some((_, new)) = LEGACY_STATUSES.iter().find(|(legacy, _)| *legacy == old)
+    {
+        changes.push(format!("status {} → {}", old, new));
+        fields.insert("status".into(), (*new).into());
+    }
+
+    let title = file
+        .body
+        .lines()
+        .find_map(|line| line.strip_prefix("# "))
+        .map_or(file.id, str::trim);
+    let created = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+    let required = [
+        ("id", file.id),
+        ("title", title),
+        ("area", file.area),
+        ("status", "todo"),
+        ("priority", "medium"),
+        ("created", created.as_str()),
+    ];
+    for (field, default) in required {
+        if matches!(fields.get(field), None | Some(Value::Null)) {
+            changes.push(format!("added {}: {}", field, default));
+            fields.insert(field.into(), default.into());
+        }
+    }
+
+    changes
+}
+
+/// Upgrade a task file to the current format version
+///
+/// Returns the rewritten file and a description of each change, or `None` when
+/// the file is already current. The front-matter format (YAML/TOML) is kept.
+pub fn migrate_content(
+    content: &str,
+    id: &str,
+    area: &str,
+) -> Result<Option<(String, Vec<String>)>> {
+    let (format, frontmatter, body, _) =
+        split_frontmatter(content).ok_or_else(|| anyhow::anyhow!("missing front-matter"))?;
+    let parsed = match format {
+        FrontmatterFormat::Yaml => serde_yaml::from_str::<Value>(frontmatter)?,
+        FrontmatterFormat::Toml => {
+            serde_yaml::to_value(toml::from_str::<toml::Table>(frontmatter)?)?
+        }
+    };
+    let Value::Mapping(mut fields) = parsed else {
+        return Err(anyhow::anyhow!("front-matter is not a list of fields"));
+    };
+
+    let version = fields
+        .get("format_version")
+        .and_then(Value::as_u64)
+        .unwrap_or(0);
+    if version > u64::from(FORMAT_VERSION) {
+        return Err(anyhow::anyhow!(
+            "written with format version {}, but this TaskGuard supports up to {}. Upgrade taskguard",
+            version,
+            FORMAT_VERSION
+        ));
+    }
+    if version == u64::from(FORMAT_VERSION) {
+        return Ok(None);
+    }
+
+    let file = FileContext { id, area, body };
+    let mut changes = Vec::new();
+    for step in &STEPS[version as usize..] {
+        changes.extend(step(&mut fields, &file));
+    }
+    fields.insert("format_version".into(), FORMAT_VERSION.into());
+    changes.push(format!("format_version {} → {}", version, FORMAT_VERSION));
+
+    let mut task: Task = serde_yaml::from_value(Value::Mapping(fields)).context(
+        "still invalid after migration, fix it by hand ('taskguard validate' shows where)",
+    )?;
+    task.content = body.trim().to_string();
+
+    Ok(Some((task.to_file_content_as(format)?, changes)))
+}
+
+/// Upgrade task files and config written by older releases
+pub fn run(dry_run: bool) -> Result<()> {
+    let tasks_dir = get_tasks_dir()?;
+    let archive_dir = get_archive_dir()?;
+
+    println!(
+        "🧬 Migrating task files to format version {}",
+        FORMAT_VERSION
+    );
+    if dry_run {
+        println!("   Mode: DRY RUN (no files will be modified)");
+    }
+    println!();
+
+    let mut migrated = 0;
+    let mut current = 0;
+    let mut failures = Vec::new();
+
+    for base in [&tasks_dir, &archive_dir] {
+        if !base.exists() {
+            continue;
+        }
+        for entry in WalkDir::new(base)
+            .into_iter()
+            .filter_map(Result::ok)
+            .filter(|e| e.file_type().is_file())
+            .filter(|e| e.path().extension().is_some_and(|ext| ext == "md"))
+        {
+            let path = entry.path();
+            let content = fs::read_to_string(path)
+                .with_context(|| format!("Failed to read task file: {}", path.display()))?;
+            let id = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or_default();
+            let area = path
+                .parent()
+                .and_then(|dir| dir.strip_prefix(base).ok())
+                .map(area_of)
+                .unwrap_or_default();
+
+            match migrate_content(&content, id, &area) {
+                Ok(Some((migrated_content, changes))) => {
+                    if !dry_run {
+                        fs::write(path, migrated_content).with_context(|| {
+                            format!("Failed to write task file: {}", path.display())
+                        })?;
+                    }
+                    println!("   ✅ {}: {}", id, changes.join("; "));
+                    migrated += 1;
+                }
+                Ok(None) => current += 1,
+                Err(e) => failures.push(format!("{}: {:#}", path.display(), e)),
+            }
+        }
+    }
+
+    let config_path = get_config_path()?;
+    let mut config = Config::load_or_default(&config_path)?;
+    if config.project.format_version < FORMAT_VERSION {
+        println!(
+            "   ⚙️  config.toml: format_version {} → {}",
+            config.project.format_version, FORMAT_VERSION
+        );
+        if !dry_run {
+            config.project.format_version = FORMAT_VERSION;
+            config.save(&config_path)?;
+        }
+    }
+
+    if !failures.is_empty() {
+        println!();
+        println!("❌ Could not migrate:");
+        for failure in &failures {
+            println!("   {}", failure);
+        }
+    }
+
+    println!();
+    println!(
+        "📊 {} migrated, {} already current, {} failed",
+        migrated,
+        current,
+        failures.len()
+    );
+    if dry_run && migrated > 0 {
+        println!("   Run without --dry-run to migrate");
+    }
+
+    check_failures(failures.len())
+}
+
+fn check_failures(failed: usize) -> Result<()> {
+    if failed > 0 {
+        return Err(anyhow::anyhow!(
+            "Migration failed for {} task file(s)",
+            failed
+        ));
+    }
+    Ok(())
+}
+
+/// Area name for a directory relative to the tasks (or archive) root
+fn area_of(relative: &Path) -> String {
+    relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
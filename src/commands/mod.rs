@@ -1,14 +1,39 @@
 pub mod ai;
 pub mod archive;
+pub mod branch;
+pub mod claim;
 pub mod clean;
+pub mod comment;
+pub mod commit_msg;
 pub mod compact;
+pub mod convert;
+pub mod coupling;
 pub mod create;
+pub mod critical_path;
+pub mod epic;
+pub mod history;
+pub mod hooks;
+pub mod impact;
 pub mod import_md;
 pub mod init;
 pub mod lint;
 pub mod list;
+pub mod log;
+pub mod metrics;
+pub mod migrate;
+pub mod plan;
+pub mod release;
+pub mod release_notes;
 pub mod restore;
+pub mod serve;
+pub mod show;
 pub mod stats;
+pub mod status;
 pub mod sync;
+pub mod tags;
+pub mod template;
+pub mod time;
 pub mod update;
 pub mod validate;
+pub mod workload;
+pub mod worktree;
@@ -0,0 +1,221 @@
+use anyhow::{Context, Result};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::commands::list::status_icon;
+use crate::commands::workload::workloads;
+use crate::config::load_all_tasks;
+use crate::task::{Priority, Task, TaskStatus, area_matches, format_estimate};
+
+/// Open tasks in dependency order, plus those that can never be scheduled
+#[derive(Debug, Default)]
+pub struct Plan<'a> {
+    /// Each wave only depends on tasks in earlier waves (or done ones)
+    pub waves: Vec<Vec<&'a Task>>,
+    /// Tasks waiting on a missing task or caught in a dependency cycle
+    pub unschedulable: Vec<&'a Task>,
+}
+
+pub(crate) fn priority_order(priority: &Priority) -> u8 {
+    match priority {
+        Priority::Critical => 4,
+        Priority::High => 3,
+        Priority::Medium => 2,
+        Priority::Low => 1,
+    }
+}
+
+/// Sort open tasks into waves with Kahn's algorithm
+///
+/// `all_tasks` decides which dependencies are already done; `tasks` are the
+/// ones to schedule. Dependencies on open tasks outside `tasks` are ignored so
+/// an area plan isn't held up by other areas. Within a wave, higher priority
+/// comes first.
+pub fn plan_waves<'a>(tasks: &[&'a Task], all_tasks: &[Task]) -> Plan<'a> {
+    let known: HashSet<&str> = all_tasks.iter().map(|t| t.id.as_str()).collect();
+    let open: HashMap<&str, &Task> = tasks
+        .iter()
+        .filter(|t| t.status != TaskStatus::Done)
+        .map(|t| (t.id.as_str(), *t))
+        .collect();
+
+    let mut plan = Plan::default();
+    let mut waiting: HashMap<&str, usize> = HashMap::new();
+    let mut dependents: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (id, task) in &open {
+        if task
+            .dependencies
+            .iter()
+            .any(|d| !known.contains(d.as_str()))
+        {
+            plan.unschedulable.push(task);
+            continue;
+        }
+        let open_deps: Vec<&str> = task
+            .dependencies
+            .iter()
+            .map(String::as_str)
+            .filter(|d| open.contains_key(d))
+            .collect();
+        waiting.insert(id, open_deps.len());
+        for dep in open_deps {
+            dependents.entry(dep).or_default().push(id);
+        }
+    }
+
+    let mut wave: Vec<&str> = waiting
+        .iter()
+        .filter(|(_, count)| **count == 0)
+        .map(|(id, _)| *id)
+        .collect();
+    let mut scheduled = 0;
+    while !wave.is_empty() {
+        let mut next = Vec::new();
+        for id in &wave {
+            for dependent in dependents.get(id).into_iter().flatten() {
+                if let Some(count) = waiting.get_mut(dependent) {
+                    *count -= 1;
+                    if *count == 0 {
+                        next.push(*dependent);
+                    }
+                }
+            }
+        }
+
+        let mut tasks: Vec<&Task> = wave.iter().map(|id| open[id]).collect();
+        tasks.sort_by(|a, b| {
+            priority_order(&b.priority)
+                .cmp(&priority_order(&a.priority))
+                .then_with(|| a.id.cmp(&b.id))
+        });
+        scheduled += tasks.len();
+        plan.waves.push(tasks);
+        wave = next;
+    }
+
+    // Whatever never reached zero waits on a cycle (or on a task that does)
+    if scheduled < waiting.len() {
+        plan.unschedulable.extend(
+            waiting
+                .iter()
+                .filter(|(_, count)| **count > 0)
+                .map(|(id, _)| open[id]),
+        );
+    }
+    plan.unschedulable.sort_by(|a, b| a.id.cmp(&b.id));
+
+    plan
+}
+
+fn print_task(task: &Task, indent: &str) {
+    let estimate = task
+        .estimate_minutes()
+        .map(|m| format!(" ({})", format_estimate(m)))
+        .unwrap_or_default();
+    println!(
+        "{}{} {} - {} [{}]{}",
+        indent,
+        status_icon(&task.status),
+        task.id,
+        task.title,
+        task.priority,
+        estimate
+    );
+}
+
+pub fn run(area: Option<&str>, by_assignee: bool) -> Result<()> {
+    let all_tasks = load_all_tasks().context("Failed to load tasks")?;
+    let scoped: Vec<&Task> = all_tasks
+        .iter()
+        .filter(|t| area.is_none_or(|a| area_matches(&t.area, a)))
+        .collect();
+
+    let plan = plan_waves(&scoped, &all_tasks);
+    if plan.waves.is_empty() && plan.unschedulable.is_empty() {
+        println!("✅ No open tasks to plan");
+        return Ok(());
+    }
+
+    let planned: usize = plan.waves.iter().map(Vec::len).sum();
+    println!(
+        "🗺️  EXECUTION PLAN{} ({} tasks in {} waves)",
+        area.map(|a| format!(" - {}", a)).unwrap_or_default(),
+        planned,
+        plan.waves.len()
+    );
+
+    for (i, wave) in plan.waves.iter().enumerate() {
+        let minutes: u32 = wave.iter().filter_map(|t| t.estimate_minutes()).sum();
+        println!();
+        println!(
+            "🌊 Wave {} - {} ({} tasks, {} estimated)",
+            i + 1,
+            if i == 0 {
+                "can be done in parallel now".to_string()
+            } else {
+                format!("unlocked after wave {}", i)
+            },
+            wave.len(),
+            format_estimate(minutes)
+        );
+
+        if by_assignee {
+            let mut groups: BTreeMap<&str, Vec<&Task>> = BTreeMap::new();
+            for task in wave {
+                if task.assignees.is_empty() {
+                    groups.entry("unassigned").or_default().push(task);
+                }
+                for assignee in &task.assignees {
+                    groups.entry(assignee).or_default().push(task);
+                }
+            }
+            for (assignee, tasks) in groups {
+                println!("   👤 {}", assignee);
+                for task in tasks {
+                    print_task(task, "      ");
+                }
+            }
+        } else {
+            for task in wave {
+                print_task(task, "   ");
+            }
+        }
+    }
+
+    // Unassigned tasks that can start now go to whoever has the least open work
+    let mut loads = workloads(&scoped);
+    let picks: Vec<&Task> = plan
+        .waves
+        .first()
+        .into_iter()
+        .flatten()
+        .filter(|t| t.assignees.is_empty())
+        .copied()
+        .collect();
+    if !picks.is_empty() && !loads.is_empty() {
+        println!();
+        println!("💡 SUGGESTED PICKS (least loaded first, see 'taskguard workload')");
+        for task in picks {
+            let Some(load) = loads.iter_mut().min_by_key(|l| (l.minutes, l.tasks)) else {
+                break;
+            };
+            println!(
+                "   {} → {} ({} open)",
+                task.id,
+                load.assignee,
+                format_estimate(load.minutes)
+            );
+            load.minutes += task.estimate_minutes().unwrap_or(0);
+            load.tasks += 1;
+        }
+    }
+
+    if !plan.unschedulable.is_empty() {
+        println!();
+        println!("⚠️  CAN'T BE SCHEDULED (missing dependency or cycle - run 'taskguard validate')");
+        for task in &plan.unschedulable {
+            print_task(task, "   ");
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+
+use crate::config::{find_taskguard_root, load_all_tasks};
+use crate::git::GitAnalyzer;
+use crate::task::{Task, TaskStatus};
+
+/// Record as `release:` the first tag up to `tag` containing all commits of
+/// each done task that has none yet. Tasks without commits, or with commits
+/// not in `tag`, are left alone.
+pub fn run_associate(tag: &str, dry_run: bool) -> Result<()> {
+    let tasks = load_all_tasks().context("Failed to load tasks")?;
+    let root =
+        find_taskguard_root().context("Not in a TaskGuard project. Run 'taskguard init' first.")?;
+    let analyzer = GitAnalyzer::new(&root)
+        .context("Releases need git history. Make sure you're in a Git repository.")?;
+
+    let tags = analyzer.release_tags(tag)?;
+    let activities = analyzer.analyze_task_activity(Some(1000))?;
+
+    let mut shipped: BTreeMap<&str, Vec<&Task>> = BTreeMap::new();
+    let mut without_commits = 0;
+    for task in tasks
+        .iter()
+        .filter(|t| t.status == TaskStatus::Done && t.release.is_none())
+    {
+        let Some(activity) = activities.iter().find(|a| a.task_id == task.id) else {
+            without_commits += 1;
+            continue;
+        };
+        // Submodule commits aren't part of this repository's tags
+        let commits: Vec<&str> = activity
+            .commits
+            .iter()
+            .filter(|c| c.submodule.is_none())
+            .map(|c| c.oid.as_str())
+            .collect();
+        if commits.is_empty() {
+            without_commits += 1;
+            continue;
+        }
+        for (name, release) in &tags {
+            let mut contained = true;
+            for oid in &commits {
+                if !analyzer.release_contains(*release, oid)? {
+                    contained = false;
+                    break;
+                }
+            }
+            if contained {
+                shipped.entry(name.as_str()).or_default().push(task);
+                break;
+            }
+        }
+    }
+
+    if shipped.is_empty() {
+        println!(
+            "🏷️  No unreleased done tasks have all their commits in {}",
+            tag
+        );
+    }
+    let mut recorded = 0;
+    for (name, _) in &tags {
+        let Some(release_tasks) = shipped.get(name.as_str()) else {
+            continue;
+        };
+        println!("🏷️  {}: {} tasks", name, release_tasks.len());
+        for task in release_tasks {
+            println!("   {} - {}", task.id, task.title);
+            if dry_run {
+                continue;
+            }
+            let mut task = (*task).clone();
+            task.release = Some(name.clone());
+            task.save_to_file(&task.file_path)
+                .with_context(|| format!("Failed to update {}", task.file_path.display()))?;
+            recorded += 1;
+        }
+    }
+
+    if dry_run {
+        println!("🔍 Dry run - no task files changed");
+    } else if recorded > 0 {
+        println!("✅ Recorded 'release:' in {} task files", recorded);
+    }
+    if without_commits > 0 {
+        println!(
+            "   {} done tasks have no commits referencing them and were skipped",
+            without_commits
+        );
+    }
+    Ok(())
+}
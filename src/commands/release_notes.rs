@@ -0,0 +1,161 @@
+use anyhow::{Context, Result};
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use crate::config::load_all_tasks;
+use crate::git::GitAnalyzer;
+use crate::github::{TaskIssueMapper, is_github_sync_enabled, load_github_config};
+use crate::task::{Task, TaskStatus};
+
+/// How completed tasks are grouped in the generated notes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GroupBy {
+    Area,
+    Tag,
+    /// The task's `release:`, tasks without one under "Unreleased"
+    Release,
+}
+
+impl GroupBy {
+    pub fn parse(value: &str) -> Result<Self> {
+        match value {
+            "area" => Ok(GroupBy::Area),
+            "tag" => Ok(GroupBy::Tag),
+            "release" => Ok(GroupBy::Release),
+            _ => Err(anyhow::anyhow!(
+                "Invalid grouping '{}'. Valid values: area, tag, release",
+                value
+            )),
+        }
+    }
+}
+
+pub fn run(since: &str, group_by: &str, output: Option<&Path>) -> Result<()> {
+    let group_by = GroupBy::parse(group_by)?;
+    let tasks = load_all_tasks().context("Failed to load tasks")?;
+
+    let current_dir = env::current_dir().context("Failed to get current directory")?;
+    let git_analyzer = GitAnalyzer::new(&current_dir)
+        .context("Failed to initialize Git analyzer. Make sure you're in a Git repository.")?;
+
+    // Tasks already done at the starting point are not part of this release
+    let previous_statuses = match git_analyzer.resolve_since(since)? {
+        Some(oid) => git_analyzer.task_statuses_at(oid)?,
+        None => HashMap::new(),
+    };
+
+    let completed: Vec<&Task> = tasks
+        .iter()
+        .filter(|t| t.status == TaskStatus::Done)
+        .filter(|t| previous_statuses.get(&t.id) != Some(&TaskStatus::Done))
+        .collect();
+
+    let issue_links = load_issue_links();
+    let title = format!("Release Notes (since {})", since);
+    let notes = render_release_notes(&title, &completed, group_by, &issue_links);
+
+    match output {
+        Some(path) => {
+            fs::write(path, &notes)
+                .with_context(|| format!("Failed to write release notes: {}", path.display()))?;
+            println!(
+                "📝 Wrote release notes for {} completed tasks to {}",
+                completed.len(),
+                path.display()
+            );
+        }
+        None => print!("{}", notes),
+    }
+
+    Ok(())
+}
+
+/// Build task ID → GitHub issue URL links from the sync mapping, if configured
+fn load_issue_links() -> HashMap<String, String> {
+    if !is_github_sync_enabled().unwrap_or(false) {
+        return HashMap::new();
+    }
+
+    let (Ok(config), Ok(mapper)) = (load_github_config(), TaskIssueMapper::new()) else {
+        return HashMap::new();
+    };
+
+    mapper
+        .get_all_mappings()
+        .iter()
+        .map(|m| {
+            (
+                m.task_id.clone(),
+                format!(
+                    "[#{}](https://github.com/{}/issues/{})",
+                    m.issue_number,
+                    m.repo
+                        .clone()
+                        .unwrap_or_else(|| format!("{}/{}", config.owner, config.repo)),
+                    m.issue_number
+                ),
+            )
+        })
+        .collect()
+}
+
+/// Render completed tasks as grouped markdown release notes
+///
+/// `issue_links` maps task IDs to pre-formatted markdown links that are
+/// appended to each entry.
+#[allow(clippy::implicit_hasher)]
+pub fn render_release_notes(
+    title: &str,
+    tasks: &[&Task],
+    group_by: GroupBy,
+    issue_links: &HashMap<String, String>,
+) -> String {
+    let mut groups: BTreeMap<String, Vec<&Task>> = BTreeMap::new();
+    for task in tasks {
+        let key = match group_by {
+            GroupBy::Area => task.area.clone(),
+            // Prefer the first tag that adds information beyond the area
+            GroupBy::Tag => task
+                .tags
+                .iter()
+                .find(|tag| **tag != task.area)
+                .or(task.tags.first())
+                .cloned()
+                .unwrap_or_else(|| task.area.clone()),
+            GroupBy::Release => task
+                .release
+                .clone()
+                .unwrap_or_else(|| "Unreleased".to_string()),
+        };
+        groups.entry(key).or_default().push(task);
+    }
+
+    let mut lines = vec![format!("# {}", title), String::new()];
+
+    if groups.is_empty() {
+        lines.push("No tasks were completed in this range.".to_string());
+    }
+
+    for (group, mut group_tasks) in groups {
+        group_tasks.sort_by(|a, b| a.id.cmp(&b.id));
+
+        lines.push(format!("## {}", group));
+        lines.push(String::new());
+        for task in group_tasks {
+            let link = issue_links
+                .get(&task.id)
+                .map(|l| format!(" ({})", l))
+                .unwrap_or_default();
+            lines.push(format!("- **{}**: {}{}", task.id, task.title, link));
+        }
+        lines.push(String::new());
+    }
+
+    let mut out = lines.join("\n");
+    if !out.ends_with('\n') {
+        out.push('\n');
+    }
+    out
+}
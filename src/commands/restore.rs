@@ -5,6 +5,7 @@ use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
 use crate::config::{find_taskguard_root, get_tasks_dir};
+use crate::git::task_trailers;
 use crate::github::{GitHubClient, GitHubMutations, TaskIssueMapper, is_github_sync_enabled};
 use crate::task::Task;
 
@@ -40,8 +41,13 @@ pub fn run(task_id: &str, dry_run: bool) -> Result<()> {
     println!("   Area: {}", area);
     println!("   From: {}", archived_path.display());
 
-    let restore_dir = tasks_dir.join(area);
-    let restore_path = restore_dir.join(archived_path.file_name().unwrap());
+    // Archive mirrors the tasks/ layout, so nested areas go back where they came from
+    let relative = archived_path.strip_prefix(&archive_dir).map_or_else(
+        |_| Path::new(area).join(archived_path.file_name().unwrap()),
+        Path::to_path_buf,
+    );
+    let restore_path = tasks_dir.join(&relative);
+    let restore_dir = restore_path.parent().unwrap_or(&tasks_dir).to_path_buf();
     println!("   To: {}", restore_path.display());
     println!();
 
@@ -96,10 +102,9 @@ pub fn run(task_id: &str, dry_run: bool) -> Result<()> {
 
     println!("✅ RESTORE COMPLETE");
     println!(
-        "   Task restored: {} → {}/{}",
+        "   Task restored: {} → {}",
         task_id,
-        area,
-        archived_path.file_name().unwrap().to_string_lossy()
+        relative.to_string_lossy().replace('\\', "/")
     );
     println!("   Location: {}", restore_path.display());
 
@@ -228,7 +233,11 @@ fn create_restore_commit(repo_path: &Path, task_id: &str) -> Result<()> {
     let signature = repo.signature().context("Failed to get Git signature")?;
 
     // Create commit message
-    let commit_message = format!("Restore archived task: {}", task_id);
+    let commit_message = format!(
+        "taskguard: restore {}\n\n{}",
+        task_id,
+        task_trailers(&[task_id.to_string()])
+    );
 
     // Create the commit
     repo.commit(
@@ -242,7 +251,10 @@ fn create_restore_commit(repo_path: &Path, task_id: &str) -> Result<()> {
     .context("Failed to create commit")?;
 
     println!("\n📝 Git commit created:");
-    println!("   Message: {}", commit_message);
+    println!(
+        "   Message: {}",
+        commit_message.lines().next().unwrap_or_default()
+    );
 
     Ok(())
 }
@@ -0,0 +1,423 @@
+use anyhow::{Context, Result};
+use git2::{ObjectType, Oid, Repository, RepositoryState, StatusOptions};
+use std::collections::HashSet;
+use std::env;
+use std::fs;
+use std::io::{self, BufReader, ErrorKind, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::commands::sync::{same_names, synced_labels};
+use crate::commands::update::validate_status_transition;
+use crate::config::{find_taskguard_root, load_all_tasks};
+use crate::github::webhook::{
+    HeadersTooLarge, IssueEvent, RecentDeliveries, Request, SECRET_VAR, WebhookQueue,
+    verify_signature,
+};
+use crate::github::{GitHubConfig, TaskIssueMapper, load_github_config};
+use crate::task::TaskStatus;
+use crate::tasks_branch;
+
+/// How often queued deliveries are retried while no new ones arrive
+const RETRY_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Longest a client may take to send a whole request; GitHub gives up on a
+/// delivery after 10 seconds too
+const REQUEST_DEADLINE: Duration = Duration::from_secs(10);
+
+/// A stream whose reads share one deadline, so a client trickling bytes can't
+/// hold the server past it
+struct DeadlineReader<'a> {
+    stream: &'a TcpStream,
+    deadline: Instant,
+}
+
+impl Read for DeadlineReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let left = self.deadline.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            return Err(io::Error::new(ErrorKind::TimedOut, "request took too long"));
+        }
+        self.stream.set_read_timeout(Some(left))?;
+        let mut stream = self.stream;
+        stream.read(buf)
+    }
+}
+
+/// `:8080` listens on every interface, like Go's `net.Listen`
+fn listen_address(addr: &str) -> String {
+    if addr.starts_with(':') {
+        format!("0.0.0.0{}", addr)
+    } else {
+        addr.to_string()
+    }
+}
+
+/// Listen for GitHub `issues` webhooks on `addr` and write the changes into
+/// the mapped tasks, until interrupted
+pub fn run_github_webhook(addr: &str) -> Result<()> {
+    let root =
+        find_taskguard_root().context("Not in a TaskGuard project. Run 'taskguard init' first.")?;
+    let secret = env::var(SECRET_VAR)
+        .ok()
+        .filter(|secret| !secret.is_empty())
+        .with_context(|| {
+            format!(
+                "Set {} to the webhook's secret; only deliveries signed with it are accepted",
+                SECRET_VAR
+            )
+        })?;
+    let config = load_github_config()
+        .context("Failed to load GitHub configuration. Run 'taskguard sync --github' first.")?;
+
+    let addr = listen_address(addr);
+    let listener =
+        TcpListener::bind(&addr).with_context(|| format!("Failed to listen on {}", addr))?;
+    // Polled, so queued deliveries can be retried between requests
+    listener.set_nonblocking(true)?;
+    println!("🪝 Listening for GitHub webhooks on {}", addr);
+    println!("   Content type: application/json, events: Issues");
+    println!("   Press Ctrl+C to stop\n");
+
+    let mut receiver = Receiver {
+        root,
+        config,
+        secret,
+        written: HashSet::new(),
+        recent: RecentDeliveries::default(),
+    };
+    receiver.drain();
+    let mut last_retry = Instant::now();
+    loop {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                if let Err(e) = receiver.handle(stream) {
+                    println!("   ⚠️  {:#}", e);
+                }
+            }
+            Err(e) if e.kind() == ErrorKind::WouldBlock => {
+                thread::sleep(Duration::from_millis(200));
+                if last_retry.elapsed() >= RETRY_INTERVAL {
+                    receiver.drain();
+                    last_retry = Instant::now();
+                }
+            }
+            Err(e) => return Err(e).context("Failed to accept a connection"),
+        }
+    }
+}
+
+struct Receiver {
+    root: PathBuf,
+    config: GitHubConfig,
+    secret: String,
+    /// Blobs of task files this server wrote; those changes don't make the
+    /// repository dirty
+    written: HashSet<Oid>,
+    /// Deliveries applied or queued, so repeats are ignored
+    recent: RecentDeliveries,
+}
+
+impl Receiver {
+    fn handle(&mut self, stream: TcpStream) -> Result<()> {
+        stream.set_nonblocking(false)?;
+        let request = Request::read(&mut BufReader::new(DeadlineReader {
+            stream: &stream,
+            deadline: Instant::now() + REQUEST_DEADLINE,
+        }));
+        let mut stream = stream;
+        let request = match request {
+            Ok(request) => request,
+            Err(e) if timed_out(&e) => {
+                respond(&mut stream, "408 Request Timeout", "request too slow")?;
+                return Err(e);
+            }
+            Err(e) if e.is::<HeadersTooLarge>() => {
+                respond(
+                    &mut stream,
+                    "431 Request Header Fields Too Large",
+                    "headers too large",
+                )?;
+                return Err(e);
+            }
+            Err(e) => {
+                respond(&mut stream, "400 Bad Request", "malformed request")?;
+                return Err(e);
+            }
+        };
+
+        if request.method != "POST" {
+            return respond(&mut stream, "405 Method Not Allowed", "webhooks are POSTed");
+        }
+        let signed = request
+            .header("x-hub-signature-256")
+            .is_some_and(|signature| {
+                verify_signature(self.secret.as_bytes(), &request.body, signature)
+            });
+        if !signed {
+            respond(&mut stream, "401 Unauthorized", "bad signature")?;
+            anyhow::bail!("Rejected a delivery with a missing or wrong signature");
+        }
+
+        let delivery = request.header("x-github-delivery").unwrap_or_default();
+        match request.header("x-github-event").unwrap_or_default() {
+            "ping" => {
+                println!("🏓 Ping from GitHub; the webhook is set up");
+                respond(&mut stream, "200 OK", "pong")
+            }
+            "issues" => {
+                if self.recent.contains(delivery, &request.body) {
+                    println!("↩️  Delivery {} was already handled; ignored", delivery);
+                    return respond(&mut stream, "200 OK", "duplicate");
+                }
+                let event = match IssueEvent::parse(delivery, &request.body) {
+                    Ok(event) => event,
+                    Err(e) => {
+                        respond(&mut stream, "400 Bad Request", "unreadable payload")?;
+                        return Err(e);
+                    }
+                };
+                // Events apply in the order they arrived, so a new one waits
+                // behind any still queued
+                self.drain();
+                let mut queue = WebhookQueue::load(&self.root);
+                if !queue.events.is_empty() || self.dirty()? {
+                    queue.events.push(event.clone());
+                    queue.save(&self.root)?;
+                    self.recent.record(delivery, &request.body);
+                    println!(
+                        "⏸️  Queued #{} ({}): tasks/ has uncommitted changes or earlier deliveries are waiting; {} waiting",
+                        event.number,
+                        event.action,
+                        queue.events.len()
+                    );
+                    return respond(&mut stream, "202 Accepted", "queued");
+                }
+                // Answered once applied, so GitHub shows a failed delivery
+                // that can be redelivered
+                if let Err(e) = self.apply(&event) {
+                    respond(&mut stream, "500 Internal Server Error", "not applied")?;
+                    return Err(e);
+                }
+                self.recent.record(delivery, &request.body);
+                respond(&mut stream, "200 OK", "ok")
+            }
+            other => respond(&mut stream, "202 Accepted", &format!("ignored {}", other)),
+        }
+    }
+
+    /// Whether a merge or rebase is in progress, or task files have changes
+    /// not committed and not made by this server
+    fn dirty(&self) -> Result<bool> {
+        let Ok(repo) = Repository::discover(&self.root) else {
+            return Ok(false);
+        };
+        if repo.state() != RepositoryState::Clean {
+            return Ok(true);
+        }
+        let Some(workdir) = repo.workdir() else {
+            return Ok(false);
+        };
+        // Paths compared canonically: the root may be reached through a symlink
+        let tasks_dir = fs::canonicalize(self.root.join("tasks"))?;
+        let workdir = fs::canonicalize(workdir)?;
+        let pathspec = tasks_dir
+            .strip_prefix(&workdir)
+            .unwrap_or(&tasks_dir)
+            .to_string_lossy()
+            .replace('\\', "/");
+        let statuses = repo
+            .statuses(Some(
+                StatusOptions::new()
+                    .include_untracked(true)
+                    .recurse_untracked_dirs(true)
+                    .pathspec(pathspec),
+            ))
+            .context("Failed to read the repository status")?;
+        Ok(statuses.iter().any(|entry| {
+            entry
+                .path()
+                .and_then(|path| Oid::hash_file(ObjectType::Blob, workdir.join(path)).ok())
+                .is_none_or(|oid| !self.written.contains(&oid))
+        }))
+    }
+
+    /// Apply queued deliveries in order, once task files are clean. One
+    /// that fails stays queued, with those after it, for the next retry.
+    fn drain(&mut self) {
+        let mut queue = WebhookQueue::load(&self.root);
+        if queue.events.is_empty() || self.dirty().unwrap_or(true) {
+            return;
+        }
+        println!("▶️  Applying {} queued deliveries", queue.events.len());
+        while let Some(event) = queue.events.first().cloned() {
+            if let Err(e) = self.apply(&event) {
+                println!(
+                    "   ⚠️  #{}: {:#}; {} deliveries stay queued",
+                    event.number,
+                    e,
+                    queue.events.len()
+                );
+                break;
+            }
+            queue.events.remove(0);
+        }
+        if let Err(e) = queue.save(&self.root) {
+            println!("   ⚠️  {:#}", e);
+        }
+    }
+
+    /// Write the issue's state, labels and assignees into its task, merged
+    /// with local changes the way `sync --github` pulls them
+    fn apply(&mut self, event: &IssueEvent) -> Result<()> {
+        tasks_branch::pull()?;
+        let config = &self.config;
+        let main_repo = format!("{}/{}", config.owner, config.repo);
+        let repo = (!event.repo.eq_ignore_ascii_case(&main_repo)).then_some(event.repo.as_str());
+        let mut mapper = TaskIssueMapper::new().context("Failed to load task-issue mapper")?;
+        let Some(mut mapping) = mapper.get_by_issue(repo, event.number).cloned() else {
+            println!(
+                "ℹ️  {}#{} ({}) isn't mapped to a task; ignored",
+                event.repo, event.number, event.action
+            );
+            return Ok(());
+        };
+        let tasks = load_all_tasks().context("Failed to load tasks")?;
+        let Some(task) = tasks.iter().find(|t| t.id == mapping.task_id) else {
+            println!(
+                "ℹ️  #{} maps to {}, which has no task file; ignored",
+                event.number, mapping.task_id
+            );
+            return Ok(());
+        };
+        if task.file_path.to_string_lossy().contains("archive") {
+            println!(
+                "ℹ️  #{} ({}) belongs to archived task {}; restore it to sync",
+                event.number, event.action, task.id
+            );
+            return Ok(());
+        }
+
+        let mut updated = task.clone();
+        let mut changes = Vec::new();
+
+        // Closed issues make their tasks done, reopened ones todo again
+        let closed = event.state.eq_ignore_ascii_case("closed");
+        let status = match (closed, &task.status) {
+            (true, status) if *status != TaskStatus::Done => Some(TaskStatus::Done),
+            (false, TaskStatus::Done) => Some(TaskStatus::Todo),
+            _ => None,
+        };
+        if let Some(status) = status {
+            match validate_status_transition(&task.status, &status) {
+                Ok(()) => {
+                    changes.push(format!("status {}", status));
+                    updated.set_status(status);
+                }
+                Err(e) => println!("   ⚠️  {}: {}", task.id, e),
+            }
+        }
+
+        let tags = synced_labels(config, &task.tags);
+        let labels = synced_labels(config, &event.labels);
+        let merged = TaskIssueMapper::merge_names(&mapping.labels, &tags, &labels);
+        if !same_names(&tags, &merged) {
+            updated.tags = task
+                .tags
+                .iter()
+                .filter(|tag| !config.labels.is_synced(tag))
+                .cloned()
+                .chain(merged.iter().cloned())
+                .collect();
+            changes.push(format!("tags {}", updated.tags.join(", ")));
+        }
+        if same_names(&labels, &merged) {
+            mapping.labels = merged;
+        }
+
+        if !config.users.is_empty() {
+            let logins: Vec<String> = task
+                .assignees
+                .iter()
+                .filter_map(|user| config.login(user).map(str::to_string))
+                .collect();
+            let remote: Vec<String> = event
+                .assignees
+                .iter()
+                .filter(|login| config.user(login).is_some())
+                .cloned()
+                .collect();
+            let merged = TaskIssueMapper::merge_names(&mapping.assignees, &logins, &remote);
+            if !same_names(&logins, &merged) {
+                updated.assignees = task
+                    .assignees
+                    .iter()
+                    .filter(|user| config.login(user).is_none())
+                    .cloned()
+                    .chain(
+                        merged
+                            .iter()
+                            .filter_map(|login| config.user(login).map(str::to_string)),
+                    )
+                    .collect();
+                changes.push(format!("assignees {}", updated.assignees.join(", ")));
+            }
+            if same_names(&remote, &merged) {
+                mapping.assignees = merged;
+            }
+        }
+
+        if !changes.is_empty() {
+            updated
+                .save_to_file(&updated.file_path)
+                .with_context(|| format!("Failed to update {}", updated.file_path.display()))?;
+            self.written
+                .insert(Oid::hash_file(ObjectType::Blob, &updated.file_path)?);
+        }
+        if mapper.get_by_task_id(&task.id) != Some(&mapping) {
+            mapper
+                .update_mapping(mapping)
+                .context(format!("Failed to save mapping for task {}", task.id))?;
+        }
+
+        if changes.is_empty() {
+            println!(
+                "🔔 #{} ({}) → {}: already in step",
+                event.number, event.action, task.id
+            );
+        } else {
+            println!(
+                "🔔 #{} ({}) → {}: {}",
+                event.number,
+                event.action,
+                task.id,
+                changes.join("; ")
+            );
+            tasks_branch::push()?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether reading a request failed because the client was too slow
+fn timed_out(error: &anyhow::Error) -> bool {
+    error.chain().any(|cause| {
+        cause
+            .downcast_ref::<io::Error>()
+            .is_some_and(|e| matches!(e.kind(), ErrorKind::TimedOut | ErrorKind::WouldBlock))
+    })
+}
+
+fn respond(stream: &mut TcpStream, status: &str, body: &str) -> Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    )?;
+    stream.flush()?;
+    Ok(())
+}
@@ -0,0 +1,227 @@
+use anyhow::{Context, Result};
+
+use std::collections::HashMap;
+
+use crate::config::{find_taskguard_root, load_all_tasks};
+use crate::git::{DiffStats, GitAnalyzer, is_source_path};
+use crate::git_cache::GitCache;
+use crate::task::{Task, format_minutes};
+
+/// Files listed by `--files` before the rest are summarized
+const MAX_FILES: usize = 20;
+
+pub fn run(task_id: &str, files: bool) -> Result<()> {
+    let tasks = load_all_tasks().context("Failed to load tasks")?;
+    let task = tasks
+        .iter()
+        .find(|t| t.id == task_id)
+        .ok_or_else(|| anyhow::anyhow!("Task '{}' not found", task_id))?;
+
+    println!("📝 {} - {}", task.id, task.title);
+    println!("   Status: {}", task.status);
+    println!("   Priority: {}", task.priority);
+    println!("   Area: {}", task.area);
+    if !task.assignees.is_empty() {
+        println!("   Assignee: {}", task.assignees.join(", "));
+    }
+    if let Some(claimed) = task.claimed {
+        println!("   Claimed: {}", claimed.format("%Y-%m-%d %H:%M UTC"));
+    }
+    if let Some(estimate) = &task.estimate {
+        println!("   Estimate: {}", estimate);
+    }
+    if let Some(stats) = diff_stats(&task.id) {
+        println!("   Changes: {}", stats);
+    }
+    if let Some((done, total)) = task.checklist_progress() {
+        println!(
+            "   Progress: {}% ({}/{} checklist items)",
+            done * 100 / total,
+            done,
+            total
+        );
+    }
+    if !task.time_log.is_empty() {
+        let running = if task.running_timer().is_some() {
+            " (timer running)"
+        } else {
+            ""
+        };
+        println!(
+            "   Time logged: {}{}",
+            format_minutes(task.logged_minutes()),
+            running
+        );
+    }
+    if let Some(complexity) = task.complexity {
+        println!("   Complexity: {}/10", complexity);
+    }
+    if let Some(due) = task.due {
+        println!("   Due: {}", due);
+    }
+    if let Some(branch) = &task.branch {
+        println!("   Branch: {}", branch);
+    }
+    if let Some(completed) = task.completed {
+        println!("   Completed: {}", completed.format("%Y-%m-%d %H:%M UTC"));
+    }
+    if let Some(release) = &task.release {
+        println!("   Shipped in: {}", release);
+    }
+    if !task.tags.is_empty() {
+        println!("   Tags: {}", task.tags.join(", "));
+    }
+    if !task.dependencies.is_empty() {
+        println!("   Dependencies: {}", task.dependencies.join(", "));
+    }
+    if let Some((done, total)) = task.subtask_progress(&tasks) {
+        println!("   Subtasks: {}/{} done", done, total);
+    }
+
+    let relations = relation_lines(task, &tasks);
+    if !relations.is_empty() {
+        println!();
+        println!("🧷 RELATIONS");
+        for line in relations {
+            println!("   {}", line);
+        }
+    }
+
+    if !task.links.is_empty() {
+        println!();
+        println!("🔗 LINKS");
+        for link in &task.links {
+            println!("   {}", link);
+        }
+    }
+
+    if !task.content.trim().is_empty() {
+        println!();
+        println!("{}", task.content.trim_end());
+    }
+
+    if files {
+        print_files(&task.id)?;
+    }
+
+    Ok(())
+}
+
+/// Source changes of the task's commits, when there's git history mentioning it
+fn diff_stats(task_id: &str) -> Option<DiffStats> {
+    let root = find_taskguard_root()?;
+    let analyzer = GitAnalyzer::new(&root)
+        .ok()?
+        .with_cache(GitCache::load(&root));
+    let activity = analyzer
+        .analyze_task_activity(Some(1000))
+        .ok()?
+        .into_iter()
+        .find(|a| a.task_id == task_id)?;
+    // Best effort: a cache that can't be written is rebuilt next time
+    let _ = analyzer.save_cache(&root);
+    Some(activity.diff_stats)
+}
+
+/// Source files the task's commits changed, noting files mostly changed under another task
+fn print_files(task_id: &str) -> Result<()> {
+    let root =
+        find_taskguard_root().context("Not in a TaskGuard project. Run 'taskguard init' first.")?;
+    let file_map = GitAnalyzer::new(&root)
+        .context("Listing files needs git history. Make sure you're in a Git repository.")?
+        .task_file_map(Some(1000))?;
+
+    let mut files: Vec<(&String, usize)> = file_map
+        .get(task_id)
+        .into_iter()
+        .flatten()
+        .filter(|(path, _)| is_source_path(path))
+        .map(|(path, &commits)| (path, commits))
+        .collect();
+    files.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+
+    println!();
+    if files.is_empty() {
+        println!(
+            "📂 No source files changed by commits referencing {}",
+            task_id
+        );
+        return Ok(());
+    }
+
+    // Path → (task, commits) of the task that changed it most
+    let mut owners: HashMap<&str, (&str, usize)> = HashMap::new();
+    for (task, task_files) in &file_map {
+        for (path, &commits) in task_files {
+            let owner = owners.entry(path.as_str()).or_insert((task.as_str(), 0));
+            if commits > owner.1 || (commits == owner.1 && task.as_str() < owner.0) {
+                *owner = (task.as_str(), commits);
+            }
+        }
+    }
+
+    println!("📂 FILES ({} changed)", files.len());
+    let width = files
+        .iter()
+        .take(MAX_FILES)
+        .map(|(path, _)| path.chars().count())
+        .max()
+        .unwrap_or(0);
+    for (path, commits) in files.iter().take(MAX_FILES) {
+        let owner = owners
+            .get(path.as_str())
+            .filter(|(owner, owner_commits)| *owner != task_id && *owner_commits > *commits)
+            .map(|(owner, owner_commits)| {
+                format!(
+                    " (mostly changed under {}: {} commits)",
+                    owner, owner_commits
+                )
+            })
+            .unwrap_or_default();
+        println!(
+            "   {:width$}  {} commit(s){}",
+            path,
+            commits,
+            owner,
+            width = width
+        );
+    }
+    if files.len() > MAX_FILES {
+        println!("   ... and {} more", files.len() - MAX_FILES);
+    }
+    Ok(())
+}
+
+/// Outgoing relations from the task, followed by the inverse links other tasks declare to it
+fn relation_lines(task: &Task, tasks: &[Task]) -> Vec<String> {
+    let title_of = |id: &str| {
+        tasks
+            .iter()
+            .find(|t| t.id == id)
+            .map_or_else(|| "(missing)".to_string(), |t| t.title.clone())
+    };
+
+    let mut lines: Vec<String> = task
+        .relations
+        .links()
+        .into_iter()
+        .map(|(kind, target)| format!("{} → {} ({})", kind, target, title_of(target)))
+        .collect();
+
+    for other in tasks.iter().filter(|t| t.id != task.id) {
+        for (kind, target) in other.relations.links() {
+            if target != task.id {
+                continue;
+            }
+            let inverse = match kind {
+                "blocks" => "blocked-by",
+                "duplicates" => "duplicated-by",
+                "parent" => "child",
+                _ => kind,
+            };
+            lines.push(format!("{} ← {} ({})", inverse, other.id, other.title));
+        }
+    }
+
+    lines
+}
@@ -1,14 +1,25 @@
-use anyhow::Result;
-use std::collections::HashMap;
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
+use std::path::Path;
 use walkdir::WalkDir;
 
-use crate::config::get_tasks_dir;
-use crate::task::Task;
+use crate::analysis::{EstimateAccuracy, TaskAnalyzer};
+use crate::config::{AgingConfig, Config, get_config_path, get_tasks_dir, load_tasks_from_dir};
+use crate::github::TaskIssueMapper;
+use crate::task::{Task, TaskStatus, format_estimate, format_minutes};
 
-pub fn run() -> Result<()> {
+/// Age after which an open `todo` task counts as stale when `[aging]` isn't configured
+const DEFAULT_STALE_DAYS: u32 = 14;
+
+pub fn run(prometheus: bool, output: Option<&Path>) -> Result<()> {
     let tasks_dir = get_tasks_dir()?;
 
+    if prometheus || output.is_some() {
+        return run_prometheus(&tasks_dir, output);
+    }
+
     if !tasks_dir.exists() {
         println!("📁 No tasks directory found.");
         return Ok(());
@@ -106,9 +117,461 @@ pub fn run() -> Result<()> {
         println!("   {} - {} ({})", id, title, format_size(*size));
     }
 
+    let tasks = load_tasks_from_dir(&tasks_dir)?;
+    print_estimated_effort(&tasks);
+    print_time_tracking(&tasks);
+
+    let config = Config::load_or_default(get_config_path()?)?;
+    let analyzer = TaskAnalyzer::from_config(config.lint.as_ref());
+    let stale_days = config
+        .aging
+        .as_ref()
+        .map_or(DEFAULT_STALE_DAYS, |aging| aging.days);
+    print_area_health(&area_health(
+        &tasks,
+        &analyzer,
+        stale_days,
+        Utc::now().date_naive(),
+    ));
+
+    Ok(())
+}
+
+/// Estimated effort per area, in total and for tasks not yet done
+fn print_estimated_effort(tasks: &[Task]) {
+    // area -> (total minutes, open minutes, estimated tasks, tasks)
+    let mut by_area: BTreeMap<&str, (u32, u32, usize, usize)> = BTreeMap::new();
+    for task in tasks {
+        let entry = by_area.entry(task.area.as_str()).or_default();
+        entry.3 += 1;
+        if let Some(minutes) = task.estimate_minutes() {
+            entry.0 += minutes;
+            if task.status != TaskStatus::Done {
+                entry.1 += minutes;
+            }
+            entry.2 += 1;
+        }
+    }
+    if by_area.values().all(|(_, _, estimated, _)| *estimated == 0) {
+        return;
+    }
+
+    println!();
+    println!("⏳ ESTIMATED EFFORT");
+    for (area, (total, open, estimated, count)) in &by_area {
+        if *estimated == 0 {
+            continue;
+        }
+        println!(
+            "   {} - {} total, {} remaining ({} of {} tasks estimated)",
+            area,
+            format_estimate(*total),
+            format_estimate(*open),
+            estimated,
+            count
+        );
+    }
+    let total: u32 = by_area.values().map(|(total, ..)| total).sum();
+    let open: u32 = by_area.values().map(|(_, open, ..)| open).sum();
+    println!(
+        "   All areas - {} total, {} remaining",
+        format_estimate(total),
+        format_estimate(open)
+    );
+}
+
+/// Logged time totals and how they compare to estimates
+fn print_time_tracking(tasks: &[Task]) {
+    let logged: u32 = tasks.iter().map(Task::logged_minutes).sum();
+    if logged == 0 {
+        return;
+    }
+
+    println!();
+    println!("⏱️  TIME TRACKING");
+    println!("   Total logged: {}", format_minutes(logged));
+
+    let accuracy = EstimateAccuracy::from_tasks(tasks);
+    if let Some(ratio) = accuracy.ratio() {
+        println!(
+            "   Estimated vs actual: {} → {} ({:.0}% of estimate, {} tasks)",
+            format_minutes(accuracy.estimated_minutes),
+            format_minutes(accuracy.actual_minutes),
+            ratio * 100.0,
+            accuracy.samples
+        );
+        println!(
+            "   Over estimate: {} of {} tasks",
+            accuracy.overruns, accuracy.samples
+        );
+        if accuracy.is_chronic_underestimation() {
+            println!("   ⚠️  Estimates are chronically low");
+        }
+    }
+}
+
+/// Export project health metrics in the Prometheus text exposition format
+///
+/// Without an output path the metrics are printed to stdout. With an output
+/// path they are written atomically (temp file + rename) so the `node_exporter`
+/// textfile collector never reads a partially written file.
+fn run_prometheus(tasks_dir: &Path, output: Option<&Path>) -> Result<()> {
+    let tasks = if tasks_dir.exists() {
+        load_tasks_from_dir(tasks_dir)?
+    } else {
+        Vec::new()
+    };
+
+    let sync_age = TaskIssueMapper::new()
+        .ok()
+        .and_then(|mapper| last_sync_time(&mapper))
+        .map(|synced_at| (Utc::now() - synced_at).num_seconds().max(0));
+
+    let metrics = render_prometheus_metrics(&tasks, sync_age);
+
+    match output {
+        Some(path) => {
+            let tmp_path = path.with_extension("prom.tmp");
+            fs::write(&tmp_path, &metrics)
+                .with_context(|| format!("Failed to write metrics file: {}", tmp_path.display()))?;
+            fs::rename(&tmp_path, path)
+                .with_context(|| format!("Failed to write metrics file: {}", path.display()))?;
+            println!("📈 Metrics written to {}", path.display());
+        }
+        None => print!("{}", metrics),
+    }
+
     Ok(())
 }
 
+/// Most recent `synced_at` timestamp across all GitHub issue mappings
+fn last_sync_time(mapper: &TaskIssueMapper) -> Option<DateTime<Utc>> {
+    mapper
+        .get_all_mappings()
+        .iter()
+        .filter_map(|m| DateTime::parse_from_rfc3339(&m.synced_at).ok())
+        .map(|dt| dt.with_timezone(&Utc))
+        .max()
+}
+
+/// Render task metrics in the Prometheus text exposition format
+///
+/// `sync_age_seconds` is the time since the last GitHub sync; the metric is
+/// omitted when GitHub sync has never run.
+pub fn render_prometheus_metrics(tasks: &[Task], sync_age_seconds: Option<i64>) -> String {
+    // Seed every known status so dashboards see explicit zeroes
+    let mut by_status: BTreeMap<String, usize> = [
+        TaskStatus::Todo,
+        TaskStatus::Doing,
+        TaskStatus::Review,
+        TaskStatus::Done,
+        TaskStatus::Blocked,
+    ]
+    .iter()
+    .map(|s| (s.to_string(), 0))
+    .collect();
+    let mut by_area: BTreeMap<String, usize> = BTreeMap::new();
+
+    for task in tasks {
+        *by_status.entry(task.status.to_string()).or_insert(0) += 1;
+        *by_area.entry(task.area.clone()).or_insert(0) += 1;
+    }
+
+    let complexities: Vec<f64> = tasks
+        .iter()
+        .filter_map(|t| t.complexity)
+        .map(f64::from)
+        .collect();
+    let avg_complexity = if complexities.is_empty() {
+        0.0
+    } else {
+        complexities.iter().sum::<f64>() / complexities.len() as f64
+    };
+
+    let mut lines = vec![
+        "# HELP taskguard_tasks Number of tasks by status".to_string(),
+        "# TYPE taskguard_tasks gauge".to_string(),
+    ];
+    for (status, count) in &by_status {
+        lines.push(format!(
+            "taskguard_tasks{{status=\"{}\"}} {}",
+            escape_label_value(status),
+            count
+        ));
+    }
+
+    lines.push("# HELP taskguard_area_tasks Number of tasks by area".to_string());
+    lines.push("# TYPE taskguard_area_tasks gauge".to_string());
+    for (area, count) in &by_area {
+        lines.push(format!(
+            "taskguard_area_tasks{{area=\"{}\"}} {}",
+            escape_label_value(area),
+            count
+        ));
+    }
+
+    lines
+        .push("# HELP taskguard_average_complexity Average complexity of tasks (1-10)".to_string());
+    lines.push("# TYPE taskguard_average_complexity gauge".to_string());
+    lines.push(format!("taskguard_average_complexity {}", avg_complexity));
+
+    lines.push(
+        "# HELP taskguard_blocked_tasks Open tasks marked blocked or waiting on dependencies"
+            .to_string(),
+    );
+    lines.push("# TYPE taskguard_blocked_tasks gauge".to_string());
+    lines.push(format!(
+        "taskguard_blocked_tasks {}",
+        count_blocked_tasks(tasks)
+    ));
+
+    if let Some(age) = sync_age_seconds {
+        lines.push(
+            "# HELP taskguard_github_sync_age_seconds Seconds since the last GitHub sync"
+                .to_string(),
+        );
+        lines.push("# TYPE taskguard_github_sync_age_seconds gauge".to_string());
+        lines.push(format!("taskguard_github_sync_age_seconds {}", age));
+    }
+
+    let mut out = lines.join("\n");
+    out.push('\n');
+    out
+}
+
+/// Count open tasks that are explicitly blocked or have unfinished dependencies
+fn count_blocked_tasks(tasks: &[Task]) -> usize {
+    let completed: HashSet<&str> = tasks
+        .iter()
+        .filter(|t| t.status == TaskStatus::Done)
+        .map(|t| t.id.as_str())
+        .collect();
+
+    tasks.iter().filter(|t| is_blocked(t, &completed)).count()
+}
+
+/// Whether an open task is explicitly blocked or waiting on unfinished dependencies
+fn is_blocked(task: &Task, completed: &HashSet<&str>) -> bool {
+    task.status != TaskStatus::Done
+        && (task.status == TaskStatus::Blocked
+            || task
+                .dependencies
+                .iter()
+                .any(|dep| !completed.contains(dep.as_str())))
+}
+
+/// Composite health of one area; `score` is 0-10, higher is healthier
+#[derive(Debug, Clone, PartialEq)]
+pub struct AreaHealth {
+    pub area: String,
+    pub tasks: usize,
+    pub open: usize,
+    /// Share of open tasks that are blocked or waiting on dependencies
+    pub blocked_ratio: f32,
+    /// Average `TaskAnalyzer` quality score (0-10)
+    pub avg_quality: f32,
+    /// Open `todo` tasks older than the aging threshold
+    pub stale: usize,
+    /// Longest chain of open tasks ending in this area
+    pub dependency_depth: usize,
+    pub score: f32,
+}
+
+impl AreaHealth {
+    /// Dependency chains longer than this count against the score
+    const COMFORTABLE_DEPTH: usize = 3;
+
+    /// What drags the score down, worst first
+    pub fn concerns(&self) -> Vec<String> {
+        let mut concerns = Vec::new();
+        if self.blocked_ratio >= 0.3 {
+            concerns.push(format!(
+                "{:.0}% of open tasks blocked",
+                self.blocked_ratio * 100.0
+            ));
+        }
+        if self.avg_quality < 6.0 {
+            concerns.push(format!("low task quality ({:.1})", self.avg_quality));
+        }
+        if self.stale > 0 {
+            concerns.push(format!("{} stale task(s)", self.stale));
+        }
+        if self.dependency_depth > Self::COMFORTABLE_DEPTH {
+            concerns.push(format!("dependency chains {} deep", self.dependency_depth));
+        }
+        concerns
+    }
+}
+
+/// Health per area, least healthy first
+///
+/// The score weighs unblocked work (30%), task quality (30%), freshness of
+/// open work (25%) and shallow dependency chains (15%). A `todo` task is stale
+/// once it's `stale_days` old.
+pub fn area_health(
+    tasks: &[Task],
+    analyzer: &TaskAnalyzer,
+    stale_days: u32,
+    today: NaiveDate,
+) -> Vec<AreaHealth> {
+    let completed: HashSet<&str> = tasks
+        .iter()
+        .filter(|t| t.status == TaskStatus::Done)
+        .map(|t| t.id.as_str())
+        .collect();
+    let open: HashMap<&str, &Task> = tasks
+        .iter()
+        .filter(|t| t.status != TaskStatus::Done)
+        .map(|t| (t.id.as_str(), t))
+        .collect();
+    let aging = AgingConfig {
+        days: stale_days,
+        escalate: false,
+    };
+
+    let mut by_area: BTreeMap<&str, Vec<&Task>> = BTreeMap::new();
+    for task in tasks {
+        by_area.entry(task.area.as_str()).or_default().push(task);
+    }
+
+    let mut depths = HashMap::new();
+    let mut health: Vec<AreaHealth> = by_area
+        .into_iter()
+        .map(|(area, tasks)| {
+            let open_tasks: Vec<&&Task> = tasks
+                .iter()
+                .filter(|t| t.status != TaskStatus::Done)
+                .collect();
+            let blocked = open_tasks
+                .iter()
+                .filter(|t| is_blocked(t, &completed))
+                .count();
+            let blocked_ratio = if open_tasks.is_empty() {
+                0.0
+            } else {
+                blocked as f32 / open_tasks.len() as f32
+            };
+            let avg_quality = tasks
+                .iter()
+                .map(|t| analyzer.analyze_task(t).quality_score)
+                .sum::<f32>()
+                / tasks.len() as f32;
+            let stale = open_tasks
+                .iter()
+                .filter(|t| aging.age(t, today).is_some())
+                .count();
+            let dependency_depth = open_tasks
+                .iter()
+                .map(|t| open_depth(&t.id, &open, &mut depths, &mut HashSet::new()))
+                .max()
+                .unwrap_or(0);
+
+            let stale_ratio = if open_tasks.is_empty() {
+                0.0
+            } else {
+                stale as f32 / open_tasks.len() as f32
+            };
+            let extra_depth = dependency_depth.saturating_sub(AreaHealth::COMFORTABLE_DEPTH) as f32;
+            let score = 10.0
+                * (0.3 * (1.0 - blocked_ratio)
+                    + 0.3 * (avg_quality / 10.0)
+                    + 0.25 * (1.0 - stale_ratio)
+                    + 0.15 * (1.0 - extra_depth / 5.0).max(0.0));
+
+            AreaHealth {
+                area: area.to_string(),
+                tasks: tasks.len(),
+                open: open_tasks.len(),
+                blocked_ratio,
+                avg_quality,
+                stale,
+                dependency_depth,
+                score,
+            }
+        })
+        .collect();
+
+    health.sort_by(|a, b| a.score.total_cmp(&b.score).then(a.area.cmp(&b.area)));
+    health
+}
+
+/// Number of open tasks in the longest chain ending at `id`; cycles stop the count
+fn open_depth<'a>(
+    id: &'a str,
+    open: &HashMap<&'a str, &'a Task>,
+    depths: &mut HashMap<&'a str, usize>,
+    visiting: &mut HashSet<&'a str>,
+) -> usize {
+    if let Some(depth) = depths.get(id) {
+        return *depth;
+    }
+    let Some(task) = open.get(id) else {
+        return 0;
+    };
+    if !visiting.insert(id) {
+        return 0;
+    }
+    let depth = 1 + task
+        .dependencies
+        .iter()
+        .filter_map(|dep| open.get_key_value(dep.as_str()))
+        .map(|(dep, _)| open_depth(dep, open, depths, visiting))
+        .max()
+        .unwrap_or(0);
+    visiting.remove(id);
+    depths.insert(id, depth);
+    depth
+}
+
+/// Per-area health scores and the areas that need attention
+fn print_area_health(health: &[AreaHealth]) {
+    if health.is_empty() {
+        return;
+    }
+
+    println!();
+    println!("🩺 AREA HEALTH (0-10, higher is better)");
+    let mut by_name: Vec<&AreaHealth> = health.iter().collect();
+    by_name.sort_by(|a, b| a.area.cmp(&b.area));
+    for h in by_name {
+        println!(
+            "   {:<20} {:>4.1}  blocked {:.0}%, quality {:.1}, stale {} of {} open, depth {}",
+            h.area,
+            h.score,
+            h.blocked_ratio * 100.0,
+            h.avg_quality,
+            h.stale,
+            h.open,
+            h.dependency_depth
+        );
+    }
+
+    let attention: Vec<&AreaHealth> = health
+        .iter()
+        .filter(|h| h.score < 7.0 && !h.concerns().is_empty())
+        .collect();
+    if !attention.is_empty() {
+        println!();
+        println!("⚠️  AREAS NEEDING ATTENTION");
+        for h in attention {
+            println!(
+                "   {} ({:.1}) - {}",
+                h.area,
+                h.score,
+                h.concerns().join(", ")
+            );
+        }
+    }
+}
+
+/// Escape a Prometheus label value (backslash, double quote, newline)
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
 #[derive(Debug)]
 struct AreaStats {
     count: usize,
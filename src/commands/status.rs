@@ -0,0 +1,165 @@
+use anyhow::Result;
+use std::collections::BTreeMap;
+
+use crate::analysis::TaskAnalyzer;
+use crate::commands::list::{aging_indicator, due_indicator, incomplete_ids, risk_indicator};
+use crate::config::{Config, get_config_path, get_tasks_dir, load_tasks_from_dir};
+use crate::risk::{self, TaskRisk};
+use crate::task::{Task, TaskStatus};
+
+/// Composite risk score from which `status --risk` lists a task
+const RISK_THRESHOLD: f32 = 4.0;
+
+pub fn run(show_risk: bool) -> Result<()> {
+    let tasks_dir = get_tasks_dir()?;
+
+    if !tasks_dir.exists() {
+        println!("📁 No tasks directory found. Run 'taskguard init' first.");
+        return Ok(());
+    }
+
+    let tasks = load_tasks_from_dir(&tasks_dir)?;
+    if tasks.is_empty() {
+        println!("📋 No tasks found. Create your first task with 'taskguard create'.");
+        return Ok(());
+    }
+
+    let today = chrono::Local::now().date_naive();
+    let incomplete = incomplete_ids(&tasks);
+
+    println!("📊 PROJECT STATUS");
+    println!("   Total tasks: {}", tasks.len());
+
+    let mut by_status: BTreeMap<String, usize> = BTreeMap::new();
+    for task in &tasks {
+        *by_status.entry(task.status.to_string()).or_insert(0) += 1;
+    }
+    for (status, count) in &by_status {
+        println!("   {}: {}", status, count);
+    }
+
+    let done = tasks
+        .iter()
+        .filter(|t| t.status == TaskStatus::Done)
+        .count();
+    println!(
+        "   Progress: {:.0}%",
+        done as f32 / tasks.len() as f32 * 100.0
+    );
+
+    let mut overdue: Vec<&Task> = tasks.iter().filter(|t| t.is_overdue(today)).collect();
+    let mut due_soon: Vec<&Task> = tasks.iter().filter(|t| t.is_due_soon(today)).collect();
+    overdue.sort_by_key(|t| t.due);
+    due_soon.sort_by_key(|t| t.due);
+
+    if !overdue.is_empty() {
+        println!();
+        println!("🔥 OVERDUE ({})", overdue.len());
+        for task in &overdue {
+            println!(
+                "   {} - {}{}",
+                task.id,
+                task.title,
+                due_indicator(task, today)
+            );
+        }
+    }
+
+    if !due_soon.is_empty() {
+        println!();
+        println!("⏰ DUE SOON ({})", due_soon.len());
+        for task in &due_soon {
+            println!(
+                "   {} - {}{}{}",
+                task.id,
+                task.title,
+                due_indicator(task, today),
+                risk_indicator(&task.deadline_risks(&incomplete, today))
+            );
+        }
+    }
+
+    if overdue.is_empty() && due_soon.is_empty() {
+        println!();
+        println!("✅ No overdue or soon-due tasks");
+    }
+
+    // Work likely to miss its due date: too little time left or waiting on dependencies
+    if show_risk {
+        let mut at_risk: Vec<(&Task, Vec<String>)> = tasks
+            .iter()
+            .map(|t| (t, t.deadline_risks(&incomplete, today)))
+            .filter(|(_, risks)| !risks.is_empty())
+            .collect();
+        at_risk.sort_by_key(|(t, _)| t.due);
+
+        println!();
+        if at_risk.is_empty() {
+            println!("✅ No at-risk tasks");
+        } else {
+            println!("⚠️  AT RISK ({})", at_risk.len());
+            for (task, risks) in &at_risk {
+                println!(
+                    "   {} - {}{}",
+                    task.id,
+                    task.title,
+                    due_indicator(task, today)
+                );
+                for risk in risks {
+                    println!("      └── {}", risk);
+                }
+            }
+        }
+
+        // Composite risk across all open work, deadline or not
+        let config = Config::load_or_default(get_config_path()?)?;
+        let analyzer = TaskAnalyzer::from_config(config.lint.as_ref());
+        let riskiest: Vec<TaskRisk> = risk::assess(&tasks, &analyzer, today)
+            .into_iter()
+            .filter(|r| r.score >= RISK_THRESHOLD)
+            .take(5)
+            .collect();
+        if !riskiest.is_empty() {
+            println!();
+            println!("🎲 RISKIEST TASKS (score {:.0}+ of 10)", RISK_THRESHOLD);
+            for risk in &riskiest {
+                let title = tasks
+                    .iter()
+                    .find(|t| t.id == risk.task_id)
+                    .map_or("", |t| t.title.as_str());
+                println!("   {} - {} ({:.1})", risk.task_id, title, risk.score);
+                for factor in risk.factors() {
+                    println!("      └── {}", factor);
+                }
+            }
+        }
+    }
+
+    // Important-but-not-urgent work left in todo, per the [aging] policy
+    if let Some(aging) = Config::load_or_default(get_config_path()?)?.aging {
+        let mut aging_tasks: Vec<(&Task, i64)> = tasks
+            .iter()
+            .filter_map(|t| aging.age(t, today).map(|age| (t, age)))
+            .collect();
+        aging_tasks.sort_by_key(|(_, age)| -age);
+
+        if !aging_tasks.is_empty() {
+            println!();
+            println!(
+                "⏳ AGING ({}, in todo for {}+ days)",
+                aging_tasks.len(),
+                aging.days
+            );
+            for (task, _) in &aging_tasks {
+                println!(
+                    "   {} - {}{}",
+                    task.id,
+                    task.title,
+                    aging_indicator(task, Some(&aging), today)
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -1,13 +1,30 @@
-use crate::config::load_all_tasks;
-use crate::git::{ConflictResolution, GitAnalyzer};
-use crate::task::{Task, TaskStatus};
+use crate::checklist;
+use crate::commands::create::{default_area, generate_task_id};
+use crate::commands::update::validate_status_transition;
+use crate::config::{
+    Config, SyncFilter, find_taskguard_root, get_config_path, get_tasks_dir, load_all_tasks,
+};
+use crate::git::{
+    AreaActivity, BranchActivity, ConflictResolution, GitAnalyzer, RevertMatch, SyncConflict,
+    TaskActivity, TaskRevert,
+};
+use crate::git_cache::GitCache;
+use crate::sync_state::SyncState;
+use crate::task::{FORMAT_VERSION, Priority, Relations, Task, TaskStatus};
+use crate::worklog::{self, LogEntry};
 use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::env;
-use std::io::{self, Write};
+use std::fmt::Write as _;
+use std::io::{self, IsTerminal, Write};
+use std::path::PathBuf;
 
+use crate::github::actions::{self, Level};
 use crate::github::{
-    GitHubClient, GitHubConfig, GitHubMutations, GitHubProjectSetup, GitHubQueries, IssueMapping,
-    TaskIssueMapper, is_github_sync_enabled, load_github_config,
+    GitHubClient, GitHubConfig, GitHubIssue, GitHubMutations, GitHubProjectSetup, GitHubQueries,
+    IssueComment, IssueMapping, PlanAction, PlanEntry, ProjectField, RateLimited, ResumeState,
+    SyncPlan, TOKEN_VARS, TaskIssueMapper, is_github_sync_enabled, load_github_config,
 };
 
 /// Get the current git branch name
@@ -28,6 +45,164 @@ fn get_current_branch() -> Option<String> {
         })
 }
 
+/// Issue body line mirroring checklist completion, if the task has a checklist
+pub fn progress_line(task: &Task) -> Option<String> {
+    task.checklist_progress().map(|(done, total)| {
+        format!(
+            "**Progress:** {}% ({}/{} checklist items)",
+            done * 100 / total,
+            done,
+            total
+        )
+    })
+}
+
+/// Issue body with the progress line replaced or added after the header
+///
+/// Returns `None` when the body is already current or the task has no checklist.
+pub fn apply_progress_line(body: &str, task: &Task) -> Option<String> {
+    let line = progress_line(task)?;
+    let mut lines: Vec<&str> = body.lines().collect();
+
+    if let Some(existing) = lines.iter().position(|l| l.starts_with("**Progress:**")) {
+        if lines[existing] == line {
+            return None;
+        }
+        lines[existing] = &line;
+    } else {
+        // Header lines come first; insert after the last of them
+        let insert_at = lines
+            .iter()
+            .position(|l| !l.starts_with("**"))
+            .unwrap_or(lines.len());
+        lines.insert(insert_at, &line);
+    }
+
+    Some(lines.join("\n"))
+}
+
+/// Log entries for issue comments not yet in the task's log, oldest first
+pub fn new_comment_entries(task: &Task, comments: &[IssueComment]) -> Vec<LogEntry> {
+    let logged = worklog::parse_entries(&task.content);
+    comments
+        .iter()
+        .filter_map(|c| LogEntry::from_comment(&c.author, &c.created_at, &c.body))
+        .filter(|entry| !logged.contains(entry))
+        .collect()
+}
+
+/// Texts of the checked leaf items in a task body or issue checklist
+pub fn checked_items(content: &str) -> Vec<String> {
+    checklist::leaf_items(&checklist::parse_items(content))
+        .filter(|item| item.completed)
+        .map(|item| item.text.clone())
+        .collect()
+}
+
+/// The task's checklist as an issue `## Checklist` section, leaf items checked
+/// when named in `checked` and parents when all their children are. Metadata
+/// suffixes are left out so assignees aren't mentioned on GitHub.
+pub fn checklist_section(task: &Task, checked: &[String]) -> Option<String> {
+    let render = |items: &[checklist::ChecklistItem],
+                  done: &dyn Fn(&checklist::ChecklistItem) -> bool| {
+        items
+            .iter()
+            .map(|item| {
+                let checkbox = if done(item) { "[x]" } else { "[ ]" };
+                format!("{}- {} {}", "  ".repeat(item.depth()), checkbox, item.text)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    let items = checklist::parse_items(&task.content);
+    if items.is_empty() {
+        return None;
+    }
+    let leaves = render(&items, &|item| {
+        !item.has_children()
+            && checked
+                .iter()
+                .any(|text| text.eq_ignore_ascii_case(&item.text))
+    });
+    // Parse the rendered leaves again to settle the parents
+    let lines = render(&checklist::parse_items(&leaves), &|item| item.completed);
+    Some(format!("## Checklist\n\n{}", lines))
+}
+
+/// Checked items of the issue body's `## Checklist` section that are also in
+/// the task's checklist; `None` when the body has no such section
+pub fn issue_checked_items(task: &Task, body: &str) -> Option<Vec<String>> {
+    let lines: Vec<&str> = body.lines().collect();
+    let start = lines.iter().position(|l| l.trim_end() == "## Checklist")?;
+    let section = lines[start + 1..]
+        .iter()
+        .take_while(|l| !l.starts_with("## ") && l.trim_end() != "---")
+        .copied()
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let items = checklist::parse_items(&task.content);
+    let leaves: Vec<&str> = checklist::leaf_items(&items)
+        .map(|item| item.text.as_str())
+        .collect();
+    Some(
+        checked_items(&section)
+            .into_iter()
+            .filter(|text| leaves.iter().any(|leaf| leaf.eq_ignore_ascii_case(text)))
+            .collect(),
+    )
+}
+
+/// Checked items of the task merged with checkboxes toggled in the issue
+/// body since they last agreed (`base`)
+fn merged_checked(task: &Task, remote: Option<&[String]>, base: &[String]) -> Vec<String> {
+    let local = checked_items(&task.content);
+    match remote {
+        Some(remote) => TaskIssueMapper::merge_names(base, &local, remote),
+        None => local,
+    }
+}
+
+/// Issue body with its `## Checklist` section replaced by `section`, or added
+/// before the closing `---` footer
+///
+/// Returns `None` when the body is already current.
+pub fn apply_checklist_section(body: &str, section: &str) -> Option<String> {
+    let mut lines: Vec<&str> = body.lines().collect();
+    let mut replacement: Vec<&str> = section.lines().collect();
+    replacement.push("");
+
+    if let Some(start) = lines.iter().position(|l| l.trim_end() == "## Checklist") {
+        let end = lines[start + 1..]
+            .iter()
+            .position(|l| l.starts_with("## ") || l.trim_end() == "---")
+            .map_or(lines.len(), |offset| start + 1 + offset);
+        lines.splice(start..end, replacement);
+    } else if let Some(footer) = lines.iter().rposition(|l| l.trim_end() == "---") {
+        lines.splice(footer..footer, replacement);
+    } else {
+        lines.push("");
+        lines.extend(section.lines());
+    }
+
+    let updated = lines.join("\n");
+    (updated != body).then_some(updated)
+}
+
+/// Log entries that have no matching issue comment yet, leaving out those
+/// pulled from the issue's comments
+pub fn unposted_log_entries(task: &Task, comments: &[String]) -> Vec<LogEntry> {
+    worklog::parse_entries(&task.content)
+        .into_iter()
+        .filter(|entry| !entry.is_from_github())
+        .filter(|entry| {
+            let body = entry.comment_body();
+            !comments.iter().any(|c| c.trim() == body)
+        })
+        .collect()
+}
+
 /// Generate a short hash of task file content for duplicate detection
 fn hash_task_content(task: &Task) -> String {
     use std::collections::hash_map::DefaultHasher;
@@ -41,142 +216,429 @@ fn hash_task_content(task: &Task) -> String {
     format!("{:x}", hash)[..8].to_string()
 }
 
+/// Markers around the task content in an issue body
+const DESCRIPTION_START: &str = "<!-- taskguard:description -->";
+const DESCRIPTION_END: &str = "<!-- /taskguard:description -->";
+
+/// Short hash of an issue description, to tell edits made on GitHub
+fn hash_description(description: &str) -> String {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = DefaultHasher::new();
+    description.trim().hash(&mut hasher);
+    format!("{:016x}", hasher.finish())[..8].to_string()
+}
+
+/// Level of a markdown heading line (`## Context` → 2)
+fn heading_level(line: &str) -> Option<usize> {
+    let level = line.len() - line.trim_start_matches('#').len();
+    (level > 0 && line[level..].starts_with(' ')).then_some(level)
+}
+
+/// The task content as an issue description. Checklist items are left to the
+/// `## Checklist` section, headings move down a level to nest under
+/// `## Description`, and headings left with nothing under them are dropped.
+pub fn task_description(task: &Task) -> String {
+    let item_lines: HashSet<usize> = checklist::parse_items(&task.content)
+        .iter()
+        .map(|item| item.line_number)
+        .collect();
+
+    // (line, heading level outside code fences)
+    let mut in_fence = false;
+    let mut lines: Vec<(String, Option<usize>)> = Vec::new();
+    for (index, line) in task.content.lines().enumerate() {
+        let fence = line.trim_start().starts_with("```");
+        if fence {
+            in_fence = !in_fence;
+        }
+        if in_fence || fence {
+            lines.push((line.to_string(), None));
+        } else if let Some(level) = heading_level(line) {
+            lines.push((format!("#{}", line), Some(level)));
+        } else if !item_lines.contains(&(index + 1)) {
+            lines.push((line.to_string(), None));
+        }
+    }
+
+    let mut kept: Vec<&str> = Vec::new();
+    for (index, (line, level)) in lines.iter().enumerate() {
+        if let Some(level) = level {
+            let next = lines[index + 1..]
+                .iter()
+                .find(|(line, _)| !line.trim().is_empty());
+            if next.is_none_or(|(_, next)| next.is_some_and(|next| next <= *level)) {
+                continue;
+            }
+        }
+        // One blank line between blocks is enough
+        if line.trim().is_empty() && kept.last().is_none_or(|last| last.trim().is_empty()) {
+            continue;
+        }
+        kept.push(line);
+    }
+
+    let description = kept.join("\n").trim().to_string();
+    if description.is_empty() {
+        "No description".to_string()
+    } else {
+        description
+    }
+}
+
+/// The task content between the description markers of an issue body;
+/// `None` for bodies written before full-body sync or with the markers removed
+pub fn issue_description(body: &str) -> Option<&str> {
+    let start = body.find(DESCRIPTION_START)? + DESCRIPTION_START.len();
+    let end = start + body[start..].find(DESCRIPTION_END)?;
+    Some(body[start..end].trim())
+}
+
+/// The task hash recorded in an issue body's `**Hash:**` line
+pub fn issue_task_hash(body: &str) -> Option<&str> {
+    body.lines()
+        .find_map(|line| line.strip_prefix("**Hash:**"))
+        .map(str::trim)
+}
+
+/// Issue body for a task: header with the task hash, progress line, full
+/// description and checklist, leaf items checked when named in `checked`
+pub fn issue_body(
+    task: &Task,
+    config: &GitHubConfig,
+    is_archived: bool,
+    checked: &[String],
+) -> String {
+    // The branch recorded by `taskguard branch`, else whatever is checked out
+    let branch_name = task
+        .branch
+        .clone()
+        .or_else(get_current_branch)
+        .unwrap_or_else(|| "unknown".to_string());
+
+    // Build task file link
+    let file_path = format!("tasks/{}/{}.md", task.area, task.id);
+    let file_url = format!(
+        "https://github.com/{}/{}/blob/{}/{}",
+        config.owner, config.repo, branch_name, file_path
+    );
+
+    let progress = progress_line(task)
+        .map(|line| format!("\n{}", line))
+        .unwrap_or_default();
+
+    let archived_note = if is_archived {
+        "\n\n📦 **Note:** This task was archived when the issue was created."
+    } else {
+        ""
+    };
+
+    let checklist = checklist_section(task, checked)
+        .map(|section| format!("\n\n{}", section))
+        .unwrap_or_default();
+
+    format!(
+        "**TaskGuard ID:** {}  \n**Task File:** [{}]({})\n**Source Branch:** {}\n**Hash:** {}{}\n\n## Description\n\n{}\n{}\n{}{}{}\n\n---\n*Synced from TaskGuard*",
+        task.id,
+        file_path,
+        file_url,
+        branch_name,
+        hash_task_content(task),
+        progress,
+        DESCRIPTION_START,
+        task_description(task),
+        DESCRIPTION_END,
+        archived_note,
+        checklist
+    )
+}
+
+/// How to settle a task changed locally whose issue description was also
+/// edited on GitHub
+enum BodyChoice {
+    /// Rewrite the issue body from the task
+    Task,
+    /// Keep GitHub's description and stop asking until either side changes again
+    GitHub,
+    /// Leave the issue alone and ask again on the next sync
+    Skip,
+}
+
+/// Ask which side wins a description edited on both sides; skips in a dry run,
+/// when not `interactive`, or without a terminal to ask on
+fn choose_body_version(task: &Task, dry_run: bool, interactive: bool) -> Result<BodyChoice> {
+    println!(
+        "   ⚠️  {} - the issue description was edited on GitHub and the task changed since the last sync",
+        task.id
+    );
+    if dry_run {
+        println!("      Would ask whether to keep the task or the GitHub edit");
+        return Ok(BodyChoice::Skip);
+    }
+    if !interactive || !io::stdin().is_terminal() {
+        println!("      ⏭️  Skipped; run 'taskguard sync --github' in a terminal to choose");
+        return Ok(BodyChoice::Skip);
+    }
+
+    print!("      Overwrite with the [t]ask file, keep the [g]itHub edit, or [s]kip? (t/g/S): ");
+    io::stdout().flush()?;
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(match input.trim().to_lowercase().as_str() {
+        "t" | "task" => BodyChoice::Task,
+        "g" | "github" => BodyChoice::GitHub,
+        _ => BodyChoice::Skip,
+    })
+}
+
 /// Search GitHub issues for existing TaskGuard ID
 #[allow(clippy::type_complexity)]
 fn search_github_for_task_id(
-    _client: &GitHubClient,
+    client: &GitHubClient,
     config: &GitHubConfig,
     task_id: &str,
 ) -> Result<Option<(u64, String, String, Option<String>)>> {
-    let output = std::process::Command::new("gh")
-        .args([
-            "issue",
-            "list",
-            "--repo",
-            &format!("{}/{}", config.owner, config.repo),
-            "--search",
-            &format!("\"**TaskGuard ID:** {}\" in:body", task_id),
-            "--json",
-            "number,title,body,state",
-            "--limit",
-            "1",
-        ])
-        .output()
+    let (owner, repo) = config.issue_repo();
+    let issues = GitHubQueries::search_issues_by_taskguard_id(client, owner, repo, task_id)
         .context("Failed to search GitHub issues")?;
 
-    if !output.status.success() {
+    // Search matches words, so check the ID line itself
+    let id_line = format!("**TaskGuard ID:** {}", task_id);
+    let Some(issue) = issues.into_iter().find(|issue| {
+        issue
+            .body
+            .as_deref()
+            .is_some_and(|body| body.lines().any(|line| line.trim_end() == id_line))
+    }) else {
         return Ok(None);
-    }
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    if stdout.trim().is_empty() || stdout.trim() == "[]" {
-        return Ok(None);
-    }
-
-    // Parse JSON response
-    let issues: Vec<serde_json::Value> = serde_json::from_str(&stdout).unwrap_or_default();
-
-    if let Some(issue) = issues.first() {
-        let number = issue["number"].as_u64().unwrap_or(0);
-        let title = issue["title"].as_str().unwrap_or("").to_string();
-        let body = issue["body"].as_str().unwrap_or("");
+    };
 
-        // Extract branch from issue body if present
-        let branch = body
-            .lines()
+    // Extract branch from issue body if present
+    let branch = issue.body.as_deref().and_then(|body| {
+        body.lines()
             .find(|line| line.starts_with("**Source Branch:**"))
             .map(|line| {
                 line.trim_start_matches("**Source Branch:**")
                     .trim()
                     .to_string()
-            });
-
-        let state = issue["state"].as_str().unwrap_or("OPEN").to_string();
+            })
+    });
 
-        if number > 0 {
-            return Ok(Some((number, title, state, branch)));
-        }
-    }
-
-    Ok(None)
+    Ok(u64::try_from(issue.number)
+        .ok()
+        .filter(|number| *number > 0)
+        .map(|number| (number, issue.title, issue.state, branch)))
 }
 
-/// Extract the Context section from task markdown content.
-/// Returns the content between "## Context" and the next "##" header.
-fn extract_context_section(content: &str) -> Option<String> {
-    let lines: Vec<&str> = content.lines().collect();
-
-    // Find "## Context" header (case-insensitive match)
-    let context_start = lines
-        .iter()
-        .position(|line| line.trim().to_lowercase() == "## context")?;
-
-    // Collect lines until next ## header or end
-    let context_content: String = lines[context_start + 1..]
-        .iter()
-        .take_while(|line| !line.starts_with("## "))
-        .skip_while(|line| line.trim().is_empty())
-        .copied()
-        .collect::<Vec<_>>()
-        .join("\n")
-        .trim()
-        .to_string();
-
-    if context_content.is_empty() {
-        None
-    } else {
-        Some(context_content)
-    }
+#[derive(Debug, Clone, Default)]
+#[allow(clippy::struct_excessive_bools)] // one per `taskguard sync` flag
+pub struct SyncOptions {
+    /// Commits scanned
+    pub limit: usize,
+    pub verbose: bool,
+    pub remote: bool,
+    pub github: bool,
+    pub backfill_project: bool,
+    pub dry_run: bool,
+    pub apply: bool,
+    pub yes: bool,
+    pub branches: bool,
+    pub remote_name: Option<String>,
+    pub all_remotes: bool,
+    pub remote_branch: Option<String>,
+    pub no_cache: bool,
+    pub since_created: bool,
+    pub full: bool,
+    pub working_tree: bool,
+    pub apply_pull: bool,
+    pub adopt_orphans: bool,
+    pub filter: SyncFilter,
+    /// `text`, `table` or `json`, for `--github --dry-run`
+    pub format: Option<String>,
+    pub apply_plan: Option<PathBuf>,
+    pub ci: bool,
 }
 
-pub fn run(
-    limit: usize,
-    verbose: bool,
-    remote: bool,
-    github: bool,
-    backfill_project: bool,
-    dry_run: bool,
-) -> Result<()> {
+pub fn run(options: SyncOptions) -> Result<()> {
+    let SyncOptions {
+        limit,
+        verbose,
+        remote,
+        github,
+        dry_run,
+        apply,
+        yes,
+        branches,
+        no_cache,
+        since_created,
+        full,
+        working_tree,
+        ci,
+        ..
+    } = options;
+
     // Load all tasks first
     let current_tasks = load_all_tasks().context("Failed to load tasks")?;
 
     // GitHub sync mode
     if github {
-        return run_github_sync(&current_tasks, backfill_project, dry_run);
+        let result = run_github_sync(&current_tasks, options);
+        if ci && let Err(e) = &result {
+            println!(
+                "{}",
+                actions::annotation(Level::Error, "taskguard sync --github", &format!("{:#}", e))
+            );
+        }
+        return result;
     }
 
     let current_dir = env::current_dir().context("Failed to get current directory")?;
 
-    // Initialize git analyzer
-    let git_analyzer = GitAnalyzer::new(&current_dir)
-        .context("Failed to initialize Git analyzer. Make sure you're in a Git repository.")?;
+    // Initialize git analyzer; conventional commit scopes may name areas
+    let config = Config::load_or_default(get_config_path()?)?;
+    let areas = config
+        .project
+        .areas
+        .iter()
+        .chain(current_tasks.iter().map(|t| &t.area));
+    let mut git_analyzer = GitAnalyzer::new(&current_dir)
+        .context("Failed to initialize Git analyzer. Make sure you're in a Git repository.")?
+        .with_scope_areas(config.git.scope_areas(areas))
+        .with_path_areas(&config.git.paths)?;
+    if config.git.submodules {
+        git_analyzer = git_analyzer.with_submodules()?;
+    }
+
+    // Commits parsed in earlier runs are reused unless --no-cache
+    let cache_root = find_taskguard_root().filter(|_| !no_cache);
+    if let Some(root) = &cache_root {
+        git_analyzer = git_analyzer.with_cache(GitCache::load(root));
+    }
+
+    // Old tasks shouldn't look inactive just because they predate the last `limit` commits
+    let scanning = if since_created {
+        git_analyzer = git_analyzer.with_task_anchors(&current_tasks);
+        "commits since each task was created".to_string()
+    } else {
+        format!("{} recent commits", limit)
+    };
+
+    if working_tree {
+        let result = run_working_tree(&git_analyzer, &current_tasks, limit, verbose);
+        if let Some(root) = &cache_root {
+            git_analyzer.save_cache(root)?;
+        }
+        return result;
+    }
 
     if remote {
         println!("🌐 REMOTE SYNC MODE");
-        return run_remote_sync(&git_analyzer, &current_tasks, limit, verbose, dry_run);
+        let result = run_remote_sync(&git_analyzer, &current_tasks, &scanning, &options);
+        if let Some(root) = &cache_root {
+            git_analyzer.save_cache(root)?;
+        }
+        return result;
     }
 
+    // Only tasks with commits since the last sync on this branch are reported,
+    // unless --full or --apply (which acts on whatever an earlier run suggested)
+    let root = find_taskguard_root();
+    let mut sync_state = root.as_deref().map(SyncState::load);
+    let head = git_analyzer.head_commit();
+    let since = match (&sync_state, &head) {
+        (Some(state), Some((branch, _))) if !full && !apply => state.last(branch),
+        _ => None,
+    };
+    let new_commits = match since {
+        Some(since) => git_analyzer
+            .commits_since(since, limit)?
+            .map(|commits| (since.to_string(), commits)),
+        None => None,
+    };
+
     println!("🔍 ANALYZING LOCAL GIT HISTORY");
-    println!(
-        "   Scanning {} recent commits for task activity...\n",
-        limit
-    );
+    println!("   Scanning {} for task activity...", scanning);
+    if let Some((since, _)) = &new_commits {
+        println!(
+            "   Showing activity since the last sync ({}); --full for everything",
+            &since[..8.min(since.len())]
+        );
+    }
+    println!();
 
     // Analyze git activity
-    let activities = git_analyzer
+    let mut activities = git_analyzer
         .analyze_task_activity(Some(limit))
         .context("Failed to analyze Git activity")?;
+    let active_task_ids: Vec<String> = activities.iter().map(|a| a.task_id.clone()).collect();
+    if let Some((_, commits)) = &new_commits {
+        activities.retain(|a| a.commits.iter().any(|c| commits.contains(&c.oid)));
+    }
+    if let Some(root) = &cache_root {
+        git_analyzer.save_cache(root)?;
+    }
+    if verbose && let Some(hits) = git_analyzer.cache_hits() {
+        println!("   Cached: {} commits parsed in earlier runs\n", hits);
+    }
+    let reverts = git_analyzer
+        .find_reverts(&activities, limit)
+        .context("Failed to look for reverted commits")?;
+    let mapper = if reverts.is_empty() {
+        None
+    } else {
+        TaskIssueMapper::new().ok()
+    };
+    let mut area_activities = git_analyzer
+        .analyze_area_activity(Some(limit))
+        .context("Failed to analyze Git activity")?;
+    if let Some((_, commits)) = &new_commits {
+        area_activities.retain_mut(|a| {
+            a.commits.retain(|c| commits.contains(&c.oid));
+            !a.commits.is_empty()
+        });
+    }
+
+    // A dry run leaves the state alone so the real run reports the same activity
+    if !dry_run
+        && let (Some(root), Some(state), Some((branch, oid))) = (&root, &mut sync_state, &head)
+    {
+        state.record(branch, oid);
+        state.save(root)?;
+    }
+    let branch_activities = if branches {
+        git_analyzer
+            .analyze_branch_activity(Some(limit))
+            .context("Failed to analyze branch activity")?
+    } else {
+        Vec::new()
+    };
 
+    if activities.is_empty() && new_commits.is_some() {
+        println!("ℹ️  No new task activity since the last sync.");
+        println!("   Run with --full to see all recent activity");
+        println!();
+        display_area_activity(&area_activities, verbose);
+        display_branch_activity(&branch_activities);
+        return Ok(());
+    }
     if activities.is_empty() {
         println!("ℹ️  No task-related activity found in recent commits.");
-        println!("   Tip: Reference task IDs in commit messages (e.g., 'Fix bug in backend-001')");
+        println!(
+            "   Tip: Reference task IDs in commit messages (e.g., 'Fix bug in backend-001' or 'feat(backend-001): ...'), or add a 'Task-Id: backend-001' trailer"
+        );
+        println!();
+        display_area_activity(&area_activities, verbose);
+        display_branch_activity(&branch_activities);
         return Ok(());
     }
 
     println!("📊 TASK ACTIVITY ANALYSIS");
     println!("   Found activity for {} tasks:\n", activities.len());
 
-    let mut suggestions_count = 0;
+    let sync_config = config.sync.clone().unwrap_or_default();
+    let mut suggestions: Vec<(&str, &str)> = Vec::new();
+    let mut assignee_suggestions: Vec<(&str, Vec<String>)> = Vec::new();
 
     for activity in &activities {
         // Find corresponding task
@@ -201,6 +663,7 @@ pub fn run(
         println!("   Commits found: {}", activity.commits.len());
 
         if verbose {
+            println!("   Changes: {}", activity.diff_stats);
             println!("   Recent commits:");
             for commit in activity.commits.iter().take(3) {
                 let short_oid = &commit.oid[..8];
@@ -210,27 +673,94 @@ pub fn run(
                 } else {
                     short_msg.to_string()
                 };
-                println!("     {} - {}", short_oid, short_msg);
+                match &commit.submodule {
+                    Some(path) => println!("     {} - [{}] {}", short_oid, path, short_msg),
+                    None => println!("     {} - {}", short_oid, short_msg),
+                }
             }
         }
 
+        // Work undone since the task was marked done
+        let undone: Vec<&TaskRevert> = match (current_task, reverts.get(&activity.task_id)) {
+            (Some(task), Some(task_reverts)) if task.status == TaskStatus::Done => task_reverts
+                .iter()
+                .filter(|r| task.completed.is_none_or(|done| r.commit.timestamp > done))
+                .collect(),
+            _ => Vec::new(),
+        };
+
         // Show suggestions
-        if let Some(suggested_status) = &activity.suggested_status
+        if !undone.is_empty() {
+            println!("   ⏪ REVERTED after the task was marked done:");
+            for revert in &undone {
+                let how = match revert.matched {
+                    RevertMatch::Message => "reverts",
+                    RevertMatch::PatchId => "undoes the changes of",
+                };
+                println!(
+                    "     {} {} {} - {}",
+                    &revert.commit.oid[..8],
+                    how,
+                    &revert.reverted[..8],
+                    revert.commit.message.lines().next().unwrap_or("")
+                );
+            }
+            suggestions.push((&activity.task_id, "doing"));
+            println!("   💡 SUGGESTION: Consider reopening the task (status 'doing')");
+            if let Some(mapping) = mapper
+                .as_ref()
+                .and_then(|m| m.get_by_task_id(&activity.task_id))
+            {
+                println!(
+                    "      Reopen GitHub issue #{} as well",
+                    mapping.issue_number
+                );
+            }
+        } else if let Some(suggested_status) = &activity.suggested_status
             && suggested_status != &current_status
-            && activity.confidence > 0.5
+            && activity.confidence > sync_config.threshold(suggested_status)
         {
-            suggestions_count += 1;
+            suggestions.push((&activity.task_id, suggested_status));
             println!(
                 "   💡 SUGGESTION: Consider changing status to '{}'",
                 suggested_status
             );
             println!("      Confidence: {:.0}%", activity.confidence * 100.0);
-            println!("      Rationale: Based on commit message patterns");
+            match &activity.merged_branch {
+                Some(branch) => {
+                    println!("      Rationale: Branch {} was merged and deleted", branch);
+                }
+                None => println!("      Rationale: Based on commit message patterns"),
+            }
+        }
+
+        let authors = activity.dominant_authors();
+        if current_task.is_some_and(|t| t.assignees.is_empty()) && !authors.is_empty() {
+            let names: Vec<String> = authors.iter().map(|(a, _)| format!("'{}'", a)).collect();
+            let counts: Vec<String> = authors.iter().map(|(_, c)| c.to_string()).collect();
+            println!(
+                "   👤 SUGGESTION: Assign to {} ({} of {} of {} commits)",
+                names.join(" and "),
+                if authors.len() == 1 {
+                    "author"
+                } else {
+                    "authors"
+                },
+                counts.join(" and "),
+                activity.commits.len()
+            );
+            assignee_suggestions.push((
+                &activity.task_id,
+                authors.into_iter().map(|(author, _)| author).collect(),
+            ));
         }
 
         println!();
     }
 
+    display_area_activity(&area_activities, verbose);
+    display_branch_activity(&branch_activities);
+
     // Repository statistics
     if verbose {
         println!("🔧 REPOSITORY STATISTICS");
@@ -245,17 +775,35 @@ pub fn run(
     }
 
     // Summary
-    if suggestions_count > 0 {
-        println!("✨ RECOMMENDATIONS");
-        println!(
-            "   Found {} task status suggestions based on Git activity",
-            suggestions_count
-        );
-        println!("   Review the suggestions above and update task files manually");
-        println!("   Future versions will support automatic updates with confirmation");
-    } else {
+    if suggestions.is_empty() && assignee_suggestions.is_empty() {
         println!("✅ ALL GOOD");
         println!("   No status changes recommended based on current Git activity");
+    } else {
+        println!("✨ RECOMMENDATIONS");
+        if !suggestions.is_empty() {
+            println!(
+                "   Found {} task status suggestions based on Git activity",
+                suggestions.len()
+            );
+        }
+        if !assignee_suggestions.is_empty() {
+            println!(
+                "   Found {} assignee suggestions for unassigned tasks based on commit authors",
+                assignee_suggestions.len()
+            );
+        }
+        if apply {
+            if !suggestions.is_empty() {
+                println!();
+                apply_suggestions(&suggestions, &current_tasks, dry_run, yes)?;
+            }
+            if !assignee_suggestions.is_empty() {
+                println!();
+                apply_assignees(&assignee_suggestions, &current_tasks, dry_run, yes)?;
+            }
+        } else {
+            println!("   Run with --apply to update task files (asks per task, --yes to skip)");
+        }
     }
 
     // Next steps guidance
@@ -263,7 +811,6 @@ pub fn run(
 
     // Find tasks with no recent activity
     let all_task_ids: Vec<String> = current_tasks.iter().map(|t| t.id.clone()).collect();
-    let active_task_ids: Vec<String> = activities.iter().map(|a| a.task_id.clone()).collect();
 
     let stale_tasks: Vec<String> = all_task_ids
         .into_iter()
@@ -298,59 +845,407 @@ pub fn run(
     Ok(())
 }
 
-/// Handle remote synchronization workflow
-fn run_remote_sync(
-    git_analyzer: &GitAnalyzer,
-    current_tasks: &[Task],
-    limit: usize,
-    verbose: bool,
-    dry_run: bool,
-) -> Result<()> {
-    // Get available remotes
-    let remotes = git_analyzer
-        .get_remotes()
-        .context("Failed to get repository remotes")?;
-
-    if remotes.is_empty() {
-        return Err(anyhow::anyhow!(
-            "No remotes configured. Add a remote repository first."
-        ));
+/// Commits scoped to an area (`feat(auth): ...`) that name no task
+fn display_area_activity(area_activities: &[AreaActivity], verbose: bool) {
+    if area_activities.is_empty() {
+        return;
     }
 
-    // Use 'origin' if available, otherwise use first remote
-    let remote_name = if remotes.contains(&"origin".to_string()) {
-        "origin"
-    } else {
-        &remotes[0]
-    };
-
-    println!("   Using remote: {}", remote_name);
-    println!("   Scanning {} recent commits from remote...\n", limit);
-
-    // Analyze local activity
-    println!("🔍 Analyzing local Git history...");
-    let local_activities = git_analyzer
-        .analyze_task_activity(Some(limit))
-        .context("Failed to analyze local Git activity")?;
+    println!("📂 AREA ACTIVITY");
+    println!(
+        "   Unattributed commits in an area (by scope or changed paths), without a task ID:\n"
+    );
+    for activity in area_activities {
+        print!("   {}: {} commits", activity.area, activity.commits.len());
+        if let Some(last_activity) = activity.last_activity {
+            let days_ago = (chrono::Utc::now() - last_activity).num_days();
+            print!(" (last {} days ago)", days_ago);
+        }
+        println!();
+        if verbose {
+            for commit in activity.commits.iter().take(3) {
+                let short_msg = commit.message.lines().next().unwrap_or("");
+                println!("     {} - {}", &commit.oid[..8], short_msg);
+            }
+        }
+    }
+    println!(
+        "   💡 Scope commits to a task ID (e.g., 'feat(auth-001): ...') to track them per task"
+    );
+    println!(
+        "   💡 Ongoing work without a task? Create one: taskguard create --area {} --title \"...\"",
+        area_activities[0].area
+    );
+    println!();
+}
 
-    // Analyze remote activity
-    println!("🌐 Analyzing remote Git history...");
-    let remote_activities = git_analyzer
-        .analyze_remote_task_activity(remote_name, Some(limit))
-        .context("Failed to analyze remote Git activity")?;
+/// Tasks touched per local branch, and tasks touched on more than one
+fn display_branch_activity(branch_activities: &[BranchActivity]) {
+    if branch_activities.is_empty() {
+        return;
+    }
 
-    if local_activities.is_empty() && remote_activities.is_empty() {
-        println!("ℹ️  No task-related activity found in local or remote commits.");
-        println!("   Tip: Reference task IDs in commit messages (e.g., 'Fix bug in backend-001')");
-        return Ok(());
+    println!("🌿 BRANCH ACTIVITY");
+    let mut branches_by_task: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for activity in branch_activities {
+        if activity.tasks.is_empty() {
+            println!(
+                "   {}: {} commits, no task references",
+                activity.branch, activity.commits
+            );
+            continue;
+        }
+        let tasks: Vec<String> = activity
+            .tasks
+            .iter()
+            .map(|(task_id, commits)| format!("{} ({})", task_id, commits))
+            .collect();
+        println!(
+            "   {}: {} commits → {}",
+            activity.branch,
+            activity.commits,
+            tasks.join(", ")
+        );
+        for task_id in activity.tasks.keys() {
+            branches_by_task
+                .entry(task_id)
+                .or_default()
+                .push(&activity.branch);
+        }
     }
 
-    // Detect conflicts between local and remote suggestions
-    println!("⚖️  Comparing local and remote task suggestions...\n");
-    let conflicts = git_analyzer.detect_sync_conflicts(&local_activities, &remote_activities);
+    branches_by_task.retain(|_, branches| branches.len() > 1);
+    if !branches_by_task.is_empty() {
+        println!();
+        println!("⚠️  Tasks touched on several branches:");
+        for (task_id, branches) in &branches_by_task {
+            println!("   {}: {}", task_id, branches.join(", "));
+        }
+        println!(
+            "   💡 Merge the work before 'taskguard sync --github' so each task maps to one issue"
+        );
+    }
+    println!();
+}
+
+/// Which open task uncommitted changes most likely belong to: tasks whose
+/// commits changed the same files, those in progress first
+fn run_working_tree(
+    git_analyzer: &GitAnalyzer,
+    tasks: &[Task],
+    limit: usize,
+    verbose: bool,
+) -> Result<()> {
+    println!("📝 WORKING TREE");
+    let changes = git_analyzer.working_tree_changes()?;
+    if changes.is_empty() {
+        println!("   No uncommitted changes to source files");
+        return Ok(());
+    }
+    println!(
+        "   {} changed files ({} staged)",
+        changes.len(),
+        changes.iter().filter(|c| c.staged).count()
+    );
+    if verbose {
+        for change in &changes {
+            let state = if change.staged { "staged" } else { "unstaged" };
+            println!("      {} [{}]", change.path, state);
+        }
+    }
+    println!();
+
+    // Open task → changed files its commits touched, and how many commits touched them
+    let file_map = git_analyzer.task_file_map(Some(limit))?;
+    let mut candidates: Vec<(&Task, Vec<&str>, usize)> = tasks
+        .iter()
+        .filter(|t| t.status != TaskStatus::Done)
+        .filter_map(|task| {
+            let files = file_map.get(&task.id)?;
+            let matched: Vec<&str> = changes
+                .iter()
+                .map(|c| c.path.as_str())
+                .filter(|path| files.contains_key(*path))
+                .collect();
+            let commits = matched.iter().map(|path| files[*path]).sum();
+            (!matched.is_empty()).then_some((task, matched, commits))
+        })
+        .collect();
+    candidates.sort_by(|a, b| {
+        (b.0.status == TaskStatus::Doing)
+            .cmp(&(a.0.status == TaskStatus::Doing))
+            .then(b.1.len().cmp(&a.1.len()))
+            .then(b.2.cmp(&a.2))
+            .then(a.0.id.cmp(&b.0.id))
+    });
+
+    let Some((task, matched, _)) = candidates.first() else {
+        let doing: Vec<&Task> = tasks
+            .iter()
+            .filter(|t| t.status == TaskStatus::Doing)
+            .collect();
+        println!("ℹ️  No open task's recent commits changed these files");
+        if let [task] = doing.as_slice() {
+            println!(
+                "   The only task in progress is {} - {}; mention it in the commit message if the changes belong to it",
+                task.id, task.title
+            );
+        }
+        return Ok(());
+    };
+
+    println!(
+        "🎯 Most likely task: {} - {} ({})",
+        task.id, task.title, task.status
+    );
+    println!(
+        "   Its commits changed {} of the {} changed files: {}",
+        matched.len(),
+        changes.len(),
+        matched.join(", ")
+    );
+    for (other, matched, _) in candidates.iter().skip(1).take(3) {
+        println!(
+            "   Also touched by {} ({}): {} file(s)",
+            other.id,
+            other.status,
+            matched.len()
+        );
+    }
+    println!(
+        "   💡 Mention {} in the commit message, or run 'taskguard commit-msg {}'",
+        task.id, task.id
+    );
+    Ok(())
+}
+
+/// Apply status suggestions, confirming each one unless `yes`
+fn apply_suggestions(
+    suggestions: &[(&str, &str)],
+    current_tasks: &[Task],
+    dry_run: bool,
+    yes: bool,
+) -> Result<()> {
+    println!("✍️  APPLYING SUGGESTIONS");
+    let mut applied = 0;
+    for (task_id, status) in suggestions {
+        let Some(task) = current_tasks.iter().find(|t| t.id == *task_id) else {
+            println!("   ⏭️  {}: no task file found", task_id);
+            continue;
+        };
+        if dry_run {
+            println!("   Would change {}: {} → {}", task_id, task.status, status);
+            continue;
+        }
+        if !yes
+            && !confirm(&format!(
+                "Change {} from {} to {}?",
+                task_id, task.status, status
+            ))?
+        {
+            println!("   ⏭️  Skipped {}", task_id);
+            continue;
+        }
+        if apply_status(current_tasks, task_id, status)? {
+            applied += 1;
+        }
+    }
+
+    if dry_run {
+        println!("   🔍 Dry run - no task files changed");
+    } else {
+        println!(
+            "   Applied {} of {} suggestions",
+            applied,
+            suggestions.len()
+        );
+    }
+    Ok(())
+}
+
+/// Set assignees of unassigned tasks, confirming each one unless `yes`
+fn apply_assignees(
+    suggestions: &[(&str, Vec<String>)],
+    current_tasks: &[Task],
+    dry_run: bool,
+    yes: bool,
+) -> Result<()> {
+    println!("👤 APPLYING ASSIGNEES");
+    let mut applied = 0;
+    for (task_id, authors) in suggestions {
+        let author = authors.join(", ");
+        let Some(task) = current_tasks.iter().find(|t| t.id == *task_id) else {
+            println!("   ⏭️  {}: no task file found", task_id);
+            continue;
+        };
+        if dry_run {
+            println!("   Would assign {} to {}", task_id, author);
+            continue;
+        }
+        if !yes && !confirm(&format!("Assign {} to {}?", task_id, author))? {
+            println!("   ⏭️  Skipped {}", task_id);
+            continue;
+        }
+
+        // Re-read the file: a status suggestion may have just been written to it
+        let mut task = Task::from_file(&task.file_path)?;
+        task.assignees.clone_from(authors);
+        task.save_to_file(&task.file_path)
+            .with_context(|| format!("Failed to update {}", task.file_path.display()))?;
+        println!("   ✅ Updated {}: assignee = {}", task.id, author);
+        applied += 1;
+    }
+
+    if dry_run {
+        println!("   🔍 Dry run - no task files changed");
+    } else {
+        println!(
+            "   Applied {} of {} assignee suggestions",
+            applied,
+            suggestions.len()
+        );
+    }
+    Ok(())
+}
+
+/// Write `status` into the task's file; false when there's nothing to change
+fn apply_status(current_tasks: &[Task], task_id: &str, status: &str) -> Result<bool> {
+    let Some(task) = current_tasks.iter().find(|t| t.id == task_id) else {
+        println!("   ⏭️  {}: no task file found", task_id);
+        return Ok(false);
+    };
+    // Conflicts use placeholders like "no local activity" when a side has no suggestion
+    let Ok(new_status) = TaskStatus::parse(status) else {
+        println!("   ⏭️  {}: no status to apply", task_id);
+        return Ok(false);
+    };
+    if task.status == new_status {
+        println!("   ✅ {} is already {}", task_id, new_status);
+        return Ok(false);
+    }
+
+    let mut task = task.clone();
+    validate_status_transition(&task.status, &new_status)?;
+    task.set_status(new_status);
+    task.save_to_file(&task.file_path)
+        .with_context(|| format!("Failed to update {}", task.file_path.display()))?;
+    println!("   ✅ Updated {}: status = {}", task.id, task.status);
+    Ok(true)
+}
+
+/// Ask a yes/no question, defaulting to no
+fn confirm(question: &str) -> Result<bool> {
+    print!("   {} (y/N): ", question);
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(matches!(input.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+/// Handle remote synchronization workflow: compare local suggestions with
+/// those of one remote (`remote_name`, else origin or the first remote), or of
+/// every remote with `all_remotes`
+fn run_remote_sync(
+    git_analyzer: &GitAnalyzer,
+    current_tasks: &[Task],
+    scanning: &str,
+    options: &SyncOptions,
+) -> Result<()> {
+    let SyncOptions {
+        all_remotes,
+        limit,
+        verbose,
+        dry_run,
+        yes,
+        ..
+    } = *options;
+    let remote_name = options.remote_name.as_deref();
+    let remote_branch = options.remote_branch.as_deref();
+    // Get available remotes
+    let remotes = git_analyzer
+        .get_remotes()
+        .context("Failed to get repository remotes")?;
+
+    if remotes.is_empty() {
+        return Err(anyhow::anyhow!(
+            "No remotes configured. Add a remote repository first."
+        ));
+    }
+
+    let selected: Vec<&str> = if all_remotes {
+        remotes.iter().map(String::as_str).collect()
+    } else if let Some(name) = remote_name {
+        if !remotes.iter().any(|r| r == name) {
+            return Err(anyhow::anyhow!(
+                "Remote '{}' not found. Remotes: {}",
+                name,
+                remotes.join(", ")
+            ));
+        }
+        vec![name]
+    } else if remotes.contains(&"origin".to_string()) {
+        // Use 'origin' if available, otherwise use first remote
+        vec!["origin"]
+    } else {
+        vec![remotes[0].as_str()]
+    };
+
+    println!("   Using remotes: {}", selected.join(", "));
+    println!("   Scanning {} per remote...\n", scanning);
+
+    // Analyze local activity
+    println!("🔍 Analyzing local Git history...");
+    let local_activities = git_analyzer
+        .analyze_task_activity(Some(limit))
+        .context("Failed to analyze local Git activity")?;
+
+    // Analyze remote activity, per remote branch
+    let mut remote_activities: Vec<(String, Vec<TaskActivity>)> = Vec::new();
+    for remote in &selected {
+        println!("🌐 Analyzing remote Git history of '{}'...", remote);
+        let analysis = git_analyzer
+            .analyze_remote_task_activity(remote, remote_branch, Some(limit))
+            .and_then(|activities| {
+                let branch = git_analyzer.remote_branch(remote, remote_branch)?;
+                Ok((format!("{}/{}", remote, branch), activities))
+            })
+            .with_context(|| format!("Failed to analyze remote Git activity of '{}'", remote));
+        match analysis {
+            Ok((label, activities)) => {
+                println!("   Compared with {}\n", label);
+                remote_activities.push((label, activities));
+            }
+            // One unreachable fork shouldn't stop the others from being compared
+            Err(e) if all_remotes => println!("   ⚠️  Skipping '{}': {:#}\n", remote, e),
+            Err(e) => return Err(e),
+        }
+    }
+
+    if local_activities.is_empty()
+        && remote_activities
+            .iter()
+            .all(|(_, activities)| activities.is_empty())
+    {
+        println!("ℹ️  No task-related activity found in local or remote commits.");
+        println!(
+            "   Tip: Reference task IDs in commit messages (e.g., 'Fix bug in backend-001'), or add a 'Task-Id: backend-001' trailer"
+        );
+        return Ok(());
+    }
+
+    // Detect conflicts between local and remote suggestions
+    println!("⚖️  Comparing local and remote task suggestions...\n");
+    let conflicts: Vec<SyncConflict> = remote_activities
+        .iter()
+        .flat_map(|(label, activities)| {
+            git_analyzer.detect_sync_conflicts(label, &local_activities, activities)
+        })
+        .collect();
 
     // Show sync analysis results
     display_sync_analysis(&local_activities, &remote_activities, &conflicts, verbose)?;
+    display_remote_disagreements(&remote_activities);
 
     if conflicts.is_empty() {
         println!("✅ NO CONFLICTS");
@@ -363,7 +1258,7 @@ fn run_remote_sync(
         println!("🔍 DRY RUN MODE - No changes will be applied");
         display_conflict_preview(&conflicts)?;
     } else {
-        handle_sync_conflicts(&conflicts, current_tasks)?;
+        handle_sync_conflicts(&conflicts, current_tasks, yes)?;
     }
 
     Ok(())
@@ -371,61 +1266,85 @@ fn run_remote_sync(
 
 /// Display comprehensive sync analysis results
 fn display_sync_analysis(
-    local_activities: &[crate::git::TaskActivity],
-    remote_activities: &[crate::git::TaskActivity],
-    conflicts: &[crate::git::SyncConflict],
+    local_activities: &[TaskActivity],
+    remote_activities: &[(String, Vec<TaskActivity>)],
+    conflicts: &[SyncConflict],
     verbose: bool,
 ) -> Result<()> {
     println!("📊 SYNC ANALYSIS RESULTS");
     println!("   Local activities: {}", local_activities.len());
-    println!("   Remote activities: {}", remote_activities.len());
+    for (remote, activities) in remote_activities {
+        println!("   Remote activities ({}): {}", remote, activities.len());
+    }
     println!("   Conflicts detected: {}\n", conflicts.len());
 
     if verbose {
         if !local_activities.is_empty() {
             println!("📝 LOCAL ACTIVITY:");
-            for activity in local_activities.iter().take(5) {
-                println!(
-                    "   {} - {} commits",
-                    activity.task_id,
-                    activity.commits.len()
-                );
-                if let Some(status) = &activity.suggested_status {
-                    println!(
-                        "     Suggested: {} (confidence: {:.0}%)",
-                        status,
-                        activity.confidence * 100.0
-                    );
-                }
-            }
-            println!();
+            display_activity_list(local_activities);
         }
 
-        if !remote_activities.is_empty() {
-            println!("🌐 REMOTE ACTIVITY:");
-            for activity in remote_activities.iter().take(5) {
-                println!(
-                    "   {} - {} commits",
-                    activity.task_id,
-                    activity.commits.len()
-                );
-                if let Some(status) = &activity.suggested_status {
-                    println!(
-                        "     Suggested: {} (confidence: {:.0}%)",
-                        status,
-                        activity.confidence * 100.0
-                    );
-                }
+        for (remote, activities) in remote_activities {
+            if !activities.is_empty() {
+                println!("🌐 REMOTE ACTIVITY ({}):", remote);
+                display_activity_list(activities);
             }
-            println!();
         }
     }
 
     Ok(())
 }
 
+fn display_activity_list(activities: &[TaskActivity]) {
+    for activity in activities.iter().take(5) {
+        println!(
+            "   {} - {} commits",
+            activity.task_id,
+            activity.commits.len()
+        );
+        if let Some(status) = &activity.suggested_status {
+            println!(
+                "     Suggested: {} (confidence: {:.0}%)",
+                status,
+                activity.confidence * 100.0
+            );
+        }
+    }
+    println!();
+}
+
+/// Tasks for which remotes suggest different statuses, as in forks that
+/// haven't merged each other's work yet
+fn display_remote_disagreements(remote_activities: &[(String, Vec<TaskActivity>)]) {
+    let mut suggestions: BTreeMap<&str, Vec<(&str, &str)>> = BTreeMap::new();
+    for (remote, activities) in remote_activities {
+        for activity in activities {
+            if let Some(status) = &activity.suggested_status {
+                suggestions
+                    .entry(&activity.task_id)
+                    .or_default()
+                    .push((remote, status));
+            }
+        }
+    }
+    suggestions.retain(|_, by_remote| by_remote.iter().any(|(_, s)| *s != by_remote[0].1));
+    if suggestions.is_empty() {
+        return;
+    }
+
+    println!("🔀 REMOTES DISAGREE");
+    for (task_id, by_remote) in &suggestions {
+        let by_remote: Vec<String> = by_remote
+            .iter()
+            .map(|(remote, status)| format!("{} → {}", remote, status))
+            .collect();
+        println!("   {}: {}", task_id, by_remote.join(", "));
+    }
+    println!();
+}
+
 /// Display conflict preview in dry-run mode
-fn display_conflict_preview(conflicts: &[crate::git::SyncConflict]) -> Result<()> {
+fn display_conflict_preview(conflicts: &[SyncConflict]) -> Result<()> {
     println!("⚠️  CONFLICTS THAT WOULD BE RESOLVED:");
     println!();
 
@@ -437,7 +1356,8 @@ fn display_conflict_preview(conflicts: &[crate::git::SyncConflict]) -> Result<()
             conflict.local_confidence * 100.0
         );
         println!(
-            "   Remote suggestion: {} (confidence: {:.0}%)",
+            "   Remote suggestion ({}): {} (confidence: {:.0}%)",
+            conflict.remote,
             conflict.remote_suggested_status,
             conflict.remote_confidence * 100.0
         );
@@ -457,12 +1377,17 @@ fn display_conflict_preview(conflicts: &[crate::git::SyncConflict]) -> Result<()
     Ok(())
 }
 
-/// Handle sync conflicts with interactive resolution
+/// Handle sync conflicts with interactive resolution; `yes` takes each recommendation
 fn handle_sync_conflicts(
-    conflicts: &[crate::git::SyncConflict],
+    conflicts: &[SyncConflict],
     current_tasks: &[Task],
+    yes: bool,
 ) -> Result<()> {
     println!("⚠️  RESOLVING {} CONFLICTS\n", conflicts.len());
+    let mut updated = 0;
+    // With several remotes a task can conflict once per remote; the first
+    // resolution that changes its file settles it
+    let mut updated_tasks: Vec<&str> = Vec::new();
 
     for (i, conflict) in conflicts.iter().enumerate() {
         println!(
@@ -472,6 +1397,11 @@ fn handle_sync_conflicts(
             conflict.task_id
         );
 
+        if updated_tasks.contains(&conflict.task_id.as_str()) {
+            println!("   ⏭️  Already updated from another remote\n");
+            continue;
+        }
+
         // Find current task for context
         let current_task = current_tasks.iter().find(|t| t.id == conflict.task_id);
         if let Some(task) = current_task {
@@ -484,7 +1414,8 @@ fn handle_sync_conflicts(
             conflict.local_confidence * 100.0
         );
         println!(
-            "   Remote suggestion: {} (confidence: {:.0}%)",
+            "   Remote suggestion ({}): {} (confidence: {:.0}%)",
+            conflict.remote,
             conflict.remote_suggested_status,
             conflict.remote_confidence * 100.0
         );
@@ -492,15 +1423,28 @@ fn handle_sync_conflicts(
         let resolution = match conflict.resolution {
             ConflictResolution::AcceptRemote => {
                 println!("   💡 Recommendation: Accept remote suggestion (higher confidence)");
-                prompt_user_choice("Accept remote suggestion?", true)?
+                if yes {
+                    UserChoice::AcceptRemote
+                } else {
+                    prompt_user_choice("Accept remote suggestion?", UserChoice::AcceptRemote)?
+                }
             }
             ConflictResolution::KeepLocal => {
                 println!("   💡 Recommendation: Keep local suggestion (higher confidence)");
-                prompt_user_choice("Keep local suggestion?", true)?
+                if yes {
+                    UserChoice::KeepLocal
+                } else {
+                    prompt_user_choice("Keep local suggestion?", UserChoice::KeepLocal)?
+                }
             }
             ConflictResolution::Interactive => {
                 println!("   💡 Both suggestions have similar confidence - your choice");
-                prompt_interactive_resolution()?
+                if yes {
+                    println!("   ⏭️  No clear recommendation; run without --yes to choose");
+                    UserChoice::Skip
+                } else {
+                    prompt_interactive_resolution()?
+                }
             }
             ConflictResolution::NoConflict => continue,
         };
@@ -511,14 +1455,21 @@ fn handle_sync_conflicts(
                     "   ✅ Accepting remote suggestion: {}",
                     conflict.remote_suggested_status
                 );
-                // TODO: Apply the status change to task file
-                println!(
-                    "   📝 Manual update required: Change task status to '{}'",
-                    conflict.remote_suggested_status
-                );
+                if apply_status(
+                    current_tasks,
+                    &conflict.task_id,
+                    &conflict.remote_suggested_status,
+                )? {
+                    updated += 1;
+                    updated_tasks.push(&conflict.task_id);
+                }
             }
             UserChoice::KeepLocal => {
                 println!("   ✅ Keeping local suggestion: {}", conflict.local_status);
+                if apply_status(current_tasks, &conflict.task_id, &conflict.local_status)? {
+                    updated += 1;
+                    updated_tasks.push(&conflict.task_id);
+                }
             }
             UserChoice::Skip => {
                 println!("   ⏭️  Skipping this conflict");
@@ -529,7 +1480,7 @@ fn handle_sync_conflicts(
 
     println!("🎯 SYNC COMPLETE");
     println!("   All conflicts have been resolved");
-    println!("   Note: Manual task file updates may be required");
+    println!("   Task files updated: {}", updated);
 
     Ok(())
 }
@@ -541,10 +1492,9 @@ enum UserChoice {
     Skip,
 }
 
-/// Prompt user for a yes/no choice with recommendation
-fn prompt_user_choice(question: &str, default: bool) -> Result<UserChoice> {
-    let default_str = if default { "Y/n" } else { "y/N" };
-    print!("   {} ({}) or (s)kip: ", question, default_str);
+/// Prompt to take the recommended choice (the default); "no" picks the other side
+fn prompt_user_choice(question: &str, recommended: UserChoice) -> Result<UserChoice> {
+    print!("   {} (Y/n) or (s)kip: ", question);
     io::stdout().flush()?;
 
     let mut input = String::new();
@@ -552,25 +1502,15 @@ fn prompt_user_choice(question: &str, default: bool) -> Result<UserChoice> {
     let input = input.trim().to_lowercase();
 
     match input.as_str() {
-        "" => Ok(if default {
-            UserChoice::AcceptRemote
-        } else {
-            UserChoice::KeepLocal
-        }),
-        "y" | "yes" => Ok(if default {
-            UserChoice::AcceptRemote
-        } else {
-            UserChoice::KeepLocal
-        }),
-        "n" | "no" => Ok(if default {
-            UserChoice::KeepLocal
-        } else {
-            UserChoice::AcceptRemote
+        "" | "y" | "yes" => Ok(recommended),
+        "n" | "no" => Ok(match recommended {
+            UserChoice::AcceptRemote => UserChoice::KeepLocal,
+            _ => UserChoice::AcceptRemote,
         }),
         "s" | "skip" => Ok(UserChoice::Skip),
         _ => {
             println!("   Invalid input. Please enter y, n, or s.");
-            prompt_user_choice(question, default)
+            prompt_user_choice(question, recommended)
         }
     }
 }
@@ -599,18 +1539,37 @@ fn prompt_interactive_resolution() -> Result<UserChoice> {
 // GITHUB SYNC FUNCTIONS
 // ========================================
 
-fn run_github_sync(tasks: &[Task], backfill_project: bool, dry_run: bool) -> Result<()> {
-    println!("🌐 GITHUB SYNC MODE");
-    if backfill_project {
-        println!("   Mode: Backfill Projects v2 Board");
-        println!("   Adding all existing issues to Projects v2 board...\n");
-    } else {
-        println!("   Syncing local tasks with GitHub Issues and Projects...\n");
+fn run_github_sync(tasks: &[Task], mut options: SyncOptions) -> Result<()> {
+    // In CI nobody can answer: the modes asked for are confirmed up front
+    options.yes |= options.ci;
+    let SyncOptions {
+        backfill_project,
+        dry_run,
+        apply_pull,
+        ci,
+        ..
+    } = options;
+
+    // `--format table|json` prints a plan instead of the dry run's narration
+    let plan_format = options.format.take().filter(|format| format != "text");
+    let json = plan_format.as_deref() == Some("json");
+    if !json {
+        println!("🌐 GITHUB SYNC MODE");
+        if backfill_project {
+            println!("   Mode: Backfill Projects v2 Board");
+            println!("   Adding all existing issues to Projects v2 board...\n");
+        } else {
+            println!("   Syncing local tasks with GitHub Issues and Projects...\n");
+        }
     }
 
     // Create GitHub client early for auto-setup
-    let client = GitHubClient::new()
-        .context("Failed to create GitHub client. Make sure you've run `gh auth login`")?;
+    let client = GitHubClient::new().with_context(|| {
+        format!(
+            "Failed to create GitHub client. Run `gh auth login` or set {}",
+            TOKEN_VARS.join(" or ")
+        )
+    })?;
 
     // Check if GitHub is configured, if not try to auto-configure
     let config = if !is_github_sync_enabled()? {
@@ -669,6 +1628,27 @@ fn run_github_sync(tasks: &[Task], backfill_project: bool, dry_run: bool) -> Res
         load_github_config().context("Failed to load GitHub configuration")?
     };
 
+    // Only tasks inside [sync.filter] and the command-line filter are synced
+    options.filter = Config::load_or_default(get_config_path()?)?
+        .sync
+        .unwrap_or_default()
+        .filter
+        .overridden_by(options.filter);
+    let options = &options;
+    let filter = &options.filter;
+    let in_scope = |tasks: &[Task]| -> Vec<Task> {
+        tasks
+            .iter()
+            .filter(|t| filter.matches(t))
+            .cloned()
+            .collect()
+    };
+    let tasks: &[Task] = &in_scope(tasks);
+    if !filter.is_empty() && !json {
+        println!("🔎 Sync filter: {}", filter.describe());
+        println!("   {} tasks in scope\n", tasks.len());
+    }
+
     // Load or create task-issue mapper
     let mut mapper = TaskIssueMapper::new().context("Failed to load task-issue mapper")?;
 
@@ -697,18 +1677,62 @@ fn run_github_sync(tasks: &[Task], backfill_project: bool, dry_run: bool) -> Res
         println!();
     }
 
+    // Each repository issues go to is pushed and pulled in turn
+    let targets = config.targets();
+    let mut report = PushReport::default();
+    let mut pulled = Vec::new();
+
     if backfill_project {
         // Backfill mode: add all existing issues to project board
         println!("🔄 BACKFILL: Adding existing issues to Projects v2 board");
         backfill_project_board(&client, &config, tasks, &mut mapper, dry_run)?;
+    } else if let Some(format) = plan_format {
+        let plan = plan_targets(&client, &targets, tasks, &mapper)?;
+        if format == "json" {
+            println!("{}", serde_json::to_string_pretty(&plan)?);
+        } else {
+            println!("📋 SYNC PLAN");
+            print!("{}", plan.render_table());
+        }
+    } else if let Some(path) = &options.apply_plan {
+        // Push only what was reviewed, and only while it's still what the
+        // push would do
+        let planned = SyncPlan::load(path)?;
+        println!("📋 APPLY PLAN: {}", path.display());
+        let current = plan_targets(&client, &targets, tasks, &mapper)?;
+        let approved = approved_tasks(&planned, &current, tasks);
+        println!("📤 PUSH: Local Tasks → GitHub Issues");
+        report = push_targets(&client, &targets, &approved, &mut mapper, options)?;
+    } else if apply_pull {
+        // GitHub's state wins: pull first so the push doesn't undo it
+        println!("📥 PULL: GitHub Issues → Local Tasks");
+        pulled = pull_targets(&client, &targets, tasks, &mut mapper, options)?;
+        let tasks = if dry_run {
+            // Push as if the changes had been written
+            let mut tasks = tasks.to_vec();
+            for task in &mut tasks {
+                if let Some((_, status)) = pulled.iter().find(|(id, _)| *id == task.id)
+                    && let Ok(status) = TaskStatus::parse(status)
+                {
+                    task.set_status(status);
+                }
+            }
+            tasks
+        } else {
+            in_scope(&load_all_tasks().context("Failed to reload tasks")?)
+        };
+
+        println!();
+        println!("📤 PUSH: Local Tasks → GitHub Issues");
+        report = push_targets(&client, &targets, &tasks, &mut mapper, options)?;
     } else {
         // Normal sync mode
         println!("📤 PUSH: Local Tasks → GitHub Issues");
-        push_tasks_to_github(&client, &config, tasks, &mut mapper, dry_run)?;
+        report = push_targets(&client, &targets, tasks, &mut mapper, options)?;
 
         println!();
         println!("📥 PULL: GitHub Issues → Local Tasks");
-        pull_issues_from_github(&client, &config, tasks, &mapper, dry_run)?;
+        pulled = pull_targets(&client, &targets, tasks, &mut mapper, options)?;
     }
 
     // Save updated mapping
@@ -718,39 +1742,553 @@ fn run_github_sync(tasks: &[Task], backfill_project: bool, dry_run: bool) -> Res
         println!("✅ Sync mapping saved to .taskguard/github-mapping.json");
     }
 
+    if ci {
+        report_to_actions(&report, pulled.len(), dry_run)?;
+    }
     Ok(())
 }
 
-fn push_tasks_to_github(
+/// What a push did, summed over targets
+#[derive(Debug, Default)]
+struct PushReport {
+    created: usize,
+    updated: usize,
+    skipped: usize,
+    /// Task ID → why it needs a person to sort it out
+    conflicts: Vec<(String, String)>,
+}
+
+/// `--ci`: annotate conflicts, set step outputs and add a job summary
+fn report_to_actions(report: &PushReport, pulled: usize, dry_run: bool) -> Result<()> {
+    for (task_id, reason) in &report.conflicts {
+        println!("{}", actions::annotation(Level::Warning, task_id, reason));
+    }
+    actions::set_outputs(&[
+        ("created", report.created.to_string()),
+        ("updated", report.updated.to_string()),
+        ("skipped", report.skipped.to_string()),
+        ("conflicts", report.conflicts.len().to_string()),
+        ("pulled", pulled.to_string()),
+    ])?;
+
+    let mut summary = format!(
+        "### TaskGuard GitHub sync{}\n\n",
+        if dry_run { " (dry run)" } else { "" }
+    );
+    summary.push_str("| Created | Updated | Skipped | Conflicts | Changed on GitHub |\n");
+    summary.push_str("| --- | --- | --- | --- | --- |\n");
+    let _ = writeln!(
+        summary,
+        "| {} | {} | {} | {} | {} |",
+        report.created,
+        report.updated,
+        report.skipped,
+        report.conflicts.len(),
+        pulled
+    );
+    if !report.conflicts.is_empty() {
+        summary.push_str("\n**Conflicts**\n\n");
+        for (task_id, reason) in &report.conflicts {
+            let _ = writeln!(summary, "- `{}`: {}", task_id, reason);
+        }
+    }
+    actions::append_summary(&summary)
+}
+
+/// Tasks whose issues belong in the target's repository: the one their issue
+/// is in, else the one `[routes]` sends their area to
+fn target_tasks(config: &GitHubConfig, tasks: &[Task], mapper: &TaskIssueMapper) -> Vec<Task> {
+    tasks
+        .iter()
+        .filter(|task| {
+            let repo = match mapper.get_by_task_id(&task.id) {
+                Some(mapping) => mapping.repo.as_deref(),
+                None => config.route(&task.area),
+            };
+            repo.map(str::to_lowercase) == config.target.as_deref().map(str::to_lowercase)
+        })
+        .cloned()
+        .collect()
+}
+
+/// Print the repository a target syncs, when there are several
+fn target_heading(target: &GitHubConfig, targets: &[GitHubConfig]) {
+    if targets.len() > 1 {
+        let (owner, repo) = target.issue_repo();
+        println!("\n📦 {}/{}", owner, repo);
+    }
+}
+
+/// Push each target's tasks, those an earlier rate-limited sync didn't get to
+/// first. Stopped by the rate limit, record the tasks left for the next sync.
+fn push_targets(
     client: &GitHubClient,
-    config: &GitHubConfig,
+    targets: &[GitHubConfig],
     tasks: &[Task],
     mapper: &mut TaskIssueMapper,
-    dry_run: bool,
-) -> Result<()> {
-    let mut created = 0;
-    let mut updated = 0;
-    let mut skipped = 0;
-
-    for task in tasks {
-        // Detect if task is archived
-        let is_archived = task.file_path.to_string_lossy().contains("archive");
+    options: &SyncOptions,
+) -> Result<PushReport> {
+    let dry_run = options.dry_run;
+    let root =
+        find_taskguard_root().context("Not in a TaskGuard project. Run 'taskguard init' first.")?;
+    let pending = ResumeState::load(&root)
+        .map(|state| state.pending)
+        .unwrap_or_default();
+    if !pending.is_empty() {
+        println!(
+            "   ▶️  Resuming: pushing {} tasks a rate-limited sync didn't get to first",
+            pending.len()
+        );
+    }
 
-        // Check if task already has a GitHub issue
-        if let Some(mapping) = mapper.get_by_task_id(&task.id) {
-            // Task has issue - check if update needed
-            let issue = GitHubQueries::get_issue_by_id(client, &mapping.issue_id)
-                .context(format!("Failed to get issue for task {}", task.id))?;
+    let mut report = PushReport::default();
+    for (index, target) in targets.iter().enumerate() {
+        target_heading(target, targets);
+        let mut routed = target_tasks(target, tasks, mapper);
+        routed.sort_by_key(|task| !pending.contains(&task.id));
+        let mut done = 0;
+        let Err(e) = push_tasks_to_github(
+            client,
+            target,
+            &routed,
+            mapper,
+            options,
+            &mut done,
+            &mut report,
+        ) else {
+            continue;
+        };
+        let Some(limited) = e.downcast_ref::<RateLimited>().copied() else {
+            return Err(e);
+        };
+        if dry_run {
+            return Err(e);
+        }
 
-            // Compare states
-            let github_state = map_github_state_to_taskguard(&issue.state);
-            let local_state = task.status.to_string();
+        let mut left: Vec<String> = routed[done..].iter().map(|t| t.id.clone()).collect();
+        for later in &targets[index + 1..] {
+            left.extend(target_tasks(later, tasks, mapper).into_iter().map(|t| t.id));
+        }
+        let count = left.len();
+        ResumeState {
+            interrupted_at: chrono::Utc::now(),
+            reset_at: limited.reset_at,
+            pending: left,
+        }
+        .save(&root)?;
+        return Err(e.context(format!(
+            "Sync stopped with {} tasks left to push; run 'taskguard sync --github' again to resume",
+            count
+        )));
+    }
 
-            if local_state != github_state {
-                println!("   🔄 {} - {} (status mismatch)", task.id, task.title);
-                println!("      Local: {:?}, GitHub: {}", task.status, issue.state);
+    if !dry_run {
+        ResumeState::clear(&root)?;
+    }
+    Ok(report)
+}
 
-                if !dry_run {
+/// Pull each target's issues, returning the status changes pulled
+fn pull_targets(
+    client: &GitHubClient,
+    targets: &[GitHubConfig],
+    tasks: &[Task],
+    mapper: &mut TaskIssueMapper,
+    options: &SyncOptions,
+) -> Result<Vec<(String, String)>> {
+    let mut pulled = Vec::new();
+    for target in targets {
+        target_heading(target, targets);
+        match pull_issues_from_github(client, target, tasks, mapper, options) {
+            Ok(changes) => pulled.extend(changes),
+            Err(e) if e.downcast_ref::<RateLimited>().is_some() => {
+                return Err(e.context(
+                    "Pull stopped before writing its changes; run 'taskguard sync --github' again to finish",
+                ));
+            }
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(pulled)
+}
+
+/// What pushing each target's tasks would do, without changing anything
+fn plan_targets(
+    client: &GitHubClient,
+    targets: &[GitHubConfig],
+    tasks: &[Task],
+    mapper: &TaskIssueMapper,
+) -> Result<SyncPlan> {
+    let mut plan = SyncPlan::default();
+    for target in targets {
+        for task in target_tasks(target, tasks, mapper) {
+            match plan_task(client, target, &task, mapper)? {
+                Some(entry) => plan.entries.push(entry),
+                None => plan.unchanged += 1,
+            }
+        }
+    }
+    Ok(plan)
+}
+
+/// The plan entry for a task, `None` when its issue is already in sync.
+/// Changes are worked out the way the push merges them.
+fn plan_task(
+    client: &GitHubClient,
+    config: &GitHubConfig,
+    task: &Task,
+    mapper: &TaskIssueMapper,
+) -> Result<Option<PlanEntry>> {
+    let (owner, repo) = config.issue_repo();
+    let mut entry = PlanEntry {
+        task_id: task.id.clone(),
+        title: task.title.clone(),
+        action: PlanAction::Create,
+        repo: format!("{}/{}", owner, repo),
+        issue: None,
+        changes: Vec::new(),
+    };
+
+    let Some(mapping) = mapper.get_by_task_id(&task.id) else {
+        match search_github_for_task_id(client, config, &task.id)? {
+            Some((number, title, _, _)) => {
+                entry.issue = i64::try_from(number).ok();
+                if title == task.title {
+                    entry.action = PlanAction::Adopt;
+                } else {
+                    entry.action = PlanAction::Conflict;
+                    entry
+                        .changes
+                        .push(format!("ID already used by \"{}\"", title));
+                }
+            }
+            None if task.file_path.to_string_lossy().contains("archive") => {
+                entry.changes.push("closed (archived task)".to_string());
+            }
+            None => {}
+        }
+        return Ok(Some(entry));
+    };
+
+    entry.action = PlanAction::Update;
+    entry.issue = Some(mapping.issue_number);
+    let issue = GitHubQueries::get_issue_by_id(client, &mapping.issue_id)
+        .context(format!("Failed to get issue for task {}", task.id))?;
+
+    if let Some(body) = issue.body.as_deref() {
+        if issue_task_hash(body) == Some(hash_task_content(task).as_str()) {
+            let remote_checked = issue_checked_items(task, body);
+            let checked = merged_checked(task, remote_checked.as_deref(), &mapping.checked);
+            let with_progress = apply_progress_line(body, task).unwrap_or_else(|| body.to_string());
+            let current = checklist_section(task, &checked)
+                .and_then(|section| apply_checklist_section(&with_progress, &section))
+                .unwrap_or(with_progress);
+            if current != body {
+                entry.changes.push("progress".to_string());
+            }
+        } else {
+            let remote = issue_description(body).map(hash_description);
+            if mapping.description_hash.is_some() && remote != mapping.description_hash {
+                entry.action = PlanAction::Conflict;
+                entry
+                    .changes
+                    .push("description edited on GitHub and locally".to_string());
+            } else {
+                entry.changes.push("body".to_string());
+            }
+        }
+    }
+
+    let state = map_taskguard_status_to_github(&task.status);
+    if !issue.state.eq_ignore_ascii_case(state) {
+        entry
+            .changes
+            .push(format!("state {} → {}", issue.state.to_uppercase(), state));
+    }
+
+    let tags = synced_labels(config, &task.tags);
+    let labels = synced_labels(config, &issue.labels);
+    let merged = TaskIssueMapper::merge_names(&mapping.labels, &tags, &labels);
+    let (add, remove) = (
+        missing_names(&merged, &labels),
+        missing_names(&labels, &merged),
+    );
+    if !add.is_empty() || !remove.is_empty() {
+        entry
+            .changes
+            .push(format!("labels {}", name_changes(&add, &remove)));
+    }
+
+    if !config.users.is_empty() {
+        let logins = assignee_logins(config, &task.assignees);
+        let remote: Vec<String> = issue
+            .assignees
+            .iter()
+            .filter(|login| config.user(login).is_some())
+            .cloned()
+            .collect();
+        let merged = TaskIssueMapper::merge_names(&mapping.assignees, &logins, &remote);
+        let (add, remove) = (
+            missing_names(&merged, &remote),
+            missing_names(&remote, &merged),
+        );
+        if !add.is_empty() || !remove.is_empty() {
+            entry
+                .changes
+                .push(format!("assignees {}", name_changes(&add, &remove)));
+        }
+    }
+
+    let milestone = TaskIssueMapper::merge_value(
+        mapping.milestone.as_deref(),
+        task.epic.as_deref(),
+        issue.milestone.as_deref(),
+    );
+    if milestone != issue.milestone {
+        entry.changes.push(milestone.map_or_else(
+            || "milestone cleared".to_string(),
+            |title| format!("milestone {}", title),
+        ));
+    }
+
+    if config.comments.push() && !worklog::parse_entries(&task.content).is_empty() {
+        let comments: Vec<String> = GitHubQueries::get_issue_comments(client, &issue.id)
+            .context(format!("Failed to get comments for task {}", task.id))?
+            .into_iter()
+            .map(|comment| comment.body)
+            .collect();
+        let unposted = unposted_log_entries(task, &comments).len();
+        if unposted > 0 {
+            entry.changes.push(format!("{} comments", unposted));
+        }
+    }
+
+    Ok((!entry.changes.is_empty()).then_some(entry))
+}
+
+/// Tasks whose entries in a reviewed plan still match a fresh plan. The rest,
+/// and conflicts, are reported and left for the next sync.
+fn approved_tasks(planned: &SyncPlan, current: &SyncPlan, tasks: &[Task]) -> Vec<Task> {
+    let mut approved = Vec::new();
+    for entry in &planned.entries {
+        if entry.action == PlanAction::Conflict {
+            println!(
+                "   ⏭️  {} - conflict: {}",
+                entry.task_id,
+                entry.changes.join(", ")
+            );
+            continue;
+        }
+        match current.entry(&entry.task_id) {
+            Some(now) if now == entry => {
+                approved.extend(tasks.iter().find(|t| t.id == entry.task_id).cloned());
+            }
+            Some(_) => println!(
+                "   ⏭️  {} - changed since the plan was made; review a new plan",
+                entry.task_id
+            ),
+            None => println!("   ⏭️  {} - nothing left to push", entry.task_id),
+        }
+    }
+
+    let unplanned = current
+        .entries
+        .iter()
+        .filter(|entry| planned.entry(&entry.task_id).is_none())
+        .count();
+    if unplanned > 0 {
+        println!(
+            "   ℹ️  {} tasks with changes not in the plan are left for the next sync",
+            unplanned
+        );
+    }
+    println!(
+        "   Applying {} of {} planned entries\n",
+        approved.len(),
+        planned.entries.len()
+    );
+    approved
+}
+
+/// Push tasks to the target's repository, adding to `report`. `done` counts
+/// the tasks finished, to tell where a failed push stopped.
+fn push_tasks_to_github(
+    client: &GitHubClient,
+    config: &GitHubConfig,
+    tasks: &[Task],
+    mapper: &mut TaskIssueMapper,
+    options: &SyncOptions,
+    done: &mut usize,
+    report: &mut PushReport,
+) -> Result<()> {
+    let dry_run = options.dry_run;
+    // In CI conflicts are reported rather than asked about
+    let interactive = !options.ci;
+    let mut created = 0;
+    let mut updated = 0;
+    let mut skipped = 0;
+    let mut repo_labels = None;
+    let mut known_users = HashMap::new();
+    let mut milestones = None;
+    let mut project = None;
+
+    for (index, task) in tasks.iter().enumerate() {
+        *done = index;
+
+        // Detect if task is archived
+        let is_archived = task.file_path.to_string_lossy().contains("archive");
+
+        // Check if task already has a GitHub issue
+        if let Some(mapping) = mapper.get_by_task_id(&task.id).cloned() {
+            // Task has issue - check if update needed
+            let issue = GitHubQueries::get_issue_by_id(client, &mapping.issue_id)
+                .context(format!("Failed to get issue for task {}", task.id))?;
+
+            // Rewrite the issue body when the task changed since it was
+            // written, asking first if the description was edited on GitHub
+            // too. Keep the progress line and the checklist current either
+            // way, keeping checkboxes toggled on GitHub for the pull.
+            let remote_checked = issue
+                .body
+                .as_deref()
+                .and_then(|body| issue_checked_items(task, body));
+            let checked = merged_checked(task, remote_checked.as_deref(), &mapping.checked);
+            let mut description_hash = mapping.description_hash.clone();
+            let new_body = match issue.body.as_deref() {
+                Some(body) => {
+                    let task_hash = hash_task_content(task);
+                    let mut current = body.to_string();
+                    if issue_task_hash(body) != Some(task_hash.as_str()) {
+                        let remote = issue_description(body).map(hash_description);
+                        let choice = if mapping.description_hash.is_some()
+                            && remote != mapping.description_hash
+                        {
+                            choose_body_version(task, dry_run, interactive)?
+                        } else {
+                            BodyChoice::Task
+                        };
+                        match choice {
+                            BodyChoice::Task => {
+                                current = issue_body(task, config, is_archived, &checked);
+                                description_hash =
+                                    issue_description(&current).map(hash_description);
+                            }
+                            BodyChoice::GitHub => {
+                                current = body
+                                    .lines()
+                                    .map(|line| {
+                                        if line.starts_with("**Hash:**") {
+                                            format!("**Hash:** {}", task_hash)
+                                        } else {
+                                            line.to_string()
+                                        }
+                                    })
+                                    .collect::<Vec<_>>()
+                                    .join("\n");
+                                description_hash = remote;
+                            }
+                            BodyChoice::Skip => report.conflicts.push((
+                                task.id.clone(),
+                                format!(
+                                    "Description of issue #{} was edited on GitHub and the task changed; run 'taskguard sync --github' in a terminal to choose",
+                                    mapping.issue_number
+                                ),
+                            )),
+                        }
+                    }
+                    let with_progress = apply_progress_line(&current, task).unwrap_or(current);
+                    let updated = checklist_section(task, &checked)
+                        .and_then(|section| apply_checklist_section(&with_progress, &section))
+                        .unwrap_or(with_progress);
+                    (updated != body).then_some(updated)
+                }
+                None => None,
+            };
+            if let Some(body) = &new_body {
+                if dry_run {
+                    println!("   📋 {} - would update issue body", task.id);
+                } else {
+                    GitHubMutations::update_issue_body(client, &issue.id, body)
+                        .context(format!("Failed to update issue body for task {}", task.id))?;
+                    println!("   📋 {} - updated issue body", task.id);
+                }
+            }
+            if !dry_run && let Some(current) = mapper.get_by_task_id(&task.id).cloned() {
+                let mut base = current.clone();
+                if same_names(&checked_items(&task.content), &checked) {
+                    base.checked = checked;
+                }
+                base.description_hash = description_hash;
+                if base != current {
+                    mapper
+                        .update_mapping(base)
+                        .context(format!("Failed to save mapping for task {}", task.id))?;
+                }
+            }
+
+            // Mirror new `## Log` entries as issue comments
+            let mut comments_posted = 0;
+            if config.comments.push() && !worklog::parse_entries(&task.content).is_empty() {
+                let comments: Vec<String> = GitHubQueries::get_issue_comments(client, &issue.id)
+                    .context(format!("Failed to get comments for task {}", task.id))?
+                    .into_iter()
+                    .map(|comment| comment.body)
+                    .collect();
+                for entry in unposted_log_entries(task, &comments) {
+                    if dry_run {
+                        println!("   💬 {} - would comment: {}", task.id, entry.text);
+                    } else {
+                        GitHubMutations::add_issue_comment(
+                            client,
+                            &issue.id,
+                            &entry.comment_body(),
+                        )
+                        .context(format!("Failed to comment on issue for task {}", task.id))?;
+                        println!("   💬 {} - commented: {}", task.id, entry.text);
+                        comments_posted += 1;
+                    }
+                }
+            }
+
+            let labels_changed = push_labels(
+                client,
+                config,
+                &mut repo_labels,
+                task,
+                &issue,
+                mapper,
+                dry_run,
+            )?;
+            let assignees_changed = push_assignees(
+                client,
+                config,
+                &mut known_users,
+                task,
+                &issue,
+                mapper,
+                dry_run,
+            )?;
+            let milestone_changed = push_milestone(
+                client,
+                config,
+                &mut milestones,
+                task,
+                &issue,
+                mapper,
+                dry_run,
+            )?;
+            let fields_changed =
+                push_project_fields(client, config, &mut project, task, mapper, dry_run)?;
+
+            // Compare states
+            let github_state = map_github_state_to_taskguard(&issue.state);
+            let local_state = task.status.to_string();
+
+            if local_state != github_state {
+                println!("   🔄 {} - {} (status mismatch)", task.id, task.title);
+                println!("      Local: {:?}, GitHub: {}", task.status, issue.state);
+
+                if !dry_run {
                     // Update GitHub to match local
                     let new_state = map_taskguard_status_to_github(&task.status);
                     GitHubMutations::update_issue_state(client, &issue.id, new_state)
@@ -794,13 +2332,26 @@ fn push_tasks_to_github(
                 } else {
                     println!("      Would update GitHub issue to {:?}", task.status);
                 }
+            } else if (new_body.is_some()
+                || comments_posted > 0
+                || labels_changed
+                || assignees_changed
+                || milestone_changed
+                || fields_changed)
+                && !dry_run
+            {
+                updated += 1;
             } else {
                 skipped += 1;
             }
         } else {
-            // No issue exists in local mapping - check GitHub for cross-branch duplicates
-            if let Ok(Some((existing_num, existing_title, _existing_state, existing_branch))) =
-                search_github_for_task_id(client, config, &task.id)
+            // No issue exists in local mapping - check GitHub for cross-branch duplicates.
+            // A rate-limited search must not pass for "none found".
+            let existing = match search_github_for_task_id(client, config, &task.id) {
+                Err(e) if e.downcast_ref::<RateLimited>().is_some() => return Err(e),
+                result => result.ok().flatten(),
+            };
+            if let Some((existing_num, existing_title, _existing_state, existing_branch)) = existing
             {
                 // Found existing issue on GitHub - check if it's the same task
                 let branch_info = existing_branch.as_deref().unwrap_or("unknown");
@@ -816,11 +2367,9 @@ fn push_tasks_to_github(
 
                     if !dry_run {
                         // Get issue details via GraphQL to get the node ID
+                        let (owner, repo) = config.issue_repo();
                         let issues = GitHubQueries::search_issues_by_taskguard_id(
-                            client,
-                            &config.owner,
-                            &config.repo,
-                            &task.id,
+                            client, owner, repo, &task.id,
                         )
                         .unwrap_or_default();
 
@@ -828,10 +2377,17 @@ fn push_tasks_to_github(
                             let mapping = IssueMapping {
                                 task_id: task.id.clone(),
                                 issue_number: existing_issue.number,
+                                repo: config.target.clone(),
                                 issue_id: existing_issue.id.clone(),
                                 project_item_id: String::new(), // Will be populated if needed
                                 synced_at: chrono::Utc::now().to_rfc3339(),
                                 is_archived,
+                                labels: Vec::new(),
+                                assignees: Vec::new(),
+                                milestone: None,
+                                fields: BTreeMap::new(),
+                                checked: Vec::new(),
+                                description_hash: None,
                             };
                             mapper.add_mapping(mapping).context(format!(
                                 "Failed to adopt issue mapping for task {}",
@@ -855,6 +2411,13 @@ fn push_tasks_to_github(
                     );
                     println!("      ⚠️  These are DIFFERENT tasks with the same ID!");
                     println!("      → Rename your local task ID to avoid conflict");
+                    report.conflicts.push((
+                        task.id.clone(),
+                        format!(
+                            "ID also used by issue #{} \"{}\"; rename the local task",
+                            existing_num, existing_title
+                        ),
+                    ));
                     skipped += 1;
                     continue;
                 }
@@ -871,73 +2434,38 @@ fn push_tasks_to_github(
             }
 
             if !dry_run {
-                // Build issue body with TaskGuard ID, branch info, and hash for tracking
-                let branch_name = get_current_branch().unwrap_or_else(|| "unknown".to_string());
-                let task_hash = hash_task_content(task);
-
-                // Try to extract Context section, fall back to first paragraph
-                let description = if let Some(context) = extract_context_section(&task.content) {
-                    // Use Context section content
-                    if context.len() > 200 {
-                        context[..200].to_string()
-                    } else {
-                        context
-                    }
-                } else {
-                    // Fallback: Extract first paragraph
-                    let first_para = task
-                        .content
-                        .lines()
-                        .skip_while(|line| line.starts_with('#') || line.trim().is_empty())
-                        .take_while(|line| !line.trim().is_empty())
-                        .collect::<Vec<_>>()
-                        .join("\n");
-
-                    if first_para.is_empty() {
-                        "No description".to_string()
-                    } else if first_para.len() > 200 {
-                        first_para[..200].to_string()
-                    } else {
-                        first_para
-                    }
-                };
+                let body = issue_body(task, config, is_archived, &checked_items(&task.content));
 
-                let description = description.as_str();
+                let (owner, repo) = config.issue_repo();
+                let mut issue =
+                    GitHubMutations::create_issue(client, owner, repo, &task.title, Some(&body))
+                        .context(format!("Failed to create issue for task {}", task.id))?;
 
-                let archived_note = if is_archived {
-                    "\n\n📦 **Note:** This task was archived when the issue was created."
-                } else {
-                    ""
-                };
-
-                // Build task file link
-                let file_path = format!("tasks/{}/{}.md", task.area, task.id);
-                let file_url = format!(
-                    "https://github.com/{}/{}/blob/{}/{}",
-                    config.owner, config.repo, branch_name, file_path
-                );
+                println!("      ✅ Created issue #{}", issue.number);
 
-                let body = format!(
-                    "**TaskGuard ID:** {}  \n**Task File:** [{}]({})\n**Source Branch:** {}\n**Hash:** {}\n\n## Description\n\n{}{}\n\n---\n*Synced from TaskGuard*",
-                    task.id,
-                    file_path,
-                    file_url,
-                    branch_name,
-                    task_hash,
-                    description,
-                    archived_note
-                );
+                let labels = synced_labels(config, &task.tags);
+                if !labels.is_empty() {
+                    let label_ids = label_ids(client, config, &mut repo_labels, &labels)?;
+                    GitHubMutations::add_labels(client, &issue.id, &label_ids)
+                        .context(format!("Failed to label issue #{}", issue.number))?;
+                    println!("      🏷️  Labeled: {}", labels.join(", "));
+                }
 
-                let mut issue = GitHubMutations::create_issue(
-                    client,
-                    &config.owner,
-                    &config.repo,
-                    &task.title,
-                    Some(&body),
-                )
-                .context(format!("Failed to create issue for task {}", task.id))?;
+                let assignees = assignee_logins(config, &task.assignees);
+                if !assignees.is_empty() {
+                    let ids = user_ids(client, &mut known_users, &assignees)?;
+                    GitHubMutations::add_assignees(client, &issue.id, &ids)
+                        .context(format!("Failed to assign issue #{}", issue.number))?;
+                    println!("      👥 Assigned: {}", assignees.join(", "));
+                }
 
-                println!("      ✅ Created issue #{}", issue.number);
+                if let Some(epic) = &task.epic {
+                    let id = milestone_id(client, config, &mut milestones, epic)?;
+                    GitHubMutations::update_issue_milestone(client, &issue.id, Some(&id)).context(
+                        format!("Failed to set milestone of issue #{}", issue.number),
+                    )?;
+                    println!("      🏁 Milestone: {}", epic);
+                }
 
                 // If task is archived, immediately close the issue
                 if is_archived {
@@ -959,101 +2487,711 @@ fn push_tasks_to_github(
                 )
                 .context("Failed to get project ID")?;
 
-                // 2. Add issue to project
-                let project_item_id =
-                    GitHubMutations::add_issue_to_project(client, &project_id, &issue.id)
-                        .context(format!("Failed to add issue #{} to project", issue.number))?;
-                println!(
-                    "      ✅ Added to project (item: {})",
-                    &project_item_id[..8]
-                );
+                // 2. Add issue to project
+                let project_item_id =
+                    GitHubMutations::add_issue_to_project(client, &project_id, &issue.id)
+                        .context(format!("Failed to add issue #{} to project", issue.number))?;
+                println!(
+                    "      ✅ Added to project (item: {})",
+                    &project_item_id[..8]
+                );
+
+                // 3. Get status field info
+                let (field_id, options) =
+                    GitHubMutations::get_status_field_info(client, &project_id)
+                        .context("Failed to get status field info")?;
+
+                // 4. Find matching status column and set it
+                if let Some(option_id) =
+                    TaskIssueMapper::find_best_status_option(&task.status, &options)
+                {
+                    // 5. Set status
+                    GitHubMutations::update_project_item_status(
+                        client,
+                        &project_id,
+                        &project_item_id,
+                        &field_id,
+                        &option_id,
+                    )
+                    .context(format!(
+                        "Failed to update status for issue #{}",
+                        issue.number
+                    ))?;
+                    println!("      🎯 Status set successfully");
+                } else {
+                    println!(
+                        "      ⚠️  No matching status column found for '{}'",
+                        task.status
+                    );
+                }
+
+                // 6. Save mapping with project_item_id and archived status
+                let mapping = IssueMapping {
+                    task_id: task.id.clone(),
+                    issue_number: issue.number,
+                    repo: config.target.clone(),
+                    issue_id: issue.id.clone(),
+                    project_item_id,
+                    synced_at: chrono::Utc::now().to_rfc3339(),
+                    is_archived,
+                    labels,
+                    assignees,
+                    milestone: task.epic.clone(),
+                    fields: BTreeMap::new(),
+                    checked: checked_items(&task.content),
+                    description_hash: issue_description(&body).map(hash_description),
+                };
+                mapper
+                    .add_mapping(mapping)
+                    .context(format!("Failed to save mapping for task {}", task.id))?;
+                push_project_fields(client, config, &mut project, task, mapper, dry_run)?;
+
+                created += 1;
+            } else {
+                println!("      Would create GitHub issue");
+            }
+        }
+    }
+
+    *done = tasks.len();
+
+    println!();
+    println!("📊 PUSH SUMMARY");
+    println!("   Created: {}", created);
+    println!("   Updated: {}", updated);
+    println!("   Skipped: {} (already in sync)", skipped);
+
+    report.created += created;
+    report.updated += updated;
+    report.skipped += skipped;
+    Ok(())
+}
+
+/// Names among `names` that aren't excluded from label sync
+pub(crate) fn synced_labels(config: &GitHubConfig, names: &[String]) -> Vec<String> {
+    names
+        .iter()
+        .filter(|name| config.labels.is_synced(name))
+        .cloned()
+        .collect()
+}
+
+/// IDs of the named labels, creating those the repository doesn't have yet.
+/// `repo_labels` caches the repository's labels (lowercased name → ID).
+fn label_ids(
+    client: &GitHubClient,
+    config: &GitHubConfig,
+    repo_labels: &mut Option<HashMap<String, String>>,
+    names: &[String],
+) -> Result<Vec<String>> {
+    let (owner, repo) = config.issue_repo();
+    let known = match repo_labels {
+        Some(known) => known,
+        None => repo_labels.insert(
+            GitHubQueries::get_repository_labels(client, owner, repo)?
+                .into_iter()
+                .map(|(id, name)| (name.to_lowercase(), id))
+                .collect(),
+        ),
+    };
+
+    let mut ids = Vec::new();
+    for name in names {
+        let id = match known.get(&name.to_lowercase()) {
+            Some(id) => id.clone(),
+            None => {
+                let id = GitHubMutations::create_label(
+                    client,
+                    owner,
+                    repo,
+                    name,
+                    config.labels.color(name),
+                )?;
+                println!("      🏷️  Created label '{}'", name);
+                known.insert(name.to_lowercase(), id.clone());
+                id
+            }
+        };
+        ids.push(id);
+    }
+    Ok(ids)
+}
+
+/// Names missing from `from`, compared case-insensitively
+fn missing_names(names: &[String], from: &[String]) -> Vec<String> {
+    names
+        .iter()
+        .filter(|name| !from.iter().any(|n| n.eq_ignore_ascii_case(name)))
+        .cloned()
+        .collect()
+}
+
+/// `+added, -removed`
+fn name_changes(add: &[String], remove: &[String]) -> String {
+    add.iter()
+        .map(|name| format!("+{}", name))
+        .chain(remove.iter().map(|name| format!("-{}", name)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// GitHub logins of assignees with a `[users]` entry
+fn assignee_logins(config: &GitHubConfig, assignees: &[String]) -> Vec<String> {
+    assignees
+        .iter()
+        .filter_map(|user| config.login(user).map(str::to_string))
+        .collect()
+}
+
+/// GitHub node IDs of users, looked up once per login
+fn user_ids(
+    client: &GitHubClient,
+    known: &mut HashMap<String, String>,
+    logins: &[String],
+) -> Result<Vec<String>> {
+    let mut ids = Vec::new();
+    for login in logins {
+        let id = match known.get(&login.to_lowercase()) {
+            Some(id) => id.clone(),
+            None => {
+                let id = GitHubQueries::get_user_id(client, login)?;
+                known.insert(login.to_lowercase(), id.clone());
+                id
+            }
+        };
+        ids.push(id);
+    }
+    Ok(ids)
+}
+
+/// Set the issue's assignees to the task's, through `[users]`, merged with
+/// assignee changes made on GitHub since the last sync, like `push_labels`.
+/// Assignees without a `[users]` entry on either side are left alone.
+/// Returns whether the issue's assignees changed.
+fn push_assignees(
+    client: &GitHubClient,
+    config: &GitHubConfig,
+    known_users: &mut HashMap<String, String>,
+    task: &Task,
+    issue: &GitHubIssue,
+    mapper: &mut TaskIssueMapper,
+    dry_run: bool,
+) -> Result<bool> {
+    if config.users.is_empty() {
+        return Ok(false);
+    }
+    let Some(mapping) = mapper.get_by_task_id(&task.id).cloned() else {
+        return Ok(false);
+    };
+    for user in task.assignees.iter().filter(|u| config.login(u).is_none()) {
+        println!(
+            "   ⚠️  {} - assignee '{}' has no [users] entry in github.toml; not assigned on GitHub",
+            task.id, user
+        );
+    }
+
+    let logins = assignee_logins(config, &task.assignees);
+    let remote: Vec<String> = issue
+        .assignees
+        .iter()
+        .filter(|login| config.user(login).is_some())
+        .cloned()
+        .collect();
+    let merged = TaskIssueMapper::merge_names(&mapping.assignees, &logins, &remote);
+
+    let add = missing_names(&merged, &remote);
+    let remove = missing_names(&remote, &merged);
+    let changed = !add.is_empty() || !remove.is_empty();
+    if changed {
+        let changes = name_changes(&add, &remove);
+        if dry_run {
+            println!("   👥 {} - would update assignees: {}", task.id, changes);
+        } else {
+            if !add.is_empty() {
+                let ids = user_ids(client, known_users, &add)?;
+                GitHubMutations::add_assignees(client, &issue.id, &ids)
+                    .context(format!("Failed to assign issue for task {}", task.id))?;
+            }
+            if !remove.is_empty() {
+                let ids = user_ids(client, known_users, &remove)?;
+                GitHubMutations::remove_assignees(client, &issue.id, &ids)
+                    .context(format!("Failed to unassign issue for task {}", task.id))?;
+            }
+            println!("   👥 {} - updated assignees: {}", task.id, changes);
+        }
+    }
+
+    if !dry_run && same_names(&logins, &merged) && !same_names(&mapping.assignees, &merged) {
+        mapper
+            .update_mapping(IssueMapping {
+                assignees: merged,
+                ..mapping
+            })
+            .context(format!("Failed to save mapping for task {}", task.id))?;
+    }
+    Ok(changed)
+}
+
+/// ID of the milestone titled `title`, creating it if the repository has none.
+/// `milestones` caches the repository's milestones (title → ID).
+fn milestone_id(
+    client: &GitHubClient,
+    config: &GitHubConfig,
+    milestones: &mut Option<HashMap<String, String>>,
+    title: &str,
+) -> Result<String> {
+    let (owner, repo) = config.issue_repo();
+    let known = match milestones {
+        Some(known) => known,
+        None => milestones.insert(
+            GitHubQueries::get_milestones(client, owner, repo)?
+                .into_iter()
+                .map(|(id, title)| (title, id))
+                .collect(),
+        ),
+    };
+    if let Some(id) = known.get(title) {
+        return Ok(id.clone());
+    }
+    let id = GitHubMutations::create_milestone(client, owner, repo, title)?;
+    println!("      🏁 Created milestone '{}'", title);
+    known.insert(title.to_string(), id.clone());
+    Ok(id)
+}
+
+/// Task attributes `[field_mapping]` can push into project fields
+const FIELD_ATTRIBUTES: [&str; 5] = ["priority", "complexity", "estimate", "due", "iteration"];
+
+/// Value of a task attribute named in `[field_mapping]`; `None` when unset
+fn task_field_value(task: &Task, attribute: &str) -> Option<String> {
+    match attribute {
+        "priority" => Some(task.priority.to_string()),
+        "complexity" => task.complexity.map(|c| c.to_string()),
+        "estimate" => task.estimate.clone(),
+        // The iteration is the one containing the due date
+        "due" | "iteration" => task.due.map(|due| due.to_string()),
+        _ => None,
+    }
+}
+
+/// The project's node ID and fields, looked up on first use. Mappings to
+/// unknown attributes or fields the project lacks are reported then.
+fn project_fields<'a>(
+    client: &GitHubClient,
+    config: &GitHubConfig,
+    project: &'a mut Option<(String, Vec<ProjectField>)>,
+) -> Result<&'a (String, Vec<ProjectField>)> {
+    if let Some(loaded) = project {
+        return Ok(loaded);
+    }
+    let project_id =
+        GitHubProjectSetup::get_project_id(client, &config.owner, config.project_number)
+            .context("Failed to get project ID")?;
+    let fields = GitHubQueries::get_project_fields(client, &project_id)
+        .context("Failed to get project fields")?;
+    for (attribute, name) in &config.field_mapping {
+        if !FIELD_ATTRIBUTES.contains(&attribute.as_str()) {
+            println!(
+                "   ⚠️  [field_mapping] {}: not a task attribute (use {})",
+                attribute,
+                FIELD_ATTRIBUTES.join(", ")
+            );
+        } else if !fields.iter().any(|f| f.name.eq_ignore_ascii_case(name)) {
+            println!(
+                "   ⚠️  [field_mapping] {}: the project has no field '{}'",
+                attribute, name
+            );
+        }
+    }
+    Ok(project.insert((project_id, fields)))
+}
+
+/// Push the task attributes named in `[field_mapping]` into the fields of its
+/// project item, skipping values unchanged since they were last pushed and
+/// clearing fields whose attribute was unset since. Returns whether any field
+/// changed.
+fn push_project_fields(
+    client: &GitHubClient,
+    config: &GitHubConfig,
+    project: &mut Option<(String, Vec<ProjectField>)>,
+    task: &Task,
+    mapper: &mut TaskIssueMapper,
+    dry_run: bool,
+) -> Result<bool> {
+    if config.field_mapping.is_empty() {
+        return Ok(false);
+    }
+    let Some(mapping) = mapper.get_by_task_id(&task.id).cloned() else {
+        return Ok(false);
+    };
+    if mapping.project_item_id.is_empty() {
+        return Ok(false);
+    }
+
+    let (project_id, fields) = project_fields(client, config, project)?;
+    let mut pushed = mapping.fields.clone();
+    for (attribute, name) in &config.field_mapping {
+        let value = task_field_value(task, attribute);
+        let Some(field) = fields.iter().find(|f| f.name.eq_ignore_ascii_case(name)) else {
+            continue;
+        };
+        if pushed.get(&field.name) == value.as_ref() {
+            continue;
+        }
+        let Some(value) = value else {
+            if dry_run {
+                println!("   📊 {} - would clear {}", task.id, field.name);
+            } else {
+                GitHubMutations::clear_project_item_field(
+                    client,
+                    project_id,
+                    &mapping.project_item_id,
+                    &field.id,
+                )
+                .context(format!(
+                    "Failed to clear {} for task {}",
+                    field.name, task.id
+                ))?;
+                println!("   📊 {} - cleared {}", task.id, field.name);
+                pushed.remove(&field.name);
+            }
+            continue;
+        };
+        let Some(field_value) = field.value_for(&value) else {
+            println!(
+                "   ⚠️  {} - '{}' doesn't fit project field '{}' ({})",
+                task.id, value, field.name, field.data_type
+            );
+            continue;
+        };
+
+        if dry_run {
+            println!("   📊 {} - would set {} to {}", task.id, field.name, value);
+        } else {
+            GitHubMutations::update_project_item_field(
+                client,
+                project_id,
+                &mapping.project_item_id,
+                &field.id,
+                &field_value,
+            )
+            .context(format!("Failed to set {} for task {}", field.name, task.id))?;
+            println!("   📊 {} - set {} to {}", task.id, field.name, value);
+            pushed.insert(field.name.clone(), value);
+        }
+    }
+
+    let changed = pushed != mapping.fields;
+    if changed {
+        mapper
+            .update_mapping(IssueMapping {
+                fields: pushed,
+                ..mapping
+            })
+            .context(format!("Failed to save mapping for task {}", task.id))?;
+    }
+    Ok(changed)
+}
 
-                // 3. Get status field info
-                let (field_id, options) =
-                    GitHubMutations::get_status_field_info(client, &project_id)
-                        .context("Failed to get status field info")?;
+/// Set the issue's milestone to the task's epic, or keep a milestone changed
+/// on GitHub since the last sync for the pull to write into the epic. Returns
+/// whether the issue's milestone changed.
+fn push_milestone(
+    client: &GitHubClient,
+    config: &GitHubConfig,
+    milestones: &mut Option<HashMap<String, String>>,
+    task: &Task,
+    issue: &GitHubIssue,
+    mapper: &mut TaskIssueMapper,
+    dry_run: bool,
+) -> Result<bool> {
+    let Some(mapping) = mapper.get_by_task_id(&task.id).cloned() else {
+        return Ok(false);
+    };
+    let merged = TaskIssueMapper::merge_value(
+        mapping.milestone.as_deref(),
+        task.epic.as_deref(),
+        issue.milestone.as_deref(),
+    );
 
-                // 4. Find matching status column and set it
-                if let Some(option_id) =
-                    TaskIssueMapper::find_best_status_option(&task.status, &options)
-                {
-                    // 5. Set status
-                    GitHubMutations::update_project_item_status(
-                        client,
-                        &project_id,
-                        &project_item_id,
-                        &field_id,
-                        &option_id,
-                    )
-                    .context(format!(
-                        "Failed to update status for issue #{}",
-                        issue.number
-                    ))?;
-                    println!("      🎯 Status set successfully");
-                } else {
-                    println!(
-                        "      ⚠️  No matching status column found for '{}'",
-                        task.status
-                    );
-                }
+    let changed = merged != issue.milestone;
+    if changed {
+        let change = merged.as_deref().map_or_else(
+            || "milestone cleared".to_string(),
+            |title| format!("milestone {}", title),
+        );
+        if dry_run {
+            println!("   🏁 {} - would set {}", task.id, change);
+        } else {
+            let id = match &merged {
+                Some(title) => Some(milestone_id(client, config, milestones, title)?),
+                None => None,
+            };
+            GitHubMutations::update_issue_milestone(client, &issue.id, id.as_deref())
+                .context(format!("Failed to set milestone for task {}", task.id))?;
+            println!("   🏁 {} - {}", task.id, change);
+        }
+    }
 
-                // 6. Save mapping with project_item_id and archived status
-                let mapping = IssueMapping {
-                    task_id: task.id.clone(),
-                    issue_number: issue.number,
-                    issue_id: issue.id.clone(),
-                    project_item_id,
-                    synced_at: chrono::Utc::now().to_rfc3339(),
-                    is_archived,
-                };
-                mapper
-                    .add_mapping(mapping)
-                    .context(format!("Failed to save mapping for task {}", task.id))?;
+    if !dry_run && task.epic == merged && mapping.milestone != merged {
+        mapper
+            .update_mapping(IssueMapping {
+                milestone: merged,
+                ..mapping
+            })
+            .context(format!("Failed to save mapping for task {}", task.id))?;
+    }
+    Ok(changed)
+}
 
-                created += 1;
-            } else {
-                println!("      Would create GitHub issue");
+/// Set the issue's labels to the task's tags merged with label changes made
+/// on GitHub since the last sync. Once tags and labels agree the merge becomes
+/// the new base; until then the pull writes it into the tags. Returns whether
+/// the issue's labels changed.
+fn push_labels(
+    client: &GitHubClient,
+    config: &GitHubConfig,
+    repo_labels: &mut Option<HashMap<String, String>>,
+    task: &Task,
+    issue: &GitHubIssue,
+    mapper: &mut TaskIssueMapper,
+    dry_run: bool,
+) -> Result<bool> {
+    let Some(mapping) = mapper.get_by_task_id(&task.id).cloned() else {
+        return Ok(false);
+    };
+    let tags = synced_labels(config, &task.tags);
+    let labels = synced_labels(config, &issue.labels);
+    let merged = TaskIssueMapper::merge_names(&mapping.labels, &tags, &labels);
+
+    let add = missing_names(&merged, &labels);
+    let remove = missing_names(&labels, &merged);
+    let changed = !add.is_empty() || !remove.is_empty();
+    if changed {
+        let changes = name_changes(&add, &remove);
+        if dry_run {
+            println!("   🏷️  {} - would update labels: {}", task.id, changes);
+        } else {
+            if !add.is_empty() {
+                let ids = label_ids(client, config, repo_labels, &add)?;
+                GitHubMutations::add_labels(client, &issue.id, &ids)
+                    .context(format!("Failed to label issue for task {}", task.id))?;
+            }
+            if !remove.is_empty() {
+                let ids = label_ids(client, config, repo_labels, &remove)?;
+                GitHubMutations::remove_labels(client, &issue.id, &ids)
+                    .context(format!("Failed to unlabel issue for task {}", task.id))?;
             }
+            println!("   🏷️  {} - updated labels: {}", task.id, changes);
         }
     }
 
-    println!();
-    println!("📊 PUSH SUMMARY");
-    println!("   Created: {}", created);
-    println!("   Updated: {}", updated);
-    println!("   Skipped: {} (already in sync)", skipped);
-
-    Ok(())
+    if !dry_run && same_names(&tags, &merged) && !same_names(&mapping.labels, &merged) {
+        mapper
+            .update_mapping(IssueMapping {
+                labels: merged,
+                ..mapping
+            })
+            .context(format!("Failed to save mapping for task {}", task.id))?;
+    }
+    Ok(changed)
 }
 
+/// Report how GitHub issues differ from local tasks. With `apply`, write the
+/// status of closed or reopened issues to their active tasks, confirming each
+/// one unless `yes`. Returns the status changes GitHub has for active tasks.
 fn pull_issues_from_github(
     client: &GitHubClient,
     config: &GitHubConfig,
     tasks: &[Task],
-    mapper: &TaskIssueMapper,
-    dry_run: bool,
-) -> Result<()> {
-    let issues =
-        GitHubQueries::get_repository_issues(client, &config.owner, &config.repo, Some(100))
-            .context("Failed to get repository issues")?;
+    mapper: &mut TaskIssueMapper,
+    options: &SyncOptions,
+) -> Result<Vec<(String, String)>> {
+    let SyncOptions {
+        dry_run,
+        apply_pull: apply,
+        adopt_orphans,
+        yes,
+        ..
+    } = *options;
+    let filter = &options.filter;
+    let (owner, repo) = config.issue_repo();
+    let issues = GitHubQueries::get_repository_issues(client, owner, repo, Some(100))
+        .context("Failed to get repository issues")?;
 
     let mut mapped_count = 0;
+    let mut out_of_scope = 0;
     let mut orphaned_issues = Vec::new();
     let mut updates_needed = Vec::new();
     let mut archived_with_changes = Vec::new();
+    let mut assignee_changes = Vec::new();
+    let mut tag_changes: Vec<(&Task, Vec<String>)> = Vec::new();
+    let mut assignee_pulls: Vec<(&Task, Vec<String>)> = Vec::new();
+    let mut epic_changes: Vec<(&Task, Option<String>)> = Vec::new();
+    let mut due_changes: Vec<(&Task, Option<NaiveDate>)> = Vec::new();
+    let mut checklist_changes: Vec<(&Task, Vec<String>)> = Vec::new();
+    let mut comment_pulls: Vec<(&Task, Vec<LogEntry>)> = Vec::new();
+    let mut project = None;
+    let mut unmapped_logins: BTreeSet<String> = BTreeSet::new();
+    // Mappings whose merge bases move once the task files are written
+    let mut bases: BTreeMap<String, IssueMapping> = BTreeMap::new();
 
     for issue in issues {
         // Check if this issue is tracked
-        if let Some(mapping) = mapper.get_by_issue_number(issue.number) {
-            mapped_count += 1;
-
+        if let Some(mapping) = mapper.get_by_issue(config.target.as_deref(), issue.number) {
             // Find the task (including archived)
             if let Some(task) = tasks.iter().find(|t| t.id == mapping.task_id) {
+                mapped_count += 1;
                 let is_archived = task.file_path.to_string_lossy().contains("archive");
                 let github_state = map_github_state_to_taskguard(&issue.state);
                 let local_state = task.status.to_string();
+                // Issues are only open or closed, so any open status matches an open issue
+                let state_differs =
+                    map_taskguard_status_to_github(&task.status) != issue.state.to_uppercase();
+
+                if config.users.is_empty() {
+                    // Only compare when GitHub has assignees; local names may not be logins
+                    if !issue.assignees.is_empty() && !same_names(&task.assignees, &issue.assignees)
+                    {
+                        assignee_changes.push((
+                            task.id.clone(),
+                            task.assignees.join(", "),
+                            issue.assignees.join(", "),
+                        ));
+                    }
+                } else if !is_archived {
+                    // Assignee changes made on GitHub since the last sync, through [users]
+                    let logins = assignee_logins(config, &task.assignees);
+                    let mut remote = Vec::new();
+                    for login in &issue.assignees {
+                        if config.user(login).is_some() {
+                            remote.push(login.clone());
+                        } else {
+                            unmapped_logins.insert(login.clone());
+                        }
+                    }
+                    let merged = TaskIssueMapper::merge_names(&mapping.assignees, &logins, &remote);
+                    if !same_names(&logins, &merged) {
+                        if same_names(&remote, &merged) {
+                            bases
+                                .entry(task.id.clone())
+                                .or_insert_with(|| mapping.clone())
+                                .assignees
+                                .clone_from(&merged);
+                        }
+                        assignee_pulls.push((task, merged));
+                    }
+                }
+
+                // A milestone changed on GitHub since the last sync becomes the epic
+                if !is_archived {
+                    let merged = TaskIssueMapper::merge_value(
+                        mapping.milestone.as_deref(),
+                        task.epic.as_deref(),
+                        issue.milestone.as_deref(),
+                    );
+                    if merged != task.epic {
+                        if merged == issue.milestone {
+                            bases
+                                .entry(task.id.clone())
+                                .or_insert_with(|| mapping.clone())
+                                .milestone
+                                .clone_from(&merged);
+                        }
+                        epic_changes.push((task, merged));
+                    }
+                }
+
+                // A due date changed on the project board since the last sync
+                // goes into the frontmatter
+                if !is_archived
+                    && !mapping.project_item_id.is_empty()
+                    && let Some(name) = config.field_mapping.get("due")
+                    && let Some(field) = project_fields(client, config, &mut project)?
+                        .1
+                        .iter()
+                        .find(|f| f.name.eq_ignore_ascii_case(name) && f.data_type == "DATE")
+                {
+                    let item = GitHubQueries::get_project_item(client, &mapping.project_item_id)
+                        .context(format!("Failed to get project item for task {}", task.id))?;
+                    let remote = item
+                        .field_values
+                        .iter()
+                        .find(|v| v.field_id == field.id)
+                        .map(|v| v.value.clone());
+                    let local = task.due.map(|due| due.to_string());
+                    let merged = TaskIssueMapper::merge_value(
+                        mapping.fields.get(&field.name).map(String::as_str),
+                        local.as_deref(),
+                        remote.as_deref(),
+                    );
+                    if merged != local {
+                        if merged == remote {
+                            let base = bases
+                                .entry(task.id.clone())
+                                .or_insert_with(|| mapping.clone());
+                            match &merged {
+                                Some(due) => base.fields.insert(field.name.clone(), due.clone()),
+                                None => base.fields.remove(&field.name),
+                            };
+                        }
+                        due_changes.push((
+                            task,
+                            merged.and_then(|due| NaiveDate::parse_from_str(&due, "%Y-%m-%d").ok()),
+                        ));
+                    }
+                }
+
+                // Label changes made on GitHub since the last sync go into the tags
+                if !is_archived {
+                    let tags = synced_labels(config, &task.tags);
+                    let labels = synced_labels(config, &issue.labels);
+                    let merged = TaskIssueMapper::merge_names(&mapping.labels, &tags, &labels);
+                    if !same_names(&tags, &merged) {
+                        if same_names(&labels, &merged) {
+                            bases
+                                .entry(task.id.clone())
+                                .or_insert_with(|| mapping.clone())
+                                .labels
+                                .clone_from(&merged);
+                        }
+                        tag_changes.push((task, merged));
+                    }
+                }
 
-                if github_state != local_state {
+                // Comments made on the issue go into the log
+                if !is_archived && config.comments.pull() {
+                    let entries = new_comment_entries(
+                        task,
+                        &GitHubQueries::get_issue_comments(client, &issue.id)
+                            .context(format!("Failed to get comments for task {}", task.id))?,
+                    );
+                    if !entries.is_empty() {
+                        comment_pulls.push((task, entries));
+                    }
+                }
+
+                // Checkboxes toggled in the issue body since the last sync go
+                // into the checklist
+                if !is_archived {
+                    let remote = issue
+                        .body
+                        .as_deref()
+                        .and_then(|body| issue_checked_items(task, body));
+                    let merged = merged_checked(task, remote.as_deref(), &mapping.checked);
+                    if !same_names(&checked_items(&task.content), &merged) {
+                        if remote.is_some_and(|remote| same_names(&remote, &merged)) {
+                            bases
+                                .entry(task.id.clone())
+                                .or_insert_with(|| mapping.clone())
+                                .checked
+                                .clone_from(&merged);
+                        }
+                        checklist_changes.push((task, merged));
+                    }
+                }
+
+                if state_differs {
                     if is_archived {
                         // Archived task with status mismatch - special handling
                         archived_with_changes.push((
@@ -1070,14 +3208,25 @@ fn pull_issues_from_github(
                         ));
                     }
                 }
+            } else if !filter.is_empty() {
+                // The task is outside the sync filter (or gone)
+                out_of_scope += 1;
             }
-        } else {
+        } else if filter.matches_issue(&issue.labels, map_github_state_to_taskguard(&issue.state)) {
             // Orphaned issue - no TaskGuard task
             orphaned_issues.push(issue);
+        } else {
+            out_of_scope += 1;
         }
     }
 
     println!("   ✅ {} issues mapped to existing tasks", mapped_count);
+    if out_of_scope > 0 {
+        println!(
+            "   ⏭️  {} issues outside the sync filter ignored",
+            out_of_scope
+        );
+    }
 
     // Report orphaned issues
     if !orphaned_issues.is_empty() {
@@ -1094,11 +3243,17 @@ fn pull_issues_from_github(
             println!("      ... and {} more", orphaned_issues.len() - 10);
         }
 
-        println!();
-        println!("   💡 SUGGESTED ACTIONS:");
-        println!("      1. Create tasks manually for these issues");
-        println!("      2. Or ignore them (they'll stay on GitHub only)");
-        println!("      3. Or ask AI: \"Create TaskGuard tasks for orphaned GitHub issues\"");
+        if adopt_orphans {
+            adopt_orphan_issues(config, &orphaned_issues, mapper, dry_run, yes)?;
+        } else {
+            println!();
+            println!("   💡 SUGGESTED ACTIONS:");
+            println!(
+                "      1. Run 'taskguard sync --github --adopt-orphans' to import them as tasks"
+            );
+            println!("      2. Or ignore them (they'll stay on GitHub only)");
+            println!("      3. Or ask AI: \"Create TaskGuard tasks for orphaned GitHub issues\"");
+        }
     }
 
     // Report status mismatches for active tasks
@@ -1112,13 +3267,239 @@ fn pull_issues_from_github(
             println!("      {} - Local: {}, GitHub: {}", task_id, local, github);
         }
 
-        if !dry_run {
+        if apply {
+            println!();
+            let suggestions: Vec<(&str, &str)> = updates_needed
+                .iter()
+                .map(|(task_id, _, github)| (task_id.as_str(), github.as_str()))
+                .collect();
+            apply_suggestions(&suggestions, tasks, dry_run, yes)?;
+        } else if !dry_run {
             println!();
-            println!("   💡 TIP: Update local task files to match GitHub state");
+            println!("   💡 TIP: Run with --apply-pull to update local task files to match GitHub");
             println!("      Or next sync will push local status back to GitHub");
         }
     }
 
+    // Write label changes into tags, keeping tags excluded from label sync
+    if !tag_changes.is_empty() {
+        println!();
+        println!(
+            "   🏷️  {} tasks have label changes on GitHub:",
+            tag_changes.len()
+        );
+        for (task, merged) in &tag_changes {
+            let mut tags: Vec<String> = task
+                .tags
+                .iter()
+                .filter(|tag| !config.labels.is_synced(tag))
+                .cloned()
+                .collect();
+            tags.extend(merged.iter().cloned());
+            if dry_run {
+                println!("      {} - would set tags: {}", task.id, tags.join(", "));
+                continue;
+            }
+            // Re-read the file: a status from --apply-pull may have just been written to it
+            let mut task = Task::from_file(&task.file_path)?;
+            task.tags = tags;
+            task.save_to_file(&task.file_path)
+                .with_context(|| format!("Failed to update {}", task.file_path.display()))?;
+            println!("      ✅ {} - tags: {}", task.id, task.tags.join(", "));
+        }
+    }
+
+    // Write milestone changes into epics
+    if !epic_changes.is_empty() {
+        println!();
+        println!(
+            "   🏁 {} tasks have milestone changes on GitHub:",
+            epic_changes.len()
+        );
+        for (task, epic) in &epic_changes {
+            let epic_line = epic.as_deref().unwrap_or("none");
+            if dry_run {
+                println!("      {} - would set epic: {}", task.id, epic_line);
+                continue;
+            }
+            let mut task = Task::from_file(&task.file_path)?;
+            task.epic.clone_from(epic);
+            task.save_to_file(&task.file_path)
+                .with_context(|| format!("Failed to update {}", task.file_path.display()))?;
+            println!("      ✅ {} - epic: {}", task.id, epic_line);
+        }
+    }
+
+    // Append new issue comments to the logs
+    if !comment_pulls.is_empty() {
+        println!();
+        println!(
+            "   💬 {} tasks have new comments on GitHub:",
+            comment_pulls.len()
+        );
+        for (task, entries) in &comment_pulls {
+            if dry_run {
+                for entry in entries {
+                    println!(
+                        "      {} - would log {}: {}",
+                        task.id, entry.author, entry.text
+                    );
+                }
+                continue;
+            }
+            let mut task = Task::from_file(&task.file_path)?;
+            for entry in entries {
+                task.content = worklog::append_entry(&task.content, entry);
+                println!(
+                    "      ✅ {} - logged {}: {}",
+                    task.id, entry.author, entry.text
+                );
+            }
+            task.save_to_file(&task.file_path)
+                .with_context(|| format!("Failed to update {}", task.file_path.display()))?;
+        }
+    }
+
+    // Write checkbox toggles into checklists
+    if !checklist_changes.is_empty() {
+        println!();
+        println!(
+            "   ☑️  {} tasks have checklist changes on GitHub:",
+            checklist_changes.len()
+        );
+        for (task, checked) in &checklist_changes {
+            // Re-read the file: other pulled changes may have just been written to it
+            let mut task = Task::from_file(&task.file_path)?;
+            let items = checklist::parse_items(&task.content);
+            let mut content = task.content.clone();
+            for (index, item) in items.iter().enumerate() {
+                let done = checked
+                    .iter()
+                    .any(|text| text.eq_ignore_ascii_case(&item.text));
+                if item.has_children() || done == item.completed {
+                    continue;
+                }
+                let state = if done { "done" } else { "todo" };
+                if dry_run {
+                    println!(
+                        "      {} - would mark item {} {}: {}",
+                        task.id,
+                        item.label(),
+                        state,
+                        item.text
+                    );
+                    continue;
+                }
+                content = checklist::update_item(&content, index, |target| {
+                    target.completed = done;
+                })?;
+                println!(
+                    "      ✅ {} - item {} {}: {}",
+                    task.id,
+                    item.label(),
+                    state,
+                    item.text
+                );
+            }
+            if !dry_run {
+                task.content = content;
+                task.save_to_file(&task.file_path)
+                    .with_context(|| format!("Failed to update {}", task.file_path.display()))?;
+            }
+        }
+    }
+
+    // Write due dates changed on the board
+    if !due_changes.is_empty() {
+        println!();
+        println!(
+            "   📅 {} tasks have due date changes on the project board:",
+            due_changes.len()
+        );
+        for (task, due) in &due_changes {
+            let due_line = due.map_or_else(|| "none".to_string(), |due| due.to_string());
+            if dry_run {
+                println!("      {} - would set due: {}", task.id, due_line);
+                continue;
+            }
+            let mut task = Task::from_file(&task.file_path)?;
+            task.due = *due;
+            task.save_to_file(&task.file_path)
+                .with_context(|| format!("Failed to update {}", task.file_path.display()))?;
+            println!("      ✅ {} - due: {}", task.id, due_line);
+        }
+    }
+
+    // Write assignee changes, keeping assignees without a GitHub login
+    if !assignee_pulls.is_empty() {
+        println!();
+        println!(
+            "   👥 {} tasks have assignee changes on GitHub:",
+            assignee_pulls.len()
+        );
+        for (task, merged) in &assignee_pulls {
+            let mut assignees: Vec<String> = task
+                .assignees
+                .iter()
+                .filter(|user| config.login(user).is_none())
+                .cloned()
+                .collect();
+            assignees.extend(
+                merged
+                    .iter()
+                    .filter_map(|login| config.user(login).map(str::to_string)),
+            );
+            if dry_run {
+                println!(
+                    "      {} - would set assignees: {}",
+                    task.id,
+                    assignees.join(", ")
+                );
+                continue;
+            }
+            let mut task = Task::from_file(&task.file_path)?;
+            task.assignees = assignees;
+            task.save_to_file(&task.file_path)
+                .with_context(|| format!("Failed to update {}", task.file_path.display()))?;
+            println!(
+                "      ✅ {} - assignees: {}",
+                task.id,
+                task.assignees.join(", ")
+            );
+        }
+    }
+    if !unmapped_logins.is_empty() {
+        println!();
+        println!(
+            "   ⚠️  GitHub assignees without a [users] entry in github.toml, not pulled: {}",
+            unmapped_logins.into_iter().collect::<Vec<_>>().join(", ")
+        );
+    }
+
+    if !dry_run {
+        for (task_id, mapping) in bases {
+            mapper
+                .update_mapping(mapping)
+                .context(format!("Failed to save mapping for task {}", task_id))?;
+        }
+    }
+
+    // Report assignee differences
+    if !assignee_changes.is_empty() {
+        println!();
+        println!(
+            "   👥 {} tasks have different assignees on GitHub:",
+            assignee_changes.len()
+        );
+        for (task_id, local, github) in &assignee_changes {
+            let local = if local.is_empty() { "none" } else { local };
+            println!("      {} - Local: {}, GitHub: {}", task_id, local, github);
+        }
+        println!(
+            "   💡 TIP: Map task assignees to GitHub logins under [users] in github.toml to sync them"
+        );
+    }
+
     // Report status mismatches for archived tasks
     if !archived_with_changes.is_empty() {
         println!();
@@ -1140,13 +3521,181 @@ fn pull_issues_from_github(
         println!("      3. Next sync will push local archived status back to GitHub");
     }
 
-    if orphaned_issues.is_empty() && updates_needed.is_empty() && archived_with_changes.is_empty() {
+    if orphaned_issues.is_empty()
+        && updates_needed.is_empty()
+        && tag_changes.is_empty()
+        && assignee_pulls.is_empty()
+        && epic_changes.is_empty()
+        && archived_with_changes.is_empty()
+        && assignee_changes.is_empty()
+    {
         println!("   ✅ All tasks in sync with GitHub");
     }
 
+    Ok(updates_needed
+        .into_iter()
+        .map(|(task_id, _, github)| (task_id, github))
+        .collect())
+}
+
+/// Create a task file and mapping for each orphaned issue, confirming each
+/// unless `yes`. The area is the first label naming one (or a commit scope
+/// mapped to one), else an area routed to the issue's repository, else the
+/// default area. Issues created for tasks on other branches are left to
+/// those branches.
+fn adopt_orphan_issues(
+    config: &GitHubConfig,
+    issues: &[GitHubIssue],
+    mapper: &mut TaskIssueMapper,
+    dry_run: bool,
+    yes: bool,
+) -> Result<()> {
+    let project_config = Config::load_or_default(get_config_path()?)?;
+    let areas = project_config
+        .git
+        .scope_areas(&project_config.project.areas);
+    let tasks_dir = get_tasks_dir()?;
+    // Issues of a [routes] repository default to an area routed there
+    let routed_area = config.target.as_deref().and_then(|target| {
+        config
+            .routes
+            .keys()
+            .find(|area| config.route(area) == Some(target))
+            .cloned()
+    });
+
+    println!();
+    println!("   📥 ADOPTING ORPHANED ISSUES");
+    let mut adopted = 0;
+    for issue in issues {
+        if issue
+            .body
+            .as_deref()
+            .is_some_and(|body| body.contains("**TaskGuard ID:**"))
+        {
+            println!(
+                "      ⏭️  #{} belongs to a task on another branch; sync from that branch instead",
+                issue.number
+            );
+            continue;
+        }
+        let area = issue
+            .labels
+            .iter()
+            .find_map(|label| areas.get(&label.to_lowercase()).cloned())
+            .or_else(|| routed_area.clone())
+            .unwrap_or_else(|| default_area(&project_config));
+        let summary = format!("#{} \"{}\" in {}", issue.number, issue.title, area);
+        if dry_run {
+            println!("      Would create a task for {}", summary);
+            continue;
+        }
+        if !yes && !confirm(&format!("Create a task for {}?", summary))? {
+            println!("      ⏭️  Skipped #{}", issue.number);
+            continue;
+        }
+
+        let task = task_from_issue(config, issue, &area, &tasks_dir)?;
+        mapper
+            .add_mapping(IssueMapping {
+                task_id: task.id.clone(),
+                issue_number: issue.number,
+                repo: config.target.clone(),
+                issue_id: issue.id.clone(),
+                project_item_id: String::new(),
+                synced_at: chrono::Utc::now().to_rfc3339(),
+                is_archived: false,
+                labels: task.tags.clone(),
+                assignees: issue.assignees.clone(),
+                milestone: issue.milestone.clone(),
+                fields: BTreeMap::new(),
+                checked: Vec::new(),
+                description_hash: None,
+            })
+            .context(format!("Failed to save mapping for task {}", task.id))?;
+        println!(
+            "      ✅ #{} → {} ({})",
+            issue.number,
+            task.id,
+            task.file_path
+                .strip_prefix(&tasks_dir)
+                .unwrap_or(&task.file_path)
+                .display()
+        );
+        adopted += 1;
+    }
+
+    if adopted > 0 {
+        println!(
+            "   Adopted {} of {} issues; their bodies are rewritten from the tasks on the next push",
+            adopted,
+            issues.len()
+        );
+    }
     Ok(())
 }
 
+/// Write a task file in `area` for an issue: its title, state, synced labels
+/// as tags, assignees, milestone as epic, and body as context
+fn task_from_issue(
+    config: &GitHubConfig,
+    issue: &GitHubIssue,
+    area: &str,
+    tasks_dir: &std::path::Path,
+) -> Result<Task> {
+    let area_dir = tasks_dir.join(area);
+    let body = issue.body.as_deref().unwrap_or_default().trim();
+    let context = if body.is_empty() {
+        "No description.".to_string()
+    } else {
+        body.replace("\r\n", "\n")
+    };
+
+    let mut task = Task {
+        id: generate_task_id(area, &area_dir)?,
+        title: issue.title.clone(),
+        status: TaskStatus::Todo,
+        priority: Priority::Medium,
+        tags: synced_labels(config, &issue.labels),
+        dependencies: Vec::new(),
+        relations: Relations::default(),
+        assignees: issue
+            .assignees
+            .iter()
+            .map(|login| config.user(login).unwrap_or(login).to_string())
+            .collect(),
+        created: chrono::Utc::now(),
+        estimate: None,
+        complexity: None,
+        due: None,
+        epic: issue.milestone.clone(),
+        time_log: Vec::new(),
+        links: Vec::new(),
+        branch: None,
+        release: None,
+        completed: None,
+        claimed: None,
+        format_version: FORMAT_VERSION,
+        area: area.to_string(),
+        extra: BTreeMap::new(),
+        content: format!(
+            "# {}\n\n## Context\n\n{}\n\nImported from GitHub issue #{}.\n",
+            issue.title, context, issue.number
+        ),
+        file_path: std::path::PathBuf::new(),
+    };
+    if issue.state.eq_ignore_ascii_case("closed") {
+        task.set_status(TaskStatus::Done);
+    }
+
+    std::fs::create_dir_all(&area_dir)
+        .with_context(|| format!("Failed to create area directory: {}", area))?;
+    task.file_path = area_dir.join(task.file_name());
+    task.save_to_file(&task.file_path)
+        .with_context(|| format!("Failed to write {}", task.file_path.display()))?;
+    Ok(task)
+}
+
 fn backfill_project_board(
     client: &GitHubClient,
     config: &GitHubConfig,
@@ -1255,6 +3804,16 @@ fn backfill_project_board(
 
 // Helper functions for status mapping
 
+/// Compare name lists (assignees, labels) ignoring order and case
+pub(crate) fn same_names(local: &[String], github: &[String]) -> bool {
+    let normalize = |names: &[String]| {
+        let mut names: Vec<String> = names.iter().map(|n| n.to_lowercase()).collect();
+        names.sort();
+        names
+    };
+    normalize(local) == normalize(github)
+}
+
 fn map_taskguard_status_to_github(status: &TaskStatus) -> &str {
     match status {
         TaskStatus::Done => "CLOSED",
@@ -0,0 +1,141 @@
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+
+use crate::config::load_all_tasks;
+use crate::task::{Task, TaskStatus};
+
+/// Usage per tag as (tasks, open tasks), counting active and archived tasks
+pub fn tag_counts(tasks: &[Task]) -> BTreeMap<&str, (usize, usize)> {
+    let mut counts: BTreeMap<&str, (usize, usize)> = BTreeMap::new();
+    for task in tasks {
+        for tag in &task.tags {
+            let entry = counts.entry(tag.as_str()).or_default();
+            entry.0 += 1;
+            if task.status != TaskStatus::Done {
+                entry.1 += 1;
+            }
+        }
+    }
+    counts
+}
+
+/// Replace any of `from` with `to` in a task's tags, keeping order and dropping duplicates
+///
+/// Returns whether the tags changed.
+pub fn retag(task: &mut Task, from: &[String], to: &str) -> bool {
+    if !task.tags.iter().any(|tag| from.contains(tag)) {
+        return false;
+    }
+
+    let mut tags: Vec<String> = Vec::with_capacity(task.tags.len());
+    for tag in &task.tags {
+        let tag = if from.contains(tag) { to } else { tag.as_str() };
+        if !tags.iter().any(|t| t == tag) {
+            tags.push(tag.to_string());
+        }
+    }
+    task.tags = tags;
+    true
+}
+
+pub fn run_list() -> Result<()> {
+    let tasks = load_all_tasks().context("Failed to load tasks")?;
+    let counts = tag_counts(&tasks);
+
+    if counts.is_empty() {
+        println!(
+            "🏷️  No tags found. Add them with 'taskguard create --tags' or 'tags:' in frontmatter."
+        );
+        return Ok(());
+    }
+
+    let mut sorted: Vec<_> = counts.into_iter().collect();
+    sorted.sort_by(|(a_tag, (a, _)), (b_tag, (b, _))| b.cmp(a).then(a_tag.cmp(b_tag)));
+
+    println!("🏷️  TAGS ({})", sorted.len());
+    for (tag, (total, open)) in &sorted {
+        println!("   {:<24} {:>4} tasks ({} open)", tag, total, open);
+    }
+
+    let untagged = tasks.iter().filter(|t| t.tags.is_empty()).count();
+    if untagged > 0 {
+        println!();
+        println!("   {} tasks have no tags", untagged);
+    }
+
+    Ok(())
+}
+
+pub fn run_rename(old: &str, new: &str, dry_run: bool) -> Result<()> {
+    let tasks = load_all_tasks().context("Failed to load tasks")?;
+    let counts = tag_counts(&tasks);
+    let new = new.trim();
+
+    if new.is_empty() {
+        return Err(anyhow::anyhow!("New tag name cannot be empty"));
+    }
+    if !counts.contains_key(old) {
+        return Err(anyhow::anyhow!("Tag '{}' not found", old));
+    }
+    if old != new && counts.contains_key(new) {
+        return Err(anyhow::anyhow!(
+            "Tag '{}' already exists. Use 'taskguard tags merge {} --into {}' to combine them",
+            new,
+            old,
+            new
+        ));
+    }
+
+    apply(tasks, &[old.to_string()], new, dry_run)
+}
+
+pub fn run_merge(sources: &[String], into: &str, dry_run: bool) -> Result<()> {
+    let tasks = load_all_tasks().context("Failed to load tasks")?;
+    let counts = tag_counts(&tasks);
+    let into = into.trim();
+
+    if into.is_empty() {
+        return Err(anyhow::anyhow!("Target tag cannot be empty"));
+    }
+    let missing: Vec<&str> = sources
+        .iter()
+        .map(String::as_str)
+        .filter(|tag| !counts.contains_key(tag))
+        .collect();
+    if !missing.is_empty() {
+        return Err(anyhow::anyhow!("Tag(s) not found: {}", missing.join(", ")));
+    }
+
+    apply(tasks, sources, into, dry_run)
+}
+
+/// Retag every affected task file and report what changed
+fn apply(tasks: Vec<Task>, from: &[String], to: &str, dry_run: bool) -> Result<()> {
+    let mut changed = Vec::new();
+    for mut task in tasks {
+        if retag(&mut task, from, to) {
+            if !dry_run {
+                task.save_to_file(&task.file_path)?;
+            }
+            changed.push(task.id);
+        }
+    }
+    changed.sort();
+
+    let verb = if dry_run { "Would retag" } else { "Retagged" };
+    println!(
+        "🏷️  {} {} task(s): {} → {}",
+        verb,
+        changed.len(),
+        from.join(", "),
+        to
+    );
+    for id in &changed {
+        println!("   {}", id);
+    }
+    if dry_run {
+        println!("   Run without --dry-run to apply");
+    }
+
+    Ok(())
+}
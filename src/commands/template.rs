@@ -0,0 +1,100 @@
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::Path;
+
+use crate::config::{Config, find_taskguard_root, get_config_path};
+use crate::templates::{BUILTIN_TEMPLATES, TemplateManager};
+
+fn taskguard_root() -> Result<std::path::PathBuf> {
+    find_taskguard_root().context("Not in a TaskGuard project. Run 'taskguard init' first.")
+}
+
+pub fn run_list() -> Result<()> {
+    let root = taskguard_root()?;
+    let custom = TemplateManager::list_custom_templates(&root);
+    let config = Config::load_or_default(get_config_path()?)?;
+
+    println!("📄 TEMPLATES");
+    println!("   Built-in:");
+    for name in BUILTIN_TEMPLATES {
+        let overridden = if custom.iter().any(|c| c == name) {
+            " (overridden by project template)"
+        } else {
+            ""
+        };
+        println!("      {}{}", name, overridden);
+    }
+
+    if !custom.is_empty() {
+        println!("   Project (.taskguard/templates/):");
+        for name in &custom {
+            println!("      {}", name);
+        }
+    }
+
+    if !config.templates.is_empty() {
+        println!();
+        println!("   Area defaults ([templates] in config.toml):");
+        for (area, name) in &config.templates {
+            println!("      {} → {}", area, name);
+        }
+    }
+
+    println!();
+    println!("💡 Use: taskguard create --title \"...\" --template <name>");
+    Ok(())
+}
+
+pub fn run_show(name: &str) -> Result<()> {
+    let root = taskguard_root()?;
+    let content = TemplateManager::get_named_template(name, Some(&root))
+        .ok_or_else(|| anyhow::anyhow!("Template '{}' not found", name))?;
+
+    print!("{}", content);
+    if !content.ends_with('\n') {
+        println!();
+    }
+    Ok(())
+}
+
+/// Create a project template, copied from a file or seeded from the `feature` template
+pub fn run_add(name: &str, from: Option<&Path>) -> Result<()> {
+    if name.is_empty() || name.contains(['/', '\\', '.']) {
+        return Err(anyhow::anyhow!(
+            "Invalid template name '{}'. Use letters, numbers, '-' or '_'",
+            name
+        ));
+    }
+
+    let root = taskguard_root()?;
+    let path = TemplateManager::custom_template_path(&root, name);
+    if path.exists() {
+        return Err(anyhow::anyhow!(
+            "Template '{}' already exists: {}",
+            name,
+            path.display()
+        ));
+    }
+
+    let content = match from {
+        Some(source) => fs::read_to_string(source)
+            .with_context(|| format!("Failed to read template source: {}", source.display()))?,
+        None => TemplateManager::get_named_template(name, None)
+            .or_else(|| TemplateManager::get_named_template("feature", None))
+            .unwrap_or_default(),
+    };
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    }
+    fs::write(&path, content)
+        .with_context(|| format!("Failed to write template: {}", path.display()))?;
+
+    println!("✅ Created template '{}': {}", name, path.display());
+    println!(
+        "   Edit it, then use: taskguard create --title \"...\" --template {}",
+        name
+    );
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use anyhow::Result;
+use chrono::Utc;
+
+use crate::commands::update::find_task_file;
+use crate::config::get_tasks_dir;
+use crate::task::{Task, TimeEntry, estimate_to_minutes, format_minutes};
+
+fn load_task(task_id: &str) -> Result<(Task, std::path::PathBuf)> {
+    let tasks_dir = get_tasks_dir()?;
+    let task_file_path = find_task_file(&tasks_dir, task_id)?;
+    let task = Task::from_file(&task_file_path)?;
+    Ok((task, task_file_path))
+}
+
+/// Start a work session timer on a task
+pub fn run_start(task_id: &str) -> Result<()> {
+    let (mut task, path) = load_task(task_id)?;
+
+    if let Some(running) = task.running_timer() {
+        return Err(anyhow::anyhow!(
+            "Timer already running for {} since {}. Stop it with 'taskguard time stop {}'",
+            task_id,
+            running.started.format("%Y-%m-%d %H:%M UTC"),
+            task_id
+        ));
+    }
+
+    task.time_log.push(TimeEntry {
+        started: Utc::now(),
+        minutes: None,
+    });
+    task.save_to_file(&path)?;
+
+    println!("⏱️  Started timer for {}: {}", task.id, task.title);
+    Ok(())
+}
+
+/// Stop the running timer and record the elapsed time
+pub fn run_stop(task_id: &str) -> Result<()> {
+    let (mut task, path) = load_task(task_id)?;
+
+    let entry = task
+        .time_log
+        .iter_mut()
+        .find(|e| e.minutes.is_none())
+        .ok_or_else(|| anyhow::anyhow!("No timer running for {}", task_id))?;
+
+    // Count any started minute so short sessions still show up
+    let elapsed = (Utc::now() - entry.started).num_minutes().max(1);
+    let minutes = u32::try_from(elapsed).unwrap_or(u32::MAX);
+    entry.minutes = Some(minutes);
+    task.save_to_file(&path)?;
+
+    println!(
+        "⏹️  Stopped timer for {}: {} (total {})",
+        task.id,
+        format_minutes(minutes),
+        format_minutes(task.logged_minutes())
+    );
+    Ok(())
+}
+
+/// Record time spent on a task after the fact
+pub fn run_log(task_id: &str, duration: &str) -> Result<()> {
+    let minutes = estimate_to_minutes(duration)
+        .filter(|m| *m > 0)
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Invalid duration '{}'. Use formats like 30m, 2h, 1h30m or 1d",
+                duration
+            )
+        })?;
+
+    let (mut task, path) = load_task(task_id)?;
+    task.time_log.push(TimeEntry {
+        started: Utc::now(),
+        minutes: Some(minutes),
+    });
+    task.save_to_file(&path)?;
+
+    let estimate = task
+        .estimate_minutes()
+        .map(|e| format!(" of {} estimated", format_minutes(e)))
+        .unwrap_or_default();
+    println!(
+        "✅ Logged {} on {} (total {}{})",
+        format_minutes(minutes),
+        task.id,
+        format_minutes(task.logged_minutes()),
+        estimate
+    );
+    Ok(())
+}
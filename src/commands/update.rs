@@ -1,9 +1,14 @@
 use anyhow::Result;
+use std::io::{self, Write};
 use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
 
-use crate::config::get_tasks_dir;
-use crate::task::{Priority, Task, TaskStatus};
-use regex::Regex;
+use crate::checklist;
+use crate::config::{Config, get_config_path, get_tasks_dir};
+use crate::git::auto_commit;
+use crate::task::{
+    Priority, Task, TaskStatus, normalize_estimate, parse_assignees, parse_due_date,
+};
 
 pub fn run(field: String, task_id: String, value: String) -> Result<()> {
     let tasks_dir = get_tasks_dir()?;
@@ -18,11 +23,14 @@ pub fn run(field: String, task_id: String, value: String) -> Result<()> {
     match field.as_str() {
         "status" => update_status(&mut task, value)?,
         "priority" => update_priority(&mut task, value)?,
-        "assignee" => update_assignee(&mut task, value)?,
+        "assignee" => update_assignee(&mut task, &value)?,
         "dependencies" => update_dependencies(&mut task, value)?,
+        "due" => update_due(&mut task, &value)?,
+        "estimate" => update_estimate(&mut task, &value)?,
+        "epic" => update_epic(&mut task, value),
         _ => {
             return Err(anyhow::anyhow!(
-                "Invalid field '{}'. Valid fields: status, priority, assignee, dependencies",
+                "Invalid field '{}'. Valid fields: status, priority, assignee, dependencies, due, estimate, epic",
                 field
             ));
         }
@@ -31,61 +39,68 @@ pub fn run(field: String, task_id: String, value: String) -> Result<()> {
     // Save the updated task
     task.save_to_file(&task_file_path)?;
 
-    println!(
-        "✅ Updated task {}: {} = {}",
-        task_id,
-        field,
-        match field.as_str() {
-            "status" => task.status.to_string(),
-            "priority" => task.priority.to_string(),
-            "assignee" => task.assignee.as_deref().unwrap_or("None").to_string(),
-            "dependencies" => task.dependencies.join(", "),
-            _ => unreachable!(),
+    let shown = match field.as_str() {
+        "status" => task.status.to_string(),
+        "priority" => task.priority.to_string(),
+        "assignee" => {
+            if task.assignees.is_empty() {
+                "None".to_string()
+            } else {
+                task.assignees.join(", ")
+            }
         }
+        "dependencies" => task.dependencies.join(", "),
+        "due" => task
+            .due
+            .map_or_else(|| "None".to_string(), |d| d.to_string()),
+        "estimate" => task.estimate.clone().unwrap_or_else(|| "None".to_string()),
+        "epic" => task.epic.clone().unwrap_or_else(|| "None".to_string()),
+        _ => unreachable!(),
+    };
+    println!("✅ Updated task {}: {} = {}", task_id, field, shown);
+    auto_commit(
+        &[task_file_path],
+        &format!("update {} {} → {}", task_id, field, shown),
+        &[task_id],
     );
 
     Ok(())
 }
 
-fn find_task_file(tasks_dir: &Path, task_id: &str) -> Result<PathBuf> {
+pub fn find_task_file(tasks_dir: &Path, task_id: &str) -> Result<PathBuf> {
     // Extract area from task ID (e.g., "backend-001" -> "backend")
     let area = task_id
         .split('-')
         .next()
         .ok_or_else(|| anyhow::anyhow!("Invalid task ID format. Expected format: area-number"))?;
 
-    let area_dir = tasks_dir.join(area);
-    let task_file = area_dir.join(format!("{}.md", task_id));
-
-    if !task_file.exists() {
-        return Err(anyhow::anyhow!(
-            "Task file not found: {}. Available tasks can be seen with 'taskguard list'",
-            task_file.display()
-        ));
+    let file_name = format!("{}.md", task_id);
+    let task_file = tasks_dir.join(area).join(&file_name);
+    if task_file.exists() {
+        return Ok(task_file);
     }
 
-    Ok(task_file)
+    // Nested areas (tasks/backend/auth/backend-auth-001.md) need a search
+    WalkDir::new(tasks_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .find(|e| e.file_type().is_file() && e.file_name().to_str() == Some(file_name.as_str()))
+        .map(walkdir::DirEntry::into_path)
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Task file not found: {}. Available tasks can be seen with 'taskguard list'",
+                task_file.display()
+            )
+        })
 }
 
 fn update_status(task: &mut Task, value: String) -> Result<()> {
-    let new_status = match value.as_str() {
-        "todo" => TaskStatus::Todo,
-        "doing" => TaskStatus::Doing,
-        "review" => TaskStatus::Review,
-        "done" => TaskStatus::Done,
-        "blocked" => TaskStatus::Blocked,
-        _ => {
-            return Err(anyhow::anyhow!(
-                "Invalid status '{}'. Valid statuses: todo, doing, review, done, blocked",
-                value
-            ));
-        }
-    };
+    let new_status = TaskStatus::parse(&value)?;
 
     // Validate status transition (basic validation)
     validate_status_transition(&task.status, &new_status)?;
 
-    task.status = new_status;
+    task.set_status(new_status);
     Ok(())
 }
 
@@ -107,11 +122,14 @@ fn update_priority(task: &mut Task, value: String) -> Result<()> {
     Ok(())
 }
 
-fn update_assignee(task: &mut Task, value: String) -> Result<()> {
-    if value.is_empty() || value == "none" || value == "null" {
-        task.assignee = None;
-    } else {
-        task.assignee = Some(value);
+fn update_assignee(task: &mut Task, value: &str) -> Result<()> {
+    let previous = std::mem::take(&mut task.assignees);
+    if !(value.is_empty() || value == "none" || value == "null") {
+        task.assignees = parse_assignees(value);
+    }
+    // Reassigning hands the task over, so an earlier claim no longer applies
+    if task.assignees != previous {
+        task.claimed = None;
     }
     Ok(())
 }
@@ -142,7 +160,33 @@ fn update_dependencies(task: &mut Task, value: String) -> Result<()> {
     Ok(())
 }
 
-fn validate_status_transition(current: &TaskStatus, new: &TaskStatus) -> Result<()> {
+fn update_due(task: &mut Task, value: &str) -> Result<()> {
+    if value.is_empty() || value == "none" || value == "null" {
+        task.due = None;
+    } else {
+        task.due = Some(parse_due_date(value)?);
+    }
+    Ok(())
+}
+
+fn update_estimate(task: &mut Task, value: &str) -> Result<()> {
+    if value.is_empty() || value == "none" || value == "null" {
+        task.estimate = None;
+    } else {
+        task.estimate = Some(normalize_estimate(value)?);
+    }
+    Ok(())
+}
+
+fn update_epic(task: &mut Task, value: String) {
+    if value.is_empty() || value == "none" || value == "null" {
+        task.epic = None;
+    } else {
+        task.epic = Some(value);
+    }
+}
+
+pub(crate) fn validate_status_transition(current: &TaskStatus, new: &TaskStatus) -> Result<()> {
     use TaskStatus::*;
 
     // Allow any transition for now, but warn about potentially problematic ones
@@ -166,21 +210,47 @@ fn validate_status_transition(current: &TaskStatus, new: &TaskStatus) -> Result<
     Ok(())
 }
 
-pub fn run_task_item(task_id: String, item_index: usize, status: String) -> Result<()> {
+/// Changes requested for a single checklist item
+#[derive(Debug, Default)]
+pub struct ItemUpdate {
+    pub status: Option<String>,
+    pub assignee: Option<String>,
+    pub due: Option<String>,
+}
+
+pub fn run_task_item(task_id: String, item: &str, changes: ItemUpdate) -> Result<()> {
     let tasks_dir = get_tasks_dir()?;
 
     // Validate status input
-    let target_completed = match status.as_str() {
-        "done" | "completed" | "true" => true,
-        "todo" | "incomplete" | "false" => false,
-        _ => {
+    let target_completed = match changes.status.as_deref() {
+        None => None,
+        Some("done" | "completed" | "true") => Some(true),
+        Some("todo" | "incomplete" | "false") => Some(false),
+        Some(other) => {
             return Err(anyhow::anyhow!(
                 "Invalid status '{}'. Valid values: done, todo",
-                status
+                other
             ));
         }
     };
 
+    // "none" clears the metadata; a leading '@' on the assignee is optional
+    let assignee = changes.assignee.map(|a| {
+        let name = a.trim().trim_start_matches('@');
+        (!name.is_empty() && name != "none").then(|| name.to_string())
+    });
+    let due = match changes.due.as_deref().map(str::trim) {
+        None => None,
+        Some("" | "none") => Some(None),
+        Some(value) => Some(Some(parse_due_date(value)?)),
+    };
+
+    if target_completed.is_none() && assignee.is_none() && due.is_none() {
+        return Err(anyhow::anyhow!(
+            "Nothing to update. Provide a status (done/todo), --assignee or --due"
+        ));
+    }
+
     // Find the task file
     let task_file_path = find_task_file(&tasks_dir, &task_id)?;
 
@@ -188,7 +258,7 @@ pub fn run_task_item(task_id: String, item_index: usize, status: String) -> Resu
     let mut task = Task::from_file(&task_file_path)?;
 
     // Parse checklist items
-    let items = parse_checklist_items(&task.content)?;
+    let items = checklist::parse_items(&task.content);
 
     if items.is_empty() {
         return Err(anyhow::anyhow!(
@@ -197,19 +267,26 @@ pub fn run_task_item(task_id: String, item_index: usize, status: String) -> Resu
         ));
     }
 
-    // Validate item index (1-based)
-    if item_index == 0 || item_index > items.len() {
+    // Items are addressed by their hierarchical index ("3" or "2.3")
+    let position = checklist::find_item(&items, item.trim()).ok_or_else(|| {
+        anyhow::anyhow!(
+            "Invalid item index {}. See 'taskguard list items {}' for valid indices",
+            item,
+            task_id
+        )
+    })?;
+
+    let target_item = &items[position];
+    if target_completed.is_some() && target_item.has_children() {
         return Err(anyhow::anyhow!(
-            "Invalid item index {}. Valid range: 1-{}",
-            item_index,
-            items.len()
+            "Item {} has sub-items; it is completed when all of them are done",
+            item
         ));
     }
-
-    let target_item = &items[item_index - 1]; // Convert to 0-based
+    let status_changes = target_completed.is_some_and(|c| c != target_item.completed);
 
     // Check if already in target state
-    if target_item.completed == target_completed {
+    if !status_changes && assignee.is_none() && due.is_none() {
         let current_status = if target_item.completed {
             "done"
         } else {
@@ -217,84 +294,100 @@ pub fn run_task_item(task_id: String, item_index: usize, status: String) -> Resu
         };
         println!(
             "✨ Item {} is already {}: {}",
-            item_index, current_status, target_item.text
+            item, current_status, target_item.text
         );
         return Ok(());
     }
 
     // Update the task content by modifying the specific item
-    let updated_content = update_checklist_item(&task.content, item_index - 1, target_completed)?;
+    let updated_content = checklist::update_item(&task.content, position, |target| {
+        if let Some(completed) = target_completed {
+            target.completed = completed;
+        }
+        if let Some(assignee) = assignee {
+            target.assignee = assignee;
+        }
+        if let Some(due) = due {
+            target.due = due;
+        }
+    })?;
     task.content = updated_content;
 
     // Save the updated task
     task.save_to_file(&task_file_path)?;
 
-    // Show success message
-    let new_status = if target_completed { "done" } else { "todo" };
-    let status_icon = if target_completed { "✅" } else { "⭕" };
+    let updated_items = checklist::parse_items(&task.content);
+    let updated = &updated_items[position];
+    let new_status = if updated.completed { "done" } else { "todo" };
+    let status_icon = if updated.completed { "✅" } else { "⭕" };
+    let metadata = updated
+        .metadata_display()
+        .map(|m| format!(" ({})", m))
+        .unwrap_or_default();
 
     println!(
-        "✅ Updated task {} item {}: {} [{}] {}",
-        task_id, item_index, status_icon, new_status, target_item.text
+        "✅ Updated task {} item {}: {} [{}] {}{}",
+        task_id, item, status_icon, new_status, updated.text, metadata
+    );
+    auto_commit(
+        std::slice::from_ref(&task_file_path),
+        &format!("update {} item {} → {}", task_id, item, new_status),
+        std::slice::from_ref(&task_id),
     );
 
-    Ok(())
-}
-
-#[derive(Debug, Clone)]
-struct ChecklistItem {
-    text: String,
-    completed: bool,
-    #[allow(dead_code)] // Reserved for future line-specific editing features
-    line_number: usize,
-}
-
-fn parse_checklist_items(content: &str) -> Result<Vec<ChecklistItem>> {
-    let mut items = Vec::new();
-
-    // Regex to match checklist items like "- [ ] text" or "- [x] text"
-    let checkbox_regex = Regex::new(r"^(\s*)-\s*\[([x\s])\]\s*(.+)$")?;
-
-    for (line_number, line) in content.lines().enumerate() {
-        if let Some(captures) = checkbox_regex.captures(line) {
-            let checkbox_state = captures.get(2).unwrap().as_str().trim();
-            let text = captures.get(3).unwrap().as_str().trim();
+    if !status_changes {
+        return Ok(());
+    }
 
-            let completed = matches!(checkbox_state, "x" | "X");
+    let all_complete = updated_items.iter().all(|i| i.completed);
 
-            items.push(ChecklistItem {
-                text: text.to_string(),
-                completed,
-                line_number: line_number + 1, // 1-based for user display
-            });
+    if !updated.completed && task.status == TaskStatus::Done {
+        println!(
+            "⚠️  Warning: {} is marked done but now has an open checklist item.",
+            task_id
+        );
+        println!("   Consider: taskguard update status {} doing", task_id);
+    } else if all_complete && !matches!(task.status, TaskStatus::Review | TaskStatus::Done) {
+        let config = Config::load_or_default(get_config_path()?)?;
+        if config.settings.checklist_complete_prompt {
+            let leaf_count = checklist::leaf_items(&updated_items).count();
+            offer_status_transition(&mut task, &task_file_path, leaf_count)?;
         }
     }
 
-    Ok(items)
+    Ok(())
 }
 
-fn update_checklist_item(content: &str, item_index: usize, completed: bool) -> Result<String> {
-    let mut lines: Vec<String> = content.lines().map(|s| s.to_string()).collect();
-    let checkbox_regex = Regex::new(r"^(\s*)-\s*\[([x\s])\]\s*(.+)$")?;
-
-    let mut current_item_index = 0;
+/// Ask whether a task with a fully checked checklist should move on
+fn offer_status_transition(task: &mut Task, path: &Path, item_count: usize) -> Result<()> {
+    println!();
+    print!(
+        "🎉 All {} checklist items are complete. Move {} to (r)eview, (d)one, or (k)eep {}? [k]: ",
+        item_count, task.id, task.status
+    );
+    io::stdout().flush()?;
 
-    for (line_index, line) in lines.iter().enumerate() {
-        if checkbox_regex.is_match(line) {
-            if current_item_index == item_index {
-                // This is the line we need to update
-                if let Some(captures) = checkbox_regex.captures(line) {
-                    let indent = captures.get(1).unwrap().as_str();
-                    let text = captures.get(3).unwrap().as_str();
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
 
-                    let new_checkbox = if completed { "[x]" } else { "[ ]" };
-                    lines[line_index] = format!("{}- {} {}", indent, new_checkbox, text);
-                }
-                break;
-            }
-            current_item_index += 1;
+    let new_status = match input.trim().to_lowercase().as_str() {
+        "r" | "review" => TaskStatus::Review,
+        "d" | "done" => TaskStatus::Done,
+        _ => {
+            println!("   Keeping status: {}", task.status);
+            return Ok(());
         }
-    }
+    };
 
-    Ok(lines.join("\n"))
+    validate_status_transition(&task.status, &new_status)?;
+    task.set_status(new_status);
+    task.save_to_file(path)?;
+    println!("✅ Updated task {}: status = {}", task.id, task.status);
+    auto_commit(
+        &[path.to_path_buf()],
+        &format!("update {} status → {}", task.id, task.status),
+        std::slice::from_ref(&task.id),
+    );
+
+    Ok(())
 }
@@ -1,15 +1,127 @@
 use anyhow::{Context, Result};
+use git2::{Delta, Repository};
+use serde::Serialize;
 use std::collections::{HashMap, HashSet};
 use std::fs;
+use std::path::PathBuf;
 use walkdir::WalkDir;
 
+use crate::commands::claim::claim_holder;
+use crate::commands::comment::current_author;
 use crate::config::{
-    Config, find_taskguard_root, get_config_path, get_tasks_dir, load_tasks_from_dir,
+    Config, FieldIssue, check_custom_fields, find_taskguard_root, get_config_path, get_tasks_dir,
+    load_tasks_from_dir,
 };
+use crate::git::GitAnalyzer;
 use crate::github::{TaskIssueMapper, is_github_sync_enabled};
-use crate::task::{Task, TaskStatus};
+use crate::task::{FORMAT_VERSION, Task, TaskStatus, validate_frontmatter};
 
-pub fn run(sync_areas: bool, show_orphans: bool) -> Result<()> {
+/// Machine-readable result of `validate --json`
+#[derive(Debug, Default, Serialize)]
+struct ValidationReport {
+    valid: bool,
+    total_tasks: usize,
+    total_issues: usize,
+    parse_errors: Vec<String>,
+    dependency_issues: Vec<String>,
+    broken_dependencies: Vec<BrokenDependency>,
+    circular_dependencies: Vec<DependencyCycle>,
+    relation_issues: Vec<String>,
+    hierarchy_issues: Vec<String>,
+    link_issues: Vec<String>,
+    field_errors: Vec<String>,
+    field_warnings: Vec<String>,
+    due_date_warnings: Vec<String>,
+    claim_warnings: Vec<String>,
+    available: Vec<String>,
+    blocked: Vec<String>,
+    orphans: Vec<String>,
+}
+
+/// A dependency on a task that isn't in `tasks/`
+#[derive(Debug, Serialize)]
+struct BrokenDependency {
+    task: String,
+    dependency: String,
+    /// `true` when the dependency lives in the archive and can be restored
+    archived: bool,
+    fix: String,
+}
+
+impl BrokenDependency {
+    fn new(task: &Task, dependency: &str, archived: bool) -> Self {
+        let remaining: Vec<&str> = task
+            .dependencies
+            .iter()
+            .map(String::as_str)
+            .filter(|d| *d != dependency)
+            .collect();
+        let remove = format!(
+            "taskguard update dependencies {} \"{}\"",
+            task.id,
+            if remaining.is_empty() {
+                "none".to_string()
+            } else {
+                remaining.join(",")
+            }
+        );
+        let fix = if archived {
+            format!("taskguard restore {} or {}", dependency, remove)
+        } else {
+            remove
+        };
+        BrokenDependency {
+            task: task.id.clone(),
+            dependency: dependency.to_string(),
+            archived,
+            fix,
+        }
+    }
+
+    fn message(&self) -> String {
+        if self.archived {
+            format!(
+                "📦 {}: Depends on archived task '{}'",
+                self.task, self.dependency
+            )
+        } else {
+            format!(
+                "❌ {}: Depends on missing task '{}'",
+                self.task, self.dependency
+            )
+        }
+    }
+}
+
+/// One dependency loop: `path` starts and ends with the same task
+#[derive(Debug, Serialize)]
+struct DependencyCycle {
+    path: Vec<String>,
+    files: Vec<String>,
+}
+
+impl DependencyCycle {
+    fn chain(&self) -> String {
+        self.path.join(" → ")
+    }
+}
+
+/// Strip the leading status icon so JSON consumers get plain messages
+fn plain(issues: &[String]) -> Vec<String> {
+    issues
+        .iter()
+        .map(|issue| issue.trim_start_matches("❌ ").to_string())
+        .collect()
+}
+
+#[allow(clippy::fn_params_excessive_bools)]
+pub fn run(
+    sync_areas: bool,
+    show_orphans: bool,
+    check_links: bool,
+    strict: bool,
+    json: bool,
+) -> Result<()> {
     let tasks_dir = get_tasks_dir()?;
 
     // Sync areas first if requested
@@ -18,6 +130,9 @@ pub fn run(sync_areas: bool, show_orphans: bool) -> Result<()> {
     }
 
     if !tasks_dir.exists() {
+        if json {
+            return print_json(&ValidationReport::default());
+        }
         println!("📁 No tasks directory found. Run 'taskguard init' first.");
         return Ok(());
     }
@@ -31,6 +146,9 @@ pub fn run(sync_areas: bool, show_orphans: bool) -> Result<()> {
         .collect();
 
     if task_files.is_empty() {
+        if json {
+            return print_json(&ValidationReport::default());
+        }
         println!("📋 No tasks found to validate.");
         return Ok(());
     }
@@ -39,6 +157,22 @@ pub fn run(sync_areas: bool, show_orphans: bool) -> Result<()> {
     let mut parse_errors = Vec::new();
 
     for file in task_files {
+        // Report schema problems with line, field and fix instead of the raw serde error
+        let schema_issues = fs::read_to_string(file.path())
+            .map(|content| validate_frontmatter(&content))
+            .unwrap_or_default();
+        if !schema_issues.is_empty() {
+            for issue in &schema_issues {
+                parse_errors.push(format!(
+                    "❌ {}:{}: {}",
+                    file.path().display(),
+                    issue.line,
+                    issue
+                ));
+            }
+            continue;
+        }
+
         match Task::from_file(file.path()) {
             Ok(task) => tasks.push(task),
             Err(e) => {
@@ -59,7 +193,7 @@ pub fn run(sync_areas: bool, show_orphans: bool) -> Result<()> {
     }
 
     // Show parse errors
-    if !parse_errors.is_empty() {
+    if !json && !parse_errors.is_empty() {
         println!("🔍 PARSE ERRORS");
         for error in &parse_errors {
             println!("   {}", error);
@@ -67,7 +201,44 @@ pub fn run(sync_areas: bool, show_orphans: bool) -> Result<()> {
         println!();
     }
 
+    // Older files still load, but `migrate` should bring them up to date
+    let config = Config::load_or_default(get_config_path()?)?;
+    let outdated = tasks
+        .iter()
+        .filter(|t| t.format_version < FORMAT_VERSION)
+        .count();
+    let newer = tasks
+        .iter()
+        .filter(|t| t.format_version > FORMAT_VERSION)
+        .count();
+    if !json && (outdated > 0 || newer > 0 || config.project.format_version < FORMAT_VERSION) {
+        println!("🧬 FORMAT VERSION");
+        if outdated > 0 {
+            println!(
+                "   ⚠️  {} task file(s) use an older format (run 'taskguard migrate')",
+                outdated
+            );
+        }
+        if config.project.format_version < FORMAT_VERSION {
+            println!("   ⚠️  config.toml uses an older format (run 'taskguard migrate')");
+        }
+        if newer > 0 {
+            println!(
+                "   ⚠️  {} task file(s) were written by a newer TaskGuard (format > {}); upgrade taskguard",
+                newer, FORMAT_VERSION
+            );
+        }
+        println!();
+    }
+
     if tasks.is_empty() {
+        if json {
+            return print_json(&ValidationReport {
+                total_issues: parse_errors.len(),
+                parse_errors: plain(&parse_errors),
+                ..ValidationReport::default()
+            });
+        }
         println!("❌ No valid tasks found to validate.");
         return Ok(());
     }
@@ -88,11 +259,9 @@ pub fn run(sync_areas: bool, show_orphans: bool) -> Result<()> {
         .filter(|t| !archived_ids.contains(&t.id))
         .collect();
 
-    // Find dependency issues (only check non-done active tasks)
-    let mut dependency_issues = Vec::new();
-    let mut circular_deps = Vec::new();
-    // Track fully-verified nodes across all cycle checks for efficiency
-    let mut cycle_verified: HashSet<String> = HashSet::new();
+    // Find dependency issues (only check non-done active tasks). Missing IDs
+    // are errors; archived ones still resolve, so they're only warnings
+    let mut broken_dependencies = Vec::new();
 
     for task in &active_tasks {
         // Skip done tasks - they don't need dependency validation
@@ -101,31 +270,139 @@ pub fn run(sync_areas: bool, show_orphans: bool) -> Result<()> {
         }
 
         for dep in &task.dependencies {
-            if !all_ids.contains(dep) {
-                dependency_issues
-                    .push(format!("❌ {}: Depends on missing task '{}'", task.id, dep));
+            if archived_ids.contains(dep) {
+                broken_dependencies.push(BrokenDependency::new(task, dep, true));
+            } else if !all_ids.contains(dep) {
+                broken_dependencies.push(BrokenDependency::new(task, dep, false));
             }
         }
-
-        // Check for circular dependencies
-        if has_circular_dependency(task, &task_map, &mut HashSet::new(), &mut cycle_verified) {
-            circular_deps.push(task.id.clone());
-        }
     }
+    let dependency_issues: Vec<String> = broken_dependencies
+        .iter()
+        .filter(|b| !b.archived)
+        .map(BrokenDependency::message)
+        .collect();
+    let archived_dependencies = broken_dependencies.iter().filter(|b| b.archived).count();
+
+    // Each distinct loop is reported once, with the files to edit to break it
+    let project_root = find_taskguard_root().unwrap_or_default();
+    let circular_deps: Vec<DependencyCycle> = find_dependency_cycles(&active_tasks, &task_map)
+        .into_iter()
+        .map(|path| {
+            let files = path[..path.len() - 1]
+                .iter()
+                .filter_map(|id| task_map.get(id))
+                .map(|t| {
+                    t.file_path
+                        .strip_prefix(&project_root)
+                        .unwrap_or(&t.file_path)
+                        .display()
+                        .to_string()
+                })
+                .collect();
+            DependencyCycle { path, files }
+        })
+        .collect();
 
     // Show dependency issues
-    if !dependency_issues.is_empty() {
+    if !json && !broken_dependencies.is_empty() {
         println!("🔗 DEPENDENCY ISSUES");
-        for issue in &dependency_issues {
+        for broken in &broken_dependencies {
+            println!("   {}", broken.message());
+            println!("      💡 Fix: {}", broken.fix);
+        }
+        println!();
+    }
+
+    // Typed relations must point at tasks that exist
+    let relation_issues = find_relation_issues(&active_tasks, &all_ids);
+    if !json && !relation_issues.is_empty() {
+        println!("🧷 RELATION ISSUES");
+        for issue in &relation_issues {
+            println!("   {}", issue);
+        }
+        println!();
+    }
+
+    // A parent can't be finished while its subtasks are still open
+    let hierarchy_issues = find_hierarchy_issues(&active_tasks, &task_map);
+    if !json && !hierarchy_issues.is_empty() {
+        println!("🌳 HIERARCHY ISSUES");
+        for issue in &hierarchy_issues {
             println!("   {}", issue);
         }
         println!();
     }
 
-    if !circular_deps.is_empty() {
+    // Due dates that can't be met because a dependency is due later
+    let due_warnings = find_due_date_conflicts(&active_tasks, &task_map);
+    if !json && !due_warnings.is_empty() {
+        println!("📅 DUE DATE WARNINGS");
+        for warning in &due_warnings {
+            println!("   ⚠️  {}", warning);
+        }
+        println!();
+    }
+
+    // Changes to claimed tasks by anyone other than the claimant
+    let claim_warnings = find_claim_conflicts(&active_tasks);
+    if !json && !claim_warnings.is_empty() {
+        println!("🔒 CLAIM WARNINGS");
+        for warning in &claim_warnings {
+            println!("   ⚠️  {}", warning);
+        }
+        println!();
+    }
+
+    // Dead relative paths and malformed URLs in `links:`, when requested
+    let mut link_issues = Vec::new();
+    if check_links {
+        for task in &active_tasks {
+            for issue in task.link_issues(&project_root) {
+                link_issues.push(format!("❌ {}: {}", task.id, issue));
+            }
+        }
+    }
+    if !json && !link_issues.is_empty() {
+        println!("🔗 LINK ISSUES");
+        for issue in &link_issues {
+            println!("   {}", issue);
+        }
+        println!();
+    }
+
+    // Custom frontmatter fields checked against the [fields] schema
+    let mut field_errors = Vec::new();
+    let mut field_warnings = Vec::new();
+    for task in &active_tasks {
+        for issue in check_custom_fields(task, &config.fields) {
+            match issue {
+                FieldIssue::TypeMismatch(msg) => field_errors.push(msg),
+                // --strict treats undeclared fields (often typos) as errors
+                FieldIssue::Unknown(msg) if strict => field_errors.push(msg),
+                FieldIssue::Unknown(msg) => field_warnings.push(msg),
+            }
+        }
+    }
+
+    if !json && (!field_errors.is_empty() || !field_warnings.is_empty()) {
+        println!("🏷️  CUSTOM FIELDS");
+        for error in &field_errors {
+            println!("   ❌ {}", error);
+        }
+        for warning in &field_warnings {
+            println!("   ⚠️  {}", warning);
+        }
+        println!();
+    }
+
+    if !json && !circular_deps.is_empty() {
         println!("🔄 CIRCULAR DEPENDENCIES");
-        for task_id in &circular_deps {
-            println!("   ❌ {}: Has circular dependency", task_id);
+        for cycle in &circular_deps {
+            println!("   ❌ Dependency cycle: {}", cycle.chain());
+            for (id, file) in cycle.path.iter().zip(&cycle.files) {
+                println!("      {}: {}", id, file);
+            }
         }
         println!();
     }
@@ -157,6 +434,41 @@ pub fn run(sync_areas: bool, show_orphans: bool) -> Result<()> {
         }
     }
 
+    // Find orphan tasks (no dependencies AND nothing depends on them)
+    let orphan_tasks = find_orphan_tasks(&active_tasks, &archived_ids);
+    let orphan_count = orphan_tasks.len();
+
+    let total_issues = parse_errors.len()
+        + dependency_issues.len()
+        + relation_issues.len()
+        + hierarchy_issues.len()
+        + circular_deps.len()
+        + field_errors.len()
+        + link_issues.len();
+
+    if json {
+        print_json(&ValidationReport {
+            valid: total_issues == 0,
+            total_tasks: tasks.len(),
+            total_issues,
+            parse_errors: plain(&parse_errors),
+            dependency_issues: plain(&dependency_issues),
+            broken_dependencies,
+            circular_dependencies: circular_deps,
+            relation_issues: plain(&relation_issues),
+            hierarchy_issues: plain(&hierarchy_issues),
+            link_issues: plain(&link_issues),
+            field_errors,
+            field_warnings,
+            due_date_warnings: due_warnings,
+            claim_warnings,
+            available: available_tasks.iter().map(|t| t.id.clone()).collect(),
+            blocked: blocked_tasks.iter().map(|(t, _)| t.id.clone()).collect(),
+            orphans: orphan_tasks.iter().map(|t| t.id.clone()).collect(),
+        })?;
+        return check_strict(strict, total_issues);
+    }
+
     // Show task availability
     println!("🚦 TASK STATUS");
 
@@ -198,10 +510,6 @@ pub fn run(sync_areas: bool, show_orphans: bool) -> Result<()> {
         println!();
     }
 
-    // Find orphan tasks (no dependencies AND nothing depends on them)
-    let orphan_tasks = find_orphan_tasks(&active_tasks, &archived_ids);
-    let orphan_count = orphan_tasks.len();
-
     // Show orphan details if requested
     if show_orphans {
         println!("🔍 ORPHAN TASKS");
@@ -228,8 +536,6 @@ pub fn run(sync_areas: bool, show_orphans: bool) -> Result<()> {
     }
 
     // Summary
-    let total_issues = parse_errors.len() + dependency_issues.len() + circular_deps.len();
-
     if total_issues == 0 {
         println!("✅ VALIDATION PASSED");
         println!("   No issues found in {} tasks", tasks.len());
@@ -258,6 +564,24 @@ pub fn run(sync_areas: bool, show_orphans: bool) -> Result<()> {
     }
     println!("   Parse errors: {}", parse_errors.len());
     println!("   Dependency issues: {}", dependency_issues.len());
+    if archived_dependencies > 0 {
+        println!(
+            "   Dependencies on archived tasks: {}",
+            archived_dependencies
+        );
+    }
+    if !relation_issues.is_empty() {
+        println!("   Relation issues: {}", relation_issues.len());
+    }
+    if !due_warnings.is_empty() {
+        println!("   Due date warnings: {}", due_warnings.len());
+    }
+    if !link_issues.is_empty() {
+        println!("   Link issues: {}", link_issues.len());
+    }
+    if !claim_warnings.is_empty() {
+        println!("   Claim warnings: {}", claim_warnings.len());
+    }
 
     // GitHub sync validation
     if is_github_sync_enabled().unwrap_or(false)
@@ -296,9 +620,299 @@ pub fn run(sync_areas: bool, show_orphans: bool) -> Result<()> {
         }
     }
 
+    check_strict(strict, total_issues)
+}
+
+/// `validate --staged`: check only the task files staged in git, as staged, and
+/// fail on broken frontmatter, unknown dependencies or duplicate IDs. Other
+/// tasks are only scanned for their IDs, so this stays fast on large projects.
+pub fn run_staged() -> Result<()> {
+    let root =
+        find_taskguard_root().context("Not in a TaskGuard project. Run 'taskguard init' first.")?;
+    let repo =
+        Repository::discover(&root).context("Not in a Git repository. Run 'git init' first.")?;
+    let workdir = repo
+        .workdir()
+        .context("Cannot check staged files in a bare repository")?
+        .canonicalize()?;
+    let task_dirs: Vec<PathBuf> = [get_tasks_dir()?, root.join(".taskguard").join("archive")]
+        .into_iter()
+        .filter(|dir| dir.exists())
+        .map(|dir| dir.canonicalize())
+        .collect::<std::io::Result<_>>()?;
+
+    let index = repo.index().context("Failed to read the git index")?;
+    let head_tree = repo.head().ok().and_then(|head| head.peel_to_tree().ok());
+    let diff = repo
+        .diff_tree_to_index(head_tree.as_ref(), Some(&index), None)
+        .context("Failed to list staged files")?;
+
+    let mut staged: Vec<(PathBuf, String)> = Vec::new();
+    for delta in diff.deltas() {
+        if delta.status() == Delta::Deleted {
+            continue;
+        }
+        let file = delta.new_file();
+        let Some(path) = file.path() else {
+            continue;
+        };
+        let absolute = workdir.join(path);
+        if absolute.extension().is_none_or(|ext| ext != "md")
+            || !task_dirs.iter().any(|dir| absolute.starts_with(dir))
+        {
+            continue;
+        }
+        let blob = repo
+            .find_blob(file.id())
+            .with_context(|| format!("Failed to read staged {}", path.display()))?;
+        staged.push((
+            path.to_path_buf(),
+            String::from_utf8_lossy(blob.content()).into_owned(),
+        ));
+    }
+
+    if staged.is_empty() {
+        println!("✅ No staged task files to validate");
+        return Ok(());
+    }
+    println!("🔍 Validating {} staged task file(s)", staged.len());
+
+    // IDs of the tasks on disk; staged files stand in for their working copies
+    let mut known: HashMap<String, PathBuf> = HashMap::new();
+    for dir in &task_dirs {
+        for entry in WalkDir::new(dir)
+            .into_iter()
+            .filter_map(Result::ok)
+            .filter(|e| e.file_type().is_file())
+            .filter(|e| e.path().extension().is_some_and(|ext| ext == "md"))
+        {
+            let relative = entry.path().strip_prefix(&workdir).unwrap_or(entry.path());
+            if staged.iter().any(|(path, _)| path == relative) {
+                continue;
+            }
+            if let Some(id) = fs::read_to_string(entry.path())
+                .ok()
+                .and_then(|content| frontmatter_id(&content))
+            {
+                known.insert(id, relative.to_path_buf());
+            }
+        }
+    }
+
+    let mut errors = Vec::new();
+    let mut parsed = Vec::new();
+    for (path, content) in &staged {
+        let schema_issues = validate_frontmatter(content);
+        if !schema_issues.is_empty() {
+            for issue in &schema_issues {
+                errors.push(format!("{}:{}: {}", path.display(), issue.line, issue));
+            }
+            continue;
+        }
+        match Task::parse_content(content) {
+            Ok(task) => parsed.push((path, task)),
+            Err(e) => errors.push(format!("{}: {}", path.display(), e)),
+        }
+    }
+
+    for (path, task) in &parsed {
+        if let Some(other) = known.get(&task.id) {
+            errors.push(format!(
+                "{}: ID '{}' is already used by {}",
+                path.display(),
+                task.id,
+                other.display()
+            ));
+        }
+        if let Some((other, _)) = parsed
+            .iter()
+            .find(|(other, t)| t.id == task.id && other != path)
+        {
+            errors.push(format!(
+                "{}: ID '{}' is also used by {}",
+                path.display(),
+                task.id,
+                other.display()
+            ));
+        }
+        for dep in &task.dependencies {
+            if !known.contains_key(dep) && !parsed.iter().any(|(_, t)| &t.id == dep) {
+                errors.push(format!(
+                    "{}: depends on missing task '{}'",
+                    path.display(),
+                    dep
+                ));
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        println!("✅ Staged task files are valid");
+        return Ok(());
+    }
+    for error in &errors {
+        println!("   ❌ {}", error);
+    }
+    Err(anyhow::anyhow!(
+        "{} problem(s) in staged task files",
+        errors.len()
+    ))
+}
+
+/// `id` from a task file's frontmatter, without parsing the rest of the file
+fn frontmatter_id(content: &str) -> Option<String> {
+    let mut lines = content.lines();
+    let delimiter = lines.next()?.trim();
+    if delimiter != "---" && delimiter != "+++" {
+        return None;
+    }
+    lines
+        .take_while(|line| line.trim() != delimiter)
+        .find_map(|line| {
+            let rest = line.strip_prefix("id")?.trim_start();
+            let value = rest.strip_prefix(':').or_else(|| rest.strip_prefix('='))?;
+            Some(value.trim().trim_matches(['"', '\'']).to_string())
+        })
+        .filter(|id| !id.is_empty())
+}
+
+fn check_strict(strict: bool, total_issues: usize) -> Result<()> {
+    if strict && total_issues > 0 {
+        return Err(anyhow::anyhow!(
+            "Strict validation failed with {} issue(s)",
+            total_issues
+        ));
+    }
+    Ok(())
+}
+
+fn print_json(report: &ValidationReport) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(report)?);
     Ok(())
 }
 
+/// Check that every typed relation targets another existing task
+fn find_relation_issues(active_tasks: &[&Task], all_ids: &HashSet<String>) -> Vec<String> {
+    let mut issues = Vec::new();
+    for task in active_tasks {
+        for (kind, target) in task.relations.links() {
+            if target == task.id {
+                issues.push(format!(
+                    "❌ {}: '{}' relation points to itself",
+                    task.id, kind
+                ));
+            } else if !all_ids.contains(target) {
+                issues.push(format!(
+                    "❌ {}: '{}' relation references missing task '{}'",
+                    task.id, kind, target
+                ));
+            }
+        }
+    }
+    issues
+}
+
+/// Find done parents with open subtasks and cycles in parent chains
+fn find_hierarchy_issues(active_tasks: &[&Task], task_map: &HashMap<String, &Task>) -> Vec<String> {
+    let mut issues = Vec::new();
+
+    for task in active_tasks {
+        if let Some(parent) = task.parent().and_then(|p| task_map.get(p))
+            && parent.status == TaskStatus::Done
+            && task.status != TaskStatus::Done
+        {
+            issues.push(format!(
+                "❌ {}: Marked done but subtask {} is still {}",
+                parent.id, task.id, task.status
+            ));
+        }
+
+        // Walk up the parent chain; revisiting a task means a cycle
+        let mut seen = HashSet::from([task.id.as_str()]);
+        let mut current = task.parent();
+        while let Some(id) = current {
+            if !seen.insert(id) {
+                issues.push(format!("❌ {}: Parent chain forms a cycle", task.id));
+                break;
+            }
+            current = task_map.get(id).and_then(|t| t.parent());
+        }
+    }
+
+    issues
+}
+
+/// Find open tasks due before one of their incomplete dependencies
+fn find_due_date_conflicts(
+    active_tasks: &[&Task],
+    task_map: &HashMap<String, &Task>,
+) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    for task in active_tasks {
+        let Some(due) = task.due else { continue };
+        if task.status == TaskStatus::Done {
+            continue;
+        }
+
+        for dep_id in &task.dependencies {
+            if let Some(dep) = task_map.get(dep_id)
+                && dep.status != TaskStatus::Done
+                && let Some(dep_due) = dep.due
+                && dep_due > due
+            {
+                warnings.push(format!(
+                    "{} is due {} but depends on {} which is due later ({})",
+                    task.id, due, dep.id, dep_due
+                ));
+            }
+        }
+    }
+
+    warnings
+}
+
+/// Find claimed tasks changed by someone other than the claimant, either in
+/// commits since the claim or as uncommitted edits by the current git user
+fn find_claim_conflicts(active_tasks: &[&Task]) -> Vec<String> {
+    let claimed: Vec<_> = active_tasks
+        .iter()
+        .filter(|t| t.status != TaskStatus::Done)
+        .filter_map(|t| Some((t, t.claimed?, claim_holder(t)?)))
+        .collect();
+    if claimed.is_empty() {
+        return Vec::new();
+    }
+    let Some(analyzer) = find_taskguard_root().and_then(|root| GitAnalyzer::new(root).ok()) else {
+        return Vec::new();
+    };
+    let user = current_author();
+
+    let mut warnings = Vec::new();
+    for (task, claimed_at, holder) in claimed {
+        for revision in analyzer.task_history(&task.id).unwrap_or_default() {
+            if revision.timestamp > claimed_at && !task.assignees.contains(&revision.author) {
+                warnings.push(format!(
+                    "{} is claimed by {} but was changed by {} in {} ({})",
+                    task.id,
+                    holder,
+                    revision.author,
+                    &revision.oid[..revision.oid.len().min(7)],
+                    revision.message
+                ));
+            }
+        }
+        if !task.assignees.contains(&user) && analyzer.has_uncommitted_changes(&task.file_path) {
+            warnings.push(format!(
+                "{} is claimed by {} but has uncommitted changes by {}",
+                task.id, holder, user
+            ));
+        }
+    }
+
+    warnings
+}
+
 /// Find orphan tasks - tasks with no dependencies AND nothing depends on them
 /// Note: setup-001 is exempt as it's the root task
 fn find_orphan_tasks<'a>(
@@ -326,40 +940,59 @@ fn find_orphan_tasks<'a>(
         .collect()
 }
 
-/// Check for circular dependencies using proper DFS with gray/black coloring.
-/// - `in_stack`: nodes currently being processed (gray) - a cycle exists if we hit one
-/// - `visited`: nodes fully processed (black) - safe to skip, already verified no cycles
-fn has_circular_dependency(
-    task: &Task,
+/// Find dependency cycles among open active tasks with a DFS, each returned as
+/// the chain of IDs from its first task back to itself (`a → b → a`)
+///
+/// Every back edge yields one cycle, so each strongly connected loop is reported
+/// at least once; a loop reached from different entry points is deduplicated by
+/// rotating its smallest ID to the front.
+fn find_dependency_cycles(
+    active_tasks: &[&Task],
     task_map: &HashMap<String, &Task>,
-    in_stack: &mut HashSet<String>,
-    visited: &mut HashSet<String>,
-) -> bool {
-    // If already fully processed, no cycle through this path
-    if visited.contains(&task.id) {
-        return false;
-    }
+) -> Vec<Vec<String>> {
+    let mut cycles = Vec::new();
+    let mut visited = HashSet::new();
 
-    // If in current recursion stack, we found a cycle!
-    if in_stack.contains(&task.id) {
-        return true;
+    for task in active_tasks {
+        if task.status != TaskStatus::Done {
+            collect_cycles(task, task_map, &mut Vec::new(), &mut visited, &mut cycles);
+        }
     }
 
-    // Mark as being processed (gray)
-    in_stack.insert(task.id.clone());
+    cycles
+}
 
-    for dep_id in &task.dependencies {
-        if let Some(dep_task) = task_map.get(dep_id)
-            && has_circular_dependency(dep_task, task_map, in_stack, visited)
-        {
-            return true;
+/// DFS step: `path` is the current recursion stack (gray), `visited` holds
+/// fully processed tasks (black) that can't lead to a new cycle
+fn collect_cycles<'a>(
+    task: &'a Task,
+    task_map: &HashMap<String, &'a Task>,
+    path: &mut Vec<&'a str>,
+    visited: &mut HashSet<&'a str>,
+    cycles: &mut Vec<Vec<String>>,
+) {
+    if let Some(start) = path.iter().position(|id| *id == task.id) {
+        let mut cycle: Vec<String> = path[start..].iter().map(ToString::to_string).collect();
+        let first = (0..cycle.len()).min_by_key(|&i| &cycle[i]).unwrap_or(0);
+        cycle.rotate_left(first);
+        cycle.push(cycle[0].clone());
+        if !cycles.contains(&cycle) {
+            cycles.push(cycle);
         }
+        return;
+    }
+    if visited.contains(task.id.as_str()) {
+        return;
     }
 
-    // Done processing - remove from stack (gray -> black)
-    in_stack.remove(&task.id);
-    visited.insert(task.id.clone());
-    false
+    path.push(&task.id);
+    for dep_id in &task.dependencies {
+        if let Some(dep_task) = task_map.get(dep_id) {
+            collect_cycles(dep_task, task_map, path, visited, cycles);
+        }
+    }
+    path.pop();
+    visited.insert(&task.id);
 }
 
 /// Sync config areas with actual task directories
@@ -367,13 +1000,21 @@ pub fn sync_config_areas() -> Result<()> {
     let tasks_dir = get_tasks_dir()?;
     let config_path = get_config_path()?;
 
-    // Discover actual areas from filesystem (only directories)
-    let mut discovered_areas: Vec<String> = fs::read_dir(&tasks_dir)
-        .context("Failed to read tasks directory")?
+    // Discover actual areas from filesystem (only directories); nested
+    // directories become nested areas like "backend/auth"
+    let mut discovered_areas: Vec<String> = WalkDir::new(&tasks_dir)
+        .min_depth(1)
+        .into_iter()
+        .filter_entry(|entry| !entry.file_name().to_string_lossy().starts_with('.')) // Skip hidden directories
         .filter_map(|entry| entry.ok())
-        .filter(|entry| entry.file_type().ok().is_some_and(|ft| ft.is_dir()))
-        .filter_map(|entry| entry.file_name().into_string().ok())
-        .filter(|name| !name.starts_with('.')) // Skip hidden directories
+        .filter(|entry| entry.file_type().is_dir())
+        .filter_map(|entry| {
+            entry
+                .path()
+                .strip_prefix(&tasks_dir)
+                .ok()
+                .map(|rel| rel.to_string_lossy().replace('\\', "/"))
+        })
         .collect();
     discovered_areas.sort();
 
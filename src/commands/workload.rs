@@ -0,0 +1,124 @@
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+
+use crate::config::load_all_tasks;
+use crate::task::{Priority, Task, TaskStatus, area_matches, estimate_to_minutes, format_estimate};
+
+/// Open work held by one assignee
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Workload {
+    pub assignee: String,
+    pub tasks: usize,
+    /// Estimated minutes; shared tasks are split evenly between their assignees
+    pub minutes: u32,
+    pub unestimated: usize,
+}
+
+/// Open workload per assignee, heaviest first
+pub fn workloads(tasks: &[&Task]) -> Vec<Workload> {
+    let mut by_assignee: BTreeMap<&str, Workload> = BTreeMap::new();
+    for task in tasks.iter().filter(|t| t.status != TaskStatus::Done) {
+        let share = u32::try_from(task.assignees.len()).unwrap_or(1).max(1);
+        for assignee in &task.assignees {
+            let load = by_assignee.entry(assignee).or_insert_with(|| Workload {
+                assignee: assignee.clone(),
+                ..Workload::default()
+            });
+            load.tasks += 1;
+            match task.estimate_minutes() {
+                Some(minutes) => load.minutes += minutes / share,
+                None => load.unestimated += 1,
+            }
+        }
+    }
+
+    let mut loads: Vec<Workload> = by_assignee.into_values().collect();
+    loads.sort_by(|a, b| b.minutes.cmp(&a.minutes).then(b.tasks.cmp(&a.tasks)));
+    loads
+}
+
+/// Open high and critical priority tasks nobody is assigned to
+pub fn unassigned_urgent<'a>(tasks: &[&'a Task]) -> Vec<&'a Task> {
+    tasks
+        .iter()
+        .filter(|t| t.status != TaskStatus::Done && t.assignees.is_empty())
+        .filter(|t| matches!(t.priority, Priority::High | Priority::Critical))
+        .copied()
+        .collect()
+}
+
+pub fn run(area: Option<&str>, max: &str) -> Result<()> {
+    let max_minutes = estimate_to_minutes(max).ok_or_else(|| {
+        anyhow::anyhow!(
+            "Invalid --max '{}'. Use an estimate like 1w, 3d or 16h",
+            max
+        )
+    })?;
+
+    let all_tasks = load_all_tasks().context("Failed to load tasks")?;
+    let scoped: Vec<&Task> = all_tasks
+        .iter()
+        .filter(|t| area.is_none_or(|a| area_matches(&t.area, a)))
+        .collect();
+
+    let loads = workloads(&scoped);
+    let unassigned = unassigned_urgent(&scoped);
+    if loads.is_empty() && unassigned.is_empty() {
+        println!("✅ No open assigned tasks");
+        return Ok(());
+    }
+
+    println!(
+        "👥 WORKLOAD{} (open tasks)",
+        area.map(|a| format!(" - {}", a)).unwrap_or_default()
+    );
+    for load in &loads {
+        let icon = if load.minutes > max_minutes {
+            "⚠️ "
+        } else {
+            "  "
+        };
+        let unestimated = if load.unestimated > 0 {
+            format!(" ({} unestimated)", load.unestimated)
+        } else {
+            String::new()
+        };
+        println!(
+            "   {} {:<20} {:>3} tasks  {:>10}{}",
+            icon,
+            load.assignee,
+            load.tasks,
+            format_estimate(load.minutes),
+            unestimated
+        );
+    }
+
+    let overloaded: Vec<&Workload> = loads.iter().filter(|l| l.minutes > max_minutes).collect();
+    if !overloaded.is_empty() {
+        println!();
+        println!(
+            "⚠️  OVERLOADED (more than {} of open work)",
+            format_estimate(max_minutes)
+        );
+        for load in overloaded {
+            println!(
+                "   {} - {} across {} tasks",
+                load.assignee,
+                format_estimate(load.minutes),
+                load.tasks
+            );
+        }
+        println!("   💡 Reassign with 'taskguard update assignee <task-id> <name>'");
+    }
+
+    if !unassigned.is_empty() {
+        println!();
+        println!("🚨 UNASSIGNED HIGH-PRIORITY TASKS");
+        for task in &unassigned {
+            println!("   {} - {} [{}]", task.id, task.title, task.priority);
+        }
+        println!("   💡 Run 'taskguard plan' to see who could pick them up");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,223 @@
+use anyhow::{Context, Result};
+use git2::{
+    BranchType, Repository, StatusOptions, Worktree, WorktreeAddOptions, WorktreePruneOptions,
+};
+use std::path::PathBuf;
+
+use crate::commands::branch::{record_branch, task_branch};
+use crate::config::{Config, find_taskguard_root, get_config_path, load_all_tasks};
+use crate::task::{Task, TaskStatus};
+
+fn open_repo() -> Result<(PathBuf, Repository)> {
+    let root =
+        find_taskguard_root().context("Not in a TaskGuard project. Run 'taskguard init' first.")?;
+    let repo =
+        Repository::discover(&root).context("Not in a Git repository. Run 'git init' first.")?;
+    Ok((root, repo))
+}
+
+fn find_task<'a>(tasks: &'a [Task], task_id: &str) -> Result<&'a Task> {
+    tasks
+        .iter()
+        .find(|t| t.id == task_id)
+        .ok_or_else(|| anyhow::anyhow!("Task '{}' not found", task_id))
+}
+
+/// Create a linked worktree named after the task in `[git] worktree_dir`, on
+/// the task's branch, and record the branch in the task's frontmatter
+pub fn run_add(task_id: &str) -> Result<()> {
+    let tasks = load_all_tasks().context("Failed to load tasks")?;
+    let task = find_task(&tasks, task_id)?;
+    let (root, repo) = open_repo()?;
+    let config = Config::load_or_default(get_config_path()?)?;
+
+    if repo.find_worktree(&task.id).is_ok() {
+        return Err(anyhow::anyhow!(
+            "{} already has a worktree; see 'taskguard worktree list'",
+            task.id
+        ));
+    }
+
+    let project = root
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let dir = root.join(config.git.worktree_dir.replace("{project}", &project));
+    let path = dir.join(&task.id);
+    if path.exists() {
+        return Err(anyhow::anyhow!("{} already exists", path.display()));
+    }
+    std::fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create worktree directory: {}", dir.display()))?;
+
+    let name = task_branch(task, &config)?;
+    let branch = match repo.find_branch(&name, BranchType::Local) {
+        Ok(branch) => branch,
+        Err(_) => {
+            let head = repo.head().and_then(|head| head.peel_to_commit()).context(
+                "The repository has no commits yet; commit once before adding worktrees",
+            )?;
+            repo.branch(&name, &head, false)
+                .with_context(|| format!("Failed to create branch {}", name))?
+        }
+    };
+
+    let reference = branch.into_reference();
+    repo.worktree(
+        &task.id,
+        &path,
+        Some(WorktreeAddOptions::new().reference(Some(&reference))),
+    )
+    .with_context(|| {
+        format!(
+            "Failed to add a worktree for {} on {} (is the branch checked out elsewhere?)",
+            task.id, name
+        )
+    })?;
+
+    println!("🌳 Added worktree for {} at {}", task.id, path.display());
+    println!("   Branch: {}", name);
+    record_branch(task, &name)?;
+    println!(
+        "   Remove it with 'taskguard worktree remove {}' once the task is done",
+        task.id
+    );
+    Ok(())
+}
+
+/// Linked worktrees with their tasks; worktrees of done tasks are flagged for removal
+pub fn run_list() -> Result<()> {
+    let tasks = load_all_tasks().context("Failed to load tasks")?;
+    let (_, repo) = open_repo()?;
+
+    let names = repo.worktrees().context("Failed to list worktrees")?;
+    let worktrees: Vec<Worktree> = names
+        .iter()
+        .flatten()
+        .filter_map(|name| repo.find_worktree(name).ok())
+        .collect();
+    if worktrees.is_empty() {
+        println!("🌳 No linked worktrees");
+        println!("   Create one with 'taskguard worktree add <task-id>'");
+        return Ok(());
+    }
+
+    println!("🌳 WORKTREES");
+    let mut done = Vec::new();
+    for worktree in &worktrees {
+        let name = worktree.name().unwrap_or_default();
+        let branch = Repository::open_from_worktree(worktree)
+            .ok()
+            .and_then(|wt_repo| {
+                wt_repo
+                    .head()
+                    .ok()
+                    .and_then(|head| head.shorthand().map(str::to_string))
+            })
+            .unwrap_or_else(|| "unknown branch".to_string());
+        let missing = if worktree.validate().is_err() {
+            " (missing; run 'git worktree prune')"
+        } else {
+            ""
+        };
+
+        match tasks.iter().find(|t| t.id == name) {
+            Some(task) => {
+                println!(
+                    "   {} [{}] {} → {}{}",
+                    task.id,
+                    task.status,
+                    branch,
+                    worktree.path().display(),
+                    missing
+                );
+                if task.status == TaskStatus::Done {
+                    done.push(task.id.as_str());
+                }
+            }
+            None => println!(
+                "   {} (no task) {} → {}{}",
+                name,
+                branch,
+                worktree.path().display(),
+                missing
+            ),
+        }
+    }
+
+    if !done.is_empty() {
+        println!();
+        println!("✅ Done tasks with worktrees: {}", done.join(", "));
+        println!("   Clean them up with 'taskguard worktree remove'");
+    }
+    Ok(())
+}
+
+/// Remove the task's worktree, or without a task ID the worktrees of all done
+/// tasks. Tasks not done and worktrees with uncommitted changes need `force`.
+/// The branch is kept.
+pub fn run_remove(task_id: Option<&str>, force: bool) -> Result<()> {
+    let tasks = load_all_tasks().context("Failed to load tasks")?;
+    let (_, repo) = open_repo()?;
+
+    let Some(task_id) = task_id else {
+        let names = repo.worktrees().context("Failed to list worktrees")?;
+        let done: Vec<&Task> = names
+            .iter()
+            .flatten()
+            .filter_map(|name| tasks.iter().find(|t| t.id == name))
+            .filter(|t| t.status == TaskStatus::Done)
+            .collect();
+        if done.is_empty() {
+            println!("🌳 No worktrees of done tasks to remove");
+            return Ok(());
+        }
+        for task in done {
+            if let Err(e) = remove(&repo, task, force) {
+                println!("   ⏭️  {}: {}", task.id, e);
+            }
+        }
+        return Ok(());
+    };
+
+    let task = find_task(&tasks, task_id)?;
+    if task.status != TaskStatus::Done && !force {
+        return Err(anyhow::anyhow!(
+            "{} is {}, not done; use --force to remove its worktree anyway",
+            task.id,
+            task.status
+        ));
+    }
+    remove(&repo, task, force)
+}
+
+fn remove(repo: &Repository, task: &Task, force: bool) -> Result<()> {
+    let worktree = repo
+        .find_worktree(&task.id)
+        .map_err(|_| anyhow::anyhow!("{} has no worktree", task.id))?;
+
+    if !force && let Ok(wt_repo) = Repository::open_from_worktree(&worktree) {
+        let statuses = wt_repo.statuses(Some(
+            StatusOptions::new()
+                .include_untracked(true)
+                .include_ignored(false),
+        ))?;
+        if !statuses.is_empty() {
+            return Err(anyhow::anyhow!(
+                "worktree has uncommitted changes; commit them or use --force"
+            ));
+        }
+    }
+
+    let path = worktree.path().to_path_buf();
+    worktree
+        .prune(Some(
+            WorktreePruneOptions::new()
+                .valid(true)
+                .locked(force)
+                .working_tree(true),
+        ))
+        .with_context(|| format!("Failed to remove worktree {}", path.display()))?;
+    println!("🗑️  Removed worktree for {} ({})", task.id, path.display());
+    Ok(())
+}
@@ -1,10 +1,15 @@
 use anyhow::{Context, Result};
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
-use crate::task::Task;
+use crate::analysis::Severity;
+use crate::task::{
+    FORMAT_VERSION, FrontmatterFormat, Priority, Task, TaskStatus, describe_yaml_value,
+};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Config {
@@ -12,6 +17,282 @@ pub struct Config {
     pub settings: SettingsConfig,
     pub git: GitConfig,
     pub ai: AiConfig,
+    /// Custom frontmatter fields and their types, e.g. `story_points = "int"`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub fields: BTreeMap<String, FieldType>,
+    /// Default template per area, e.g. `backend = "feature"`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub templates: BTreeMap<String, String>,
+    /// Optional policy flagging tasks that sit in `todo` too long
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aging: Option<AgingConfig>,
+    /// Project-specific `taskguard lint` thresholds; unset values keep the defaults
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lint: Option<LintConfig>,
+    /// Confidence `taskguard sync` needs before suggesting a status
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sync: Option<SyncConfig>,
+}
+
+/// Confidence a status suggestion must exceed unless `[sync]` says otherwise
+pub const DEFAULT_MIN_CONFIDENCE: f32 = 0.5;
+
+/// `[sync]`: how sure commit analysis must be before suggesting a status
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SyncConfig {
+    /// Threshold for every status, from 0.0 to 1.0
+    pub min_confidence: Option<f32>,
+    /// Thresholds per suggested status, e.g. `done = 0.8`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub status_confidence: BTreeMap<String, f32>,
+    /// Which tasks `sync --github` pushes and which issues it pulls
+    #[serde(default, skip_serializing_if = "SyncFilter::is_empty")]
+    pub filter: SyncFilter,
+}
+
+impl SyncConfig {
+    /// Confidence a suggestion of `status` must exceed
+    pub fn threshold(&self, status: &str) -> f32 {
+        self.status_confidence
+            .get(status)
+            .copied()
+            .or(self.min_confidence)
+            .unwrap_or(DEFAULT_MIN_CONFIDENCE)
+    }
+}
+
+/// `[sync.filter]`: the tasks kept in step with GitHub. Empty lists allow
+/// everything; names match case-insensitively.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncFilter {
+    /// Only tasks in these areas
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub areas: Vec<String>,
+    /// Never tasks in these areas, e.g. `personal`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude_areas: Vec<String>,
+    /// Only tasks with these statuses
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub statuses: Vec<String>,
+    /// Only tasks with at least one of these tags
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Never tasks with any of these tags
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude_tags: Vec<String>,
+}
+
+impl SyncFilter {
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// This filter with each list given in `other` replacing its own
+    #[must_use]
+    pub fn overridden_by(mut self, other: Self) -> Self {
+        for (list, replacement) in [
+            (&mut self.areas, other.areas),
+            (&mut self.exclude_areas, other.exclude_areas),
+            (&mut self.statuses, other.statuses),
+            (&mut self.tags, other.tags),
+            (&mut self.exclude_tags, other.exclude_tags),
+        ] {
+            if !replacement.is_empty() {
+                *list = replacement;
+            }
+        }
+        self
+    }
+
+    /// Whether the task is synced
+    pub fn matches(&self, task: &Task) -> bool {
+        self.admits(
+            std::slice::from_ref(&task.area),
+            &task.status.to_string(),
+            &task.tags,
+        )
+    }
+
+    /// Whether an issue no task maps to is pulled: its labels stand in for
+    /// both the area and the tags, and `status` is `done` for closed issues
+    pub fn matches_issue(&self, labels: &[String], status: &str) -> bool {
+        self.admits(labels, status, labels)
+    }
+
+    fn admits(&self, areas: &[String], status: &str, tags: &[String]) -> bool {
+        let any = |list: &[String], names: &[String]| {
+            names
+                .iter()
+                .any(|name| list.iter().any(|l| l.eq_ignore_ascii_case(name)))
+        };
+        (self.areas.is_empty() || any(&self.areas, areas))
+            && !any(&self.exclude_areas, areas)
+            && (self.statuses.is_empty()
+                || self.statuses.iter().any(|s| s.eq_ignore_ascii_case(status)))
+            && (self.tags.is_empty() || any(&self.tags, tags))
+            && !any(&self.exclude_tags, tags)
+    }
+
+    /// One-line description for sync output
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        for (label, list) in [
+            ("areas", &self.areas),
+            ("not areas", &self.exclude_areas),
+            ("statuses", &self.statuses),
+            ("tags", &self.tags),
+            ("not tags", &self.exclude_tags),
+        ] {
+            if !list.is_empty() {
+                parts.push(format!("{} {}", label, list.join(", ")));
+            }
+        }
+        parts.join("; ")
+    }
+}
+
+/// `[lint]`: thresholds and required sections used by `TaskAnalyzer`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LintConfig {
+    pub max_content_length: Option<usize>,
+    pub max_task_items: Option<usize>,
+    pub max_dependencies: Option<usize>,
+    pub max_assignees: Option<usize>,
+    pub max_estimate_overrun: Option<f32>,
+    pub high_complexity_score: Option<f32>,
+    /// Headings every task must have, e.g. `["Context", "Acceptance Criteria"]`.
+    /// When set, they replace the built-in objectives/acceptance criteria check.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub required_sections: Vec<String>,
+    /// Project-defined checks, reported under the `custom` category
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<LintRule>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub naming: Option<NamingConfig>,
+    /// Check titles and bodies for common misspellings (same as `lint --spellcheck`)
+    pub spellcheck: Option<bool>,
+    /// Report severity per rule id or category, e.g. `missing-estimate = "error"`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub severity: BTreeMap<String, Severity>,
+}
+
+/// `[lint.naming]`: title conventions, reported under the `naming` category
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NamingConfig {
+    pub min_title_length: Option<usize>,
+    pub max_title_length: Option<usize>,
+    /// Require titles to start with one of `verbs`
+    #[serde(default)]
+    pub imperative: bool,
+    /// Replaces the built-in imperative verbs
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verbs: Option<Vec<String>>,
+    /// Replaces the built-in vague titles; `[]` turns the check off
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub banned_titles: Option<Vec<String>>,
+}
+
+/// `[[lint.rules]]`: a declarative check run on every task in `areas`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LintRule {
+    pub name: String,
+    /// Areas the rule applies to, nested areas included; empty means all
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub areas: Vec<String>,
+    /// Regex that must not match the task body
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forbid: Option<String>,
+    /// Regex that must match the task body
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require: Option<String>,
+    /// Frontmatter fields (built-in or custom) that must be set
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub required_fields: Vec<String>,
+    /// Replaces the generated message
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
+    #[serde(default)]
+    pub severity: Severity,
+}
+
+/// `[aging]`: flag, and optionally escalate, tasks left in `todo`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgingConfig {
+    /// Days since `created` before a `todo` task counts as aging
+    pub days: u32,
+    /// Raise the displayed priority one level per `days` period, up to `high`
+    #[serde(default)]
+    pub escalate: bool,
+}
+
+impl AgingConfig {
+    /// Days the task has waited in `todo`, if that's past the threshold
+    pub fn age(&self, task: &Task, today: NaiveDate) -> Option<i64> {
+        if task.status != TaskStatus::Todo || self.days == 0 {
+            return None;
+        }
+        let age = (today - task.created.date_naive()).num_days();
+        (age >= i64::from(self.days)).then_some(age)
+    }
+
+    /// Priority after escalation; critical is left for people to decide
+    pub fn effective_priority(&self, task: &Task, today: NaiveDate) -> Priority {
+        let mut priority = task.priority.clone();
+        if let Some(age) = self.age(task, today).filter(|_| self.escalate) {
+            for _ in 0..age / i64::from(self.days) {
+                if matches!(priority, Priority::High | Priority::Critical) {
+                    break;
+                }
+                priority = priority.escalated();
+            }
+        }
+        priority
+    }
+}
+
+/// Value types allowed for custom frontmatter fields
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FieldType {
+    String,
+    Int,
+    Float,
+    Bool,
+    Date,
+    List,
+}
+
+impl FieldType {
+    /// Check whether a YAML value conforms to this field type
+    pub fn matches(self, value: &serde_yaml::Value) -> bool {
+        use serde_yaml::Value;
+
+        match self {
+            FieldType::String => value.is_string(),
+            FieldType::Int => value.is_i64() || value.is_u64(),
+            FieldType::Float => value.is_number(),
+            FieldType::Bool => value.is_bool(),
+            FieldType::Date => value
+                .as_str()
+                .is_some_and(|s| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").is_ok()),
+            FieldType::List => matches!(value, Value::Sequence(_)),
+        }
+    }
+}
+
+impl std::fmt::Display for FieldType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FieldType::String => write!(f, "string"),
+            FieldType::Int => write!(f, "int"),
+            FieldType::Float => write!(f, "float"),
+            FieldType::Bool => write!(f, "bool"),
+            FieldType::Date => write!(f, "date"),
+            FieldType::List => write!(f, "list"),
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -19,6 +300,9 @@ pub struct ProjectConfig {
     pub name: String,
     pub version: String,
     pub areas: Vec<String>,
+    /// Task file schema version of this project; missing in projects created before versioning
+    #[serde(default)]
+    pub format_version: u32,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -27,13 +311,87 @@ pub struct SettingsConfig {
     pub priorities: Vec<String>,
     pub complexity_scale: String,
     pub default_estimate_unit: String,
+    /// Offer to move a task to review/done when its last checklist item is checked
+    #[serde(default)]
+    pub checklist_complete_prompt: bool,
+    /// Front-matter syntax for new task files: "yaml" (`---`) or "toml" (`+++`)
+    #[serde(default)]
+    pub frontmatter: FrontmatterFormat,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
+#[allow(clippy::struct_excessive_bools)] // independent switches from config.toml
 pub struct GitConfig {
     pub auto_add_tasks: bool,
     pub auto_commit_on_status_change: bool,
     pub commit_message_template: String,
+    /// Conventional commit scopes that stand for an area, e.g. `login = "auth"`.
+    /// Area names are recognized as scopes without an entry
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub scopes: BTreeMap<String, String>,
+    /// Path globs that stand for an area, e.g. `"services/api/**" = "backend"`,
+    /// for commits that name neither a task nor an area
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub paths: BTreeMap<String, String>,
+    /// What the commit-msg hook does when a message names no known task
+    #[serde(default)]
+    pub commit_msg_check: CommitMsgCheck,
+    /// Name for branches `taskguard branch` creates; `{area}`, `{id}` and
+    /// `{slug}` (the title in lowercase words joined by `-`) are filled in
+    #[serde(default = "default_branch_pattern")]
+    pub branch_pattern: String,
+    /// Directory for `taskguard worktree add`, relative to the project root;
+    /// `{project}` is the project directory's name
+    #[serde(default = "default_worktree_dir")]
+    pub worktree_dir: String,
+    /// Commit task files after `update`, `task update` and `import-md` change them
+    #[serde(default)]
+    pub auto_commit: bool,
+    /// Also read the history of checked-out submodules in `sync`
+    #[serde(default)]
+    pub submodules: bool,
+    /// Orphan branch holding the task files; `tasks/` is then an ignored
+    /// working copy of it (see `taskguard init --tasks-branch`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tasks_branch: Option<String>,
+}
+
+fn default_branch_pattern() -> String {
+    "{area}/{id}-{slug}".to_string()
+}
+
+fn default_worktree_dir() -> String {
+    "../{project}-worktrees".to_string()
+}
+
+/// `[git] commit_msg_check`, read by the hook `taskguard hooks install` adds
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CommitMsgCheck {
+    /// Accept every message
+    Off,
+    /// Print a warning and commit anyway
+    #[default]
+    Warn,
+    /// Reject the commit
+    Error,
+}
+
+impl GitConfig {
+    /// Lowercased scope → area, for `areas` plus the `scopes` table
+    pub fn scope_areas<'a>(
+        &self,
+        areas: impl IntoIterator<Item = &'a String>,
+    ) -> HashMap<String, String> {
+        let mut map: HashMap<String, String> = areas
+            .into_iter()
+            .map(|area| (area.to_lowercase(), area.clone()))
+            .collect();
+        for (scope, area) in &self.scopes {
+            map.insert(scope.to_lowercase(), area.clone());
+        }
+        map
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -59,6 +417,7 @@ impl Default for Config {
                     "testing".to_string(),
                     "deployment".to_string(),
                 ],
+                format_version: FORMAT_VERSION,
             },
             settings: SettingsConfig {
                 statuses: vec![
@@ -76,11 +435,21 @@ impl Default for Config {
                 ],
                 complexity_scale: "1-10".to_string(),
                 default_estimate_unit: "hours".to_string(),
+                checklist_complete_prompt: false,
+                frontmatter: FrontmatterFormat::Yaml,
             },
             git: GitConfig {
                 auto_add_tasks: true,
                 auto_commit_on_status_change: false,
                 commit_message_template: "Task {{id}}: {{action}} - {{title}}".to_string(),
+                scopes: BTreeMap::new(),
+                paths: BTreeMap::new(),
+                commit_msg_check: CommitMsgCheck::Warn,
+                branch_pattern: default_branch_pattern(),
+                worktree_dir: default_worktree_dir(),
+                auto_commit: false,
+                submodules: false,
+                tasks_branch: None,
             },
             ai: AiConfig {
                 enabled: true,
@@ -88,6 +457,11 @@ impl Default for Config {
                 auto_suggestions: true,
                 complexity_analysis: true,
             },
+            fields: BTreeMap::new(),
+            templates: BTreeMap::new(),
+            aging: None,
+            lint: None,
+            sync: None,
         }
     }
 }
@@ -122,6 +496,41 @@ impl Config {
     }
 }
 
+/// Problem found when checking custom fields against the schema
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldIssue {
+    /// Field present in the task but not declared under `[fields]`
+    Unknown(String),
+    /// Value does not match the declared type
+    TypeMismatch(String),
+}
+
+/// Check a task's custom frontmatter fields against the configured schema
+pub fn check_custom_fields(task: &Task, schema: &BTreeMap<String, FieldType>) -> Vec<FieldIssue> {
+    let mut issues = Vec::new();
+
+    for (key, value) in &task.extra {
+        match schema.get(key) {
+            Some(field_type) if !field_type.matches(value) => {
+                issues.push(FieldIssue::TypeMismatch(format!(
+                    "{}: field '{}' should be {} but found {}",
+                    task.id,
+                    key,
+                    field_type,
+                    describe_yaml_value(value)
+                )));
+            }
+            Some(_) => {}
+            None => issues.push(FieldIssue::Unknown(format!(
+                "{}: unknown field '{}' (declare it under [fields] in .taskguard/config.toml)",
+                task.id, key
+            ))),
+        }
+    }
+
+    issues
+}
+
 pub fn find_taskguard_root() -> Option<PathBuf> {
     let mut current = std::env::current_dir().ok()?;
 
@@ -151,6 +560,14 @@ pub fn get_config_path() -> Result<PathBuf> {
     Ok(root.join(".taskguard").join("config.toml"))
 }
 
+/// Front-matter format configured for the current project (YAML outside a project)
+pub fn frontmatter_format() -> FrontmatterFormat {
+    get_config_path()
+        .and_then(Config::load_or_default)
+        .map(|config| config.settings.frontmatter)
+        .unwrap_or_default()
+}
+
 pub fn get_archive_dir() -> Result<PathBuf> {
     let root =
         find_taskguard_root().context("Not in a TaskGuard project. Run 'taskguard init' first.")?;
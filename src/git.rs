@@ -1,22 +1,460 @@
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
-use git2::{CertificateCheckStatus, Commit, FetchOptions, RemoteCallbacks, Repository};
-use std::collections::HashMap;
-use std::path::Path;
+use git2::{
+    BranchType, CertificateCheckStatus, Commit, Cred, CredentialType, Diff, DiffFormat,
+    FetchOptions, Oid, Patch, Reference, RemoteCallbacks, Repository, Status, StatusOptions,
+};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::LazyLock;
+
+use crate::config::{Config, get_config_path};
+use crate::git_cache::GitCache;
+use crate::github::client::{TOKEN_VARS, env_token};
+use crate::task::{Task, TaskStatus};
+
+/// Ways `fetch_remote` authenticates, in the order they are tried
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum AuthMethod {
+    /// Keys loaded in `ssh-agent`
+    SshAgent,
+    /// `credential.helper` from git config, as `git fetch` uses it
+    CredentialHelper,
+    /// A token from one of [`TOKEN_VARS`] as the HTTPS password, for GitHub
+    /// remotes only; see [`github_https_url`]
+    Token,
+    /// The program in `GIT_ASKPASS`, asked for username and password
+    Askpass,
+    /// Integrated Windows authentication (NTLM, Negotiate)
+    Default,
+}
+
+impl AuthMethod {
+    const ALL: [AuthMethod; 5] = [
+        AuthMethod::SshAgent,
+        AuthMethod::CredentialHelper,
+        AuthMethod::Token,
+        AuthMethod::Askpass,
+        AuthMethod::Default,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            AuthMethod::SshAgent => "ssh-agent",
+            AuthMethod::CredentialHelper => "git credential helper",
+            AuthMethod::Token => "GitHub token",
+            AuthMethod::Askpass => "GIT_ASKPASS",
+            AuthMethod::Default => "system default credentials",
+        }
+    }
+
+    fn applies_to(self, allowed: CredentialType) -> bool {
+        match self {
+            AuthMethod::SshAgent => allowed.contains(CredentialType::SSH_KEY),
+            AuthMethod::CredentialHelper | AuthMethod::Token | AuthMethod::Askpass => {
+                allowed.contains(CredentialType::USER_PASS_PLAINTEXT)
+            }
+            AuthMethod::Default => allowed.contains(CredentialType::DEFAULT),
+        }
+    }
+
+    /// Credentials from this method, if it has any to offer
+    fn credentials(self, config: &git2::Config, url: &str, username: Option<&str>) -> Option<Cred> {
+        match self {
+            AuthMethod::SshAgent => Cred::ssh_key_from_agent(username.unwrap_or("git")).ok(),
+            AuthMethod::CredentialHelper => Cred::credential_helper(config, url, username).ok(),
+            AuthMethod::Token => {
+                if !github_https_url(url) {
+                    return None;
+                }
+                let token = env_token()?;
+                Cred::userpass_plaintext(username.unwrap_or("x-access-token"), &token).ok()
+            }
+            AuthMethod::Askpass => {
+                let program = std::env::var("GIT_ASKPASS")
+                    .ok()
+                    .filter(|p| !p.is_empty())?;
+                let ask = |prompt: String| {
+                    Command::new(&program)
+                        .arg(prompt)
+                        .output()
+                        .ok()
+                        .filter(|output| output.status.success())
+                        .and_then(|output| String::from_utf8(output.stdout).ok())
+                        .map(|answer| answer.trim_end_matches(['\r', '\n']).to_string())
+                };
+                let username = match username {
+                    Some(username) => username.to_string(),
+                    None => ask(format!("Username for '{}': ", url))?,
+                };
+                let password = ask(format!("Password for '{}': ", url))?;
+                Cred::userpass_plaintext(&username, &password).ok()
+            }
+            AuthMethod::Default => Cred::default().ok(),
+        }
+    }
+}
+
+/// Whether `url` is an `https://` URL on github.com, or on the GitHub
+/// Enterprise host named by `GH_HOST`, the only places a token is sent
+fn github_https_url(url: &str) -> bool {
+    let Some(rest) = url.strip_prefix("https://") else {
+        return false;
+    };
+    let authority = rest.split('/').next().unwrap_or_default();
+    let host_port = authority.rsplit('@').next().unwrap_or_default();
+    let host = host_port.split(':').next().unwrap_or_default();
+    let enterprise = std::env::var("GH_HOST").unwrap_or_default();
+    host.eq_ignore_ascii_case("github.com")
+        || (!enterprise.trim().is_empty() && host.eq_ignore_ascii_case(enterprise.trim()))
+}
 
 /// Git repository analysis for TaskGuard intelligence features
 pub struct GitAnalyzer {
     repo: Repository,
+    /// Lowercased conventional commit scope → area
+    scope_areas: HashMap<String, String>,
+    /// Path glob → area, most specific pattern first
+    path_areas: Vec<(Regex, String)>,
+    /// Checked-out submodules by path, see [`Self::with_submodules`]
+    submodules: Vec<(String, Repository)>,
+    /// Parsed commits from earlier runs, see [`Self::with_cache`]
+    cache: Option<RefCell<GitCache>>,
+    /// Task ID → creation time, see [`Self::with_task_anchors`]
+    anchors: HashMap<String, DateTime<Utc>>,
 }
 
 /// Represents a commit that potentially relates to a task
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskCommit {
     pub oid: String,
     pub message: String,
     pub author: String,
     pub timestamp: DateTime<Utc>,
     pub task_ids: Vec<String>,
+    /// Path of the submodule the commit was made in; `None` for the top-level repository
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub submodule: Option<String>,
+}
+
+/// How a commit message names a task
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReferenceKind {
+    /// A `Task-Id: backend-001` trailer
+    Trailer,
+    /// A conventional commit scope, `feat(backend-001): ...`
+    Scope,
+    /// An `area-NNN` ID in the message text
+    TaskId,
+    /// The task branch the commit was made on, `task/backend-001-login`
+    Branch,
+    /// A generic `#123` or `task 123` reference
+    Issue,
+}
+
+impl ReferenceKind {
+    /// Weight applied to status suggestions drawn from commits referenced this way
+    pub fn confidence(self) -> f32 {
+        match self {
+            ReferenceKind::Trailer | ReferenceKind::Scope => 1.0,
+            ReferenceKind::TaskId => 0.9,
+            ReferenceKind::Branch => 0.8,
+            ReferenceKind::Issue => 0.75,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskReference {
+    pub task_id: String,
+    pub kind: ReferenceKind,
+}
+
+/// Trailer key naming the task a commit belongs to
+pub const TASK_ID_TRAILER: &str = "Task-Id";
+
+/// `Task-Id:` values from the trailer block (the message's last paragraph).
+/// Several IDs may share a line, separated by commas.
+pub fn trailer_task_ids(message: &str) -> Vec<String> {
+    let Some(block) = message.trim_end().rsplit("\n\n").next() else {
+        return Vec::new();
+    };
+    let valid = |id: &&str| {
+        !id.is_empty()
+            && id.len() <= 64
+            && id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    };
+    block
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .filter(|(key, _)| key.trim().eq_ignore_ascii_case(TASK_ID_TRAILER))
+        .flat_map(|(_, value)| value.split(',').map(str::trim))
+        .filter(valid)
+        .map(str::to_string)
+        .collect()
+}
+
+/// Whether `task_id` appears in `text` as a whole word, for IDs of any shape
+pub fn mentions_task_id(text: &str, task_id: &str) -> bool {
+    let is_id_char = |c: char| c.is_alphanumeric() || c == '-' || c == '_';
+    text.match_indices(task_id).any(|(start, _)| {
+        let before = text[..start].chars().next_back();
+        let after = text[start + task_id.len()..].chars().next();
+        !before.is_some_and(is_id_char) && !after.is_some_and(is_id_char)
+    })
+}
+
+/// Names from `Co-authored-by: Name <email>` trailers, as GitHub writes them
+/// for pair-programmed and suggested-change commits
+pub fn co_authors(message: &str) -> Vec<String> {
+    let Some(block) = message.trim_end().rsplit("\n\n").next() else {
+        return Vec::new();
+    };
+    block
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .filter(|(key, _)| key.trim().eq_ignore_ascii_case("Co-authored-by"))
+        .filter_map(|(_, value)| {
+            let value = value.trim();
+            let name = match value.split_once('<') {
+                Some((name, email)) if name.trim().is_empty() => email.trim_end_matches('>'),
+                Some((name, _)) => name,
+                None => value,
+            };
+            let name = name.trim();
+            (!name.is_empty()).then(|| name.to_string())
+        })
+        .collect()
+}
+
+/// Trailer lines for a generated commit message, one per task
+pub fn task_trailers(task_ids: &[String]) -> String {
+    task_ids
+        .iter()
+        .map(|id| format!("{}: {}", TASK_ID_TRAILER, id))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Commit exactly `paths`, staging deleted ones as removals, with `subject` and
+/// `Task-Id` trailers for `task_ids`. Anything else already staged stays staged
+/// and out of the commit.
+pub fn commit_task_files(paths: &[PathBuf], subject: &str, task_ids: &[String]) -> Result<Oid> {
+    let first = paths.first().context("No files to commit")?;
+    let repo = Repository::discover(first.parent().unwrap_or(first))
+        .context("Not in a Git repository. Run 'git init' first.")?;
+    let workdir = repo
+        .workdir()
+        .context("Cannot commit in a bare repository")?
+        .to_path_buf();
+
+    let mut index = repo.index().context("Failed to get repository index")?;
+    let head = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+    // The commit's tree is HEAD's with only these files changed
+    let mut tree_index = git2::Index::new()?;
+    if let Some(head) = &head {
+        tree_index.read_tree(&head.tree()?)?;
+    }
+    for path in paths {
+        let relative = repo_relative(&workdir, path)?;
+        if path.exists() {
+            index.add_path(&relative)?;
+            let entry = index
+                .get_path(&relative, 0)
+                .with_context(|| format!("Failed to stage {}", relative.display()))?;
+            tree_index.add(&entry)?;
+        } else {
+            // Removing a path the index doesn't know about is fine
+            let _ = index.remove_path(&relative);
+            let _ = tree_index.remove_path(&relative);
+        }
+    }
+    index.write().context("Failed to write index")?;
+
+    let tree = repo.find_tree(tree_index.write_tree_to(&repo)?)?;
+    let signature = repo.signature().context("Failed to get Git signature")?;
+    let message = format!("{}\n\n{}", subject, task_trailers(task_ids));
+    let parents: Vec<&Commit> = head.iter().collect();
+    repo.commit(
+        Some("HEAD"),
+        &signature,
+        &signature,
+        &message,
+        &tree,
+        &parents,
+    )
+    .context("Failed to create commit")
+}
+
+/// `path` relative to the work tree, also when one of them goes through a
+/// symlink; a deleted file's directory still exists
+fn repo_relative(workdir: &Path, path: &Path) -> Result<PathBuf> {
+    if let Ok(relative) = path.strip_prefix(workdir) {
+        return Ok(relative.to_path_buf());
+    }
+    let name = path.file_name().context("Not a file path")?;
+    let dir = path.parent().unwrap_or(Path::new(".")).canonicalize()?;
+    dir.join(name)
+        .strip_prefix(workdir.canonicalize()?)
+        .map(Path::to_path_buf)
+        .with_context(|| format!("{} is outside the repository", path.display()))
+}
+
+/// With `[git] auto_commit`, commit the task files a command just changed as
+/// `taskguard: <action>`. The change already happened, so failing to commit
+/// only warns.
+pub fn auto_commit(paths: &[PathBuf], action: &str, task_ids: &[String]) {
+    let enabled = get_config_path()
+        .and_then(Config::load_or_default)
+        // On a tasks branch, every change is committed there anyway
+        .is_ok_and(|config| config.git.auto_commit && config.git.tasks_branch.is_none());
+    if !enabled || paths.is_empty() {
+        return;
+    }
+
+    let subject = format!("taskguard: {}", action);
+    match commit_task_files(paths, &subject, task_ids) {
+        Ok(_) => println!("📝 Committed: {}", subject),
+        Err(e) => eprintln!("⚠️  Warning: Failed to auto-commit: {:#}", e),
+    }
+}
+
+/// `type(scope)!:` at the start of a conventional commit subject; compiled once
+/// since every analyzed commit is checked
+static CONVENTIONAL_SUBJECT: LazyLock<Option<Regex>> =
+    LazyLock::new(|| Regex::new(r"^\s*[a-zA-Z]{1,20}\(([^()]{1,100})\)!?:").ok());
+
+/// Generated merge commit subjects, capturing the merged branch
+static MERGE_SUBJECT: LazyLock<Option<Regex>> = LazyLock::new(|| {
+    Regex::new(
+        r"^(?:Merge (?:(remote-tracking )?branch '([^']{1,200})'|pull request #\d{1,9} from ([^\s/]{1,100})/(\S{1,200}))|Merged in (\S{1,200}))",
+    )
+    .ok()
+});
+
+/// Scopes of a conventional commit subject, `type(scope)!: description`.
+/// Several scopes may be given, separated by commas.
+pub fn conventional_scopes(message: &str) -> Vec<String> {
+    let Some(subject) = message.lines().next() else {
+        return Vec::new();
+    };
+    let Some(pattern) = CONVENTIONAL_SUBJECT.as_ref() else {
+        return Vec::new();
+    };
+    pattern
+        .captures(subject)
+        .and_then(|cap| cap.get(1))
+        .map(|scopes| {
+            scopes
+                .as_str()
+                .split(',')
+                .map(str::trim)
+                .filter(|scope| !scope.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Branches that other work is merged into; merging one of them into a task
+/// branch only brings that branch up to date
+const TRUNK_BRANCHES: [&str; 6] = ["main", "master", "develop", "development", "dev", "trunk"];
+
+/// Branch a merge commit merged, from the messages Git, GitHub, GitLab and
+/// Bitbucket generate: `Merge branch 'x'`, `Merge remote-tracking branch
+/// 'origin/x'`, `Merge pull request #1 from owner/x` and `Merged in x`
+pub fn merged_branch(message: &str) -> Option<String> {
+    let subject = message.lines().next()?.trim();
+    let cap = MERGE_SUBJECT.as_ref()?.captures(subject)?;
+    let branch = if let Some(branch) = cap.get(2) {
+        let branch = branch.as_str();
+        // Drop the remote name from remote-tracking branches
+        match (cap.get(1), branch.split_once('/')) {
+            (Some(_), Some((_, rest))) => rest,
+            _ => branch,
+        }
+    } else {
+        cap.get(4).or_else(|| cap.get(5))?.as_str()
+    };
+    Some(branch.to_string())
+}
+
+/// Whether a commit merges finished work: a merge of any branch but a trunk
+pub fn is_completion_merge(message: &str) -> bool {
+    merged_branch(message).is_some_and(|branch| !TRUNK_BRANCHES.contains(&branch.as_str()))
+}
+
+/// Commit named in a `This reverts commit <oid>.` line, as `git revert` writes
+/// it; the OID may be abbreviated
+pub fn reverted_commit(message: &str) -> Option<&str> {
+    message.lines().find_map(|line| {
+        let oid = line
+            .trim()
+            .strip_prefix("This reverts commit ")?
+            .trim_end_matches(['.', ',']);
+        (oid.len() >= 7 && oid.len() <= 40 && oid.chars().all(|c| c.is_ascii_hexdigit()))
+            .then_some(oid)
+    })
+}
+
+/// How a revert was matched to the commit it undoes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RevertMatch {
+    /// The revert's message names the commit
+    Message,
+    /// The revert's changes are the commit's, inverted (same patch ID)
+    PatchId,
+}
+
+/// A commit undoing one of a task's commits
+#[derive(Debug, Clone)]
+pub struct TaskRevert {
+    /// The reverting commit
+    pub commit: TaskCommit,
+    /// The task commit it undoes
+    pub reverted: String,
+    pub matched: RevertMatch,
+}
+
+/// Commits whose conventional commit scope names an area but no task
+#[derive(Debug)]
+pub struct AreaActivity {
+    pub area: String,
+    pub commits: Vec<TaskCommit>,
+    pub last_activity: Option<DateTime<Utc>>,
+}
+
+/// Tasks referenced by the commits on one local branch
+#[derive(Debug)]
+pub struct BranchActivity {
+    pub branch: String,
+    /// Commits counted for the branch
+    pub commits: usize,
+    /// Task ID → commits referencing it
+    pub tasks: BTreeMap<String, usize>,
+}
+
+struct LocalBranch {
+    name: String,
+    tip: Oid,
+    task_ids: Vec<String>,
+}
+
+/// A task branch that no longer exists but whose last tip is on a trunk branch
+struct MergedBranch {
+    name: String,
+    tip: Oid,
+    /// Where the branch was created, when the reflog still says
+    base: Option<Oid>,
+    task_ids: Vec<String>,
 }
 
 /// Analysis results for task activity
@@ -27,12 +465,125 @@ pub struct TaskActivity {
     pub last_activity: Option<DateTime<Utc>>,
     pub suggested_status: Option<String>,
     pub confidence: f32,
+    /// Deleted task branch whose merge marks the task as done
+    pub merged_branch: Option<String>,
+    /// Source changes made by the task's commits
+    pub diff_stats: DiffStats,
+}
+
+/// A source file with uncommitted changes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkingChange {
+    pub path: String,
+    /// Changes to the file are in the index
+    pub staged: bool,
+}
+
+/// Lines and source files changed by a set of commits; merge commits and
+/// task files are left out
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiffStats {
+    pub files_changed: usize,
+    pub insertions: usize,
+    pub deletions: usize,
+}
+
+impl fmt::Display for DiffStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "+{} -{} in {} file{}",
+            self.insertions,
+            self.deletions,
+            self.files_changed,
+            if self.files_changed == 1 { "" } else { "s" }
+        )
+    }
+}
+
+/// Regex for a path glob: `*` and `?` stay within a directory, `**` spans any
+/// number of them, and a trailing `/` matches everything below
+pub fn glob_regex(pattern: &str) -> Result<Regex> {
+    let pattern = match pattern.strip_suffix('/') {
+        Some(dir) => format!("{}/**", dir),
+        None => pattern.to_string(),
+    };
+    let mut regex = String::from("^");
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    regex.push_str("(?:.*/)?");
+                } else {
+                    regex.push_str(".*");
+                }
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex.push('$');
+    Regex::new(&regex).with_context(|| format!("Invalid path pattern '{}'", pattern))
+}
+
+/// Whether a path holds project code rather than task files or `.taskguard` state
+pub fn is_source_path(path: &str) -> bool {
+    !path.starts_with("tasks/") && !path.starts_with(".taskguard/")
+}
+
+impl TaskCommit {
+    /// The commit's author followed by its co-authors, each named once
+    pub fn authors(&self) -> Vec<String> {
+        let mut authors = vec![self.author.clone()];
+        for co_author in co_authors(&self.message) {
+            if !authors.contains(&co_author) {
+                authors.push(co_author);
+            }
+        }
+        authors
+    }
+}
+
+impl TaskActivity {
+    /// People credited, as author or co-author, on more than half of the
+    /// task's commits, with their commit counts, once there are at least two.
+    /// A pair-programmed task has two. Merge commits are left out: whoever
+    /// merges isn't necessarily whoever did the work.
+    pub fn dominant_authors(&self) -> Vec<(String, usize)> {
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+        let mut total = 0;
+        for commit in &self.commits {
+            if merged_branch(&commit.message).is_some() {
+                continue;
+            }
+            for author in commit.authors() {
+                *counts.entry(author).or_default() += 1;
+            }
+            total += 1;
+        }
+        if total < 2 {
+            return Vec::new();
+        }
+
+        let mut authors: Vec<(String, usize)> = counts
+            .into_iter()
+            .filter(|(author, count)| count * 2 > total && author != "Unknown")
+            .collect();
+        authors.sort_by_key(|a| Reverse(a.1));
+        authors
+    }
 }
 
 /// Represents sync conflict between local and remote task states
 #[derive(Debug)]
 pub struct SyncConflict {
     pub task_id: String,
+    /// Remote branch the remote suggestion comes from, e.g. `origin/main`
+    pub remote: String,
     pub local_status: String,
     pub remote_suggested_status: String,
     pub local_confidence: f32,
@@ -40,6 +591,16 @@ pub struct SyncConflict {
     pub resolution: ConflictResolution,
 }
 
+/// A committed version of a task file
+#[derive(Debug, Clone)]
+pub struct TaskRevision {
+    pub oid: String,
+    pub author: String,
+    pub timestamp: DateTime<Utc>,
+    pub message: String,
+    pub task: Task,
+}
+
 /// Conflict resolution strategies
 #[derive(Debug, PartialEq)]
 pub enum ConflictResolution {
@@ -98,18 +659,269 @@ impl GitAnalyzer {
 
         let repo = Repository::open(&canonical_path).context("Failed to open Git repository")?;
 
-        Ok(GitAnalyzer { repo })
+        Ok(GitAnalyzer {
+            repo,
+            scope_areas: HashMap::new(),
+            path_areas: Vec::new(),
+            submodules: Vec::new(),
+            cache: None,
+            anchors: HashMap::new(),
+        })
+    }
+
+    /// Reuse commits parsed in earlier runs for local and remote task
+    /// activity; write them back with [`Self::save_cache`]
+    #[must_use]
+    pub fn with_cache(mut self, cache: GitCache) -> Self {
+        self.cache = Some(RefCell::new(cache));
+        self
+    }
+
+    /// Write the attached cache, if any
+    pub fn save_cache(&self, root: &Path) -> Result<()> {
+        match &self.cache {
+            Some(cache) => cache.borrow_mut().save(root),
+            None => Ok(()),
+        }
+    }
+
+    /// Commits served from the attached cache so far
+    pub fn cache_hits(&self) -> Option<usize> {
+        self.cache.as_ref().map(|cache| cache.borrow().hits)
+    }
+
+    /// Recognize these conventional commit scopes (lowercased) as areas
+    #[must_use]
+    pub fn with_scope_areas(mut self, scope_areas: HashMap<String, String>) -> Self {
+        self.scope_areas = scope_areas;
+        self
+    }
+
+    /// Attribute commits to areas by the paths they change, from `[git.paths]`
+    pub fn with_path_areas(mut self, paths: &BTreeMap<String, String>) -> Result<Self> {
+        let mut patterns: Vec<(&String, &String)> = paths.iter().collect();
+        patterns.sort_by_key(|p| Reverse(p.0.len()));
+        self.path_areas = patterns
+            .into_iter()
+            .map(|(pattern, area)| Ok((glob_regex(pattern)?, area.clone())))
+            .collect::<Result<_>>()?;
+        Ok(self)
+    }
+
+    /// Also analyze the history of submodules, nested ones included. Submodules
+    /// that aren't checked out are skipped.
+    pub fn with_submodules(mut self) -> Result<Self> {
+        let mut submodules = Vec::new();
+        collect_submodules(&self.repo, "", &mut submodules)?;
+        self.submodules = submodules;
+        Ok(self)
+    }
+
+    /// Repository a task commit was made in
+    fn commit_repo(&self, commit: &TaskCommit) -> &Repository {
+        commit
+            .submodule
+            .as_ref()
+            .and_then(|path| self.submodules.iter().find(|(p, _)| p == path))
+            .map_or(&self.repo, |(_, repo)| repo)
+    }
+
+    /// Task commits among the last `limit` of each submodule
+    fn submodule_task_commits(&self, limit: usize) -> Result<Vec<TaskCommit>> {
+        let mut task_commits = Vec::new();
+        for (path, repo) in &self.submodules {
+            for mut commit in self.parse_task_commits(recent_commits(repo, limit)?)? {
+                commit.submodule = Some(path.clone());
+                task_commits.push(commit);
+            }
+        }
+        Ok(task_commits)
+    }
+
+    /// Scan back to the oldest task's creation instead of a fixed number of
+    /// commits, and ignore commits that predate the task they mention
+    #[must_use]
+    pub fn with_task_anchors<'a>(mut self, tasks: impl IntoIterator<Item = &'a Task>) -> Self {
+        self.anchors = tasks
+            .into_iter()
+            .map(|t| (t.id.clone(), t.created))
+            .collect();
+        self
+    }
+
+    /// Number of commits to walk from `tip`: all commits since the oldest
+    /// anchored task when anchors are set, `limit` otherwise
+    fn anchored_limit(&self, tip: Option<Oid>, limit: usize) -> Result<usize> {
+        const MAX_COMMITS: usize = 1000; // Maximum commits to process for security
+
+        let (Some(tip), Some(since)) = (tip, self.anchors.values().min()) else {
+            return Ok(limit);
+        };
+        let mut revwalk = self
+            .repo
+            .revwalk()
+            .context("Failed to create revision walker")?;
+        revwalk.push(tip)?;
+
+        let mut count = 0;
+        for oid in revwalk.take(MAX_COMMITS) {
+            let commit = self.repo.find_commit(oid?)?;
+            if commit.time().seconds() < since.timestamp() {
+                break;
+            }
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Whether a commit was made before the anchored task it mentions was created
+    fn predates_task(&self, task_id: &str, commit: &TaskCommit) -> bool {
+        self.anchors
+            .get(task_id)
+            .is_some_and(|created| commit.timestamp < *created)
+    }
+
+    /// Area named by a commit's conventional commit scope, if any
+    pub fn scope_area(&self, message: &str) -> Option<&str> {
+        conventional_scopes(message)
+            .iter()
+            .find_map(|scope| self.scope_areas.get(&scope.to_lowercase()))
+            .map(String::as_str)
+    }
+
+    /// Area of the most specific `[git.paths]` glob matching `path`, if any
+    pub fn path_area(&self, path: &str) -> Option<&str> {
+        self.path_areas
+            .iter()
+            .find(|(glob, _)| glob.is_match(path))
+            .map(|(_, area)| area.as_str())
+    }
+
+    /// Areas whose `[git.paths]` globs match files a commit changed. Merges
+    /// are skipped since they repeat their branch's changes.
+    fn changed_path_areas(&self, commit: &Commit) -> Result<BTreeSet<&str>> {
+        let mut areas = BTreeSet::new();
+        if self.path_areas.is_empty() || commit.parent_count() > 1 {
+            return Ok(areas);
+        }
+        for delta in self.commit_diff(commit)?.deltas() {
+            if let Some(path) = delta.new_file().path().or_else(|| delta.old_file().path())
+                && let Some(area) = self.path_area(&path.to_string_lossy())
+            {
+                areas.insert(area);
+            }
+        }
+        Ok(areas)
+    }
+
+    /// Recent commits that reference no task but whose scope names an area,
+    /// or failing that whose changed paths fall under `[git.paths]`; most
+    /// recently active area first
+    pub fn analyze_area_activity(&self, limit: Option<usize>) -> Result<Vec<AreaActivity>> {
+        if self.scope_areas.is_empty() && self.path_areas.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut area_groups: HashMap<String, Vec<TaskCommit>> = HashMap::new();
+        for commit in self.get_recent_commits(limit.unwrap_or(100))? {
+            let message = commit.message().unwrap_or("");
+            if !self.extract_task_ids(message).is_empty() {
+                continue;
+            }
+            let areas = match self.scope_area(message) {
+                Some(area) => BTreeSet::from([area]),
+                None => self.changed_path_areas(&commit)?,
+            };
+            for area in areas {
+                area_groups
+                    .entry(area.to_string())
+                    .or_default()
+                    .push(Self::task_commit(&commit, Vec::new()));
+            }
+        }
+
+        let mut activities: Vec<AreaActivity> = area_groups
+            .into_iter()
+            .map(|(area, commits)| AreaActivity {
+                last_activity: commits.iter().map(|c| c.timestamp).max(),
+                area,
+                commits,
+            })
+            .collect();
+        activities.sort_by(|a, b| {
+            b.last_activity
+                .cmp(&a.last_activity)
+                .then(a.area.cmp(&b.area))
+        });
+
+        Ok(activities)
     }
 
     /// Analyze recent commits for task-related activity
     pub fn analyze_task_activity(&self, limit: Option<usize>) -> Result<Vec<TaskActivity>> {
-        let commits = self.get_recent_commits(limit.unwrap_or(100))?;
-        let task_commits = self.parse_task_commits(commits)?;
+        let head = self.repo.head().ok();
+        let limit = self.anchored_limit(
+            head.as_ref().and_then(Reference::target),
+            limit.unwrap_or(100),
+        )?;
+        let mut task_commits = match (&self.cache, head.as_ref().and_then(Reference::target)) {
+            (Some(cache), Some(tip)) => {
+                let name = head.as_ref().and_then(|h| h.name()).unwrap_or("HEAD");
+                self.cached_task_commits(cache, name, tip, limit)?
+            }
+            _ => self.parse_task_commits(self.get_recent_commits(limit)?)?,
+        };
+
+        // Commits on task branches count for the branch's task even when their
+        // messages don't name it
+        for branch_commit in self.branch_task_commits(limit)? {
+            match task_commits.iter_mut().find(|c| c.oid == branch_commit.oid) {
+                Some(existing) => {
+                    for task_id in branch_commit.task_ids {
+                        if !existing.task_ids.contains(&task_id) {
+                            existing.task_ids.push(task_id);
+                        }
+                    }
+                }
+                None => task_commits.push(branch_commit),
+            }
+        }
+
+        // Task branches merged and deleted since: their commits count for the
+        // task, and the merge marks it done
+        let mut merged_branches: HashMap<String, String> = HashMap::new();
+        for branch in self.merged_task_branches()? {
+            let hidden: Vec<Oid> = branch.base.into_iter().collect();
+            let limit = if branch.base.is_some() { limit } else { 1 };
+            for commit in self.branch_commits(branch.tip, &hidden, limit)? {
+                let oid = commit.id().to_string();
+                match task_commits.iter_mut().find(|c| c.oid == oid) {
+                    Some(existing) => {
+                        for task_id in &branch.task_ids {
+                            if !existing.task_ids.contains(task_id) {
+                                existing.task_ids.push(task_id.clone());
+                            }
+                        }
+                    }
+                    None => task_commits.push(Self::task_commit(&commit, branch.task_ids.clone())),
+                }
+            }
+            for task_id in branch.task_ids {
+                merged_branches
+                    .entry(task_id)
+                    .or_insert_with(|| branch.name.clone());
+            }
+        }
+        task_commits.extend(self.submodule_task_commits(limit)?);
+        task_commits.sort_by_key(|c| Reverse(c.timestamp));
 
         // Group commits by task ID
         let mut task_groups: HashMap<String, Vec<TaskCommit>> = HashMap::new();
         for commit in task_commits {
             for task_id in &commit.task_ids {
+                if self.predates_task(task_id, &commit) {
+                    continue;
+                }
                 task_groups
                     .entry(task_id.clone())
                     .or_default()
@@ -122,7 +934,14 @@ impl GitAnalyzer {
         for (task_id, commits) in task_groups {
             let last_activity = commits.iter().map(|c| c.timestamp).max();
 
-            let (suggested_status, confidence) = self.suggest_status(&commits);
+            let merged_branch = merged_branches.remove(&task_id);
+            let (suggested_status, confidence) = if merged_branch.is_some() {
+                (Some("done".to_string()), 1.0)
+            } else {
+                self.suggest_status(&commits)
+            };
+            let confidence = confidence * self.reference_confidence(&commits, &task_id);
+            let diff_stats = self.diff_stats(&commits)?;
 
             activities.push(TaskActivity {
                 task_id,
@@ -130,6 +949,8 @@ impl GitAnalyzer {
                 last_activity,
                 suggested_status,
                 confidence,
+                merged_branch,
+                diff_stats,
             });
         }
 
@@ -141,90 +962,302 @@ impl GitAnalyzer {
 
     /// Get recent commits from the repository with streaming and memory limits
     fn get_recent_commits(&self, limit: usize) -> Result<Vec<Commit<'_>>> {
-        const MAX_COMMITS: usize = 1000; // Maximum commits to process for security
+        recent_commits(&self.repo, limit)
+    }
+
+    /// Task IDs in a branch name, e.g. `backend-001` in `task/backend-001-login`
+    pub fn branch_task_ids(&self, branch_name: &str) -> Vec<String> {
+        self.extract_task_references(branch_name)
+            .into_iter()
+            .filter(|r| r.kind == ReferenceKind::TaskId)
+            .map(|r| r.task_id)
+            .collect()
+    }
+
+    /// Commits on local branches named after tasks, attributed to those tasks.
+    /// Commits also reachable from a branch without a task ID (main, develop)
+    /// are left out; without such a branch there is no telling where task work
+    /// started, so nothing is attributed.
+    fn branch_task_commits(&self, limit: usize) -> Result<Vec<TaskCommit>> {
+        let branches = self.local_branches()?;
+        let trunk_tips: Vec<Oid> = branches
+            .iter()
+            .filter(|b| b.task_ids.is_empty())
+            .map(|b| b.tip)
+            .collect();
+        if trunk_tips.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut task_commits = Vec::new();
+        for branch in branches.iter().filter(|b| !b.task_ids.is_empty()) {
+            for commit in self.branch_commits(branch.tip, &trunk_tips, limit)? {
+                task_commits.push(Self::task_commit(&commit, branch.task_ids.clone()));
+            }
+        }
+
+        Ok(task_commits)
+    }
+
+    /// Tasks touched on each local branch, by branch name. Task branches count
+    /// the commits not on any branch without a task ID; those branches count
+    /// the commits not on each other. Commits reference tasks through their
+    /// messages and the branch name.
+    pub fn analyze_branch_activity(&self, limit: Option<usize>) -> Result<Vec<BranchActivity>> {
+        let limit = limit.unwrap_or(100);
+        let branches = self.local_branches()?;
+
+        let mut activities = Vec::new();
+        for branch in &branches {
+            let hidden: Vec<Oid> = branches
+                .iter()
+                .filter(|other| other.task_ids.is_empty() && other.name != branch.name)
+                .map(|other| other.tip)
+                .collect();
+            let commits = self.branch_commits(branch.tip, &hidden, limit)?;
+
+            let mut tasks: BTreeMap<String, usize> = BTreeMap::new();
+            for commit in &commits {
+                let mut task_ids = self.extract_task_ids(commit.message().unwrap_or(""));
+                for task_id in &branch.task_ids {
+                    if !task_ids.contains(task_id) {
+                        task_ids.push(task_id.clone());
+                    }
+                }
+                for task_id in task_ids {
+                    *tasks.entry(task_id).or_default() += 1;
+                }
+            }
+
+            activities.push(BranchActivity {
+                branch: branch.name.clone(),
+                commits: commits.len(),
+                tasks,
+            });
+        }
+
+        Ok(activities)
+    }
+
+    /// Local branches with their tips and the task IDs in their names
+    fn local_branches(&self) -> Result<Vec<LocalBranch>> {
+        let mut branches = Vec::new();
+        for branch in self
+            .repo
+            .branches(Some(BranchType::Local))
+            .context("Failed to list branches")?
+        {
+            let (branch, _) = branch.context("Failed to read branch")?;
+            let (Ok(Some(name)), Some(tip)) = (branch.name(), branch.get().target()) else {
+                continue;
+            };
+            branches.push(LocalBranch {
+                task_ids: self.branch_task_ids(name),
+                name: name.to_string(),
+                tip,
+            });
+        }
+        Ok(branches)
+    }
+
+    /// Task branches that were deleted after being merged, from the HEAD
+    /// reflog: the branch's last tip is the commit HEAD left when moving away
+    /// from it, or reached when fast-forwarding to it, and it must be on a
+    /// local branch without a task ID. Squash and rebase merges leave no such
+    /// trace and aren't detected.
+    fn merged_task_branches(&self) -> Result<Vec<MergedBranch>> {
+        const MAX_REFLOG_ENTRIES: usize = 1000;
+
+        let branches = self.local_branches()?;
+        let trunk_tips: Vec<Oid> = branches
+            .iter()
+            .filter(|b| b.task_ids.is_empty())
+            .map(|b| b.tip)
+            .collect();
+        let Ok(reflog) = self.repo.reflog("HEAD") else {
+            return Ok(Vec::new());
+        };
+
+        // Newest entries come first: the first tip seen for a branch is its
+        // last, and the last `moving ... to` entry is where it was created
+        let mut tips: BTreeMap<String, Oid> = BTreeMap::new();
+        let mut bases: HashMap<String, Oid> = HashMap::new();
+        for entry in reflog.iter().take(MAX_REFLOG_ENTRIES) {
+            let Some(message) = entry.message() else {
+                continue;
+            };
+            if let Some(moves) = message.strip_prefix("checkout: moving from ") {
+                let Some((from, to)) = moves.split_once(" to ") else {
+                    continue;
+                };
+                tips.entry(from.to_string()).or_insert(entry.id_old());
+                bases.insert(to.to_string(), entry.id_new());
+            } else if let Some(merge) = message.strip_prefix("merge ")
+                && let Some(branch) = merge.strip_suffix(": Fast-forward")
+            {
+                tips.entry(branch.to_string()).or_insert(entry.id_new());
+            }
+        }
+
+        let mut merged = Vec::new();
+        for (name, tip) in tips {
+            if branches.iter().any(|b| b.name == name) {
+                continue;
+            }
+            let task_ids = self.branch_task_ids(&name);
+            if task_ids.is_empty() || self.repo.find_commit(tip).is_err() {
+                continue;
+            }
+            let on_trunk = trunk_tips.iter().any(|&trunk| {
+                trunk == tip || self.repo.graph_descendant_of(trunk, tip).unwrap_or(false)
+            });
+            if on_trunk {
+                merged.push(MergedBranch {
+                    base: bases.get(&name).copied().filter(|&base| base != tip),
+                    name,
+                    tip,
+                    task_ids,
+                });
+            }
+        }
+        Ok(merged)
+    }
+
+    /// Up to `limit` commits reachable from `tip` but not from `hidden`
+    fn branch_commits(&self, tip: Oid, hidden: &[Oid], limit: usize) -> Result<Vec<Commit<'_>>> {
+        const MAX_COMMITS: usize = 1000; // Maximum commits to process per branch
         const MAX_COMMIT_MESSAGE_SIZE: usize = 64 * 1024; // 64KB max message size
 
         let mut revwalk = self
             .repo
             .revwalk()
             .context("Failed to create revision walker")?;
-
-        // Handle repositories with no commits (HEAD doesn't exist yet)
-        if revwalk.push_head().is_err() {
-            return Ok(Vec::new()); // Return empty list for repos with no commits
+        revwalk.push(tip)?;
+        for hidden_tip in hidden {
+            revwalk.hide(*hidden_tip)?;
         }
 
-        // Limit the requested commits to a safe maximum
-        let safe_limit = std::cmp::min(limit, MAX_COMMITS);
-
         let mut commits = Vec::new();
-        let mut total_memory_used = 0usize;
-        const MAX_TOTAL_MEMORY: usize = 10 * 1024 * 1024; // 10MB total memory limit
-
-        for (i, oid) in revwalk.enumerate() {
-            if i >= safe_limit {
-                break;
-            }
-
+        for oid in revwalk.take(limit.min(MAX_COMMITS)) {
             let oid = oid.context("Failed to get commit OID")?;
             let commit = self
                 .repo
                 .find_commit(oid)
                 .with_context(|| format!("Failed to find commit {}", oid))?;
-
-            // Check commit message size for security
-            if let Some(message) = commit.message() {
-                if message.len() > MAX_COMMIT_MESSAGE_SIZE {
-                    // Skip commits with excessively large messages
-                    continue;
-                }
-                total_memory_used += message.len();
-            }
-
-            // Basic memory usage estimation and limit
-            total_memory_used += 200; // Approximate overhead per commit object
-            if total_memory_used > MAX_TOTAL_MEMORY {
-                break; // Prevent memory exhaustion
+            if commit
+                .message()
+                .is_some_and(|m| m.len() > MAX_COMMIT_MESSAGE_SIZE)
+            {
+                continue;
             }
-
             commits.push(commit);
         }
-
         Ok(commits)
     }
 
     /// Parse commits to extract task-related information
+    /// Task commits among the `limit` newest reachable from `tip`, reading
+    /// and parsing only commits the cache hasn't seen
+    fn cached_task_commits(
+        &self,
+        cache: &RefCell<GitCache>,
+        ref_name: &str,
+        tip: Oid,
+        limit: usize,
+    ) -> Result<Vec<TaskCommit>> {
+        const MAX_COMMITS: usize = 1000; // Maximum commits to process for security
+        const MAX_COMMIT_MESSAGE_SIZE: usize = 64 * 1024; // 64KB max message size
+
+        let mut cache = cache.borrow_mut();
+        let tip_key = tip.to_string();
+        if let Some(commits) = cache.walk(ref_name, &tip_key, limit) {
+            return Ok(commits);
+        }
+
+        let mut revwalk = self
+            .repo
+            .revwalk()
+            .context("Failed to create revision walker")?;
+        revwalk.push(tip)?;
+
+        let mut oids = Vec::new();
+        let mut task_commits = Vec::new();
+        for oid in revwalk.take(limit.min(MAX_COMMITS)) {
+            let oid = oid.context("Failed to get commit OID")?;
+            let key = oid.to_string();
+            let parsed = match cache.commit(&key) {
+                Some(parsed) => parsed,
+                None => {
+                    let commit = self
+                        .repo
+                        .find_commit(oid)
+                        .with_context(|| format!("Failed to find commit {}", oid))?;
+                    let message = commit.message().unwrap_or("");
+                    // Skip commits with excessively large messages
+                    let task_ids = if message.len() > MAX_COMMIT_MESSAGE_SIZE {
+                        Vec::new()
+                    } else {
+                        self.extract_task_ids(message)
+                    };
+                    let parsed =
+                        (!task_ids.is_empty()).then(|| Self::task_commit(&commit, task_ids));
+                    cache.insert_commit(key.clone(), parsed.clone());
+                    parsed
+                }
+            };
+            oids.push(key);
+            task_commits.extend(parsed);
+        }
+        cache.record_walk(ref_name, &tip_key, limit, oids);
+        Ok(task_commits)
+    }
+
     fn parse_task_commits(&self, commits: Vec<Commit>) -> Result<Vec<TaskCommit>> {
         let mut task_commits = Vec::new();
 
         for commit in commits {
-            let message = commit.message().unwrap_or("").to_string();
-            let task_ids = self.extract_task_ids(&message);
+            let task_ids = self.extract_task_ids(commit.message().unwrap_or(""));
 
             if !task_ids.is_empty() {
-                let author = commit.author().name().unwrap_or("Unknown").to_string();
-                let timestamp =
-                    DateTime::from_timestamp(commit.time().seconds(), 0).unwrap_or_else(Utc::now);
-
-                task_commits.push(TaskCommit {
-                    oid: commit.id().to_string(),
-                    message,
-                    author,
-                    timestamp,
-                    task_ids,
-                });
+                task_commits.push(Self::task_commit(&commit, task_ids));
             }
         }
 
         Ok(task_commits)
     }
 
+    fn task_commit(commit: &Commit, task_ids: Vec<String>) -> TaskCommit {
+        TaskCommit {
+            oid: commit.id().to_string(),
+            message: commit.message().unwrap_or("").to_string(),
+            author: commit.author().name().unwrap_or("Unknown").to_string(),
+            timestamp: DateTime::from_timestamp(commit.time().seconds(), 0)
+                .unwrap_or_else(Utc::now),
+            task_ids,
+            submodule: None,
+        }
+    }
+
     /// Extract task IDs from commit message using common patterns with Unicode safety
     pub fn extract_task_ids(&self, message: &str) -> Vec<String> {
+        self.extract_task_references(message)
+            .into_iter()
+            .map(|r| r.task_id)
+            .collect()
+    }
+
+    /// Task references in a commit message, `Task-Id:` trailers and conventional
+    /// commit scopes first; each task appears once, with its most reliable kind
+    pub fn extract_task_references(&self, message: &str) -> Vec<TaskReference> {
         const MAX_TASK_IDS: usize = 100;
         const MAX_MESSAGE_SIZE: usize = 1024 * 1024; // 1MB - allow large messages but with processing limits
 
-        let mut task_ids = Vec::new();
+        let mut references: Vec<TaskReference> = Vec::new();
+        let mut push = |task_id: String, kind: ReferenceKind| {
+            if references.len() < MAX_TASK_IDS && !references.iter().any(|r| r.task_id == task_id) {
+                references.push(TaskReference { task_id, kind });
+            }
+        };
 
         // Normalize Unicode and sanitize the message first
         let normalized_message = self.normalize_unicode_message(message);
@@ -241,18 +1274,32 @@ impl GitAnalyzer {
             &normalized_message
         };
 
+        // Pattern 0: `Task-Id: backend-001` trailers, which name the task exactly.
+        // Read from the raw message, since normalization truncates long ones
+        for task_id in trailer_task_ids(message) {
+            push(task_id, ReferenceKind::Trailer);
+        }
+
         // Pattern 1: area-number format (e.g., "setup-001", "backend-002")
         // Pattern with word boundaries to avoid false positives in version strings
         let task_pattern = match regex::Regex::new(r"\b([a-zA-Z]{1,20})-(\d{3})\b") {
             Ok(pattern) => pattern,
-            Err(_) => return Vec::new(), // Safe fallback
+            Err(_) => return references, // Safe fallback
         };
 
+        // Scopes shaped like a task ID, `feat(backend-001): ...`; other scopes
+        // name areas (see `scope_area`)
+        for scope in conventional_scopes(working_message) {
+            if task_pattern
+                .find(&scope)
+                .is_some_and(|m| m.as_str().len() == scope.len())
+            {
+                push(scope, ReferenceKind::Scope);
+            }
+        }
+
         // Process message using iterator to prevent ReDoS while finding legitimate task IDs
         for cap in task_pattern.captures_iter(working_message) {
-            if task_ids.len() >= MAX_TASK_IDS {
-                break; // Prevent unbounded allocation
-            }
             if let (Some(area), Some(number)) = (cap.get(1), cap.get(2)) {
                 let area_str = area.as_str();
                 let num_str = number.as_str();
@@ -274,7 +1321,7 @@ impl GitAnalyzer {
                         }
                     }
 
-                    task_ids.push(format!("{}-{}", area_str, num_str));
+                    push(format!("{}-{}", area_str, num_str), ReferenceKind::TaskId);
                 }
             }
         }
@@ -283,13 +1330,10 @@ impl GitAnalyzer {
         // Case-insensitive pattern to handle "Task" and "Issue"
         let issue_pattern = match regex::Regex::new(r"(?i)#(\d{1,6})|(?:task|issue)\s+(\d{1,6})") {
             Ok(pattern) => pattern,
-            Err(_) => return task_ids, // Continue with what we have
+            Err(_) => return references, // Continue with what we have
         };
 
         for cap in issue_pattern.captures_iter(working_message) {
-            if task_ids.len() >= MAX_TASK_IDS {
-                break; // Prevent unbounded allocation
-            }
             // Check both capture groups (for different patterns)
             let number = cap.get(1).or_else(|| cap.get(2));
             if let Some(num) = number {
@@ -300,12 +1344,24 @@ impl GitAnalyzer {
                     && task_num < 1000000
                 {
                     // Reasonable task ID range
-                    task_ids.push(format!("task-{}", num_str));
+                    push(format!("task-{}", num_str), ReferenceKind::Issue);
                 }
             }
         }
 
-        task_ids
+        references
+    }
+
+    /// How reliably the commits point at `task_id`: the best reference among
+    /// them, or the branch name when no message mentions the task
+    fn reference_confidence(&self, commits: &[TaskCommit], task_id: &str) -> f32 {
+        commits
+            .iter()
+            .flat_map(|c| self.extract_task_references(&c.message))
+            .filter(|r| r.task_id == task_id)
+            .map(|r| r.kind.confidence())
+            .reduce(f32::max)
+            .unwrap_or(ReferenceKind::Branch.confidence())
     }
 
     /// Normalize Unicode text and sanitize control characters for safe processing
@@ -342,12 +1398,18 @@ impl GitAnalyzer {
         }
     }
 
-    /// Suggest task status based on commit patterns with Unicode normalization
+    /// Suggest task status based on commit patterns with Unicode normalization.
+    /// A merged branch or pull request is the strongest completion signal: as
+    /// the latest commit it settles on `done`, otherwise it outweighs keywords.
     pub fn suggest_status(&self, commits: &[TaskCommit]) -> (Option<String>, f32) {
         if commits.is_empty() {
             return (None, 0.0);
         }
 
+        if is_completion_merge(&commits[0].message) {
+            return (Some("done".to_string()), 1.0);
+        }
+
         let recent_messages: Vec<&str> = commits
             .iter()
             .take(5) // Look at 5 most recent commits
@@ -362,6 +1424,11 @@ impl GitAnalyzer {
             let normalized_message = self.normalize_unicode_message(message);
             let lower = normalized_message.to_lowercase();
 
+            if is_completion_merge(&normalized_message) {
+                *indicators.entry("done").or_insert(0.0) += 1.0;
+                continue;
+            }
+
             // Check for completion indicators first (highest priority)
             let has_completion =
                 lower.contains("complete") || lower.contains("finish") || lower.contains("done");
@@ -423,6 +1490,347 @@ impl GitAnalyzer {
         (Some(status.to_string()), clamped_confidence)
     }
 
+    /// Paths a commit changed relative to its first parent (every path for a
+    /// root commit), relative to the repository root
+    pub fn commit_files(&self, oid: &str) -> Result<Vec<String>> {
+        let oid = Oid::from_str(oid).with_context(|| format!("Invalid commit id {}", oid))?;
+        let commit = self
+            .repo
+            .find_commit(oid)
+            .with_context(|| format!("Failed to find commit {}", oid))?;
+
+        Ok(self
+            .commit_diff(&commit)?
+            .deltas()
+            .filter_map(|delta| delta.new_file().path().or_else(|| delta.old_file().path()))
+            .map(|path| path.to_string_lossy().into_owned())
+            .collect())
+    }
+
+    /// Changes of a commit against its first parent
+    fn commit_diff(&self, commit: &Commit) -> Result<Diff<'_>> {
+        commit_diff(&self.repo, commit)
+    }
+
+    /// Source lines and files changed by `commits`. Merges are skipped since
+    /// they repeat their branch's changes; commits not in the repository count
+    /// for nothing.
+    pub fn diff_stats(&self, commits: &[TaskCommit]) -> Result<DiffStats> {
+        let mut stats = DiffStats::default();
+        let mut files = HashSet::new();
+
+        for task_commit in commits {
+            let repo = self.commit_repo(task_commit);
+            let Some(commit) = Oid::from_str(&task_commit.oid)
+                .ok()
+                .and_then(|oid| repo.find_commit(oid).ok())
+            else {
+                continue;
+            };
+            if commit.parent_count() > 1 {
+                continue;
+            }
+
+            let diff = commit_diff(repo, &commit)?;
+            for (idx, delta) in diff.deltas().enumerate() {
+                let Some(path) = delta.new_file().path().or_else(|| delta.old_file().path()) else {
+                    continue;
+                };
+                let path = path.to_string_lossy();
+                if !is_source_path(&path) {
+                    continue;
+                }
+                let path = match &task_commit.submodule {
+                    Some(submodule) => format!("{}/{}", submodule, path).into(),
+                    None => path,
+                };
+                if let Some(patch) = Patch::from_diff(&diff, idx)? {
+                    let (_, insertions, deletions) = patch.line_stats()?;
+                    stats.insertions += insertions;
+                    stats.deletions += deletions;
+                }
+                files.insert(path.into_owned());
+            }
+        }
+
+        stats.files_changed = files.len();
+        Ok(stats)
+    }
+
+    /// Files touched by each task's commits among the last `limit`: task ID →
+    /// path → number of the task's commits that changed it
+    pub fn task_file_map(
+        &self,
+        limit: Option<usize>,
+    ) -> Result<HashMap<String, BTreeMap<String, usize>>> {
+        let mut files_by_commit: HashMap<String, Vec<String>> = HashMap::new();
+        let mut map: HashMap<String, BTreeMap<String, usize>> = HashMap::new();
+
+        for activity in self.analyze_task_activity(limit)? {
+            let files = map.entry(activity.task_id).or_default();
+            for commit in &activity.commits {
+                if !files_by_commit.contains_key(&commit.oid) {
+                    let changed = self.commit_files(&commit.oid)?;
+                    files_by_commit.insert(commit.oid.clone(), changed);
+                }
+                for path in &files_by_commit[&commit.oid] {
+                    *files.entry(path.clone()).or_default() += 1;
+                }
+            }
+        }
+
+        Ok(map)
+    }
+
+    /// Source files changed in the index or working tree, untracked ones included
+    pub fn working_tree_changes(&self) -> Result<Vec<WorkingChange>> {
+        let staged = Status::INDEX_NEW
+            | Status::INDEX_MODIFIED
+            | Status::INDEX_DELETED
+            | Status::INDEX_RENAMED
+            | Status::INDEX_TYPECHANGE;
+        let statuses = self
+            .repo
+            .statuses(Some(
+                StatusOptions::new()
+                    .include_untracked(true)
+                    .recurse_untracked_dirs(true)
+                    .include_ignored(false),
+            ))
+            .context("Failed to read the working tree status")?;
+
+        Ok(statuses
+            .iter()
+            .filter_map(|entry| {
+                let path = entry.path()?.to_string();
+                is_source_path(&path).then(|| WorkingChange {
+                    staged: entry.status().intersects(staged),
+                    path,
+                })
+            })
+            .collect())
+    }
+
+    /// Commits among the last `limit` that reference `task_id`, newest first
+    pub fn task_commits(&self, task_id: &str, limit: Option<usize>) -> Result<Vec<TaskCommit>> {
+        let commits = self.get_recent_commits(limit.unwrap_or(100))?;
+        Ok(self
+            .parse_task_commits(commits)?
+            .into_iter()
+            .filter(|c| c.task_ids.iter().any(|id| id == task_id))
+            .collect())
+    }
+
+    /// Patch ID of a commit's changes, or with `inverted` of the changes that
+    /// would undo it. `None` for merges and commits changing nothing.
+    fn patch_id(&self, commit: &Commit, inverted: bool) -> Result<Option<Oid>> {
+        if commit.parent_count() > 1 {
+            return Ok(None);
+        }
+        let tree = commit.tree()?;
+        let parent_tree = match commit.parent(0) {
+            Ok(parent) => Some(parent.tree()?),
+            Err(_) => None,
+        };
+        let (old, new) = if inverted {
+            (Some(&tree), parent_tree.as_ref())
+        } else {
+            (parent_tree.as_ref(), Some(&tree))
+        };
+        let diff = self
+            .repo
+            .diff_tree_to_tree(old, new, None)
+            .with_context(|| format!("Failed to diff commit {}", commit.id()))?;
+        if diff.deltas().len() == 0 {
+            return Ok(None);
+        }
+        Ok(Some(diff.patchid(None)?))
+    }
+
+    /// Commits among the last `limit` that revert commits of `activities`,
+    /// keyed by task ID, newest first
+    ///
+    /// A revert is matched by the `This reverts commit` line `git revert`
+    /// writes, or failing that by undoing a commit's exact changes (patch-id
+    /// matching), which also catches reverts made by hand or squashed. Reverts
+    /// that were themselves reverted don't count.
+    pub fn find_reverts(
+        &self,
+        activities: &[TaskActivity],
+        limit: usize,
+    ) -> Result<HashMap<String, Vec<TaskRevert>>> {
+        let mut reverts: HashMap<String, Vec<TaskRevert>> = HashMap::new();
+        if activities.is_empty() {
+            return Ok(reverts);
+        }
+
+        // Candidate reverts with the commit they name and the patch they undo
+        let mut candidates = Vec::new();
+        for commit in self.get_recent_commits(limit)? {
+            let Ok(message) = std::str::from_utf8(commit.message_bytes()) else {
+                continue;
+            };
+            let named = reverted_commit(message).map(str::to_string);
+            let undone = self.patch_id(&commit, true)?;
+            if named.is_some() || undone.is_some() {
+                candidates.push((Self::task_commit(&commit, Vec::new()), named, undone));
+            }
+        }
+        let named: Vec<String> = candidates
+            .iter()
+            .filter_map(|(_, named, _)| named.clone())
+            .collect();
+        candidates.retain(|(revert, _, _)| !named.iter().any(|n| revert.oid.starts_with(n)));
+
+        let mut patch_ids: HashMap<&str, Option<Oid>> = HashMap::new();
+        for activity in activities {
+            for task_commit in &activity.commits {
+                // Reapplying a reverted commit isn't undoing the task's work
+                if reverted_commit(&task_commit.message).is_some() {
+                    continue;
+                }
+                if !patch_ids.contains_key(task_commit.oid.as_str()) {
+                    let patch_id = match Oid::from_str(&task_commit.oid)
+                        .ok()
+                        .and_then(|oid| self.repo.find_commit(oid).ok())
+                    {
+                        Some(commit) => self.patch_id(&commit, false)?,
+                        None => None,
+                    };
+                    patch_ids.insert(&task_commit.oid, patch_id);
+                }
+                let patch_id = patch_ids[task_commit.oid.as_str()];
+
+                for (revert, named, undone) in &candidates {
+                    if revert.oid == task_commit.oid || revert.timestamp < task_commit.timestamp {
+                        continue;
+                    }
+                    let matched = if named
+                        .as_ref()
+                        .is_some_and(|n| task_commit.oid.starts_with(n))
+                    {
+                        RevertMatch::Message
+                    } else if named.is_none() && patch_id.is_some() && *undone == patch_id {
+                        RevertMatch::PatchId
+                    } else {
+                        continue;
+                    };
+                    let task_reverts = reverts.entry(activity.task_id.clone()).or_default();
+                    if !task_reverts.iter().any(|r| r.commit.oid == revert.oid) {
+                        task_reverts.push(TaskRevert {
+                            commit: revert.clone(),
+                            reverted: task_commit.oid.clone(),
+                            matched,
+                        });
+                    }
+                }
+            }
+        }
+
+        for task_reverts in reverts.values_mut() {
+            task_reverts.sort_by_key(|r| Reverse(r.commit.timestamp));
+        }
+        Ok(reverts)
+    }
+
+    /// Full name of the branch HEAD is on (`HEAD` when detached) and its commit
+    pub fn head_commit(&self) -> Option<(String, String)> {
+        let head = self.repo.head().ok()?;
+        let oid = head.target()?;
+        let name = if head.is_branch() {
+            head.name()?
+        } else {
+            "HEAD"
+        };
+        Some((name.to_string(), oid.to_string()))
+    }
+
+    /// Commits reachable from HEAD but not from `since`, at most `limit`.
+    /// `None` when `since` is gone or no longer in HEAD's history, as after a
+    /// rebase or reset.
+    pub fn commits_since(&self, since: &str, limit: usize) -> Result<Option<HashSet<String>>> {
+        let Some(since) = Oid::from_str(since)
+            .ok()
+            .filter(|oid| self.repo.find_commit(*oid).is_ok())
+        else {
+            return Ok(None);
+        };
+        let Some(head) = self.repo.head().ok().and_then(|h| h.target()) else {
+            return Ok(None);
+        };
+        if head != since && !self.repo.graph_descendant_of(head, since)? {
+            return Ok(None);
+        }
+
+        let mut revwalk = self
+            .repo
+            .revwalk()
+            .context("Failed to create revision walker")?;
+        revwalk.push(head)?;
+        revwalk.hide(since)?;
+        let mut commits = HashSet::new();
+        for oid in revwalk.take(limit) {
+            commits.insert(oid?.to_string());
+        }
+        Ok(Some(commits))
+    }
+
+    /// Tags whose commit is `tag`'s or one of its ancestors, oldest first, with
+    /// the commits they point to
+    pub fn release_tags(&self, tag: &str) -> Result<Vec<(String, Oid)>> {
+        let release = self
+            .repo
+            .revparse_single(&format!("refs/tags/{}", tag))
+            .and_then(|object| object.peel_to_commit())
+            .with_context(|| format!("Tag '{}' not found", tag))?;
+
+        let mut tags = Vec::new();
+        for name in self.repo.tag_names(None)?.iter().flatten() {
+            let Ok(commit) = self
+                .repo
+                .revparse_single(&format!("refs/tags/{}", name))
+                .and_then(|object| object.peel_to_commit())
+            else {
+                continue;
+            };
+            if commit.id() == release.id()
+                || self.repo.graph_descendant_of(release.id(), commit.id())?
+            {
+                tags.push((name.to_string(), commit.id(), commit.time().seconds()));
+            }
+        }
+        tags.sort_by(|a, b| a.2.cmp(&b.2).then_with(|| a.0.cmp(&b.0)));
+        Ok(tags.into_iter().map(|(name, oid, _)| (name, oid)).collect())
+    }
+
+    /// Whether a commit is part of the history of `release`
+    pub fn release_contains(&self, release: Oid, commit: &str) -> Result<bool> {
+        let commit =
+            Oid::from_str(commit).with_context(|| format!("Invalid commit id {}", commit))?;
+        Ok(commit == release || self.repo.graph_descendant_of(release, commit)?)
+    }
+
+    /// Unified diff of a commit against its first parent
+    pub fn commit_patch(&self, oid: &str) -> Result<String> {
+        let oid = Oid::from_str(oid).with_context(|| format!("Invalid commit id {}", oid))?;
+        let commit = self
+            .repo
+            .find_commit(oid)
+            .with_context(|| format!("Failed to find commit {}", oid))?;
+
+        let mut patch = String::new();
+        self.commit_diff(&commit)?
+            .print(DiffFormat::Patch, |_, _, line| {
+                if matches!(line.origin(), '+' | '-' | ' ') {
+                    patch.push(line.origin());
+                }
+                patch.push_str(&String::from_utf8_lossy(line.content()));
+                true
+            })
+            .with_context(|| format!("Failed to format the diff of commit {}", oid))?;
+        Ok(patch)
+    }
+
     /// Get repository statistics
     pub fn get_repo_stats(&self) -> Result<HashMap<String, String>> {
         let mut stats = HashMap::new();
@@ -460,20 +1868,28 @@ impl GitAnalyzer {
             .find_remote(remote_name)
             .context("Failed to find remote repository")?;
 
-        let mut callbacks = RemoteCallbacks::new();
+        // Handle authentication: libgit2 asks again after each rejected
+        // credential, so every method gets one try
+        let config = self.repo.config().context("Failed to read git config")?;
+        // Methods tried, and whether they had credentials to offer
+        let tried: RefCell<Vec<(AuthMethod, bool)>> = RefCell::new(Vec::new());
 
-        // Handle authentication
-        callbacks.credentials(|_url, username_from_url, _allowed_types| {
-            // Try SSH key from agent first
-            if let Some(username) = username_from_url {
-                git2::Cred::ssh_key_from_agent(username)
-            } else {
-                // Fallback to default user
-                git2::Cred::ssh_key_from_agent("git")
+        let mut callbacks = RemoteCallbacks::new();
+        callbacks.credentials(|url, username_from_url, allowed_types| {
+            let mut tried = tried.borrow_mut();
+            for method in AuthMethod::ALL {
+                if tried.iter().any(|(m, _)| *m == method) || !method.applies_to(allowed_types) {
+                    continue;
+                }
+                let cred = method.credentials(&config, url, username_from_url);
+                tried.push((method, cred.is_some()));
+                if let Some(cred) = cred {
+                    return Ok(cred);
+                }
             }
-            .map_err(|_| {
-                git2::Error::from_str("Authentication required but no credentials available")
-            })
+            Err(git2::Error::from_str(
+                "Authentication required but no credentials available",
+            ))
         });
 
         // Progress callback for long operations
@@ -491,12 +1907,10 @@ impl GitAnalyzer {
             true
         });
 
-        // Handle certificate verification
-        callbacks.certificate_check(|_cert, _valid| {
-            // For now, accept all certificates
-            // In production, you might want stricter validation
-            Ok(CertificateCheckStatus::CertificateOk)
-        });
+        // Certificates are verified as usual: credentials may be sent over
+        // the connection
+        callbacks
+            .certificate_check(|_cert, _valid| Ok(CertificateCheckStatus::CertificatePassthrough));
 
         let mut fetch_options = FetchOptions::new();
         fetch_options.remote_callbacks(callbacks);
@@ -509,6 +1923,41 @@ impl GitAnalyzer {
                 Ok(())
             }
             Err(e) => {
+                let tried = tried.borrow();
+                if !tried.is_empty() {
+                    let names = |offered: bool| {
+                        tried
+                            .iter()
+                            .filter(|(_, o)| *o == offered)
+                            .map(|(m, _)| m.name())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    };
+                    let url = remote.url().unwrap_or(remote_name);
+                    let problem = if tried.iter().any(|(_, offered)| *offered) {
+                        format!(
+                            "Authentication failed for {} (rejected: {})",
+                            url,
+                            names(true)
+                        )
+                    } else {
+                        format!(
+                            "No credentials found for {} (checked: {})",
+                            url,
+                            names(false)
+                        )
+                    };
+                    let hint = if url.starts_with("http://") || url.starts_with("https://") {
+                        format!(
+                            "For HTTPS remotes, configure 'git config credential.helper' or set GIT_ASKPASS; for GitHub ones, {} also work",
+                            TOKEN_VARS.join(" or ")
+                        )
+                    } else {
+                        "For SSH remotes, add your key to ssh-agent ('ssh-add')".to_string()
+                    };
+                    return Err(anyhow::anyhow!("{}. {}: {}", problem, hint, e));
+                }
+
                 let error_msg = match e.class() {
                     git2::ErrorClass::Net => {
                         "Network error: Check your internet connection and repository URL"
@@ -531,10 +1980,81 @@ impl GitAnalyzer {
         }
     }
 
-    /// Analyze remote task activity by comparing with local commits
+    /// Remote branch to analyze: `branch` when given (with or without the
+    /// remote's name in front), else the remote's default branch as recorded
+    /// in `refs/remotes/<remote>/HEAD`, else the first trunk branch the remote has
+    pub fn remote_branch(&self, remote_name: &str, branch: Option<&str>) -> Result<String> {
+        let prefix = format!("refs/remotes/{}/", remote_name);
+        let exists = |name: &str| {
+            self.repo
+                .find_reference(&format!("{}{}", prefix, name))
+                .is_ok()
+        };
+
+        if let Some(branch) = branch {
+            let branch = branch
+                .strip_prefix(&format!("{}/", remote_name))
+                .unwrap_or(branch);
+            if exists(branch) {
+                return Ok(branch.to_string());
+            }
+            return Err(anyhow::anyhow!(
+                "Remote branch {}/{} not found. Fetched branches: {}",
+                remote_name,
+                branch,
+                self.remote_branches(remote_name)?.join(", ")
+            ));
+        }
+
+        let head = self
+            .repo
+            .find_reference(&format!("{}HEAD", prefix))
+            .ok()
+            .and_then(|head| head.symbolic_target().map(str::to_string))
+            .and_then(|target| target.strip_prefix(&prefix).map(str::to_string));
+        if let Some(head) = head.filter(|head| exists(head)) {
+            return Ok(head);
+        }
+
+        TRUNK_BRANCHES
+            .iter()
+            .find(|name| exists(name))
+            .map(ToString::to_string)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Can't tell the default branch of '{}'; pass --branch, or run 'git remote set-head {} --auto'",
+                    remote_name,
+                    remote_name
+                )
+            })
+    }
+
+    /// Branches fetched from a remote, without the remote's name
+    pub fn remote_branches(&self, remote_name: &str) -> Result<Vec<String>> {
+        let prefix = format!("{}/", remote_name);
+        let mut names = Vec::new();
+        for branch in self
+            .repo
+            .branches(Some(BranchType::Remote))
+            .context("Failed to list remote branches")?
+        {
+            let (branch, _) = branch.context("Failed to read branch")?;
+            if let Ok(Some(name)) = branch.name()
+                && let Some(name) = name.strip_prefix(&prefix)
+                && name != "HEAD"
+            {
+                names.push(name.to_string());
+            }
+        }
+        Ok(names)
+    }
+
+    /// Analyze remote task activity on `branch` of the remote (its default
+    /// branch when `None`, see [`Self::remote_branch`])
     pub fn analyze_remote_task_activity(
         &self,
         remote_name: &str,
+        branch: Option<&str>,
         limit: Option<usize>,
     ) -> Result<Vec<TaskActivity>> {
         // First, ensure we have the latest remote data
@@ -544,13 +2064,34 @@ impl GitAnalyzer {
         }
 
         // Get remote tracking branch commits
-        let remote_commits = self.get_remote_commits(remote_name, limit.unwrap_or(100))?;
-        let remote_task_commits = self.parse_task_commits(remote_commits)?;
+        let branch = self.remote_branch(remote_name, branch)?;
+        let name = format!("refs/remotes/{}/{}", remote_name, branch);
+        let tip = self
+            .repo
+            .find_reference(&name)
+            .ok()
+            .and_then(|r| r.target())
+            .with_context(|| {
+                format!(
+                    "Failed to find remote tracking branch {}/{}",
+                    remote_name, branch
+                )
+            })?;
+        let limit = self.anchored_limit(Some(tip), limit.unwrap_or(100))?;
+        let remote_task_commits = match &self.cache {
+            Some(cache) => self.cached_task_commits(cache, &name, tip, limit)?,
+            None => {
+                self.parse_task_commits(self.get_remote_commits(remote_name, &branch, limit)?)?
+            }
+        };
 
         // Group commits by task ID for remote analysis
         let mut remote_task_groups: HashMap<String, Vec<TaskCommit>> = HashMap::new();
         for commit in remote_task_commits {
             for task_id in &commit.task_ids {
+                if self.predates_task(task_id, &commit) {
+                    continue;
+                }
                 remote_task_groups
                     .entry(task_id.clone())
                     .or_default()
@@ -564,6 +2105,8 @@ impl GitAnalyzer {
             let last_activity = commits.iter().map(|c| c.timestamp).max();
 
             let (suggested_status, confidence) = self.suggest_status(&commits);
+            let confidence = confidence * self.reference_confidence(&commits, &task_id);
+            let diff_stats = self.diff_stats(&commits)?;
 
             remote_activities.push(TaskActivity {
                 task_id,
@@ -571,6 +2114,8 @@ impl GitAnalyzer {
                 last_activity,
                 suggested_status,
                 confidence,
+                merged_branch: None,
+                diff_stats,
             });
         }
 
@@ -581,19 +2126,24 @@ impl GitAnalyzer {
     }
 
     /// Get commits from remote tracking branch with streaming and memory limits
-    fn get_remote_commits(&self, remote_name: &str, limit: usize) -> Result<Vec<Commit<'_>>> {
+    fn get_remote_commits(
+        &self,
+        remote_name: &str,
+        branch: &str,
+        limit: usize,
+    ) -> Result<Vec<Commit<'_>>> {
         const MAX_COMMITS: usize = 1000; // Maximum commits to process for security
         const MAX_COMMIT_MESSAGE_SIZE: usize = 64 * 1024; // 64KB max message size
 
-        let remote_branch_name = format!("{}/master", remote_name); // Assuming master branch
         let remote_ref = self
             .repo
-            .find_reference(&format!("refs/remotes/{}", remote_branch_name))
-            .or_else(|_| {
-                self.repo
-                    .find_reference(&format!("refs/remotes/{}/main", remote_name))
-            })
-            .context("Failed to find remote tracking branch")?;
+            .find_reference(&format!("refs/remotes/{}/{}", remote_name, branch))
+            .with_context(|| {
+                format!(
+                    "Failed to find remote tracking branch {}/{}",
+                    remote_name, branch
+                )
+            })?;
 
         let remote_oid = remote_ref
             .target()
@@ -647,9 +2197,11 @@ impl GitAnalyzer {
         Ok(commits)
     }
 
-    /// Compare local and remote task activities to detect conflicts
+    /// Compare local and remote task activities to detect conflicts; `remote`
+    /// names the remote branch `remote_activities` come from
     pub fn detect_sync_conflicts(
         &self,
+        remote: &str,
         local_activities: &[TaskActivity],
         remote_activities: &[TaskActivity],
     ) -> Vec<SyncConflict> {
@@ -709,6 +2261,7 @@ impl GitAnalyzer {
             if resolution != ConflictResolution::NoConflict {
                 conflicts.push(SyncConflict {
                     task_id,
+                    remote: remote.to_string(),
                     local_status: local_activity
                         .and_then(|a| a.suggested_status.clone())
                         .unwrap_or_else(|| "no local activity".to_string()),
@@ -725,6 +2278,232 @@ impl GitAnalyzer {
         conflicts
     }
 
+    /// Resolve a starting point given as a tag, branch, commit, or `YYYY-MM-DD` date
+    ///
+    /// Dates resolve to the newest commit on HEAD made before that day. Returns
+    /// `None` when the date predates the whole history.
+    pub fn resolve_since(&self, since: &str) -> Result<Option<Oid>> {
+        if let Ok(object) = self.repo.revparse_single(since) {
+            let commit = object
+                .peel_to_commit()
+                .with_context(|| format!("'{}' does not point to a commit", since))?;
+            return Ok(Some(commit.id()));
+        }
+
+        let date = chrono::NaiveDate::parse_from_str(since, "%Y-%m-%d").map_err(|_| {
+            anyhow::anyhow!(
+                "'{}' is neither a git revision nor a date (expected YYYY-MM-DD)",
+                since
+            )
+        })?;
+        let cutoff = date
+            .and_hms_opt(0, 0, 0)
+            .map(|dt| dt.and_utc().timestamp())
+            .context("Invalid date")?;
+
+        let mut revwalk = self
+            .repo
+            .revwalk()
+            .context("Failed to create revision walker")?;
+        if revwalk.push_head().is_err() {
+            return Ok(None);
+        }
+        revwalk.set_sorting(git2::Sort::TIME)?;
+
+        for oid in revwalk {
+            let oid = oid.context("Failed to get commit OID")?;
+            let commit = self.repo.find_commit(oid)?;
+            if commit.time().seconds() < cutoff {
+                return Ok(Some(oid));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Read the status of every task file recorded in a commit's tree
+    ///
+    /// Both active (`tasks/`) and archived (`.taskguard/archive/`) task files
+    /// are considered, keyed by task ID.
+    pub fn task_statuses_at(&self, oid: Oid) -> Result<HashMap<String, TaskStatus>> {
+        let commit = self
+            .repo
+            .find_commit(oid)
+            .with_context(|| format!("Failed to find commit {}", oid))?;
+        let tree = commit.tree().context("Failed to read commit tree")?;
+
+        let mut statuses = HashMap::new();
+        tree.walk(git2::TreeWalkMode::PreOrder, |dir, entry| {
+            let name = entry.name().unwrap_or("");
+            let is_task_dir = dir.starts_with("tasks/")
+                || dir.contains("/tasks/")
+                || dir.contains(".taskguard/archive/");
+
+            if is_task_dir
+                && name.ends_with(".md")
+                && let Ok(object) = entry.to_object(&self.repo)
+                && let Some(blob) = object.as_blob()
+                && let Ok(content) = std::str::from_utf8(blob.content())
+                && let Ok(task) = Task::parse_content(content)
+            {
+                statuses.insert(task.id, task.status);
+            }
+            git2::TreeWalkResult::Ok
+        })
+        .context("Failed to walk commit tree")?;
+
+        Ok(statuses)
+    }
+
+    /// Every committed version of a task's file reachable from HEAD, oldest first
+    ///
+    /// The file is located by name in each commit, so moves between areas and
+    /// into the archive are followed. Commits that leave the file unchanged are skipped.
+    pub fn task_history(&self, task_id: &str) -> Result<Vec<TaskRevision>> {
+        let mut revwalk = self
+            .repo
+            .revwalk()
+            .context("Failed to create revision walker")?;
+        if revwalk.push_head().is_err() {
+            return Ok(Vec::new());
+        }
+        revwalk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::REVERSE)?;
+
+        let file_name = format!("{}.md", task_id);
+        let mut revisions = Vec::new();
+        let mut last_blob: Option<Oid> = None;
+
+        for oid in revwalk {
+            let oid = oid.context("Failed to get commit OID")?;
+            let commit = self.repo.find_commit(oid)?;
+            let tree = commit.tree().context("Failed to read commit tree")?;
+
+            let mut blob_id = None;
+            tree.walk(git2::TreeWalkMode::PreOrder, |dir, entry| {
+                let is_task_dir = dir.starts_with("tasks/")
+                    || dir.contains("/tasks/")
+                    || dir.contains(".taskguard/archive/");
+                if is_task_dir && entry.name() == Some(file_name.as_str()) {
+                    blob_id = Some(entry.id());
+                    return git2::TreeWalkResult::Abort;
+                }
+                git2::TreeWalkResult::Ok
+            })
+            .ok(); // Aborting the walk early reports an error; the match is already recorded
+
+            let Some(blob_id) = blob_id else {
+                continue;
+            };
+            if last_blob == Some(blob_id) {
+                continue;
+            }
+            last_blob = Some(blob_id);
+
+            let blob = self.repo.find_blob(blob_id)?;
+            let Some(task) = std::str::from_utf8(blob.content())
+                .ok()
+                .and_then(|content| Task::parse_content(content).ok())
+            else {
+                continue;
+            };
+
+            revisions.push(TaskRevision {
+                oid: oid.to_string(),
+                author: commit.author().name().unwrap_or("Unknown").to_string(),
+                timestamp: DateTime::from_timestamp(commit.time().seconds(), 0)
+                    .unwrap_or_else(Utc::now),
+                message: commit.summary().unwrap_or("").to_string(),
+                task,
+            });
+        }
+
+        Ok(revisions)
+    }
+
+    /// Committed versions of every task reachable from HEAD, keyed by task ID, oldest first
+    ///
+    /// Same as [`task_history`](Self::task_history) for all tasks, in a single
+    /// pass over the commits.
+    pub fn all_task_histories(&self) -> Result<HashMap<String, Vec<TaskRevision>>> {
+        let mut revwalk = self
+            .repo
+            .revwalk()
+            .context("Failed to create revision walker")?;
+        if revwalk.push_head().is_err() {
+            return Ok(HashMap::new());
+        }
+        revwalk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::REVERSE)?;
+
+        let mut histories: HashMap<String, Vec<TaskRevision>> = HashMap::new();
+        let mut last_blobs: HashMap<String, Oid> = HashMap::new();
+
+        for oid in revwalk {
+            let oid = oid.context("Failed to get commit OID")?;
+            let commit = self.repo.find_commit(oid)?;
+            let tree = commit.tree().context("Failed to read commit tree")?;
+
+            let mut blobs = Vec::new();
+            tree.walk(git2::TreeWalkMode::PreOrder, |dir, entry| {
+                let is_task_dir = dir.starts_with("tasks/")
+                    || dir.contains("/tasks/")
+                    || dir.contains(".taskguard/archive/");
+                if is_task_dir
+                    && let Some(name) = entry.name()
+                    && name.ends_with(".md")
+                {
+                    blobs.push((name.to_string(), entry.id()));
+                }
+                git2::TreeWalkResult::Ok
+            })
+            .context("Failed to walk commit tree")?;
+
+            for (name, blob_id) in blobs {
+                if last_blobs.insert(name, blob_id) == Some(blob_id) {
+                    continue;
+                }
+                let blob = self.repo.find_blob(blob_id)?;
+                let Some(task) = std::str::from_utf8(blob.content())
+                    .ok()
+                    .and_then(|content| Task::parse_content(content).ok())
+                else {
+                    continue;
+                };
+
+                histories
+                    .entry(task.id.clone())
+                    .or_default()
+                    .push(TaskRevision {
+                        oid: oid.to_string(),
+                        author: commit.author().name().unwrap_or("Unknown").to_string(),
+                        timestamp: DateTime::from_timestamp(commit.time().seconds(), 0)
+                            .unwrap_or_else(Utc::now),
+                        message: commit.summary().unwrap_or("").to_string(),
+                        task,
+                    });
+            }
+        }
+
+        Ok(histories)
+    }
+
+    /// Whether a file has staged or unstaged changes relative to HEAD
+    pub fn has_uncommitted_changes(&self, path: &Path) -> bool {
+        let Some(workdir) = self.repo.workdir() else {
+            return false;
+        };
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        let workdir = workdir
+            .canonicalize()
+            .unwrap_or_else(|_| workdir.to_path_buf());
+        let Ok(relative) = path.strip_prefix(&workdir) else {
+            return false;
+        };
+
+        self.repo
+            .status_file(relative)
+            .is_ok_and(|status| !status.is_empty() && !status.is_ignored())
+    }
+
     /// Get list of available remotes
     pub fn get_remotes(&self) -> Result<Vec<String>> {
         Ok(self
@@ -736,4 +2515,97 @@ impl GitAnalyzer {
     }
 }
 
+/// Recent commits reachable from `repo`'s HEAD, newest first, within the
+/// commit count and memory limits
+fn recent_commits(repo: &Repository, limit: usize) -> Result<Vec<Commit<'_>>> {
+    const MAX_COMMITS: usize = 1000; // Maximum commits to process for security
+    const MAX_COMMIT_MESSAGE_SIZE: usize = 64 * 1024; // 64KB max message size
+
+    let mut revwalk = repo.revwalk().context("Failed to create revision walker")?;
+
+    // Handle repositories with no commits (HEAD doesn't exist yet)
+    if revwalk.push_head().is_err() {
+        return Ok(Vec::new()); // Return empty list for repos with no commits
+    }
+
+    // Limit the requested commits to a safe maximum
+    let safe_limit = std::cmp::min(limit, MAX_COMMITS);
+
+    let mut commits = Vec::new();
+    let mut total_memory_used = 0usize;
+    const MAX_TOTAL_MEMORY: usize = 10 * 1024 * 1024; // 10MB total memory limit
+
+    for (i, oid) in revwalk.enumerate() {
+        if i >= safe_limit {
+            break;
+        }
+
+        let oid = oid.context("Failed to get commit OID")?;
+        let commit = repo
+            .find_commit(oid)
+            .with_context(|| format!("Failed to find commit {}", oid))?;
+
+        // Check commit message size for security
+        if let Some(message) = commit.message() {
+            if message.len() > MAX_COMMIT_MESSAGE_SIZE {
+                // Skip commits with excessively large messages
+                continue;
+            }
+            total_memory_used += message.len();
+        }
+
+        // Basic memory usage estimation and limit
+        total_memory_used += 200; // Approximate overhead per commit object
+        if total_memory_used > MAX_TOTAL_MEMORY {
+            break; // Prevent memory exhaustion
+        }
+
+        commits.push(commit);
+    }
+
+    Ok(commits)
+}
+
+/// Changes of a commit against its first parent
+fn commit_diff<'r>(repo: &'r Repository, commit: &Commit) -> Result<Diff<'r>> {
+    let tree = commit.tree()?;
+    let parent_tree = match commit.parent(0) {
+        Ok(parent) => Some(parent.tree()?),
+        Err(_) => None,
+    };
+    repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None)
+        .with_context(|| format!("Failed to diff commit {}", commit.id()))
+}
+
+/// Checked-out submodules of `repo` and of its submodules, with their paths
+/// from the top-level repository
+fn collect_submodules(
+    repo: &Repository,
+    prefix: &str,
+    submodules: &mut Vec<(String, Repository)>,
+) -> Result<()> {
+    for submodule in repo.submodules().context("Failed to list submodules")? {
+        let path = format!("{}{}", prefix, submodule.path().to_string_lossy());
+        let Ok(sub_repo) = submodule.open() else {
+            continue;
+        };
+        collect_submodules(&sub_repo, &format!("{}/", path), submodules)?;
+        submodules.push((path, sub_repo));
+    }
+    Ok(())
+}
+
+/// `user.name` from the git config that applies at `path` (repository, then global)
+pub fn config_user_name<P: AsRef<Path>>(path: P) -> Option<String> {
+    let config = match Repository::discover(path) {
+        Ok(repo) => repo.config().ok()?,
+        Err(_) => git2::Config::open_default().ok()?,
+    };
+    config
+        .get_string("user.name")
+        .ok()
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+}
+
 // Unit tests are in tests/git_analysis_tests.rs
@@ -0,0 +1,104 @@
+//! On-disk cache of parsed commits for `sync`
+//!
+//! Commits are keyed by OID, so each is read and parsed for task references
+//! once. Walks are keyed by the ref they start from: when its tip hasn't moved
+//! since the last run, the walk is served from the cache without touching the
+//! repository. The whole cache is dropped when the taskguard version changes.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+use crate::git::TaskCommit;
+
+/// Cache file, relative to the project root
+pub const CACHE_FILE: &str = ".taskguard/cache/git.json";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct GitCache {
+    version: String,
+    /// Ref name → the last walk from it
+    walks: HashMap<String, CachedWalk>,
+    /// Commit OID → parsed commit, `None` when it references no task
+    commits: HashMap<String, Option<TaskCommit>>,
+    /// Commits served from the cache in this run
+    #[serde(skip)]
+    pub hits: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedWalk {
+    tip: String,
+    limit: usize,
+    /// Commits walked, newest first
+    oids: Vec<String>,
+}
+
+impl GitCache {
+    /// Load the cache, starting empty when it's missing, unreadable or stale
+    pub fn load(root: &Path) -> Self {
+        let version = env!("CARGO_PKG_VERSION").to_string();
+        fs::read_to_string(root.join(CACHE_FILE))
+            .ok()
+            .and_then(|content| serde_json::from_str::<Self>(&content).ok())
+            .filter(|cache| cache.version == version)
+            .unwrap_or_else(|| Self {
+                version,
+                ..Self::default()
+            })
+    }
+
+    /// Task commits of the last walk from `ref_name`, if it started at `tip`
+    /// and went as far
+    pub fn walk(&mut self, ref_name: &str, tip: &str, limit: usize) -> Option<Vec<TaskCommit>> {
+        let walk = self
+            .walks
+            .get(ref_name)
+            .filter(|w| w.tip == tip && w.limit == limit)?;
+        let mut commits = Vec::new();
+        for oid in &walk.oids {
+            commits.extend(self.commits.get(oid)?.clone());
+        }
+        self.hits += walk.oids.len();
+        Some(commits)
+    }
+
+    /// Remember the commits a walk from `ref_name` visited
+    pub fn record_walk(&mut self, ref_name: &str, tip: &str, limit: usize, oids: Vec<String>) {
+        self.walks.insert(
+            ref_name.to_string(),
+            CachedWalk {
+                tip: tip.to_string(),
+                limit,
+                oids,
+            },
+        );
+    }
+
+    /// A commit parsed in an earlier run: `Some(None)` when it references no task
+    pub fn commit(&mut self, oid: &str) -> Option<Option<TaskCommit>> {
+        let commit = self.commits.get(oid)?.clone();
+        self.hits += 1;
+        Some(commit)
+    }
+
+    pub fn insert_commit(&mut self, oid: String, commit: Option<TaskCommit>) {
+        self.commits.insert(oid, commit);
+    }
+
+    /// Write the cache, keeping only commits some walk still visits
+    pub fn save(&mut self, root: &Path) -> Result<()> {
+        let visited: HashSet<&String> = self.walks.values().flat_map(|w| &w.oids).collect();
+        self.commits.retain(|oid, _| visited.contains(oid));
+
+        let path = root.join(CACHE_FILE);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+        fs::write(&path, serde_json::to_string(self)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
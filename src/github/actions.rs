@@ -0,0 +1,78 @@
+//! Reporting to GitHub Actions
+//!
+//! `sync --github --ci` reports through workflow commands: annotations on
+//! stdout, step outputs appended to the file `GITHUB_OUTPUT` names, and a job
+//! summary appended to the one `GITHUB_STEP_SUMMARY` names. Outside Actions
+//! those variables are unset and only the annotations are printed.
+
+use anyhow::{Context, Result};
+use std::env;
+use std::fmt::Write as _;
+use std::fs::OpenOptions;
+use std::io::Write;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    Notice,
+    Warning,
+    Error,
+}
+
+/// Escape workflow command data; properties also escape `:` and `,`
+fn escape(value: &str, property: bool) -> String {
+    let mut escaped = value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A");
+    if property {
+        escaped = escaped.replace(':', "%3A").replace(',', "%2C");
+    }
+    escaped
+}
+
+/// Workflow command annotating the run, e.g. `::warning title=T::message`
+pub fn annotation(level: Level, title: &str, message: &str) -> String {
+    let command = match level {
+        Level::Notice => "notice",
+        Level::Warning => "warning",
+        Level::Error => "error",
+    };
+    format!(
+        "::{} title={}::{}",
+        command,
+        escape(title, true),
+        escape(message, false)
+    )
+}
+
+/// `name=value` lines for `GITHUB_OUTPUT`
+pub fn output_lines(outputs: &[(&str, String)]) -> String {
+    let mut lines = String::new();
+    for (name, value) in outputs {
+        let _ = writeln!(lines, "{}={}", name, value);
+    }
+    lines
+}
+
+fn append(var: &str, content: &str) -> Result<()> {
+    let Some(path) = env::var_os(var) else {
+        return Ok(());
+    };
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("Failed to open {}", var))?;
+    file.write_all(content.as_bytes())
+        .with_context(|| format!("Failed to write {}", var))
+}
+
+/// Set step outputs, when running in Actions
+pub fn set_outputs(outputs: &[(&str, String)]) -> Result<()> {
+    append("GITHUB_OUTPUT", &output_lines(outputs))
+}
+
+/// Add Markdown to the job summary, when running in Actions
+pub fn append_summary(markdown: &str) -> Result<()> {
+    append("GITHUB_STEP_SUMMARY", markdown)
+}
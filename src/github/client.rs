@@ -1,27 +1,39 @@
 use anyhow::{Context, Result};
-use reqwest::blocking::Client;
+use reqwest::StatusCode;
+use reqwest::blocking::{Client, RequestBuilder};
 use serde_json::Value;
+use std::cell::Cell;
 use std::process::Command;
 
+use super::rate_limit::{self, Limits, MAX_RETRIES, MAX_WAIT, RateLimited};
+
+/// Environment variables holding a token for machines without `gh`, such as
+/// CI runners and containers, in order of preference. The API client and
+/// HTTPS fetches both read them.
+pub const TOKEN_VARS: [&str; 3] = ["TASKGUARD_GITHUB_TOKEN", "GITHUB_TOKEN", "GH_TOKEN"];
+
 /// GitHub GraphQL API client
-/// Authentication is handled via `gh` CLI for better UX
+/// Authentication is handled via `gh` CLI for better UX, falling back to a
+/// token from the environment
 pub struct GitHubClient {
     client: Client,
     token: String,
     api_url: String,
+    /// Rate limit reported with the last response
+    limits: Cell<Limits>,
 }
 
 impl GitHubClient {
     /// Create a new GitHub client using `gh` CLI authentication
     ///
-    /// This is the ONLY supported authentication method for simplicity.
-    /// Users must have the `gh` CLI installed and authenticated.
+    /// When `gh` is not installed or not logged in, the first token among
+    /// [`TOKEN_VARS`] is used instead.
     ///
     /// # Errors
     ///
     /// Returns an error if:
-    /// - `gh` CLI is not installed
-    /// - User is not authenticated (needs to run `gh auth login`)
+    /// - `gh` CLI is not installed or the user is not authenticated (needs to
+    ///   run `gh auth login`), and neither token variable is set
     /// - Token retrieval fails
     ///
     /// # Example
@@ -33,34 +45,29 @@ impl GitHubClient {
     /// # Ok::<(), anyhow::Error>(())
     /// ```
     pub fn new() -> Result<Self> {
-        // Get token from `gh auth token` command
-        let output = Command::new("gh")
-            .args(["auth", "token"])
-            .output()
-            .context("Failed to run 'gh auth token'. Is gh CLI installed?")?;
-
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            anyhow::bail!(
-                "GitHub authentication failed.\n\n\
-                Please run: gh auth login\n\n\
-                Error: {}",
-                stderr
-            );
-        }
-
-        let token = String::from_utf8(output.stdout)
-            .context("Invalid UTF-8 in gh token")?
-            .trim()
-            .to_string();
-
-        if token.is_empty() {
-            anyhow::bail!(
-                "No GitHub token found.\n\n\
-                Please run: gh auth login"
-            );
-        }
+        let token = match gh_token() {
+            Ok(token) => token,
+            Err(gh_error) => env_token().ok_or_else(|| {
+                anyhow::anyhow!(
+                    "GitHub authentication failed.\n\n\
+                    Please run: gh auth login\n\
+                    Or set {} (a token with the repo and project scopes)\n\n\
+                    Error: {:#}",
+                    TOKEN_VARS.join(" or "),
+                    gh_error
+                )
+            })?,
+        };
+        Self::with_token(token)
+    }
 
+    /// Create a client authenticated with `token`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client can't be created.
+    pub fn with_token(token: impl Into<String>) -> Result<Self> {
+        let token = token.into();
         let client = Client::builder()
             .user_agent("TaskGuard/0.3.0")
             .build()
@@ -70,6 +77,7 @@ impl GitHubClient {
             client,
             token,
             api_url: "https://api.github.com/graphql".to_string(),
+            limits: Cell::new(Limits::default()),
         })
     }
 
@@ -86,6 +94,7 @@ impl GitHubClient {
     /// - Network request fails
     /// - Response parsing fails
     /// - GraphQL API returns errors
+    /// - The rate limit doesn't reset soon enough ([`RateLimited`])
     ///
     /// # Example
     ///
@@ -106,17 +115,12 @@ impl GitHubClient {
             "variables": variables,
         });
 
-        let response = self
-            .client
-            .post(&self.api_url)
-            .header("Authorization", format!("Bearer {}", self.token))
-            .json(&body)
-            .send()
-            .context("Failed to send GraphQL request")?;
-
-        let json: Value = response
-            .json()
-            .context("Failed to parse GraphQL response")?;
+        let (_, json) = self.send("GraphQL", || {
+            self.client
+                .post(&self.api_url)
+                .header("Authorization", format!("Bearer {}", self.token))
+                .json(&body)
+        })?;
 
         // Check for GraphQL errors
         if let Some(errors) = json.get("errors") {
@@ -125,6 +129,129 @@ impl GitHubClient {
 
         Ok(json)
     }
+
+    /// POST to a REST endpoint, for the few operations GraphQL lacks, such as
+    /// creating milestones
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Endpoint path, e.g. `/repos/owner/repo/milestones`
+    /// * `body` - Request body as JSON value
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Network request fails
+    /// - Response parsing fails
+    /// - GitHub answers with an error status
+    /// - The rate limit doesn't reset soon enough ([`RateLimited`])
+    pub fn rest_post(&self, path: &str, body: &Value) -> Result<Value> {
+        let (status, json) = self.send("REST", || {
+            self.client
+                .post(format!("https://api.github.com{}", path))
+                .header("Authorization", format!("Bearer {}", self.token))
+                .header("Accept", "application/vnd.github+json")
+                .json(body)
+        })?;
+        if !status.is_success() {
+            anyhow::bail!(
+                "GitHub API error ({}): {}",
+                status,
+                json["message"].as_str().unwrap_or("unknown error")
+            );
+        }
+
+        Ok(json)
+    }
+
+    /// Send a request, waiting out rate limits: before sending when the last
+    /// response used the limit up, and between retries of refused requests
+    fn send(
+        &self,
+        kind: &str,
+        request: impl Fn() -> RequestBuilder,
+    ) -> Result<(StatusCode, Value)> {
+        let mut attempt = 0;
+        loop {
+            let limits = self.limits.get();
+            if limits.exhausted() {
+                self.wait(&limits, 0)?;
+            }
+
+            let response = request()
+                .send()
+                .with_context(|| format!("Failed to send {} request", kind))?;
+            let status = response.status();
+            let limits = Limits::from_headers(response.headers());
+            self.limits.set(limits);
+            let json: Value = response
+                .json()
+                .with_context(|| format!("Failed to parse {} response", kind))?;
+
+            if !rate_limit::is_rate_limited(status.as_u16(), &json) {
+                return Ok((status, json));
+            }
+            if attempt == MAX_RETRIES {
+                return Err(RateLimited {
+                    reset_at: limits.reset_at,
+                }
+                .into());
+            }
+            self.wait(&limits, attempt)?;
+            attempt += 1;
+        }
+    }
+
+    /// Sleep as long as `limits` asks before retry number `attempt`, unless
+    /// that's longer than [`MAX_WAIT`]
+    fn wait(&self, limits: &Limits, attempt: u32) -> Result<()> {
+        let wait = limits.wait(attempt, chrono::Utc::now());
+        if wait > MAX_WAIT {
+            return Err(RateLimited {
+                reset_at: limits.reset_at,
+            }
+            .into());
+        }
+        eprintln!(
+            "⏳ GitHub rate limit reached; retrying in {}s",
+            wait.as_secs().max(1)
+        );
+        std::thread::sleep(wait);
+        // Assume the wait restored some points until a response says otherwise
+        self.limits.set(Limits::default());
+        Ok(())
+    }
+}
+
+/// Token from `gh auth token`
+fn gh_token() -> Result<String> {
+    let output = Command::new("gh")
+        .args(["auth", "token"])
+        .output()
+        .context("Failed to run 'gh auth token'. Is gh CLI installed?")?;
+
+    if !output.status.success() {
+        anyhow::bail!("{}", String::from_utf8_lossy(&output.stderr).trim());
+    }
+
+    let token = String::from_utf8(output.stdout)
+        .context("Invalid UTF-8 in gh token")?
+        .trim()
+        .to_string();
+    if token.is_empty() {
+        anyhow::bail!("No GitHub token found");
+    }
+    Ok(token)
+}
+
+/// First non-empty token among [`TOKEN_VARS`]
+pub(crate) fn env_token() -> Option<String> {
+    TOKEN_VARS.iter().find_map(|var| {
+        std::env::var(var)
+            .ok()
+            .map(|token| token.trim().to_string())
+            .filter(|token| !token.is_empty())
+    })
 }
 
 #[cfg(test)]
@@ -79,6 +79,17 @@ pub fn load_github_config() -> Result<GitHubConfig> {
 
     let config: GitHubConfig = toml::from_str(&content).context("Failed to parse GitHub config")?;
 
+    if let Some((area, repo)) = config.routes.iter().find(|(_, repo)| {
+        repo.split_once('/')
+            .is_none_or(|(owner, name)| owner.is_empty() || name.is_empty() || name.contains('/'))
+    }) {
+        anyhow::bail!(
+            "[routes] {} = \"{}\" must name a repository as owner/repo",
+            area,
+            repo
+        );
+    }
+
     Ok(config)
 }
 
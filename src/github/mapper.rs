@@ -1,18 +1,48 @@
+use crate::github::config::load_github_config;
+use crate::github::types::MappingStorage;
 use crate::task::TaskStatus;
+use git2::{ErrorCode, ObjectType, Oid, Repository, Signature};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
+use std::io;
 use std::path::PathBuf;
 
+/// Notes ref holding one mapping per task with `mapping_storage = "notes"`
+pub const NOTES_REF: &str = "refs/notes/taskguard";
+
 /// Represents the mapping between a TaskGuard task and GitHub issue/project item
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct IssueMapping {
     pub task_id: String,
     pub issue_number: i64,
+    /// `[routes]` repository of the issue as `owner/repo`; unset for the
+    /// configured `owner`/`repo`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repo: Option<String>,
     pub issue_id: String,
     pub project_item_id: String,
     pub synced_at: String,
     pub is_archived: bool,
+    /// Synced issue labels when tags and labels last agreed, the base for
+    /// merging changes made on either side
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// Assignee logins when task and issue last agreed, likewise
+    #[serde(default)]
+    pub assignees: Vec<String>,
+    /// Milestone when the task's epic and the issue last agreed, likewise
+    #[serde(default)]
+    pub milestone: Option<String>,
+    /// Values last pushed into project fields, by field name
+    #[serde(default)]
+    pub fields: BTreeMap<String, String>,
+    /// Checked checklist items, by text, when task and issue body last agreed
+    #[serde(default)]
+    pub checked: Vec<String>,
+    /// Hash of the issue description as last written, to tell edits made on GitHub
+    #[serde(default)]
+    pub description_hash: Option<String>,
 }
 
 /// Manages persistent mappings between TaskGuard tasks and GitHub entities
@@ -21,6 +51,9 @@ pub struct TaskIssueMapper {
     mappings: Vec<IssueMapping>,
     #[serde(skip)]
     file_path: Option<PathBuf>,
+    /// Repository whose notes hold the mappings instead of the file
+    #[serde(skip)]
+    notes_repo: Option<PathBuf>,
 }
 
 impl TaskIssueMapper {
@@ -35,13 +68,24 @@ impl TaskIssueMapper {
 
         let file_path = root.join(".taskguard").join("github-mapping.json");
 
+        let storage = load_github_config()
+            .map(|config| config.mapping_storage)
+            .unwrap_or_default();
         let mut mapper = Self {
             mappings: Vec::new(),
             file_path: Some(file_path.clone()),
+            notes_repo: (storage == MappingStorage::Notes).then_some(root),
         };
 
         // Try to load existing mappings
-        if file_path.exists() {
+        if mapper.notes_repo.is_some() {
+            mapper.load()?;
+            // Switching to notes: bring the file's mappings along once
+            if mapper.mappings.is_empty() && file_path.exists() {
+                mapper.load_file()?;
+                mapper.save()?;
+            }
+        } else if file_path.exists() {
             mapper.load()?;
         }
 
@@ -53,11 +97,35 @@ impl TaskIssueMapper {
         Self {
             mappings: Vec::new(),
             file_path: Some(path),
+            notes_repo: None,
+        }
+    }
+
+    /// Create a mapper keeping mappings in the git notes of the repository at `repo_path`
+    pub fn with_notes(repo_path: PathBuf) -> Self {
+        Self {
+            mappings: Vec::new(),
+            file_path: None,
+            notes_repo: Some(repo_path),
         }
     }
 
-    /// Load mappings from the JSON file
+    /// Load mappings from the JSON file, or from git notes
     pub fn load(&mut self) -> Result<(), std::io::Error> {
+        if let Some(repo_path) = &self.notes_repo {
+            let repo = Repository::discover(repo_path).map_err(io::Error::other)?;
+            let mut mappings: Vec<IssueMapping> = read_notes(&repo)?
+                .values()
+                .filter_map(|note| serde_json::from_str(note).ok())
+                .collect();
+            mappings.sort_by(|a, b| a.task_id.cmp(&b.task_id));
+            self.mappings = mappings;
+            return Ok(());
+        }
+        self.load_file()
+    }
+
+    fn load_file(&mut self) -> Result<(), std::io::Error> {
         let path = self
             .file_path
             .as_ref()
@@ -75,8 +143,12 @@ impl TaskIssueMapper {
         Ok(())
     }
 
-    /// Save mappings to the JSON file
+    /// Save mappings to the JSON file, or to git notes
     pub fn save(&self) -> Result<(), std::io::Error> {
+        if let Some(repo_path) = &self.notes_repo {
+            return self.save_notes(repo_path);
+        }
+
         let path = self
             .file_path
             .as_ref()
@@ -92,6 +164,30 @@ impl TaskIssueMapper {
         Ok(())
     }
 
+    /// Write a note for each changed mapping and drop notes of removed ones
+    fn save_notes(&self, repo_path: &PathBuf) -> Result<(), std::io::Error> {
+        let repo = Repository::discover(repo_path).map_err(io::Error::other)?;
+        let signature = repo
+            .signature()
+            .or_else(|_| Signature::now("TaskGuard", "taskguard@localhost"))
+            .map_err(io::Error::other)?;
+
+        let mut existing = read_notes(&repo)?;
+        for mapping in &self.mappings {
+            let target = note_target(&mapping.task_id)?;
+            let note = serde_json::to_string_pretty(mapping)?;
+            if existing.remove(&target).as_ref() != Some(&note) {
+                repo.note(&signature, &signature, Some(NOTES_REF), target, &note, true)
+                    .map_err(io::Error::other)?;
+            }
+        }
+        for target in existing.into_keys() {
+            repo.note_delete(target, Some(NOTES_REF), &signature, &signature)
+                .map_err(io::Error::other)?;
+        }
+        Ok(())
+    }
+
     /// Add a new mapping
     pub fn add_mapping(&mut self, mapping: IssueMapping) -> Result<(), std::io::Error> {
         // Check for duplicates
@@ -143,11 +239,18 @@ impl TaskIssueMapper {
         self.mappings.iter().find(|m| m.task_id == task_id)
     }
 
-    /// Get a mapping by issue number
+    /// Get a mapping by issue number in the configured `owner`/`repo`
     pub fn get_by_issue_number(&self, issue_number: i64) -> Option<&IssueMapping> {
-        self.mappings
-            .iter()
-            .find(|m| m.issue_number == issue_number)
+        self.get_by_issue(None, issue_number)
+    }
+
+    /// Get a mapping by issue number in a `[routes]` repository (`None` for
+    /// the configured `owner`/`repo`)
+    pub fn get_by_issue(&self, repo: Option<&str>, issue_number: i64) -> Option<&IssueMapping> {
+        self.mappings.iter().find(|m| {
+            m.issue_number == issue_number
+                && m.repo.as_deref().map(str::to_lowercase) == repo.map(str::to_lowercase)
+        })
     }
 
     /// Get a mapping by project item ID
@@ -240,6 +343,37 @@ impl TaskIssueMapper {
         None
     }
 
+    /// Three-way merge of local names (tags, assignee logins) with an issue's
+    /// (labels, assignees) from the names both had at the last sync: additions
+    /// and removals on either side are kept. Names compare case-insensitively.
+    pub fn merge_names(base: &[String], local: &[String], remote: &[String]) -> Vec<String> {
+        let contains =
+            |names: &[String], name: &str| names.iter().any(|n| n.eq_ignore_ascii_case(name));
+
+        // Local names, less those removed on GitHub, then those added there
+        let mut merged: Vec<String> = local
+            .iter()
+            .filter(|name| !contains(base, name) || contains(remote, name))
+            .cloned()
+            .collect();
+        for name in remote {
+            if !contains(base, name) && !contains(&merged, name) {
+                merged.push(name.clone());
+            }
+        }
+        merged
+    }
+
+    /// Three-way merge of a single value such as the milestone: a local change
+    /// wins, otherwise GitHub's value is taken
+    pub fn merge_value(
+        base: Option<&str>,
+        local: Option<&str>,
+        remote: Option<&str>,
+    ) -> Option<String> {
+        if local == base { remote } else { local }.map(str::to_string)
+    }
+
     /// Convert a GitHub column name to a TaskGuard status
     ///
     /// Uses pattern matching to handle various common column naming conventions.
@@ -304,6 +438,37 @@ fn get_status_priority_names(status: &TaskStatus) -> Vec<String> {
     }
 }
 
+/// Object a task's note is attached to: the ID of a blob holding the task ID.
+/// The blob is never written; notes don't need their object to exist.
+fn note_target(task_id: &str) -> Result<Oid, std::io::Error> {
+    Oid::hash_object(
+        ObjectType::Blob,
+        format!("taskguard task {}", task_id).as_bytes(),
+    )
+    .map_err(io::Error::other)
+}
+
+/// Notes under [`NOTES_REF`]: annotated object → note
+fn read_notes(repo: &Repository) -> Result<HashMap<Oid, String>, std::io::Error> {
+    let notes = match repo.notes(Some(NOTES_REF)) {
+        Ok(notes) => notes,
+        Err(e) if e.code() == ErrorCode::NotFound => return Ok(HashMap::new()),
+        Err(e) => return Err(io::Error::other(e)),
+    };
+
+    let mut contents = HashMap::new();
+    for entry in notes {
+        let (_, target) = entry.map_err(io::Error::other)?;
+        let note = repo
+            .find_note(Some(NOTES_REF), target)
+            .map_err(io::Error::other)?;
+        if let Some(message) = note.message() {
+            contents.insert(target, message.to_string());
+        }
+    }
+    Ok(contents)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -314,10 +479,17 @@ mod tests {
         IssueMapping {
             task_id: task_id.to_string(),
             issue_number,
+            repo: None,
             issue_id: format!("issue_{}", issue_number),
             project_item_id: format!("item_{}", issue_number),
             synced_at: Utc::now().to_rfc3339(),
             is_archived: false,
+            labels: Vec::new(),
+            assignees: Vec::new(),
+            milestone: None,
+            fields: BTreeMap::new(),
+            checked: Vec::new(),
+            description_hash: None,
         }
     }
 
@@ -1,7 +1,7 @@
 //! GitHub integration module for TaskGuard
 //!
 //! This module provides GitHub Projects v2 integration with focus on:
-//! - GraphQL API client with `gh` CLI authentication
+//! - GraphQL API client with `gh` CLI or token authentication
 //! - Type-safe representations of GitHub entities (Issues, Projects v2)
 //! - Configuration management (no token storage - uses `gh` CLI)
 //!
@@ -12,6 +12,9 @@
 //! 2. Authenticate: `gh auth login`
 //! 3. Ensure proper scopes: `gh auth refresh -s project`
 //!
+//! Without `gh`, a token in `TASKGUARD_GITHUB_TOKEN`, `GITHUB_TOKEN` or
+//! `GH_TOKEN` is used.
+//!
 //! # Configuration
 //!
 //! Create `.taskguard/github.toml`:
@@ -39,21 +42,28 @@
 //! # Ok::<(), anyhow::Error>(())
 //! ```
 
+pub mod actions;
 pub mod client;
 pub mod config;
 pub mod mapper;
 pub mod mutations;
+pub mod plan;
 pub mod queries;
+pub mod rate_limit;
 pub mod setup;
 pub mod types;
+pub mod webhook;
 
 // Re-export commonly used items
-pub use client::GitHubClient;
+pub use client::{GitHubClient, TOKEN_VARS};
 pub use config::{get_github_config_path, is_github_sync_enabled, load_github_config};
 pub use mapper::{IssueMapping, TaskIssueMapper};
 pub use mutations::GitHubMutations;
-pub use queries::{GitHubQueries, ProjectField};
+pub use plan::{PlanAction, PlanEntry, SyncPlan};
+pub use queries::{GitHubQueries, ProjectField, ProjectIteration};
+pub use rate_limit::{RateLimited, ResumeState};
 pub use setup::GitHubProjectSetup;
 pub use types::{
-    FieldValue, GitHubConfig, GitHubIssue, ProjectItem, ProjectV2StatusUpdate, TaskMapping,
+    CommentSync, FieldValue, GitHubConfig, GitHubIssue, IssueComment, LabelConfig, MappingStorage,
+    ProjectItem, ProjectV2StatusUpdate, TaskMapping,
 };
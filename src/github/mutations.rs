@@ -1,7 +1,7 @@
 //! GitHub GraphQL mutations for creating and updating issues and project items
 //!
 //! This module provides functions to:
-//! - Create and update GitHub issues
+//! - Create and update GitHub issues and comment on them
 //! - Add issues to GitHub Projects v2 boards
 //! - Update project item status fields
 //! - Query project field information for status updates
@@ -64,7 +64,7 @@
 //! ```
 
 use anyhow::{Context, Result};
-use serde_json::json;
+use serde_json::{Value, json};
 
 use super::client::GitHubClient;
 use super::types::GitHubIssue;
@@ -146,6 +146,9 @@ impl GitHubMutations {
                                 login
                             }
                         }
+                        milestone {
+                            title
+                        }
                     }
                 }
             }
@@ -203,6 +206,9 @@ impl GitHubMutations {
             body: issue_data["body"].as_str().map(|s| s.to_string()),
             labels,
             assignees,
+            milestone: issue_data["milestone"]["title"]
+                .as_str()
+                .map(str::to_string),
         })
     }
 
@@ -360,6 +366,389 @@ impl GitHubMutations {
         Ok(())
     }
 
+    /// Add a comment to an issue
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Authenticated GitHub client
+    /// * `issue_id` - GraphQL node ID of the issue
+    /// * `body` - Comment text (markdown)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Issue not found
+    /// - Network request fails
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use taskguard::github::{GitHubClient, GitHubMutations};
+    ///
+    /// let client = GitHubClient::new()?;
+    /// GitHubMutations::add_issue_comment(&client, "issue_id", "Blocked on infra ticket")?;
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn add_issue_comment(client: &GitHubClient, issue_id: &str, body: &str) -> Result<()> {
+        let mutation = r#"
+            mutation($subjectId: ID!, $body: String!) {
+                addComment(input: {
+                    subjectId: $subjectId,
+                    body: $body
+                }) {
+                    commentEdge { node { id } }
+                }
+            }
+        "#;
+
+        let variables = json!({
+            "subjectId": issue_id,
+            "body": body,
+        });
+
+        client
+            .query(mutation, variables)
+            .context("Failed to add issue comment")?;
+
+        Ok(())
+    }
+
+    /// Create a label in a repository
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Authenticated GitHub client
+    /// * `owner` - Repository owner (username or organization)
+    /// * `repo` - Repository name
+    /// * `name` - Label name
+    /// * `color` - Hex color without `#`, e.g. `d73a4a`
+    ///
+    /// # Returns
+    ///
+    /// The GraphQL node ID of the new label
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - A label with that name already exists
+    /// - User lacks write permissions
+    /// - Network request fails
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use taskguard::github::{GitHubClient, GitHubMutations};
+    ///
+    /// let client = GitHubClient::new()?;
+    /// let label_id = GitHubMutations::create_label(&client, "owner", "repo", "security", "d73a4a")?;
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn create_label(
+        client: &GitHubClient,
+        owner: &str,
+        repo: &str,
+        name: &str,
+        color: &str,
+    ) -> Result<String> {
+        let repo_id = Self::get_repository_id(client, owner, repo)?;
+
+        let mutation = r"
+            mutation($repositoryId: ID!, $name: String!, $color: String!) {
+                createLabel(input: {
+                    repositoryId: $repositoryId,
+                    name: $name,
+                    color: $color
+                }) {
+                    label { id }
+                }
+            }
+        ";
+
+        let variables = json!({
+            "repositoryId": repo_id,
+            "name": name,
+            "color": color,
+        });
+
+        let response = client
+            .query(mutation, variables)
+            .with_context(|| format!("Failed to create label '{}'", name))?;
+
+        let label_id = response["data"]["createLabel"]["label"]["id"]
+            .as_str()
+            .context("Missing label ID in response")?;
+
+        Ok(label_id.to_string())
+    }
+
+    /// Add labels to an issue
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Authenticated GitHub client
+    /// * `issue_id` - GraphQL node ID of the issue
+    /// * `label_ids` - GraphQL node IDs of the labels
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use taskguard::github::{GitHubClient, GitHubMutations};
+    ///
+    /// let client = GitHubClient::new()?;
+    /// GitHubMutations::add_labels(&client, "issue_id", &["label_id".to_string()])?;
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn add_labels(client: &GitHubClient, issue_id: &str, label_ids: &[String]) -> Result<()> {
+        let mutation = r"
+            mutation($labelableId: ID!, $labelIds: [ID!]!) {
+                addLabelsToLabelable(input: {
+                    labelableId: $labelableId,
+                    labelIds: $labelIds
+                }) {
+                    clientMutationId
+                }
+            }
+        ";
+
+        let variables = json!({
+            "labelableId": issue_id,
+            "labelIds": label_ids,
+        });
+
+        client
+            .query(mutation, variables)
+            .context("Failed to add labels")?;
+
+        Ok(())
+    }
+
+    /// Remove labels from an issue
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Authenticated GitHub client
+    /// * `issue_id` - GraphQL node ID of the issue
+    /// * `label_ids` - GraphQL node IDs of the labels
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use taskguard::github::{GitHubClient, GitHubMutations};
+    ///
+    /// let client = GitHubClient::new()?;
+    /// GitHubMutations::remove_labels(&client, "issue_id", &["label_id".to_string()])?;
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn remove_labels(
+        client: &GitHubClient,
+        issue_id: &str,
+        label_ids: &[String],
+    ) -> Result<()> {
+        let mutation = r"
+            mutation($labelableId: ID!, $labelIds: [ID!]!) {
+                removeLabelsFromLabelable(input: {
+                    labelableId: $labelableId,
+                    labelIds: $labelIds
+                }) {
+                    clientMutationId
+                }
+            }
+        ";
+
+        let variables = json!({
+            "labelableId": issue_id,
+            "labelIds": label_ids,
+        });
+
+        client
+            .query(mutation, variables)
+            .context("Failed to remove labels")?;
+
+        Ok(())
+    }
+
+    /// Create a milestone in a repository
+    ///
+    /// GraphQL has no mutation for this, so it goes through the REST API.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Authenticated GitHub client
+    /// * `owner` - Repository owner (username or organization)
+    /// * `repo` - Repository name
+    /// * `title` - Milestone title
+    ///
+    /// # Returns
+    ///
+    /// The GraphQL node ID of the new milestone
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - A milestone with that title already exists
+    /// - User lacks write permissions
+    /// - Network request fails
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use taskguard::github::{GitHubClient, GitHubMutations};
+    ///
+    /// let client = GitHubClient::new()?;
+    /// let milestone_id = GitHubMutations::create_milestone(&client, "owner", "repo", "v1.0")?;
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn create_milestone(
+        client: &GitHubClient,
+        owner: &str,
+        repo: &str,
+        title: &str,
+    ) -> Result<String> {
+        let response = client
+            .rest_post(
+                &format!("/repos/{}/{}/milestones", owner, repo),
+                &json!({ "title": title }),
+            )
+            .with_context(|| format!("Failed to create milestone '{}'", title))?;
+
+        let milestone_id = response["node_id"]
+            .as_str()
+            .context("Missing milestone ID in response")?;
+
+        Ok(milestone_id.to_string())
+    }
+
+    /// Set or clear an issue's milestone
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Authenticated GitHub client
+    /// * `issue_id` - GraphQL node ID of the issue
+    /// * `milestone_id` - GraphQL node ID of the milestone, `None` to clear it
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use taskguard::github::{GitHubClient, GitHubMutations};
+    ///
+    /// let client = GitHubClient::new()?;
+    /// GitHubMutations::update_issue_milestone(&client, "issue_id", Some("milestone_id"))?;
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn update_issue_milestone(
+        client: &GitHubClient,
+        issue_id: &str,
+        milestone_id: Option<&str>,
+    ) -> Result<()> {
+        let mutation = r"
+            mutation($issueId: ID!, $milestoneId: ID) {
+                updateIssue(input: {
+                    id: $issueId,
+                    milestoneId: $milestoneId
+                }) {
+                    issue { id }
+                }
+            }
+        ";
+
+        let variables = json!({
+            "issueId": issue_id,
+            "milestoneId": milestone_id,
+        });
+
+        client
+            .query(mutation, variables)
+            .context("Failed to update issue milestone")?;
+
+        Ok(())
+    }
+
+    /// Assign users to an issue
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Authenticated GitHub client
+    /// * `issue_id` - GraphQL node ID of the issue
+    /// * `user_ids` - GraphQL node IDs of the users
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use taskguard::github::{GitHubClient, GitHubMutations};
+    ///
+    /// let client = GitHubClient::new()?;
+    /// GitHubMutations::add_assignees(&client, "issue_id", &["user_id".to_string()])?;
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn add_assignees(client: &GitHubClient, issue_id: &str, user_ids: &[String]) -> Result<()> {
+        let mutation = r"
+            mutation($assignableId: ID!, $assigneeIds: [ID!]!) {
+                addAssigneesToAssignable(input: {
+                    assignableId: $assignableId,
+                    assigneeIds: $assigneeIds
+                }) {
+                    clientMutationId
+                }
+            }
+        ";
+
+        let variables = json!({
+            "assignableId": issue_id,
+            "assigneeIds": user_ids,
+        });
+
+        client
+            .query(mutation, variables)
+            .context("Failed to add assignees")?;
+
+        Ok(())
+    }
+
+    /// Unassign users from an issue
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Authenticated GitHub client
+    /// * `issue_id` - GraphQL node ID of the issue
+    /// * `user_ids` - GraphQL node IDs of the users
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use taskguard::github::{GitHubClient, GitHubMutations};
+    ///
+    /// let client = GitHubClient::new()?;
+    /// GitHubMutations::remove_assignees(&client, "issue_id", &["user_id".to_string()])?;
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn remove_assignees(
+        client: &GitHubClient,
+        issue_id: &str,
+        user_ids: &[String],
+    ) -> Result<()> {
+        let mutation = r"
+            mutation($assignableId: ID!, $assigneeIds: [ID!]!) {
+                removeAssigneesFromAssignable(input: {
+                    assignableId: $assignableId,
+                    assigneeIds: $assigneeIds
+                }) {
+                    clientMutationId
+                }
+            }
+        ";
+
+        let variables = json!({
+            "assignableId": issue_id,
+            "assigneeIds": user_ids,
+        });
+
+        client
+            .query(mutation, variables)
+            .context("Failed to remove assignees")?;
+
+        Ok(())
+    }
+
     // ========================================
     // PROJECTS V2 MUTATIONS (HIGH PRIORITY)
     // ========================================
@@ -492,6 +881,51 @@ impl GitHubMutations {
         item_id: &str,
         field_id: &str,
         option_id: &str,
+    ) -> Result<()> {
+        Self::update_project_item_field(
+            client,
+            project_id,
+            item_id,
+            field_id,
+            &json!({ "singleSelectOptionId": option_id }),
+        )
+        .context("Failed to update project item status")
+    }
+
+    /// Set a custom field of a project item
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Authenticated GitHub client
+    /// * `project_id` - GraphQL node ID of the Projects v2 board
+    /// * `item_id` - GraphQL node ID of the project item
+    /// * `field_id` - GraphQL node ID of the field
+    /// * `value` - `ProjectV2FieldValue`, as built by [`ProjectField::value_for`]
+    ///
+    /// [`ProjectField::value_for`]: super::ProjectField::value_for
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use serde_json::json;
+    /// use taskguard::github::{GitHubClient, GitHubMutations};
+    ///
+    /// let client = GitHubClient::new()?;
+    /// GitHubMutations::update_project_item_field(
+    ///     &client,
+    ///     "project_id",
+    ///     "item_id",
+    ///     "field_id",
+    ///     &json!({ "number": 5 }),
+    /// )?;
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn update_project_item_field(
+        client: &GitHubClient,
+        project_id: &str,
+        item_id: &str,
+        field_id: &str,
+        value: &Value,
     ) -> Result<()> {
         let mutation = r#"
             mutation($projectId: ID!, $itemId: ID!, $fieldId: ID!, $value: ProjectV2FieldValue!) {
@@ -512,14 +946,63 @@ impl GitHubMutations {
             "projectId": project_id,
             "itemId": item_id,
             "fieldId": field_id,
-            "value": {
-                "singleSelectOptionId": option_id
+            "value": value,
+        });
+
+        client
+            .query(mutation, variables)
+            .context("Failed to update project item field")?;
+
+        Ok(())
+    }
+
+    /// Clear a custom field of a project item
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Authenticated GitHub client
+    /// * `project_id` - GraphQL node ID of the Projects v2 board
+    /// * `item_id` - GraphQL node ID of the project item
+    /// * `field_id` - GraphQL node ID of the field
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use taskguard::github::{GitHubClient, GitHubMutations};
+    ///
+    /// let client = GitHubClient::new()?;
+    /// GitHubMutations::clear_project_item_field(&client, "project_id", "item_id", "field_id")?;
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn clear_project_item_field(
+        client: &GitHubClient,
+        project_id: &str,
+        item_id: &str,
+        field_id: &str,
+    ) -> Result<()> {
+        let mutation = r"
+            mutation($projectId: ID!, $itemId: ID!, $fieldId: ID!) {
+                clearProjectV2ItemFieldValue(input: {
+                    projectId: $projectId,
+                    itemId: $itemId,
+                    fieldId: $fieldId
+                }) {
+                    projectV2Item {
+                        id
+                    }
+                }
             }
+        ";
+
+        let variables = json!({
+            "projectId": project_id,
+            "itemId": item_id,
+            "fieldId": field_id,
         });
 
         client
             .query(mutation, variables)
-            .context("Failed to update project item status")?;
+            .context("Failed to clear project item field")?;
 
         Ok(())
     }
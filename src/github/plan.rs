@@ -0,0 +1,166 @@
+//! What `sync --github` would push, as data
+//!
+//! `sync --github --dry-run --format table|json` prints a [`SyncPlan`]
+//! instead of narrating the push. A plan saved as JSON can be reviewed and
+//! handed back with `sync --github --apply-plan`, which pushes only the tasks
+//! whose entries still match what a fresh plan says.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Write as _};
+use std::fs;
+use std::path::Path;
+
+/// Changes only when fields are removed or renamed
+pub const PLAN_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlanAction {
+    /// Open an issue for the task
+    Create,
+    /// Bring the task's issue up to date
+    Update,
+    /// Map an issue synced from another branch to the task
+    Adopt,
+    /// Needs a decision before it can be pushed
+    Conflict,
+}
+
+impl fmt::Display for PlanAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PlanAction::Create => "create",
+            PlanAction::Update => "update",
+            PlanAction::Adopt => "adopt",
+            PlanAction::Conflict => "conflict",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlanEntry {
+    pub task_id: String,
+    pub title: String,
+    pub action: PlanAction,
+    /// Repository the issue is or would be in, `owner/repo`
+    pub repo: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<i64>,
+    /// What would change, or why the entry conflicts
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncPlan {
+    pub version: u32,
+    pub created_at: DateTime<Utc>,
+    pub entries: Vec<PlanEntry>,
+    /// Tasks already in sync
+    #[serde(default)]
+    pub unchanged: usize,
+}
+
+impl Default for SyncPlan {
+    fn default() -> Self {
+        Self {
+            version: PLAN_VERSION,
+            created_at: Utc::now(),
+            entries: Vec::new(),
+            unchanged: 0,
+        }
+    }
+}
+
+impl SyncPlan {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read plan {}", path.display()))?;
+        let plan: Self = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse plan {}", path.display()))?;
+        if plan.version != PLAN_VERSION {
+            anyhow::bail!(
+                "Plan {} has version {}, expected {}; make a new one with 'taskguard sync --github --dry-run --format json'",
+                path.display(),
+                plan.version,
+                PLAN_VERSION
+            );
+        }
+        Ok(plan)
+    }
+
+    pub fn entry(&self, task_id: &str) -> Option<&PlanEntry> {
+        self.entries.iter().find(|e| e.task_id == task_id)
+    }
+
+    /// Number of entries with `action`
+    pub fn count(&self, action: PlanAction) -> usize {
+        self.entries.iter().filter(|e| e.action == action).count()
+    }
+
+    /// The plan as an aligned table, followed by totals
+    pub fn render_table(&self) -> String {
+        let mut table = String::new();
+        if self.entries.is_empty() {
+            let _ = writeln!(
+                table,
+                "   Nothing to push ({} tasks in sync)",
+                self.unchanged
+            );
+            return table;
+        }
+
+        let issue = |entry: &PlanEntry| {
+            entry
+                .issue
+                .map_or_else(|| "-".to_string(), |number| format!("#{}", number))
+        };
+        let task_width = self
+            .entries
+            .iter()
+            .map(|e| e.task_id.len())
+            .max()
+            .unwrap_or(0)
+            .max("TASK".len());
+        let repo_width = self
+            .entries
+            .iter()
+            .map(|e| e.repo.len())
+            .max()
+            .unwrap_or(0)
+            .max("REPO".len());
+        let _ = writeln!(
+            table,
+            "   {:<task_width$}  {:<8}  {:<6}  {:<repo_width$}  CHANGES",
+            "TASK", "ACTION", "ISSUE", "REPO"
+        );
+        for entry in &self.entries {
+            let changes = if entry.changes.is_empty() {
+                entry.title.clone()
+            } else {
+                entry.changes.join(", ")
+            };
+            let _ = writeln!(
+                table,
+                "   {:<task_width$}  {:<8}  {:<6}  {:<repo_width$}  {}",
+                entry.task_id,
+                entry.action.to_string(),
+                issue(entry),
+                entry.repo,
+                changes
+            );
+        }
+        let _ = writeln!(
+            table,
+            "\n   {} to create, {} to update, {} to adopt, {} conflicts, {} unchanged",
+            self.count(PlanAction::Create),
+            self.count(PlanAction::Update),
+            self.count(PlanAction::Adopt),
+            self.count(PlanAction::Conflict),
+            self.unchanged
+        );
+        table
+    }
+}
@@ -43,10 +43,11 @@
 //! ```
 
 use anyhow::{Context, Result};
-use serde_json::json;
+use chrono::NaiveDate;
+use serde_json::{Value, json};
 
 use super::client::GitHubClient;
-use super::types::{FieldValue, GitHubIssue, ProjectItem};
+use super::types::{FieldValue, GitHubIssue, IssueComment, ProjectItem};
 
 /// Project field definition
 #[derive(Debug, Clone)]
@@ -59,6 +60,65 @@ pub struct ProjectField {
     pub data_type: String,
     /// Available options (for single-select fields)
     pub options: Vec<(String, String)>, // (id, name) pairs
+    /// Iterations, current and completed (for iteration fields)
+    pub iterations: Vec<ProjectIteration>,
+}
+
+/// One iteration of a project's iteration field
+#[derive(Debug, Clone)]
+pub struct ProjectIteration {
+    /// Iteration ID
+    pub id: String,
+    /// Iteration title (e.g., "Sprint 4")
+    pub title: String,
+    /// First day of the iteration
+    pub start_date: NaiveDate,
+    /// Length in days
+    pub duration: i64,
+}
+
+impl ProjectIteration {
+    /// Whether `date` falls within the iteration
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        date >= self.start_date && (date - self.start_date).num_days() < self.duration
+    }
+}
+
+impl ProjectField {
+    /// `ProjectV2FieldValue` setting this field to `value`: the option named
+    /// `value` (ignoring case) for a single-select field, its leading number
+    /// for a number field (`4h` → 4), the text itself for a text field. A
+    /// `YYYY-MM-DD` value sets a date field, or an iteration field to the
+    /// iteration containing that day. `None` when the field can't hold the value.
+    pub fn value_for(&self, value: &str) -> Option<Value> {
+        match self.data_type.as_str() {
+            "SINGLE_SELECT" => self
+                .options
+                .iter()
+                .find(|(_, name)| name.eq_ignore_ascii_case(value))
+                .map(|(id, _)| json!({ "singleSelectOptionId": id })),
+            "NUMBER" => {
+                let end = value
+                    .find(|c: char| !c.is_ascii_digit() && c != '.')
+                    .unwrap_or(value.len());
+                let number: f64 = value[..end].parse().ok()?;
+                Some(json!({ "number": number }))
+            }
+            "TEXT" => Some(json!({ "text": value })),
+            "DATE" => {
+                let date = NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()?;
+                Some(json!({ "date": date.to_string() }))
+            }
+            "ITERATION" => {
+                let date = NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()?;
+                self.iterations
+                    .iter()
+                    .find(|iteration| iteration.contains(date))
+                    .map(|iteration| json!({ "iterationId": iteration.id }))
+            }
+            _ => None,
+        }
+    }
 }
 
 /// GitHub queries for issues and Projects v2
@@ -125,6 +185,9 @@ impl GitHubQueries {
                                     login
                                 }
                             }
+                            milestone {
+                                title
+                            }
                         }
                     }
                 }
@@ -174,6 +237,7 @@ impl GitHubQueries {
                     body: node["body"].as_str().map(|s| s.to_string()),
                     labels,
                     assignees,
+                    milestone: node["milestone"]["title"].as_str().map(str::to_string),
                 })
             })
             .collect();
@@ -235,6 +299,9 @@ impl GitHubQueries {
                                 login
                             }
                         }
+                        milestone {
+                            title
+                        }
                     }
                 }
             }
@@ -291,6 +358,9 @@ impl GitHubQueries {
             body: issue_data["body"].as_str().map(|s| s.to_string()),
             labels,
             assignees,
+            milestone: issue_data["milestone"]["title"]
+                .as_str()
+                .map(str::to_string),
         })
     }
 
@@ -341,6 +411,9 @@ impl GitHubQueries {
                                 login
                             }
                         }
+                        milestone {
+                            title
+                        }
                     }
                 }
             }
@@ -388,9 +461,256 @@ impl GitHubQueries {
             body: node["body"].as_str().map(|s| s.to_string()),
             labels,
             assignees,
+            milestone: node["milestone"]["title"].as_str().map(str::to_string),
         })
     }
 
+    /// Get an issue's comments with their authors, oldest first
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Authenticated GitHub client
+    /// * `issue_id` - GraphQL node ID of the issue
+    ///
+    /// # Returns
+    ///
+    /// Every comment, oldest first, fetched 100 at a time
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Issue not found
+    /// - Network request fails
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use taskguard::github::{GitHubClient, GitHubQueries};
+    ///
+    /// let client = GitHubClient::new()?;
+    /// let comments = GitHubQueries::get_issue_comments(&client, "I_node_id")?;
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn get_issue_comments(client: &GitHubClient, issue_id: &str) -> Result<Vec<IssueComment>> {
+        let query = r#"
+            query($id: ID!, $after: String) {
+                node(id: $id) {
+                    ... on Issue {
+                        comments(first: 100, after: $after) {
+                            nodes {
+                                body
+                                createdAt
+                                author {
+                                    login
+                                }
+                            }
+                            pageInfo {
+                                hasNextPage
+                                endCursor
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let mut comments = Vec::new();
+        let mut after: Option<String> = None;
+        loop {
+            let variables = json!({ "id": issue_id, "after": after });
+
+            let response = client
+                .query(query, variables)
+                .context("Failed to get issue comments")?;
+
+            let page = &response["data"]["node"]["comments"];
+            let nodes = page["nodes"].as_array().context("Issue not found")?;
+            comments.extend(nodes.iter().filter_map(|comment| {
+                Some(IssueComment {
+                    author: comment["author"]["login"]
+                        .as_str()
+                        .unwrap_or_default()
+                        .to_string(),
+                    created_at: comment["createdAt"].as_str()?.to_string(),
+                    body: comment["body"].as_str()?.to_string(),
+                })
+            }));
+
+            after = page["pageInfo"]["endCursor"]
+                .as_str()
+                .filter(|_| page["pageInfo"]["hasNextPage"].as_bool() == Some(true))
+                .map(str::to_string);
+            if after.is_none() {
+                return Ok(comments);
+            }
+        }
+    }
+
+    /// Get a repository's labels
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Authenticated GitHub client
+    /// * `owner` - Repository owner (username or organization)
+    /// * `repo` - Repository name
+    ///
+    /// # Returns
+    ///
+    /// (label ID, label name) pairs for up to 100 labels
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use taskguard::github::{GitHubClient, GitHubQueries};
+    ///
+    /// let client = GitHubClient::new()?;
+    /// let labels = GitHubQueries::get_repository_labels(&client, "owner", "repo")?;
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn get_repository_labels(
+        client: &GitHubClient,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<(String, String)>> {
+        let query = r"
+            query($owner: String!, $name: String!) {
+                repository(owner: $owner, name: $name) {
+                    labels(first: 100) {
+                        nodes {
+                            id
+                            name
+                        }
+                    }
+                }
+            }
+        ";
+
+        let variables = json!({
+            "owner": owner,
+            "name": repo,
+        });
+
+        let response = client
+            .query(query, variables)
+            .context("Failed to get repository labels")?;
+
+        let nodes = response["data"]["repository"]["labels"]["nodes"]
+            .as_array()
+            .context("Invalid labels response")?;
+
+        Ok(nodes
+            .iter()
+            .filter_map(|label| {
+                Some((
+                    label["id"].as_str()?.to_string(),
+                    label["name"].as_str()?.to_string(),
+                ))
+            })
+            .collect())
+    }
+
+    /// Get a repository's milestones, open and closed
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Authenticated GitHub client
+    /// * `owner` - Repository owner (username or organization)
+    /// * `repo` - Repository name
+    ///
+    /// # Returns
+    ///
+    /// (milestone ID, title) pairs for up to 100 milestones
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use taskguard::github::{GitHubClient, GitHubQueries};
+    ///
+    /// let client = GitHubClient::new()?;
+    /// let milestones = GitHubQueries::get_milestones(&client, "owner", "repo")?;
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn get_milestones(
+        client: &GitHubClient,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<(String, String)>> {
+        let query = r"
+            query($owner: String!, $name: String!) {
+                repository(owner: $owner, name: $name) {
+                    milestones(first: 100, states: [OPEN, CLOSED]) {
+                        nodes {
+                            id
+                            title
+                        }
+                    }
+                }
+            }
+        ";
+
+        let variables = json!({
+            "owner": owner,
+            "name": repo,
+        });
+
+        let response = client
+            .query(query, variables)
+            .context("Failed to get milestones")?;
+
+        let nodes = response["data"]["repository"]["milestones"]["nodes"]
+            .as_array()
+            .context("Invalid milestones response")?;
+
+        Ok(nodes
+            .iter()
+            .filter_map(|milestone| {
+                Some((
+                    milestone["id"].as_str()?.to_string(),
+                    milestone["title"].as_str()?.to_string(),
+                ))
+            })
+            .collect())
+    }
+
+    /// Get a user's GraphQL node ID from their login
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - No user has that login
+    /// - Network request fails
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use taskguard::github::{GitHubClient, GitHubQueries};
+    ///
+    /// let client = GitHubClient::new()?;
+    /// let user_id = GitHubQueries::get_user_id(&client, "octocat")?;
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn get_user_id(client: &GitHubClient, login: &str) -> Result<String> {
+        let query = r"
+            query($login: String!) {
+                user(login: $login) {
+                    id
+                }
+            }
+        ";
+
+        let variables = json!({ "login": login });
+
+        let response = client
+            .query(query, variables)
+            .with_context(|| format!("Failed to look up GitHub user '{}'", login))?;
+
+        let user_id = response["data"]["user"]["id"]
+            .as_str()
+            .with_context(|| format!("GitHub user '{}' not found", login))?;
+
+        Ok(user_id.to_string())
+    }
+
     /// Helper: Convert issue number to GraphQL node ID
     ///
     /// # Arguments
@@ -474,6 +794,9 @@ impl GitHubQueries {
                                     login
                                 }
                             }
+                            milestone {
+                                title
+                            }
                         }
                     }
                 }
@@ -530,6 +853,7 @@ impl GitHubQueries {
                     body: node["body"].as_str().map(|s| s.to_string()),
                     labels,
                     assignees,
+                    milestone: node["milestone"]["title"].as_str().map(str::to_string),
                 })
             })
             .collect();
@@ -595,6 +919,25 @@ impl GitHubQueries {
                                         name
                                     }
                                 }
+                                ... on ProjectV2IterationField {
+                                    id
+                                    name
+                                    dataType
+                                    configuration {
+                                        iterations {
+                                            id
+                                            title
+                                            startDate
+                                            duration
+                                        }
+                                        completedIterations {
+                                            id
+                                            title
+                                            startDate
+                                            duration
+                                        }
+                                    }
+                                }
                             }
                         }
                     }
@@ -631,11 +974,32 @@ impl GitHubQueries {
                     Vec::new()
                 };
 
+                // Extract iterations if this is an iteration field
+                let configuration = &node["configuration"];
+                let iterations = ["iterations", "completedIterations"]
+                    .iter()
+                    .filter_map(|key| configuration[key].as_array())
+                    .flatten()
+                    .filter_map(|iteration| {
+                        Some(ProjectIteration {
+                            id: iteration["id"].as_str()?.to_string(),
+                            title: iteration["title"].as_str()?.to_string(),
+                            start_date: NaiveDate::parse_from_str(
+                                iteration["startDate"].as_str()?,
+                                "%Y-%m-%d",
+                            )
+                            .ok()?,
+                            duration: iteration["duration"].as_i64()?,
+                        })
+                    })
+                    .collect();
+
                 Some(ProjectField {
                     id,
                     name,
                     data_type,
                     options,
+                    iterations,
                 })
             })
             .collect();
@@ -704,6 +1068,24 @@ impl GitHubQueries {
                                         }
                                     }
                                 }
+                                ... on ProjectV2ItemFieldDateValue {
+                                    date
+                                    field {
+                                        ... on ProjectV2Field {
+                                            id
+                                            name
+                                        }
+                                    }
+                                }
+                                ... on ProjectV2ItemFieldIterationValue {
+                                    title
+                                    field {
+                                        ... on ProjectV2IterationField {
+                                            id
+                                            name
+                                        }
+                                    }
+                                }
                             }
                         }
                     }
@@ -743,11 +1125,15 @@ impl GitHubQueries {
                 let field_id = field["id"].as_str().unwrap_or("").to_string();
                 let field_name = field["name"].as_str().unwrap_or("");
 
-                // Get the value (either name for single-select or text for text fields)
+                // Get the value: name for single-select, text, date or iteration title
                 let value = if let Some(name) = node["name"].as_str() {
                     name.to_string()
                 } else if let Some(text) = node["text"].as_str() {
                     text.to_string()
+                } else if let Some(date) = node["date"].as_str() {
+                    date.to_string()
+                } else if let Some(title) = node["title"].as_str() {
+                    title.to_string()
                 } else {
                     continue;
                 };
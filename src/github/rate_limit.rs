@@ -0,0 +1,158 @@
+//! Riding out GitHub rate limits
+//!
+//! Each API response reports how many points of the hourly limit remain and
+//! when it resets (the same data as GraphQL's `rateLimit` object). Secondary
+//! limits answer 403 or 429, often with a `Retry-After`. Short waits are slept
+//! through, retrying with exponential backoff; a wait too long for that fails
+//! the request with [`RateLimited`], so `sync --github` can stop cleanly and
+//! record in a [`ResumeState`] which tasks it didn't get to.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+/// Retries of a rate-limited request before giving up
+pub const MAX_RETRIES: u32 = 5;
+
+/// Longest wait slept through; beyond it the request fails with [`RateLimited`]
+pub const MAX_WAIT: Duration = Duration::from_mins(2);
+
+/// First backoff delay, doubled on each retry
+const BASE_DELAY: Duration = Duration::from_secs(2);
+
+/// Resume state file, relative to the project root
+pub const RESUME_FILE: &str = ".taskguard/state/github-resume.json";
+
+/// A request GitHub kept rate-limiting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimited {
+    /// When the limit resets, if GitHub said
+    pub reset_at: Option<DateTime<Utc>>,
+}
+
+impl fmt::Display for RateLimited {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "GitHub rate limit reached")?;
+        if let Some(reset_at) = self.reset_at {
+            write!(f, "; it resets at {} UTC", reset_at.format("%H:%M"))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for RateLimited {}
+
+/// Rate limit headers of a response
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Limits {
+    /// Points left until the reset (`x-ratelimit-remaining`)
+    pub remaining: Option<u64>,
+    /// When the limit resets (`x-ratelimit-reset`)
+    pub reset_at: Option<DateTime<Utc>>,
+    /// Wait GitHub asked for (`Retry-After`)
+    pub retry_after: Option<Duration>,
+}
+
+impl Limits {
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let number = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<u64>().ok())
+        };
+        Self {
+            remaining: number("x-ratelimit-remaining"),
+            reset_at: number("x-ratelimit-reset")
+                .and_then(|secs| i64::try_from(secs).ok())
+                .and_then(|secs| DateTime::from_timestamp(secs, 0)),
+            retry_after: number("retry-after").map(Duration::from_secs),
+        }
+    }
+
+    /// Whether the hourly limit is used up
+    pub fn exhausted(&self) -> bool {
+        self.remaining == Some(0)
+    }
+
+    /// How long to wait before retry number `attempt` (from 0): what GitHub
+    /// asked for, else until the reset when the limit is used up, else
+    /// exponential backoff
+    pub fn wait(&self, attempt: u32, now: DateTime<Utc>) -> Duration {
+        if let Some(retry_after) = self.retry_after {
+            return retry_after;
+        }
+        if self.exhausted()
+            && let Some(reset_at) = self.reset_at
+        {
+            return (reset_at - now).to_std().unwrap_or_default() + Duration::from_secs(1);
+        }
+        BASE_DELAY * 2u32.saturating_pow(attempt)
+    }
+}
+
+/// Whether GitHub refused a request for its rate limits: 429, a 403 saying so,
+/// or GraphQL errors of type `RATE_LIMITED`
+pub fn is_rate_limited(status: u16, body: &Value) -> bool {
+    if status == 429 {
+        return true;
+    }
+    if status == 403
+        && body["message"]
+            .as_str()
+            .is_some_and(|message| message.to_lowercase().contains("rate limit"))
+    {
+        return true;
+    }
+    body["errors"].as_array().is_some_and(|errors| {
+        errors
+            .iter()
+            .any(|error| error["type"].as_str() == Some("RATE_LIMITED"))
+    })
+}
+
+/// Tasks a rate-limited `sync --github` didn't push; the next sync pushes them
+/// first and removes the file once it gets through
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResumeState {
+    pub interrupted_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reset_at: Option<DateTime<Utc>>,
+    /// Task IDs left to push
+    pub pending: Vec<String>,
+}
+
+impl ResumeState {
+    /// State left by an interrupted sync, if any
+    pub fn load(root: &Path) -> Option<Self> {
+        fs::read_to_string(root.join(RESUME_FILE))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+    }
+
+    pub fn save(&self, root: &Path) -> Result<()> {
+        let path = root.join(RESUME_FILE);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+        fs::write(&path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Remove the state once a sync got through
+    pub fn clear(root: &Path) -> Result<()> {
+        let path = root.join(RESUME_FILE);
+        if path.exists() {
+            fs::remove_file(&path)
+                .with_context(|| format!("Failed to remove {}", path.display()))?;
+        }
+        Ok(())
+    }
+}
@@ -32,11 +32,12 @@
 
 use anyhow::{Context, Result};
 use serde_json::json;
+use std::fmt::Write as _;
 
 use super::client::GitHubClient;
-use super::config::get_github_config_path;
+use super::config::{get_github_config_path, load_github_config};
 use super::mutations::GitHubMutations;
-use super::types::GitHubConfig;
+use super::types::{CommentSync, GitHubConfig, LabelConfig, MappingStorage};
 
 /// GitHub Projects v2 setup automation
 pub struct GitHubProjectSetup;
@@ -454,10 +455,56 @@ impl GitHubProjectSetup {
             std::fs::create_dir_all(parent).context("Failed to create .taskguard directory")?;
         }
 
-        let config_content = format!(
+        let mut config_content = format!(
             "owner = \"{}\"\nrepo = \"{}\"\nproject_number = {}\n",
             owner, repo, project_number
         );
+        // Keep the mapping storage, comment, label, user, field and route settings chosen before
+        if let Ok(existing) = load_github_config() {
+            if existing.mapping_storage == MappingStorage::Notes {
+                config_content.push_str("mapping_storage = \"notes\"\n");
+            }
+            if existing.comments != CommentSync::default() {
+                let _ = writeln!(
+                    config_content,
+                    "comments = {}",
+                    toml::Value::try_from(existing.comments)?
+                );
+            }
+            if existing.labels != LabelConfig::default() {
+                let mut table = toml::Table::new();
+                table.insert(
+                    "labels".to_string(),
+                    toml::Value::try_from(&existing.labels)?,
+                );
+                config_content.push('\n');
+                config_content.push_str(&toml::to_string(&table)?);
+            }
+            if !existing.users.is_empty() {
+                let mut table = toml::Table::new();
+                table.insert("users".to_string(), toml::Value::try_from(&existing.users)?);
+                config_content.push('\n');
+                config_content.push_str(&toml::to_string(&table)?);
+            }
+            if !existing.field_mapping.is_empty() {
+                let mut table = toml::Table::new();
+                table.insert(
+                    "field_mapping".to_string(),
+                    toml::Value::try_from(&existing.field_mapping)?,
+                );
+                config_content.push('\n');
+                config_content.push_str(&toml::to_string(&table)?);
+            }
+            if !existing.routes.is_empty() {
+                let mut table = toml::Table::new();
+                table.insert(
+                    "routes".to_string(),
+                    toml::Value::try_from(&existing.routes)?,
+                );
+                config_content.push('\n');
+                config_content.push_str(&toml::to_string(&table)?);
+            }
+        }
 
         std::fs::write(&config_path, config_content).context("Failed to write github.toml")?;
 
@@ -478,6 +525,13 @@ mod tests {
             owner: "Guard8-ai".to_string(),
             repo: "TaskGuard".to_string(),
             project_number: 1,
+            mapping_storage: MappingStorage::File,
+            labels: LabelConfig::default(),
+            users: std::collections::BTreeMap::new(),
+            field_mapping: std::collections::BTreeMap::new(),
+            comments: CommentSync::Both,
+            routes: std::collections::BTreeMap::new(),
+            target: None,
         };
 
         let result = GitHubProjectSetup::check_project_exists(&client, &config);
@@ -1,4 +1,7 @@
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::task::area_matches;
 
 /// GitHub Issue representation
 /// Issues are used to populate Projects v2 boards
@@ -18,6 +21,20 @@ pub struct GitHubIssue {
     pub labels: Vec<String>,
     /// Assignee usernames
     pub assignees: Vec<String>,
+    /// Title of the issue's milestone
+    #[serde(default)]
+    pub milestone: Option<String>,
+}
+
+/// Comment on a GitHub issue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueComment {
+    /// Login of the comment's author, empty for deleted accounts
+    pub author: String,
+    /// Creation timestamp (RFC 3339)
+    pub created_at: String,
+    /// Comment text
+    pub body: String,
 }
 
 /// Projects v2 Item - PRIMARY FOCUS
@@ -59,7 +76,7 @@ pub struct ProjectV2StatusUpdate {
 }
 
 /// GitHub configuration for TaskGuard integration
-/// Authentication is handled via `gh` CLI - no token stored here
+/// Authentication is handled via `gh` CLI or an environment token - no token stored here
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GitHubConfig {
     /// Repository owner (username or organization)
@@ -68,6 +85,157 @@ pub struct GitHubConfig {
     pub repo: String,
     /// Projects v2 project number
     pub project_number: i64,
+    /// Where task-to-issue mappings are kept
+    #[serde(default)]
+    pub mapping_storage: MappingStorage,
+    /// How task tags map to issue labels
+    #[serde(default)]
+    pub labels: LabelConfig,
+    /// Task assignee → GitHub login, e.g. `alice = "alice-gh"`; assignees are
+    /// only synced for users listed here
+    #[serde(default)]
+    pub users: BTreeMap<String, String>,
+    /// Task attribute (`priority`, `complexity` or `estimate`) → Projects v2
+    /// field it's pushed into, e.g. `priority = "Priority"`
+    #[serde(default)]
+    pub field_mapping: BTreeMap<String, String>,
+    /// Which way `## Log` entries and issue comments are synced
+    #[serde(default)]
+    pub comments: CommentSync,
+    /// Area → repository its issues go to, as `owner/repo`, e.g.
+    /// `frontend = "org/web-repo"`; other areas use `owner`/`repo`
+    #[serde(default)]
+    pub routes: BTreeMap<String, String>,
+    /// The `[routes]` repository this config syncs; unset for `owner`/`repo`
+    #[serde(skip)]
+    pub target: Option<String>,
+}
+
+impl GitHubConfig {
+    /// GitHub login of a task assignee
+    pub fn login(&self, user: &str) -> Option<&str> {
+        self.users
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(user))
+            .map(|(_, login)| login.as_str())
+    }
+
+    /// Task assignee of a GitHub login
+    pub fn user(&self, login: &str) -> Option<&str> {
+        self.users
+            .iter()
+            .find(|(_, l)| l.eq_ignore_ascii_case(login))
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Owner and name of the repository this config's issues live in
+    pub fn issue_repo(&self) -> (&str, &str) {
+        self.target
+            .as_deref()
+            .and_then(|target| target.split_once('/'))
+            .unwrap_or((&self.owner, &self.repo))
+    }
+
+    /// `[routes]` repository of an area, or of its nearest routed parent;
+    /// `None` when its issues go to `owner`/`repo`
+    pub fn route(&self, area: &str) -> Option<&str> {
+        let home = format!("{}/{}", self.owner, self.repo);
+        self.routes
+            .iter()
+            .filter(|(routed, _)| area_matches(area, routed))
+            .max_by_key(|(routed, _)| routed.len())
+            .map(|(_, repo)| repo.as_str())
+            .filter(|repo| !repo.eq_ignore_ascii_case(&home))
+    }
+
+    /// One config per repository issues go to: `owner`/`repo`, then each
+    /// `[routes]` repository
+    pub fn targets(&self) -> Vec<Self> {
+        let routed: BTreeSet<&str> = self
+            .routes
+            .keys()
+            .filter_map(|area| self.route(area))
+            .collect();
+        std::iter::once(None)
+            .chain(routed.into_iter().map(Some))
+            .map(|target| Self {
+                target: target.map(str::to_string),
+                ..self.clone()
+            })
+            .collect()
+    }
+}
+
+/// `[labels]` in `.taskguard/github.toml`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LabelConfig {
+    /// Labels managed elsewhere, e.g. `triage`: never pushed from tags, removed
+    /// from issues or pulled into tags
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    /// Color of labels created for tags, as hex (default `ededed`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// Colors of particular labels created for tags
+    #[serde(default)]
+    pub colors: BTreeMap<String, String>,
+}
+
+impl LabelConfig {
+    pub const DEFAULT_COLOR: &str = "ededed";
+
+    /// Whether `label` is kept in step with tags, i.e. not excluded
+    pub fn is_synced(&self, label: &str) -> bool {
+        !self.exclude.iter().any(|l| l.eq_ignore_ascii_case(label))
+    }
+
+    /// Color for a new label, without the leading `#` GitHub doesn't accept
+    pub fn color(&self, label: &str) -> &str {
+        self.colors
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(label))
+            .map(|(_, color)| color.as_str())
+            .or(self.color.as_deref())
+            .unwrap_or(Self::DEFAULT_COLOR)
+            .trim_start_matches('#')
+    }
+}
+
+/// `mapping_storage` in `.taskguard/github.toml`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MappingStorage {
+    /// `.taskguard/github-mapping.json`
+    #[default]
+    File,
+    /// One git note per task under `refs/notes/taskguard`, which travels with
+    /// clones and never conflicts on branch switches
+    Notes,
+}
+
+/// `comments` in `.taskguard/github.toml`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CommentSync {
+    /// New log entries become issue comments and new comments log entries
+    #[default]
+    Both,
+    /// Only post log entries as comments
+    Push,
+    /// Only pull comments into the log
+    Pull,
+    /// Leave comments and the log alone
+    Off,
+}
+
+impl CommentSync {
+    pub fn push(self) -> bool {
+        matches!(self, Self::Both | Self::Push)
+    }
+
+    pub fn pull(self) -> bool {
+        matches!(self, Self::Both | Self::Pull)
+    }
 }
 
 /// Mapping between TaskGuard tasks and GitHub entities
@@ -0,0 +1,278 @@
+//! Receiving GitHub webhooks
+//!
+//! `taskguard serve --github-webhook ADDR` takes `issues` deliveries, checks
+//! their `X-Hub-Signature-256` against the shared secret, and writes status,
+//! label and assignee changes into the mapped tasks right away instead of at
+//! the next `sync --github`. Deliveries arriving while task files have
+//! uncommitted changes wait in a [`WebhookQueue`] until they're committed.
+
+use anyhow::{Context, Result};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::VecDeque;
+use std::fmt;
+use std::fs;
+use std::io::{BufRead, Read, Take};
+use std::path::Path;
+
+/// Environment variable holding the webhook secret
+pub const SECRET_VAR: &str = "TASKGUARD_WEBHOOK_SECRET";
+
+/// Queued deliveries, relative to the project root
+pub const QUEUE_FILE: &str = ".taskguard/state/webhook-queue.json";
+
+/// Largest payload read; GitHub caps deliveries at 25 MB
+const MAX_BODY: usize = 25 * 1024 * 1024;
+
+/// Largest request line and header block read, before anything is verified
+const MAX_HEADER_BYTES: u64 = 64 * 1024;
+
+/// Most header lines read
+const MAX_HEADERS: usize = 100;
+
+/// The request line and headers exceeded [`MAX_HEADER_BYTES`] or
+/// [`MAX_HEADERS`]; answered with `431 Request Header Fields Too Large`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeadersTooLarge;
+
+impl fmt::Display for HeadersTooLarge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Request headers exceed {} bytes or {} lines",
+            MAX_HEADER_BYTES, MAX_HEADERS
+        )
+    }
+}
+
+impl std::error::Error for HeadersTooLarge {}
+
+/// Whether `signature` (`sha256=<hex>`) is the HMAC-SHA256 of `body` under
+/// `secret`, compared in constant time
+pub fn verify_signature(secret: &[u8], body: &[u8], signature: &str) -> bool {
+    let Some(expected) = signature
+        .trim()
+        .strip_prefix("sha256=")
+        .and_then(decode_hex)
+    else {
+        return false;
+    };
+    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(secret) else {
+        return false;
+    };
+    mac.update(body);
+    mac.verify_slice(&expected).is_ok()
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/// An HTTP request, as much of it as a webhook needs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    /// Lowercased name → value
+    headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Request {
+    /// Read a request with a `Content-Length` body. Fails with
+    /// [`HeadersTooLarge`] when the header block is over the limits.
+    pub fn read(reader: &mut impl BufRead) -> Result<Self> {
+        let mut head = reader.by_ref().take(MAX_HEADER_BYTES);
+        let mut line = String::new();
+        read_head_line(&mut head, &mut line)?;
+        let mut parts = line.split_whitespace();
+        let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+            anyhow::bail!("Malformed request line: {}", line.trim());
+        };
+        let (method, path) = (method.to_string(), path.to_string());
+
+        let mut headers = Vec::new();
+        for count in 0.. {
+            line.clear();
+            if read_head_line(&mut head, &mut line)? == 0 {
+                break;
+            }
+            let header = line.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            if count == MAX_HEADERS {
+                return Err(HeadersTooLarge.into());
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                headers.push((name.trim().to_lowercase(), value.trim().to_string()));
+            }
+        }
+
+        let mut request = Self {
+            method,
+            path,
+            headers,
+            body: Vec::new(),
+        };
+        let length = match request.header("content-length") {
+            Some(length) => length
+                .parse::<usize>()
+                .with_context(|| format!("Invalid Content-Length: {}", length))?,
+            None => 0,
+        };
+        if length > MAX_BODY {
+            anyhow::bail!("Payload of {} bytes is too large", length);
+        }
+        // Grows with what arrives rather than with what was announced
+        reader
+            .take(length as u64)
+            .read_to_end(&mut request.body)
+            .context("Failed to read the payload")?;
+        if request.body.len() < length {
+            anyhow::bail!(
+                "Payload ended after {} of {} bytes",
+                request.body.len(),
+                length
+            );
+        }
+        Ok(request)
+    }
+
+    /// Value of a header, by case-insensitive name
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Read one line of the header block, which has to end before the limit
+fn read_head_line<R: BufRead>(head: &mut Take<R>, line: &mut String) -> Result<usize> {
+    let read = head.read_line(line).context("Failed to read the request")?;
+    if head.limit() == 0 && !line.ends_with('\n') {
+        return Err(HeadersTooLarge.into());
+    }
+    Ok(read)
+}
+
+/// The issue an `issues` delivery is about, as it stands after the change
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IssueEvent {
+    /// `X-GitHub-Delivery` ID
+    pub delivery: String,
+    /// What happened, e.g. `closed` or `labeled`
+    pub action: String,
+    /// Repository of the issue, `owner/repo`
+    pub repo: String,
+    pub number: i64,
+    /// `open` or `closed`
+    pub state: String,
+    pub labels: Vec<String>,
+    /// Logins
+    pub assignees: Vec<String>,
+}
+
+impl IssueEvent {
+    pub fn parse(delivery: &str, payload: &[u8]) -> Result<Self> {
+        let value: Value = serde_json::from_slice(payload).context("Payload isn't valid JSON")?;
+        let issue = &value["issue"];
+        let names = |list: &Value, key: &str| -> Vec<String> {
+            list.as_array()
+                .map(|items| {
+                    items
+                        .iter()
+                        .filter_map(|item| item[key].as_str().map(str::to_string))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        Ok(Self {
+            delivery: delivery.to_string(),
+            action: value["action"].as_str().unwrap_or_default().to_string(),
+            repo: value["repository"]["full_name"]
+                .as_str()
+                .context("Payload has no repository")?
+                .to_string(),
+            number: issue["number"].as_i64().context("Payload has no issue")?,
+            state: issue["state"].as_str().unwrap_or("open").to_string(),
+            labels: names(&issue["labels"], "name"),
+            assignees: names(&issue["assignees"], "login"),
+        })
+    }
+}
+
+/// Deliveries remembered by [`RecentDeliveries`]
+const RECENT_DELIVERIES: usize = 1000;
+
+/// Deliveries handled lately, so a redelivered or replayed payload is applied
+/// once. The `X-GitHub-Delivery` ID isn't covered by the signature, so the
+/// payload's digest is remembered too.
+#[derive(Debug, Clone, Default)]
+pub struct RecentDeliveries {
+    seen: VecDeque<(String, [u8; 32])>,
+}
+
+impl RecentDeliveries {
+    /// Whether the delivery, or its payload, was recorded before
+    pub fn contains(&self, delivery: &str, payload: &[u8]) -> bool {
+        let digest: [u8; 32] = Sha256::digest(payload).into();
+        self.seen
+            .iter()
+            .any(|(id, seen)| (!delivery.is_empty() && id == delivery) || *seen == digest)
+    }
+
+    /// Remember a delivery, forgetting the oldest past the limit
+    pub fn record(&mut self, delivery: &str, payload: &[u8]) {
+        if self.seen.len() == RECENT_DELIVERIES {
+            self.seen.pop_front();
+        }
+        self.seen
+            .push_back((delivery.to_string(), Sha256::digest(payload).into()));
+    }
+}
+
+/// Issue events received while task files had uncommitted changes, applied in
+/// order once they're committed
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookQueue {
+    pub events: Vec<IssueEvent>,
+}
+
+impl WebhookQueue {
+    /// The queue left by earlier deliveries; empty when there's none
+    pub fn load(root: &Path) -> Self {
+        fs::read_to_string(root.join(QUEUE_FILE))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Write the queue, removing the file once it's empty
+    pub fn save(&self, root: &Path) -> Result<()> {
+        let path = root.join(QUEUE_FILE);
+        if self.events.is_empty() {
+            if path.exists() {
+                fs::remove_file(&path)
+                    .with_context(|| format!("Failed to remove {}", path.display()))?;
+            }
+            return Ok(());
+        }
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+        fs::write(&path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
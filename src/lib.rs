@@ -1,7 +1,15 @@
 pub mod analysis;
+pub mod analysis_cache;
+pub mod checklist;
 pub mod commands;
 pub mod config;
 pub mod git;
+pub mod git_cache;
 pub mod github;
+pub mod risk;
+pub mod spelling;
+pub mod sync_state;
 pub mod task;
+pub mod tasks_branch;
 pub mod templates;
+pub mod worklog;
@@ -2,16 +2,26 @@ use anyhow::Result;
 use clap::{Parser, Subcommand};
 
 pub mod analysis;
+pub mod analysis_cache;
+pub mod checklist;
 pub mod commands;
 pub mod config;
 pub mod git;
+pub mod git_cache;
 pub mod github;
+pub mod risk;
+pub mod spelling;
+pub mod sync_state;
 pub mod task;
+pub mod tasks_branch;
 pub mod templates;
+pub mod worklog;
 
 use commands::{
-    ai, archive, clean, compact, create, import_md, init, lint, list, restore, stats, sync, update,
-    validate,
+    ai, archive, branch, claim, clean, comment, commit_msg, compact, convert, coupling, create,
+    critical_path, epic, history, hooks, impact, import_md, init, lint, list, log, metrics,
+    migrate, plan, release, release_notes, restore, serve, show, stats, status, sync, tags,
+    template, time, update, validate, workload, worktree,
 };
 
 #[derive(Parser)]
@@ -38,17 +48,155 @@ enum TaskCommands {
     Update {
         /// Task ID
         task_id: String,
-        /// Item index (1-based)
-        item_index: usize,
+        /// Item index (1-based; nested items as 2.3)
+        item: String,
         /// New status (done or todo)
-        status: String,
+        status: Option<String>,
+        /// Assign the item to someone (written as @name, "none" to clear)
+        #[arg(long)]
+        assignee: Option<String>,
+        /// Item due date (YYYY-MM-DD, "none" to clear)
+        #[arg(long)]
+        due: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum EpicCommands {
+    /// List epics with progress across areas
+    List,
+    /// Show the tasks in an epic grouped by area
+    Show {
+        /// Epic name
+        name: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum TagsCommands {
+    /// List tags with usage counts
+    List,
+    /// Rename a tag in every task, including archived ones
+    Rename {
+        /// Current tag
+        old: String,
+        /// New tag name
+        new: String,
+        /// Show affected tasks without modifying files
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Merge several tags into one
+    Merge {
+        /// Tags to fold into the target
+        #[arg(required = true)]
+        sources: Vec<String>,
+        /// Tag to keep
+        #[arg(long)]
+        into: String,
+        /// Show affected tasks without modifying files
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum TimeCommands {
+    /// Start a work session timer
+    Start {
+        /// Task ID
+        task_id: String,
+    },
+    /// Stop the running timer and record the session
+    Stop {
+        /// Task ID
+        task_id: String,
+    },
+    /// Record time already spent (e.g., "2h", "45m", "1h30m")
+    Log {
+        /// Task ID
+        task_id: String,
+        /// Duration to record
+        duration: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum TemplateCommands {
+    /// List built-in and project templates
+    List,
+    /// Print a template's content
+    Show {
+        /// Template name
+        name: String,
+    },
+    /// Add a project template in .taskguard/templates/
+    Add {
+        /// Template name
+        name: String,
+        /// Copy content from this file instead of the built-in starter
+        #[arg(long)]
+        from: Option<std::path::PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum WorktreeCommands {
+    /// Create a linked worktree for a task, on the task's branch
+    Add {
+        /// Task ID
+        task_id: String,
+    },
+    /// List worktrees and the tasks they belong to
+    List,
+    /// Remove a done task's worktree, or those of all done tasks (the branch is kept)
+    Remove {
+        /// Task ID (default: every done task with a worktree)
+        task_id: Option<String>,
+        /// Remove even if the task isn't done or the worktree has uncommitted changes
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum HooksCommands {
+    /// Install pre-commit, commit-msg and pre-push hooks, keeping existing hooks in the chain
+    Install {
+        /// Run `taskguard validate --strict` before pushing, so any issue blocks the push
+        #[arg(long)]
+        strict: bool,
+    },
+    /// Remove TaskGuard's hooks and restore the ones they replaced
+    Uninstall,
+    /// Check a commit message file for a known task ID (run by the commit-msg hook)
+    #[command(hide = true)]
+    CheckCommitMsg {
+        /// Commit message file
+        file: std::path::PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+enum ReleaseCommands {
+    /// Record the first tag up to TAG containing each done task's commits as its `release:`
+    Associate {
+        /// Release tag, e.g. v1.3.0
+        tag: String,
+        /// Show what would be recorded without modifying task files
+        #[arg(long)]
+        dry_run: bool,
     },
 }
 
 #[derive(Subcommand)]
 enum Commands {
     /// Initialize TaskGuard in the current project
-    Init,
+    Init {
+        /// Keep task files on this orphan branch instead of the code branch
+        #[arg(long, num_args = 0..=1, default_missing_value = "taskguard")]
+        tasks_branch: Option<String>,
+    },
     /// List tasks or task items
     List {
         #[command(subcommand)]
@@ -59,9 +207,27 @@ enum Commands {
         /// Filter by area (when listing tasks)
         #[arg(short, long)]
         area: Option<String>,
+        /// Filter by assignee (matches any of a task's assignees)
+        #[arg(long)]
+        assignee: Option<String>,
         /// Include archived tasks in the list
         #[arg(long)]
         include_archive: bool,
+        /// Only show tasks due before this date (YYYY-MM-DD)
+        #[arg(long)]
+        due_before: Option<String>,
+        /// Only show open tasks past their due date
+        #[arg(long)]
+        overdue: bool,
+        /// Extra fields to display (comma-separated, built-in or custom)
+        #[arg(long)]
+        columns: Option<String>,
+        /// Show subtasks nested under their parent task (filters by status and area only)
+        #[arg(
+            long,
+            conflicts_with_all = ["assignee", "include_archive", "due_before", "overdue", "columns"]
+        )]
+        tree: bool,
     },
     /// Create a new task
     Create {
@@ -83,12 +249,24 @@ enum Commands {
         /// Dependencies (comma-separated task IDs)
         #[arg(short, long)]
         dependencies: Option<String>,
-        /// Assignee
+        /// Assignee(s) (comma-separated)
         #[arg(long)]
         assignee: Option<String>,
         /// Time estimate (e.g., "4h", "2d")
         #[arg(short, long)]
         estimate: Option<String>,
+        /// Due date (YYYY-MM-DD)
+        #[arg(long)]
+        due: Option<String>,
+        /// Epic this task belongs to
+        #[arg(long)]
+        epic: Option<String>,
+        /// Template to start from (e.g., bugfix, feature, spike)
+        #[arg(long)]
+        template: Option<String>,
+        /// Template variable for {{custom.KEY}} placeholders (repeatable, KEY=VALUE)
+        #[arg(long = "var", value_name = "KEY=VALUE")]
+        vars: Vec<String>,
         /// Allow creating task without dependencies (not recommended)
         #[arg(long)]
         allow_orphan_task: bool,
@@ -97,6 +275,9 @@ enum Commands {
     Show {
         /// Task ID
         task_id: String,
+        /// List the source files commits referencing the task changed
+        #[arg(long)]
+        files: bool,
     },
     /// Validate tasks and dependencies
     Validate {
@@ -106,6 +287,18 @@ enum Commands {
         /// Show orphan tasks (no dependencies and nothing depends on them)
         #[arg(long)]
         orphans: bool,
+        /// Check links: for dead relative file paths and malformed URLs
+        #[arg(long)]
+        check_links: bool,
+        /// Treat unknown frontmatter fields as errors and exit non-zero on any issue
+        #[arg(long)]
+        strict: bool,
+        /// Print the validation report as JSON
+        #[arg(long)]
+        json: bool,
+        /// Check only task files staged in git and exit non-zero on problems (pre-commit)
+        #[arg(long, conflicts_with_all = ["sync_areas", "orphans", "check_links", "json"])]
+        staged: bool,
     },
     /// Analyze Git history and suggest task updates
     Sync {
@@ -127,6 +320,76 @@ enum Commands {
         /// Dry run mode - show what would change without applying
         #[arg(long)]
         dry_run: bool,
+        /// Write suggested statuses into task files, confirming each one
+        #[arg(long)]
+        apply: bool,
+        /// Don't ask before applying (with --apply or --apply-pull, or the recommendation for --remote conflicts)
+        #[arg(short, long)]
+        yes: bool,
+        /// Show which tasks each local branch touches
+        #[arg(long)]
+        branches: bool,
+        /// Remote to compare with (default: origin, or the first remote)
+        #[arg(long, requires = "remote")]
+        remote_name: Option<String>,
+        /// Compare with every remote, e.g. upstream and forks
+        #[arg(long, requires = "remote", conflicts_with = "remote_name")]
+        all_remotes: bool,
+        /// Remote branch to compare with (default: the remote's default branch)
+        #[arg(long, requires = "remote")]
+        branch: Option<String>,
+        /// Parse every commit instead of reusing results from earlier runs
+        #[arg(long)]
+        no_cache: bool,
+        /// Scan back to each task's creation date instead of the last --limit commits
+        #[arg(long)]
+        since_created: bool,
+        /// Report all activity in the scanned commits, not just what's new since the last sync
+        #[arg(long)]
+        full: bool,
+        /// Suggest which open task uncommitted changes belong to, from the files they touch
+        #[arg(long, conflicts_with_all = ["remote", "github", "apply", "branches"])]
+        working_tree: bool,
+        /// Write status changes made on GitHub (closed or reopened issues) to local task files
+        #[arg(long, requires = "github", conflicts_with = "backfill_project")]
+        apply_pull: bool,
+        /// Create task files for GitHub issues no task maps to, confirming each unless --yes
+        #[arg(long, requires = "github", conflicts_with = "backfill_project")]
+        adopt_orphans: bool,
+        /// Only sync tasks in these areas (comma-separated; replaces [sync.filter])
+        #[arg(long, value_delimiter = ',', requires = "github")]
+        area: Vec<String>,
+        /// Don't sync tasks in these areas (comma-separated)
+        #[arg(long, value_delimiter = ',', requires = "github")]
+        exclude_area: Vec<String>,
+        /// Only sync tasks with these statuses (comma-separated)
+        #[arg(long, value_delimiter = ',', requires = "github")]
+        status: Vec<String>,
+        /// Only sync tasks with one of these tags (comma-separated)
+        #[arg(long, value_delimiter = ',', requires = "github")]
+        tag: Vec<String>,
+        /// Don't sync tasks with any of these tags (comma-separated)
+        #[arg(long, value_delimiter = ',', requires = "github")]
+        exclude_tag: Vec<String>,
+        /// Dry run output: text, or a plan as table or json (GitHub sync only)
+        #[arg(
+            long,
+            value_parser = ["text", "table", "json"],
+            requires_all = ["github", "dry_run"],
+            conflicts_with_all = ["apply_pull", "adopt_orphans", "backfill_project"]
+        )]
+        format: Option<String>,
+        /// Push the tasks of a plan saved with --dry-run --format json, if still current
+        #[arg(
+            long,
+            value_name = "FILE",
+            requires = "github",
+            conflicts_with_all = ["dry_run", "apply_pull", "adopt_orphans", "backfill_project"]
+        )]
+        apply_plan: Option<std::path::PathBuf>,
+        /// Run unattended in GitHub Actions: never prompt (implies --yes), emit annotations, a job summary and step outputs
+        #[arg(long, requires = "github")]
+        ci: bool,
     },
     /// Analyze task complexity and quality
     Lint {
@@ -136,15 +399,39 @@ enum Commands {
         /// Filter by area
         #[arg(short, long)]
         area: Option<String>,
+        /// Check links: for dead relative file paths and malformed URLs
+        #[arg(long)]
+        check_links: bool,
+        /// Check titles and bodies for common misspellings
+        #[arg(long)]
+        spellcheck: bool,
+        /// Write the computed complexity into tasks that don't set one
+        #[arg(long)]
+        apply_complexity: bool,
+        /// Analyze every task instead of reusing results for unchanged files
+        #[arg(long)]
+        no_cache: bool,
+        /// Rank open tasks by composite risk score
+        #[arg(long)]
+        risk: bool,
+        /// Output format: text or json
+        #[arg(long, default_value = "text")]
+        format: String,
+        /// Exit non-zero when there are more errors than this (for CI)
+        #[arg(long)]
+        max_errors: Option<usize>,
+        /// Exit non-zero when there are more warnings than this (for CI)
+        #[arg(long)]
+        max_warnings: Option<usize>,
     },
     /// AI-powered natural language task management
     Ai {
         /// Natural language input for task management
         input: String,
     },
-    /// Update task fields (status, priority, assignee, dependencies)
+    /// Update task fields (status, priority, assignee, dependencies, due, estimate, epic)
     Update {
-        /// Field to update (status, priority, assignee, dependencies)
+        /// Field to update (status, priority, assignee, dependencies, due, estimate, epic)
         field: String,
         /// Task ID to update
         task_id: String,
@@ -156,8 +443,131 @@ enum Commands {
         #[command(subcommand)]
         command: TaskCommands,
     },
+    /// Claim a task for yourself (git user.name) so teammates know it's taken
+    Claim {
+        /// Task ID
+        task_id: String,
+        /// Give up your claim instead
+        #[arg(long)]
+        release: bool,
+        /// Take over a task claimed by someone else
+        #[arg(long)]
+        force: bool,
+    },
+    /// Append a timestamped note to a task's activity log
+    Comment {
+        /// Task ID
+        task_id: String,
+        /// Note text
+        message: String,
+    },
+    /// Show how a task's status, assignee and other fields changed over time (from git history)
+    History {
+        /// Task ID
+        task_id: String,
+    },
+    /// Show the git log of commits referencing a task
+    Log {
+        /// Task ID
+        task_id: String,
+        /// Number of commits to search
+        #[arg(short, long, default_value = "1000")]
+        limit: usize,
+        /// Show each commit's diff
+        #[arg(short, long)]
+        patch: bool,
+        /// List the files each commit changed
+        #[arg(long)]
+        files: bool,
+    },
+    /// Show the longest chain of open tasks weighted by estimates
+    CriticalPath {
+        /// Only consider tasks in this area (includes nested areas)
+        #[arg(short, long)]
+        area: Option<String>,
+    },
+    /// Show how often areas depend on each other, as a matrix
+    Coupling {
+        /// Share of an area's tasks (percent) depending on another area that counts as tight coupling
+        #[arg(long, default_value_t = 50)]
+        threshold: u8,
+        /// Also write a Graphviz (DOT) diagram to this file
+        #[arg(long)]
+        dot: Option<std::path::PathBuf>,
+    },
+    /// Show which open tasks finishing a task would unblock
+    Impact {
+        /// Task ID
+        task_id: String,
+    },
+    /// Order open tasks into waves that can be worked on in parallel
+    Plan {
+        /// Only plan tasks in this area (includes nested areas)
+        #[arg(short, long)]
+        area: Option<String>,
+        /// Group each wave by assignee
+        #[arg(long)]
+        by_assignee: bool,
+    },
+    /// Sum open tasks and estimates per assignee
+    Workload {
+        /// Only count tasks in this area (includes nested areas)
+        #[arg(short, long)]
+        area: Option<String>,
+        /// Flag assignees with more open estimated work than this
+        #[arg(long, default_value = "1w")]
+        max: String,
+    },
+    /// Report throughput, lead time and cycle time, by area
+    Metrics {
+        /// Only include tasks in this area (includes nested areas)
+        #[arg(short, long)]
+        area: Option<String>,
+        /// Number of weeks of throughput to show
+        #[arg(long, default_value = "8")]
+        weeks: u32,
+        /// Export daily status counts for burndown and cumulative flow charts
+        #[arg(long)]
+        burndown: bool,
+        /// First day of the burndown (YYYY-MM-DD, default: 14 days ago)
+        #[arg(long, requires = "burndown")]
+        since: Option<String>,
+        /// Burndown format: csv or json
+        #[arg(long, default_value = "csv", requires = "burndown")]
+        format: String,
+        /// Write burndown data to a file instead of stdout
+        #[arg(short, long, requires = "burndown")]
+        output: Option<std::path::PathBuf>,
+        /// Compare estimates of done tasks with their git activity span
+        #[arg(long, conflicts_with = "burndown")]
+        estimates: bool,
+    },
+    /// Track epics spanning multiple areas
+    Epic {
+        #[command(subcommand)]
+        command: EpicCommands,
+    },
+    /// List, rename and merge tags across all tasks
+    Tags {
+        #[command(subcommand)]
+        command: TagsCommands,
+    },
+    /// Track time spent on tasks
+    Time {
+        #[command(subcommand)]
+        command: TimeCommands,
+    },
+    /// Manage task templates
+    Template {
+        #[command(subcommand)]
+        command: TemplateCommands,
+    },
     /// Show project status
-    Status,
+    Status {
+        /// Summarize tasks at risk of missing their due date
+        #[arg(long)]
+        risk: bool,
+    },
     /// Import tasks from structured markdown file
     ImportMd {
         /// Path to markdown file to import
@@ -186,18 +596,25 @@ enum Commands {
         /// Dry run - show what would be deleted without actually deleting
         #[arg(long)]
         dry_run: bool,
-        /// Number of days to retain completed tasks (default: 30)
+        /// Only include tasks completed at least this many days ago
         #[arg(short, long)]
         days: Option<u32>,
     },
     /// Show storage statistics and usage breakdown (efficiency optimization)
-    Stats,
+    Stats {
+        /// Print project health metrics in Prometheus text format
+        #[arg(long)]
+        prometheus: bool,
+        /// Write Prometheus metrics to a file (for the node_exporter textfile collector)
+        #[arg(short, long)]
+        output: Option<std::path::PathBuf>,
+    },
     /// Archive old completed tasks to preserve history without bloat (efficiency optimization)
     Archive {
         /// Dry run - show what would be archived without actually moving files
         #[arg(long)]
         dry_run: bool,
-        /// Number of days to retain completed tasks (default: 30)
+        /// Only include tasks completed at least this many days ago
         #[arg(short, long)]
         days: Option<u32>,
     },
@@ -207,6 +624,60 @@ enum Commands {
         #[arg(long)]
         dry_run: bool,
     },
+    /// Upgrade task files and config written by older TaskGuard versions
+    Migrate {
+        /// Show what would change without modifying files
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Convert task front-matter between YAML and TOML and make it the project default
+    Convert {
+        /// Target format: yaml or toml
+        format: String,
+        /// Show which files would be converted without modifying them
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Generate markdown release notes from tasks completed since a tag or date
+    ReleaseNotes {
+        /// Git tag, commit, or date (YYYY-MM-DD) to collect completed tasks since
+        #[arg(long)]
+        since: String,
+        /// Group entries by "area", "tag" or "release" (see 'taskguard release associate')
+        #[arg(long, default_value = "area")]
+        group_by: String,
+        /// Write release notes to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<std::path::PathBuf>,
+    },
+    /// Link done tasks to the release tags that shipped them
+    Release {
+        #[command(subcommand)]
+        command: ReleaseCommands,
+    },
+    /// Print a commit message template for a task (ID, title, open checklist items)
+    CommitMsg {
+        /// Task ID (default: the task named by the current branch, or the only one in progress)
+        task_id: Option<String>,
+        /// Prepend the template to this commit message file (for a prepare-commit-msg hook)
+        #[arg(long)]
+        file: Option<std::path::PathBuf>,
+    },
+    /// Create and check out a branch for a task, named by [git] branch_pattern
+    Branch {
+        /// Task ID
+        task_id: String,
+    },
+    /// Manage linked git worktrees for working on tasks in parallel
+    Worktree {
+        #[command(subcommand)]
+        command: WorktreeCommands,
+    },
+    /// Manage git hooks that validate task files and check commit messages
+    Hooks {
+        #[command(subcommand)]
+        command: HooksCommands,
+    },
     /// Restore archived task back to active tasks
     Restore {
         /// Task ID to restore from archive
@@ -215,21 +686,44 @@ enum Commands {
         #[arg(long)]
         dry_run: bool,
     },
+    /// Receive GitHub webhooks and update tasks as issues change
+    Serve {
+        /// Address to receive issue webhooks on, e.g. :8080 or 127.0.0.1:8080
+        #[arg(long, value_name = "ADDR")]
+        github_webhook: String,
+    },
 }
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
-    match cli.command {
-        Commands::Init => init::run(),
+    // With [git] tasks_branch, tasks/ is a working copy of that branch
+    tasks_branch::pull()?;
+
+    let result = match cli.command {
+        Commands::Init { tasks_branch } => init::run(tasks_branch.as_deref()),
         Commands::List {
             command,
             status,
             area,
+            assignee,
             include_archive,
+            due_before,
+            overdue,
+            columns,
+            tree,
         } => match command {
             Some(ListCommands::Items { task_id }) => list::run_items(task_id),
-            None => list::run(status, area, include_archive),
+            None if tree => list::run_tree(status.as_deref(), area.as_deref()),
+            None => list::run(
+                status,
+                area,
+                assignee,
+                include_archive,
+                due_before,
+                overdue,
+                columns,
+            ),
         },
         Commands::Create {
             title,
@@ -240,6 +734,10 @@ fn main() -> Result<()> {
             dependencies,
             assignee,
             estimate,
+            due,
+            epic,
+            template,
+            vars,
             allow_orphan_task,
         } => create::run(
             title,
@@ -250,16 +748,27 @@ fn main() -> Result<()> {
             dependencies,
             assignee,
             estimate,
+            due,
+            epic,
+            template,
+            vars,
             allow_orphan_task,
         ),
-        Commands::Show { task_id } => {
-            println!("Show task: {}", task_id);
-            Ok(())
-        }
+        Commands::Show { task_id, files } => show::run(&task_id, files),
         Commands::Validate {
             sync_areas,
             orphans,
-        } => validate::run(sync_areas, orphans),
+            check_links,
+            strict,
+            json,
+            staged,
+        } => {
+            if staged {
+                validate::run_staged()
+            } else {
+                validate::run(sync_areas, orphans, check_links, strict, json)
+            }
+        }
         Commands::Sync {
             limit,
             verbose,
@@ -267,8 +776,87 @@ fn main() -> Result<()> {
             github,
             backfill_project,
             dry_run,
-        } => sync::run(limit, verbose, remote, github, backfill_project, dry_run),
-        Commands::Lint { verbose, area } => lint::run(verbose, area),
+            apply,
+            yes,
+            branches,
+            remote_name,
+            all_remotes,
+            branch,
+            no_cache,
+            since_created,
+            full,
+            working_tree,
+            apply_pull,
+            adopt_orphans,
+            area,
+            exclude_area,
+            status,
+            tag,
+            exclude_tag,
+            format,
+            apply_plan,
+            ci,
+        } => {
+            let options = sync::SyncOptions {
+                limit,
+                verbose,
+                remote,
+                github,
+                backfill_project,
+                dry_run,
+                apply,
+                yes,
+                branches,
+                remote_name,
+                all_remotes,
+                remote_branch: branch,
+                no_cache,
+                since_created,
+                full,
+                working_tree,
+                apply_pull,
+                adopt_orphans,
+                filter: config::SyncFilter {
+                    areas: area,
+                    exclude_areas: exclude_area,
+                    statuses: status,
+                    tags: tag,
+                    exclude_tags: exclude_tag,
+                },
+                format,
+                apply_plan,
+                ci,
+            };
+
+            sync::run(options)
+        }
+        Commands::Lint {
+            verbose,
+            area,
+            check_links,
+            spellcheck,
+            apply_complexity,
+            no_cache,
+            risk,
+            format,
+            max_errors,
+            max_warnings,
+        } => {
+            let options = lint::LintOptions {
+                verbose,
+                area,
+                check_links,
+                spellcheck,
+                apply_complexity,
+                no_cache,
+                risk,
+                format,
+                max_errors,
+                max_warnings,
+            };
+
+            lint::run(options)
+        }
         Commands::Ai { input } => ai::run(input),
         Commands::Update {
             field,
@@ -278,14 +866,84 @@ fn main() -> Result<()> {
         Commands::Task { command } => match command {
             TaskCommands::Update {
                 task_id,
-                item_index,
+                item,
                 status,
-            } => update::run_task_item(task_id, item_index, status),
+                assignee,
+                due,
+            } => update::run_task_item(
+                task_id,
+                &item,
+                update::ItemUpdate {
+                    status,
+                    assignee,
+                    due,
+                },
+            ),
         },
-        Commands::Status => {
-            println!("Project status overview");
-            Ok(())
+        Commands::Claim {
+            task_id,
+            release,
+            force,
+        } => claim::run(&task_id, release, force),
+        Commands::Comment { task_id, message } => comment::run(&task_id, &message),
+        Commands::History { task_id } => history::run(&task_id),
+        Commands::Log {
+            task_id,
+            limit,
+            patch,
+            files,
+        } => log::run(&task_id, limit, patch, files),
+        Commands::CriticalPath { area } => critical_path::run(area.as_deref()),
+        Commands::Coupling { threshold, dot } => coupling::run(threshold, dot.as_deref()),
+        Commands::Impact { task_id } => impact::run(&task_id),
+        Commands::Plan { area, by_assignee } => plan::run(area.as_deref(), by_assignee),
+        Commands::Workload { area, max } => workload::run(area.as_deref(), &max),
+        Commands::Metrics {
+            area,
+            weeks,
+            burndown,
+            since,
+            format,
+            output,
+            estimates,
+        } => {
+            if estimates {
+                metrics::run_estimates(area.as_deref())
+            } else if burndown {
+                metrics::run_burndown(
+                    area.as_deref(),
+                    since.as_deref(),
+                    &format,
+                    output.as_deref(),
+                )
+            } else {
+                metrics::run(area.as_deref(), weeks)
+            }
         }
+        Commands::Epic { command } => match command {
+            EpicCommands::List => epic::run_list(),
+            EpicCommands::Show { name } => epic::run_show(&name),
+        },
+        Commands::Tags { command } => match command {
+            TagsCommands::List => tags::run_list(),
+            TagsCommands::Rename { old, new, dry_run } => tags::run_rename(&old, &new, dry_run),
+            TagsCommands::Merge {
+                sources,
+                into,
+                dry_run,
+            } => tags::run_merge(&sources, &into, dry_run),
+        },
+        Commands::Time { command } => match command {
+            TimeCommands::Start { task_id } => time::run_start(&task_id),
+            TimeCommands::Stop { task_id } => time::run_stop(&task_id),
+            TimeCommands::Log { task_id, duration } => time::run_log(&task_id, &duration),
+        },
+        Commands::Template { command } => match command {
+            TemplateCommands::List => template::run_list(),
+            TemplateCommands::Show { name } => template::run_show(&name),
+            TemplateCommands::Add { name, from } => template::run_add(&name, from.as_deref()),
+        },
+        Commands::Status { risk } => status::run(risk),
         Commands::ImportMd {
             file,
             area,
@@ -326,9 +984,41 @@ fn main() -> Result<()> {
             import_md::run(file, options)
         }
         Commands::Clean { dry_run, days } => clean::run(dry_run, days),
-        Commands::Stats => stats::run(),
+        Commands::Stats { prometheus, output } => stats::run(prometheus, output.as_deref()),
         Commands::Archive { dry_run, days } => archive::run(dry_run, days),
         Commands::Compact { dry_run } => compact::run(dry_run),
+        Commands::Convert { format, dry_run } => convert::run(&format, dry_run),
+        Commands::CommitMsg { task_id, file } => {
+            commit_msg::run(task_id.as_deref(), file.as_deref())
+        }
+        Commands::Branch { task_id } => branch::run(&task_id),
+        Commands::Worktree { command } => match command {
+            WorktreeCommands::Add { task_id } => worktree::run_add(&task_id),
+            WorktreeCommands::List => worktree::run_list(),
+            WorktreeCommands::Remove { task_id, force } => {
+                worktree::run_remove(task_id.as_deref(), force)
+            }
+        },
+        Commands::Hooks { command } => match command {
+            HooksCommands::Install { strict } => hooks::run_install(strict),
+            HooksCommands::Uninstall => hooks::run_uninstall(),
+            HooksCommands::CheckCommitMsg { file } => hooks::run_check_commit_msg(&file),
+        },
+        Commands::Migrate { dry_run } => migrate::run(dry_run),
+        Commands::ReleaseNotes {
+            since,
+            group_by,
+            output,
+        } => release_notes::run(&since, &group_by, output.as_deref()),
+        Commands::Release { command } => match command {
+            ReleaseCommands::Associate { tag, dry_run } => release::run_associate(&tag, dry_run),
+        },
         Commands::Restore { task_id, dry_run } => restore::run(&task_id, dry_run),
+        Commands::Serve { github_webhook } => serve::run_github_webhook(&github_webhook),
+    };
+
+    if result.is_ok() {
+        tasks_branch::push()?;
     }
+    result
 }
@@ -0,0 +1,157 @@
+//! Composite risk score for open tasks
+//!
+//! Combines how hard a task is, how much work waits on it, how long it has sat
+//! untouched, how loaded its assignees are and how long it has been blocked, so
+//! the tasks most likely to slip can be looked at first.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+use crate::analysis::TaskAnalyzer;
+use crate::task::{Task, TaskStatus};
+
+/// Days without activity at which staleness counts fully
+const STALE_DAYS: i64 = 28;
+/// Days blocked at which blocking counts fully
+const BLOCKED_DAYS: i64 = 14;
+/// Open dependents at which dependents count fully
+const MANY_DEPENDENTS: usize = 5;
+/// Open tasks per assignee beyond the task itself at which workload counts fully
+const HEAVY_WORKLOAD: usize = 5;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TaskRisk {
+    pub task_id: String,
+    /// 0-10, higher is riskier
+    pub score: f32,
+    /// `complexity` from the frontmatter, or the computed score (0-10)
+    pub complexity: f32,
+    /// Open tasks that depend on this one, directly or transitively
+    pub dependents: usize,
+    /// Days since creation, claim or the last time log entry
+    pub idle_days: i64,
+    /// Open tasks held by the busiest assignee, this one included
+    pub workload: usize,
+    /// Days without activity while blocked or waiting on dependencies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocked_days: Option<i64>,
+}
+
+impl TaskRisk {
+    /// What drives the score, in weight order: any dependents or blocking, and
+    /// the other factors once they count at least half
+    pub fn factors(&self) -> Vec<String> {
+        let mut factors = Vec::new();
+        if self.complexity >= 5.0 {
+            factors.push(format!("complexity {:.0}", self.complexity));
+        }
+        if self.dependents > 0 {
+            factors.push(format!("{} task(s) waiting on it", self.dependents));
+        }
+        if self.idle_days * 2 >= STALE_DAYS {
+            factors.push(format!("idle {} days", self.idle_days));
+        }
+        if self.workload.saturating_sub(1) * 2 >= HEAVY_WORKLOAD {
+            factors.push(format!("assignee has {} open tasks", self.workload));
+        }
+        if let Some(days) = self.blocked_days {
+            factors.push(format!("blocked {} days", days));
+        }
+        factors
+    }
+}
+
+/// Risk of every open task, riskiest first
+///
+/// The score weighs complexity (30%), open dependents (25%), staleness (15%),
+/// assignee workload (15%) and blocked duration (15%).
+pub fn assess(tasks: &[Task], analyzer: &TaskAnalyzer, today: NaiveDate) -> Vec<TaskRisk> {
+    let open: Vec<&Task> = tasks
+        .iter()
+        .filter(|t| t.status != TaskStatus::Done)
+        .collect();
+    let open_ids: HashSet<&str> = open.iter().map(|t| t.id.as_str()).collect();
+
+    let mut dependents_of: HashMap<&str, Vec<&str>> = HashMap::new();
+    for task in &open {
+        for dep in &task.dependencies {
+            dependents_of
+                .entry(dep.as_str())
+                .or_default()
+                .push(task.id.as_str());
+        }
+    }
+    let mut workload: HashMap<&str, usize> = HashMap::new();
+    for assignee in open.iter().flat_map(|t| &t.assignees) {
+        *workload.entry(assignee.as_str()).or_default() += 1;
+    }
+
+    let mut risks: Vec<TaskRisk> = open
+        .iter()
+        .map(|task| {
+            let complexity = task
+                .complexity
+                .map_or_else(|| analyzer.analyze_task(task).complexity_score, f32::from);
+            let dependents = count_dependents(&task.id, &dependents_of);
+            let idle_days = (today - last_activity(task).date_naive()).num_days().max(0);
+            let load = task
+                .assignees
+                .iter()
+                .filter_map(|a| workload.get(a.as_str()))
+                .max()
+                .copied()
+                .unwrap_or(0);
+            let blocked = task.status == TaskStatus::Blocked
+                || task
+                    .dependencies
+                    .iter()
+                    .any(|dep| open_ids.contains(dep.as_str()));
+            let blocked_days = blocked.then_some(idle_days);
+
+            let ratio = |value: f32, full: f32| (value / full).min(1.0);
+            let score = 10.0
+                * (0.3 * ratio(complexity, 10.0)
+                    + 0.25 * ratio(dependents as f32, MANY_DEPENDENTS as f32)
+                    + 0.15 * ratio(idle_days as f32, STALE_DAYS as f32)
+                    + 0.15 * ratio(load.saturating_sub(1) as f32, HEAVY_WORKLOAD as f32)
+                    + 0.15 * ratio(blocked_days.unwrap_or(0) as f32, BLOCKED_DAYS as f32));
+
+            TaskRisk {
+                task_id: task.id.clone(),
+                score,
+                complexity,
+                dependents,
+                idle_days,
+                workload: load,
+                blocked_days,
+            }
+        })
+        .collect();
+
+    risks.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.task_id.cmp(&b.task_id)));
+    risks
+}
+
+/// Most recent of creation, claim and time log entries
+fn last_activity(task: &Task) -> DateTime<Utc> {
+    task.time_log
+        .iter()
+        .map(|entry| entry.started)
+        .chain(task.claimed)
+        .fold(task.created, DateTime::max)
+}
+
+/// Open tasks reachable through reverse dependency edges from `id`
+fn count_dependents(id: &str, dependents_of: &HashMap<&str, Vec<&str>>) -> usize {
+    let mut seen = HashSet::new();
+    let mut queue = vec![id];
+    while let Some(current) = queue.pop() {
+        for &dependent in dependents_of.get(current).into_iter().flatten() {
+            if dependent != id && seen.insert(dependent) {
+                queue.push(dependent);
+            }
+        }
+    }
+    seen.len()
+}
@@ -0,0 +1,239 @@
+//! Spell checking for task titles and bodies
+//!
+//! A lightweight checker in the style of codespell: instead of a full
+//! dictionary it knows common misspellings and their corrections, so it never
+//! flags project jargon. `.taskguard/dictionary.txt` extends it with one entry
+//! per line: a plain word is accepted as correct (overriding a built-in
+//! correction), and `wrong -> right` adds a correction. `#` starts a comment.
+//! Fenced code blocks, inline code and URLs are skipped.
+
+use anyhow::{Context, Result};
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+/// Project dictionary, relative to the project root
+pub const DICTIONARY_FILE: &str = ".taskguard/dictionary.txt";
+
+const COMMON_MISSPELLINGS: [(&str, &str); 118] = [
+    ("accomodate", "accommodate"),
+    ("acheive", "achieve"),
+    ("accross", "across"),
+    ("adress", "address"),
+    ("agressive", "aggressive"),
+    ("alot", "a lot"),
+    ("amature", "amateur"),
+    ("apparant", "apparent"),
+    ("appearence", "appearance"),
+    ("arguement", "argument"),
+    ("asynchonous", "asynchronous"),
+    ("authenication", "authentication"),
+    ("authentification", "authentication"),
+    ("availabe", "available"),
+    ("availible", "available"),
+    ("basicly", "basically"),
+    ("becuase", "because"),
+    ("begining", "beginning"),
+    ("beleive", "believe"),
+    ("buisness", "business"),
+    ("calender", "calendar"),
+    ("catagory", "category"),
+    ("changable", "changeable"),
+    ("commited", "committed"),
+    ("comming", "coming"),
+    ("comparision", "comparison"),
+    ("compatability", "compatibility"),
+    ("compatable", "compatible"),
+    ("completly", "completely"),
+    ("configuraton", "configuration"),
+    ("conection", "connection"),
+    ("consistant", "consistent"),
+    ("continous", "continuous"),
+    ("convertion", "conversion"),
+    ("currenly", "currently"),
+    ("defenitely", "definitely"),
+    ("definately", "definitely"),
+    ("dependancy", "dependency"),
+    ("dependancies", "dependencies"),
+    ("deprecatd", "deprecated"),
+    ("desciption", "description"),
+    ("develoment", "development"),
+    ("differnt", "different"),
+    ("dissapear", "disappear"),
+    ("documenation", "documentation"),
+    ("embarass", "embarrass"),
+    ("enviroment", "environment"),
+    ("environement", "environment"),
+    ("equivalant", "equivalent"),
+    ("existance", "existence"),
+    ("existant", "existent"),
+    ("explaination", "explanation"),
+    ("familar", "familiar"),
+    ("finaly", "finally"),
+    ("foward", "forward"),
+    ("fucntion", "function"),
+    ("funtion", "function"),
+    ("goverment", "government"),
+    ("gaurantee", "guarantee"),
+    ("guarentee", "guarantee"),
+    ("happend", "happened"),
+    ("immediatly", "immediately"),
+    ("implemenation", "implementation"),
+    ("implmentation", "implementation"),
+    ("independant", "independent"),
+    ("infomation", "information"),
+    ("initalize", "initialize"),
+    ("intergration", "integration"),
+    ("knowlege", "knowledge"),
+    ("lenght", "length"),
+    ("libary", "library"),
+    ("maintainance", "maintenance"),
+    ("maintenence", "maintenance"),
+    ("managment", "management"),
+    ("messsage", "message"),
+    ("migation", "migration"),
+    ("mispell", "misspell"),
+    ("neccessary", "necessary"),
+    ("necesary", "necessary"),
+    ("noticable", "noticeable"),
+    ("occured", "occurred"),
+    ("occurence", "occurrence"),
+    ("occurrance", "occurrence"),
+    ("paramter", "parameter"),
+    ("parrallel", "parallel"),
+    ("performace", "performance"),
+    ("permision", "permission"),
+    ("persistant", "persistent"),
+    ("posible", "possible"),
+    ("prefered", "preferred"),
+    ("priviledge", "privilege"),
+    ("probaly", "probably"),
+    ("proccess", "process"),
+    ("publically", "publicly"),
+    ("recieve", "receive"),
+    ("recieved", "received"),
+    ("recomend", "recommend"),
+    ("refered", "referred"),
+    ("relevent", "relevant"),
+    ("repositiory", "repository"),
+    ("reponse", "response"),
+    ("requirment", "requirement"),
+    ("resouce", "resource"),
+    ("retreive", "retrieve"),
+    ("seperate", "separate"),
+    ("seperately", "separately"),
+    ("succesful", "successful"),
+    ("successfull", "successful"),
+    ("supress", "suppress"),
+    ("suprise", "surprise"),
+    ("teh", "the"),
+    ("threshhold", "threshold"),
+    ("tommorow", "tomorrow"),
+    ("truely", "truly"),
+    ("untill", "until"),
+    ("usefull", "useful"),
+    ("wich", "which"),
+    ("writting", "writing"),
+];
+
+/// A word the checker knows a correction for
+#[derive(Debug, Clone, PartialEq)]
+pub struct Misspelling {
+    pub word: String,
+    pub suggestion: String,
+    /// 1-based line within the checked text
+    pub line: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct SpellChecker {
+    corrections: HashMap<String, String>,
+}
+
+impl Default for SpellChecker {
+    fn default() -> Self {
+        Self {
+            corrections: COMMON_MISSPELLINGS
+                .iter()
+                .map(|(wrong, right)| ((*wrong).to_string(), (*right).to_string()))
+                .collect(),
+        }
+    }
+}
+
+impl SpellChecker {
+    /// Built-in corrections plus the project dictionary, if the file exists
+    pub fn load(root: &Path) -> Result<Self> {
+        let mut checker = Self::default();
+        let path = root.join(DICTIONARY_FILE);
+        if path.exists() {
+            let content = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            checker.add_dictionary(&content);
+        }
+        Ok(checker)
+    }
+
+    /// Apply dictionary lines: `word` accepts it, `wrong -> right` corrects it
+    pub fn add_dictionary(&mut self, content: &str) {
+        let mut accepted = HashSet::new();
+        for line in content.lines() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            match line.split_once("->") {
+                Some((wrong, right)) => {
+                    self.corrections
+                        .insert(wrong.trim().to_lowercase(), right.trim().to_string());
+                }
+                None => {
+                    accepted.insert(line.to_lowercase());
+                }
+            }
+        }
+        self.corrections
+            .retain(|wrong, _| !accepted.contains(wrong));
+    }
+
+    /// Misspellings in `text`, in order, skipping code and URLs
+    pub fn check(&self, text: &str) -> Vec<Misspelling> {
+        let inline_code = Regex::new(r"`[^`]*`").expect("valid inline code regex");
+        let word = Regex::new(r"[A-Za-z]+(?:'[A-Za-z]+)*").expect("valid word regex");
+
+        let mut found = Vec::new();
+        let mut in_fence = false;
+        for (i, line) in text.lines().enumerate() {
+            if line.trim_start().starts_with("```") {
+                in_fence = !in_fence;
+                continue;
+            }
+            if in_fence {
+                continue;
+            }
+            let line = inline_code.replace_all(line, " ");
+            for token in line.split_whitespace().filter(|t| !t.contains("://")) {
+                for m in word.find_iter(token) {
+                    if let Some(right) = self.corrections.get(&m.as_str().to_lowercase()) {
+                        found.push(Misspelling {
+                            word: m.as_str().to_string(),
+                            suggestion: match_case(m.as_str(), right),
+                            line: i + 1,
+                        });
+                    }
+                }
+            }
+        }
+        found
+    }
+}
+
+/// Capitalize the suggestion when the misspelled word was
+fn match_case(word: &str, suggestion: &str) -> String {
+    let mut chars = suggestion.chars();
+    match (word.chars().next(), chars.next()) {
+        (Some(w), Some(first)) if w.is_uppercase() => first.to_uppercase().chain(chars).collect(),
+        _ => suggestion.to_string(),
+    }
+}
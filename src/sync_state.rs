@@ -0,0 +1,49 @@
+//! Where the last `sync` left off on each branch
+//!
+//! `sync` reports only tasks with commits made since the commit it last
+//! analyzed on the current branch, so running it every morning shows what
+//! happened since yesterday. The state is per clone and gitignored.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// State file, relative to the project root
+pub const STATE_FILE: &str = ".taskguard/sync-state.json";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SyncState {
+    /// Ref name → OID of the last commit analyzed on it
+    branches: BTreeMap<String, String>,
+}
+
+impl SyncState {
+    /// Load the state, starting empty when it's missing or unreadable
+    pub fn load(root: &Path) -> Self {
+        fs::read_to_string(root.join(STATE_FILE))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Last commit analyzed on `branch`
+    pub fn last(&self, branch: &str) -> Option<&str> {
+        self.branches.get(branch).map(String::as_str)
+    }
+
+    pub fn record(&mut self, branch: &str, oid: &str) {
+        self.branches.insert(branch.to_string(), oid.to_string());
+    }
+
+    pub fn save(&self, root: &Path) -> Result<()> {
+        let path = root.join(STATE_FILE);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+        fs::write(&path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
@@ -1,8 +1,10 @@
 use anyhow::{Context, Result};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskYaml {
@@ -12,13 +14,50 @@ pub struct TaskYaml {
     pub priority: Priority,
     pub tags: Vec<String>,
     pub dependencies: Vec<String>,
-    pub assignee: Option<String>,
+    #[serde(skip_serializing_if = "Relations::is_empty")]
+    pub relations: Relations,
+    #[serde(rename = "assignee", serialize_with = "serialize_assignees")]
+    pub assignees: Vec<String>,
     pub created: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub claimed: Option<DateTime<Utc>>,
     pub estimate: Option<String>,
     pub complexity: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due: Option<NaiveDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub epic: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub time_log: Vec<TimeEntry>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub links: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub release: Option<String>,
     pub area: String,
+    pub format_version: u32,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_yaml::Value>,
 }
 
+/// Task file schema version written by this release; files without one are version 0
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Status names used by older task files and what `taskguard migrate` renames them to
+pub const LEGACY_STATUSES: [(&str, &str); 5] = [
+    ("pending", "todo"),
+    ("in-progress", "doing"),
+    ("in_progress", "doing"),
+    ("in-review", "review"),
+    ("completed", "done"),
+];
+
+/// Tasks due within this many days are flagged as "due soon"
+pub const DUE_SOON_DAYS: i64 = 3;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Task {
     pub id: String,
@@ -31,12 +70,50 @@ pub struct Task {
     pub tags: Vec<String>,
     #[serde(default)]
     pub dependencies: Vec<String>,
-    pub assignee: Option<String>,
+    #[serde(default)]
+    pub relations: Relations,
+    /// Written as `assignee:` in frontmatter, either a single name or a list
+    #[serde(
+        rename = "assignee",
+        default,
+        deserialize_with = "deserialize_assignees"
+    )]
+    pub assignees: Vec<String>,
     #[serde(default = "default_created")]
     pub created: DateTime<Utc>,
+    /// When the task last reached `done`; cleared when it is reopened
+    #[serde(default)]
+    pub completed: Option<DateTime<Utc>>,
+    /// When the assignee took the task with `taskguard claim`
+    #[serde(default)]
+    pub claimed: Option<DateTime<Utc>>,
     pub estimate: Option<String>,
     pub complexity: Option<u8>,
+    #[serde(default)]
+    pub due: Option<NaiveDate>,
+    /// Cross-area initiative this task belongs to
+    #[serde(default)]
+    pub epic: Option<String>,
+    /// Work sessions recorded with `taskguard time`
+    #[serde(default)]
+    pub time_log: Vec<TimeEntry>,
+    /// Design docs, PRs, dashboards: URLs or paths relative to the task file or project root
+    #[serde(default)]
+    pub links: Vec<String>,
+    /// Git branch for the task's work, recorded by `taskguard branch`
+    #[serde(default)]
+    pub branch: Option<String>,
+    /// First release tag containing the task's commits, recorded by
+    /// `taskguard release associate`
+    #[serde(default)]
+    pub release: Option<String>,
     pub area: String,
+    /// Schema version the file was written with; `taskguard migrate` upgrades old files
+    #[serde(default)]
+    pub format_version: u32,
+    /// Project-specific frontmatter fields (see `[fields]` in config.toml)
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_yaml::Value>,
     #[serde(skip)]
     pub content: String,
     #[serde(skip)]
@@ -55,6 +132,124 @@ fn default_created() -> DateTime<Utc> {
     Utc::now()
 }
 
+/// Typed links to other tasks, in addition to `dependencies`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Relations {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blocks: Vec<String>,
+    #[serde(default, rename = "relates-to", skip_serializing_if = "Vec::is_empty")]
+    pub relates_to: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub duplicates: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+}
+
+impl Relations {
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+            && self.relates_to.is_empty()
+            && self.duplicates.is_empty()
+            && self.parent.is_none()
+    }
+
+    /// All links as (relation kind, target task ID) pairs
+    pub fn links(&self) -> Vec<(&'static str, &str)> {
+        let mut links = Vec::new();
+        links.extend(self.blocks.iter().map(|id| ("blocks", id.as_str())));
+        links.extend(self.relates_to.iter().map(|id| ("relates-to", id.as_str())));
+        links.extend(self.duplicates.iter().map(|id| ("duplicates", id.as_str())));
+        links.extend(self.parent.iter().map(|id| ("parent", id.as_str())));
+        links
+    }
+}
+
+/// A recorded work session; `minutes` is unset while the timer is running
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TimeEntry {
+    pub started: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub minutes: Option<u32>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OneOrMany {
+    One(String),
+    Many(Vec<String>),
+}
+
+fn deserialize_assignees<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value: Option<OneOrMany> = Option::deserialize(deserializer)?;
+    Ok(match value {
+        None => Vec::new(),
+        Some(OneOrMany::One(name)) => vec![name],
+        Some(OneOrMany::Many(names)) => names,
+    })
+}
+
+/// Keep single-assignee files unchanged: null, a plain string, or a list
+#[allow(clippy::ptr_arg)]
+fn serialize_assignees<S>(assignees: &Vec<String>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match assignees.as_slice() {
+        [] => serializer.serialize_none(),
+        [name] => serializer.serialize_str(name),
+        names => names.serialize(serializer),
+    }
+}
+
+/// Split a comma-separated assignee list, dropping empty entries
+pub fn parse_assignees(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Clean up an area name from user input; nested areas use `/` (`backend/auth`)
+pub fn normalize_area(area: &str) -> Result<String> {
+    let segments: Vec<&str> = area
+        .trim()
+        .split(['/', '\\'])
+        .filter(|s| !s.is_empty())
+        .collect();
+    if segments.is_empty() {
+        return Err(anyhow::anyhow!("Area name cannot be empty"));
+    }
+    if let Some(bad) = segments
+        .iter()
+        .find(|s| s.starts_with('.') || s.chars().any(char::is_whitespace))
+    {
+        return Err(anyhow::anyhow!(
+            "Invalid area segment '{}'. Use names like 'backend' or 'backend/auth'",
+            bad
+        ));
+    }
+    Ok(segments.join("/"))
+}
+
+/// Whether `area` is `filter` or nested below it (`backend` matches `backend/auth`)
+pub fn area_matches(area: &str, filter: &str) -> bool {
+    let filter = filter.trim_matches('/');
+    area == filter
+        || area
+            .strip_prefix(filter)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// Task ID prefix for an area: `backend/auth` gives IDs like `backend-auth-001`
+pub fn area_id_prefix(area: &str) -> String {
+    area.replace('/', "-")
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum TaskStatus {
     #[serde(rename = "todo")]
@@ -69,6 +264,22 @@ pub enum TaskStatus {
     Blocked,
 }
 
+impl TaskStatus {
+    pub fn parse(value: &str) -> Result<Self> {
+        match value {
+            "todo" => Ok(TaskStatus::Todo),
+            "doing" => Ok(TaskStatus::Doing),
+            "review" => Ok(TaskStatus::Review),
+            "done" => Ok(TaskStatus::Done),
+            "blocked" => Ok(TaskStatus::Blocked),
+            _ => Err(anyhow::anyhow!(
+                "Invalid status '{}'. Valid statuses: todo, doing, review, done, blocked",
+                value
+            )),
+        }
+    }
+}
+
 impl std::fmt::Display for TaskStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -93,6 +304,18 @@ pub enum Priority {
     Critical,
 }
 
+impl Priority {
+    /// The next priority level up; `critical` stays `critical`
+    #[must_use]
+    pub fn escalated(&self) -> Priority {
+        match self {
+            Priority::Low => Priority::Medium,
+            Priority::Medium => Priority::High,
+            Priority::High | Priority::Critical => Priority::Critical,
+        }
+    }
+}
+
 impl std::fmt::Display for Priority {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -115,21 +338,43 @@ impl Task {
     }
 
     pub fn parse_content(content: &str) -> Result<Self> {
-        // Split YAML front-matter from markdown content
-        let parts: Vec<&str> = content.splitn(3, "---").collect();
-
-        if parts.len() < 3 {
+        // Split front-matter (YAML or TOML) from markdown content
+        let Some((format, frontmatter, markdown_content, _)) = split_frontmatter(content) else {
             return Err(anyhow::anyhow!(
                 "Invalid task file format: missing YAML front-matter"
             ));
-        }
+        };
+        let markdown_content = markdown_content.trim();
 
-        let yaml_content = parts[1].trim();
-        let markdown_content = parts[2].trim();
+        let parsed: Result<Task> = match format {
+            FrontmatterFormat::Yaml => serde_yaml::from_str(frontmatter).map_err(Into::into),
+            FrontmatterFormat::Toml => toml::from_str(frontmatter).map_err(Into::into),
+        };
 
-        // Parse YAML front-matter
-        let mut task: Task = serde_yaml::from_str(yaml_content)
-            .with_context(|| format!("Failed to parse YAML front-matter: {}", yaml_content))?;
+        // Parse front-matter, explaining schema problems when it doesn't fit
+        let mut task = match parsed {
+            Ok(task) => task,
+            Err(e) => {
+                let issues = validate_frontmatter(content);
+                if issues.is_empty() {
+                    return Err(e).with_context(|| {
+                        format!(
+                            "Failed to parse {} front-matter: {}",
+                            format.to_string().to_uppercase(),
+                            frontmatter
+                        )
+                    });
+                }
+                let details: Vec<String> = issues
+                    .iter()
+                    .map(|issue| format!("line {}: {}", issue.line, issue))
+                    .collect();
+                return Err(anyhow::anyhow!(
+                    "Invalid frontmatter: {}",
+                    details.join("; ")
+                ));
+            }
+        };
 
         // Add markdown content
         task.content = markdown_content.to_string();
@@ -141,21 +386,44 @@ impl Task {
     }
 
     pub fn to_file_content(&self) -> Result<String> {
-        // Create a copy without the content field for YAML serialization
-        let yaml_task = TaskYaml {
+        self.to_file_content_as(FrontmatterFormat::Yaml)
+    }
+
+    /// Render the task file with front-matter in the given format
+    pub fn to_file_content_as(&self, format: FrontmatterFormat) -> Result<String> {
+        // Create a copy without the content field for serialization
+        let mut yaml_task = TaskYaml {
             id: self.id.clone(),
             title: self.title.clone(),
             status: self.status.clone(),
             priority: self.priority.clone(),
             tags: self.tags.clone(),
             dependencies: self.dependencies.clone(),
-            assignee: self.assignee.clone(),
+            relations: self.relations.clone(),
+            assignees: self.assignees.clone(),
             created: self.created,
+            completed: self.completed,
+            claimed: self.claimed,
             estimate: self.estimate.clone(),
             complexity: self.complexity,
+            due: self.due,
+            epic: self.epic.clone(),
+            time_log: self.time_log.clone(),
+            links: self.links.clone(),
+            branch: self.branch.clone(),
+            release: self.release.clone(),
             area: self.area.clone(),
+            format_version: self.format_version,
+            extra: self.extra.clone(),
         };
 
+        if format == FrontmatterFormat::Toml {
+            // TOML has no null, so custom fields set to null are left out
+            yaml_task.extra.retain(|_, value| !value.is_null());
+            let toml = toml::to_string(&yaml_task).context("Failed to serialize task to TOML")?;
+            return Ok(format!("+++\n{}\n+++\n\n{}", toml.trim(), self.content));
+        }
+
         let mut yaml =
             serde_yaml::to_string(&yaml_task).context("Failed to serialize task to YAML")?;
 
@@ -176,8 +444,199 @@ impl Task {
             .all(|dep| completed_tasks.contains(dep))
     }
 
+    /// Parent task ID from `relations.parent`, if this is a subtask
+    pub fn parent(&self) -> Option<&str> {
+        self.relations.parent.as_deref()
+    }
+
+    /// Completed and total direct subtasks, or `None` if the task has no children
+    pub fn subtask_progress(&self, tasks: &[Task]) -> Option<(usize, usize)> {
+        let children: Vec<&Task> = tasks
+            .iter()
+            .filter(|t| t.parent() == Some(self.id.as_str()))
+            .collect();
+        if children.is_empty() {
+            return None;
+        }
+        let done = children
+            .iter()
+            .filter(|t| t.status == TaskStatus::Done)
+            .count();
+        Some((done, children.len()))
+    }
+
+    /// Checked and total `- [ ]` checklist items in the task body, if it has any.
+    /// Parent items of nested checklists are not counted, only their sub-items.
+    pub fn checklist_progress(&self) -> Option<(usize, usize)> {
+        let items = crate::checklist::parse_items(&self.content);
+        let leaves: Vec<_> = crate::checklist::leaf_items(&items).collect();
+        if leaves.is_empty() {
+            return None;
+        }
+        let done = leaves.iter().filter(|item| item.completed).count();
+        Some((done, leaves.len()))
+    }
+
+    /// Checklist completion as a whole percentage
+    pub fn progress_percent(&self) -> Option<u8> {
+        self.checklist_progress()
+            .map(|(done, total)| (done * 100 / total) as u8)
+    }
+
+    /// Total minutes from completed work sessions
+    pub fn logged_minutes(&self) -> u32 {
+        self.time_log.iter().filter_map(|e| e.minutes).sum()
+    }
+
+    /// The session started with `taskguard time start` and not yet stopped
+    pub fn running_timer(&self) -> Option<&TimeEntry> {
+        self.time_log.iter().find(|e| e.minutes.is_none())
+    }
+
+    /// The `estimate` field in minutes, if it is a valid estimate
+    pub fn estimate_minutes(&self) -> Option<u32> {
+        self.estimate.as_deref().and_then(estimate_to_minutes)
+    }
+
+    /// Days remaining until the due date (negative when overdue)
+    pub fn days_until_due(&self, today: NaiveDate) -> Option<i64> {
+        self.due.map(|due| (due - today).num_days())
+    }
+
+    /// An open task whose due date has passed
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        self.status != TaskStatus::Done && self.days_until_due(today).is_some_and(|d| d < 0)
+    }
+
+    /// An open task due within `DUE_SOON_DAYS` (but not yet overdue)
+    pub fn is_due_soon(&self, today: NaiveDate) -> bool {
+        self.status != TaskStatus::Done
+            && self
+                .days_until_due(today)
+                .is_some_and(|d| (0..=DUE_SOON_DAYS).contains(&d))
+    }
+
+    /// Why an open task with a future due date may miss it
+    ///
+    /// Flags remaining estimated work that exceeds the working time left
+    /// (8h weekdays, as in `estimate`) and dependencies listed in `incomplete`.
+    pub fn deadline_risks(&self, incomplete: &HashSet<String>, today: NaiveDate) -> Vec<String> {
+        let Some(due) = self.due else {
+            return Vec::new();
+        };
+        if self.status == TaskStatus::Done || due < today {
+            return Vec::new();
+        }
+
+        let mut risks = Vec::new();
+        if let Some(estimate) = self.estimate_minutes() {
+            let remaining = estimate.saturating_sub(self.logged_minutes());
+            let available = working_minutes_until(today, due);
+            if remaining > available {
+                risks.push(format!(
+                    "needs {} of work, {} left before {}",
+                    format_minutes(remaining),
+                    format_minutes(available),
+                    due
+                ));
+            }
+        }
+
+        let waiting: Vec<&str> = self
+            .dependencies
+            .iter()
+            .filter(|dep| incomplete.contains(*dep))
+            .map(String::as_str)
+            .collect();
+        if !waiting.is_empty() {
+            risks.push(format!("waiting on {}", waiting.join(", ")));
+        }
+
+        risks
+    }
+
+    /// Change the status, stamping `completed` on reaching done and clearing it on reopen
+    pub fn set_status(&mut self, status: TaskStatus) {
+        if status == TaskStatus::Done {
+            if self.status != TaskStatus::Done || self.completed.is_none() {
+                self.completed = Some(Utc::now());
+            }
+        } else {
+            self.completed = None;
+        }
+        self.status = status;
+    }
+
+    /// When a done task was completed: the `completed` timestamp, or the task
+    /// file's modification time for tasks finished before it was recorded
+    pub fn completed_at(&self) -> Option<DateTime<Utc>> {
+        if self.status != TaskStatus::Done {
+            return None;
+        }
+        self.completed.or_else(|| {
+            fs::metadata(&self.file_path)
+                .and_then(|m| m.modified())
+                .ok()
+                .map(DateTime::<Utc>::from)
+        })
+    }
+
+    /// Display value for a built-in or custom frontmatter field, if set
+    pub fn field_display(&self, name: &str) -> Option<String> {
+        match name {
+            "id" => Some(self.id.clone()),
+            "title" => Some(self.title.clone()),
+            "status" => Some(self.status.to_string()),
+            "priority" => Some(self.priority.to_string()),
+            "area" => Some(self.area.clone()),
+            "assignee" | "assignees" => Some(self.assignees.join(", ")).filter(|a| !a.is_empty()),
+            "estimate" => self.estimate.clone(),
+            "complexity" => self.complexity.map(|c| c.to_string()),
+            "due" => self.due.map(|d| d.to_string()),
+            "epic" => self.epic.clone(),
+            "branch" => self.branch.clone(),
+            "release" => self.release.clone(),
+            "progress" => self.progress_percent().map(|p| format!("{}%", p)),
+            "tags" => Some(self.tags.join(", ")).filter(|t| !t.is_empty()),
+            "dependencies" => Some(self.dependencies.join(", ")).filter(|d| !d.is_empty()),
+            _ => self.extra.get(name).map(yaml_value_display),
+        }
+    }
+
+    /// Problems with `links:` entries: malformed URLs, and relative paths found
+    /// neither next to the task file nor under `project_root`
+    pub fn link_issues(&self, project_root: &Path) -> Vec<String> {
+        let task_dir = self.file_path.parent().unwrap_or(project_root);
+        self.links
+            .iter()
+            .filter_map(|link| {
+                let link = link.trim();
+                if link.is_empty() {
+                    return Some("empty link entry".to_string());
+                }
+                if link.contains("://") || link.starts_with("http:") || link.starts_with("https:") {
+                    return (!is_valid_url(link)).then(|| format!("malformed URL '{}'", link));
+                }
+
+                // Anchors and query strings don't affect whether the file exists
+                let path = link.split(['#', '?']).next().unwrap_or(link);
+                let found = if Path::new(path).is_absolute() {
+                    Path::new(path).exists()
+                } else {
+                    task_dir.join(path).exists() || project_root.join(path).exists()
+                };
+                (!found).then(|| format!("linked file '{}' not found", path))
+            })
+            .collect()
+    }
+
     pub fn save_to_file<P: AsRef<Path>>(&self, path: P) -> Result<()> {
-        let content = self.to_file_content()?;
+        // Keep an existing file's front-matter format; new files follow the project setting
+        let format = fs::read_to_string(&path).map_or_else(
+            |_| crate::config::frontmatter_format(),
+            |existing| FrontmatterFormat::detect(&existing),
+        );
+        let content = self.to_file_content_as(format)?;
 
         // Ensure parent directory exists
         if let Some(parent) = path.as_ref().parent() {
@@ -191,3 +650,528 @@ impl Task {
         Ok(())
     }
 }
+
+static URL_RE: LazyLock<Option<regex::Regex>> = LazyLock::new(|| {
+    regex::Regex::new(r"^https?://[A-Za-z0-9-]+(\.[A-Za-z0-9-]+)*(:\d+)?([/?#]\S*)?$").ok()
+});
+
+/// An http(s) URL with a host and no whitespace
+fn is_valid_url(link: &str) -> bool {
+    URL_RE.as_ref().is_some_and(|re| re.is_match(link))
+}
+
+/// Parse a due date given as `YYYY-MM-DD`
+pub fn parse_due_date(value: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d")
+        .with_context(|| format!("Invalid date '{}'. Expected format: YYYY-MM-DD", value))
+}
+
+/// Syntax of the front-matter block at the top of a task file
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FrontmatterFormat {
+    /// `---`-delimited YAML
+    #[default]
+    Yaml,
+    /// `+++`-delimited TOML
+    Toml,
+}
+
+impl FrontmatterFormat {
+    /// Format of existing file content, judged by its opening delimiter
+    pub fn detect(content: &str) -> Self {
+        if content.trim_start().starts_with("+++") {
+            FrontmatterFormat::Toml
+        } else {
+            FrontmatterFormat::Yaml
+        }
+    }
+
+    pub fn delimiter(self) -> &'static str {
+        match self {
+            FrontmatterFormat::Yaml => "---",
+            FrontmatterFormat::Toml => "+++",
+        }
+    }
+}
+
+impl std::fmt::Display for FrontmatterFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FrontmatterFormat::Yaml => write!(f, "yaml"),
+            FrontmatterFormat::Toml => write!(f, "toml"),
+        }
+    }
+}
+
+/// Front-matter format and block, markdown body, and the file line the block starts on
+pub(crate) fn split_frontmatter(content: &str) -> Option<(FrontmatterFormat, &str, &str, usize)> {
+    let format = FrontmatterFormat::detect(content);
+    let parts: Vec<&str> = content.splitn(3, format.delimiter()).collect();
+    if parts.len() < 3 {
+        return None;
+    }
+
+    let block = parts[1];
+    let leading = &block[..block.len() - block.trim_start().len()];
+    let first_line =
+        1 + content[..parts[0].len() + 3].matches('\n').count() + leading.matches('\n').count();
+    Some((format, block.trim(), parts[2], first_line))
+}
+
+/// 1-based line in a task file where the markdown body starts
+pub fn body_start_line(content: &str) -> Option<usize> {
+    let (_, _, body, _) = split_frontmatter(content)?;
+    let start = content.len() - body.trim_start().len();
+    Some(1 + content[..start].matches('\n').count())
+}
+
+const STATUS_VALUES: [&str; 5] = ["todo", "doing", "review", "done", "blocked"];
+const PRIORITY_VALUES: [&str; 4] = ["low", "medium", "high", "critical"];
+
+/// A frontmatter problem found by [`validate_frontmatter`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaIssue {
+    /// 1-based line in the task file
+    pub line: usize,
+    pub field: String,
+    pub expected: String,
+    /// What the file has instead, e.g. `string ("urgent")` or `missing`
+    pub found: String,
+    pub fix: String,
+}
+
+impl std::fmt::Display for SchemaIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "field '{}' should be {}, found {} (fix: {})",
+            self.field, self.expected, self.found, self.fix
+        )
+    }
+}
+
+/// Check a task file's frontmatter against the built-in field types
+///
+/// Accepts the same shapes the loader does, so a file with no issues parses.
+/// Fields outside the built-in set are left to `check_custom_fields`.
+pub fn validate_frontmatter(content: &str) -> Vec<SchemaIssue> {
+    use serde_yaml::Value;
+
+    let Some((format, frontmatter, _, first_line)) = split_frontmatter(content) else {
+        return vec![SchemaIssue {
+            line: 1,
+            field: "frontmatter".to_string(),
+            expected: "a YAML block between '---' lines".to_string(),
+            found: "missing".to_string(),
+            fix: "start the file with '---', the task fields, then another '---'".to_string(),
+        }];
+    };
+    let separator = match format {
+        FrontmatterFormat::Yaml => ':',
+        FrontmatterFormat::Toml => '=',
+    };
+    let line_of = |field: &str| {
+        frontmatter
+            .lines()
+            .position(|l| {
+                l.strip_prefix(field)
+                    .is_some_and(|rest| rest.trim_start().starts_with(separator))
+            })
+            .map_or(first_line, |i| first_line + i)
+    };
+
+    let parsed = match format {
+        FrontmatterFormat::Yaml => serde_yaml::from_str::<Value>(frontmatter),
+        FrontmatterFormat::Toml => match toml::from_str::<toml::Table>(frontmatter) {
+            Ok(table) => serde_yaml::to_value(table),
+            Err(e) => {
+                let offset = e.span().map_or(0, |span| span.start);
+                return vec![SchemaIssue {
+                    line: first_line + frontmatter[..offset].matches('\n').count(),
+                    field: "frontmatter".to_string(),
+                    expected: "valid TOML".to_string(),
+                    found: format!("a syntax error ({})", e.message().trim()),
+                    fix: "use 'field = value' lines and quote strings and dates".to_string(),
+                }];
+            }
+        },
+    };
+
+    let mapping = match parsed {
+        Ok(Value::Mapping(mapping)) => mapping,
+        Ok(other) => {
+            return vec![SchemaIssue {
+                line: first_line,
+                field: "frontmatter".to_string(),
+                expected: "a mapping of 'field: value' lines".to_string(),
+                found: describe_yaml_value(&other),
+                fix: "write one 'field: value' pair per line".to_string(),
+            }];
+        }
+        Err(e) => {
+            let message = e.to_string();
+            let message = message.split(" at line ").next().unwrap_or(&message);
+            return vec![SchemaIssue {
+                line: e
+                    .location()
+                    .map_or(first_line, |l| first_line + l.line() - 1),
+                field: "frontmatter".to_string(),
+                expected: "valid YAML".to_string(),
+                found: format!("a syntax error ({})", message),
+                fix: "check indentation, and quote values containing ':' or '#'".to_string(),
+            }];
+        }
+    };
+
+    let mut issues = Vec::new();
+    for field in ["id", "title", "area"] {
+        if matches!(mapping.get(field), None | Some(Value::Null)) {
+            issues.push(SchemaIssue {
+                line: first_line,
+                field: field.to_string(),
+                expected: "a string".to_string(),
+                found: "missing".to_string(),
+                fix: format!("add '{}: ...' to the frontmatter", field),
+            });
+        }
+    }
+
+    for (key, value) in &mapping {
+        let Some(field) = key.as_str() else { continue };
+        if let Some((expected, fix)) = field_problem(field, value) {
+            issues.push(SchemaIssue {
+                line: line_of(field),
+                field: field.to_string(),
+                expected,
+                found: describe_yaml_value(value),
+                fix,
+            });
+        }
+    }
+
+    issues.sort_by_key(|issue| issue.line);
+    issues
+}
+
+/// Expected type and suggested fix when a built-in field has the wrong shape
+fn field_problem(field: &str, value: &serde_yaml::Value) -> Option<(String, String)> {
+    use serde_yaml::Value;
+
+    let is_scalar = |v: &Value| matches!(v, Value::String(_) | Value::Number(_) | Value::Bool(_));
+    let is_scalar_list = |v: &Value| match v {
+        Value::Null => true,
+        Value::Sequence(items) => items.iter().all(is_scalar),
+        _ => false,
+    };
+    let list_fix = |field: &str, v: &Value| match v {
+        Value::String(s) => format!("write it as a list: '{}: [{}]'", field, s),
+        _ => format!("write it as a list, e.g. '{}: [a, b]'", field),
+    };
+    let one_of = |values: &[&str]| {
+        let valid = matches!(value, Value::String(s) if values.contains(&s.as_str()));
+        let fix = match value.as_str().map(str::to_lowercase) {
+            Some(lower) if values.contains(&lower.as_str()) => {
+                format!("use lowercase: '{}: {}'", field, lower)
+            }
+            _ => format!("use one of {}", values.join(", ")),
+        };
+        (!valid).then(|| (format!("one of {}", values.join(", ")), fix))
+    };
+
+    match field {
+        "id" | "title" | "area" => (!is_scalar(value)).then(|| {
+            (
+                "a string".to_string(),
+                format!("put the {} on one line, e.g. '{}: ...'", field, field),
+            )
+        }),
+        "status" => one_of(&STATUS_VALUES).map(|(expected, fix)| {
+            let renamed = value.as_str().and_then(|s| {
+                LEGACY_STATUSES
+                    .iter()
+                    .find(|(old, _)| *old == s)
+                    .map(|(_, new)| new)
+            });
+            match renamed {
+                Some(new) => (
+                    expected,
+                    format!("run 'taskguard migrate' to rename it to '{}'", new),
+                ),
+                None => (expected, fix),
+            }
+        }),
+        "priority" => one_of(&PRIORITY_VALUES),
+        "tags" | "dependencies" | "links" => (!is_scalar_list(value))
+            .then(|| ("a list of strings".to_string(), list_fix(field, value))),
+        "assignee" => {
+            let valid = match value {
+                Value::Null | Value::String(_) => true,
+                Value::Sequence(items) => items.iter().all(Value::is_string),
+                _ => false,
+            };
+            (!valid).then(|| {
+                (
+                    "a name or a list of names".to_string(),
+                    "quote each name, e.g. 'assignee: \"alice\"'".to_string(),
+                )
+            })
+        }
+        "created" | "completed" | "claimed" => {
+            let valid = (field != "created" && value.is_null())
+                || value
+                    .as_str()
+                    .is_some_and(|s| s.parse::<DateTime<Utc>>().is_ok());
+            (!valid).then(|| {
+                (
+                    "an RFC 3339 timestamp".to_string(),
+                    "use a quoted timestamp, e.g. \"2025-01-15T09:00:00Z\"".to_string(),
+                )
+            })
+        }
+        "estimate" | "epic" | "branch" | "release" => (!(value.is_null() || is_scalar(value)))
+            .then(|| {
+                (
+                    "a string".to_string(),
+                    format!("put the value on one line, e.g. '{}: ...'", field),
+                )
+            }),
+        "complexity" => {
+            let valid = value.is_null() || value.as_u64().is_some_and(|n| (1..=10).contains(&n));
+            (!valid).then(|| {
+                (
+                    "a whole number from 1 to 10".to_string(),
+                    "use an unquoted number, e.g. 'complexity: 3'".to_string(),
+                )
+            })
+        }
+        "format_version" => value
+            .as_u64()
+            .is_none_or(|n| u32::try_from(n).is_err())
+            .then(|| {
+                (
+                    "a whole number".to_string(),
+                    "run 'taskguard migrate' to set it".to_string(),
+                )
+            }),
+        "due" => {
+            let valid = value.is_null()
+                || value
+                    .as_str()
+                    .is_some_and(|s| s.parse::<NaiveDate>().is_ok());
+            (!valid).then(|| {
+                (
+                    "a date (YYYY-MM-DD)".to_string(),
+                    "use a quoted date, e.g. \"2025-03-01\"".to_string(),
+                )
+            })
+        }
+        "time_log" => {
+            let entry_ok = |entry: &Value| {
+                entry
+                    .get("started")
+                    .and_then(Value::as_str)
+                    .is_some_and(|s| s.parse::<DateTime<Utc>>().is_ok())
+                    && entry.get("minutes").is_none_or(|m| {
+                        m.is_null() || m.as_u64().is_some_and(|n| u32::try_from(n).is_ok())
+                    })
+            };
+            let valid = match value {
+                Value::Null => true,
+                Value::Sequence(entries) => entries.iter().all(entry_ok),
+                _ => false,
+            };
+            (!valid).then(|| {
+                (
+                    "a list of sessions with 'started' and optional 'minutes'".to_string(),
+                    "record sessions with 'taskguard time start/stop' or write \
+                     '- started: 2025-01-15T09:00:00Z' with 'minutes: 30'"
+                        .to_string(),
+                )
+            })
+        }
+        "relations" => {
+            let valid = match value {
+                Value::Mapping(relations) => {
+                    relations.iter().all(|(kind, targets)| match kind.as_str() {
+                        Some("blocks" | "relates-to" | "duplicates") => is_scalar_list(targets),
+                        Some("parent") => targets.is_null() || is_scalar(targets),
+                        _ => false,
+                    })
+                }
+                _ => false,
+            };
+            (!valid).then(|| {
+                (
+                    "a mapping of blocks, relates-to, duplicates and parent".to_string(),
+                    "use e.g. 'relations: { blocks: [backend-002], parent: backend-001 }'"
+                        .to_string(),
+                )
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Short description of a frontmatter value's type, for error messages
+pub fn describe_yaml_value(value: &serde_yaml::Value) -> String {
+    use serde_yaml::Value;
+
+    match value {
+        Value::Null => "null".to_string(),
+        Value::Bool(b) => format!("bool ({})", b),
+        Value::Number(n) => format!("number ({})", n),
+        Value::String(s) => format!("string (\"{}\")", s),
+        Value::Sequence(_) => "list".to_string(),
+        Value::Mapping(_) => "mapping".to_string(),
+        Value::Tagged(_) => "tagged value".to_string(),
+    }
+}
+
+/// Render a frontmatter value for display in listings
+fn yaml_value_display(value: &serde_yaml::Value) -> String {
+    use serde_yaml::Value;
+
+    match value {
+        Value::Null => "~".to_string(),
+        Value::Bool(b) => b.to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => s.clone(),
+        Value::Sequence(items) => items
+            .iter()
+            .map(yaml_value_display)
+            .collect::<Vec<_>>()
+            .join(", "),
+        other => serde_yaml::to_string(other)
+            .map(|s| s.trim().to_string())
+            .unwrap_or_default(),
+    }
+}
+
+/// An amount with its unit, e.g. "30m" or "1.5 days"
+const DURATION_PART: &str =
+    r"(\d+(?:\.\d+)?)\s*(months?|mo|weeks?|wk|w|days?|d|hours?|hrs?|h|minutes?|mins?|m)";
+
+static DURATION_PART_RE: LazyLock<Option<regex::Regex>> =
+    LazyLock::new(|| regex::Regex::new(&format!("(?i){}", DURATION_PART)).ok());
+
+/// A whole string of duration parts, optionally comma-separated
+static DURATION_RE: LazyLock<Option<regex::Regex>> =
+    LazyLock::new(|| regex::Regex::new(&format!(r"(?i)^\s*(?:{}\s*,?\s*)+$", DURATION_PART)).ok());
+
+/// Minutes per canonical estimate unit, largest first (8h days, 5-day weeks)
+const ESTIMATE_UNITS: [(char, u32); 4] = [('w', 5 * 8 * 60), ('d', 8 * 60), ('h', 60), ('m', 1)];
+
+/// Split a duration into (amount, unit) parts with units reduced to w/d/h/m
+///
+/// The whole string must be amounts with units, so "2h garbage" is rejected;
+/// months count as 4 weeks.
+fn estimate_parts(value: &str) -> Option<Vec<(f64, char)>> {
+    if !DURATION_RE.as_ref()?.is_match(value) {
+        return None;
+    }
+
+    DURATION_PART_RE
+        .as_ref()?
+        .captures_iter(value)
+        .map(|caps| {
+            let amount: f64 = caps[1].parse().ok()?;
+            let unit = caps[2].to_lowercase();
+            Some(match unit.chars().next()? {
+                'm' if unit.starts_with("mo") => (amount * 4.0, 'w'),
+                unit => (amount, unit),
+            })
+        })
+        .collect()
+}
+
+/// Validate an `estimate` and rewrite it in canonical form
+///
+/// "4 hours" becomes "4h", "2 days" becomes "2d" and "1 hour 30 mins" becomes
+/// "1h 30m". The units written are kept, so "8h" is not turned into "1d".
+pub fn normalize_estimate(value: &str) -> Result<String> {
+    let parts = estimate_parts(value).ok_or_else(|| {
+        anyhow::anyhow!(
+            "Invalid estimate '{}'. Use an amount and unit, e.g. 30m, 4h, 2d, 1w or 1h 30m",
+            value
+        )
+    })?;
+
+    let canonical: Vec<String> = ESTIMATE_UNITS
+        .iter()
+        .filter_map(|(unit, _)| {
+            let amount: f64 = parts
+                .iter()
+                .filter(|(_, u)| u == unit)
+                .map(|(a, _)| a)
+                .sum();
+            (amount > 0.0).then(|| format!("{}{}", amount, unit))
+        })
+        .collect();
+
+    Ok(if canonical.is_empty() {
+        "0h".to_string()
+    } else {
+        canonical.join(" ")
+    })
+}
+
+/// Minutes in a duration like "2h", "1h30m", "1.5d" or "1w", in the grammar
+/// `normalize_estimate` accepts
+///
+/// Days, weeks and months are working time (8h days, 5-day weeks, 4-week
+/// months). Estimates, logged time and workload limits all go through here.
+pub fn estimate_to_minutes(value: &str) -> Option<u32> {
+    let minutes: f64 = estimate_parts(value)?
+        .iter()
+        .filter_map(|(amount, unit)| {
+            ESTIMATE_UNITS
+                .iter()
+                .find(|(u, _)| u == unit)
+                .map(|(_, per)| amount * f64::from(*per))
+        })
+        .sum();
+    Some(minutes.round() as u32)
+}
+
+/// Format minutes of estimated work in working weeks, days and hours, e.g. "1w 2d 4h"
+pub fn format_estimate(minutes: u32) -> String {
+    let mut rest = minutes;
+    let parts: Vec<String> = ESTIMATE_UNITS
+        .iter()
+        .filter_map(|(unit, per)| {
+            let amount = rest / per;
+            rest %= per;
+            (amount > 0).then(|| format!("{}{}", amount, unit))
+        })
+        .collect();
+
+    if parts.is_empty() {
+        "0h".to_string()
+    } else {
+        parts.join(" ")
+    }
+}
+
+/// Working minutes (8h per weekday) from the start of `today` to the end of `due`
+pub(crate) fn working_minutes_until(today: NaiveDate, due: NaiveDate) -> u32 {
+    use chrono::Datelike;
+
+    let weekdays = today
+        .iter_days()
+        .take_while(|day| *day <= due)
+        .filter(|day| day.weekday().num_days_from_monday() < 5)
+        .count();
+    u32::try_from(weekdays)
+        .unwrap_or(u32::MAX)
+        .saturating_mul(8 * 60)
+}
+
+/// Format minutes as a compact duration, e.g. "2h 15m"
+pub fn format_minutes(minutes: u32) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{}m", m),
+        (h, 0) => format!("{}h", h),
+        (h, m) => format!("{}h {}m", h, m),
+    }
+}
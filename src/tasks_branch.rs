@@ -0,0 +1,273 @@
+//! Task files kept on a dedicated orphan branch (`[git] tasks_branch`)
+//!
+//! `tasks/` is then an ignored working copy of that branch. Before each
+//! command, commits made to the branch since the last run (fetched from a
+//! teammate, say) are merged into it file by file; afterwards whatever changed
+//! in `tasks/` is committed back to the branch. Both go through git2's tree
+//! and blob APIs, so the checked-out branch and the index are never touched.
+
+use anyhow::{Context, Result};
+use git2::{Commit, Index, IndexEntry, IndexTime, ObjectType, Oid, Repository, TreeWalkMode};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::PathBuf;
+use walkdir::WalkDir;
+
+use crate::config::{Config, find_taskguard_root};
+use crate::task::Task;
+
+/// Commit of the tasks branch that `tasks/` was last synced with, relative to
+/// the project root
+const STATE_FILE: &str = ".taskguard/state/tasks-branch";
+
+/// Path under `tasks/` (with `/` separators) → blob
+type Files = BTreeMap<String, Oid>;
+
+struct TasksBranch {
+    repo: Repository,
+    branch: String,
+    tasks_dir: PathBuf,
+    state_path: PathBuf,
+}
+
+impl TasksBranch {
+    /// The project's tasks branch, or `None` when task files live on the code branch
+    fn open() -> Result<Option<Self>> {
+        let Some(root) = find_taskguard_root() else {
+            return Ok(None);
+        };
+        let config = Config::load_or_default(root.join(".taskguard").join("config.toml"))?;
+        let Some(branch) = config.git.tasks_branch else {
+            return Ok(None);
+        };
+        let repo = Repository::discover(&root)
+            .context("[git] tasks_branch needs a Git repository. Run 'git init' first.")?;
+
+        Ok(Some(Self {
+            repo,
+            branch,
+            tasks_dir: root.join("tasks"),
+            state_path: root.join(STATE_FILE),
+        }))
+    }
+
+    fn reference(&self) -> String {
+        format!("refs/heads/{}", self.branch)
+    }
+
+    fn tip(&self) -> Option<Commit<'_>> {
+        self.repo
+            .find_reference(&self.reference())
+            .and_then(|r| r.peel_to_commit())
+            .ok()
+    }
+
+    fn synced(&self) -> Option<Oid> {
+        fs::read_to_string(&self.state_path)
+            .ok()
+            .and_then(|oid| Oid::from_str(oid.trim()).ok())
+    }
+
+    fn record_synced(&self, oid: Oid) -> Result<()> {
+        if let Some(dir) = self.state_path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.state_path, format!("{}\n", oid))
+            .with_context(|| format!("Failed to write {}", self.state_path.display()))
+    }
+
+    /// Files of a commit on the tasks branch; none for no commit
+    fn commit_files(&self, oid: Option<Oid>) -> Result<Files> {
+        let mut files = Files::new();
+        let Some(commit) = oid.and_then(|oid| self.repo.find_commit(oid).ok()) else {
+            return Ok(files);
+        };
+        commit
+            .tree()?
+            .walk(TreeWalkMode::PreOrder, |dir, entry| {
+                if entry.kind() == Some(ObjectType::Blob)
+                    && let Some(name) = entry.name()
+                {
+                    files.insert(format!("{}{}", dir, name), entry.id());
+                }
+                git2::TreeWalkResult::Ok
+            })
+            .context("Failed to read the tasks branch")?;
+        Ok(files)
+    }
+
+    /// Files in `tasks/`, hashed the way git would store them
+    fn local_files(&self) -> Result<Files> {
+        let mut files = Files::new();
+        if !self.tasks_dir.exists() {
+            return Ok(files);
+        }
+        for entry in WalkDir::new(&self.tasks_dir) {
+            let entry = entry?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let relative = entry.path().strip_prefix(&self.tasks_dir)?;
+            let path = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            files.insert(path, Oid::hash_file(ObjectType::Blob, entry.path())?);
+        }
+        Ok(files)
+    }
+
+    /// Merge the branch's changes since the last sync into `tasks/`, keeping
+    /// local versions of files changed on both sides
+    fn pull(&self) -> Result<()> {
+        let Some(tip) = self.tip().map(|c| c.id()) else {
+            return Ok(());
+        };
+        let synced = self.synced();
+        if synced == Some(tip) {
+            return Ok(());
+        }
+
+        let base = self.commit_files(synced)?;
+        let theirs = self.commit_files(Some(tip))?;
+        let ours = self.local_files()?;
+        let paths: BTreeSet<&String> = base
+            .keys()
+            .chain(theirs.keys())
+            .chain(ours.keys())
+            .collect();
+
+        let mut conflicts = Vec::new();
+        for path in paths {
+            let (base, theirs, ours) = (base.get(path), theirs.get(path), ours.get(path));
+            // Nothing new on the branch, or the same change made on both sides
+            if theirs == base || theirs == ours {
+                continue;
+            }
+            if ours != base {
+                conflicts.push(path.as_str());
+                continue;
+            }
+
+            let file = self.tasks_dir.join(path);
+            match theirs {
+                Some(oid) => {
+                    if let Some(dir) = file.parent() {
+                        fs::create_dir_all(dir)?;
+                    }
+                    fs::write(&file, self.repo.find_blob(*oid)?.content())
+                        .with_context(|| format!("Failed to write {}", file.display()))?;
+                }
+                None => fs::remove_file(&file)
+                    .with_context(|| format!("Failed to remove {}", file.display()))?,
+            }
+        }
+
+        if !conflicts.is_empty() {
+            eprintln!(
+                "⚠️  Kept local versions of task files also changed on the '{}' branch:",
+                self.branch
+            );
+            for path in conflicts {
+                eprintln!("   tasks/{}", path);
+            }
+        }
+        self.record_synced(tip)
+    }
+
+    /// Commit `tasks/` to the branch if it differs, creating the branch as an
+    /// orphan on first use
+    fn push(&self) -> Result<()> {
+        if self.tip().map(|c| c.id()) != self.synced() {
+            self.pull()?;
+        }
+        let tip = self.tip();
+        let theirs = self.commit_files(tip.as_ref().map(Commit::id))?;
+        let ours = self.local_files()?;
+        if tip.is_some() && ours == theirs {
+            return Ok(());
+        }
+
+        let mut index = Index::new()?;
+        for path in ours.keys() {
+            let content = fs::read(self.tasks_dir.join(path))?;
+            let id = self.repo.blob(&content)?;
+            index.add(&blob_entry(path, id, content.len()))?;
+        }
+        let tree = self.repo.find_tree(index.write_tree_to(&self.repo)?)?;
+
+        // Changed blobs, ours or for deleted files theirs, are all stored by now
+        let mut task_ids: Vec<String> = ours
+            .keys()
+            .chain(theirs.keys())
+            .filter(|path| ours.get(*path) != theirs.get(*path))
+            .filter_map(|path| ours.get(path).or_else(|| theirs.get(path)))
+            .filter_map(|oid| {
+                let blob = self.repo.find_blob(*oid).ok()?;
+                let content = std::str::from_utf8(blob.content()).ok()?;
+                Task::parse_content(content).ok().map(|task| task.id)
+            })
+            .collect();
+        task_ids.sort_unstable();
+        task_ids.dedup();
+        let subject = match task_ids.len() {
+            0 => "taskguard: update task files".to_string(),
+            1..=3 => format!("taskguard: update {}", task_ids.join(", ")),
+            n => format!("taskguard: update {} tasks", n),
+        };
+
+        let signature = self
+            .repo
+            .signature()
+            .context("Failed to get Git signature")?;
+        let parents: Vec<&Commit> = tip.iter().collect();
+        let oid = self
+            .repo
+            .commit(
+                Some(&self.reference()),
+                &signature,
+                &signature,
+                &subject,
+                &tree,
+                &parents,
+            )
+            .with_context(|| format!("Failed to commit to the '{}' branch", self.branch))?;
+        println!("📝 Saved to branch '{}': {}", self.branch, subject);
+        self.record_synced(oid)
+    }
+}
+
+/// Index entry for a regular file; only the path, mode and blob matter for a tree
+fn blob_entry(path: &str, id: Oid, size: usize) -> IndexEntry {
+    IndexEntry {
+        ctime: IndexTime::new(0, 0),
+        mtime: IndexTime::new(0, 0),
+        dev: 0,
+        ino: 0,
+        mode: 0o100_644,
+        uid: 0,
+        gid: 0,
+        file_size: u32::try_from(size).unwrap_or(u32::MAX),
+        id,
+        flags: u16::try_from(path.len()).map_or(0xfff, |len| len.min(0xfff)),
+        flags_extended: 0,
+        path: path.as_bytes().to_vec(),
+    }
+}
+
+/// With `[git] tasks_branch`, bring `tasks/` up to date with the branch
+pub fn pull() -> Result<()> {
+    match TasksBranch::open()? {
+        Some(tasks_branch) => tasks_branch.pull(),
+        None => Ok(()),
+    }
+}
+
+/// With `[git] tasks_branch`, commit changes in `tasks/` to the branch
+pub fn push() -> Result<()> {
+    match TasksBranch::open()? {
+        Some(tasks_branch) => tasks_branch.push(),
+        None => Ok(()),
+    }
+}
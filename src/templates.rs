@@ -3,8 +3,18 @@
 //! Provides tailored templates for different task areas (api, auth, backend, etc.)
 //! with domain-appropriate causation chain prompts and verification commands.
 
+use regex::Regex;
+use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+/// Matches `{{name}}` and `{{custom.name}}` placeholders
+fn placeholder_regex() -> Regex {
+    Regex::new(r"\{\{\s*([A-Za-z_][A-Za-z0-9_.-]*)\s*\}\}").expect("valid placeholder regex")
+}
+
+/// Built-in named templates, usable with `create --template`
+pub const BUILTIN_TEMPLATES: &[&str] = &["bugfix", "feature", "incident", "rfc", "spike"];
 
 /// Template manager for loading domain-specific or custom templates
 pub struct TemplateManager;
@@ -36,6 +46,181 @@ impl TemplateManager {
         Self::get_builtin_template(area)
     }
 
+    /// Get a template by name (e.g. "bugfix")
+    /// Priority: custom `.taskguard/templates/<name>.md` > built-in named template
+    pub fn get_named_template(name: &str, taskguard_root: Option<&Path>) -> Option<String> {
+        if let Some(root) = taskguard_root
+            && let Ok(content) = fs::read_to_string(Self::custom_template_path(root, name))
+        {
+            return Some(content);
+        }
+
+        Self::get_builtin_named_template(name).map(str::to_string)
+    }
+
+    /// Path of a project-specific template file
+    pub fn custom_template_path(taskguard_root: &Path, name: &str) -> PathBuf {
+        taskguard_root
+            .join(".taskguard")
+            .join("templates")
+            .join(format!("{}.md", name))
+    }
+
+    /// Names of templates stored in `.taskguard/templates/`, sorted
+    pub fn list_custom_templates(taskguard_root: &Path) -> Vec<String> {
+        let Ok(entries) = fs::read_dir(taskguard_root.join(".taskguard").join("templates")) else {
+            return Vec::new();
+        };
+
+        let mut names: Vec<String> = entries
+            .filter_map(Result::ok)
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|ext| ext == "md"))
+            .filter_map(|p| p.file_stem().and_then(|s| s.to_str()).map(String::from))
+            .collect();
+        names.sort();
+        names
+    }
+
+    /// Get a built-in named template
+    pub fn get_builtin_named_template(name: &str) -> Option<&'static str> {
+        match name {
+            "bugfix" => Some(
+                r"# {{title}}
+
+## Context
+[What is broken, who is affected, and how it was reported]
+
+## Reproduction
+1. [Step to reproduce]
+2. [Observed vs expected behavior]
+
+## Objectives
+- Find and fix the root cause, not just the symptom
+- Prevent regression with a test
+
+## Tasks
+- [ ] Reproduce the bug locally
+- [ ] Identify the root cause
+- [ ] Write a failing test
+- [ ] Implement the fix
+- [ ] Build + test + run to verify
+
+## Acceptance Criteria
+- [ ] Reproduction steps no longer trigger the bug
+- [ ] Regression test passes
+
+## Notes
+[Root cause analysis, affected versions]
+",
+            ),
+            "feature" => Some(
+                r"# {{title}}
+
+## Context
+[Why this feature is needed and who it is for]
+
+## Objectives
+- [User-visible outcome]
+- [Technical outcome]
+
+## Tasks
+- [ ] [Design / interface]
+- [ ] [Implementation]
+- [ ] [Tests]
+- [ ] [Documentation]
+
+## Acceptance Criteria
+- [ ] [Testable criterion 1]
+- [ ] [Testable criterion 2]
+
+## Notes
+[Constraints, alternatives considered]
+",
+            ),
+            "incident" => Some(
+                "# {{title}}
+
+**Service:** {{custom.service}}
+**Severity:** {{custom.severity}}
+**Reported:** {{date}} by {{assignee}}
+
+## Context
+[Impact on users and systems, start time, detection]
+
+## Timeline
+- {{date}}: Incident opened
+
+## Objectives
+- Restore service
+- Understand and remove the root cause
+
+## Tasks
+- [ ] Mitigate impact
+- [ ] Identify root cause
+- [ ] Implement permanent fix
+- [ ] Write postmortem
+
+## Acceptance Criteria
+- [ ] {{custom.service}} operating normally
+- [ ] Postmortem reviewed and follow-up tasks created
+",
+            ),
+            "rfc" => Some(
+                "# RFC: {{title}}
+
+**Author:** {{assignee}}
+**Status:** Draft ({{date}})
+**Reviewers:** {{custom.reviewers}}
+
+## Context
+[Problem statement and motivation]
+
+## Objectives
+- [What this proposal should achieve]
+
+## Proposal
+[Detailed design]
+
+## Alternatives Considered
+[Other approaches and why they were rejected]
+
+## Tasks
+- [ ] Write draft
+- [ ] Collect review feedback
+- [ ] Record decision
+
+## Acceptance Criteria
+- [ ] Reviewed by {{custom.reviewers}}
+- [ ] Decision recorded in this task
+",
+            ),
+            "spike" => Some(
+                r"# {{title}}
+
+## Context
+[Question to answer and why it matters now]
+
+## Objectives
+- [Decision or knowledge this spike should produce]
+
+## Tasks
+- [ ] Research options
+- [ ] Build a throwaway prototype if needed
+- [ ] Write up findings and recommendation
+
+## Acceptance Criteria
+- [ ] Findings documented in this task
+- [ ] Follow-up tasks created for the chosen approach
+
+## Notes
+Timebox: [e.g. 1d]
+",
+            ),
+            _ => None,
+        }
+    }
+
     /// Get the causation chain prompt for a specific area
     pub fn get_causation_prompt(area: &str) -> &'static str {
         match area {
@@ -260,6 +445,33 @@ output. Verify actual implementation matches expected behavior."#
             .replace("{{title}}", title)
             .replace("{{date}}", date)
     }
+
+    /// Placeholder names used in a template (`{{name}}`), in order of first use
+    pub fn placeholders(template: &str) -> Vec<String> {
+        let mut names: Vec<String> = Vec::new();
+        for caps in placeholder_regex().captures_iter(template) {
+            let name = caps[1].to_string();
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        names
+    }
+
+    /// Render a template, replacing every `{{name}}` found in `vars`
+    ///
+    /// Placeholders without a value are left in place so they stay visible in
+    /// the task file.
+    #[allow(clippy::implicit_hasher)]
+    pub fn render_vars(template: &str, vars: &HashMap<String, String>) -> String {
+        placeholder_regex()
+            .replace_all(template, |caps: &regex::Captures| {
+                vars.get(&caps[1])
+                    .cloned()
+                    .unwrap_or_else(|| caps[0].to_string())
+            })
+            .into_owned()
+    }
 }
 
 #[cfg(test)]
@@ -300,6 +512,34 @@ mod tests {
         assert_eq!(result, "# My Task\nCreated: 2025-01-01");
     }
 
+    #[test]
+    fn test_render_vars_and_placeholders() {
+        let template =
+            "# {{title}}\nArea: {{area}}\nService: {{ custom.service }}\n{{custom.owner}}";
+        assert_eq!(
+            TemplateManager::placeholders(template),
+            vec!["title", "area", "custom.service", "custom.owner"]
+        );
+
+        let mut vars = HashMap::new();
+        vars.insert("title".to_string(), "Outage".to_string());
+        vars.insert("area".to_string(), "ops".to_string());
+        vars.insert("custom.service".to_string(), "auth".to_string());
+        assert_eq!(
+            TemplateManager::render_vars(template, &vars),
+            "# Outage\nArea: ops\nService: auth\n{{custom.owner}}"
+        );
+    }
+
+    #[test]
+    fn test_get_named_template() {
+        let template = TemplateManager::get_named_template("bugfix", None).unwrap();
+        assert!(template.contains("## Context"));
+        assert!(template.contains("## Objectives"));
+        assert!(template.contains("## Acceptance Criteria"));
+        assert!(TemplateManager::get_named_template("nope", None).is_none());
+    }
+
     #[test]
     fn test_get_template_fallback() {
         // Without custom path, should return builtin
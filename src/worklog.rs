@@ -0,0 +1,145 @@
+//! Activity log kept in a task's `## Log` section
+//!
+//! Each entry is one list line: `- 2024-07-01 14:30 UTC alice: blocked on infra ticket`.
+//! Entries are appended in order and pushed to the linked GitHub issue as comments.
+//! Comments made on the issue come back as entries by the commenter's `@login`.
+
+use chrono::{DateTime, NaiveDateTime, Timelike, Utc};
+use regex::Regex;
+
+const LOG_HEADING: &str = "## Log";
+const TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M UTC";
+/// Start of the issue comments that mirror log entries
+const COMMENT_PREFIX: &str = "💬 **";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogEntry {
+    pub timestamp: DateTime<Utc>,
+    pub author: String,
+    pub text: String,
+}
+
+impl LogEntry {
+    /// New entry stamped with the current time; line breaks in the note are folded
+    pub fn new(author: &str, text: &str) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            author: author.trim().to_string(),
+            text: text.split_whitespace().collect::<Vec<_>>().join(" "),
+        }
+    }
+
+    /// Entry for a comment made on the linked issue, timestamped to the minute
+    /// like local entries. `None` for comments mirroring a log entry, and for
+    /// empty or unreadable ones.
+    pub fn from_comment(login: &str, created_at: &str, body: &str) -> Option<Self> {
+        if body.starts_with(COMMENT_PREFIX) {
+            return None;
+        }
+        let timestamp = DateTime::parse_from_rfc3339(created_at)
+            .ok()?
+            .with_timezone(&Utc)
+            .with_second(0)?
+            .with_nanosecond(0)?;
+        let login = if login.is_empty() { "ghost" } else { login };
+        let text = body.split_whitespace().collect::<Vec<_>>().join(" ");
+        (!text.is_empty()).then(|| Self {
+            timestamp,
+            author: format!("@{}", login),
+            text,
+        })
+    }
+
+    /// Whether the entry was pulled from an issue comment rather than logged here
+    pub fn is_from_github(&self) -> bool {
+        self.author.starts_with('@')
+    }
+
+    fn to_line(&self) -> String {
+        format!(
+            "- {} {}: {}",
+            self.timestamp.format(TIMESTAMP_FORMAT),
+            self.author,
+            self.text
+        )
+    }
+
+    /// Body of the GitHub issue comment mirroring this entry
+    pub fn comment_body(&self) -> String {
+        format!(
+            "{}{}** · {}\n\n{}",
+            COMMENT_PREFIX,
+            self.author,
+            self.timestamp.format(TIMESTAMP_FORMAT),
+            self.text
+        )
+    }
+}
+
+fn entry_regex() -> Regex {
+    Regex::new(r"^-\s+(\d{4}-\d{2}-\d{2} \d{2}:\d{2}) UTC ([^:]+):\s*(.*)$")
+        .expect("valid log entry regex")
+}
+
+/// Line range of the log section body: after the heading, up to the next `## ` heading
+fn log_section(lines: &[&str]) -> Option<(usize, usize)> {
+    let heading = lines.iter().position(|l| l.trim() == LOG_HEADING)?;
+    let end = lines[heading + 1..]
+        .iter()
+        .position(|l| l.starts_with("## "))
+        .map_or(lines.len(), |offset| heading + 1 + offset);
+    Some((heading + 1, end))
+}
+
+/// Parse the entries of the `## Log` section, oldest first
+pub fn parse_entries(content: &str) -> Vec<LogEntry> {
+    let lines: Vec<&str> = content.lines().collect();
+    let Some((start, end)) = log_section(&lines) else {
+        return Vec::new();
+    };
+    let entry = entry_regex();
+
+    lines[start..end]
+        .iter()
+        .filter_map(|line| {
+            let captures = entry.captures(line.trim_end())?;
+            let timestamp = NaiveDateTime::parse_from_str(&captures[1], "%Y-%m-%d %H:%M").ok()?;
+            Some(LogEntry {
+                timestamp: timestamp.and_utc(),
+                author: captures[2].trim().to_string(),
+                text: captures[3].to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Task body with the entry added at the end of the `## Log` section,
+/// creating the section at the end of the body if needed
+pub fn append_entry(content: &str, entry: &LogEntry) -> String {
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    let line = entry.to_line();
+
+    let borrowed: Vec<&str> = lines.iter().map(String::as_str).collect();
+    if let Some((start, end)) = log_section(&borrowed) {
+        // Keep blank lines before the next heading below the new entry
+        let insert_at = (start..end)
+            .rev()
+            .find(|&i| !lines[i].trim().is_empty())
+            .map_or(start, |i| i + 1);
+        lines.insert(insert_at, line);
+    } else {
+        while lines.last().is_some_and(|l| l.trim().is_empty()) {
+            lines.pop();
+        }
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.push(LOG_HEADING.to_string());
+        lines.push(String::new());
+        lines.push(line);
+    }
+
+    let mut updated = lines.join("\n");
+    updated.push('\n');
+    updated
+}
@@ -1,9 +1,10 @@
 use anyhow::Result;
 use chrono::Utc;
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
 use taskguard::commands::ai::AIAgent;
-use taskguard::task::{Priority, Task, TaskStatus};
+use taskguard::task::{FORMAT_VERSION, Priority, Relations, Task, TaskStatus};
 use tempfile::TempDir;
 
 /// Test fixture for creating a temporary TaskGuard project
@@ -64,11 +65,22 @@ priorities = ["low", "medium", "high", "critical"]
             priority,
             tags: vec!["test".to_string()],
             dependencies,
-            assignee: None,
+            relations: Relations::default(),
+            assignees: vec![],
             created: Utc::now(),
             estimate: None,
             complexity: Some(5),
+            due: None,
+            epic: None,
+            time_log: vec![],
+            links: vec![],
+            branch: None,
+            release: None,
+            completed: None,
+            claimed: None,
+            format_version: FORMAT_VERSION,
             area: area.to_string(),
+            extra: BTreeMap::new(),
             content: format!("Test task content for {}", title),
             file_path: file_path.clone(),
         };
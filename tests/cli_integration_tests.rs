@@ -1,9 +1,11 @@
 use anyhow::Result;
 use chrono::Utc;
+use std::collections::BTreeMap;
 use std::fs;
+use std::io::Write;
 use std::path::PathBuf;
-use std::process::Command;
-use taskguard::task::{Priority, Task, TaskStatus};
+use std::process::{Command, Stdio};
+use taskguard::task::{FORMAT_VERSION, Priority, Relations, Task, TaskStatus};
 use tempfile::TempDir;
 
 /// Test fixture for CLI integration testing
@@ -67,6 +69,28 @@ impl CLITestProject {
         Ok((stdout, stderr, exit_code))
     }
 
+    fn run_command_with_input(&self, args: &[&str], input: &str) -> Result<(String, String, i32)> {
+        let mut child = Command::new(&self.binary_path)
+            .args(args)
+            .current_dir(&self.project_path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        child
+            .stdin
+            .take()
+            .expect("stdin is piped")
+            .write_all(input.as_bytes())?;
+        let output = child.wait_with_output()?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        let exit_code = output.status.code().unwrap_or(-1);
+
+        Ok((stdout, stderr, exit_code))
+    }
+
     fn create_task_file(
         &self,
         area: &str,
@@ -89,11 +113,22 @@ impl CLITestProject {
             priority: Priority::Medium,
             tags: vec!["test".to_string()],
             dependencies,
-            assignee: None,
+            relations: Relations::default(),
+            assignees: vec![],
             created: Utc::now(),
             estimate: Some("4h".to_string()),
             complexity: Some(5),
+            due: None,
+            epic: None,
+            time_log: vec![],
+            links: vec![],
+            branch: None,
+            release: None,
+            completed: None,
+            claimed: None,
+            format_version: FORMAT_VERSION,
             area: area.to_string(),
+            extra: BTreeMap::new(),
             content: format!("Test content for {}", title),
             file_path: file_path.clone(),
         };
@@ -452,6 +487,154 @@ fn test_create_minimum_required_args() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_nested_areas_create_filter_archive() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    let (stdout, stderr, exit_code) = project.run_command(&[
+        "create",
+        "--title",
+        "Login flow",
+        "--area",
+        "backend/auth/",
+        "--allow-orphan-task",
+    ])?;
+    assert_eq!(
+        exit_code, 0,
+        "nested area create should succeed: {}",
+        stderr
+    );
+    assert!(stdout.contains("ID: backend-auth-001"));
+    assert!(
+        project
+            .project_path
+            .join("tasks/backend/auth/backend-auth-001.md")
+            .exists()
+    );
+    project.run_command(&[
+        "create",
+        "--title",
+        "API",
+        "--area",
+        "backend",
+        "--allow-orphan-task",
+    ])?;
+    project.run_command(&[
+        "create",
+        "--title",
+        "UI",
+        "--area",
+        "frontend",
+        "--allow-orphan-task",
+    ])?;
+
+    // A flat area spelled like the nested one's prefix doesn't reuse its IDs
+    let (stdout, _stderr, _) = project.run_command(&[
+        "create",
+        "--title",
+        "Flat",
+        "--area",
+        "backend-auth",
+        "--allow-orphan-task",
+    ])?;
+    assert!(stdout.contains("ID: backend-auth-002"), "{}", stdout);
+
+    // A parent area filter includes nested areas, a nested one only itself
+    let (stdout, _stderr, _) = project.run_command(&["list", "--area", "backend"])?;
+    assert!(stdout.contains("backend-auth-001"));
+    assert!(stdout.contains("backend-001"));
+    assert!(!stdout.contains("frontend-001"));
+    let (stdout, _stderr, _) = project.run_command(&["list", "--area", "backend/auth"])?;
+    assert!(stdout.contains("backend-auth-001"));
+    assert!(!stdout.contains("backend-001 "));
+
+    let (_stdout, _stderr, exit_code) =
+        project.run_command(&["update", "status", "backend-auth-001", "done"])?;
+    assert_eq!(exit_code, 0, "nested tasks are found by ID");
+
+    // Archive keeps the nested directory, and restore puts the file back there
+    project.run_command(&["archive"])?;
+    let archived = project
+        .project_path
+        .join(".taskguard/archive/backend/auth/backend-auth-001.md");
+    assert!(archived.exists());
+    project.run_command(&["restore", "backend-auth-001"])?;
+    assert!(!archived.exists());
+    assert!(
+        project
+            .project_path
+            .join("tasks/backend/auth/backend-auth-001.md")
+            .exists()
+    );
+
+    // Directories created by hand are picked up as nested areas
+    fs::create_dir_all(project.project_path.join("tasks/frontend/widgets"))?;
+    let (stdout, _stderr, _) = project.run_command(&["validate", "--sync-areas"])?;
+    assert!(stdout.contains("+ frontend/widgets"));
+
+    let (_stdout, _stderr, exit_code) = project.run_command(&[
+        "create",
+        "--title",
+        "Escape",
+        "--area",
+        "../outside",
+        "--allow-orphan-task",
+    ])?;
+    assert_ne!(exit_code, 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_completed_timestamp_drives_archive_and_clean_days() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    for title in ["Recent", "Reopened"] {
+        project.run_command(&[
+            "create",
+            "--title",
+            title,
+            "--area",
+            "backend",
+            "--allow-orphan-task",
+        ])?;
+    }
+    let recent = project.project_path.join("tasks/backend/backend-001.md");
+    let reopened = project.project_path.join("tasks/backend/backend-002.md");
+
+    project.run_command(&["update", "status", "backend-001", "done"])?;
+    assert!(fs::read_to_string(&recent)?.contains("completed:"));
+    let (stdout, _stderr, _) = project.run_command(&["show", "backend-001"])?;
+    assert!(stdout.contains("Completed: "));
+
+    project.run_command(&["update", "status", "backend-002", "done"])?;
+    project.run_command(&["update", "status", "backend-002", "doing"])?;
+    assert!(!fs::read_to_string(&reopened)?.contains("completed:"));
+
+    // Finished long ago according to its timestamp, though the file is new
+    fs::write(
+        project.project_path.join("tasks/backend/backend-003.md"),
+        "---\nid: backend-003\ntitle: Old\nstatus: done\npriority: low\ntags: []\ndependencies: []\nassignee: null\ncreated: 2020-01-01T00:00:00Z\ncompleted: 2020-01-02T00:00:00Z\nestimate: ~\ncomplexity: ~\narea: backend\n---\n\nDone long ago\n",
+    )?;
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["archive", "--days", "30"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("Keeping 1 task(s) completed in the last 30 days"));
+    assert!(stdout.contains("Archived: backend-003"));
+    assert!(recent.exists());
+
+    let (stdout, _stderr, _) = project.run_command(&["clean", "--days", "30", "--dry-run"])?;
+    assert!(stdout.contains("Keeping 1 task(s) completed in the last 30 days"));
+    assert!(!stdout.contains("backend-001 - Recent"));
+
+    // Without --days every completed task is eligible
+    let (stdout, _stderr, _) = project.run_command(&["clean", "--dry-run"])?;
+    assert!(stdout.contains("backend-001 - Recent"));
+
+    Ok(())
+}
+
 // =============================================================================
 // VALIDATE COMMAND TESTS
 // =============================================================================
@@ -545,6 +728,118 @@ fn test_validate_circular_dependencies() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_validate_reports_full_cycle_path() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    // backend-001 → api-002 → backend-001, plus a task that only leads into the loop
+    project.create_task_file(
+        "backend",
+        "backend-001",
+        "Task A",
+        TaskStatus::Todo,
+        vec!["api-002".to_string()],
+    )?;
+    project.create_task_file(
+        "api",
+        "api-002",
+        "Task B",
+        TaskStatus::Todo,
+        vec!["backend-001".to_string()],
+    )?;
+    project.create_task_file(
+        "backend",
+        "backend-003",
+        "Task C",
+        TaskStatus::Todo,
+        vec!["backend-001".to_string()],
+    )?;
+
+    let (stdout, _, _) = project.run_command(&["validate"])?;
+    assert!(stdout.contains("Dependency cycle: api-002 → backend-001 → api-002"));
+    assert!(stdout.contains("api-002: tasks/api/api-002.md"));
+    assert!(stdout.contains("backend-001: tasks/backend/backend-001.md"));
+    // The loop is reported once, and the task leading into it isn't part of it
+    assert_eq!(stdout.matches("Dependency cycle:").count(), 1);
+    assert!(!stdout.contains("→ backend-003"));
+
+    let (stdout, _, exit_code) = project.run_command(&["validate", "--json"])?;
+    assert_eq!(exit_code, 0);
+    let report: serde_json::Value = serde_json::from_str(&stdout)?;
+    assert_eq!(report["valid"], false);
+    assert_eq!(report["total_tasks"], 4);
+    let cycles = report["circular_dependencies"].as_array().unwrap();
+    assert_eq!(cycles.len(), 1);
+    assert_eq!(
+        cycles[0]["path"],
+        serde_json::json!(["api-002", "backend-001", "api-002"])
+    );
+    assert_eq!(
+        cycles[0]["files"],
+        serde_json::json!(["tasks/api/api-002.md", "tasks/backend/backend-001.md"])
+    );
+
+    let (_, stderr, exit_code) = project.run_command(&["validate", "--json", "--strict"])?;
+    assert_ne!(exit_code, 0);
+    assert!(stderr.contains("Strict validation failed"));
+
+    Ok(())
+}
+
+#[test]
+fn test_validate_separates_missing_and_archived_dependencies() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    project.create_task_file(
+        "backend",
+        "backend-001",
+        "Old work",
+        TaskStatus::Done,
+        vec![],
+    )?;
+    project.run_command(&["archive"])?;
+    project.create_task_file(
+        "api",
+        "api-001",
+        "Needs both",
+        TaskStatus::Todo,
+        vec![
+            "backend-001".to_string(),
+            "backend-099".to_string(),
+            "setup-001".to_string(),
+        ],
+    )?;
+
+    let (stdout, _, _) = project.run_command(&["validate"])?;
+    assert!(stdout.contains("❌ api-001: Depends on missing task 'backend-099'"));
+    assert!(
+        stdout.contains("💡 Fix: taskguard update dependencies api-001 \"backend-001,setup-001\"")
+    );
+    assert!(stdout.contains("📦 api-001: Depends on archived task 'backend-001'"));
+    assert!(stdout.contains(
+        "💡 Fix: taskguard restore backend-001 or taskguard update dependencies api-001 \"backend-099,setup-001\""
+    ));
+    assert!(stdout.contains("Dependency issues: 1"));
+    assert!(stdout.contains("Dependencies on archived tasks: 1"));
+
+    let (stdout, _, _) = project.run_command(&["validate", "--json"])?;
+    let report: serde_json::Value = serde_json::from_str(&stdout)?;
+    assert_eq!(
+        report["dependency_issues"],
+        serde_json::json!(["api-001: Depends on missing task 'backend-099'"])
+    );
+    let broken = report["broken_dependencies"].as_array().unwrap();
+    assert_eq!(broken.len(), 2);
+    assert_eq!(broken[0]["dependency"], "backend-001");
+    assert_eq!(broken[0]["archived"], true);
+    assert_eq!(broken[1]["dependency"], "backend-099");
+    assert_eq!(broken[1]["archived"], false);
+
+    Ok(())
+}
+
 // =============================================================================
 // SYNC COMMAND TESTS (GIT INTEGRATION)
 // =============================================================================
@@ -616,353 +911,3651 @@ fn test_sync_with_task_commits() -> Result<()> {
 }
 
 #[test]
-fn test_sync_verbose_mode() -> Result<()> {
+fn test_sync_apply_updates_task_status() -> Result<()> {
     let project = CLITestProject::new()?;
     project.run_command(&["init"])?;
     project.init_git_repo()?;
-
     project.create_task_file(
         "backend",
         "backend-001",
-        "Test Task",
+        "Backend Task",
         TaskStatus::Todo,
         vec![],
     )?;
-    project.add_git_commit("Work on backend-001")?;
+    project.add_git_commit("Complete backend-001 implementation")?;
+    let path = project.project_path.join("tasks/backend/backend-001.md");
+
+    let (stdout, _, _) = project.run_command(&["sync"])?;
+    assert!(stdout.contains("Run with --apply to update task files"));
+
+    let (stdout, _, _) = project.run_command(&["sync", "--apply", "--dry-run"])?;
+    assert!(stdout.contains("Would change backend-001: todo → done"));
+    assert!(fs::read_to_string(&path)?.contains("status: todo"));
+
+    let (stdout, _, _) = project.run_command_with_input(&["sync", "--apply"], "n\n")?;
+    assert!(stdout.contains("Change backend-001 from todo to done? (y/N)"));
+    assert!(stdout.contains("Skipped backend-001"));
+    assert!(fs::read_to_string(&path)?.contains("status: todo"));
+
+    let (stdout, _, code) = project.run_command_with_input(&["sync", "--apply"], "y\n")?;
+    assert_eq!(code, 0);
+    assert!(stdout.contains("Updated backend-001: status = done"));
+    assert!(stdout.contains("Applied 1 of 1 suggestions"));
+    let content = fs::read_to_string(&path)?;
+    assert!(content.contains("status: done"));
+    assert!(content.contains("completed:"));
+
+    // Nothing left to suggest once the status matches
+    let (stdout, _, _) = project.run_command(&["sync", "--apply", "--yes"])?;
+    assert!(stdout.contains("No status changes recommended"));
+    Ok(())
+}
 
-    let (stdout, _stderr, exit_code) = project.run_command(&["sync", "--verbose"])?;
+#[test]
+fn test_sync_confidence_thresholds_from_config() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.init_git_repo()?;
+    project.create_task_file(
+        "backend",
+        "backend-001",
+        "Backend Task",
+        TaskStatus::Todo,
+        vec![],
+    )?;
+    project.add_git_commit("Complete backend-001 implementation")?;
+    let config_path = project.project_path.join(".taskguard/config.toml");
+    let config = fs::read_to_string(&config_path)?;
+
+    let (stdout, _, _) = project.run_command(&["sync"])?;
+    assert!(stdout.contains("Consider changing status to 'done'"));
+
+    // Stricter for done than for anything else
+    fs::write(
+        &config_path,
+        format!(
+            "{}\n[sync]\nmin_confidence = 0.1\n\n[sync.status_confidence]\ndone = 0.95\n",
+            config
+        ),
+    )?;
+    let (stdout, _, code) = project.run_command(&["sync", "--full"])?;
+    assert_eq!(code, 0);
+    assert!(!stdout.contains("Consider changing status to 'done'"));
+    assert!(stdout.contains("No status changes recommended"));
+    Ok(())
+}
 
-    assert_eq!(exit_code, 0, "Sync verbose should succeed");
-    assert!(stdout.contains("backend-001"), "Should show task activity");
-    // Verbose mode should show more details
-    assert!(stdout.len() > 100, "Verbose output should be substantial");
+#[test]
+fn test_sync_apply_pull_requires_github() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.init_git_repo()?;
 
+    let (_, stderr, code) = project.run_command(&["sync", "--apply-pull"])?;
+    assert_ne!(code, 0);
+    assert!(stderr.contains("--github"));
     Ok(())
 }
 
-// =============================================================================
-// LINT COMMAND TESTS
-// =============================================================================
+#[test]
+fn test_sync_adopt_orphans_requires_github() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.init_git_repo()?;
+
+    let (_, stderr, code) = project.run_command(&["sync", "--adopt-orphans"])?;
+    assert_ne!(code, 0);
+    assert!(stderr.contains("--github"));
+    Ok(())
+}
 
 #[test]
-fn test_lint_empty_project() -> Result<()> {
+fn test_sync_plan_flags_require_github_dry_run() -> Result<()> {
     let project = CLITestProject::new()?;
     project.run_command(&["init"])?;
+    project.init_git_repo()?;
 
-    let (stdout, _stderr, exit_code) = project.run_command(&["lint"])?;
+    let (_, stderr, code) = project.run_command(&["sync", "--github", "--format", "json"])?;
+    assert_ne!(code, 0);
+    assert!(stderr.contains("--dry-run"));
 
-    assert_eq!(exit_code, 0, "Lint should succeed on empty project");
-    assert!(
-        stdout.contains("0") || stdout.contains("No tasks") || stdout.contains("analyzed"),
-        "Should handle empty project"
-    );
+    let (_, stderr, code) =
+        project.run_command(&["sync", "--github", "--dry-run", "--format", "yaml"])?;
+    assert_ne!(code, 0);
+    assert!(stderr.contains("table"));
+
+    let (_, stderr, code) = project.run_command(&["sync", "--apply-plan", "plan.json"])?;
+    assert_ne!(code, 0);
+    assert!(stderr.contains("--github"));
 
+    let (_, stderr, code) =
+        project.run_command(&["sync", "--github", "--dry-run", "--apply-plan", "plan.json"])?;
+    assert_ne!(code, 0);
+    assert!(stderr.contains("cannot be used with"));
     Ok(())
 }
 
 #[test]
-fn test_lint_with_tasks() -> Result<()> {
+fn test_serve_github_webhook_needs_secret() -> Result<()> {
     let project = CLITestProject::new()?;
     project.run_command(&["init"])?;
 
+    let output = Command::new(&project.binary_path)
+        .args(["serve", "--github-webhook", "127.0.0.1:0"])
+        .current_dir(&project.project_path)
+        .env_remove("TASKGUARD_WEBHOOK_SECRET")
+        .output()?;
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("TASKGUARD_WEBHOOK_SECRET"));
+    Ok(())
+}
+
+#[test]
+fn test_serve_github_webhook_applies_signed_issue_events() -> Result<()> {
+    use hmac::{Hmac, Mac};
+    use std::io::Read;
+    use std::net::{TcpListener, TcpStream};
+
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
     project.create_task_file(
         "backend",
         "backend-001",
-        "Good Task",
-        TaskStatus::Todo,
+        "Backend Task",
+        TaskStatus::Doing,
         vec![],
     )?;
+    let taskguard_dir = project.project_path.join(".taskguard");
+    fs::write(
+        taskguard_dir.join("github.toml"),
+        "owner = \"acme\"\nrepo = \"app\"\nproject_number = 1\n",
+    )?;
+    fs::write(
+        taskguard_dir.join("github-mapping.json"),
+        r#"{"mappings":[{"task_id":"backend-001","issue_number":5,"issue_id":"I_5","project_item_id":"","synced_at":"2025-01-01T00:00:00Z","is_archived":false,"labels":["test"]}]}"#,
+    )?;
 
-    let (stdout, _stderr, exit_code) = project.run_command(&["lint"])?;
-
-    assert_eq!(exit_code, 0, "Lint should succeed");
-    assert!(
-        stdout.contains("analyzed") || stdout.contains("complexity") || stdout.contains("quality"),
-        "Should show analysis results"
-    );
-    assert!(stdout.contains("backend-001"), "Should mention the task");
-
+    let port = TcpListener::bind("127.0.0.1:0")?.local_addr()?.port();
+    let mut server = Command::new(&project.binary_path)
+        .args(["serve", "--github-webhook", &format!("127.0.0.1:{}", port)])
+        .current_dir(&project.project_path)
+        .env("TASKGUARD_WEBHOOK_SECRET", "s3cret")
+        .stdout(Stdio::null())
+        .spawn()?;
+
+    let deliver = |signature: &str, body: &str| -> Result<String> {
+        let mut stream = None;
+        for _ in 0..50 {
+            if let Ok(s) = TcpStream::connect(("127.0.0.1", port)) {
+                stream = Some(s);
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(100));
+        }
+        let mut stream = stream.ok_or_else(|| anyhow::anyhow!("server didn't start"))?;
+        write!(
+            stream,
+            "POST / HTTP/1.1\r\nX-GitHub-Event: issues\r\nX-GitHub-Delivery: d-1\r\nX-Hub-Signature-256: {}\r\nContent-Length: {}\r\n\r\n{}",
+            signature,
+            body.len(),
+            body
+        )?;
+        let mut response = String::new();
+        stream.read_to_string(&mut response)?;
+        Ok(response)
+    };
+    let payload = r#"{"action":"closed","repository":{"full_name":"acme/app"},"issue":{"number":5,"state":"closed","labels":[{"name":"test"},{"name":"bug"}],"assignees":[]}}"#;
+    let mut mac = Hmac::<sha2::Sha256>::new_from_slice(b"s3cret")?;
+    mac.update(payload.as_bytes());
+    let signature = mac
+        .finalize()
+        .into_bytes()
+        .iter()
+        .fold(String::new(), |mut hex, b| {
+            let _ = std::fmt::Write::write_fmt(&mut hex, format_args!("{:02x}", b));
+            hex
+        });
+
+    let rejected = deliver("sha256=00", payload);
+    let accepted = deliver(&format!("sha256={}", signature), payload);
+    let task = Task::from_file(project.project_path.join("tasks/backend/backend-001.md"));
+    let redelivered = deliver(&format!("sha256={}", signature), payload);
+    server.kill()?;
+    server.wait()?;
+
+    assert!(rejected?.starts_with("HTTP/1.1 401"));
+    assert!(accepted?.starts_with("HTTP/1.1 200"));
+    assert!(redelivered?.ends_with("duplicate"));
+    let task = task?;
+    assert_eq!(task.status, TaskStatus::Done);
+    assert_eq!(task.tags, vec!["test".to_string(), "bug".to_string()]);
     Ok(())
 }
 
 #[test]
-fn test_lint_area_filter() -> Result<()> {
+fn test_sync_working_tree_names_likely_task() -> Result<()> {
     let project = CLITestProject::new()?;
     project.run_command(&["init"])?;
-
+    project.init_git_repo()?;
     project.create_task_file(
         "backend",
         "backend-001",
         "Backend Task",
-        TaskStatus::Todo,
+        TaskStatus::Doing,
         vec![],
     )?;
     project.create_task_file(
-        "frontend",
-        "frontend-001",
-        "Frontend Task",
-        TaskStatus::Todo,
+        "backend",
+        "backend-002",
+        "Other Task",
+        TaskStatus::Doing,
         vec![],
     )?;
 
-    let (stdout, _stderr, exit_code) = project.run_command(&["lint", "--area", "backend"])?;
-
-    assert_eq!(exit_code, 0, "Lint with area filter should succeed");
-    assert!(
-        stdout.contains("backend-001"),
-        "Should include backend task"
-    );
-    assert!(
-        !stdout.contains("frontend-001"),
-        "Should exclude frontend task"
-    );
-
+    let (stdout, _, code) = project.run_command(&["sync", "--working-tree"])?;
+    assert_eq!(code, 0);
+    assert!(stdout.contains("No open task's recent commits changed these files"));
+
+    project.add_git_commit("Start backend-001")?;
+    fs::write(project.project_path.join("test.txt"), "edited")?;
+    let (stdout, _, code) = project.run_command(&["sync", "--working-tree"])?;
+    assert_eq!(code, 0);
+    assert!(stdout.contains("WORKING TREE"));
+    assert!(stdout.contains("Most likely task: backend-001 - Backend Task (doing)"));
+    assert!(stdout.contains("test.txt"));
+    assert!(!stdout.contains("backend-002"));
     Ok(())
 }
 
 #[test]
-fn test_lint_verbose_mode() -> Result<()> {
+fn test_sync_reports_only_new_activity() -> Result<()> {
     let project = CLITestProject::new()?;
     project.run_command(&["init"])?;
-
+    project.init_git_repo()?;
     project.create_task_file(
         "backend",
         "backend-001",
-        "Test Task",
+        "Backend Task",
         TaskStatus::Todo,
         vec![],
     )?;
+    project.add_git_commit("Start work on backend-001")?;
 
-    let (stdout, _stderr, exit_code) = project.run_command(&["lint", "--verbose"])?;
+    let (stdout, _, _) = project.run_command(&["sync"])?;
+    assert!(stdout.contains("backend-001"));
+    assert!(
+        project
+            .project_path
+            .join(".taskguard/sync-state.json")
+            .exists()
+    );
 
-    assert_eq!(exit_code, 0, "Lint verbose should succeed");
-    assert!(stdout.contains("backend-001"), "Should analyze the task");
-    // Verbose should show more details
-    assert!(stdout.len() > 50, "Verbose output should be detailed");
+    let (stdout, _, code) = project.run_command(&["sync"])?;
+    assert_eq!(code, 0);
+    assert!(stdout.contains("No new task activity since the last sync"));
+    assert!(!stdout.contains("TASK ACTIVITY ANALYSIS"));
 
+    let (stdout, _, _) = project.run_command(&["sync", "--full"])?;
+    assert!(stdout.contains("TASK ACTIVITY ANALYSIS"));
+    assert!(stdout.contains("backend-001"));
+
+    let git = |args: &[&str]| {
+        std::process::Command::new("git")
+            .args(args)
+            .current_dir(&project.project_path)
+            .output()
+    };
+    git(&["commit", "--allow-empty", "-m", "Finish backend-001"])?;
+    let (stdout, _, _) = project.run_command(&["sync"])?;
+    assert!(stdout.contains("Showing activity since the last sync"));
+    assert!(stdout.contains("backend-001"));
     Ok(())
 }
 
-// =============================================================================
-// AI COMMAND TESTS
-// =============================================================================
-
 #[test]
-fn test_ai_basic_interaction() -> Result<()> {
+fn test_sync_suggests_assignee_from_commit_authors() -> Result<()> {
     let project = CLITestProject::new()?;
     project.run_command(&["init"])?;
+    project.init_git_repo()?;
+    project.create_task_file(
+        "backend",
+        "backend-001",
+        "Backend Task",
+        TaskStatus::Todo,
+        vec![],
+    )?;
+    project.add_git_commit("Start work on backend-001")?;
+    Command::new("git")
+        .args(["commit", "--allow-empty", "-m", "Complete backend-001"])
+        .current_dir(&project.project_path)
+        .output()?;
+    let path = project.project_path.join("tasks/backend/backend-001.md");
+
+    let (stdout, _, _) = project.run_command(&["sync"])?;
+    assert!(stdout.contains("Assign to 'Test User' (author of 2 of 2 commits)"));
+
+    let (stdout, _, _) = project.run_command(&["sync", "--apply", "--dry-run"])?;
+    assert!(stdout.contains("Would assign backend-001 to Test User"));
+    assert!(!fs::read_to_string(&path)?.contains("Test User"));
+
+    let (stdout, _, code) = project.run_command(&["sync", "--apply", "--yes"])?;
+    assert_eq!(code, 0);
+    assert!(stdout.contains("Updated backend-001: assignee = Test User"));
+    let content = fs::read_to_string(&path)?;
+    assert!(content.contains("status: done"));
+    assert!(content.contains("assignee: Test User"));
+
+    // Assigned tasks get no further suggestion
+    let (stdout, _, _) = project.run_command(&["sync"])?;
+    assert!(!stdout.contains("Assign to"));
+    Ok(())
+}
 
-    let (stdout, _stderr, exit_code) =
-        project.run_command(&["ai", "What should I work on next?"])?;
-
-    assert_eq!(exit_code, 0, "AI command should succeed");
+#[test]
+fn test_sync_detects_merged_and_deleted_task_branches() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.init_git_repo()?;
+    for id in ["backend-001", "backend-002"] {
+        project.create_task_file("backend", id, "Backend Task", TaskStatus::Doing, vec![])?;
+    }
+    project.add_git_commit("Initial commit")?;
+    let git = |args: &[&str]| -> Result<()> {
+        Command::new("git")
+            .args(args)
+            .current_dir(&project.project_path)
+            .output()?;
+        Ok(())
+    };
+
+    // Merged by fast-forward, then deleted: no commit message names the task
+    git(&["checkout", "-b", "task/backend-001-login"])?;
+    git(&["commit", "--allow-empty", "-m", "Add login handler"])?;
+    git(&["checkout", "-"])?;
+    git(&["merge", "task/backend-001-login"])?;
+    git(&["branch", "-d", "task/backend-001-login"])?;
+
+    // Deleted without merging: abandoned, not done
+    git(&["checkout", "-b", "task/backend-002"])?;
+    git(&["commit", "--allow-empty", "-m", "Try another approach"])?;
+    git(&["checkout", "-"])?;
+    git(&["branch", "-D", "task/backend-002"])?;
+
+    let (stdout, _, code) = project.run_command(&["sync"])?;
+    assert_eq!(code, 0);
+    assert!(stdout.contains("backend-001 - Backend Task"));
+    assert!(stdout.contains("Consider changing status to 'done'"));
+    assert!(stdout.contains("Branch task/backend-001-login was merged and deleted"));
+    assert!(!stdout.contains("backend-002 - Backend Task"));
+    Ok(())
+}
+
+#[test]
+fn test_sync_suggests_reopening_reverted_tasks() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.init_git_repo()?;
+    project.create_task_file("backend", "backend-001", "Login", TaskStatus::Done, vec![])?;
+    project.add_git_commit("Initial commit")?;
+    let git = |args: &[&str]| -> Result<()> {
+        Command::new("git")
+            .args(args)
+            .current_dir(&project.project_path)
+            .output()?;
+        Ok(())
+    };
+
+    fs::write(project.project_path.join("login.rs"), "fn login() {}\n")?;
+    git(&["add", "login.rs"])?;
+    git(&["commit", "-m", "Finish backend-001 login"])?;
+    git(&["revert", "--no-edit", "HEAD"])?;
+
+    let (stdout, _, code) = project.run_command(&["sync"])?;
+    assert_eq!(code, 0);
+    assert!(stdout.contains("REVERTED after the task was marked done"));
+    assert!(stdout.contains("Revert \"Finish backend-001 login\""));
+    assert!(stdout.contains("Consider reopening the task (status 'doing')"));
+    Ok(())
+}
+
+#[test]
+fn test_sync_compares_suggestions_across_remotes() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.init_git_repo()?;
+    project.create_task_file(
+        "backend",
+        "backend-001",
+        "Backend Task",
+        TaskStatus::Doing,
+        vec![],
+    )?;
+    project.add_git_commit("Initial commit")?;
+
+    // Two clones standing in for upstream and a fork, each with its own work
+    let remotes = TempDir::new()?;
+    for (name, message) in [
+        ("origin", "Complete backend-001"),
+        ("fork", "Fix tests for backend-001"),
+    ] {
+        let path = remotes.path().join(name);
+        let path = path.to_str().unwrap();
+        let git = |args: &[&str], dir: &str| -> Result<()> {
+            Command::new("git").args(args).current_dir(dir).output()?;
+            Ok(())
+        };
+        let project_path = project.project_path.to_str().unwrap();
+        git(&["clone", "-q", project_path, path], project_path)?;
+        git(&["config", "user.name", "Test User"], path)?;
+        git(&["config", "user.email", "test@example.com"], path)?;
+        git(&["commit", "--allow-empty", "-m", message], path)?;
+        git(&["remote", "add", name, path], project_path)?;
+    }
+
+    let (stdout, _, code) =
+        project.run_command(&["sync", "--remote", "--all-remotes", "--dry-run"])?;
+    assert_eq!(code, 0);
+    assert!(stdout.contains("Using remotes: "));
+    assert!(stdout.contains("REMOTES DISAGREE"));
+    let disagreement = stdout
+        .lines()
+        .find(|line| line.trim_start().starts_with("backend-001:"))
+        .unwrap_or_default();
+    assert!(disagreement.contains("origin/") && disagreement.contains("→ done"));
+    assert!(disagreement.contains("fork/") && disagreement.contains("→ review"));
+    assert!(stdout.contains("Remote suggestion (origin/"));
+    assert!(stdout.contains("Remote suggestion (fork/"));
+
+    let (stdout, _, code) =
+        project.run_command(&["sync", "--remote", "--remote-name", "fork", "--dry-run"])?;
+    assert_eq!(code, 0);
+    assert!(stdout.contains("Using remotes: fork"));
+    assert!(!stdout.contains("Remote suggestion (origin/"));
+
+    let (_, stderr, code) = project.run_command(&["sync", "--remote", "--remote-name", "nope"])?;
+    assert_ne!(code, 0);
+    assert!(stderr.contains("Remote 'nope' not found"));
+    Ok(())
+}
+
+#[test]
+fn test_sync_remote_keeps_token_from_non_github_remotes() -> Result<()> {
+    use std::io::{BufRead, BufReader};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.init_git_repo()?;
+    project.add_git_commit("Initial commit")?;
+
+    // A server that rejects every request, recording the credentials it was sent
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let port = listener.local_addr()?.port();
+    let authorizations = Arc::new(Mutex::new(Vec::new()));
+    let seen = Arc::clone(&authorizations);
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let mut reader = BufReader::new(&stream);
+            let mut line = String::new();
+            while reader.read_line(&mut line).is_ok_and(|n| n > 0) && line != "\r\n" {
+                if line.to_lowercase().starts_with("authorization:") {
+                    seen.lock().unwrap().push(line.trim().to_string());
+                }
+                line.clear();
+            }
+            let _ = (&stream).write_all(
+                b"HTTP/1.1 401 Unauthorized\r\nWWW-Authenticate: Basic realm=\"git\"\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            );
+        }
+    });
+    Command::new("git")
+        .args([
+            "remote",
+            "add",
+            "origin",
+            &format!("http://127.0.0.1:{}/repo.git", port),
+        ])
+        .current_dir(&project.project_path)
+        .output()?;
+
+    let home = TempDir::new()?;
+    let output = Command::new(&project.binary_path)
+        .args(["sync", "--remote"])
+        .current_dir(&project.project_path)
+        .env("HOME", home.path())
+        .env("GIT_CONFIG_NOSYSTEM", "1")
+        .env("GITHUB_TOKEN", "secret-token")
+        // Even when named as the GitHub host, plain HTTP never gets the token
+        .env("GH_HOST", "127.0.0.1")
+        .env_remove("TASKGUARD_GITHUB_TOKEN")
+        .env_remove("GH_TOKEN")
+        .env_remove("GIT_ASKPASS")
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(stderr.contains("No credentials found for http://127.0.0.1"));
+    assert!(
+        stderr.contains(
+            "for GitHub ones, TASKGUARD_GITHUB_TOKEN or GITHUB_TOKEN or GH_TOKEN also work"
+        )
+    );
+    // base64("x-access-token:secret-token")
+    assert!(
+        !authorizations
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|a| a.contains("eC1hY2Nlc3MtdG9rZW46c2VjcmV0LXRva2Vu"))
+    );
+
+    let output = Command::new(&project.binary_path)
+        .args(["sync", "--remote"])
+        .current_dir(&project.project_path)
+        .env("HOME", home.path())
+        .env("GIT_CONFIG_NOSYSTEM", "1")
+        .env_remove("TASKGUARD_GITHUB_TOKEN")
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GH_TOKEN")
+        .env_remove("GIT_ASKPASS")
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("No credentials found for http://127.0.0.1"));
+    assert!(stderr.contains("GitHub token, GIT_ASKPASS)"));
+    Ok(())
+}
+
+#[test]
+fn test_sync_github_without_gh_asks_for_token() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    // No gh on PATH and no token: both ways to authenticate are named
+    let empty_path = TempDir::new()?;
+    let output = Command::new(&project.binary_path)
+        .args(["sync", "--github"])
+        .current_dir(&project.project_path)
+        .env("PATH", empty_path.path())
+        .env_remove("TASKGUARD_GITHUB_TOKEN")
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GH_TOKEN")
+        .output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("gh auth login"));
+    assert!(stderr.contains("TASKGUARD_GITHUB_TOKEN or GITHUB_TOKEN or GH_TOKEN"));
+    assert!(stderr.contains("Is gh CLI installed?"));
+    Ok(())
+}
+
+#[test]
+fn test_sync_github_ci_annotates_failure() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    let (_, stderr, code) = project.run_command(&["sync", "--ci"])?;
+    assert_ne!(code, 0);
+    assert!(stderr.contains("--github"));
+
+    // A failed sync in CI is reported as an error annotation
+    let empty_path = TempDir::new()?;
+    let output = Command::new(&project.binary_path)
+        .args(["sync", "--github", "--ci"])
+        .current_dir(&project.project_path)
+        .env("PATH", empty_path.path())
+        .env_remove("TASKGUARD_GITHUB_TOKEN")
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GH_TOKEN")
+        .output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!output.status.success());
+    assert!(stdout.contains("::error title=taskguard sync --github::"));
+    // Multi-line messages stay on one line
+    let annotation = stdout
+        .lines()
+        .find(|line| line.starts_with("::error"))
+        .unwrap();
+    assert!(annotation.contains("%0A"));
+    Ok(())
+}
+
+#[test]
+fn test_sync_reports_area_scoped_commits() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.init_git_repo()?;
+    let config_path = project.project_path.join(".taskguard/config.toml");
+    let config = fs::read_to_string(&config_path)?;
+    fs::write(
+        &config_path,
+        format!("{}\n[git.scopes]\nlogin = \"auth\"\n", config),
+    )?;
+    project.add_git_commit("fix(login): handle token expiry")?;
+
+    let (stdout, _, code) = project.run_command(&["sync", "--verbose"])?;
+    assert_eq!(code, 0);
+    assert!(stdout.contains("No task-related activity found"));
+    assert!(stdout.contains("AREA ACTIVITY"));
+    assert!(stdout.contains("auth: 1 commits"));
+    assert!(stdout.contains("fix(login): handle token expiry"));
+    Ok(())
+}
+
+#[test]
+fn test_sync_branches_flags_tasks_on_several_branches() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.init_git_repo()?;
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(&project.project_path)
+            .output()
+    };
+    git(&["commit", "--allow-empty", "-m", "Start backend-001"])?;
+    git(&["checkout", "-b", "task/backend-001-login"])?;
+    git(&["commit", "--allow-empty", "-m", "Add login form"])?;
+
+    let (stdout, _, _) = project.run_command(&["sync"])?;
+    assert!(!stdout.contains("BRANCH ACTIVITY"));
+
+    let (stdout, _, code) = project.run_command(&["sync", "--branches"])?;
+    assert_eq!(code, 0);
+    assert!(stdout.contains("BRANCH ACTIVITY"));
+    assert!(stdout.contains("task/backend-001-login: 1 commits → backend-001 (1)"));
+    assert!(stdout.contains("Tasks touched on several branches"));
+    Ok(())
+}
+
+#[test]
+fn test_commit_msg_template_for_active_task() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.init_git_repo()?;
+    project.create_task_file(
+        "backend",
+        "backend-001",
+        "Backend Task",
+        TaskStatus::Doing,
+        vec![],
+    )?;
+    let path = project.project_path.join("tasks/backend/backend-001.md");
+    let content = fs::read_to_string(&path)?;
+    fs::write(
+        &path,
+        format!("{}\n- [x] Add route\n- [ ] Add form\n", content),
+    )?;
+
+    let (stdout, _, code) = project.run_command(&["commit-msg", "backend-001"])?;
+    assert_eq!(code, 0);
+    assert!(stdout.starts_with("\n\nTask-Id: backend-001\n"));
+    assert!(stdout.contains("# backend-001: Backend Task (doing)"));
+    assert!(stdout.contains("[ ] 2. Add form"));
+    assert!(!stdout.contains("Add route"));
+
+    // Without an ID, the only task in progress is used
+    let (stdout, _, code) = project.run_command(&["commit-msg"])?;
+    assert_eq!(code, 0);
+    assert!(stdout.contains("Task-Id: backend-001"));
+
+    let message = project.project_path.join("COMMIT_EDITMSG");
+    fs::write(&message, "\n# Please enter the commit message\n")?;
+    project.run_command(&["commit-msg", "--file", "COMMIT_EDITMSG"])?;
+    project.run_command(&["commit-msg", "--file", "COMMIT_EDITMSG"])?;
+    let written = fs::read_to_string(&message)?;
+    assert_eq!(written.matches("Task-Id: backend-001").count(), 1);
+    assert!(written.ends_with("# Please enter the commit message\n"));
+
+    let (_, stderr, code) = project.run_command(&["commit-msg", "backend-999"])?;
+    assert_ne!(code, 0);
+    assert!(stderr.contains("Task 'backend-999' not found"));
+    Ok(())
+}
+
+#[test]
+fn test_validate_staged_checks_only_staged_task_files() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.init_git_repo()?;
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(&project.project_path)
+            .output()
+    };
+
+    let (stdout, _, code) = project.run_command(&["validate", "--staged"])?;
+    assert_eq!(code, 0);
+    assert!(stdout.contains("No staged task files"));
+
+    // A broken file that isn't staged doesn't matter
+    fs::write(
+        project.project_path.join("tasks/backend/broken.md"),
+        "no frontmatter here\n",
+    )?;
+    project.create_task_file(
+        "backend",
+        "backend-001",
+        "Backend Task",
+        TaskStatus::Todo,
+        vec!["setup-001".to_string()],
+    )?;
+    git(&["add", "tasks/backend/backend-001.md"])?;
+    let (stdout, _, code) = project.run_command(&["validate", "--staged"])?;
+    assert_eq!(code, 0, "{}", stdout);
+    assert!(stdout.contains("Validating 1 staged task file(s)"));
+    assert!(stdout.contains("Staged task files are valid"));
+
+    // The staged version is checked, not the working copy
+    project.create_task_file(
+        "backend",
+        "backend-002",
+        "Other Task",
+        TaskStatus::Todo,
+        vec!["missing-001".to_string()],
+    )?;
+    git(&["add", "tasks/backend/backend-002.md"])?;
+    project.create_task_file(
+        "backend",
+        "backend-002",
+        "Other Task",
+        TaskStatus::Todo,
+        vec![],
+    )?;
+    let (stdout, stderr, code) = project.run_command(&["validate", "--staged"])?;
+    assert_ne!(code, 0);
+    assert!(stdout.contains("backend-002.md: depends on missing task 'missing-001'"));
+    assert!(stderr.contains("1 problem(s) in staged task files"));
+
+    git(&[
+        "add",
+        "tasks/backend/broken.md",
+        "tasks/backend/backend-002.md",
+    ])?;
+    let (stdout, _, code) = project.run_command(&["validate", "--staged"])?;
+    assert_ne!(code, 0);
+    assert!(stdout.contains("broken.md:1: field 'frontmatter'"));
+    assert!(!stdout.contains("missing-001"));
+    Ok(())
+}
+
+#[test]
+fn test_show_files_lists_files_changed_under_task() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.init_git_repo()?;
+    project.create_task_file(
+        "backend",
+        "backend-001",
+        "Backend Task",
+        TaskStatus::Doing,
+        vec![],
+    )?;
+    let commit = |path: &str, content: &str, message: &str| -> Result<()> {
+        let file = project.project_path.join(path);
+        fs::create_dir_all(file.parent().unwrap())?;
+        fs::write(&file, content)?;
+        Command::new("git")
+            .args(["add", "-A"])
+            .current_dir(&project.project_path)
+            .output()?;
+        Command::new("git")
+            .args(["commit", "-m", message])
+            .current_dir(&project.project_path)
+            .output()?;
+        Ok(())
+    };
+    commit("README.md", "x", "Initial commit")?;
+    commit("src/login.rs", "a", "Start backend-001")?;
+    commit("src/shared.rs", "a", "Shared helper for backend-001")?;
+    commit("src/shared.rs", "b", "backend-002 uses shared")?;
+    commit("src/shared.rs", "c", "backend-002 extends shared")?;
+
+    let (stdout, _, code) = project.run_command(&["show", "backend-001", "--files"])?;
+    assert_eq!(code, 0);
+    assert!(stdout.contains("FILES (2 changed)"));
+    assert!(stdout.contains("src/login.rs"));
+    assert!(stdout.contains("mostly changed under backend-002: 2 commits"));
+    assert!(!stdout.contains("tasks/backend/backend-001.md"));
+
+    let (stdout, _, _) = project.run_command(&["show", "backend-001"])?;
+    assert!(!stdout.contains("FILES"));
+    Ok(())
+}
+
+#[test]
+fn test_log_lists_commits_referencing_task() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.init_git_repo()?;
+    let commit = |path: &str, content: &str, message: &str| -> Result<()> {
+        let file = project.project_path.join(path);
+        fs::create_dir_all(file.parent().unwrap())?;
+        fs::write(&file, content)?;
+        Command::new("git")
+            .args(["add", "-A"])
+            .current_dir(&project.project_path)
+            .output()?;
+        Command::new("git")
+            .args(["commit", "-m", message])
+            .current_dir(&project.project_path)
+            .output()?;
+        Ok(())
+    };
+    commit("README.md", "x", "Initial commit")?;
+    commit(
+        "src/login.rs",
+        "fn login() {}\n",
+        "feat(backend-001): add login",
+    )?;
+    commit("src/other.rs", "a", "Work on backend-0011")?;
+    commit(
+        "src/shared.rs",
+        "a",
+        "Shared helper\n\nTask-Id: backend-001",
+    )?;
+
+    let (stdout, _, code) = project.run_command(&["log", "backend-001"])?;
+    assert_eq!(code, 0);
+    assert!(stdout.contains("2 commit(s) referencing backend-001"));
+    assert!(stdout.contains("    feat(backend-001): add login"));
+    assert!(stdout.contains("    Task-Id: backend-001"));
+    assert!(!stdout.contains("backend-0011"));
+    assert!(!stdout.contains("src/login.rs"));
+
+    let (stdout, _, _) = project.run_command(&["log", "backend-001", "--files", "--patch"])?;
+    assert!(stdout.contains("    src/login.rs"));
+    assert!(stdout.contains("+fn login() {}"));
+
+    let (stdout, _, code) = project.run_command(&["log", "frontend-001"])?;
+    assert_eq!(code, 0);
+    assert!(stdout.contains("No commits among the last 1000 reference frontend-001"));
+    Ok(())
+}
+
+#[test]
+fn test_auto_commit_commits_only_changed_task_files() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.init_git_repo()?;
+    project.create_task_file(
+        "backend",
+        "backend-001",
+        "Backend Task",
+        TaskStatus::Todo,
+        vec![],
+    )?;
+    let git = |args: &[&str]| -> Result<String> {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(&project.project_path)
+            .output()?;
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+    git(&["add", "-A"])?;
+    git(&["commit", "-m", "Initial commit"])?;
+
+    // Off by default
+    project.run_command(&["update", "priority", "backend-001", "high"])?;
+    assert!(git(&["log", "-1", "--format=%s"])?.contains("Initial commit"));
+
+    let config_path = project.project_path.join(".taskguard/config.toml");
+    let config =
+        fs::read_to_string(&config_path)?.replace("auto_commit = false", "auto_commit = true");
+    fs::write(&config_path, config)?;
+    fs::write(project.project_path.join("notes.txt"), "unrelated")?;
+    git(&["add", "notes.txt"])?;
+
+    let (stdout, stderr, code) =
+        project.run_command(&["update", "status", "backend-001", "doing"])?;
+    assert_eq!(code, 0, "{}", stderr);
+    assert!(stdout.contains("Committed: taskguard: update backend-001 status → doing"));
+    let message = git(&["log", "-1", "--format=%B"])?;
+    assert!(message.starts_with("taskguard: update backend-001 status → doing"));
+    assert!(message.contains("Task-Id: backend-001"));
+    let files = git(&["show", "--name-only", "--format=", "HEAD"])?;
+    assert_eq!(files.trim(), "tasks/backend/backend-001.md");
+    // Changes staged before stay staged
+    assert!(git(&["diff", "--cached", "--name-only"])?.contains("notes.txt"));
+    Ok(())
+}
+
+#[test]
+fn test_tasks_branch_keeps_task_files_off_the_code_branch() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.init_git_repo()?;
+    project.add_git_commit("Initial commit")?;
+    let git = |dir: &std::path::Path, args: &[&str]| -> Result<String> {
+        let output = Command::new("git").args(args).current_dir(dir).output()?;
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+    let root = project.project_path.clone();
+
+    let (stdout, stderr, code) = project.run_command(&["init", "--tasks-branch"])?;
+    assert_eq!(code, 0, "{}", stderr);
+    assert!(stdout.contains("stored on the 'taskguard' orphan branch"));
+    assert!(!git(&root, &["status", "--short"])?.contains("tasks/"));
+    assert!(git(&root, &["ls-tree", "-r", "--name-only", "taskguard"])?.contains("setup/"));
+
+    // Changes made in tasks/ are committed to the branch
+    project.create_task_file(
+        "backend",
+        "backend-001",
+        "Backend Task",
+        TaskStatus::Todo,
+        vec![],
+    )?;
+    let (stdout, _, _) = project.run_command(&["update", "status", "backend-001", "doing"])?;
+    assert!(stdout.contains("Saved to branch 'taskguard': taskguard: update backend-001"));
+    let committed = git(&root, &["show", "taskguard:backend/backend-001.md"])?;
+    assert!(committed.contains("status: doing"));
+    assert_eq!(git(&root, &["rev-list", "--count", "HEAD"])?.trim(), "1");
+
+    // Commits made to the branch elsewhere show up in tasks/
+    let worktree = root.join("branch-checkout");
+    git(&root, &["worktree", "add", "branch-checkout", "taskguard"])?;
+    let file = worktree.join("backend/backend-001.md");
+    fs::write(
+        &file,
+        fs::read_to_string(&file)?.replace("Backend Task", "Renamed Task"),
+    )?;
+    git(&worktree, &["commit", "-am", "Rename backend-001"])?;
+
+    let (stdout, _, code) = project.run_command(&["list"])?;
+    assert_eq!(code, 0);
+    assert!(stdout.contains("Renamed Task"));
+    Ok(())
+}
+
+#[test]
+fn test_release_associate_records_first_tag_with_task_commits() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.init_git_repo()?;
+    for id in ["backend-001", "backend-002", "backend-003"] {
+        project.create_task_file("backend", id, "Backend Task", TaskStatus::Done, vec![])?;
+    }
+    let git = |args: &[&str]| -> Result<()> {
+        Command::new("git")
+            .args(args)
+            .current_dir(&project.project_path)
+            .output()?;
+        Ok(())
+    };
+
+    git(&["commit", "--allow-empty", "-m", "Finish backend-001"])?;
+    git(&["tag", "v1.0.0"])?;
+    git(&["commit", "--allow-empty", "-m", "Finish backend-002"])?;
+    git(&["tag", "-a", "v1.1.0", "-m", "Release 1.1.0"])?;
+
+    let (stdout, _, code) =
+        project.run_command(&["release", "associate", "v1.1.0", "--dry-run"])?;
+    assert_eq!(code, 0);
+    assert!(stdout.contains("v1.0.0: 1 tasks"));
+    assert!(stdout.contains("v1.1.0: 1 tasks"));
+    assert!(stdout.contains("1 done tasks have no commits referencing them"));
+
+    let (_, _, code) = project.run_command(&["release", "associate", "v1.1.0"])?;
+    assert_eq!(code, 0);
+    let (stdout, _, _) = project.run_command(&["show", "backend-001"])?;
+    assert!(stdout.contains("Shipped in: v1.0.0"));
+    let (stdout, _, _) = project.run_command(&["show", "backend-002"])?;
+    assert!(stdout.contains("Shipped in: v1.1.0"));
+
+    let (_, stderr, code) = project.run_command(&["release", "associate", "v9.9.9"])?;
+    assert_ne!(code, 0);
+    assert!(stderr.contains("Tag 'v9.9.9' not found"));
+    Ok(())
+}
+
+#[test]
+fn test_branch_creates_and_records_task_branch() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.init_git_repo()?;
+    project.create_task_file(
+        "backend",
+        "backend-001",
+        "Add OAuth login (v2)!",
+        TaskStatus::Todo,
+        vec![],
+    )?;
+    project.create_task_file(
+        "backend",
+        "backend-002",
+        "Backend Task",
+        TaskStatus::Todo,
+        vec![],
+    )?;
+    project.add_git_commit("Initial commit")?;
+    let current_branch = || -> Result<String> {
+        let output = Command::new("git")
+            .args(["rev-parse", "--abbrev-ref", "HEAD"])
+            .current_dir(&project.project_path)
+            .output()?;
+        Ok(String::from_utf8(output.stdout)?.trim().to_string())
+    };
+    let trunk = current_branch()?;
+    let path = project.project_path.join("tasks/backend/backend-001.md");
+
+    let (stdout, _, code) = project.run_command(&["branch", "backend-001"])?;
+    assert_eq!(code, 0);
+    assert!(stdout.contains("Created and switched to backend/backend-001-add-oauth-login-v2"));
+    assert_eq!(current_branch()?, "backend/backend-001-add-oauth-login-v2");
+    assert!(fs::read_to_string(&path)?.contains("branch: backend/backend-001-add-oauth-login-v2"));
+
+    let (stdout, _, _) = project.run_command(&["branch", "backend-001"])?;
+    assert!(stdout.contains("Already on backend/backend-001-add-oauth-login-v2"));
+
+    Command::new("git")
+        .args(["checkout", &trunk])
+        .current_dir(&project.project_path)
+        .output()?;
+    let (stdout, _, _) = project.run_command(&["branch", "backend-001"])?;
+    assert!(stdout.contains("Switched to existing branch backend/backend-001-add-oauth-login-v2"));
+    assert_eq!(current_branch()?, "backend/backend-001-add-oauth-login-v2");
+
+    let (stdout, _, _) = project.run_command(&["show", "backend-001"])?;
+    assert!(stdout.contains("Branch: backend/backend-001-add-oauth-login-v2"));
+
+    // The naming pattern comes from config
+    let config_path = project.project_path.join(".taskguard/config.toml");
+    let config = fs::read_to_string(&config_path)?.replace(
+        "branch_pattern = \"{area}/{id}-{slug}\"",
+        "branch_pattern = \"task/{id}\"",
+    );
+    fs::write(&config_path, config)?;
+    let (stdout, _, code) = project.run_command(&["branch", "backend-002"])?;
+    assert_eq!(code, 0);
+    assert!(stdout.contains("Created and switched to task/backend-002"));
+    Ok(())
+}
+
+#[test]
+fn test_worktree_add_list_and_remove() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.init_git_repo()?;
+    project.create_task_file(
+        "backend",
+        "backend-001",
+        "Backend Task",
+        TaskStatus::Doing,
+        vec![],
+    )?;
+    project.add_git_commit("Initial commit")?;
+    let config_path = project.project_path.join(".taskguard/config.toml");
+    let config = fs::read_to_string(&config_path)?.replace(
+        "worktree_dir = \"../{project}-worktrees\"",
+        "worktree_dir = \".worktrees\"",
+    );
+    fs::write(&config_path, config)?;
+    let worktree = project.project_path.join(".worktrees/backend-001");
+
+    let (stdout, stderr, code) = project.run_command(&["worktree", "add", "backend-001"])?;
+    assert_eq!(code, 0, "{}", stderr);
+    assert!(stdout.contains("Added worktree for backend-001"));
+    assert!(stdout.contains("Branch: backend/backend-001-backend-task"));
+    assert!(worktree.join("test.txt").exists());
+    let task_file = project.project_path.join("tasks/backend/backend-001.md");
+    assert!(fs::read_to_string(&task_file)?.contains("branch: backend/backend-001-backend-task"));
+
+    let (_, stderr, code) = project.run_command(&["worktree", "add", "backend-001"])?;
+    assert_ne!(code, 0);
+    assert!(stderr.contains("already has a worktree"));
+
+    let (stdout, _, _) = project.run_command(&["worktree", "list"])?;
+    assert!(stdout.contains("backend-001 [doing] backend/backend-001-backend-task"));
+
+    // Only done tasks are cleaned up without --force
+    let (_, stderr, code) = project.run_command(&["worktree", "remove", "backend-001"])?;
+    assert_ne!(code, 0);
+    assert!(stderr.contains("not done"));
+    project.run_command(&["update", "status", "backend-001", "done"])?;
+    let (stdout, _, _) = project.run_command(&["worktree", "list"])?;
+    assert!(stdout.contains("Done tasks with worktrees: backend-001"));
+
+    fs::write(worktree.join("scratch.txt"), "wip")?;
+    let (stdout, _, _) = project.run_command(&["worktree", "remove"])?;
+    assert!(stdout.contains("uncommitted changes"));
+    assert!(worktree.exists());
+
+    fs::remove_file(worktree.join("scratch.txt"))?;
+    let (stdout, _, code) = project.run_command(&["worktree", "remove"])?;
+    assert_eq!(code, 0);
+    assert!(stdout.contains("Removed worktree for backend-001"));
+    assert!(!worktree.exists());
+    let (stdout, _, _) = project.run_command(&["worktree", "list"])?;
+    assert!(stdout.contains("No linked worktrees"));
+    Ok(())
+}
+
+#[test]
+fn test_hooks_install_check_and_uninstall() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.init_git_repo()?;
+    project.create_task_file(
+        "backend",
+        "backend-001",
+        "Backend Task",
+        TaskStatus::Todo,
+        vec![],
+    )?;
+    let hooks_dir = project.project_path.join(".git/hooks");
+    fs::create_dir_all(&hooks_dir)?;
+    fs::write(hooks_dir.join("pre-push"), "#!/bin/sh\necho existing\n")?;
+
+    let (stdout, _, code) = project.run_command(&["hooks", "install"])?;
+    assert_eq!(code, 0);
+    assert!(stdout.contains("Kept existing pre-push hook"));
+    let commit_msg = fs::read_to_string(hooks_dir.join("commit-msg"))?;
+    assert!(commit_msg.contains("taskguard hooks check-commit-msg \"$1\""));
+    assert!(fs::read_to_string(hooks_dir.join("pre-push"))?.contains("exec taskguard validate"));
+    assert!(hooks_dir.join("pre-push.pre-taskguard").exists());
+
+    // Reinstalling replaces TaskGuard's own hooks without touching the saved one
+    let (_, _, code) = project.run_command(&["hooks", "install", "--strict"])?;
+    assert_eq!(code, 0);
+    assert!(fs::read_to_string(hooks_dir.join("pre-push"))?.contains("validate --strict"));
+    assert_eq!(
+        fs::read_to_string(hooks_dir.join("pre-push.pre-taskguard"))?,
+        "#!/bin/sh\necho existing\n"
+    );
+
+    let message = project.project_path.join("COMMIT_EDITMSG");
+    fs::write(
+        &message,
+        "Add login form\n\n# Please enter the commit message\n",
+    )?;
+    let (_, stderr, code) =
+        project.run_command(&["hooks", "check-commit-msg", "COMMIT_EDITMSG"])?;
+    assert_eq!(code, 0);
+    assert!(stderr.contains("doesn't reference a known task ID"));
+
+    fs::write(&message, "Add login form for backend-001\n")?;
+    let (_, stderr, code) =
+        project.run_command(&["hooks", "check-commit-msg", "COMMIT_EDITMSG"])?;
+    assert_eq!(code, 0);
+    assert!(stderr.is_empty());
+
+    let config_path = project.project_path.join(".taskguard/config.toml");
+    let config = fs::read_to_string(&config_path)?;
+    fs::write(
+        &config_path,
+        config.replace(
+            "commit_msg_check = \"warn\"",
+            "commit_msg_check = \"error\"",
+        ),
+    )?;
+    fs::write(&message, "Add login form\n\nTask-Id: backend-999\n")?;
+    let (_, stderr, code) =
+        project.run_command(&["hooks", "check-commit-msg", "COMMIT_EDITMSG"])?;
+    assert_ne!(code, 0);
+    assert!(stderr.contains("No task named backend-999"));
+
+    let (stdout, _, code) = project.run_command(&["hooks", "uninstall"])?;
+    assert_eq!(code, 0);
+    assert!(stdout.contains("previous hook restored"));
+    assert!(!hooks_dir.join("commit-msg").exists());
+    assert_eq!(
+        fs::read_to_string(hooks_dir.join("pre-push"))?,
+        "#!/bin/sh\necho existing\n"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_sync_verbose_mode() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.init_git_repo()?;
+
+    project.create_task_file(
+        "backend",
+        "backend-001",
+        "Test Task",
+        TaskStatus::Todo,
+        vec![],
+    )?;
+    project.add_git_commit("Work on backend-001")?;
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["sync", "--verbose"])?;
+
+    assert_eq!(exit_code, 0, "Sync verbose should succeed");
+    assert!(stdout.contains("backend-001"), "Should show task activity");
+    // Verbose mode should show more details
+    assert!(stdout.len() > 100, "Verbose output should be substantial");
+
+    Ok(())
+}
+
+// =============================================================================
+// LINT COMMAND TESTS
+// =============================================================================
+
+#[test]
+fn test_lint_empty_project() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["lint"])?;
+
+    assert_eq!(exit_code, 0, "Lint should succeed on empty project");
+    assert!(
+        stdout.contains("0") || stdout.contains("No tasks") || stdout.contains("analyzed"),
+        "Should handle empty project"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_lint_with_tasks() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    project.create_task_file(
+        "backend",
+        "backend-001",
+        "Good Task",
+        TaskStatus::Todo,
+        vec![],
+    )?;
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["lint"])?;
+
+    assert_eq!(exit_code, 0, "Lint should succeed");
+    assert!(
+        stdout.contains("analyzed") || stdout.contains("complexity") || stdout.contains("quality"),
+        "Should show analysis results"
+    );
+    assert!(stdout.contains("backend-001"), "Should mention the task");
+
+    Ok(())
+}
+
+#[test]
+fn test_lint_area_filter() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    project.create_task_file(
+        "backend",
+        "backend-001",
+        "Backend Task",
+        TaskStatus::Todo,
+        vec![],
+    )?;
+    project.create_task_file(
+        "frontend",
+        "frontend-001",
+        "Frontend Task",
+        TaskStatus::Todo,
+        vec![],
+    )?;
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["lint", "--area", "backend"])?;
+
+    assert_eq!(exit_code, 0, "Lint with area filter should succeed");
+    assert!(
+        stdout.contains("backend-001"),
+        "Should include backend task"
+    );
+    assert!(
+        !stdout.contains("frontend-001"),
+        "Should exclude frontend task"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_lint_ignore_directive_reported_in_verbose() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    fs::create_dir_all(project.project_path.join("tasks/backend"))?;
+    fs::write(
+        project.project_path.join("tasks/backend/backend-001.md"),
+        "---\nid: backend-001\ntitle: Big on purpose\nstatus: todo\npriority: medium\ntags: [infra]\ndependencies: [setup-001]\nassignee: null\ncreated: 2025-01-01T00:00:00Z\nestimate: 2h\ncomplexity: ~\narea: backend\n---\n\n<!-- taskguard-lint: ignore structure, completeness -->\nShort.\n",
+    )?;
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["lint", "--area", "backend"])?;
+    assert_eq!(exit_code, 0);
+    assert!(!stdout.contains("lacks clear objectives"));
+    assert!(!stdout.contains("description is very brief"));
+    assert!(stdout.contains("Ignored: 2 (by lint-ignore directives, see --verbose)"));
+
+    let (stdout, _stderr, _) = project.run_command(&["lint", "--area", "backend", "--verbose"])?;
+    assert!(stdout.contains("Ignored by lint-ignore directive:"));
+    assert!(stdout.contains("[structure] Task lacks clear objectives or acceptance criteria"));
+    assert!(stdout.contains("[completeness] Task description is very brief"));
+
+    Ok(())
+}
+
+#[test]
+fn test_lint_max_errors_and_warnings_exit_codes() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.create_task_file(
+        "backend",
+        "backend-001",
+        "Thin task",
+        TaskStatus::Todo,
+        vec![],
+    )?;
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["lint"])?;
+    assert_eq!(exit_code, 0, "Without thresholds lint only reports");
+    assert!(stdout.contains("Warnings:"));
+
+    let (_stdout, stderr, exit_code) =
+        project.run_command(&["lint", "--max-errors", "0", "--max-warnings", "0"])?;
+    assert_ne!(exit_code, 0);
+    assert!(stderr.contains("Lint thresholds exceeded:"));
+    assert!(stderr.contains("warning(s) (max 0)"));
+    assert!(!stderr.contains("error(s)"));
+
+    let (_stdout, stderr, exit_code) =
+        project.run_command(&["lint", "--max-errors", "0", "--max-warnings", "100"])?;
+    assert_eq!(exit_code, 0, "Within thresholds: {}", stderr);
+
+    Ok(())
+}
+
+#[test]
+fn test_lint_config_thresholds_and_required_sections() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.create_task_file(
+        "backend",
+        "backend-001",
+        "Wide task",
+        TaskStatus::Todo,
+        vec!["setup-001".to_string(), "backend-000".to_string()],
+    )?;
+
+    let (stdout, _stderr, _) = project.run_command(&["lint"])?;
+    assert!(stdout.contains("lacks clear objectives"));
+    assert!(!stdout.contains("many dependencies"));
+
+    let config_path = project.project_path.join(".taskguard/config.toml");
+    let mut config = fs::read_to_string(&config_path)?;
+    config.push_str(
+        "\n[lint]\nmax_dependencies = 1\nrequired_sections = [\"Context\", \"Rollout\"]\n",
+    );
+    fs::write(&config_path, config)?;
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["lint"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("Task has many dependencies (2)"));
+    assert!(stdout.contains("Missing required section: Context"));
+    assert!(stdout.contains("Missing required section: Rollout"));
+    assert!(!stdout.contains("lacks clear objectives"));
+
+    Ok(())
+}
+
+#[test]
+fn test_lint_spellcheck_is_opt_in_and_uses_project_dictionary() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    fs::create_dir_all(project.project_path.join("tasks/backend"))?;
+    fs::write(
+        project.project_path.join("tasks/backend/backend-001.md"),
+        "---\nid: backend-001\ntitle: Seperate the enviroment config\nstatus: todo\npriority: medium\ntags: [infra]\ndependencies: [setup-001]\nassignee: null\ncreated: 2025-01-01T00:00:00Z\nestimate: 2h\ncomplexity: ~\narea: backend\n---\n\n## Objectives\nRecieve events.\n\n```\nteh = 1\n```\n",
+    )?;
+
+    let (stdout, _, _) = project.run_command(&["lint", "--area", "backend", "--verbose"])?;
+    assert!(!stdout.contains("Possible typo"));
+
+    let (stdout, stderr, exit_code) =
+        project.run_command(&["lint", "--area", "backend", "--verbose", "--spellcheck"])?;
+    assert_eq!(exit_code, 0, "{}", stderr);
+    assert!(stdout.contains("Possible typo 'Seperate' in title"));
+    assert!(stdout.contains("Did you mean 'Separate'?"));
+    assert!(stdout.contains("Possible typo 'enviroment' in title"));
+    assert!(stdout.contains("Possible typo 'Recieve' in body line 2"));
+    assert!(!stdout.contains("'teh'"), "code blocks are skipped");
+
+    // The project dictionary accepts words and adds corrections; config turns it on
+    fs::write(
+        project.project_path.join(".taskguard/dictionary.txt"),
+        "# project words\nenviroment\nevents -> occurrences\n",
+    )?;
+    let config_path = project.project_path.join(".taskguard/config.toml");
+    let mut config = fs::read_to_string(&config_path)?;
+    config.push_str("\n[lint]\nspellcheck = true\n");
+    fs::write(&config_path, config)?;
+
+    let (stdout, _, _) = project.run_command(&["lint", "--area", "backend", "--verbose"])?;
+    assert!(stdout.contains("Possible typo 'Seperate' in title"));
+    assert!(!stdout.contains("'enviroment'"));
+    assert!(stdout.contains("Possible typo 'events' in body line 2"));
+
+    Ok(())
+}
+
+#[test]
+fn test_lint_apply_complexity_fills_missing_values_only() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    fs::create_dir_all(project.project_path.join("tasks/backend"))?;
+    let path = project.project_path.join("tasks/backend/backend-001.md");
+    fs::write(
+        &path,
+        "---\nid: backend-001\ntitle: Unscored task\nstatus: todo\npriority: medium\ntags: [infra]\ndependencies: [setup-001]\nassignee: null\ncreated: 2025-01-01T00:00:00Z\nestimate: 2d\ncomplexity: ~\narea: backend\n---\n\n## Tasks\n- [ ] One\n- [ ] Two\n",
+    )?;
+    let setup = project
+        .project_path
+        .join("tasks/setup/001-project-setup.md");
+    let setup_before = fs::read_to_string(&setup)?;
+
+    let (stdout, _, _) = project.run_command(&["lint"])?;
+    assert!(!stdout.contains("COMPLEXITY APPLIED"));
+    assert!(fs::read_to_string(&path)?.contains("complexity: ~"));
+
+    let (stdout, stderr, exit_code) = project.run_command(&["lint", "--apply-complexity"])?;
+    assert_eq!(exit_code, 0, "{}", stderr);
+    let task = Task::from_file(&path)?;
+    let complexity = task.complexity.expect("complexity written");
+    assert!((1..=10).contains(&complexity));
+    assert!(stdout.contains(&format!(
+        "Set complexity on 1 task(s): backend-001 → {}",
+        complexity
+    )));
+    // The manual value in setup-001 is left alone
+    assert_eq!(fs::read_to_string(&setup)?, setup_before);
+
+    let (stdout, _, _) = project.run_command(&["lint", "--apply-complexity"])?;
+    assert!(stdout.contains("All tasks already have a complexity"));
+
+    Ok(())
+}
+
+#[test]
+fn test_lint_cache_reanalyzes_only_changed_files() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.create_task_file(
+        "backend",
+        "backend-001",
+        "Cached task",
+        TaskStatus::Todo,
+        vec!["setup-001".to_string()],
+    )?;
+    let cache_file = project.project_path.join(".taskguard/cache/lint.json");
+    assert!(
+        fs::read_to_string(project.project_path.join(".gitignore"))?.contains(".taskguard/cache/")
+    );
+
+    let (stdout, _, _) = project.run_command(&["lint", "--verbose"])?;
+    assert!(stdout.contains("Cached: 0 of 2 tasks unchanged"));
+    assert!(cache_file.exists());
+
+    let (cached, _, _) = project.run_command(&["lint", "--verbose"])?;
+    assert!(cached.contains("Cached: 2 of 2 tasks unchanged"));
+    // Reused results print the same report
+    let strip = |out: &str| -> String {
+        out.lines()
+            .filter(|l| !l.contains("Cached:"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    assert_eq!(strip(&cached), strip(&stdout));
+
+    let path = project.project_path.join("tasks/backend/backend-001.md");
+    let mut content = fs::read_to_string(&path)?;
+    content.push_str("\nMore detail.\n");
+    fs::write(&path, content)?;
+    let (stdout, _, _) = project.run_command(&["lint", "--verbose"])?;
+    assert!(stdout.contains("Cached: 1 of 2 tasks unchanged"));
+
+    // Changing lint settings invalidates everything
+    let config_path = project.project_path.join(".taskguard/config.toml");
+    let mut config = fs::read_to_string(&config_path)?;
+    config.push_str("\n[lint]\nmax_dependencies = 1\n");
+    fs::write(&config_path, config)?;
+    let (stdout, _, _) = project.run_command(&["lint", "--verbose"])?;
+    assert!(stdout.contains("Cached: 0 of 2 tasks unchanged"));
+
+    fs::remove_file(&cache_file)?;
+    let (stdout, _, _) = project.run_command(&["lint", "--verbose", "--no-cache"])?;
+    assert!(!stdout.contains("Cached:"));
+    assert!(!cache_file.exists());
+
+    Ok(())
+}
+
+#[test]
+fn test_lint_json_format_reports_issues_with_lines() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    fs::create_dir_all(project.project_path.join("tasks/backend"))?;
+    fs::write(
+        project.project_path.join("tasks/backend/backend-001.md"),
+        "---\nid: backend-001\ntitle: Cleanup\nstatus: todo\npriority: medium\ntags: []\ndependencies: []\nassignee: null\ncreated: 2025-01-01T00:00:00Z\nestimate: 2h\ncomplexity: 3\narea: backend\n---\n\n# Cleanup\n\nFix teh parser.\n",
+    )?;
+
+    let (stdout, _, code) = project.run_command(&["lint", "--format", "json", "--spellcheck"])?;
+    assert_eq!(code, 0);
+    let report: serde_json::Value = serde_json::from_str(&stdout)?;
+    assert_eq!(report["version"], 1);
+    assert_eq!(report["summary"]["tasks"], 2);
+
+    let task = report["tasks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|t| t["id"] == "backend-001")
+        .unwrap();
+    assert_eq!(task["file"], "tasks/backend/backend-001.md");
+    let issues = task["issues"].as_array().unwrap();
+    let typo = issues.iter().find(|i| i["category"] == "spelling").unwrap();
+    assert_eq!(typo["severity"], "info");
+    assert_eq!(typo["line"], 17);
+    let naming = issues.iter().find(|i| i["category"] == "naming").unwrap();
+    assert_eq!(naming["line"], 3);
+    assert!(issues.iter().all(|i| i["message"].is_string()));
+
+    // Thresholds still apply
+    let (_, stderr, code) =
+        project.run_command(&["lint", "--format", "json", "--max-warnings", "0"])?;
+    assert_ne!(code, 0);
+    assert!(stderr.contains("warning"));
+
+    let (_, stderr, code) = project.run_command(&["lint", "--format", "xml"])?;
+    assert_ne!(code, 0);
+    assert!(stderr.contains("Valid values: text, json"));
+    Ok(())
+}
+
+#[test]
+fn test_lint_severity_overrides_drive_exit_code() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.create_task_file(
+        "backend",
+        "backend-001",
+        "Add login",
+        TaskStatus::Todo,
+        vec![],
+    )?;
+    let (_, _, code) = project.run_command(&["lint", "--max-errors", "0"])?;
+    assert_eq!(code, 0);
+
+    let config_path = project.project_path.join(".taskguard/config.toml");
+    let mut config = fs::read_to_string(&config_path)?;
+    config.push_str("\n[lint.severity]\nbrief-description = \"error\"\n");
+    fs::write(&config_path, config)?;
+    let (stdout, stderr, code) = project.run_command(&["lint", "--max-errors", "0"])?;
+    assert_ne!(code, 0);
+    assert!(stdout.contains("[ERROR] Task description is very brief"));
+    assert!(stderr.contains("error"));
+    Ok(())
+}
+
+#[test]
+fn test_lint_and_status_rank_risky_tasks() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.create_task_file(
+        "backend",
+        "backend-001",
+        "Build API",
+        TaskStatus::Todo,
+        vec![],
+    )?;
+    for id in ["backend-002", "backend-003", "backend-004"] {
+        project.create_task_file(
+            "backend",
+            id,
+            "Use API",
+            TaskStatus::Todo,
+            vec!["backend-001".to_string()],
+        )?;
+    }
+    let path = project.project_path.join("tasks/backend/backend-001.md");
+    let content = fs::read_to_string(&path)?.replace("complexity: 5", "complexity: 10");
+    fs::write(&path, content)?;
+
+    let (stdout, _, code) = project.run_command(&["lint", "--risk", "--area", "backend"])?;
+    assert_eq!(code, 0);
+    let section = stdout.split("🎲 RISKIEST TASKS").nth(1).unwrap();
+    let first = section.lines().nth(1).unwrap();
+    assert!(first.contains("backend-001 - complexity 10, 3 task(s) waiting on it"));
+    assert!(!section.contains("setup-001"));
+
+    let (stdout, _, _) = project.run_command(&["lint", "--risk", "--format", "json"])?;
+    let report: serde_json::Value = serde_json::from_str(&stdout)?;
+    assert_eq!(report["risk"][0]["task_id"], "backend-001");
+    assert_eq!(report["risk"][0]["dependents"], 3);
+    let (stdout, _, _) = project.run_command(&["lint", "--format", "json"])?;
+    assert!(!stdout.contains("\"risk\""));
+
+    let (stdout, _, _) = project.run_command(&["status", "--risk"])?;
+    assert!(stdout.contains("🎲 RISKIEST TASKS"));
+    assert!(stdout.contains("backend-001 - Build API"));
+    assert!(stdout.contains("└── 3 task(s) waiting on it"));
+    Ok(())
+}
+
+#[test]
+fn test_lint_verbose_mode() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    project.create_task_file(
+        "backend",
+        "backend-001",
+        "Test Task",
+        TaskStatus::Todo,
+        vec![],
+    )?;
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["lint", "--verbose"])?;
+
+    assert_eq!(exit_code, 0, "Lint verbose should succeed");
+    assert!(stdout.contains("backend-001"), "Should analyze the task");
+    // Verbose should show more details
+    assert!(stdout.len() > 50, "Verbose output should be detailed");
+
+    Ok(())
+}
+
+// =============================================================================
+// AI COMMAND TESTS
+// =============================================================================
+
+#[test]
+fn test_ai_basic_interaction() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    let (stdout, _stderr, exit_code) =
+        project.run_command(&["ai", "What should I work on next?"])?;
+
+    assert_eq!(exit_code, 0, "AI command should succeed");
+    assert!(
+        stdout.contains("tasks") || stdout.contains("No tasks") || stdout.contains("available"),
+        "Should provide meaningful response"
+    );
+    assert!(!stdout.is_empty(), "Should provide some output");
+
+    Ok(())
+}
+
+#[test]
+fn test_ai_task_creation_guidance() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    let (stdout, _stderr, exit_code) =
+        project.run_command(&["ai", "Create a task for user authentication"])?;
+
+    assert_eq!(exit_code, 0, "AI task creation should succeed");
+    assert!(
+        stdout.contains("create") || stdout.contains("taskguard"),
+        "Should provide creation guidance"
+    );
+    assert!(
+        stdout.contains("authentication") || stdout.contains("auth"),
+        "Should reference the topic"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_ai_status_inquiry() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    project.create_task_file(
+        "backend",
+        "backend-001",
+        "Test Task",
+        TaskStatus::Todo,
+        vec![],
+    )?;
+
+    let (stdout, _stderr, exit_code) =
+        project.run_command(&["ai", "What's the project status?"])?;
+
+    assert_eq!(exit_code, 0, "AI status inquiry should succeed");
+    assert!(
+        stdout.contains("status") || stdout.contains("tasks") || stdout.contains("1"),
+        "Should provide status information"
+    );
+    assert!(
+        stdout.contains("backend-001"),
+        "Should mention existing task"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_ai_empty_input() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["ai", ""])?;
+
+    assert_eq!(exit_code, 0, "AI should handle empty input gracefully");
+    assert!(
+        !stdout.is_empty(),
+        "Should provide some guidance for empty input"
+    );
+    assert!(
+        stdout.contains("help") || stdout.contains("guidance") || stdout.contains("assist"),
+        "Should offer helpful guidance"
+    );
+
+    Ok(())
+}
+
+// =============================================================================
+// ERROR HANDLING AND EDGE CASES
+// =============================================================================
+
+#[test]
+fn test_invalid_command() -> Result<()> {
+    let project = CLITestProject::new()?;
+
+    let (stdout, stderr, exit_code) = project.run_command(&["nonexistent-command"])?;
+
+    assert_ne!(exit_code, 0, "Invalid command should fail");
+    assert!(
+        stdout.contains("help")
+            || stderr.contains("help")
+            || stdout.contains("usage")
+            || stderr.contains("usage")
+            || stdout.contains("subcommand")
+            || stderr.contains("subcommand"),
+        "Should provide helpful error message"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_missing_required_args() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    let (stdout, stderr, exit_code) = project.run_command(&["create"])?;
+
+    assert_ne!(exit_code, 0, "Create without required args should fail");
+    assert!(
+        stdout.contains("required")
+            || stderr.contains("required")
+            || stdout.contains("title")
+            || stderr.contains("title"),
+        "Should indicate missing required arguments"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_invalid_area_filter() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["list", "--area", "nonexistent"])?;
+
+    assert_eq!(
+        exit_code, 0,
+        "List with invalid area should succeed but show no results"
+    );
+    assert!(
+        stdout.contains("0") || stdout.contains("No tasks") || stdout.contains("empty"),
+        "Should show no tasks for invalid area"
+    );
+
+    Ok(())
+}
+
+// =============================================================================
+// PERFORMANCE AND STRESS TESTS
+// =============================================================================
+
+#[test]
+fn test_large_project_performance() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    // Create many tasks to test performance
+    for i in 1..=50 {
+        project.create_task_file(
+            "backend",
+            &format!("backend-{:03}", i),
+            &format!("Task {}", i),
+            TaskStatus::Todo,
+            vec![],
+        )?;
+    }
+
+    let start = std::time::Instant::now();
+    let (stdout, _stderr, exit_code) = project.run_command(&["list"])?;
+    let list_duration = start.elapsed();
+
+    assert_eq!(exit_code, 0, "List should succeed with many tasks");
+    assert!(
+        stdout.contains("50") || stdout.contains("backend-050"),
+        "Should show all tasks"
+    );
+    assert!(
+        list_duration < std::time::Duration::from_secs(5),
+        "List should complete within 5 seconds"
+    );
+
+    let start = std::time::Instant::now();
+    let (_stdout, _stderr, exit_code) = project.run_command(&["validate"])?;
+    let validate_duration = start.elapsed();
+
+    assert_eq!(exit_code, 0, "Validate should succeed with many tasks");
+    assert!(
+        validate_duration < std::time::Duration::from_secs(10),
+        "Validate should complete within 10 seconds"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_command_timeout_handling() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    // Test that commands complete within reasonable time
+    let commands = vec![vec!["list"], vec!["validate"], vec!["ai", "help"]];
+
+    for cmd in commands {
+        let start = std::time::Instant::now();
+        let (_stdout, _stderr, _exit_code) = project.run_command(&cmd)?;
+        let duration = start.elapsed();
+
+        assert!(
+            duration < std::time::Duration::from_secs(30),
+            "Command {:?} should complete within 30 seconds",
+            cmd
+        );
+    }
+
+    Ok(())
+}
+
+// =============================================================================
+// DUE DATE TESTS
+// =============================================================================
+
+#[test]
+fn test_due_date_create_and_overdue_filter() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    let (_stdout, stderr, exit_code) = project.run_command(&[
+        "create",
+        "--title",
+        "Past due task",
+        "--area",
+        "backend",
+        "--due",
+        "2000-01-01",
+        "--allow-orphan-task",
+    ])?;
+    assert_eq!(exit_code, 0, "Create with --due should succeed: {}", stderr);
+
+    let content = fs::read_to_string(project.project_path.join("tasks/backend/backend-001.md"))?;
+    assert!(content.contains("due: 2000-01-01"));
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["list", "--overdue"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("backend-001"));
+    assert!(stdout.contains("OVERDUE"));
+    assert!(!stdout.contains("setup-001"));
+
+    let (stdout, _stderr, _) = project.run_command(&["list", "--due-before", "1999-01-01"])?;
+    assert!(stdout.contains("No tasks match"));
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["status"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("OVERDUE (1)"));
+
+    let (_stdout, _stderr, exit_code) =
+        project.run_command(&["create", "--title", "Bad", "--due", "tomorrow"])?;
+    assert_ne!(exit_code, 0, "Invalid due date should be rejected");
+
+    Ok(())
+}
+
+#[test]
+fn test_validate_warns_on_due_before_dependency() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.create_task_file("backend", "backend-001", "Base", TaskStatus::Todo, vec![])?;
+    project.create_task_file(
+        "backend",
+        "backend-002",
+        "Follow-up",
+        TaskStatus::Todo,
+        vec!["backend-001".to_string()],
+    )?;
+
+    project.run_command(&["update", "due", "backend-001", "2030-06-01"])?;
+    let (stdout, _stderr, exit_code) =
+        project.run_command(&["update", "due", "backend-002", "2030-05-01"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("due = 2030-05-01"));
+
+    let (stdout, _stderr, _) = project.run_command(&["validate"])?;
+    assert!(stdout.contains("DUE DATE WARNINGS"));
+    assert!(stdout.contains("backend-002 is due 2030-05-01 but depends on backend-001"));
+
+    Ok(())
+}
+
+#[test]
+fn test_deadline_risk_markers_and_status_section() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    let soon = (Utc::now() + chrono::Duration::days(2))
+        .format("%Y-%m-%d")
+        .to_string();
+    project.run_command(&[
+        "create",
+        "--title",
+        "Big job",
+        "--area",
+        "backend",
+        "--due",
+        &soon,
+        "--estimate",
+        "3w",
+        "--dependencies",
+        "setup-001",
+    ])?;
+    project.run_command(&[
+        "create",
+        "--title",
+        "Follow-up",
+        "--area",
+        "backend",
+        "--due",
+        "2099-01-01",
+        "--dependencies",
+        "backend-001",
+    ])?;
+    project.run_command(&[
+        "create",
+        "--title",
+        "Relaxed",
+        "--area",
+        "backend",
+        "--due",
+        "2099-01-01",
+        "--estimate",
+        "2h",
+        "--allow-orphan-task",
+    ])?;
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["list", "--area", "backend"])?;
+    assert_eq!(exit_code, 0);
+    let line = |id: &str| {
+        stdout
+            .lines()
+            .find(|l| l.contains(id))
+            .unwrap_or_default()
+            .to_string()
+    };
+    assert!(line("backend-001").contains("at risk"));
+    assert!(line("backend-002").contains("at risk"));
+    assert!(!line("backend-003").contains("at risk"));
+
+    let (stdout, _stderr, _) = project.run_command(&["status"])?;
+    assert!(!stdout.contains("AT RISK"));
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["status", "--risk"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("AT RISK (2)"));
+    assert!(stdout.contains("needs 120h of work"), "{}", stdout);
+    assert!(stdout.contains("waiting on backend-001"));
+
+    Ok(())
+}
+
+#[test]
+fn test_aging_policy_flags_and_escalates_old_todo_tasks() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    fs::create_dir_all(project.project_path.join("tasks/backend"))?;
+    fs::write(
+        project.project_path.join("tasks/backend/backend-001.md"),
+        "---\nid: backend-001\ntitle: Forgotten refactor\nstatus: todo\npriority: low\ndependencies: [setup-001]\ncreated: 2020-01-01T00:00:00Z\narea: backend\n---\n",
+    )?;
+
+    // Without a policy nothing is flagged
+    let (stdout, _stderr, _) = project.run_command(&["list"])?;
+    assert!(!stdout.contains("aging"));
+
+    let config_path = project.project_path.join(".taskguard/config.toml");
+    let mut config = fs::read_to_string(&config_path)?;
+    config.push_str("\n[aging]\ndays = 30\nescalate = true\n");
+    fs::write(&config_path, config)?;
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["list"])?;
+    assert_eq!(exit_code, 0);
+    let line = stdout
+        .lines()
+        .find(|l| l.contains("backend-001"))
+        .expect("task listed");
+    assert!(line.contains("🟠"), "escalated to high: {}", line);
+    assert!(line.contains("⏳ aging") && line.contains("(low → high)"));
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["status"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("in todo for 30+ days)"));
+    assert!(stdout.contains("backend-001 - Forgotten refactor ⏳ aging"));
+
+    // Escalation is display-only; the file keeps its priority
+    let content = fs::read_to_string(project.project_path.join("tasks/backend/backend-001.md"))?;
+    assert!(content.contains("priority: low"));
+
+    Ok(())
+}
+
+#[test]
+fn test_validate_schema_errors_and_strict_mode() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    fs::create_dir_all(project.project_path.join("tasks/backend"))?;
+    let task_path = project.project_path.join("tasks/backend/backend-001.md");
+    fs::write(
+        &task_path,
+        "---\nid: backend-001\ntitle: Bad types\npriority: urgent\ncomplexity: \"3\"\ndependencies: [setup-001]\narea: backend\n---\n",
+    )?;
+
+    let (stdout, _stderr, _) = project.run_command(&["validate"])?;
+    assert!(stdout.contains("backend-001.md:4: field 'priority' should be one of low"));
+    assert!(stdout.contains("backend-001.md:5: field 'complexity'"));
+    assert!(stdout.contains("'complexity: 3'"));
+
+    // Numbers outside 1-10 don't pass either
+    for complexity in ["0", "11"] {
+        fs::write(
+            &task_path,
+            format!(
+                "---\nid: backend-001\ntitle: Out of range\ncomplexity: {}\ndependencies: [setup-001]\narea: backend\n---\n",
+                complexity
+            ),
+        )?;
+        let (stdout, _stderr, _) = project.run_command(&["validate"])?;
+        assert!(
+            stdout.contains("field 'complexity' should be a whole number from 1 to 10"),
+            "{}",
+            stdout
+        );
+    }
+
+    // Unknown fields are warnings by default and errors with --strict
+    fs::write(
+        &task_path,
+        "---\nid: backend-001\ntitle: Typo\npriorty: high\ndependencies: [setup-001]\narea: backend\n---\n",
+    )?;
+    let (stdout, _stderr, exit_code) = project.run_command(&["validate"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("VALIDATION PASSED"));
+    assert!(stdout.contains("unknown field 'priorty'"));
+
+    let (stdout, stderr, exit_code) = project.run_command(&["validate", "--strict"])?;
+    assert_ne!(exit_code, 0);
+    assert!(stdout.contains("VALIDATION FAILED"));
+    assert!(stderr.contains("Strict validation failed"));
+
+    Ok(())
+}
+
+#[test]
+fn test_convert_frontmatter_to_toml_and_back() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.run_command(&[
+        "create",
+        "--title",
+        "Convert me",
+        "--area",
+        "backend",
+        "--dependencies",
+        "setup-001",
+    ])?;
+    let task_path = project.project_path.join("tasks/backend/backend-001.md");
+
+    let (_stdout, _stderr, exit_code) = project.run_command(&["convert", "toml", "--dry-run"])?;
+    assert_eq!(exit_code, 0);
+    assert!(fs::read_to_string(&task_path)?.starts_with("---"));
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["convert", "toml"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("2 converted"));
+    assert!(fs::read_to_string(&task_path)?.starts_with("+++\nid = \"backend-001\""));
+    let config = fs::read_to_string(project.project_path.join(".taskguard/config.toml"))?;
+    assert!(config.contains("frontmatter = \"toml\""));
+
+    // TOML tasks load everywhere, and updates keep the format
+    project.run_command(&["update", "status", "backend-001", "doing"])?;
+    let content = fs::read_to_string(&task_path)?;
+    assert!(content.starts_with("+++"));
+    assert!(content.contains("status = \"doing\""));
+    let (stdout, _stderr, _) = project.run_command(&["list", "--area", "backend"])?;
+    assert!(stdout.contains("Convert me"));
+
+    // New tasks follow the project setting
+    project.run_command(&[
+        "create",
+        "--title",
+        "Born in TOML",
+        "--area",
+        "backend",
+        "--dependencies",
+        "backend-001",
+    ])?;
+    let created = fs::read_to_string(project.project_path.join("tasks/backend/backend-002.md"))?;
+    assert!(created.starts_with("+++"));
+
+    let (stdout, _stderr, _) = project.run_command(&["convert", "yaml"])?;
+    assert!(stdout.contains("3 converted"));
+    assert!(fs::read_to_string(&task_path)?.starts_with("---\nid: backend-001"));
+
+    Ok(())
+}
+
+#[test]
+fn test_convert_toml_drops_null_custom_fields() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    fs::create_dir_all(project.project_path.join("tasks/backend"))?;
+    let task_path = project.project_path.join("tasks/backend/backend-001.md");
+    fs::write(
+        &task_path,
+        "---\nid: backend-001\ntitle: Custom fields\nstatus: todo\npriority: medium\ntags: []\ndependencies: []\narea: backend\ncreated: 2025-01-01T00:00:00Z\ncustomer: null\nowner: acme\n---\n\nBody\n",
+    )?;
+    let nested_path = project.project_path.join("tasks/backend/backend-002.md");
+    fs::write(
+        &nested_path,
+        "---\nid: backend-002\ntitle: Nested null\nstatus: todo\npriority: medium\ntags: []\ndependencies: []\narea: backend\ncreated: 2025-01-01T00:00:00Z\nmeta:\n  reviewer: null\n---\n\nBody\n",
+    )?;
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["convert", "toml"])?;
+    assert_eq!(exit_code, 0);
+    let content = fs::read_to_string(&task_path)?;
+    assert!(content.starts_with("+++"));
+    assert!(content.contains("owner = \"acme\""));
+    assert!(!content.contains("customer"));
+
+    // A task TOML can't hold is reported, and the rest still convert
+    assert!(stdout.contains("backend-002.md"));
+    assert!(stdout.contains("2 converted, 0 already toml, 1 failed"));
+    assert!(fs::read_to_string(&nested_path)?.starts_with("---"));
+
+    Ok(())
+}
+
+#[test]
+fn test_migrate_upgrades_old_task_files() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    fs::create_dir_all(project.project_path.join("tasks/backend"))?;
+    let task_path = project.project_path.join("tasks/backend/backend-001.md");
+    fs::write(
+        &task_path,
+        "---\nid: backend-001\ntitle: Old task\nstatus: completed\ndependencies: [setup-001]\narea: backend\n---\n\nBody\n",
+    )?;
+
+    let (stdout, _stderr, _) = project.run_command(&["validate"])?;
+    assert!(stdout.contains("run 'taskguard migrate' to rename it to 'done'"));
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["migrate", "--dry-run"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("backend-001: status completed → done"));
+    assert!(fs::read_to_string(&task_path)?.contains("status: completed"));
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["migrate"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("1 migrated"));
+    let content = fs::read_to_string(&task_path)?;
+    assert!(content.contains("status: done"));
+    assert!(content.contains("format_version: 1"));
+
+    let (stdout, _stderr, _) = project.run_command(&["validate"])?;
+    assert!(!stdout.contains("FORMAT VERSION"));
+    assert!(stdout.contains("VALIDATION PASSED"));
+
+    fs::write(
+        project.project_path.join("tasks/backend/backend-002.md"),
+        "---\nid: backend-002\ntitle: Future task\nformat_version: 99\narea: backend\n---\n\nBody\n",
+    )?;
+    let (stdout, stderr, exit_code) = project.run_command(&["migrate"])?;
+    assert_ne!(exit_code, 0);
+    assert!(stdout.contains("1 failed"));
+    assert!(stderr.contains("Migration failed for 1 task file(s)"));
+
+    Ok(())
+}
+
+// =============================================================================
+// CUSTOM FIELD TESTS
+// =============================================================================
+
+#[test]
+fn test_custom_fields_preserved_listed_and_validated() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    let config_path = project.project_path.join(".taskguard/config.toml");
+    let mut config = fs::read_to_string(&config_path)?;
+    config.push_str("\n[fields]\ncustomer = \"string\"\nstory_points = \"int\"\n");
+    fs::write(&config_path, config)?;
+
+    fs::create_dir_all(project.project_path.join("tasks/backend"))?;
+    fs::write(
+        project.project_path.join("tasks/backend/backend-001.md"),
+        "---\nid: backend-001\ntitle: Custom task\nstatus: todo\npriority: medium\ntags: []\ndependencies: [setup-001]\nassignee: null\ncreated: 2025-01-01T00:00:00Z\nestimate: ~\ncomplexity: 3\narea: backend\ncustomer: Acme\nstory_points: five\nsprint: 12\n---\n\n# Custom task\n",
+    )?;
+
+    let (stdout, _stderr, exit_code) =
+        project.run_command(&["list", "--columns", "customer,story_points"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("customer: Acme │ story_points: five"));
+
+    let (stdout, _stderr, _) = project.run_command(&["validate"])?;
+    assert!(stdout.contains("CUSTOM FIELDS"));
+    assert!(stdout.contains("field 'story_points' should be int"));
+    assert!(stdout.contains("unknown field 'sprint'"));
+    assert!(stdout.contains("VALIDATION FAILED"));
+
+    // Custom fields survive a load/save round trip
+    project.run_command(&["update", "priority", "backend-001", "high"])?;
+    let content = fs::read_to_string(project.project_path.join("tasks/backend/backend-001.md"))?;
+    assert!(content.contains("customer: Acme"));
+    assert!(content.contains("story_points: five"));
+    assert!(content.contains("sprint: 12"));
+
+    Ok(())
+}
+
+// =============================================================================
+// ASSIGNEE TESTS
+// =============================================================================
+
+#[test]
+fn test_multiple_assignees_create_update_and_filter() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    let (_stdout, stderr, exit_code) = project.run_command(&[
+        "create",
+        "--title",
+        "Pair task",
+        "--area",
+        "backend",
+        "--assignee",
+        "alice, bob",
+        "--allow-orphan-task",
+    ])?;
+    assert_eq!(
+        exit_code, 0,
+        "Create with two assignees should succeed: {}",
+        stderr
+    );
+
+    let task_path = project.project_path.join("tasks/backend/backend-001.md");
+    let content = fs::read_to_string(&task_path)?;
+    assert!(content.contains("assignee:\n- alice\n- bob"));
+
+    let (stdout, _stderr, _) = project.run_command(&["list", "--assignee", "bob"])?;
+    assert!(stdout.contains("backend-001"));
+    assert!(!stdout.contains("setup-001"));
+
+    // A single assignee is written back as a plain string
+    let (stdout, _stderr, exit_code) =
+        project.run_command(&["update", "assignee", "backend-001", "carol"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("assignee = carol"));
+    let content = fs::read_to_string(&task_path)?;
+    assert!(content.contains("assignee: carol\n"));
+
+    let (stdout, _stderr, _) = project.run_command(&["list", "--assignee", "bob"])?;
+    assert!(stdout.contains("No tasks match"));
+
+    Ok(())
+}
+
+#[test]
+fn test_claim_assigns_and_validate_warns_on_foreign_changes() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.init_git_repo()?;
+    project.run_command(&["init"])?;
+    project.run_command(&[
+        "create",
+        "--title",
+        "Shared",
+        "--area",
+        "backend",
+        "--allow-orphan-task",
+    ])?;
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(&project.project_path)
+            .output()
+    };
+
+    let (stdout, stderr, exit_code) = project.run_command(&["claim", "backend-001"])?;
+    assert_eq!(exit_code, 0, "claim should succeed: {}", stderr);
+    assert!(stdout.contains("Claimed backend-001 for Test User"));
+    let content = fs::read_to_string(project.project_path.join("tasks/backend/backend-001.md"))?;
+    assert!(content.contains("assignee: Test User"));
+    assert!(content.contains("claimed:"));
+    git(&["add", "-A"])?;
+    git(&["commit", "-m", "Claim backend-001"])?;
+
+    // Another teammate edits the claimed task
+    git(&["config", "user.name", "Bob"])?;
+    let (_stdout, stderr, exit_code) = project.run_command(&["claim", "backend-001"])?;
+    assert_ne!(exit_code, 0);
+    assert!(stderr.contains("claimed by Test User"));
+
+    project.run_command(&["update", "priority", "backend-001", "high"])?;
+    let (stdout, _stderr, _) = project.run_command(&["validate"])?;
+    assert!(stdout.contains("CLAIM WARNINGS"));
     assert!(
-        stdout.contains("tasks") || stdout.contains("No tasks") || stdout.contains("available"),
-        "Should provide meaningful response"
+        stdout.contains("backend-001 is claimed by Test User but has uncommitted changes by Bob")
     );
-    assert!(!stdout.is_empty(), "Should provide some output");
+
+    Command::new("git")
+        .args(["commit", "-am", "Bump priority"])
+        .env("GIT_COMMITTER_DATE", "2099-01-01T00:00:00Z")
+        .current_dir(&project.project_path)
+        .output()?;
+    let (stdout, _stderr, _) = project.run_command(&["validate"])?;
+    assert!(stdout.contains("backend-001 is claimed by Test User but was changed by Bob"));
+    assert!(stdout.contains("(Bump priority)"));
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["claim", "backend-001", "--force"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("Taking over backend-001 from Test User"));
+
+    let (stdout, _stderr, exit_code) =
+        project.run_command(&["claim", "backend-001", "--release"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("Released claim on backend-001"));
+    let content = fs::read_to_string(project.project_path.join("tasks/backend/backend-001.md"))?;
+    assert!(!content.contains("claimed:"));
+    assert!(content.contains("assignee: null"));
+
+    Ok(())
+}
+
+// =============================================================================
+// RELATION TESTS
+// =============================================================================
+
+#[test]
+fn test_relations_shown_and_validated() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    fs::create_dir_all(project.project_path.join("tasks/backend"))?;
+    fs::write(
+        project.project_path.join("tasks/backend/backend-001.md"),
+        "---\nid: backend-001\ntitle: Auth API\nstatus: todo\npriority: medium\ntags: []\ndependencies: [setup-001]\nrelations:\n  blocks: [backend-002]\n  relates-to: [setup-001]\nassignee: null\ncreated: 2025-01-01T00:00:00Z\nestimate: ~\ncomplexity: 3\narea: backend\n---\n\n# Auth API\n",
+    )?;
+    project.create_task_file(
+        "backend",
+        "backend-002",
+        "Login page",
+        TaskStatus::Todo,
+        vec!["backend-001".to_string()],
+    )?;
+
+    let (stdout, stderr, exit_code) = project.run_command(&["show", "backend-002"])?;
+    assert_eq!(exit_code, 0, "show should succeed: {}", stderr);
+    assert!(stdout.contains("backend-002 - Login page"));
+    assert!(stdout.contains("blocked-by ← backend-001 (Auth API)"));
+
+    let (stdout, _stderr, _) = project.run_command(&["show", "backend-001"])?;
+    assert!(stdout.contains("blocks → backend-002 (Login page)"));
+    assert!(stdout.contains("relates-to → setup-001"));
+
+    let (stdout, _stderr, _) = project.run_command(&["validate"])?;
+    assert!(!stdout.contains("RELATION ISSUES"));
+
+    // Dangling relation targets fail validation
+    project.run_command(&["update", "priority", "backend-001", "high"])?;
+    let path = project.project_path.join("tasks/backend/backend-001.md");
+    let content = fs::read_to_string(&path)?;
+    assert!(content.contains("relates-to:\n  - setup-001"));
+    fs::write(&path, content.replace("backend-002", "backend-404"))?;
+
+    let (stdout, _stderr, _) = project.run_command(&["validate"])?;
+    assert!(stdout.contains("RELATION ISSUES"));
+    assert!(stdout.contains("'blocks' relation references missing task 'backend-404'"));
+    assert!(stdout.contains("VALIDATION FAILED"));
+
+    let (_stdout, _stderr, exit_code) = project.run_command(&["show", "backend-999"])?;
+    assert_ne!(exit_code, 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_parent_tree_progress_and_done_parent_check() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    let write_task = |id: &str, status: &str, parent: Option<&str>| -> Result<()> {
+        let relations = parent
+            .map(|p| format!("relations:\n  parent: {}\n", p))
+            .unwrap_or_default();
+        fs::write(
+            project
+                .project_path
+                .join(format!("tasks/backend/{}.md", id)),
+            format!(
+                "---\nid: {id}\ntitle: Task {id}\nstatus: {status}\npriority: medium\ntags: []\ndependencies: [setup-001]\n{relations}assignee: null\ncreated: 2025-01-01T00:00:00Z\nestimate: ~\ncomplexity: 3\narea: backend\n---\n\n# Task {id}\n"
+            ),
+        )?;
+        Ok(())
+    };
+
+    fs::create_dir_all(project.project_path.join("tasks/backend"))?;
+    write_task("backend-001", "done", None)?;
+    write_task("backend-002", "done", Some("backend-001"))?;
+    write_task("backend-003", "todo", Some("backend-001"))?;
+
+    let (stdout, stderr, exit_code) = project.run_command(&["list", "--tree"])?;
+    assert_eq!(exit_code, 0, "list --tree should succeed: {}", stderr);
+    assert!(stdout.contains("✅ backend-001 Task backend-001 [1/2 subtasks done]"));
+    assert!(stdout.contains("       ⭕ backend-003 Task backend-003"));
+    // Filters the tree can't apply are rejected rather than ignored
+    let (_stdout, stderr, exit_code) =
+        project.run_command(&["list", "--tree", "--assignee", "alice"])?;
+    assert_ne!(exit_code, 0);
+    assert!(stderr.contains("cannot be used with"));
+
+    let (stdout, _stderr, _) = project.run_command(&["show", "backend-001"])?;
+    assert!(stdout.contains("Subtasks: 1/2 done"));
+
+    let (stdout, _stderr, _) = project.run_command(&["validate"])?;
+    assert!(stdout.contains("HIERARCHY ISSUES"));
+    assert!(stdout.contains("backend-001: Marked done but subtask backend-003 is still todo"));
+    assert!(stdout.contains("VALIDATION FAILED"));
+
+    project.run_command(&["update", "status", "backend-003", "done"])?;
+    let (stdout, _stderr, _) = project.run_command(&["validate"])?;
+    assert!(!stdout.contains("HIERARCHY ISSUES"));
+
+    Ok(())
+}
+
+#[test]
+fn test_links_shown_and_checked() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    fs::create_dir_all(project.project_path.join("docs"))?;
+    fs::write(project.project_path.join("docs/design.md"), "# Design")?;
+    fs::create_dir_all(project.project_path.join("tasks/backend"))?;
+    fs::write(
+        project.project_path.join("tasks/backend/backend-001.md"),
+        "---\nid: backend-001\ntitle: Linked task\nstatus: todo\npriority: medium\ntags: []\ndependencies: [setup-001]\nassignee: null\ncreated: 2025-01-01T00:00:00Z\nestimate: ~\ncomplexity: 3\nlinks:\n- docs/design.md#api\n- https://github.com/org/repo/pull/42\n- docs/missing.md\n- htps//broken\n- https://bad host/x\narea: backend\n---\n\nBody",
+    )?;
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["show", "backend-001"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("🔗 LINKS"));
+    assert!(stdout.contains("https://github.com/org/repo/pull/42"));
+
+    // Links are only checked on request
+    let (stdout, _stderr, _) = project.run_command(&["validate"])?;
+    assert!(!stdout.contains("LINK ISSUES"));
+
+    let (stdout, _stderr, _) = project.run_command(&["validate", "--check-links"])?;
+    assert!(stdout.contains("LINK ISSUES"));
+    assert!(stdout.contains("backend-001: linked file 'docs/missing.md' not found"));
+    assert!(stdout.contains("linked file 'htps//broken' not found"));
+    assert!(stdout.contains("malformed URL 'https://bad host/x'"));
+    assert!(!stdout.contains("design.md"));
+    assert!(!stdout.contains("pull/42"));
+    assert!(stdout.contains("Link issues: 3"));
+
+    let (stdout, _stderr, _) = project.run_command(&["lint", "--check-links"])?;
+    assert!(stdout.contains("Broken link: linked file 'docs/missing.md' not found"));
+
+    Ok(())
+}
+
+// =============================================================================
+// EPIC TESTS
+// =============================================================================
+
+#[test]
+fn test_epic_list_and_show_across_areas() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    for (title, area) in [("Auth API", "backend"), ("Login form", "frontend")] {
+        let (_stdout, stderr, exit_code) = project.run_command(&[
+            "create",
+            "--title",
+            title,
+            "--area",
+            area,
+            "--epic",
+            "auth-system",
+            "--allow-orphan-task",
+        ])?;
+        assert_eq!(exit_code, 0, "create --epic should succeed: {}", stderr);
+    }
+    project.run_command(&["update", "status", "backend-001", "doing"])?;
+    project.run_command(&["update", "status", "backend-001", "done"])?;
+
+    let content = fs::read_to_string(project.project_path.join("tasks/backend/backend-001.md"))?;
+    assert!(content.contains("epic: auth-system"));
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["epic", "list"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("auth-system - 1/2 done (50%) │ areas: backend, frontend"));
+    assert!(stdout.contains("1 tasks are not part of an epic"));
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["epic", "show", "auth-system"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("📁 backend (1/1 done)"));
+    assert!(stdout.contains("📁 frontend (0/1 done)"));
+    assert!(stdout.contains("frontend-001 - Login form [todo]"));
+
+    let (_stdout, _stderr, exit_code) = project.run_command(&["epic", "show", "billing"])?;
+    assert_ne!(exit_code, 0);
+
+    Ok(())
+}
+
+// =============================================================================
+// TAG MANAGEMENT TESTS
+// =============================================================================
+
+#[test]
+fn test_tags_list_rename_and_merge() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    for (title, tags) in [
+        ("One", "ui,frontend-ui"),
+        ("Two", "UI"),
+        ("Three", "ui,perf"),
+    ] {
+        project.run_command(&[
+            "create",
+            "--title",
+            title,
+            "--area",
+            "frontend",
+            "--tags",
+            tags,
+            "--allow-orphan-task",
+        ])?;
+    }
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["tags", "list"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("frontend"));
+    let ui_line = stdout
+        .lines()
+        .find(|l| l.trim_start().starts_with("ui "))
+        .unwrap();
+    assert!(ui_line.contains("2 tasks (2 open)"));
+
+    // Renaming onto an existing tag is refused in favour of merge
+    let (_stdout, stderr, exit_code) = project.run_command(&["tags", "rename", "perf", "ui"])?;
+    assert_ne!(exit_code, 0);
+    assert!(stderr.contains("tags merge perf --into ui"));
+
+    let (stdout, _stderr, exit_code) =
+        project.run_command(&["tags", "rename", "perf", "performance"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("Retagged 1 task(s)"));
+    let three = fs::read_to_string(project.project_path.join("tasks/frontend/frontend-003.md"))?;
+    assert!(three.contains("- performance"));
+
+    let (stdout, _stderr, _) = project.run_command(&[
+        "tags",
+        "merge",
+        "UI",
+        "frontend-ui",
+        "--into",
+        "ui",
+        "--dry-run",
+    ])?;
+    assert!(stdout.contains("Would retag 2 task(s)"));
+    let (stdout, _stderr, exit_code) =
+        project.run_command(&["tags", "merge", "UI", "frontend-ui", "--into", "ui"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("Retagged 2 task(s): UI, frontend-ui → ui"));
+
+    // Merging into a tag the task already has doesn't duplicate it
+    let one = fs::read_to_string(project.project_path.join("tasks/frontend/frontend-001.md"))?;
+    assert_eq!(one.matches("- ui").count(), 1);
+    let (stdout, _stderr, _) = project.run_command(&["tags", "list"])?;
+    assert!(!stdout.contains("frontend-ui"));
+    assert!(stdout.contains("3 tasks (3 open)"));
+
+    Ok(())
+}
+
+// =============================================================================
+// CRITICAL PATH TESTS
+// =============================================================================
+
+#[test]
+fn test_critical_path_weighted_by_estimates() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    // setup-001 (2h) → backend-001 (1d) → backend-002 (2h) → api-002 (4h)
+    // setup-001 (2h) → api-001 (3d) → api-002
+    for (title, area, estimate, deps) in [
+        ("Schema", "backend", "1d", "setup-001"),
+        ("Endpoints", "backend", "2h", "backend-001"),
+        ("Contract", "api", "3d", "setup-001"),
+        ("Client", "api", "4h", "api-001,backend-002"),
+    ] {
+        let (_, stderr, exit_code) = project.run_command(&[
+            "create",
+            "--title",
+            title,
+            "--area",
+            area,
+            "--estimate",
+            estimate,
+            "--dependencies",
+            deps,
+        ])?;
+        assert_eq!(exit_code, 0, "{}", stderr);
+    }
+
+    let (stdout, stderr, exit_code) = project.run_command(&["critical-path"])?;
+    assert_eq!(exit_code, 0, "{}", stderr);
+    assert!(stdout.contains("CRITICAL PATH (3d 6h, 3 tasks)"));
+    assert!(stdout.contains("1. ⭕ setup-001 - "));
+    assert!(stdout.contains("2. ⭕ api-001 - Contract (3d, done after 3d 2h)"));
+    assert!(stdout.contains("3. ⭕ api-002 - Client (4h, done after 3d 6h)"));
+    assert!(!stdout.contains("backend-001"));
+    assert!(stdout.contains("Start with setup-001"));
+
+    // Within an area, dependencies elsewhere don't extend the chain
+    let (stdout, _, _) = project.run_command(&["critical-path", "--area", "backend"])?;
+    assert!(stdout.contains("CRITICAL PATH - backend (1d 2h, 2 tasks)"));
+    assert!(stdout.contains("backend-001 - Schema"));
+    assert!(stdout.contains("backend-002 - Endpoints"));
+
+    // Unestimated tasks count as zero and are called out
+    project.run_command(&["update", "estimate", "api-001", ""])?;
+    project.run_command(&["update", "estimate", "api-002", ""])?;
+    let (stdout, _, _) = project.run_command(&["critical-path"])?;
+    assert!(stdout.contains("(1d 4h, 4 tasks)"));
+    assert!(stdout.contains("backend-002 - Endpoints (2h, done after 1d 4h)"));
+    assert!(stdout.contains("api-002 - Client (no estimate)"));
+    assert!(stdout.contains("1 task(s) on the path have no estimate"));
+
+    project.run_command(&["update", "dependencies", "backend-001", "backend-002"])?;
+    let (_, stderr, exit_code) = project.run_command(&["critical-path"])?;
+    assert_ne!(exit_code, 0);
+    assert!(stderr.contains("Dependency cycle through"));
+
+    Ok(())
+}
+
+#[test]
+fn test_plan_orders_open_tasks_into_waves() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    for (title, area, priority, assignee, deps) in [
+        ("Schema", "backend", "high", "alice", "setup-001"),
+        ("Endpoints", "backend", "medium", "bob", "backend-001"),
+        ("Shell", "frontend", "critical", "bob", "setup-001"),
+        (
+            "Page",
+            "frontend",
+            "medium",
+            "alice",
+            "backend-002,frontend-001",
+        ),
+        ("Ghost", "api", "low", "carol", "api-999"),
+    ] {
+        let (_, stderr, exit_code) = project.run_command(&[
+            "create",
+            "--title",
+            title,
+            "--area",
+            area,
+            "--priority",
+            priority,
+            "--assignee",
+            assignee,
+            "--dependencies",
+            deps,
+        ])?;
+        assert_eq!(exit_code, 0, "{}", stderr);
+    }
+
+    let (stdout, stderr, exit_code) = project.run_command(&["plan"])?;
+    assert_eq!(exit_code, 0, "{}", stderr);
+    assert!(stdout.contains("EXECUTION PLAN (5 tasks in 4 waves)"));
+    assert!(stdout.contains("Wave 1 - can be done in parallel now (1 tasks"));
+    assert!(stdout.contains("Wave 2 - unlocked after wave 1 (2 tasks"));
+    assert!(stdout.contains("Wave 4 - unlocked after wave 3 (1 tasks"));
+
+    // Waves appear in dependency order, higher priority first within a wave
+    let position = |id: &str| stdout.find(&format!(" {} - ", id)).unwrap();
+    assert!(position("setup-001") < position("frontend-001"));
+    assert!(position("frontend-001") < position("backend-001"));
+    assert!(position("backend-001") < position("backend-002"));
+    assert!(position("backend-002") < position("frontend-002"));
+
+    // A task waiting on a missing dependency is listed apart
+    assert!(stdout.contains("CAN'T BE SCHEDULED"));
+    assert!(position("frontend-002") < position("api-001"));
+
+    let (stdout, _, _) = project.run_command(&["plan", "--by-assignee"])?;
+    let wave2 = &stdout[stdout.find("Wave 2").unwrap()..stdout.find("Wave 3").unwrap()];
+    assert!(wave2.contains("👤 alice\n      ⭕ backend-001 - Schema [high]"));
+    assert!(wave2.contains("👤 bob\n      ⭕ frontend-001 - Shell [critical]"));
+
+    // Open tasks in other areas don't hold up an area plan
+    let (stdout, _, _) = project.run_command(&["plan", "--area", "frontend"])?;
+    assert!(stdout.contains("EXECUTION PLAN - frontend (2 tasks in 2 waves)"));
+    assert!(stdout.contains("Wave 1 - can be done in parallel now (1 tasks"));
+    assert!(!stdout.contains("backend-002"));
+
+    Ok(())
+}
+
+#[test]
+fn test_workload_per_assignee_feeds_plan_suggestions() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    for (title, area, priority, assignee, estimate) in [
+        ("Schema", "backend", "high", "alice", "1w"),
+        ("Endpoints", "backend", "medium", "alice", "2d"),
+        ("Shared", "backend", "medium", "alice,bob", "1d"),
+        ("Shell", "frontend", "medium", "bob", ""),
+    ] {
+        let mut args = vec![
+            "create",
+            "--title",
+            title,
+            "--area",
+            area,
+            "--priority",
+            priority,
+            "--assignee",
+            assignee,
+            "--dependencies",
+            "setup-001",
+        ];
+        if !estimate.is_empty() {
+            args.extend(["--estimate", estimate]);
+        }
+        let (_, stderr, exit_code) = project.run_command(&args)?;
+        assert_eq!(exit_code, 0, "{}", stderr);
+    }
+    project.run_command(&[
+        "create",
+        "--title",
+        "Hotfix",
+        "--area",
+        "frontend",
+        "--priority",
+        "critical",
+        "--estimate",
+        "3h",
+        "--allow-orphan-task",
+    ])?;
+    project.run_command(&["update", "assignee", "frontend-002", ""])?;
+
+    let (stdout, stderr, exit_code) = project.run_command(&["workload"])?;
+    assert_eq!(exit_code, 0, "{}", stderr);
+    let line = |name: &str| {
+        stdout
+            .lines()
+            .find(|l| l.contains(&format!(" {} ", name)))
+            .unwrap()
+            .to_string()
+    };
+    // The shared 1d task counts 4h for each of its two assignees
+    assert!(line("alice").contains("3 tasks    1w 2d 4h"));
+    assert!(line("bob").contains("2 tasks          4h (1 unestimated)"));
+    assert!(stdout.contains("OVERLOADED (more than 1w of open work)"));
+    assert!(stdout.contains("alice - 1w 2d 4h across 3 tasks"));
+    assert!(stdout.contains("UNASSIGNED HIGH-PRIORITY TASKS"));
+    assert!(stdout.contains("frontend-002 - Hotfix [critical]"));
+
+    let (stdout, _, _) = project.run_command(&["workload", "--max", "2w"])?;
+    assert!(!stdout.contains("OVERLOADED"));
+
+    let (_, stderr, exit_code) = project.run_command(&["workload", "--max", "lots"])?;
+    assert_ne!(exit_code, 0);
+    assert!(stderr.contains("Invalid --max 'lots'"));
+
+    // The unassigned task that can start now goes to the least loaded person
+    let (stdout, _, _) = project.run_command(&["plan"])?;
+    assert!(stdout.contains("SUGGESTED PICKS"));
+    assert!(stdout.contains("frontend-002 → developer (2h open)"));
+
+    Ok(())
+}
+
+#[test]
+fn test_coupling_matrix_and_dot_output() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.create_task_file(
+        "backend",
+        "backend-001",
+        "Build API",
+        TaskStatus::Todo,
+        vec![],
+    )?;
+    project.create_task_file(
+        "backend",
+        "backend-002",
+        "Add auth",
+        TaskStatus::Todo,
+        vec![],
+    )?;
+    for (id, deps) in [
+        ("frontend-001", vec!["backend-001", "backend-002"]),
+        ("frontend-002", vec!["backend-001"]),
+        ("frontend-003", vec!["frontend-001"]),
+    ] {
+        project.create_task_file(
+            "frontend",
+            id,
+            "Use API",
+            TaskStatus::Todo,
+            deps.into_iter().map(String::from).collect(),
+        )?;
+    }
+
+    let (stdout, _, code) = project.run_command(&["coupling", "--dot", "coupling.dot"])?;
+    assert_eq!(code, 0);
+    assert!(stdout.contains("3 cross-area and 1 same-area dependency edges across 3 areas"));
+    let row = stdout
+        .lines()
+        .find(|l| l.trim_start().starts_with("frontend"))
+        .unwrap();
+    assert_eq!(
+        row.split_whitespace().collect::<Vec<_>>(),
+        vec!["frontend", "67%", "-", "·"]
+    );
+    assert!(stdout.contains("frontend → backend: 2 of 3 tasks (67%), 3 edges"));
+
+    let dot = fs::read_to_string(project.project_path.join("coupling.dot"))?;
+    assert!(dot.starts_with("digraph coupling {"));
+    assert!(dot.contains("\"frontend\" -> \"backend\" [label=\"2 (67%)\""));
+    assert!(dot.contains("color=red"));
+
+    let (stdout, _, _) = project.run_command(&["coupling", "--threshold", "80"])?;
+    assert!(stdout.contains("✅ No tightly coupled areas (80%+"));
+    Ok(())
+}
+
+#[test]
+fn test_impact_lists_unblocked_and_downstream_tasks() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    for (title, area, priority, dependencies) in [
+        ("Models", "backend", "low", "setup-001"),
+        ("Auth", "api", "critical", "setup-001"),
+        ("Sessions", "api", "high", "api-001,setup-001"),
+        ("Login page", "frontend", "medium", "backend-001"),
+    ] {
+        let (_, stderr, exit_code) = project.run_command(&[
+            "create",
+            "--title",
+            title,
+            "--area",
+            area,
+            "--priority",
+            priority,
+            "--dependencies",
+            dependencies,
+        ])?;
+        assert_eq!(exit_code, 0, "{}", stderr);
+    }
+
+    let (stdout, stderr, exit_code) = project.run_command(&["impact", "setup-001"])?;
+    assert_eq!(exit_code, 0, "{}", stderr);
+    assert!(stdout.contains("IMPACT OF FINISHING setup-001"));
+    assert!(stdout.contains("BECOMES AVAILABLE (2 tasks, by priority)"));
+    let auth = stdout.find("api-001 - Auth [critical]").unwrap();
+    let models = stdout.find("backend-001 - Models [low]").unwrap();
+    assert!(auth < models, "higher priority first:\n{}", stdout);
+    assert!(stdout.contains("CLOSER TO AVAILABLE (2 tasks, still waiting on others)"));
+    assert!(stdout.contains("api-002 - Sessions [high] (waiting for: api-001)"));
+    assert!(stdout.contains("frontend-001 - Login page [medium] (waiting for: backend-001)"));
+
+    let (stdout, _, _) = project.run_command(&["impact", "frontend-001"])?;
+    assert!(stdout.contains("No open tasks depend on frontend-001"));
+
+    let (_, stderr, exit_code) = project.run_command(&["impact", "nope-001"])?;
+    assert_ne!(exit_code, 0);
+    assert!(stderr.contains("Task 'nope-001' not found"));
+
+    Ok(())
+}
+// =============================================================================
+// METRICS TESTS
+// =============================================================================
+
+#[test]
+fn test_metrics_lead_cycle_and_doing_time_by_area() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.init_git_repo()?;
+    project.run_command(&["init"])?;
+
+    let base = Utc::now() - chrono::Duration::days(6);
+    let at = |hours: i64| (base + chrono::Duration::hours(hours)).to_rfc3339();
+    let write = |area: &str, id: &str, status: &str, created: &str, completed: Option<&str>| {
+        let dir = project.project_path.join("tasks").join(area);
+        fs::create_dir_all(&dir).unwrap();
+        let completed = completed
+            .map(|c| format!("completed: {}\n", c))
+            .unwrap_or_default();
+        fs::write(
+            dir.join(format!("{}.md", id)),
+            format!(
+                "---\nid: {}\ntitle: Task {}\nstatus: {}\npriority: medium\ntags: []\ndependencies: []\nassignee: dev\ncreated: {}\n{}area: {}\n---\n\nBody\n",
+                id, id, status, created, completed, area
+            ),
+        )
+        .unwrap();
+    };
+    let commit = |message: &str, date: &str| {
+        Command::new("git")
+            .args(["add", "-A"])
+            .current_dir(&project.project_path)
+            .output()
+            .unwrap();
+        Command::new("git")
+            .args(["commit", "-m", message])
+            .env("GIT_COMMITTER_DATE", date)
+            .current_dir(&project.project_path)
+            .output()
+            .unwrap();
+    };
+
+    // backend-001: created at 0h, doing from 1h to 49h, done at 73h
+    write("backend", "backend-001", "todo", &at(0), None);
+    commit("Create backend-001", &at(1));
+    write("backend", "backend-001", "doing", &at(0), None);
+    commit("Start backend-001", &at(1));
+    write("backend", "backend-001", "review", &at(0), None);
+    commit("Review backend-001", &at(49));
+    write("backend", "backend-001", "done", &at(0), Some(&at(73)));
+    commit("Finish backend-001", &at(73));
+
+    // frontend-001: done 12h after creation without ever being committed as doing
+    write("frontend", "frontend-001", "done", &at(0), Some(&at(12)));
+    commit("Add frontend-001", &at(12));
+
+    let (stdout, stderr, exit_code) = project.run_command(&["metrics", "--weeks", "2"])?;
+    assert_eq!(exit_code, 0, "{}", stderr);
+    let row = |name: &str| {
+        stdout
+            .lines()
+            .find(|l| l.trim_start().starts_with(name))
+            .unwrap()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    assert_eq!(row("backend"), "backend 1 3.0d 3.0d 2.0d");
+    assert_eq!(row("frontend"), "frontend 1 12.0h - -");
+    assert_eq!(row("setup"), "setup 0 - - -");
+    assert_eq!(row("All areas"), "All areas 2 1.8d 3.0d 2.0d");
+    assert!(stdout.contains("Average: 1.0 per week over 2 weeks"));
+
+    let (stdout, _, _) = project.run_command(&["metrics", "--area", "frontend"])?;
+    assert!(stdout.contains("DELIVERY METRICS - frontend"));
+    assert!(!stdout.contains("backend"));
 
     Ok(())
 }
 
 #[test]
-fn test_ai_task_creation_guidance() -> Result<()> {
+fn test_metrics_burndown_export_replays_git_history() -> Result<()> {
     let project = CLITestProject::new()?;
+    project.init_git_repo()?;
     project.run_command(&["init"])?;
 
-    let (stdout, _stderr, exit_code) =
-        project.run_command(&["ai", "Create a task for user authentication"])?;
-
-    assert_eq!(exit_code, 0, "AI task creation should succeed");
-    assert!(
-        stdout.contains("create") || stdout.contains("taskguard"),
-        "Should provide creation guidance"
-    );
-    assert!(
-        stdout.contains("authentication") || stdout.contains("auth"),
-        "Should reference the topic"
-    );
+    let today = Utc::now().date_naive();
+    let day = |offset: i64| today - chrono::Duration::days(offset);
+    let commit = |message: &str, offset: i64| {
+        Command::new("git")
+            .args(["add", "-A"])
+            .current_dir(&project.project_path)
+            .output()
+            .unwrap();
+        Command::new("git")
+            .args(["commit", "-m", message])
+            .env("GIT_COMMITTER_DATE", format!("{}T12:00:00Z", day(offset)))
+            .current_dir(&project.project_path)
+            .output()
+            .unwrap();
+    };
+
+    for title in ["One", "Two"] {
+        project.run_command(&[
+            "create",
+            "--title",
+            title,
+            "--area",
+            "backend",
+            "--allow-orphan-task",
+        ])?;
+    }
+    commit("Plan sprint", 4);
+    project.run_command(&["update", "status", "backend-001", "doing"])?;
+    commit("Start backend-001", 3);
+    project.run_command(&["update", "status", "backend-001", "done"])?;
+    commit("Finish backend-001", 2);
+    // Uncommitted change shows up in today's row
+    project.run_command(&["update", "status", "backend-002", "review"])?;
+
+    let since = day(5).to_string();
+    let (stdout, stderr, exit_code) =
+        project.run_command(&["metrics", "--burndown", "--since", &since])?;
+    assert_eq!(exit_code, 0, "{}", stderr);
+    let rows: Vec<&str> = stdout.lines().collect();
+    assert_eq!(rows[0], "date,todo,doing,review,blocked,done,open,total");
+    assert_eq!(rows.len(), 7);
+    assert_eq!(rows[1], format!("{},0,0,0,0,0,0,0", day(5)));
+    assert_eq!(rows[2], format!("{},3,0,0,0,0,3,3", day(4)));
+    assert_eq!(rows[3], format!("{},2,1,0,0,0,3,3", day(3)));
+    assert_eq!(rows[4], format!("{},2,0,0,0,1,2,3", day(2)));
+    assert_eq!(rows[5], format!("{},2,0,0,0,1,2,3", day(1)));
+    assert_eq!(rows[6], format!("{},1,0,1,0,1,2,3", day(0)));
+
+    let output = project.project_path.join("burndown.json");
+    let (stdout, _, exit_code) = project.run_command(&[
+        "metrics",
+        "--burndown",
+        "--since",
+        &day(3).to_string(),
+        "--area",
+        "backend",
+        "--format",
+        "json",
+        "--output",
+        output.to_str().unwrap(),
+    ])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("Burndown data for 4 days written to"));
+    let days: serde_json::Value = serde_json::from_str(&fs::read_to_string(&output)?)?;
+    assert_eq!(days.as_array().unwrap().len(), 4);
+    assert_eq!(days[0]["date"], day(3).to_string());
+    assert_eq!(days[0]["doing"], 1);
+    assert_eq!(days[0]["total"], 2);
+    assert_eq!(days[3]["open"], 1);
+
+    let (_, stderr, exit_code) =
+        project.run_command(&["metrics", "--burndown", "--format", "xml"])?;
+    assert_ne!(exit_code, 0);
+    assert!(stderr.contains("Invalid format 'xml'"));
 
     Ok(())
 }
 
 #[test]
-fn test_ai_status_inquiry() -> Result<()> {
+fn test_metrics_estimates_compare_with_git_activity_span() -> Result<()> {
     let project = CLITestProject::new()?;
+    project.init_git_repo()?;
     project.run_command(&["init"])?;
 
-    project.create_task_file(
+    for (title, area, assignee, estimate) in [
+        ("Models", "backend", "alice", "1d"),
+        ("Endpoints", "backend", "alice", "2d"),
+        ("Caching", "backend", "bob", "4h"),
+        ("Open work", "backend", "bob", "1d"),
+        ("Client", "api", "bob", "1w"),
+    ] {
+        let (_, stderr, exit_code) = project.run_command(&[
+            "create",
+            "--title",
+            title,
+            "--area",
+            area,
+            "--assignee",
+            assignee,
+            "--estimate",
+            estimate,
+            "--allow-orphan-task",
+        ])?;
+        assert_eq!(exit_code, 0, "{}", stderr);
+    }
+    for id in ["backend-001", "backend-002", "backend-003", "api-001"] {
+        project.run_command(&["update", "status", id, "done"])?;
+    }
+
+    // 2025-03-03 is a Monday
+    let commit = |message: &str, date: &str| {
+        Command::new("git")
+            .args(["add", "-A"])
+            .current_dir(&project.project_path)
+            .output()
+            .unwrap();
+        Command::new("git")
+            .args(["commit", "--allow-empty", "-m", message])
+            .env("GIT_COMMITTER_DATE", date)
+            .current_dir(&project.project_path)
+            .output()
+            .unwrap();
+    };
+    commit("Add tasks", "2025-03-01T09:00:00Z");
+    commit("Start backend-001", "2025-03-03T09:00:00Z");
+    commit("Finish backend-001", "2025-03-05T17:00:00Z");
+    commit("Start backend-002", "2025-03-03T10:00:00Z");
+    commit("Finish backend-002", "2025-03-04T10:00:00Z");
+    commit("Start backend-003", "2025-03-06T09:00:00Z");
+    commit("Finish backend-003", "2025-03-07T09:00:00Z");
+    commit("Start backend-004", "2025-03-06T09:00:00Z");
+    commit("More backend-004", "2025-03-10T09:00:00Z");
+    commit("Start api-001", "2025-03-03T10:00:00Z");
+    commit("Finish api-001", "2025-03-03T12:00:00Z");
+
+    let (stdout, stderr, exit_code) = project.run_command(&["metrics", "--estimates"])?;
+    assert_eq!(exit_code, 0, "{}", stderr);
+    assert!(stdout.contains("ESTIMATE ACCURACY (4 done tasks"));
+    let line = |name: &str| {
+        stdout
+            .lines()
+            .find(|l| l.trim_start().starts_with(name))
+            .unwrap_or_else(|| panic!("no row for {}:\n{}", name, stdout))
+            .to_string()
+    };
+    // 1d → 3 weekdays, 2d → 2 weekdays, 4h → 2 weekdays: twice the estimates
+    assert!(line("backend").contains("3      3d 4h      1w 2d   2.0x"));
+    assert!(line("backend").contains("underestimated"));
+    assert!(line("api").contains("1         1w         2h   0.1x  too few tasks"));
+    assert!(line("alice").contains("2         3d         1w   1.7x"));
+    assert!(line("bob").contains("2      1w 4h      2d 2h   0.4x"));
+    assert!(!stdout.contains("backend-004"));
+
+    let (stdout, _, _) = project.run_command(&["metrics", "--estimates", "--area", "api"])?;
+    assert!(stdout.contains("ESTIMATE ACCURACY - api (1 done tasks"));
+
+    let (_, stderr, exit_code) = project.run_command(&["metrics", "--estimates", "--burndown"])?;
+    assert_ne!(exit_code, 0);
+    assert!(stderr.contains("cannot be used with"));
+
+    Ok(())
+}
+// =============================================================================
+// TIME TRACKING TESTS
+// =============================================================================
+
+#[test]
+fn test_time_tracking_start_stop_log() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+    project.run_command(&[
+        "create",
+        "--title",
+        "Tracked",
+        "--area",
         "backend",
-        "backend-001",
-        "Test Task",
-        TaskStatus::Todo,
-        vec![],
-    )?;
+        "--estimate",
+        "1h",
+        "--allow-orphan-task",
+    ])?;
+
+    let (stdout, stderr, exit_code) = project.run_command(&["time", "start", "backend-001"])?;
+    assert_eq!(exit_code, 0, "time start should succeed: {}", stderr);
+    assert!(stdout.contains("Started timer"));
+
+    let (_stdout, _stderr, exit_code) = project.run_command(&["time", "start", "backend-001"])?;
+    assert_ne!(exit_code, 0, "A second timer should be rejected");
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["time", "stop", "backend-001"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("Stopped timer for backend-001: 1m"));
 
     let (stdout, _stderr, exit_code) =
-        project.run_command(&["ai", "What's the project status?"])?;
+        project.run_command(&["time", "log", "backend-001", "1h30m"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("Logged 1h 30m on backend-001 (total 1h 31m of 1h estimated)"));
 
-    assert_eq!(exit_code, 0, "AI status inquiry should succeed");
-    assert!(
-        stdout.contains("status") || stdout.contains("tasks") || stdout.contains("1"),
-        "Should provide status information"
-    );
-    assert!(
-        stdout.contains("backend-001"),
-        "Should mention existing task"
-    );
+    let content = fs::read_to_string(project.project_path.join("tasks/backend/backend-001.md"))?;
+    assert!(content.contains("time_log:"));
+    assert!(content.contains("minutes: 90"));
+
+    let (stdout, _stderr, _) = project.run_command(&["lint"])?;
+    assert!(stdout.contains("Logged time (1h 31m) exceeds the estimate (1h)"));
+
+    let (stdout, _stderr, _) = project.run_command(&["stats"])?;
+    assert!(stdout.contains("TIME TRACKING"));
+    assert!(stdout.contains("Estimated vs actual: 1h → 1h 31m"));
+
+    let (_stdout, _stderr, exit_code) =
+        project.run_command(&["time", "log", "backend-001", "soon"])?;
+    assert_ne!(exit_code, 0);
 
     Ok(())
 }
 
 #[test]
-fn test_ai_empty_input() -> Result<()> {
+fn test_time_log_and_estimate_share_duration_grammar() -> Result<()> {
     let project = CLITestProject::new()?;
     project.run_command(&["init"])?;
+    project.run_command(&[
+        "create",
+        "--title",
+        "Tracked",
+        "--area",
+        "backend",
+        "--allow-orphan-task",
+    ])?;
 
-    let (stdout, _stderr, exit_code) = project.run_command(&["ai", ""])?;
+    for (value, valid) in [
+        ("2h", true),
+        ("1 hour 30 mins", true),
+        ("1 month", true),
+        ("30m, 1h", true),
+        ("2h garbage", false),
+        ("about 2h", false),
+        ("2-3 days", false),
+    ] {
+        let (_stdout, _stderr, log_code) =
+            project.run_command(&["time", "log", "backend-001", value])?;
+        let (_stdout, _stderr, estimate_code) =
+            project.run_command(&["update", "estimate", "backend-001", value])?;
+        assert_eq!(log_code == 0, valid, "time log {}", value);
+        assert_eq!(estimate_code == 0, valid, "update estimate {}", value);
+    }
 
-    assert_eq!(exit_code, 0, "AI should handle empty input gracefully");
-    assert!(
-        !stdout.is_empty(),
-        "Should provide some guidance for empty input"
-    );
-    assert!(
-        stdout.contains("help") || stdout.contains("guidance") || stdout.contains("assist"),
-        "Should offer helpful guidance"
-    );
+    Ok(())
+}
+
+#[test]
+fn test_estimate_validation_normalization_and_stats() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    let (_stdout, stderr, exit_code) = project.run_command(&[
+        "create",
+        "--title",
+        "Vague",
+        "--area",
+        "backend",
+        "--estimate",
+        "a while",
+        "--allow-orphan-task",
+    ])?;
+    assert_ne!(exit_code, 0);
+    assert!(stderr.contains("Invalid estimate 'a while'"));
+
+    for (title, estimate) in [("API", "2 days"), ("DB", "4 hours")] {
+        project.run_command(&[
+            "create",
+            "--title",
+            title,
+            "--area",
+            "backend",
+            "--estimate",
+            estimate,
+            "--allow-orphan-task",
+        ])?;
+    }
+    let content = fs::read_to_string(project.project_path.join("tasks/backend/backend-001.md"))?;
+    assert!(content.contains("estimate: 2d"));
+
+    let (_stdout, _stderr, exit_code) =
+        project.run_command(&["update", "estimate", "backend-002", "tbd"])?;
+    assert_ne!(exit_code, 0);
+    let (stdout, _stderr, exit_code) =
+        project.run_command(&["update", "estimate", "backend-002", "1 hour 30 minutes"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("estimate = 1h 30m"));
+
+    project.run_command(&["update", "status", "backend-002", "done"])?;
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["stats"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("ESTIMATED EFFORT"));
+    assert!(stdout.contains("backend - 2d 1h 30m total, 2d remaining (2 of 2 tasks estimated)"));
+    assert!(stdout.contains("setup - 2h total"));
 
     Ok(())
 }
 
 // =============================================================================
-// ERROR HANDLING AND EDGE CASES
+// CHECKLIST PROGRESS TESTS
 // =============================================================================
 
 #[test]
-fn test_invalid_command() -> Result<()> {
+fn test_checklist_progress_in_list_and_show() -> Result<()> {
     let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
 
-    let (stdout, stderr, exit_code) = project.run_command(&["nonexistent-command"])?;
+    fs::create_dir_all(project.project_path.join("tasks/backend"))?;
+    fs::write(
+        project.project_path.join("tasks/backend/backend-001.md"),
+        "---\nid: backend-001\ntitle: Checklist task\nstatus: doing\npriority: medium\ntags: []\ndependencies: [setup-001]\nassignee: null\ncreated: 2025-01-01T00:00:00Z\nestimate: ~\ncomplexity: 3\narea: backend\n---\n\n## Tasks\n- [x] First\n- [ ] Second\n- [ ] Third\n- [ ] Fourth\n",
+    )?;
 
-    assert_ne!(exit_code, 0, "Invalid command should fail");
-    assert!(
-        stdout.contains("help")
-            || stderr.contains("help")
-            || stdout.contains("usage")
-            || stderr.contains("usage")
-            || stdout.contains("subcommand")
-            || stderr.contains("subcommand"),
-        "Should provide helpful error message"
-    );
+    let (stdout, _stderr, exit_code) = project.run_command(&["list"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("backend-001 Checklist task [1/4 items, 25%]"));
+
+    project.run_command(&["task", "update", "backend-001", "2", "done"])?;
+    let (stdout, _stderr, _) = project.run_command(&["show", "backend-001"])?;
+    assert!(stdout.contains("Progress: 50% (2/4 checklist items)"));
 
     Ok(())
 }
 
 #[test]
-fn test_missing_required_args() -> Result<()> {
+fn test_checklist_completion_prompts_for_status() -> Result<()> {
     let project = CLITestProject::new()?;
     project.run_command(&["init"])?;
 
-    let (stdout, stderr, exit_code) = project.run_command(&["create"])?;
+    let task_path = project.project_path.join("tasks/backend/backend-001.md");
+    fs::create_dir_all(project.project_path.join("tasks/backend"))?;
+    fs::write(
+        &task_path,
+        "---\nid: backend-001\ntitle: Checklist task\nstatus: doing\npriority: medium\ntags: []\ndependencies: [setup-001]\nassignee: null\ncreated: 2025-01-01T00:00:00Z\nestimate: ~\ncomplexity: 3\narea: backend\n---\n\n## Tasks\n- [x] First\n- [ ] Second\n",
+    )?;
 
-    assert_ne!(exit_code, 0, "Create without required args should fail");
-    assert!(
-        stdout.contains("required")
-            || stderr.contains("required")
-            || stdout.contains("title")
-            || stderr.contains("title"),
-        "Should indicate missing required arguments"
-    );
+    // Prompt is off by default
+    let (stdout, _stderr, _) =
+        project.run_command_with_input(&["task", "update", "backend-001", "2", "done"], "r\n")?;
+    assert!(!stdout.contains("checklist items are complete"));
+    project.run_command(&["task", "update", "backend-001", "2", "todo"])?;
+
+    let config_path = project.project_path.join(".taskguard/config.toml");
+    let config = fs::read_to_string(&config_path)?;
+    fs::write(
+        &config_path,
+        config.replace(
+            "checklist_complete_prompt = false",
+            "checklist_complete_prompt = true",
+        ),
+    )?;
+
+    let (stdout, _stderr, exit_code) =
+        project.run_command_with_input(&["task", "update", "backend-001", "2", "done"], "r\n")?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("All 2 checklist items are complete"));
+    assert!(stdout.contains("status = review"));
+    assert!(fs::read_to_string(&task_path)?.contains("status: review"));
+
+    // Reopening an item on a done task warns
+    project.run_command(&["update", "status", "backend-001", "done"])?;
+    let (stdout, _stderr, _) =
+        project.run_command(&["task", "update", "backend-001", "1", "todo"])?;
+    assert!(stdout.contains("is marked done but now has an open checklist item"));
 
     Ok(())
 }
 
 #[test]
-fn test_invalid_area_filter() -> Result<()> {
+fn test_checklist_item_assignee_and_due() -> Result<()> {
     let project = CLITestProject::new()?;
     project.run_command(&["init"])?;
 
-    let (stdout, _stderr, exit_code) = project.run_command(&["list", "--area", "nonexistent"])?;
+    let task_path = project.project_path.join("tasks/backend/backend-001.md");
+    fs::create_dir_all(project.project_path.join("tasks/backend"))?;
+    fs::write(
+        &task_path,
+        "---\nid: backend-001\ntitle: Checklist task\nstatus: doing\npriority: medium\ntags: []\ndependencies: [setup-001]\nassignee: null\ncreated: 2025-01-01T00:00:00Z\nestimate: ~\ncomplexity: 3\narea: backend\n---\n\n## Tasks\n- [ ] write tests @alice due:2000-07-01\n- [ ] ship it\n",
+    )?;
 
-    assert_eq!(
-        exit_code, 0,
-        "List with invalid area should succeed but show no results"
-    );
-    assert!(
-        stdout.contains("0") || stdout.contains("No tasks") || stdout.contains("empty"),
-        "Should show no tasks for invalid area"
-    );
+    let (stdout, _stderr, exit_code) = project.run_command(&["list", "items", "backend-001"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("write tests (@alice, due 2000-07-01) 🔥 OVERDUE"));
+
+    let (_stdout, _stderr, exit_code) = project.run_command(&[
+        "task",
+        "update",
+        "backend-001",
+        "2",
+        "--assignee",
+        "@bob",
+        "--due",
+        "2099-01-31",
+    ])?;
+    assert_eq!(exit_code, 0);
+    let content = fs::read_to_string(&task_path)?;
+    assert!(content.contains("- [ ] ship it @bob due:2099-01-31"));
+
+    // Status changes keep metadata; "none" clears it
+    project.run_command(&["task", "update", "backend-001", "1", "done"])?;
+    project.run_command(&["task", "update", "backend-001", "2", "--due", "none"])?;
+    let content = fs::read_to_string(&task_path)?;
+    assert!(content.contains("- [x] write tests @alice due:2000-07-01"));
+    assert!(content.trim_end().ends_with("- [ ] ship it @bob"));
+
+    let (_stdout, stderr, exit_code) =
+        project.run_command(&["task", "update", "backend-001", "1"])?;
+    assert_ne!(exit_code, 0);
+    assert!(stderr.contains("Nothing to update"));
+
+    Ok(())
+}
+
+#[test]
+fn test_nested_checklist_items() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    let task_path = project.project_path.join("tasks/backend/backend-001.md");
+    fs::create_dir_all(project.project_path.join("tasks/backend"))?;
+    fs::write(
+        &task_path,
+        "---\nid: backend-001\ntitle: Nested task\nstatus: doing\npriority: medium\ntags: []\ndependencies: [setup-001]\nassignee: null\ncreated: 2025-01-01T00:00:00Z\nestimate: ~\ncomplexity: 3\narea: backend\n---\n\n## Tasks\n- [ ] Design\n- [ ] Build\n  - [x] Schema\n  - [ ] Endpoints\n    - [ ] Create\n- [ ] Release\n",
+    )?;
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["list", "items", "backend-001"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("   2.2. ⭕ [todo] Endpoints"));
+    assert!(stdout.contains("      2.2.1. ⭕ [todo] Create"));
+    assert!(stdout.contains("   3. ⭕ [todo] Release"));
+    assert!(stdout.contains("Total items: 4"));
+
+    // Parents cannot be checked directly
+    let (_stdout, stderr, exit_code) =
+        project.run_command(&["task", "update", "backend-001", "2", "done"])?;
+    assert_ne!(exit_code, 0);
+    assert!(stderr.contains("has sub-items"));
+
+    // Completing the last child completes its ancestors
+    let (_stdout, _stderr, exit_code) =
+        project.run_command(&["task", "update", "backend-001", "2.2.1", "done"])?;
+    assert_eq!(exit_code, 0);
+    let content = fs::read_to_string(&task_path)?;
+    assert!(content.contains("- [x] Build\n  - [x] Schema\n  - [x] Endpoints\n    - [x] Create"));
+
+    // Reopening a child reopens the parent
+    project.run_command(&["task", "update", "backend-001", "2.1", "todo"])?;
+    let content = fs::read_to_string(&task_path)?;
+    assert!(content.contains("- [ ] Build\n  - [ ] Schema\n  - [x] Endpoints"));
 
     Ok(())
 }
 
 // =============================================================================
-// PERFORMANCE AND STRESS TESTS
+// WORKLOG TESTS
 // =============================================================================
 
 #[test]
-fn test_large_project_performance() -> Result<()> {
+fn test_comment_appends_to_log_section() -> Result<()> {
     let project = CLITestProject::new()?;
+    project.init_git_repo()?;
     project.run_command(&["init"])?;
+    project.run_command(&[
+        "create",
+        "--title",
+        "Logged",
+        "--area",
+        "backend",
+        "--allow-orphan-task",
+    ])?;
 
-    // Create many tasks to test performance
-    for i in 1..=50 {
-        project.create_task_file(
-            "backend",
-            &format!("backend-{:03}", i),
-            &format!("Task {}", i),
-            TaskStatus::Todo,
-            vec![],
-        )?;
-    }
+    let (stdout, stderr, exit_code) =
+        project.run_command(&["comment", "backend-001", "blocked on infra ticket"])?;
+    assert_eq!(exit_code, 0, "comment should succeed: {}", stderr);
+    assert!(stdout.contains("Logged on backend-001 by Test User: blocked on infra ticket"));
 
-    let start = std::time::Instant::now();
-    let (stdout, _stderr, exit_code) = project.run_command(&["list"])?;
-    let list_duration = start.elapsed();
+    project.run_command(&["comment", "backend-001", "unblocked"])?;
+    let content = fs::read_to_string(project.project_path.join("tasks/backend/backend-001.md"))?;
+    assert_eq!(content.matches("## Log").count(), 1);
+    let log = content.split("## Log").nth(1).unwrap();
+    let first = log.find("Test User: blocked on infra ticket").unwrap();
+    let second = log.find("Test User: unblocked").unwrap();
+    assert!(first < second, "entries are appended in order");
+    assert!(log.contains(" UTC Test User: "));
 
-    assert_eq!(exit_code, 0, "List should succeed with many tasks");
-    assert!(
-        stdout.contains("50") || stdout.contains("backend-050"),
-        "Should show all tasks"
-    );
-    assert!(
-        list_duration < std::time::Duration::from_secs(5),
-        "List should complete within 5 seconds"
-    );
+    let (_stdout, _stderr, exit_code) = project.run_command(&["comment", "backend-001", "  "])?;
+    assert_ne!(exit_code, 0);
 
-    let start = std::time::Instant::now();
-    let (_stdout, _stderr, exit_code) = project.run_command(&["validate"])?;
-    let validate_duration = start.elapsed();
+    Ok(())
+}
 
-    assert_eq!(exit_code, 0, "Validate should succeed with many tasks");
-    assert!(
-        validate_duration < std::time::Duration::from_secs(10),
-        "Validate should complete within 10 seconds"
-    );
+// =============================================================================
+// TEMPLATE TESTS
+// =============================================================================
+
+#[test]
+fn test_create_with_named_and_area_default_templates() -> Result<()> {
+    let project = CLITestProject::new()?;
+    project.run_command(&["init"])?;
+
+    let (stdout, _stderr, exit_code) = project.run_command(&["template", "list"])?;
+    assert_eq!(exit_code, 0);
+    assert!(stdout.contains("bugfix"));
+
+    let (_stdout, stderr, exit_code) = project.run_command(&[
+        "create",
+        "--title",
+        "Crash on login",
+        "--area",
+        "backend",
+        "--template",
+        "bugfix",
+        "--allow-orphan-task",
+    ])?;
+    assert_eq!(exit_code, 0, "create --template should succeed: {}", stderr);
+    let content = fs::read_to_string(project.project_path.join("tasks/backend/backend-001.md"))?;
+    assert!(content.contains("# Crash on login"));
+    assert!(content.contains("## Reproduction"));
+
+    // Project template + per-area default from config
+    let source = project.project_path.join("release.md");
+    fs::write(&source, "# {{title}}\n\n## Context\nRelease checklist\n")?;
+    let (_stdout, _stderr, exit_code) = project.run_command(&[
+        "template",
+        "add",
+        "release",
+        "--from",
+        source.to_str().unwrap(),
+    ])?;
+    assert_eq!(exit_code, 0);
+    let (_stdout, _stderr, exit_code) = project.run_command(&["template", "add", "release"])?;
+    assert_ne!(exit_code, 0, "Duplicate template should be rejected");
+
+    let config_path = project.project_path.join(".taskguard/config.toml");
+    let mut config = fs::read_to_string(&config_path)?;
+    config.push_str("\n[templates]\ndeployment = \"release\"\n");
+    fs::write(&config_path, config)?;
+
+    project.run_command(&[
+        "create",
+        "--title",
+        "Ship 1.0",
+        "--area",
+        "deployment",
+        "--allow-orphan-task",
+    ])?;
+    let content = fs::read_to_string(
+        project
+            .project_path
+            .join("tasks/deployment/deployment-001.md"),
+    )?;
+    assert!(content.contains("Release checklist"));
+
+    let (stdout, _stderr, _) = project.run_command(&["template", "list"])?;
+    assert!(stdout.contains("deployment → release"));
+
+    let (_stdout, _stderr, exit_code) = project.run_command(&[
+        "create",
+        "--title",
+        "X",
+        "--template",
+        "missing",
+        "--allow-orphan-task",
+    ])?;
+    assert_ne!(exit_code, 0);
 
     Ok(())
 }
 
 #[test]
-fn test_command_timeout_handling() -> Result<()> {
+fn test_template_variables_from_flags() -> Result<()> {
     let project = CLITestProject::new()?;
     project.run_command(&["init"])?;
 
-    // Test that commands complete within reasonable time
-    let commands = vec![vec!["list"], vec!["validate"], vec!["ai", "help"]];
+    let (stdout, stderr, exit_code) = project.run_command(&[
+        "create",
+        "--title",
+        "Login outage",
+        "--area",
+        "backend",
+        "--template",
+        "incident",
+        "--assignee",
+        "alice",
+        "--var",
+        "service=auth-api",
+        "--allow-orphan-task",
+    ])?;
+    assert_eq!(exit_code, 0, "create with --var should succeed: {}", stderr);
+    // Not a terminal, so the missing value is reported instead of prompted for
+    assert!(stdout.contains("placeholders left unfilled: custom.severity"));
 
-    for cmd in commands {
-        let start = std::time::Instant::now();
-        let (_stdout, _stderr, _exit_code) = project.run_command(&cmd)?;
-        let duration = start.elapsed();
+    let content = fs::read_to_string(project.project_path.join("tasks/backend/backend-001.md"))?;
+    assert!(content.contains("**Service:** auth-api"));
+    assert!(content.contains("by alice"));
+    assert!(content.contains("**Severity:** {{custom.severity}}"));
 
-        assert!(
-            duration < std::time::Duration::from_secs(30),
-            "Command {:?} should complete within 30 seconds",
-            cmd
-        );
-    }
+    let (_stdout, _stderr, exit_code) = project.run_command(&[
+        "create",
+        "--title",
+        "Bad var",
+        "--var",
+        "noequals",
+        "--allow-orphan-task",
+    ])?;
+    assert_ne!(exit_code, 0);
 
     Ok(())
 }
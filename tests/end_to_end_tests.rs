@@ -1,11 +1,14 @@
 use anyhow::Result;
 use chrono::Utc;
 use git2::Repository;
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
+use taskguard::commands::lint::LintOptions;
+use taskguard::commands::sync::SyncOptions;
 use taskguard::commands::{ai, create, init, lint, sync, validate};
 use taskguard::config::Config;
-use taskguard::task::{Priority, Task, TaskStatus};
+use taskguard::task::{FORMAT_VERSION, Priority, Relations, Task, TaskStatus};
 use tempfile::TempDir;
 
 /// Helper to create a task with minimal arguments (title, area, priority)
@@ -14,12 +17,16 @@ fn create_task(title: &str, area: &str, priority: &str) -> Result<()> {
         title.to_string(),
         Some(area.to_string()),
         Some(priority.to_string()),
-        None, // complexity
-        None, // tags
-        None, // dependencies
-        None, // assignee
-        None, // estimate
-        true, // allow_orphan_task - tests don't need causality enforcement
+        None,   // complexity
+        None,   // tags
+        None,   // dependencies
+        None,   // assignee
+        None,   // estimate
+        None,   // due
+        None,   // epic
+        None,   // template
+        vec![], // vars
+        true,   // allow_orphan_task - tests don't need causality enforcement
     )
 }
 
@@ -122,11 +129,22 @@ impl TaskGuardTestProject {
             priority: Priority::Medium,
             tags: vec!["test".to_string()],
             dependencies,
-            assignee: None,
+            relations: Relations::default(),
+            assignees: vec![],
             created: Utc::now(),
             estimate: Some("4h".to_string()),
             complexity: Some(5),
+            due: None,
+            epic: None,
+            time_log: vec![],
+            links: vec![],
+            branch: None,
+            release: None,
+            completed: None,
+            claimed: None,
+            format_version: FORMAT_VERSION,
             area: area.to_string(),
+            extra: BTreeMap::new(),
             content: format!("Test content for {}", title),
             file_path: file_path.clone(),
         };
@@ -146,7 +164,7 @@ fn test_complete_project_lifecycle() -> Result<()> {
     project.set_current_dir()?;
 
     // 1. Initialize project
-    init::run()?;
+    init::run(None)?;
     assert!(
         project.taskguard_dir.exists(),
         "Should create .taskguard directory"
@@ -159,7 +177,7 @@ fn test_complete_project_lifecycle() -> Result<()> {
     create_task("Build login form", "frontend", "medium")?;
 
     // 3. Validate initial state
-    validate::run(false, false)?;
+    validate::run(false, false, false, false, false)?;
 
     // 4. Simulate work progress with Git commits
     let repo = project.init_git_repo()?;
@@ -168,10 +186,13 @@ fn test_complete_project_lifecycle() -> Result<()> {
     project.add_git_commit(&repo, "Complete setup-001 configuration")?;
 
     // 5. Run sync to analyze Git activity
-    sync::run(50, false, false, false, false, false)?;
+    sync::run(SyncOptions {
+        limit: 50,
+        ..SyncOptions::default()
+    })?;
 
     // 6. Run lint to analyze task quality
-    lint::run(false, None)?;
+    lint::run(LintOptions::default())?;
 
     // 7. Use AI to get recommendations
     ai::run("What should I work on next?".to_string())?;
@@ -185,7 +206,7 @@ fn test_dependency_workflow() -> Result<()> {
     project.set_current_dir()?;
 
     // Initialize project
-    init::run()?;
+    init::run(None)?;
 
     // Create tasks with dependency chain
     project.create_task_manually(
@@ -218,7 +239,7 @@ fn test_dependency_workflow() -> Result<()> {
     )?;
 
     // Initial validation should show only setup-001 as available
-    validate::run(false, false)?;
+    validate::run(false, false, false, false, false)?;
 
     // Complete setup-001
     let setup_task_path = project.tasks_dir.join("setup").join("setup-001.md");
@@ -227,7 +248,7 @@ fn test_dependency_workflow() -> Result<()> {
     setup_task.save_to_file(&setup_task_path)?;
 
     // Now backend-001 should be available
-    validate::run(false, false)?;
+    validate::run(false, false, false, false, false)?;
 
     // AI should recommend backend-001
     ai::run("What should I work on next?".to_string())?;
@@ -245,7 +266,7 @@ fn test_git_analysis_workflow() -> Result<()> {
     project.set_current_dir()?;
 
     // Initialize project and Git
-    init::run()?;
+    init::run(None)?;
     let repo = project.init_git_repo()?;
 
     // Create tasks
@@ -262,7 +283,11 @@ fn test_git_analysis_workflow() -> Result<()> {
     project.add_git_commit(&repo, "Complete backend-001 authentication feature")?;
 
     // Run sync to analyze Git activity
-    sync::run(10, true, false, false, false, false)?; // Verbose mode
+    sync::run(SyncOptions {
+        limit: 10,
+        verbose: true,
+        ..SyncOptions::default()
+    })?; // Verbose mode
 
     // Git analysis should suggest status changes
     Ok(())
@@ -273,7 +298,7 @@ fn test_complex_git_scenario() -> Result<()> {
     let project = TaskGuardTestProject::new()?;
     project.set_current_dir()?;
 
-    init::run()?;
+    init::run(None)?;
     let repo = project.init_git_repo()?;
 
     // Create multiple tasks
@@ -301,7 +326,11 @@ fn test_complex_git_scenario() -> Result<()> {
     }
 
     // Analyze the complex Git history
-    sync::run(20, true, false, false, false, false)?;
+    sync::run(SyncOptions {
+        limit: 20,
+        verbose: true,
+        ..SyncOptions::default()
+    })?;
 
     Ok(())
 }
@@ -315,7 +344,7 @@ fn test_task_quality_improvement_workflow() -> Result<()> {
     let project = TaskGuardTestProject::new()?;
     project.set_current_dir()?;
 
-    init::run()?;
+    init::run(None)?;
 
     // Create tasks with varying quality levels
 
@@ -354,11 +383,22 @@ This task implements a comprehensive user authentication system using JWT tokens
         priority: Priority::High,
         tags: vec!["auth".to_string(), "security".to_string()],
         dependencies: vec![],
-        assignee: Some("developer".to_string()),
+        relations: Relations::default(),
+        assignees: vec!["developer".to_string()],
         created: Utc::now(),
         estimate: Some("8 hours".to_string()),
         complexity: Some(6),
+        due: None,
+        epic: None,
+        time_log: vec![],
+        links: vec![],
+        branch: None,
+        release: None,
+        completed: None,
+        claimed: None,
+        format_version: FORMAT_VERSION,
         area: "backend".to_string(),
+        extra: BTreeMap::new(),
         content: high_quality_content.to_string(),
         file_path: backend_file_path.clone(),
     };
@@ -377,11 +417,22 @@ This task implements a comprehensive user authentication system using JWT tokens
         priority: Priority::Medium,
         tags: vec![],
         dependencies: vec![],
-        assignee: None,
+        relations: Relations::default(),
+        assignees: vec![],
         created: Utc::now(),
         estimate: None,
         complexity: None,
+        due: None,
+        epic: None,
+        time_log: vec![],
+        links: vec![],
+        branch: None,
+        release: None,
+        completed: None,
+        claimed: None,
+        format_version: FORMAT_VERSION,
         area: "frontend".to_string(),
+        extra: BTreeMap::new(),
         content: "Make the UI better.".to_string(),
         file_path: frontend_file_path.clone(),
     };
@@ -389,7 +440,10 @@ This task implements a comprehensive user authentication system using JWT tokens
     low_quality_task.save_to_file(&frontend_file_path)?;
 
     // Run lint analysis
-    lint::run(true, None)?; // Verbose mode
+    lint::run(LintOptions {
+        verbose: true,
+        ..LintOptions::default()
+    })?; // Verbose mode
 
     // Should identify quality issues
     Ok(())
@@ -400,7 +454,7 @@ fn test_complexity_analysis_workflow() -> Result<()> {
     let project = TaskGuardTestProject::new()?;
     project.set_current_dir()?;
 
-    init::run()?;
+    init::run(None)?;
 
     // Create directories and save tasks
     let docs_dir = project.tasks_dir.join("docs");
@@ -419,11 +473,22 @@ fn test_complexity_analysis_workflow() -> Result<()> {
         priority: Priority::Low,
         tags: vec!["docs".to_string()],
         dependencies: vec![],
-        assignee: None,
+        relations: Relations::default(),
+        assignees: vec![],
         created: Utc::now(),
         estimate: Some("30 minutes".to_string()),
         complexity: Some(2),
+        due: None,
+        epic: None,
+        time_log: vec![],
+        links: vec![],
+        branch: None,
+        release: None,
+        completed: None,
+        claimed: None,
+        format_version: FORMAT_VERSION,
         area: "docs".to_string(),
+        extra: BTreeMap::new(),
         content: "Update the project README with new installation instructions.".to_string(),
         file_path: simple_file_path.clone(),
     };
@@ -450,11 +515,22 @@ fn test_complexity_analysis_workflow() -> Result<()> {
             "other-001".to_string(),
             "dep-003".to_string(),
         ],
-        assignee: Some("senior-dev".to_string()),
+        relations: Relations::default(),
+        assignees: vec!["senior-dev".to_string()],
         created: Utc::now(),
         estimate: Some("3 months".to_string()),
         complexity: Some(10),
+        due: None,
+        epic: None,
+        time_log: vec![],
+        links: vec![],
+        branch: None,
+        release: None,
+        completed: None,
+        claimed: None,
+        format_version: FORMAT_VERSION,
         area: "architecture".to_string(),
+        extra: BTreeMap::new(),
         content: complex_task_content,
         file_path: complex_file_path.clone(),
     };
@@ -463,7 +539,10 @@ fn test_complexity_analysis_workflow() -> Result<()> {
     complex_task.save_to_file(&complex_file_path)?;
 
     // Run complexity analysis
-    lint::run(true, None)?;
+    lint::run(LintOptions {
+        verbose: true,
+        ..LintOptions::default()
+    })?;
 
     // AI should understand complexity differences
     ai::run("How complex are my tasks?".to_string())?;
@@ -481,7 +560,7 @@ fn test_ai_guided_development_workflow() -> Result<()> {
     let project = TaskGuardTestProject::new()?;
     project.set_current_dir()?;
 
-    init::run()?;
+    init::run(None)?;
 
     // Simulate AI-guided development process
 
@@ -525,7 +604,7 @@ fn test_ai_error_handling_workflow() -> Result<()> {
     let project = TaskGuardTestProject::new()?;
     project.set_current_dir()?;
 
-    init::run()?;
+    init::run(None)?;
 
     // Test AI handling of various edge cases
     ai::run("".to_string())?; // Empty input
@@ -550,7 +629,7 @@ fn test_large_project_workflow() -> Result<()> {
     let project = TaskGuardTestProject::new()?;
     project.set_current_dir()?;
 
-    init::run()?;
+    init::run(None)?;
 
     // Create a large number of tasks to test performance
     let areas = [
@@ -579,11 +658,11 @@ fn test_large_project_workflow() -> Result<()> {
 
     // Test performance of various operations on large project
     let start = std::time::Instant::now();
-    validate::run(false, false)?;
+    validate::run(false, false, false, false, false)?;
     let validate_duration = start.elapsed();
 
     let start = std::time::Instant::now();
-    lint::run(false, None)?;
+    lint::run(LintOptions::default())?;
     let lint_duration = start.elapsed();
 
     let start = std::time::Instant::now();
@@ -612,7 +691,7 @@ fn test_complex_dependency_chains() -> Result<()> {
     let project = TaskGuardTestProject::new()?;
     project.set_current_dir()?;
 
-    init::run()?;
+    init::run(None)?;
 
     // Create complex dependency scenarios
 
@@ -684,7 +763,7 @@ fn test_complex_dependency_chains() -> Result<()> {
     )?;
 
     // Test dependency resolution
-    validate::run(false, false)?;
+    validate::run(false, false, false, false, false)?;
     ai::run("What's blocked by dependencies?".to_string())?;
     ai::run("What can I work on right now?".to_string())?;
 
@@ -694,7 +773,7 @@ fn test_complex_dependency_chains() -> Result<()> {
     backend_task.status = TaskStatus::Done;
     backend_task.save_to_file(&backend_task_path)?;
 
-    validate::run(false, false)?;
+    validate::run(false, false, false, false, false)?;
     ai::run("What's now available after completing backend-001?".to_string())?;
 
     Ok(())
@@ -709,7 +788,7 @@ fn test_git_sync_lint_ai_integration() -> Result<()> {
     let project = TaskGuardTestProject::new()?;
     project.set_current_dir()?;
 
-    init::run()?;
+    init::run(None)?;
     let repo = project.init_git_repo()?;
 
     // Create tasks with quality issues
@@ -744,11 +823,22 @@ Well-structured task with clear objectives.
         priority: Priority::Medium,
         tags: vec![],
         dependencies: vec![],
-        assignee: None,
+        relations: Relations::default(),
+        assignees: vec![],
         created: Utc::now(),
         estimate: None,
         complexity: None,
+        due: None,
+        epic: None,
+        time_log: vec![],
+        links: vec![],
+        branch: None,
+        release: None,
+        completed: None,
+        claimed: None,
+        format_version: FORMAT_VERSION,
         area: "backend".to_string(),
+        extra: BTreeMap::new(),
         content: poor_quality_content.to_string(),
         file_path: poor_file_path.clone(),
     };
@@ -760,11 +850,22 @@ Well-structured task with clear objectives.
         priority: Priority::High,
         tags: vec!["backend".to_string(), "service".to_string()],
         dependencies: vec![],
-        assignee: Some("developer".to_string()),
+        relations: Relations::default(),
+        assignees: vec!["developer".to_string()],
         created: Utc::now(),
         estimate: Some("6 hours".to_string()),
         complexity: Some(7),
+        due: None,
+        epic: None,
+        time_log: vec![],
+        links: vec![],
+        branch: None,
+        release: None,
+        completed: None,
+        claimed: None,
+        format_version: FORMAT_VERSION,
         area: "backend".to_string(),
+        extra: BTreeMap::new(),
         content: good_quality_content.to_string(),
         file_path: good_file_path.clone(),
     };
@@ -773,7 +874,10 @@ Well-structured task with clear objectives.
     good_task.save_to_file(&good_file_path)?;
 
     // 1. Lint identifies quality issues
-    lint::run(true, None)?;
+    lint::run(LintOptions {
+        verbose: true,
+        ..LintOptions::default()
+    })?;
 
     // 2. Git commits reference tasks
     project.add_git_commit(&repo, "Start work on backend-001 bug fix")?;
@@ -781,7 +885,11 @@ Well-structured task with clear objectives.
     project.add_git_commit(&repo, "Complete backend-001 fixes")?;
 
     // 3. Sync analyzes Git activity
-    sync::run(10, true, false, false, false, false)?;
+    sync::run(SyncOptions {
+        limit: 10,
+        verbose: true,
+        ..SyncOptions::default()
+    })?;
 
     // 4. AI integrates all information
     ai::run("What's the quality of my tasks?".to_string())?;
@@ -797,7 +905,7 @@ fn test_complete_feature_development_cycle() -> Result<()> {
     project.set_current_dir()?;
 
     // Initialize everything
-    init::run()?;
+    init::run(None)?;
     let repo = project.init_git_repo()?;
 
     // 1. Planning phase - AI helps create task structure
@@ -863,9 +971,16 @@ fn test_complete_feature_development_cycle() -> Result<()> {
     backend_task.save_to_file(&backend_path)?;
 
     // 3. Analysis phase - understand progress
-    validate::run(false, false)?; // Check what's now available
-    sync::run(10, true, false, false, false, false)?; // Analyze Git activity
-    lint::run(true, None)?; // Check task quality
+    validate::run(false, false, false, false, false)?; // Check what's now available
+    sync::run(SyncOptions {
+        limit: 10,
+        verbose: true,
+        ..SyncOptions::default()
+    })?; // Analyze Git activity
+    lint::run(LintOptions {
+        verbose: true,
+        ..LintOptions::default()
+    })?; // Check task quality
 
     // 4. AI provides guidance
     ai::run("What should I work on next?".to_string())?;
@@ -882,8 +997,11 @@ fn test_complete_feature_development_cycle() -> Result<()> {
     frontend_task.save_to_file(&frontend_path)?;
 
     // Final analysis
-    validate::run(false, false)?;
-    sync::run(20, false, false, false, false, false)?;
+    validate::run(false, false, false, false, false)?;
+    sync::run(SyncOptions {
+        limit: 20,
+        ..SyncOptions::default()
+    })?;
     ai::run("Show me the final project status".to_string())?;
 
     Ok(())
@@ -895,7 +1013,7 @@ fn test_area_sync_workflow() -> Result<()> {
     let project = TaskGuardTestProject::new()?;
     project.set_current_dir()?;
 
-    init::run()?;
+    init::run(None)?;
 
     let config_path = project.taskguard_dir.join("config.toml");
 
@@ -955,7 +1073,7 @@ Test task content.
     );
 
     // 4. Run validate --sync-areas to discover and add custom area
-    validate::run(true, false)?; // sync_areas = true
+    validate::run(true, false, false, false, false)?; // sync_areas = true
 
     // Verify custom area was added
     let final_config = Config::load_or_default(&config_path)?;
@@ -972,7 +1090,7 @@ Test task content.
     );
 
     // 6. Run validate --sync-areas again - should report already in sync
-    validate::run(true, false)?; // Should succeed and report "in sync"
+    validate::run(true, false, false, false, false)?; // Should succeed and report "in sync"
 
     Ok(())
 }
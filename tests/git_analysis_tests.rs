@@ -1,9 +1,15 @@
 use anyhow::Result;
 use chrono::Utc;
 use git2::Repository;
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
-use taskguard::git::{GitAnalyzer, TaskCommit};
+use taskguard::git::{
+    DiffStats, GitAnalyzer, RevertMatch, TaskActivity, TaskCommit, WorkingChange, co_authors,
+    glob_regex, reverted_commit,
+};
+use taskguard::git_cache::{self, GitCache};
+use taskguard::task::Task;
 use tempfile::TempDir;
 
 struct TestRepo {
@@ -70,6 +76,45 @@ impl TestRepo {
 
         Ok(())
     }
+
+    fn commit_file(&self, relative_path: &str, content: &str, message: &str) -> Result<git2::Oid> {
+        let file_path = self.repo_path.join(relative_path);
+        if let Some(parent) = file_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&file_path, content)?;
+
+        let mut index = self.repo.index()?;
+        index.add_path(std::path::Path::new(relative_path))?;
+        index.write()?;
+
+        let tree_id = index.write_tree()?;
+        let tree = self.repo.find_tree(tree_id)?;
+        let signature = self.repo.signature()?;
+
+        let parent_commit = match self.repo.head() {
+            Ok(head) => Some(head.peel_to_commit()?),
+            Err(_) => None,
+        };
+        let parents: Vec<&git2::Commit> = parent_commit.iter().collect();
+
+        Ok(self.repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            message,
+            &tree,
+            &parents,
+        )?)
+    }
+}
+
+fn task_file(id: &str, status: &str) -> String {
+    let area = id.split('-').next().unwrap_or("test");
+    format!(
+        "---\nid: {}\ntitle: Task {}\nstatus: {}\npriority: medium\ntags: []\ndependencies: []\nassignee: null\ncreated: 2025-01-01T00:00:00Z\nestimate: ~\ncomplexity: 3\narea: {}\n---\n\n# Task\n",
+        id, id, status, area
+    )
 }
 
 #[test]
@@ -137,6 +182,7 @@ fn test_suggest_status_completion_indicators() {
         author: "test".to_string(),
         timestamp: Utc::now(),
         task_ids: vec!["auth-001".to_string()],
+        submodule: None,
     }];
 
     let (status, confidence) = analyzer.suggest_status(&commits);
@@ -155,6 +201,7 @@ fn test_suggest_status_testing_indicators() {
         author: "test".to_string(),
         timestamp: Utc::now(),
         task_ids: vec!["auth-001".to_string()],
+        submodule: None,
     }];
 
     let (status, confidence) = analyzer.suggest_status(&commits);
@@ -173,6 +220,7 @@ fn test_suggest_status_work_in_progress() {
         author: "test".to_string(),
         timestamp: Utc::now(),
         task_ids: vec!["auth-001".to_string()],
+        submodule: None,
     }];
 
     let (status, confidence) = analyzer.suggest_status(&commits);
@@ -447,6 +495,7 @@ fn test_status_suggestion_comprehensive_patterns() -> Result<()> {
                 author: "test".to_string(),
                 timestamp: Utc::now(),
                 task_ids: vec!["backend-001".to_string()],
+                submodule: None,
             })
             .collect();
 
@@ -826,3 +875,758 @@ fn test_concurrent_git_access_safety() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_task_statuses_at_tag() -> Result<()> {
+    let test_repo = TestRepo::new()?;
+
+    test_repo.commit_file(
+        "tasks/backend/backend-001.md",
+        &task_file("backend-001", "todo"),
+        "Add backend-001",
+    )?;
+    let tagged = test_repo.commit_file(
+        ".taskguard/archive/setup/setup-001.md",
+        &task_file("setup-001", "done"),
+        "Archive setup-001",
+    )?;
+    let tagged_commit = test_repo.repo.find_object(tagged, None)?;
+    test_repo
+        .repo
+        .tag_lightweight("v1.0.0", &tagged_commit, false)?;
+    test_repo.commit_file(
+        "tasks/backend/backend-001.md",
+        &task_file("backend-001", "done"),
+        "Complete backend-001",
+    )?;
+
+    let analyzer = GitAnalyzer::new(&test_repo.repo_path)?;
+    let oid = analyzer
+        .resolve_since("v1.0.0")?
+        .expect("tag should resolve");
+    assert_eq!(oid, tagged);
+
+    let statuses = analyzer.task_statuses_at(oid)?;
+    assert_eq!(
+        statuses.get("backend-001"),
+        Some(&taskguard::task::TaskStatus::Todo)
+    );
+    assert_eq!(
+        statuses.get("setup-001"),
+        Some(&taskguard::task::TaskStatus::Done)
+    );
+
+    // A date before any commit resolves to nothing
+    assert!(analyzer.resolve_since("2000-01-01")?.is_none());
+    assert!(analyzer.resolve_since("not-a-ref").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_task_history_follows_field_changes() -> Result<()> {
+    use taskguard::commands::history::describe_changes;
+
+    let test_repo = TestRepo::new()?;
+    test_repo.commit_file(
+        "tasks/backend/backend-001.md",
+        &task_file("backend-001", "todo"),
+        "Add backend-001",
+    )?;
+    test_repo.add_commit("Unrelated change")?;
+    test_repo.commit_file(
+        "tasks/backend/backend-001.md",
+        &task_file("backend-001", "doing").replace("assignee: null", "assignee: alice"),
+        "Start backend-001",
+    )?;
+    // Archived copies are still followed
+    test_repo.commit_file(
+        ".taskguard/archive/backend/backend-001.md",
+        &task_file("backend-001", "done").replace("assignee: null", "assignee: alice"),
+        "Archive backend-001",
+    )?;
+
+    let analyzer = GitAnalyzer::new(&test_repo.repo_path)?;
+    let history = analyzer.task_history("backend-001")?;
+    assert_eq!(history.len(), 3, "unrelated commits are skipped");
+    assert_eq!(history[0].message, "Add backend-001");
+    assert_eq!(history[0].author, "Test User");
+
+    assert_eq!(
+        describe_changes(None, &history[0].task),
+        vec!["created (todo, medium priority, assignee none)"]
+    );
+    assert_eq!(
+        describe_changes(Some(&history[0].task), &history[1].task),
+        vec!["status todo → doing", "assignee none → alice"]
+    );
+    assert_eq!(
+        describe_changes(Some(&history[1].task), &history[2].task),
+        vec!["status doing → done"]
+    );
+
+    assert!(analyzer.task_history("backend-999")?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_task_id_trailers() {
+    use taskguard::git::{ReferenceKind, task_trailers, trailer_task_ids};
+
+    let temp_repo = TestRepo::new().unwrap();
+    let analyzer = GitAnalyzer::new(&temp_repo.repo_path).unwrap();
+
+    let message = "Fix login redirect for task 12\n\nSee backend-002 for context.\n\nTask-Id: auth-v2-0001, backend-001\nSigned-off-by: Dev <dev@example.com>";
+    assert_eq!(
+        trailer_task_ids(message),
+        vec!["auth-v2-0001", "backend-001"]
+    );
+    let refs = analyzer.extract_task_references(message);
+    let kinds: Vec<(&str, ReferenceKind)> =
+        refs.iter().map(|r| (r.task_id.as_str(), r.kind)).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            ("auth-v2-0001", ReferenceKind::Trailer),
+            ("backend-001", ReferenceKind::Trailer),
+            ("backend-002", ReferenceKind::TaskId),
+            ("task-12", ReferenceKind::Issue),
+        ]
+    );
+
+    // Only the last paragraph is a trailer block
+    assert!(trailer_task_ids("Task-Id: backend-001\n\nMore text").is_empty());
+    assert!(trailer_task_ids("Fix\n\nTask-Id: rm -rf /").is_empty());
+
+    assert_eq!(
+        task_trailers(&["api-001".to_string(), "api-002".to_string()]),
+        "Task-Id: api-001\nTask-Id: api-002"
+    );
+}
+
+#[test]
+fn test_trailer_references_raise_activity_confidence() {
+    let temp_repo = TestRepo::new().unwrap();
+    temp_repo
+        .add_commit("Complete api-001 implementation")
+        .unwrap();
+    temp_repo
+        .add_commit("Complete the login flow\n\nTask-Id: auth-v2-0001")
+        .unwrap();
+    let analyzer = GitAnalyzer::new(&temp_repo.repo_path).unwrap();
+
+    let activities = analyzer.analyze_task_activity(Some(10)).unwrap();
+    let confidence = |id: &str| {
+        activities
+            .iter()
+            .find(|a| a.task_id == id)
+            .map(|a| a.confidence)
+            .unwrap()
+    };
+    assert!(confidence("auth-v2-0001") > confidence("api-001"));
+}
+
+#[test]
+fn test_conventional_commit_scopes() {
+    use taskguard::git::{ReferenceKind, conventional_scopes};
+
+    assert_eq!(
+        conventional_scopes("feat(backend-001, api-002)!: add login"),
+        vec!["backend-001", "api-002"]
+    );
+    assert_eq!(
+        conventional_scopes("fix(auth): handle expiry"),
+        vec!["auth"]
+    );
+    assert!(conventional_scopes("Fix(auth) handling").is_empty());
+    assert!(conventional_scopes("chore: bump deps\n\nfeat(auth): no").is_empty());
+
+    let temp_repo = TestRepo::new().unwrap();
+    let analyzer = GitAnalyzer::new(&temp_repo.repo_path).unwrap();
+    let references = analyzer.extract_task_references("feat(backend-001): add login");
+    assert_eq!(references.len(), 1);
+    assert_eq!(references[0].task_id, "backend-001");
+    assert_eq!(references[0].kind, ReferenceKind::Scope);
+    // Scopes that aren't task IDs don't become references
+    assert!(
+        analyzer
+            .extract_task_ids("feat(auth-v2): add login")
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_scopes_map_commits_to_areas() {
+    use std::collections::HashMap;
+
+    let temp_repo = TestRepo::new().unwrap();
+    temp_repo.add_commit("feat(auth): add login form").unwrap();
+    temp_repo.add_commit("fix(Login): handle expiry").unwrap();
+    temp_repo
+        .add_commit("feat(auth-001): covered by a task")
+        .unwrap();
+    temp_repo.add_commit("docs(readme): unrelated").unwrap();
+
+    let scopes = HashMap::from([
+        ("auth".to_string(), "auth".to_string()),
+        ("login".to_string(), "auth".to_string()),
+    ]);
+    let analyzer = GitAnalyzer::new(&temp_repo.repo_path)
+        .unwrap()
+        .with_scope_areas(scopes);
+
+    assert_eq!(analyzer.scope_area("fix(login): x"), Some("auth"));
+    assert_eq!(analyzer.scope_area("docs(readme): x"), None);
+
+    let areas = analyzer.analyze_area_activity(Some(10)).unwrap();
+    assert_eq!(areas.len(), 1);
+    assert_eq!(areas[0].area, "auth");
+    assert_eq!(areas[0].commits.len(), 2);
+}
+
+#[test]
+fn test_branch_names_attribute_commits_to_tasks() {
+    let temp_repo = TestRepo::new().unwrap();
+    temp_repo.add_commit("Initial commit").unwrap();
+    let trunk = temp_repo.repo.head().unwrap().name().unwrap().to_string();
+    let analyzer = GitAnalyzer::new(&temp_repo.repo_path).unwrap();
+    assert_eq!(
+        analyzer.branch_task_ids("task/backend-001-login"),
+        vec!["backend-001"]
+    );
+
+    let head = temp_repo.repo.head().unwrap().peel_to_commit().unwrap();
+    temp_repo
+        .repo
+        .branch("task/backend-001-login", &head, false)
+        .unwrap();
+    temp_repo
+        .repo
+        .set_head("refs/heads/task/backend-001-login")
+        .unwrap();
+    temp_repo
+        .commit_file("src/login.rs", "fn login() {}", "Add login form")
+        .unwrap();
+    temp_repo
+        .commit_file("src/login.rs", "fn login() { todo!() }", "WIP validation")
+        .unwrap();
+    temp_repo.repo.set_head(&trunk).unwrap();
+
+    let activities = analyzer.analyze_task_activity(Some(10)).unwrap();
+    assert_eq!(activities.len(), 1);
+    let activity = &activities[0];
+    assert_eq!(activity.task_id, "backend-001");
+    assert_eq!(activity.commits.len(), 2);
+    assert!(
+        activity
+            .commits
+            .iter()
+            .all(|c| c.message != "Initial commit")
+    );
+    assert_eq!(activity.commits[0].message, "WIP validation");
+}
+
+#[test]
+fn test_branch_activity_lists_tasks_per_branch() {
+    let temp_repo = TestRepo::new().unwrap();
+    temp_repo.add_commit("Start backend-001").unwrap();
+    let trunk_ref = temp_repo.repo.head().unwrap().name().unwrap().to_string();
+    let trunk = trunk_ref.trim_start_matches("refs/heads/").to_string();
+
+    let head = temp_repo.repo.head().unwrap().peel_to_commit().unwrap();
+    temp_repo
+        .repo
+        .branch("task/backend-001-login", &head, false)
+        .unwrap();
+    temp_repo
+        .repo
+        .set_head("refs/heads/task/backend-001-login")
+        .unwrap();
+    temp_repo
+        .commit_file(
+            "src/login.rs",
+            "fn login() {}",
+            "Add login form for api-002",
+        )
+        .unwrap();
+    temp_repo.repo.set_head(&trunk_ref).unwrap();
+
+    let analyzer = GitAnalyzer::new(&temp_repo.repo_path).unwrap();
+    let activities = analyzer.analyze_branch_activity(Some(10)).unwrap();
+    assert_eq!(activities.len(), 2);
+
+    let trunk_activity = activities.iter().find(|a| a.branch == trunk).unwrap();
+    assert_eq!(trunk_activity.commits, 1);
+    assert_eq!(trunk_activity.tasks.get("backend-001"), Some(&1));
+
+    // Only the commit not on the trunk counts, for the branch's task and the one it names
+    let task_activity = activities
+        .iter()
+        .find(|a| a.branch == "task/backend-001-login")
+        .unwrap();
+    assert_eq!(task_activity.commits, 1);
+    assert_eq!(task_activity.tasks.get("backend-001"), Some(&1));
+    assert_eq!(task_activity.tasks.get("api-002"), Some(&1));
+}
+
+#[test]
+fn test_merged_pull_requests_signal_completion() {
+    use taskguard::git::{is_completion_merge, merged_branch};
+
+    assert_eq!(
+        merged_branch("Merge pull request #42 from acme/task/backend-001-login"),
+        Some("task/backend-001-login".to_string())
+    );
+    assert_eq!(
+        merged_branch("Merge remote-tracking branch 'origin/backend-001'"),
+        Some("backend-001".to_string())
+    );
+    assert_eq!(
+        merged_branch("Merge branch 'backend-001' into 'main'"),
+        Some("backend-001".to_string())
+    );
+    assert_eq!(
+        merged_branch("Merged in backend-001 (pull request #7)"),
+        Some("backend-001".to_string())
+    );
+    assert_eq!(merged_branch("Merge the backend-001 changes"), None);
+    // Bringing a task branch up to date isn't completion
+    assert!(!is_completion_merge(
+        "Merge branch 'main' into task/backend-001-login"
+    ));
+
+    let temp_repo = TestRepo::new().unwrap();
+    let analyzer = GitAnalyzer::new(&temp_repo.repo_path).unwrap();
+    let commit = |message: &str| TaskCommit {
+        oid: "abc123".to_string(),
+        message: message.to_string(),
+        author: "test".to_string(),
+        timestamp: Utc::now(),
+        task_ids: vec!["backend-001".to_string()],
+        submodule: None,
+    };
+
+    // The merge settles it even after several work-in-progress commits
+    let commits = vec![
+        commit("Merge pull request #42 from acme/task/backend-001-login"),
+        commit("WIP backend-001 implement validation"),
+        commit("Implement backend-001 form"),
+        commit("Start backend-001"),
+    ];
+    assert_eq!(
+        analyzer.suggest_status(&commits),
+        (Some("done".to_string()), 1.0)
+    );
+
+    // Older than the latest commit, a merge still outweighs a keyword
+    let commits = vec![
+        commit("Add backend-001 tests"),
+        commit("Merge branch 'task/backend-001-login'"),
+    ];
+    assert_eq!(
+        analyzer.suggest_status(&commits).0,
+        Some("done".to_string())
+    );
+
+    let commits = vec![commit("Merge branch 'main' into task/backend-001-login")];
+    assert_ne!(
+        analyzer.suggest_status(&commits).0,
+        Some("done".to_string())
+    );
+}
+
+#[test]
+fn test_task_file_map_from_commit_diffs() {
+    let temp_repo = TestRepo::new().unwrap();
+    temp_repo
+        .commit_file("src/login.rs", "fn login() {}", "Start backend-001 login")
+        .unwrap();
+    temp_repo
+        .commit_file(
+            "src/login.rs",
+            "fn login() { check() }",
+            "backend-001 validation",
+        )
+        .unwrap();
+    let oid = temp_repo
+        .commit_file(
+            "src/db.rs",
+            "fn db() {}",
+            "Add db for backend-002 and backend-001",
+        )
+        .unwrap();
+    temp_repo
+        .commit_file("README.md", "docs", "Update docs")
+        .unwrap();
+
+    let analyzer = GitAnalyzer::new(&temp_repo.repo_path).unwrap();
+    assert_eq!(
+        analyzer.commit_files(&oid.to_string()).unwrap(),
+        vec!["src/db.rs"]
+    );
+
+    let map = analyzer.task_file_map(Some(10)).unwrap();
+    let backend_001 = &map["backend-001"];
+    assert_eq!(backend_001.get("src/login.rs"), Some(&2));
+    assert_eq!(backend_001.get("src/db.rs"), Some(&1));
+    assert!(!backend_001.contains_key("README.md"));
+    assert_eq!(map["backend-002"].len(), 1);
+}
+
+#[test]
+fn test_dominant_author_ignores_merges() {
+    let commit = |author: &str, message: &str| TaskCommit {
+        oid: "0".repeat(40),
+        message: message.to_string(),
+        author: author.to_string(),
+        timestamp: Utc::now(),
+        task_ids: vec!["backend-001".to_string()],
+        submodule: None,
+    };
+    let mut activity = TaskActivity {
+        task_id: "backend-001".to_string(),
+        commits: vec![
+            commit(
+                "Maintainer",
+                "Merge pull request #4 from acme/task/backend-001",
+            ),
+            commit("Maintainer", "Merge branch 'task/backend-001'"),
+            commit("Alice", "Start backend-001"),
+            commit("Alice", "Finish backend-001"),
+            commit("Bob", "Fix typo in backend-001"),
+        ],
+        last_activity: None,
+        suggested_status: None,
+        confidence: 0.0,
+        merged_branch: None,
+        diff_stats: DiffStats::default(),
+    };
+    assert_eq!(activity.dominant_authors(), vec![("Alice".to_string(), 2)]);
+
+    // No majority, and a single commit isn't enough
+    activity.commits.push(commit("Bob", "Test backend-001"));
+    assert!(activity.dominant_authors().is_empty());
+    activity.commits.truncate(3);
+    assert!(activity.dominant_authors().is_empty());
+
+    // Pair-programmed commits credit both people
+    activity.commits.truncate(2);
+    for message in ["Start backend-001", "Finish backend-001"] {
+        activity.commits.push(commit(
+            "Alice",
+            &format!("{}\n\nCo-authored-by: Bob <bob@example.com>", message),
+        ));
+    }
+    assert_eq!(
+        activity.dominant_authors(),
+        vec![("Alice".to_string(), 2), ("Bob".to_string(), 2)]
+    );
+}
+
+#[test]
+fn test_co_author_trailers() {
+    let message = "Add login\n\nDetails here\n\nCo-authored-by: Bob Smith <bob@example.com>\nco-authored-by: <carol@example.com>\nTask-Id: backend-001";
+    assert_eq!(co_authors(message), vec!["Bob Smith", "carol@example.com"]);
+    // Only the trailer block counts
+    assert!(co_authors("Co-authored-by: Bob <bob@example.com>\n\nAdd login").is_empty());
+}
+
+#[test]
+fn test_remote_analysis_uses_remote_default_branch() -> Result<()> {
+    let test_repo = TestRepo::new()?;
+    let develop = test_repo.commit_file("src/a.rs", "a", "Start backend-001")?;
+    let release = test_repo.commit_file("src/b.rs", "b", "Complete backend-002")?;
+    test_repo.commit_file("src/c.rs", "c", "Work on frontend-001")?;
+
+    // A clone of a remote whose default branch is develop, with a release branch
+    let repo = &test_repo.repo;
+    repo.reference("refs/remotes/origin/develop", develop, true, "fetch")?;
+    repo.reference("refs/remotes/origin/release/1.0", release, true, "fetch")?;
+    repo.reference_symbolic(
+        "refs/remotes/origin/HEAD",
+        "refs/remotes/origin/develop",
+        true,
+        "clone",
+    )?;
+
+    let analyzer = GitAnalyzer::new(&test_repo.repo_path)?;
+    assert_eq!(analyzer.remote_branch("origin", None)?, "develop");
+    assert_eq!(
+        analyzer.remote_branch("origin", Some("origin/release/1.0"))?,
+        "release/1.0"
+    );
+    let missing = analyzer.remote_branch("origin", Some("main")).unwrap_err();
+    assert!(missing.to_string().contains("develop, release/1.0"));
+
+    // No "origin" remote to fetch from, so the tracking refs above are used
+    let ids = |activities: Vec<TaskActivity>| {
+        let mut ids: Vec<String> = activities.into_iter().map(|a| a.task_id).collect();
+        ids.sort();
+        ids
+    };
+    let default = analyzer.analyze_remote_task_activity("origin", None, Some(10))?;
+    assert_eq!(ids(default), vec!["backend-001"]);
+    let release = analyzer.analyze_remote_task_activity("origin", Some("release/1.0"), Some(10))?;
+    assert_eq!(ids(release), vec!["backend-001", "backend-002"]);
+    Ok(())
+}
+
+#[test]
+fn test_commit_cache_reuses_parsed_commits() -> Result<()> {
+    let test_repo = TestRepo::new()?;
+    test_repo.add_commit("Start backend-001")?;
+    test_repo.add_commit("Unrelated cleanup")?;
+    let root = test_repo.repo_path.clone();
+
+    let analyzer = GitAnalyzer::new(&root)?.with_cache(GitCache::load(&root));
+    assert_eq!(analyzer.analyze_task_activity(Some(10))?.len(), 1);
+    assert_eq!(analyzer.cache_hits(), Some(0));
+    analyzer.save_cache(&root)?;
+    assert!(root.join(git_cache::CACHE_FILE).exists());
+
+    // Same tip: the whole walk comes from the cache
+    let analyzer = GitAnalyzer::new(&root)?.with_cache(GitCache::load(&root));
+    let activities = analyzer.analyze_task_activity(Some(10))?;
+    assert_eq!(activities[0].task_id, "backend-001");
+    assert_eq!(activities[0].commits[0].message.trim(), "Start backend-001");
+    assert_eq!(analyzer.cache_hits(), Some(2));
+
+    // New tip: only the new commit is parsed
+    test_repo.add_commit("Complete frontend-001")?;
+    let analyzer = GitAnalyzer::new(&root)?.with_cache(GitCache::load(&root));
+    let mut ids: Vec<String> = analyzer
+        .analyze_task_activity(Some(10))?
+        .into_iter()
+        .map(|a| a.task_id)
+        .collect();
+    ids.sort();
+    assert_eq!(ids, vec!["backend-001", "frontend-001"]);
+    assert_eq!(analyzer.cache_hits(), Some(2));
+    Ok(())
+}
+
+#[test]
+fn test_task_anchors_scan_back_to_creation() -> Result<()> {
+    let test_repo = TestRepo::new()?;
+    test_repo.add_commit("Start backend-002")?;
+    test_repo.add_commit("Fix backend-001")?;
+    test_repo.add_commit("Unrelated cleanup")?;
+
+    // backend-001 is created after its commit, so that commit is about an older task
+    let task = |id: &str, created: &str| {
+        Task::parse_content(&format!(
+            "---\nid: {}\ntitle: T\narea: backend\ncreated: {}\n---\n\nBody\n",
+            id, created
+        ))
+    };
+    let tomorrow = (Utc::now() + chrono::Duration::days(1)).to_rfc3339();
+    let tasks = vec![
+        task("backend-001", &tomorrow)?,
+        task("backend-002", "2020-01-01T00:00:00Z")?,
+    ];
+
+    let analyzer = GitAnalyzer::new(&test_repo.repo_path)?;
+    assert!(analyzer.analyze_task_activity(Some(1))?.is_empty());
+
+    let analyzer = GitAnalyzer::new(&test_repo.repo_path)?.with_task_anchors(&tasks);
+    let activities = analyzer.analyze_task_activity(Some(1))?;
+    let ids: Vec<&str> = activities.iter().map(|a| a.task_id.as_str()).collect();
+    assert_eq!(ids, vec!["backend-002"]);
+    Ok(())
+}
+
+#[test]
+fn test_activity_diff_stats() -> Result<()> {
+    let test_repo = TestRepo::new()?;
+    test_repo.commit_file("src/a.rs", "one\ntwo\nthree\n", "Start backend-001")?;
+    test_repo.commit_file("src/a.rs", "one\n2\nthree\n", "Fix backend-001")?;
+    test_repo.commit_file("src/b.rs", "b\n", "Finish backend-001")?;
+    test_repo.commit_file("tasks/backend/backend-001.md", "x\n", "Close backend-001")?;
+
+    let analyzer = GitAnalyzer::new(&test_repo.repo_path)?;
+    let activities = analyzer.analyze_task_activity(Some(10))?;
+    // Task files don't count as changes
+    assert_eq!(
+        activities[0].diff_stats,
+        DiffStats {
+            files_changed: 2,
+            insertions: 5,
+            deletions: 1,
+        }
+    );
+    assert_eq!(activities[0].diff_stats.to_string(), "+5 -1 in 2 files");
+    Ok(())
+}
+
+#[test]
+fn test_reverted_commit_parsing() {
+    assert_eq!(
+        reverted_commit(
+            "Revert \"feat(backend-001): login\"\n\nThis reverts commit 1234567abcdef.\n"
+        ),
+        Some("1234567abcdef")
+    );
+    assert_eq!(reverted_commit("Revert the login change"), None);
+    assert_eq!(reverted_commit("This reverts commit abc."), None);
+}
+
+#[test]
+fn test_find_reverts() -> Result<()> {
+    let test_repo = TestRepo::new()?;
+    test_repo.commit_file("src/a.rs", "a\n", "Add a")?;
+    test_repo.commit_file("src/b.rs", "b\n", "Add b")?;
+    let login = test_repo.commit_file("src/a.rs", "a\nlogin\n", "Finish backend-001 login")?;
+    let feature = test_repo.commit_file("src/b.rs", "b\nfeature\n", "Finish backend-002")?;
+    test_repo.commit_file("src/c.rs", "c\n", "Finish backend-003")?;
+
+    // `git revert` style, and the same change undone by hand
+    test_repo.commit_file(
+        "src/a.rs",
+        "a\n",
+        &format!(
+            "Revert \"Finish backend-001 login\"\n\nThis reverts commit {}.\n",
+            login
+        ),
+    )?;
+    test_repo.commit_file("src/b.rs", "b\n", "Drop the feature again")?;
+
+    let analyzer = GitAnalyzer::new(&test_repo.repo_path)?;
+    let activities = analyzer.analyze_task_activity(Some(20))?;
+    let reverts = analyzer.find_reverts(&activities, 20)?;
+
+    let backend_001 = &reverts["backend-001"];
+    assert_eq!(backend_001.len(), 1);
+    assert_eq!(backend_001[0].reverted, login.to_string());
+    assert_eq!(backend_001[0].matched, RevertMatch::Message);
+    let backend_002 = &reverts["backend-002"];
+    assert_eq!(backend_002.len(), 1);
+    assert_eq!(backend_002[0].reverted, feature.to_string());
+    assert_eq!(backend_002[0].matched, RevertMatch::PatchId);
+    assert!(!reverts.contains_key("backend-003"));
+    Ok(())
+}
+
+#[test]
+fn test_path_glob_patterns() -> Result<()> {
+    let glob = glob_regex("services/api/**")?;
+    assert!(glob.is_match("services/api/src/main.rs"));
+    assert!(!glob.is_match("services/apis/main.rs"));
+    assert!(glob_regex("web/")?.is_match("web/src/app.tsx"));
+    assert!(glob_regex("**/*.sql")?.is_match("db/migrations/001.sql"));
+    assert!(glob_regex("**/*.sql")?.is_match("schema.sql"));
+    assert!(!glob_regex("docs/*.md")?.is_match("docs/api/index.md"));
+    Ok(())
+}
+
+#[test]
+fn test_area_activity_from_changed_paths() -> Result<()> {
+    let test_repo = TestRepo::new()?;
+    test_repo.commit_file("services/api/main.rs", "fn main() {}", "Tweak handler")?;
+    test_repo.commit_file("services/api/auth/mod.rs", "mod auth;", "Rework sessions")?;
+    test_repo.commit_file("web/app.tsx", "app", "Polish header")?;
+    test_repo.commit_file("services/api/main.rs", "fn main() { }", "Fix backend-001")?;
+    test_repo.commit_file("README.md", "readme", "Update readme")?;
+
+    let paths = BTreeMap::from([
+        ("services/api/**".to_string(), "backend".to_string()),
+        ("services/api/auth/".to_string(), "auth".to_string()),
+        ("web/**".to_string(), "frontend".to_string()),
+    ]);
+    let analyzer = GitAnalyzer::new(&test_repo.repo_path)?.with_path_areas(&paths)?;
+    // The most specific pattern wins
+    assert_eq!(analyzer.path_area("services/api/auth/mod.rs"), Some("auth"));
+
+    let areas = analyzer.analyze_area_activity(Some(10))?;
+    let counts: BTreeMap<&str, usize> = areas
+        .iter()
+        .map(|a| (a.area.as_str(), a.commits.len()))
+        .collect();
+    assert_eq!(
+        counts,
+        BTreeMap::from([("auth", 1), ("backend", 1), ("frontend", 1)])
+    );
+    Ok(())
+}
+
+#[test]
+fn test_submodule_commits_count_for_tasks() -> Result<()> {
+    let library = TestRepo::new()?;
+    library.commit_file(
+        "src/lib.rs",
+        "pub fn parse() {}\n",
+        "Finish backend-002 parser",
+    )?;
+
+    let test_repo = TestRepo::new()?;
+    test_repo.add_commit("Start backend-001")?;
+    let output = std::process::Command::new("git")
+        .args(["-c", "protocol.file.allow=always", "submodule", "add", "-q"])
+        .arg(&library.repo_path)
+        .arg("libs/parser")
+        .current_dir(&test_repo.repo_path)
+        .output()?;
+    assert!(output.status.success(), "{:?}", output);
+
+    let analyzer = GitAnalyzer::new(&test_repo.repo_path)?;
+    let activities = analyzer.analyze_task_activity(Some(10))?;
+    assert!(!activities.iter().any(|a| a.task_id == "backend-002"));
+
+    let analyzer = GitAnalyzer::new(&test_repo.repo_path)?.with_submodules()?;
+    let activities = analyzer.analyze_task_activity(Some(10))?;
+    let parser = activities
+        .iter()
+        .find(|a| a.task_id == "backend-002")
+        .expect("submodule commit attributed");
+    assert_eq!(parser.commits[0].submodule.as_deref(), Some("libs/parser"));
+    assert_eq!(parser.diff_stats.files_changed, 1);
+    let backend_001 = activities.iter().find(|a| a.task_id == "backend-001");
+    assert!(backend_001.is_some_and(|a| a.commits[0].submodule.is_none()));
+    Ok(())
+}
+
+#[test]
+fn test_working_tree_changes() -> Result<()> {
+    let test_repo = TestRepo::new()?;
+    test_repo.commit_file(
+        "src/auth.rs",
+        "fn login() {}\n",
+        "Add login for backend-001",
+    )?;
+    test_repo.commit_file("src/db.rs", "fn connect() {}\n", "Add db for backend-002")?;
+
+    fs::write(
+        test_repo.repo_path.join("src/auth.rs"),
+        "fn login() { todo!() }\n",
+    )?;
+    fs::write(
+        test_repo.repo_path.join("src/db.rs"),
+        "fn connect() { todo!() }\n",
+    )?;
+    fs::create_dir_all(test_repo.repo_path.join("tasks/backend"))?;
+    fs::write(
+        test_repo.repo_path.join("tasks/backend/backend-001.md"),
+        "---\n",
+    )?;
+    let mut index = test_repo.repo.index()?;
+    index.add_path(std::path::Path::new("src/db.rs"))?;
+    index.write()?;
+
+    let analyzer = GitAnalyzer::new(&test_repo.repo_path)?;
+    let mut changes = analyzer.working_tree_changes()?;
+    changes.sort_by(|a, b| a.path.cmp(&b.path));
+    assert_eq!(
+        changes,
+        vec![
+            WorkingChange {
+                path: "src/auth.rs".to_string(),
+                staged: false,
+            },
+            WorkingChange {
+                path: "src/db.rs".to_string(),
+                staged: true,
+            },
+        ]
+    );
+    Ok(())
+}
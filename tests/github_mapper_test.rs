@@ -1,6 +1,9 @@
+use chrono::NaiveDate;
 use chrono::Utc;
+use std::collections::BTreeMap;
 use std::fs;
-use taskguard::github::mapper::{IssueMapping, TaskIssueMapper};
+use taskguard::github::mapper::{IssueMapping, NOTES_REF, TaskIssueMapper};
+use taskguard::github::{GitHubConfig, LabelConfig, ProjectField, ProjectIteration};
 use taskguard::task::TaskStatus;
 use tempfile::TempDir;
 
@@ -8,10 +11,17 @@ fn create_test_mapping(task_id: &str, issue_number: i64, archived: bool) -> Issu
     IssueMapping {
         task_id: task_id.to_string(),
         issue_number,
+        repo: None,
         issue_id: format!("issue_id_{}", issue_number),
         project_item_id: format!("project_item_{}", issue_number),
         synced_at: Utc::now().to_rfc3339(),
         is_archived: archived,
+        labels: Vec::new(),
+        assignees: Vec::new(),
+        milestone: None,
+        fields: BTreeMap::new(),
+        checked: Vec::new(),
+        description_hash: None,
     }
 }
 
@@ -159,6 +169,43 @@ fn test_persistence() {
     assert!(task2.is_archived);
 }
 
+#[test]
+fn test_persistence_in_git_notes() {
+    let temp_dir = TempDir::new().unwrap();
+    let repo = git2::Repository::init(temp_dir.path()).unwrap();
+    let mut config = repo.config().unwrap();
+    config.set_str("user.name", "Test User").unwrap();
+    config.set_str("user.email", "test@example.com").unwrap();
+
+    {
+        let mut mapper = TaskIssueMapper::with_notes(temp_dir.path().to_path_buf());
+        mapper
+            .add_mapping(create_test_mapping("task-001", 1, false))
+            .unwrap();
+        mapper
+            .add_mapping(create_test_mapping("task-002", 2, true))
+            .unwrap();
+    }
+
+    // Nothing is written to the working tree
+    assert!(!temp_dir.path().join(".taskguard").exists());
+    assert!(repo.find_reference(NOTES_REF).is_ok());
+
+    let mut mapper = TaskIssueMapper::with_notes(temp_dir.path().to_path_buf());
+    mapper.load().unwrap();
+    assert_eq!(mapper.get_all_mappings().len(), 2);
+    assert_eq!(mapper.get_by_task_id("task-001").unwrap().issue_number, 1);
+    assert!(mapper.get_by_task_id("task-002").unwrap().is_archived);
+
+    // Removing a mapping deletes its note
+    mapper.remove_mapping("task-001").unwrap();
+    let mut mapper = TaskIssueMapper::with_notes(temp_dir.path().to_path_buf());
+    mapper.load().unwrap();
+    assert_eq!(mapper.get_all_mappings().len(), 1);
+    assert!(mapper.get_by_task_id("task-001").is_none());
+    assert_eq!(repo.notes(Some(NOTES_REF)).unwrap().count(), 1);
+}
+
 #[test]
 fn test_json_serialization() {
     let temp_dir = TempDir::new().unwrap();
@@ -495,3 +542,506 @@ fn test_empty_mapper() {
     assert!(mapper.get_by_issue_number(1).is_none());
     assert!(mapper.get_by_project_item_id("any").is_none());
 }
+
+#[test]
+fn test_merge_names_keeps_changes_from_both_sides() {
+    let names = |names: &[&str]| names.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+    let base = names(&["backend", "bug", "perf"]);
+
+    // Tag "perf" removed locally, label "bug" removed and "urgent" added on GitHub
+    let local = names(&["backend", "bug", "api"]);
+    let remote = names(&["Backend", "perf", "urgent"]);
+    assert_eq!(
+        TaskIssueMapper::merge_names(&base, &local, &remote),
+        names(&["backend", "api", "urgent"])
+    );
+
+    // Nothing recorded yet: the union
+    assert_eq!(
+        TaskIssueMapper::merge_names(&[], &names(&["a"]), &names(&["A", "b"])),
+        names(&["a", "b"])
+    );
+    assert_eq!(
+        TaskIssueMapper::merge_names(&base, &base, &base),
+        base.clone()
+    );
+}
+
+#[test]
+fn test_label_config_from_github_toml() {
+    let config: GitHubConfig = toml::from_str(
+        "owner = \"o\"\nrepo = \"r\"\nproject_number = 1\n\n[labels]\nexclude = [\"triage\"]\ncolor = \"#0e8a16\"\n\n[labels.colors]\nsecurity = \"d73a4a\"\n",
+    )
+    .unwrap();
+    assert!(!config.labels.is_synced("Triage"));
+    assert!(config.labels.is_synced("security"));
+    assert_eq!(config.labels.color("Security"), "d73a4a");
+    assert_eq!(config.labels.color("docs"), "0e8a16");
+
+    let config: GitHubConfig =
+        toml::from_str("owner = \"o\"\nrepo = \"r\"\nproject_number = 1\n").unwrap();
+    assert_eq!(config.labels, LabelConfig::default());
+    assert_eq!(config.labels.color("docs"), LabelConfig::DEFAULT_COLOR);
+}
+
+#[test]
+fn test_users_from_github_toml() {
+    let config: GitHubConfig = toml::from_str(
+        "owner = \"o\"\nrepo = \"r\"\nproject_number = 1\n\n[users]\nalice = \"alice-gh\"\nBob = \"bobby\"\n",
+    )
+    .unwrap();
+    assert_eq!(config.login("alice"), Some("alice-gh"));
+    assert_eq!(config.login("bob"), Some("bobby"));
+    assert_eq!(config.login("carol"), None);
+    assert_eq!(config.user("Alice-GH"), Some("alice"));
+    assert_eq!(config.user("octocat"), None);
+}
+
+#[test]
+fn test_merge_value_prefers_local_changes() {
+    // Changed locally: local wins, even over a change on GitHub
+    assert_eq!(
+        TaskIssueMapper::merge_value(Some("v1"), Some("v2"), Some("v3")),
+        Some("v2".to_string())
+    );
+    // Changed or cleared on GitHub only
+    assert_eq!(
+        TaskIssueMapper::merge_value(Some("v1"), Some("v1"), Some("v3")),
+        Some("v3".to_string())
+    );
+    assert_eq!(
+        TaskIssueMapper::merge_value(Some("v1"), Some("v1"), None),
+        None
+    );
+    // Cleared locally
+    assert_eq!(
+        TaskIssueMapper::merge_value(Some("v1"), None, Some("v1")),
+        None
+    );
+}
+
+#[test]
+fn test_field_mapping_from_github_toml() {
+    let config: GitHubConfig = toml::from_str(
+        "owner = \"o\"\nrepo = \"r\"\nproject_number = 1\n\n[field_mapping]\npriority = \"Priority\"\nestimate = \"Estimate\"\n",
+    )
+    .unwrap();
+    assert_eq!(config.field_mapping["priority"], "Priority");
+    assert_eq!(config.field_mapping["estimate"], "Estimate");
+    assert!(!config.field_mapping.contains_key("complexity"));
+}
+
+#[test]
+fn test_project_field_values() {
+    let field = |data_type: &str| ProjectField {
+        id: "field".to_string(),
+        name: "Field".to_string(),
+        data_type: data_type.to_string(),
+        options: vec![
+            ("opt_high".to_string(), "High".to_string()),
+            ("opt_low".to_string(), "Low".to_string()),
+        ],
+        iterations: Vec::new(),
+    };
+
+    let select = field("SINGLE_SELECT");
+    assert_eq!(
+        select.value_for("high"),
+        Some(serde_json::json!({ "singleSelectOptionId": "opt_high" }))
+    );
+    assert_eq!(select.value_for("critical"), None);
+
+    let number = field("NUMBER");
+    assert_eq!(
+        number.value_for("7"),
+        Some(serde_json::json!({ "number": 7.0 }))
+    );
+    assert_eq!(
+        number.value_for("2.5d"),
+        Some(serde_json::json!({ "number": 2.5 }))
+    );
+    assert_eq!(number.value_for("soon"), None);
+
+    assert_eq!(
+        field("TEXT").value_for("4h"),
+        Some(serde_json::json!({ "text": "4h" }))
+    );
+    assert_eq!(field("DATE").value_for("4h"), None);
+}
+
+#[test]
+fn test_due_date_project_field_values() {
+    let date = ProjectField {
+        id: "due".to_string(),
+        name: "Due".to_string(),
+        data_type: "DATE".to_string(),
+        options: Vec::new(),
+        iterations: Vec::new(),
+    };
+    assert_eq!(
+        date.value_for("2026-03-05"),
+        Some(serde_json::json!({ "date": "2026-03-05" }))
+    );
+    assert_eq!(date.value_for("next week"), None);
+
+    let sprint = |id: &str, start: &str| ProjectIteration {
+        id: id.to_string(),
+        title: id.to_string(),
+        start_date: NaiveDate::parse_from_str(start, "%Y-%m-%d").unwrap(),
+        duration: 14,
+    };
+    let iteration = ProjectField {
+        id: "sprint".to_string(),
+        name: "Sprint".to_string(),
+        data_type: "ITERATION".to_string(),
+        options: Vec::new(),
+        iterations: vec![sprint("s1", "2026-03-02"), sprint("s2", "2026-03-16")],
+    };
+    assert_eq!(
+        iteration.value_for("2026-03-15"),
+        Some(serde_json::json!({ "iterationId": "s1" }))
+    );
+    assert_eq!(
+        iteration.value_for("2026-03-16"),
+        Some(serde_json::json!({ "iterationId": "s2" }))
+    );
+    // Outside every iteration
+    assert_eq!(iteration.value_for("2026-04-01"), None);
+}
+
+#[test]
+fn test_sync_filter_scope() {
+    use taskguard::config::{SyncConfig, SyncFilter};
+    use taskguard::task::Task;
+
+    // The [sync] table of config.toml
+    let sync: SyncConfig =
+        toml::from_str("[filter]\nexclude_areas = [\"personal\"]\nexclude_tags = [\"internal\"]\n")
+            .unwrap();
+    let filter = sync.filter;
+    let task = |area: &str, status: &str, tags: &str| {
+        Task::parse_content(&format!(
+            "---\nid: {area}-001\ntitle: T\nstatus: {status}\narea: {area}\ntags: [{tags}]\n---\n\nBody\n"
+        ))
+        .unwrap()
+    };
+
+    assert!(filter.matches(&task("backend", "todo", "api")));
+    assert!(!filter.matches(&task("personal", "todo", "api")));
+    assert!(!filter.matches(&task("backend", "todo", "api, Internal")));
+    assert!(filter.matches_issue(&["bug".to_string()], "todo"));
+    assert!(!filter.matches_issue(&["personal".to_string()], "todo"));
+
+    // Lists given on the command line replace the configured ones
+    let filter = filter.overridden_by(SyncFilter {
+        areas: vec!["backend".to_string()],
+        statuses: vec!["doing".to_string()],
+        exclude_tags: vec!["wip".to_string()],
+        ..SyncFilter::default()
+    });
+    assert_eq!(filter.exclude_areas, vec!["personal"]);
+    assert!(filter.matches(&task("backend", "doing", "internal")));
+    assert!(!filter.matches(&task("backend", "todo", "")));
+    assert!(!filter.matches(&task("backend", "doing", "wip")));
+    assert!(!filter.matches_issue(&["bug".to_string()], "doing"));
+    assert!(filter.matches_issue(&["Backend".to_string()], "doing"));
+    assert!(SyncFilter::default().is_empty());
+}
+
+#[test]
+fn test_routes_to_multiple_repositories() {
+    let config: GitHubConfig = toml::from_str(
+        "owner = \"org\"\nrepo = \"api-repo\"\nproject_number = 1\n\n[routes]\nfrontend = \"org/web-repo\"\n\"frontend/legacy\" = \"org/old-web\"\nbackend = \"org/api-repo\"\n",
+    )
+    .unwrap();
+
+    assert_eq!(config.route("frontend"), Some("org/web-repo"));
+    assert_eq!(config.route("frontend/forms"), Some("org/web-repo"));
+    assert_eq!(config.route("frontend/legacy"), Some("org/old-web"));
+    // Routes to the configured repository and unrouted areas stay there
+    assert_eq!(config.route("backend"), None);
+    assert_eq!(config.route("docs"), None);
+
+    let targets = config.targets();
+    let repos: Vec<(&str, &str)> = targets.iter().map(GitHubConfig::issue_repo).collect();
+    assert_eq!(
+        repos,
+        vec![("org", "api-repo"), ("org", "old-web"), ("org", "web-repo")]
+    );
+    assert_eq!(targets[0].target, None);
+    assert_eq!(targets[2].target.as_deref(), Some("org/web-repo"));
+
+    // Issue numbers only identify an issue together with its repository
+    let temp_dir = TempDir::new().unwrap();
+    let mut mapper = TaskIssueMapper::with_path(temp_dir.path().join("mapping.json"));
+    mapper
+        .add_mapping(create_test_mapping("backend-001", 7, false))
+        .unwrap();
+    mapper
+        .add_mapping(IssueMapping {
+            repo: Some("org/web-repo".to_string()),
+            ..create_test_mapping("frontend-001", 7, false)
+        })
+        .unwrap();
+    assert_eq!(
+        mapper.get_by_issue_number(7).unwrap().task_id,
+        "backend-001"
+    );
+    assert_eq!(
+        mapper
+            .get_by_issue(Some("Org/Web-Repo"), 7)
+            .unwrap()
+            .task_id,
+        "frontend-001"
+    );
+    assert!(mapper.get_by_issue(Some("org/old-web"), 7).is_none());
+
+    // Mappings written before routing belong to the configured repository
+    let legacy: IssueMapping = serde_json::from_str(
+        r#"{"task_id":"a-001","issue_number":1,"issue_id":"i","project_item_id":"","synced_at":"","is_archived":false}"#,
+    )
+    .unwrap();
+    assert_eq!(legacy.repo, None);
+    assert!(!serde_json::to_string(&legacy).unwrap().contains("\"repo\""));
+}
+
+#[test]
+fn test_rate_limit_backoff_and_resume_state() {
+    use chrono::{DateTime, Duration as ChronoDuration};
+    use reqwest::header::{HeaderMap, HeaderValue};
+    use std::time::Duration;
+    use taskguard::github::rate_limit::{
+        Limits, RESUME_FILE, RateLimited, ResumeState, is_rate_limited,
+    };
+
+    let now = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+    let mut headers = HeaderMap::new();
+    headers.insert("x-ratelimit-remaining", HeaderValue::from_static("0"));
+    headers.insert("x-ratelimit-reset", HeaderValue::from_static("1700000030"));
+    let limits = Limits::from_headers(&headers);
+    assert!(limits.exhausted());
+    assert_eq!(limits.reset_at, Some(now + ChronoDuration::seconds(30)));
+    // Used up: wait for the reset
+    assert_eq!(limits.wait(0, now), Duration::from_secs(31));
+
+    // Secondary limits: Retry-After wins, else exponential backoff
+    headers.insert("retry-after", HeaderValue::from_static("7"));
+    assert_eq!(
+        Limits::from_headers(&headers).wait(3, now),
+        Duration::from_secs(7)
+    );
+    let plenty = Limits {
+        remaining: Some(4000),
+        ..Limits::default()
+    };
+    assert_eq!(plenty.wait(0, now), Duration::from_secs(2));
+    assert_eq!(plenty.wait(3, now), Duration::from_secs(16));
+
+    let message = |text: &str| serde_json::json!({ "message": text });
+    assert!(is_rate_limited(429, &serde_json::json!({})));
+    assert!(is_rate_limited(
+        403,
+        &message("You have exceeded a secondary rate limit")
+    ));
+    assert!(!is_rate_limited(403, &message("Resource not accessible")));
+    assert!(is_rate_limited(
+        200,
+        &serde_json::json!({ "errors": [{ "type": "RATE_LIMITED", "message": "API rate limit exceeded" }] })
+    ));
+    assert!(!is_rate_limited(
+        200,
+        &serde_json::json!({ "errors": [{ "type": "NOT_FOUND" }] })
+    ));
+
+    let error = anyhow::Error::new(RateLimited {
+        reset_at: limits.reset_at,
+    })
+    .context("Failed to create issue");
+    assert!(error.downcast_ref::<RateLimited>().is_some());
+    assert!(format!("{:#}", error).contains("resets at 22:13 UTC"));
+
+    let temp_dir = TempDir::new().unwrap();
+    assert_eq!(ResumeState::load(temp_dir.path()), None);
+    let state = ResumeState {
+        interrupted_at: now,
+        reset_at: limits.reset_at,
+        pending: vec!["backend-002".to_string(), "backend-003".to_string()],
+    };
+    state.save(temp_dir.path()).unwrap();
+    assert!(temp_dir.path().join(RESUME_FILE).exists());
+    assert_eq!(ResumeState::load(temp_dir.path()), Some(state));
+    ResumeState::clear(temp_dir.path()).unwrap();
+    assert_eq!(ResumeState::load(temp_dir.path()), None);
+}
+
+#[test]
+fn test_sync_plan_json_round_trip_and_table() {
+    use taskguard::github::{PlanAction, PlanEntry, SyncPlan};
+
+    let plan = SyncPlan {
+        entries: vec![
+            PlanEntry {
+                task_id: "backend-001".to_string(),
+                title: "Add login".to_string(),
+                action: PlanAction::Create,
+                repo: "acme/app".to_string(),
+                issue: None,
+                changes: Vec::new(),
+            },
+            PlanEntry {
+                task_id: "frontend-002".to_string(),
+                title: "Fix header".to_string(),
+                action: PlanAction::Update,
+                repo: "acme/web".to_string(),
+                issue: Some(42),
+                changes: vec!["state OPEN → CLOSED".to_string(), "labels +ui".to_string()],
+            },
+            PlanEntry {
+                task_id: "docs-003".to_string(),
+                title: "Write guide".to_string(),
+                action: PlanAction::Conflict,
+                repo: "acme/app".to_string(),
+                issue: Some(7),
+                changes: vec!["ID already used by \"Old guide\"".to_string()],
+            },
+        ],
+        unchanged: 3,
+        ..SyncPlan::default()
+    };
+
+    let json = serde_json::to_string_pretty(&plan).unwrap();
+    assert!(json.contains("\"action\": \"create\""));
+    assert!(json.contains("\"action\": \"conflict\""));
+    // No issue yet, nothing to list: the fields are left out
+    assert!(!json.contains("\"issue\": null"));
+
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("plan.json");
+    fs::write(&path, &json).unwrap();
+    let loaded = SyncPlan::load(&path).unwrap();
+    assert_eq!(loaded, plan);
+    assert_eq!(loaded.entry("frontend-002").unwrap().issue, Some(42));
+    assert!(loaded.entry("backend-999").is_none());
+
+    // A plan from an incompatible version is refused
+    fs::write(&path, json.replace("\"version\": 1", "\"version\": 99")).unwrap();
+    let err = SyncPlan::load(&path).unwrap_err();
+    assert!(err.to_string().contains("version 99"));
+
+    let table = plan.render_table();
+    let lines: Vec<&str> = table.lines().collect();
+    assert!(lines[0].contains("TASK") && lines[0].contains("CHANGES"));
+    assert!(lines[1].contains("backend-001") && lines[1].contains("create"));
+    assert!(lines[1].contains("Add login"));
+    assert!(lines[2].contains("#42") && lines[2].contains("state OPEN → CLOSED, labels +ui"));
+    // Columns line up whatever the ID length
+    assert_eq!(lines[1].find("acme/app"), lines[3].find("acme/app"));
+    assert!(table.contains("1 to create, 1 to update, 0 to adopt, 1 conflicts, 3 unchanged"));
+
+    let empty = SyncPlan {
+        unchanged: 5,
+        ..SyncPlan::default()
+    };
+    assert!(
+        empty
+            .render_table()
+            .contains("Nothing to push (5 tasks in sync)")
+    );
+}
+
+#[test]
+fn test_webhook_signature_request_and_queue() {
+    use taskguard::github::webhook::{
+        HeadersTooLarge, IssueEvent, RecentDeliveries, Request, WebhookQueue, verify_signature,
+    };
+
+    // Example from GitHub's webhook documentation
+    let secret = b"It's a Secret to Everybody";
+    let signature = "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+    assert!(verify_signature(secret, b"Hello, World!", signature));
+    assert!(!verify_signature(secret, b"Hello, World?", signature));
+    assert!(!verify_signature(b"wrong", b"Hello, World!", signature));
+    assert!(!verify_signature(secret, b"Hello, World!", "sha1=757107ea"));
+    assert!(!verify_signature(secret, b"Hello, World!", "sha256=zz"));
+
+    let payload = r#"{"action":"labeled","repository":{"full_name":"acme/web"},"issue":{"number":12,"state":"open","labels":[{"name":"ui"}],"assignees":[{"login":"octocat"}]}}"#;
+    let raw = format!(
+        "POST /hook HTTP/1.1\r\nHost: example.com\r\nX-GitHub-Event: issues\r\ncontent-length: {}\r\n\r\n{}",
+        payload.len(),
+        payload
+    );
+    let request = Request::read(&mut raw.as_bytes()).unwrap();
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/hook");
+    assert_eq!(request.header("X-GITHUB-EVENT"), Some("issues"));
+    assert_eq!(request.body, payload.as_bytes());
+    assert!(Request::read(&mut "\r\n".as_bytes()).is_err());
+
+    // Header blocks are capped before the signature can be checked
+    let long = format!(
+        "POST /hook HTTP/1.1\r\nX-Long: {}\r\n\r\n",
+        "a".repeat(70_000)
+    );
+    let error = Request::read(&mut long.as_bytes()).unwrap_err();
+    assert!(error.is::<HeadersTooLarge>());
+    let many = format!("POST /hook HTTP/1.1\r\n{}\r\n", "X-A: b\r\n".repeat(101));
+    let error = Request::read(&mut many.as_bytes()).unwrap_err();
+    assert!(error.is::<HeadersTooLarge>());
+    // A large Content-Length with a short body fails without reserving it
+    let short = "POST /hook HTTP/1.1\r\nContent-Length: 20000000\r\n\r\n{}";
+    assert!(Request::read(&mut short.as_bytes()).is_err());
+
+    let event = IssueEvent::parse("d-1", &request.body).unwrap();
+    assert_eq!(event.action, "labeled");
+    assert_eq!(event.repo, "acme/web");
+    assert_eq!(event.number, 12);
+    assert_eq!(event.state, "open");
+    assert_eq!(event.labels, vec!["ui".to_string()]);
+    assert_eq!(event.assignees, vec!["octocat".to_string()]);
+    assert!(IssueEvent::parse("d-2", b"{\"action\":\"opened\"}").is_err());
+
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    assert!(WebhookQueue::load(root).events.is_empty());
+    let queue = WebhookQueue {
+        events: vec![event],
+    };
+    queue.save(root).unwrap();
+    assert_eq!(WebhookQueue::load(root), queue);
+    // An emptied queue leaves no file behind
+    WebhookQueue::default().save(root).unwrap();
+    assert!(!root.join(taskguard::github::webhook::QUEUE_FILE).exists());
+
+    // Redeliveries repeat the ID; replays under a new ID repeat the payload
+    let mut recent = RecentDeliveries::default();
+    assert!(!recent.contains("d-1", b"payload"));
+    recent.record("d-1", b"payload");
+    assert!(recent.contains("d-1", b"other payload"));
+    assert!(recent.contains("d-9", b"payload"));
+    assert!(!recent.contains("d-2", b"other payload"));
+    assert!(!recent.contains("", b"other payload"));
+}
+
+#[test]
+fn test_actions_annotations_and_outputs() {
+    use taskguard::github::actions::{Level, annotation, output_lines};
+
+    assert_eq!(
+        annotation(Level::Warning, "backend-001", "Issue #4 conflicts"),
+        "::warning title=backend-001::Issue #4 conflicts"
+    );
+    // Data escapes %, CR and LF; properties also : and ,
+    assert_eq!(
+        annotation(Level::Error, "a: b, c", "100% done\nnext line\r"),
+        "::error title=a%3A b%2C c::100%25 done%0Anext line%0D"
+    );
+    assert_eq!(
+        annotation(Level::Notice, "sync", "ok: fine"),
+        "::notice title=sync::ok: fine"
+    );
+
+    assert_eq!(
+        output_lines(&[("created", "2".to_string()), ("updated", "0".to_string())]),
+        "created=2\nupdated=0\n"
+    );
+}
@@ -1,7 +1,8 @@
 use chrono::Utc;
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 use taskguard::analysis::{IssueCategory, Severity, TaskAnalyzer};
-use taskguard::task::{Priority, Task, TaskStatus};
+use taskguard::task::{FORMAT_VERSION, Priority, Relations, Task, TaskStatus};
 
 fn create_test_task(
     id: &str,
@@ -18,11 +19,22 @@ fn create_test_task(
         priority: Priority::Medium,
         tags: vec!["test".to_string()],
         dependencies,
-        assignee: None,
+        relations: Relations::default(),
+        assignees: vec![],
         created: Utc::now(),
         estimate,
         complexity,
+        due: None,
+        epic: None,
+        time_log: vec![],
+        links: vec![],
+        branch: None,
+        release: None,
+        completed: None,
+        claimed: None,
+        format_version: FORMAT_VERSION,
         area: "test".to_string(),
+        extra: BTreeMap::new(),
         content: content.to_string(),
         file_path: PathBuf::from(format!("tasks/test/{}.md", id)),
     }
@@ -271,11 +283,22 @@ fn test_completeness_issues_detection() {
         priority: Priority::Medium,
         tags: vec![], // No tags
         dependencies: vec![],
-        assignee: None,
+        relations: Relations::default(),
+        assignees: vec![],
         created: Utc::now(),
         estimate: None, // No estimate
         complexity: None,
+        due: None,
+        epic: None,
+        time_log: vec![],
+        links: vec![],
+        branch: None,
+        release: None,
+        completed: None,
+        claimed: None,
+        format_version: FORMAT_VERSION,
         area: "test".to_string(),
+        extra: BTreeMap::new(),
         content: "Brief.".to_string(), // Very brief content
         file_path: PathBuf::from("tasks/test/test-008.md"),
     };
@@ -535,11 +558,22 @@ Clear context provided here.
         priority: Priority::High,
         tags: vec!["backend".to_string(), "api".to_string(), "auth".to_string()],
         dependencies: vec!["setup-001".to_string()],
-        assignee: Some("developer".to_string()),
+        relations: Relations::default(),
+        assignees: vec!["developer".to_string()],
         created: Utc::now(),
         estimate: Some("4 hours".to_string()),
         complexity: Some(5),
+        due: None,
+        epic: None,
+        time_log: vec![],
+        links: vec![],
+        branch: None,
+        release: None,
+        completed: None,
+        claimed: None,
+        format_version: FORMAT_VERSION,
         area: "backend".to_string(),
+        extra: BTreeMap::new(),
         content: good_structure.to_string(),
         file_path: PathBuf::from("tasks/backend/complete-001.md"),
     };
@@ -551,11 +585,22 @@ Clear context provided here.
         priority: Priority::Medium,
         tags: vec![],
         dependencies: vec![],
-        assignee: None,
+        relations: Relations::default(),
+        assignees: vec![],
         created: Utc::now(),
         estimate: None,
         complexity: None,
+        due: None,
+        epic: None,
+        time_log: vec![],
+        links: vec![],
+        branch: None,
+        release: None,
+        completed: None,
+        claimed: None,
+        format_version: FORMAT_VERSION,
         area: "misc".to_string(),
+        extra: BTreeMap::new(),
         content: "Brief.".to_string(),
         file_path: PathBuf::from("tasks/misc/incomplete-001.md"),
     };
@@ -813,11 +858,22 @@ fn test_task_recommendations() {
         priority: Priority::Medium,
         tags: vec![],
         dependencies: vec![],
-        assignee: None,
+        relations: Relations::default(),
+        assignees: vec![],
         created: Utc::now(),
         estimate: None,
         complexity: None,
+        due: None,
+        epic: None,
+        time_log: vec![],
+        links: vec![],
+        branch: None,
+        release: None,
+        completed: None,
+        claimed: None,
+        format_version: FORMAT_VERSION,
         area: "misc".to_string(),
+        extra: BTreeMap::new(),
         content: "do stuff".to_string(),
         file_path: PathBuf::from("tasks/misc/prob-001.md"),
     };
@@ -969,3 +1025,837 @@ fn test_edge_case_task_content() {
         "Should handle malformed markdown"
     );
 }
+
+#[test]
+fn test_custom_field_type_checks() {
+    use taskguard::config::{FieldIssue, FieldType, check_custom_fields};
+
+    let mut task = create_test_task("test-100", "Typed", "content", vec![], None, None);
+    task.extra
+        .insert("story_points".to_string(), serde_yaml::Value::from(5));
+    task.extra.insert(
+        "deadline".to_string(),
+        serde_yaml::Value::from("2025-13-40"),
+    );
+
+    let mut schema = BTreeMap::new();
+    schema.insert("story_points".to_string(), FieldType::Int);
+    schema.insert("deadline".to_string(), FieldType::Date);
+
+    let issues = check_custom_fields(&task, &schema);
+    assert_eq!(issues.len(), 1);
+    assert!(matches!(&issues[0], FieldIssue::TypeMismatch(msg) if msg.contains("deadline")));
+
+    assert!(FieldType::Float.matches(&serde_yaml::Value::from(1.5)));
+    assert!(FieldType::Float.matches(&serde_yaml::Value::from(2)));
+    assert!(!FieldType::Bool.matches(&serde_yaml::Value::from("yes")));
+    assert_eq!(task.field_display("story_points").as_deref(), Some("5"));
+}
+
+#[test]
+fn test_assignee_lint_and_yaml_compat() {
+    let analyzer = TaskAnalyzer::new();
+    let mut task = create_test_task("test-101", "Crowded", "content", vec![], None, None);
+    task.assignees = ["a", "b", "c", "d"].iter().map(|s| s.to_string()).collect();
+
+    let analysis = analyzer.analyze_task(&task);
+    assert!(
+        analysis
+            .issues
+            .iter()
+            .any(|i| matches!(i.severity, Severity::Info) && i.message.contains("many assignees"))
+    );
+
+    // Both the legacy string form and the list form parse
+    let single = "---\nid: test-102\ntitle: One\narea: test\nassignee: alice\n---\n\nBody\n";
+    let many = "---\nid: test-103\ntitle: Two\narea: test\nassignee: [alice, bob]\n---\n\nBody\n";
+    let none = "---\nid: test-104\ntitle: None\narea: test\nassignee: null\n---\n\nBody\n";
+    assert_eq!(
+        Task::parse_content(single).unwrap().assignees,
+        vec!["alice"]
+    );
+    assert_eq!(
+        Task::parse_content(many).unwrap().assignees,
+        vec!["alice", "bob"]
+    );
+    assert!(Task::parse_content(none).unwrap().assignees.is_empty());
+    assert_eq!(
+        Task::parse_content(many)
+            .unwrap()
+            .field_display("assignee")
+            .as_deref(),
+        Some("alice, bob")
+    );
+}
+
+#[test]
+fn test_checklist_item_metadata_lint() {
+    use taskguard::checklist;
+
+    let content = "## Tasks\n- [ ] write tests @alice due:2000-07-01\n- [x] done already due:2000-01-01\n- [ ] mail @ops about it\n";
+    let items = checklist::parse_items(content);
+    assert_eq!(items[0].text, "write tests");
+    assert_eq!(items[0].assignee.as_deref(), Some("alice"));
+    assert_eq!(
+        items[0].due.map(|d| d.to_string()).as_deref(),
+        Some("2000-07-01")
+    );
+    assert_eq!(items[2].text, "mail @ops about it");
+    assert_eq!(items[2].assignee, None);
+
+    let nested =
+        checklist::parse_items("- [ ] Build\n  - [x] Schema\n  - [x] Endpoints\n- [x] Ship\n");
+    let labels: Vec<String> = nested.iter().map(|i| i.label()).collect();
+    assert_eq!(labels, vec!["1", "1.1", "1.2", "2"]);
+    assert!(nested[0].completed, "parent follows its children");
+    assert_eq!(checklist::leaf_items(&nested).count(), 3);
+
+    let analyzer = TaskAnalyzer::new();
+    let task = create_test_task("test-110", "Items", content, vec![], None, None);
+    let analysis = analyzer.analyze_task(&task);
+    assert!(analysis.issues.iter().any(|i| {
+        matches!(i.severity, Severity::Warning)
+            && i.message
+                .contains("1 checklist item(s) past their due date: write tests")
+    }));
+}
+
+#[test]
+fn test_duration_parsing_and_estimate_accuracy() {
+    use taskguard::analysis::EstimateAccuracy;
+    use taskguard::task::{TimeEntry, estimate_to_minutes, normalize_estimate};
+
+    // Logged time and estimates share one grammar
+    for (value, minutes) in [
+        ("2h", Some(120)),
+        ("1h30m", Some(90)),
+        ("1.5 days", Some(720)),
+        ("1w", Some(2400)),
+        ("2 months", Some(19200)),
+        ("1mo", Some(9600)),
+        ("30m, 1h", Some(90)),
+        ("later", None),
+        ("2h garbage", None),
+        ("about 2h", None),
+        ("2-3 days", None),
+    ] {
+        assert_eq!(estimate_to_minutes(value), minutes, "{}", value);
+        assert_eq!(
+            normalize_estimate(value).is_ok(),
+            minutes.is_some(),
+            "{}",
+            value
+        );
+    }
+
+    let tasks: Vec<Task> = (0..3)
+        .map(|i| {
+            let mut task = create_test_task(
+                &format!("test-{:03}", i),
+                "Tracked",
+                "content",
+                vec![],
+                Some("2h".to_string()),
+                None,
+            );
+            task.time_log.push(TimeEntry {
+                started: Utc::now(),
+                minutes: Some(180),
+            });
+            task
+        })
+        .collect();
+
+    let accuracy = EstimateAccuracy::from_tasks(&tasks);
+    assert_eq!(accuracy.samples, 3);
+    assert_eq!(accuracy.overruns, 3);
+    assert_eq!(accuracy.ratio(), Some(1.5));
+    assert!(accuracy.is_chronic_underestimation());
+}
+
+#[test]
+fn test_estimate_normalization() {
+    use taskguard::task::{estimate_to_minutes, format_estimate, normalize_estimate};
+
+    assert_eq!(normalize_estimate("4 hours").unwrap(), "4h");
+    assert_eq!(normalize_estimate("2 Days").unwrap(), "2d");
+    assert_eq!(normalize_estimate("1 hour 30 mins").unwrap(), "1h 30m");
+    assert_eq!(normalize_estimate("30m, 1h").unwrap(), "1h 30m");
+    assert_eq!(normalize_estimate("1.5d").unwrap(), "1.5d");
+    assert_eq!(normalize_estimate("1 month").unwrap(), "4w");
+    assert_eq!(normalize_estimate("8h").unwrap(), "8h");
+
+    for invalid in ["", "soon", "2-3 days", "4", "4hx", "h4"] {
+        let err = normalize_estimate(invalid).unwrap_err();
+        assert!(err.to_string().contains("Invalid estimate"), "{}", invalid);
+    }
+
+    assert_eq!(estimate_to_minutes("1d 4h"), Some(720));
+    assert_eq!(estimate_to_minutes("2 weeks"), Some(4800));
+    assert_eq!(estimate_to_minutes("2-3 days"), None);
+
+    assert_eq!(format_estimate(0), "0h");
+    assert_eq!(format_estimate(720), "1d 4h");
+    assert_eq!(format_estimate(2400 + 960 + 30), "1w 2d 30m");
+}
+
+#[test]
+fn test_checklist_progress_and_issue_body_line() {
+    use taskguard::commands::sync::{apply_progress_line, progress_line};
+
+    let content = "## Tasks\n- [x] Schema\n- [X] Migration\n- [ ] Endpoint\n- [ ] Docs\n";
+    let task = create_test_task("test-200", "Progress", content, vec![], None, None);
+    assert_eq!(task.checklist_progress(), Some((2, 4)));
+    assert_eq!(task.progress_percent(), Some(50));
+    assert_eq!(task.field_display("progress").as_deref(), Some("50%"));
+
+    let empty = create_test_task("test-201", "No items", "Just prose", vec![], None, None);
+    assert_eq!(empty.checklist_progress(), None);
+    assert!(progress_line(&empty).is_none());
+
+    let body = "**TaskGuard ID:** test-200  \n**Hash:** abc\n\n## Description\n\nText";
+    let updated = apply_progress_line(body, &task).unwrap();
+    assert_eq!(
+        updated,
+        "**TaskGuard ID:** test-200  \n**Hash:** abc\n**Progress:** 50% (2/4 checklist items)\n\n## Description\n\nText"
+    );
+    assert!(
+        apply_progress_line(&updated, &task).is_none(),
+        "already current"
+    );
+
+    let stale = updated.replace("50% (2/4", "25% (1/4");
+    assert_eq!(apply_progress_line(&stale, &task).unwrap(), updated);
+}
+
+#[test]
+fn test_checklist_section_in_issue_body() {
+    use taskguard::commands::sync::{
+        apply_checklist_section, checked_items, checklist_section, issue_checked_items,
+    };
+
+    let content =
+        "## Tasks\n- [x] Schema @alice\n- [ ] API\n  - [x] Endpoint\n  - [ ] Docs due:2026-01-05\n";
+    let task = create_test_task("test-202", "Checklist", content, vec![], None, None);
+    assert_eq!(checked_items(&task.content), vec!["Schema", "Endpoint"]);
+
+    // Metadata is left out; parents follow their children
+    let section = checklist_section(&task, &checked_items(&task.content)).unwrap();
+    assert_eq!(
+        section,
+        "## Checklist\n\n- [x] Schema\n- [ ] API\n  - [x] Endpoint\n  - [ ] Docs"
+    );
+    let all_done = checklist_section(
+        &task,
+        &[
+            "Schema".to_string(),
+            "Endpoint".to_string(),
+            "docs".to_string(),
+        ],
+    )
+    .unwrap();
+    assert!(all_done.contains("- [x] API"));
+
+    // Added before the footer, then replaced in place
+    let body =
+        "**TaskGuard ID:** test-202\n\n## Description\n\nText\n\n---\n*Synced from TaskGuard*";
+    let updated = apply_checklist_section(body, &section).unwrap();
+    assert_eq!(
+        updated,
+        format!(
+            "**TaskGuard ID:** test-202\n\n## Description\n\nText\n\n{}\n\n---\n*Synced from TaskGuard*",
+            section
+        )
+    );
+    assert!(apply_checklist_section(&updated, &section).is_none());
+    let toggled = apply_checklist_section(&updated, &all_done).unwrap();
+    assert!(toggled.contains("  - [x] Docs\n\n---"));
+
+    // Checkboxes read back from the issue, limited to the task's own items
+    assert_eq!(
+        issue_checked_items(&task, &toggled),
+        Some(vec![
+            "Schema".to_string(),
+            "Endpoint".to_string(),
+            "Docs".to_string()
+        ])
+    );
+    let on_github = toggled
+        .replace("- [x] Schema", "- [ ] Schema")
+        .replace("  - [x] Docs", "  - [x] Docs\n- [x] Added on GitHub");
+    assert_eq!(
+        issue_checked_items(&task, &on_github),
+        Some(vec!["Endpoint".to_string(), "Docs".to_string()])
+    );
+    assert_eq!(issue_checked_items(&task, body), None);
+}
+
+#[test]
+fn test_full_issue_body_and_description() {
+    use taskguard::commands::sync::{
+        issue_body, issue_description, issue_task_hash, task_description,
+    };
+    use taskguard::github::GitHubConfig;
+
+    let content = "# Login\n\n## Context\nUsers sign in with SSO.\n\n## Tasks\n- [x] Schema\n- [ ] Endpoint\n\n## Notes\n```bash\n# not a heading\n```\n";
+    let mut task = create_test_task("test-203", "Login", content, vec![], None, None);
+    task.branch = Some("main".to_string());
+
+    // Checklist items go to their own section; emptied headings are dropped
+    assert_eq!(
+        task_description(&task),
+        "## Login\n\n### Context\nUsers sign in with SSO.\n\n### Notes\n```bash\n# not a heading\n```"
+    );
+    let empty = create_test_task(
+        "test-204",
+        "Empty",
+        "## Tasks\n- [ ] One\n",
+        vec![],
+        None,
+        None,
+    );
+    assert_eq!(task_description(&empty), "No description");
+
+    let config: GitHubConfig =
+        toml::from_str("owner = \"o\"\nrepo = \"r\"\nproject_number = 1\n").unwrap();
+    let body = issue_body(&task, &config, false, &["Schema".to_string()]);
+    assert_eq!(
+        issue_description(&body),
+        Some(task_description(&task).as_str())
+    );
+    assert!(issue_task_hash(&body).is_some_and(|hash| hash.len() == 8));
+    assert!(body.contains("**Progress:** 50% (1/2 checklist items)"));
+    assert!(body.contains("## Checklist\n\n- [x] Schema\n- [ ] Endpoint\n\n---"));
+
+    // A body from before full-body sync
+    assert_eq!(
+        issue_description("**Hash:** abc\n\n## Description\n\nText"),
+        None
+    );
+    assert_eq!(
+        issue_task_hash("**Hash:** abc\n\n## Description\n\nText"),
+        Some("abc")
+    );
+}
+
+#[test]
+fn test_worklog_entries_and_unposted_comments() {
+    use taskguard::commands::sync::unposted_log_entries;
+    use taskguard::worklog::{LogEntry, append_entry, parse_entries};
+
+    let content = "## Context\nSome work\n\n## Log\n\n- 2024-07-01 14:30 UTC Alice Doe: blocked on infra ticket\n\n## Notes\nLater";
+    let entries = parse_entries(content);
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].author, "Alice Doe");
+    assert_eq!(entries[0].text, "blocked on infra ticket");
+
+    let appended = append_entry(content, &LogEntry::new("bob", "unblocked\nmoving on"));
+    let entries = parse_entries(&appended);
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[1].text, "unblocked moving on");
+    assert!(appended.contains("moving on\n\n## Notes\nLater"));
+
+    // A body without a log gets the section at the end
+    let fresh = append_entry("## Context\nSome work", &LogEntry::new("bob", "started"));
+    assert!(fresh.starts_with("## Context\nSome work\n\n## Log\n\n- "));
+    assert_eq!(parse_entries(&fresh).len(), 1);
+
+    let task = create_test_task("test-300", "Logged", &appended, vec![], None, None);
+    let posted = vec![entries[0].comment_body()];
+    let pending = unposted_log_entries(&task, &posted);
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].author, "bob");
+}
+
+#[test]
+fn test_issue_comments_pulled_into_log() {
+    use taskguard::commands::sync::{new_comment_entries, unposted_log_entries};
+    use taskguard::github::IssueComment;
+    use taskguard::worklog::{LogEntry, append_entry, parse_entries};
+
+    let content = "## Log\n\n- 2024-07-01 14:30 UTC bob: started\n";
+    let task = create_test_task("test-301", "Discussed", content, vec![], None, None);
+    let comment = |author: &str, created_at: &str, body: &str| IssueComment {
+        author: author.to_string(),
+        created_at: created_at.to_string(),
+        body: body.to_string(),
+    };
+    let comments = vec![
+        // Mirror of bob's entry, posted by sync
+        comment(
+            "bob-gh",
+            "2024-07-01T14:31:00Z",
+            &parse_entries(content)[0].comment_body(),
+        ),
+        comment(
+            "octocat",
+            "2024-07-02T09:15:42Z",
+            "Any update?\nThe demo is Friday.",
+        ),
+        comment("", "2024-07-02T10:00:00Z", "   "),
+    ];
+
+    let entries = new_comment_entries(&task, &comments);
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].author, "@octocat");
+    assert_eq!(entries[0].text, "Any update? The demo is Friday.");
+    assert!(entries[0].is_from_github());
+
+    // Once logged, the comment is neither pulled again nor posted back
+    let logged = create_test_task(
+        "test-301",
+        "Discussed",
+        &append_entry(content, &entries[0]),
+        vec![],
+        None,
+        None,
+    );
+    assert!(
+        logged
+            .content
+            .contains("- 2024-07-02 09:15 UTC @octocat: Any update?")
+    );
+    assert!(new_comment_entries(&logged, &comments).is_empty());
+    let posted = vec![comments[0].body.clone()];
+    assert!(unposted_log_entries(&logged, &posted).is_empty());
+
+    assert!(LogEntry::from_comment("octocat", "yesterday", "Hi").is_none());
+}
+
+#[test]
+fn test_nested_area_helpers() {
+    use taskguard::task::{area_id_prefix, area_matches, normalize_area};
+
+    assert_eq!(normalize_area(" /backend/auth/ ").unwrap(), "backend/auth");
+    assert_eq!(normalize_area("backend\\auth").unwrap(), "backend/auth");
+    assert!(normalize_area("backend/../etc").is_err());
+    assert!(normalize_area("/").is_err());
+
+    assert!(area_matches("backend/auth", "backend"));
+    assert!(area_matches("backend", "backend/"));
+    assert!(!area_matches("backend-legacy", "backend"));
+    assert!(!area_matches("backend", "backend/auth"));
+
+    assert_eq!(area_id_prefix("backend/auth"), "backend-auth");
+}
+
+#[test]
+fn test_frontmatter_schema_issues() {
+    use taskguard::task::validate_frontmatter;
+
+    let valid = "---\nid: test-001\ntitle: Fine\nstatus: todo\ntags: [a]\nestimate: 4\narea: test\n---\n\nBody\n";
+    assert!(validate_frontmatter(valid).is_empty());
+    assert!(Task::parse_content(valid).is_ok());
+
+    let broken =
+        "---\nid: test-002\ntitle: Broken\nstatus: Done\ntags: backend\narea: test\n---\n\nBody\n";
+    let issues = validate_frontmatter(broken);
+    assert_eq!(issues.len(), 2);
+    assert_eq!(issues[0].line, 4);
+    assert_eq!(issues[0].field, "status");
+    assert!(issues[0].fix.contains("status: done"));
+    assert_eq!(issues[1].line, 5);
+    assert_eq!(issues[1].expected, "a list of strings");
+    assert!(issues[1].fix.contains("tags: [backend]"));
+
+    // The loader reports the same issues instead of a raw serde error
+    let error = Task::parse_content(broken).unwrap_err().to_string();
+    assert!(error.contains("line 4: field 'status'"), "{}", error);
+
+    let missing = "---\ntitle: No id\n---\n";
+    let issues = validate_frontmatter(missing);
+    assert!(
+        issues
+            .iter()
+            .any(|i| i.field == "id" && i.found == "missing")
+    );
+    assert!(issues.iter().any(|i| i.field == "area"));
+
+    let syntax = "---\nid: test-003\ntitle: [unclosed\narea: test\n---\n";
+    assert_eq!(validate_frontmatter(syntax)[0].field, "frontmatter");
+}
+
+#[test]
+fn test_toml_frontmatter_round_trip() {
+    use taskguard::task::{FrontmatterFormat, validate_frontmatter};
+
+    let yaml = "---\nid: test-010\ntitle: Round trip\nstatus: doing\npriority: high\ntags: [a]\ndependencies: [test-009]\nrelations:\n  parent: test-001\nassignee: [alice, bob]\ncreated: 2025-01-01T00:00:00Z\nestimate: 4h\ncomplexity: 5\ndue: 2025-02-01\narea: test\nsprint: 12\n---\n\nBody text\n";
+    let task = Task::parse_content(yaml).unwrap();
+
+    let toml = task.to_file_content_as(FrontmatterFormat::Toml).unwrap();
+    assert!(toml.starts_with("+++\nid = \"test-010\""));
+    assert_eq!(FrontmatterFormat::detect(&toml), FrontmatterFormat::Toml);
+    assert!(validate_frontmatter(&toml).is_empty());
+
+    let parsed = Task::parse_content(&toml).unwrap();
+    assert_eq!(parsed.status, TaskStatus::Doing);
+    assert_eq!(parsed.assignees, vec!["alice", "bob"]);
+    assert_eq!(parsed.relations.parent.as_deref(), Some("test-001"));
+    assert_eq!(parsed.due, task.due);
+    assert_eq!(parsed.estimate.as_deref(), Some("4h"));
+    assert_eq!(parsed.field_display("sprint").as_deref(), Some("12"));
+    assert_eq!(parsed.content, "Body text");
+
+    let back = parsed.to_file_content_as(FrontmatterFormat::Yaml).unwrap();
+    assert_eq!(FrontmatterFormat::detect(&back), FrontmatterFormat::Yaml);
+    assert_eq!(Task::parse_content(&back).unwrap().title, "Round trip");
+
+    let broken =
+        "+++\nid = \"test-011\"\ntitle = \"Bad\"\nstatus = \"Done\"\narea = \"test\"\n+++\n";
+    let issues = validate_frontmatter(broken);
+    assert_eq!(issues.len(), 1);
+    assert_eq!((issues[0].line, issues[0].field.as_str()), (4, "status"));
+}
+
+#[test]
+fn test_migrate_legacy_task_file() {
+    use taskguard::commands::migrate::migrate_content;
+
+    let legacy =
+        "---\nstatus: in-progress\ndependencies: [setup-001]\n---\n# Legacy task\n\nBody\n";
+    let (migrated, changes) = migrate_content(legacy, "backend-auth-003", "backend/auth")
+        .unwrap()
+        .expect("legacy file needs migration");
+    assert!(changes.contains(&"status in-progress → doing".to_string()));
+    assert!(changes.contains(&"added title: Legacy task".to_string()));
+
+    let task = Task::parse_content(&migrated).unwrap();
+    assert_eq!(task.id, "backend-auth-003");
+    assert_eq!(task.area, "backend/auth");
+    assert_eq!(task.status, TaskStatus::Doing);
+    assert_eq!(task.format_version, FORMAT_VERSION);
+    assert_eq!(task.dependencies, vec!["setup-001"]);
+
+    // Already current files are left alone; newer ones are refused
+    assert!(
+        migrate_content(&migrated, "backend-auth-003", "backend/auth")
+            .unwrap()
+            .is_none()
+    );
+    let newer = migrated.replace(
+        &format!("format_version: {}", FORMAT_VERSION),
+        "format_version: 99",
+    );
+    assert!(migrate_content(&newer, "backend-auth-003", "backend/auth").is_err());
+}
+
+#[test]
+fn test_analyzer_from_lint_config() {
+    use taskguard::config::LintConfig;
+
+    let content = "# Task\n\n## context\nWhy this matters.\n\n- [ ] One\n- [ ] Two\n- [ ] Three\n";
+    let task = create_test_task("test-001", "Configured", content, vec![], None, None);
+
+    // Unset values keep the defaults
+    let analyzer = TaskAnalyzer::from_config(Some(&LintConfig::default()));
+    assert_eq!(analyzer.complexity_thresholds.max_task_items, 20);
+    assert!(
+        !analyzer
+            .analyze_task(&task)
+            .issues
+            .iter()
+            .any(|i| i.message.contains("many subtasks"))
+    );
+
+    let lint = LintConfig {
+        max_task_items: Some(2),
+        required_sections: vec!["Context".to_string(), "Testing".to_string()],
+        ..LintConfig::default()
+    };
+    let analyzer = TaskAnalyzer::from_config(Some(&lint));
+    let messages: Vec<String> = analyzer
+        .analyze_task(&task)
+        .issues
+        .into_iter()
+        .map(|i| i.message)
+        .collect();
+    assert!(messages.contains(&"Task has many subtasks (3 items)".to_string()));
+    assert!(messages.contains(&"Missing required section: Testing".to_string()));
+    assert!(!messages.iter().any(|m| m.contains("section: Context")));
+    assert!(
+        !messages
+            .iter()
+            .any(|m| m.contains("lacks clear objectives"))
+    );
+}
+
+#[test]
+fn test_custom_lint_rules() {
+    use taskguard::config::LintConfig;
+
+    let lint: LintConfig = toml::from_str(
+        r#"
+[[rules]]
+name = "no-todo"
+forbid = "(?i)\\bTODO\\b"
+severity = "error"
+suggestion = "Resolve or move TODOs into subtasks"
+
+[[rules]]
+name = "needs-rollback"
+areas = ["test"]
+require = "(?m)^## Rollback"
+required_fields = ["estimate", "reviewer"]
+message = "Release tasks need a rollback plan"
+
+[[rules]]
+name = "other-area"
+areas = ["backend"]
+forbid = "Task"
+
+[[rules]]
+name = "broken"
+forbid = "(unclosed"
+"#,
+    )
+    .unwrap();
+
+    let analyzer = TaskAnalyzer::from_config(Some(&lint));
+    assert_eq!(analyzer.rule_errors.len(), 1);
+    assert!(analyzer.rule_errors[0].contains("'broken'"));
+
+    let task = create_test_task(
+        "test-001",
+        "Custom",
+        "# Task\n\n## Objectives\nShip it. TODO: write docs\n",
+        vec![],
+        None,
+        None,
+    );
+    let custom: Vec<_> = analyzer
+        .analyze_task(&task)
+        .issues
+        .into_iter()
+        .filter(|i| i.category == IssueCategory::Custom)
+        .collect();
+
+    let todo = custom
+        .iter()
+        .find(|i| i.message.starts_with("[no-todo]"))
+        .unwrap();
+    assert!(matches!(todo.severity, Severity::Error));
+    assert!(todo.message.contains("forbidden pattern"));
+    assert_eq!(
+        todo.suggestion.as_deref(),
+        Some("Resolve or move TODOs into subtasks")
+    );
+
+    // One issue per failed check, all with the rule's message and default severity
+    let rollback: Vec<_> = custom
+        .iter()
+        .filter(|i| i.message.starts_with("[needs-rollback]"))
+        .collect();
+    assert_eq!(rollback.len(), 3);
+    assert!(rollback.iter().all(|i| {
+        matches!(i.severity, Severity::Warning)
+            && i.message == "[needs-rollback] Release tasks need a rollback plan"
+    }));
+
+    // Rules scoped to another area don't run
+    assert!(!custom.iter().any(|i| i.message.starts_with("[other-area]")));
+
+    // Custom issues can be silenced like built-in ones
+    let task = create_test_task(
+        "test-002",
+        "Ignored",
+        "# Task\n<!-- taskguard-lint: ignore custom -->\n## Objectives\nTODO\n",
+        vec![],
+        None,
+        None,
+    );
+    let analysis = analyzer.analyze_task(&task);
+    assert!(
+        !analysis
+            .issues
+            .iter()
+            .any(|i| i.category == IssueCategory::Custom)
+    );
+    assert!(!analysis.ignored.is_empty());
+}
+
+#[test]
+fn test_lint_ignore_directives() {
+    use taskguard::analysis::lint_ignores;
+
+    assert_eq!(
+        lint_ignores(
+            "<!-- taskguard-lint: ignore Complexity, structure -->\n<!--taskguard-lint: ignore structure-->"
+        ),
+        vec!["complexity".to_string(), "structure".to_string()]
+    );
+    assert!(lint_ignores("<!-- just a comment -->").is_empty());
+
+    let analyzer = TaskAnalyzer::new();
+    let short = "Too short";
+    let task = create_test_task("test-001", "Noisy", short, vec![], None, None);
+    let baseline = analyzer.analyze_task(&task);
+    assert!(
+        baseline
+            .issues
+            .iter()
+            .any(|i| i.category == IssueCategory::Structure)
+    );
+    assert!(baseline.ignored.is_empty());
+
+    let content = format!(
+        "{}\n<!-- taskguard-lint: ignore structure completness -->",
+        short
+    );
+    let task = create_test_task("test-002", "Quiet", &content, vec![], None, None);
+    let analysis = analyzer.analyze_task(&task);
+    assert!(
+        !analysis
+            .issues
+            .iter()
+            .any(|i| i.category == IssueCategory::Structure)
+    );
+    assert!(
+        analysis
+            .ignored
+            .iter()
+            .all(|i| i.category == IssueCategory::Structure)
+    );
+    assert!(!analysis.ignored.is_empty());
+    // Typos are reported rather than silently ignoring nothing
+    assert!(
+        analysis
+            .issues
+            .iter()
+            .any(|i| i.message == "Unknown lint-ignore category 'completness'")
+    );
+
+    let content = format!("{}\n<!-- taskguard-lint: ignore all -->", short);
+    let task = create_test_task("test-003", "Silent", &content, vec![], None, None);
+    let analysis = analyzer.analyze_task(&task);
+    assert!(analysis.issues.is_empty());
+    assert_eq!(analysis.ignored.len(), baseline.issues.len());
+}
+
+#[test]
+fn test_naming_lint_rules() {
+    use taskguard::config::LintConfig;
+
+    let naming = |analyzer: &TaskAnalyzer, title: &str| -> Vec<String> {
+        let task = create_test_task("test-001", title, "# Task\n", vec![], None, None);
+        analyzer
+            .analyze_task(&task)
+            .issues
+            .into_iter()
+            .filter(|i| i.category == IssueCategory::Naming)
+            .map(|i| i.message)
+            .collect()
+    };
+
+    // Vague titles and length bounds are on by default, the verb check isn't
+    let analyzer = TaskAnalyzer::new();
+    assert_eq!(
+        naming(&analyzer, "Fix stuff."),
+        vec!["Title 'Fix stuff.' is too vague"]
+    );
+    assert_eq!(
+        naming(&analyzer, "Auth"),
+        vec!["Title is too short (4 characters, minimum 5)"]
+    );
+    assert!(naming(&analyzer, &"a".repeat(81))[0].contains("too long (81 characters"));
+    assert!(naming(&analyzer, "User service").is_empty());
+
+    let lint: LintConfig = toml::from_str(
+        r#"
+[naming]
+imperative = true
+max_title_length = 30
+banned_titles = ["cleanup"]
+"#,
+    )
+    .unwrap();
+    let analyzer = TaskAnalyzer::from_config(Some(&lint));
+    assert_eq!(
+        naming(&analyzer, "User service"),
+        vec!["Title doesn't start with an imperative verb"]
+    );
+    assert!(naming(&analyzer, "Set up CI caching").is_empty());
+    assert!(naming(&analyzer, "Fix stuff").is_empty());
+    assert!(naming(&analyzer, "Cleanup").contains(&"Title 'Cleanup' is too vague".to_string()));
+    assert!(naming(&analyzer, "Implement the user service endpoints")[0].contains("maximum 30"));
+
+    let lint: LintConfig = toml::from_str(
+        r#"
+[naming]
+imperative = true
+verbs = ["Spike"]
+"#,
+    )
+    .unwrap();
+    let analyzer = TaskAnalyzer::from_config(Some(&lint));
+    assert!(naming(&analyzer, "Spike on caching").is_empty());
+    assert_eq!(naming(&analyzer, "Add caching").len(), 1);
+}
+
+#[test]
+fn test_acceptance_criteria_coverage() {
+    let analyzer = TaskAnalyzer::new();
+    let coverage = |content: &str| {
+        let task = create_test_task("test-001", "Add login", content, vec![], None, None);
+        analyzer
+            .analyze_task(&task)
+            .issues
+            .into_iter()
+            .find(|i| i.message.starts_with("Acceptance criteria without"))
+    };
+
+    let content = "## Tasks\n- [ ] Reproduce the bug locally\n- [ ] Write a failing test\n\n## Acceptance Criteria\n- [ ] Reproduction steps no longer trigger the bug\n- [ ] Regression tests pass\n- [ ] Dashboard shows login latency\n";
+    let issue = coverage(content).expect("uncovered criterion");
+    assert_eq!(issue.severity, Severity::Info);
+    assert_eq!(issue.category, IssueCategory::Completeness);
+    assert_eq!(
+        issue.message,
+        "Acceptance criteria without a matching checklist item: Dashboard shows login latency"
+    );
+    assert_eq!(issue.line, Some(8));
+
+    // Criteria without any planned work are all reported
+    let issue = coverage("## Acceptance Criteria\n1. Users can log in\n2. Sessions expire\n")
+        .expect("uncovered criteria");
+    assert!(issue.message.ends_with("Users can log in, Sessions expire"));
+
+    // Template placeholders and tasks without the section are fine
+    assert!(coverage("## Acceptance Criteria\n- [ ] [Testable criterion 1]\n").is_none());
+    assert!(coverage("## Tasks\n- [ ] Add login form\n").is_none());
+}
+
+#[test]
+fn test_lint_severity_overrides() {
+    use taskguard::config::LintConfig;
+
+    let lint: LintConfig = toml::from_str(
+        r#"
+[severity]
+missing-estimate = "error"
+naming = "info"
+short-title = "warning"
+no-such-rule = "error"
+"#,
+    )
+    .unwrap();
+    let analyzer = TaskAnalyzer::from_config(Some(&lint));
+    assert_eq!(
+        analyzer.rule_errors,
+        vec!["Unknown rule 'no-such-rule' in [lint.severity]; use a rule id or category"]
+    );
+
+    let task = create_test_task("test-001", "Fix", "Short body.", vec![], None, None);
+    let analysis = analyzer.analyze_task(&task);
+    let severity = |rule: &str| {
+        analysis
+            .issues
+            .iter()
+            .find(|i| i.rule == rule)
+            .map(|i| i.severity.clone())
+    };
+    assert_eq!(severity("missing-estimate"), Some(Severity::Error));
+    // The rule id wins over its category
+    assert_eq!(severity("short-title"), Some(Severity::Warning));
+    assert_eq!(severity("vague-title"), Some(Severity::Info));
+    // Untouched rules keep their defaults
+    assert_eq!(severity("brief-description"), Some(Severity::Warning));
+}
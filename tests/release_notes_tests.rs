@@ -0,0 +1,94 @@
+use chrono::Utc;
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use taskguard::commands::release_notes::{GroupBy, render_release_notes};
+use taskguard::task::{FORMAT_VERSION, Priority, Relations, Task, TaskStatus};
+
+fn create_done_task(id: &str, area: &str, tags: &[&str]) -> Task {
+    Task {
+        id: id.to_string(),
+        title: format!("Task {}", id),
+        status: TaskStatus::Done,
+        priority: Priority::Medium,
+        tags: tags.iter().map(|t| t.to_string()).collect(),
+        dependencies: vec![],
+        relations: Relations::default(),
+        assignees: vec![],
+        created: Utc::now(),
+        estimate: None,
+        complexity: None,
+        due: None,
+        epic: None,
+        time_log: vec![],
+        links: vec![],
+        branch: None,
+        release: None,
+        completed: None,
+        claimed: None,
+        format_version: FORMAT_VERSION,
+        area: area.to_string(),
+        extra: BTreeMap::new(),
+        content: String::new(),
+        file_path: PathBuf::from(format!("tasks/{}/{}.md", area, id)),
+    }
+}
+
+#[test]
+fn test_release_notes_grouped_by_area_with_links() {
+    let api = create_done_task("api-001", "api", &["api"]);
+    let backend2 = create_done_task("backend-002", "backend", &["backend"]);
+    let backend1 = create_done_task("backend-001", "backend", &["backend"]);
+    let tasks = vec![&backend2, &api, &backend1];
+
+    let mut links = HashMap::new();
+    links.insert(
+        "api-001".to_string(),
+        "[#7](https://github.com/o/r/issues/7)".to_string(),
+    );
+
+    let notes = render_release_notes("Release Notes", &tasks, GroupBy::Area, &links);
+
+    assert!(notes.starts_with("# Release Notes\n"));
+    let api_pos = notes.find("## api").unwrap();
+    let backend_pos = notes.find("## backend").unwrap();
+    assert!(api_pos < backend_pos, "groups should be sorted");
+    assert!(notes.contains("- **api-001**: Task api-001 ([#7](https://github.com/o/r/issues/7))"));
+    assert!(
+        notes.find("backend-001").unwrap() < notes.find("backend-002").unwrap(),
+        "tasks should be sorted by ID within a group"
+    );
+}
+
+#[test]
+fn test_release_notes_grouped_by_tag() {
+    let task = create_done_task("backend-001", "backend", &["backend", "feature"]);
+    let untagged = create_done_task("api-001", "api", &[]);
+    let tasks = vec![&task, &untagged];
+
+    let notes = render_release_notes("Notes", &tasks, GroupBy::Tag, &HashMap::new());
+
+    assert!(notes.contains("## feature"));
+    assert!(notes.contains("## api"));
+    assert!(!notes.contains("## backend"));
+    assert!(GroupBy::parse("owner").is_err());
+}
+
+#[test]
+fn test_release_notes_grouped_by_release() {
+    let mut shipped = create_done_task("backend-001", "backend", &[]);
+    shipped.release = Some("v1.3.0".to_string());
+    let pending = create_done_task("api-001", "api", &[]);
+    let tasks = vec![&shipped, &pending];
+
+    let notes = render_release_notes("Notes", &tasks, GroupBy::Release, &HashMap::new());
+
+    assert!(notes.contains("## v1.3.0\n\n- **backend-001**"));
+    assert!(notes.contains("## Unreleased\n\n- **api-001**"));
+    assert_eq!(GroupBy::parse("release").unwrap(), GroupBy::Release);
+}
+
+#[test]
+fn test_release_notes_empty() {
+    let notes = render_release_notes("Notes", &[], GroupBy::Area, &HashMap::new());
+    assert!(notes.contains("No tasks were completed"));
+}
@@ -0,0 +1,103 @@
+use chrono::{Duration, Local, Utc};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use taskguard::analysis::TaskAnalyzer;
+use taskguard::risk::assess;
+use taskguard::task::{FORMAT_VERSION, Priority, Relations, Task, TaskStatus};
+
+fn create_test_task(
+    id: &str,
+    status: TaskStatus,
+    dependencies: Vec<String>,
+    complexity: Option<u8>,
+    age_days: i64,
+) -> Task {
+    Task {
+        id: id.to_string(),
+        title: format!("Task {}", id),
+        status,
+        priority: Priority::Medium,
+        tags: vec![],
+        dependencies,
+        relations: Relations::default(),
+        assignees: vec![],
+        created: Utc::now() - Duration::days(age_days),
+        estimate: None,
+        complexity,
+        due: None,
+        epic: None,
+        time_log: vec![],
+        links: vec![],
+        branch: None,
+        release: None,
+        completed: None,
+        claimed: None,
+        format_version: FORMAT_VERSION,
+        area: "backend".to_string(),
+        extra: BTreeMap::new(),
+        content: String::new(),
+        file_path: PathBuf::from(format!("tasks/backend/{}.md", id)),
+    }
+}
+
+#[test]
+fn test_risk_ranks_complex_blocking_stale_work_first() {
+    let mut busy = create_test_task("backend-004", TaskStatus::Todo, vec![], Some(3), 0);
+    busy.assignees = vec!["alice".to_string()];
+    let mut tasks = vec![
+        create_test_task("backend-001", TaskStatus::Todo, vec![], Some(8), 30),
+        create_test_task(
+            "backend-002",
+            TaskStatus::Todo,
+            vec!["backend-001".to_string()],
+            Some(3),
+            10,
+        ),
+        create_test_task(
+            "backend-003",
+            TaskStatus::Todo,
+            vec!["backend-002".to_string()],
+            Some(3),
+            0,
+        ),
+        create_test_task("backend-005", TaskStatus::Done, vec![], Some(9), 60),
+        busy,
+    ];
+    for i in 0..5 {
+        let mut task = create_test_task(
+            &format!("other-00{}", i),
+            TaskStatus::Doing,
+            vec![],
+            Some(1),
+            0,
+        );
+        task.assignees = vec!["alice".to_string()];
+        tasks.push(task);
+    }
+
+    let today = Local::now().date_naive();
+    let risks = assess(&tasks, &TaskAnalyzer::new(), today);
+    assert!(risks.iter().all(|r| r.task_id != "backend-005"));
+
+    let top = &risks[0];
+    assert_eq!(top.task_id, "backend-001");
+    assert_eq!(top.dependents, 2);
+    assert_eq!(top.idle_days, 30);
+    assert_eq!(top.blocked_days, None);
+    assert_eq!(
+        top.factors(),
+        vec!["complexity 8", "2 task(s) waiting on it", "idle 30 days"]
+    );
+
+    let waiting = risks.iter().find(|r| r.task_id == "backend-002").unwrap();
+    assert_eq!(waiting.blocked_days, Some(10));
+    assert!(waiting.factors().contains(&"blocked 10 days".to_string()));
+
+    let busy = risks.iter().find(|r| r.task_id == "backend-004").unwrap();
+    assert_eq!(busy.workload, 6);
+    assert!(
+        busy.factors()
+            .contains(&"assignee has 6 open tasks".to_string())
+    );
+    assert!(risks.windows(2).all(|w| w[0].score >= w[1].score));
+}
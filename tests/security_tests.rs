@@ -386,6 +386,7 @@ fn test_status_suggestion_with_malicious_commits() {
             author: "attacker".to_string(),
             timestamp: Utc::now(),
             task_ids: vec!["task-001".to_string()],
+            submodule: None,
         },
         TaskCommit {
             oid: "def456".to_string(),
@@ -393,6 +394,7 @@ fn test_status_suggestion_with_malicious_commits() {
             author: "test".to_string(),
             timestamp: Utc::now(),
             task_ids: vec!["task-001".to_string()],
+            submodule: None,
         },
     ];
 
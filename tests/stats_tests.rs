@@ -0,0 +1,167 @@
+use chrono::{Duration, Utc};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use taskguard::analysis::TaskAnalyzer;
+use taskguard::commands::stats::{area_health, render_prometheus_metrics};
+use taskguard::task::{FORMAT_VERSION, Priority, Relations, Task, TaskStatus};
+
+fn create_test_task(
+    id: &str,
+    area: &str,
+    status: TaskStatus,
+    dependencies: Vec<String>,
+    complexity: Option<u8>,
+) -> Task {
+    Task {
+        id: id.to_string(),
+        title: format!("Task {}", id),
+        status,
+        priority: Priority::Medium,
+        tags: vec![area.to_string()],
+        dependencies,
+        relations: Relations::default(),
+        assignees: vec![],
+        created: Utc::now(),
+        estimate: None,
+        complexity,
+        due: None,
+        epic: None,
+        time_log: vec![],
+        links: vec![],
+        branch: None,
+        release: None,
+        completed: None,
+        claimed: None,
+        format_version: FORMAT_VERSION,
+        area: area.to_string(),
+        extra: BTreeMap::new(),
+        content: String::new(),
+        file_path: PathBuf::from(format!("tasks/{}/{}.md", area, id)),
+    }
+}
+
+#[test]
+fn test_prometheus_metrics_counts() {
+    let tasks = vec![
+        create_test_task("setup-001", "setup", TaskStatus::Done, vec![], Some(2)),
+        create_test_task(
+            "backend-001",
+            "backend",
+            TaskStatus::Todo,
+            vec!["setup-001".to_string()],
+            Some(4),
+        ),
+        create_test_task(
+            "backend-002",
+            "backend",
+            TaskStatus::Todo,
+            vec!["backend-001".to_string()],
+            Some(6),
+        ),
+        create_test_task("api-001", "api", TaskStatus::Blocked, vec![], None),
+    ];
+
+    let metrics = render_prometheus_metrics(&tasks, None);
+
+    assert!(metrics.contains("# TYPE taskguard_tasks gauge"));
+    assert!(metrics.contains("taskguard_tasks{status=\"todo\"} 2"));
+    assert!(metrics.contains("taskguard_tasks{status=\"done\"} 1"));
+    assert!(metrics.contains("taskguard_tasks{status=\"review\"} 0"));
+    assert!(metrics.contains("taskguard_area_tasks{area=\"backend\"} 2"));
+    assert!(metrics.contains("taskguard_average_complexity 4"));
+    // backend-002 waits on backend-001, api-001 is explicitly blocked
+    assert!(metrics.contains("taskguard_blocked_tasks 2"));
+    assert!(!metrics.contains("taskguard_github_sync_age_seconds"));
+}
+
+#[test]
+fn test_prometheus_metrics_sync_age_and_escaping() {
+    let tasks = vec![create_test_task(
+        "odd-001",
+        "we\"ird",
+        TaskStatus::Todo,
+        vec![],
+        None,
+    )];
+
+    let metrics = render_prometheus_metrics(&tasks, Some(120));
+
+    assert!(metrics.contains("taskguard_area_tasks{area=\"we\\\"ird\"} 1"));
+    assert!(metrics.contains("taskguard_github_sync_age_seconds 120"));
+    assert!(metrics.ends_with('\n'));
+}
+
+#[test]
+fn test_area_health_ranks_struggling_areas_first() {
+    let old = Utc::now() - Duration::days(30);
+    let mut stale = create_test_task("api-001", "api", TaskStatus::Todo, vec![], None);
+    stale.created = old;
+    let tasks = vec![
+        create_test_task("setup-001", "setup", TaskStatus::Done, vec![], Some(2)),
+        stale,
+        create_test_task("api-002", "api", TaskStatus::Blocked, vec![], None),
+        create_test_task(
+            "api-003",
+            "api",
+            TaskStatus::Todo,
+            vec!["api-002".to_string()],
+            None,
+        ),
+        create_test_task(
+            "backend-001",
+            "backend",
+            TaskStatus::Todo,
+            vec!["setup-001".to_string()],
+            Some(3),
+        ),
+    ];
+
+    let analyzer = TaskAnalyzer::new();
+    let health = area_health(&tasks, &analyzer, 14, Utc::now().date_naive());
+    let areas: Vec<&str> = health.iter().map(|h| h.area.as_str()).collect();
+    assert_eq!(areas[0], "api");
+    assert_eq!(areas.len(), 3);
+
+    let api = &health[0];
+    assert_eq!(api.open, 3);
+    assert_eq!(api.stale, 1);
+    assert_eq!(api.dependency_depth, 2);
+    assert!((api.blocked_ratio - 2.0 / 3.0).abs() < 0.01);
+    assert!(api.score < health[1].score);
+    assert!(
+        api.concerns()
+            .iter()
+            .any(|c| c.contains("67% of open tasks blocked"))
+    );
+    assert!(api.concerns().iter().any(|c| c == "1 stale task(s)"));
+
+    let backend = health.iter().find(|h| h.area == "backend").unwrap();
+    assert!(backend.blocked_ratio.abs() < f32::EPSILON);
+    assert_eq!(backend.stale, 0);
+    assert_eq!(backend.dependency_depth, 1);
+}
+
+#[test]
+fn test_area_health_survives_dependency_cycles() {
+    let tasks = vec![
+        create_test_task(
+            "a-001",
+            "a",
+            TaskStatus::Todo,
+            vec!["a-002".to_string()],
+            None,
+        ),
+        create_test_task(
+            "a-002",
+            "a",
+            TaskStatus::Todo,
+            vec!["a-001".to_string()],
+            None,
+        ),
+    ];
+
+    let health = area_health(&tasks, &TaskAnalyzer::new(), 14, Utc::now().date_naive());
+    assert_eq!(health.len(), 1);
+    assert!((health[0].blocked_ratio - 1.0).abs() < f32::EPSILON);
+    assert!(health[0].dependency_depth <= 2);
+}